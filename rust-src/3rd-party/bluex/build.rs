@@ -18,6 +18,7 @@ fn main() {
     println!("cargo:rerun-if-changed=src/bluetooth.c");
     println!("cargo:rerun-if-changed=src/include/hci_lib.h");
 
+    if std::env::var("SKIP_BINDGEN").is_ok() { return; }
     let mut include_dir = vec![];
     std::env::var("TOOLCHAIN_INC")
         .expect("NOT FOUND {TOOLCHAIN_INC}")
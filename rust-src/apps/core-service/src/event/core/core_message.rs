@@ -3,17 +3,19 @@ use std::sync::Arc;
 
 use log::{error, warn};
 
-use near_base::{NearResult, NearError, ErrorCode, ObjectId,
+use near_base::{NearResult, NearError, ErrorCode, ObjectId, RevocationListObject,
     };
 use near_transport::{ProcessTrait, RoutineEventTrait, RoutineWrap, EventResult, Routine, HeaderMeta,
     };
 use near_util::TopicRef;
 
 use base::raw_object::RawObjectGuard;
-use topic_util::topic_types::{TOPIC_P_CORE_LABEL, TOPIC_S_SUBSCRIBE_LABEL, TOPIC_S_DISSUBSCRIBE_LABEL};
+use topic_util::topic_types::{TOPIC_P_CORE_LABEL, TOPIC_S_SUBSCRIBE_LABEL, TOPIC_S_DISSUBSCRIBE_LABEL,
+                              TOPIC_S_REDEEM_LABEL, TOPIC_S_GRANT_LABEL, TOPIC_S_MEMBERS_LABEL, TOPIC_S_REVOKE_LABEL, TOPIC_S_REVOCATION_LABEL, };
 use protos::{core_message::{Subscribe_message, Dissubscribe_message, }, 
              DataContent, RawObjectHelper
     };
+use right::{RedeemObject, GrantObject};
 
 use crate::event::MessageTrait;
 
@@ -60,6 +62,16 @@ impl ProcessTrait for CoreMessage {
                 Ok(RoutineWrap::new(OnSubscribeMessageRoutine::new(self.0.cb.clone_as_coremessage())) as Box<dyn RoutineEventTrait>) 
             } else if secondary.eq_ignore_ascii_case(TOPIC_S_DISSUBSCRIBE_LABEL) {
                 Ok(RoutineWrap::new(OnDissubscribeMessageRoutine::new(self.0.cb.clone_as_coremessage())) as Box<dyn RoutineEventTrait>) 
+            } else if secondary.eq_ignore_ascii_case(TOPIC_S_REDEEM_LABEL) {
+                Ok(RoutineWrap::new(OnRedeemRoutine::new(self.0.cb.clone_as_coremessage())) as Box<dyn RoutineEventTrait>)
+            } else if secondary.eq_ignore_ascii_case(TOPIC_S_GRANT_LABEL) {
+                Ok(RoutineWrap::new(OnGrantRoutine::new(self.0.cb.clone_as_coremessage())) as Box<dyn RoutineEventTrait>)
+            } else if secondary.eq_ignore_ascii_case(TOPIC_S_MEMBERS_LABEL) {
+                Ok(RoutineWrap::new(OnMembersRoutine::new(self.0.cb.clone_as_coremessage())) as Box<dyn RoutineEventTrait>)
+            } else if secondary.eq_ignore_ascii_case(TOPIC_S_REVOKE_LABEL) {
+                Ok(RoutineWrap::new(OnRevokeRoutine::new(self.0.cb.clone_as_coremessage())) as Box<dyn RoutineEventTrait>)
+            } else if secondary.eq_ignore_ascii_case(TOPIC_S_REVOCATION_LABEL) {
+                Ok(RoutineWrap::new(OnRevocationRoutine::new(self.0.cb.clone_as_coremessage())) as Box<dyn RoutineEventTrait>)
             } else {
                 Err(NearError::new(ErrorCode::NEAR_ERROR_TOPIC_SECONDARY, format!("The [{}] secondary topic cann't found.", topic)))
             }
@@ -168,3 +180,228 @@ impl Routine<RawObjectGuard, RawObjectGuard> for OnDissubscribeMessageRoutine {
     }
 
 }
+
+/// OnRedeemRoutine
+pub struct OnRedeemRoutine {
+    cb: Box<dyn CoreMessageCallbackTrait>,
+}
+
+impl OnRedeemRoutine {
+    pub fn new(cb: Box<dyn CoreMessageCallbackTrait>) -> Box<Self> {
+        Box::new(Self{cb})
+    }
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for OnRedeemRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+
+        let redeem = match RawObjectHelper::decode::<RedeemObject>(req) {
+            Ok(message) => {
+                if let DataContent::Content(m) = message {
+                    m
+                } else {
+                    error!("The redeem message is error.");
+                    return EventResult::Ignore;
+                }
+            }
+            Err(e) => {
+                error!("failed decode redeem message with err = {e}");
+                return EventResult::Ignore;
+            }
+        };
+
+        let r =
+            match self.cb.on_redeem(&header_meta.requestor, redeem) {
+                Ok(grant) => RawObjectHelper::encode(DataContent::Content(grant)),
+                Err(e) => {
+                    warn!("Warning: {} failed redeem with err: {e}", header_meta.requestor);
+                    RawObjectHelper::encode_with_error(e)
+                }
+            };
+
+        if let Ok(r) = r {
+            EventResult::Response(r.into())
+        } else {
+            EventResult::Ignore
+        }
+    }
+
+}
+
+/// OnGrantRoutine
+pub struct OnGrantRoutine {
+    cb: Box<dyn CoreMessageCallbackTrait>,
+}
+
+impl OnGrantRoutine {
+    pub fn new(cb: Box<dyn CoreMessageCallbackTrait>) -> Box<Self> {
+        Box::new(Self{cb})
+    }
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for OnGrantRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+
+        let grant = match RawObjectHelper::decode::<GrantObject>(req) {
+            Ok(message) => {
+                if let DataContent::Content(m) = message {
+                    m
+                } else {
+                    error!("The grant message is error.");
+                    return EventResult::Ignore;
+                }
+            }
+            Err(e) => {
+                error!("failed decode grant message with err = {e}");
+                return EventResult::Ignore;
+            }
+        };
+
+        let r =
+            match self.cb.on_grant(&header_meta.requestor, grant) {
+                Ok(_) => RawObjectHelper::encode_none(),
+                Err(e) => {
+                    warn!("Warning: {} failed grant with err: {e}", header_meta.requestor);
+                    RawObjectHelper::encode_with_error(e)
+                }
+            };
+
+        if let Ok(r) = r {
+            EventResult::Response(r.into())
+        } else {
+            EventResult::Ignore
+        }
+    }
+
+}
+
+/// OnMembersRoutine
+pub struct OnMembersRoutine {
+    cb: Box<dyn CoreMessageCallbackTrait>,
+}
+
+impl OnMembersRoutine {
+    pub fn new(cb: Box<dyn CoreMessageCallbackTrait>) -> Box<Self> {
+        Box::new(Self{cb})
+    }
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for OnMembersRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, _req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+
+        let r =
+            match self.cb.on_members(&header_meta.requestor) {
+                Ok(members) => RawObjectHelper::encode(DataContent::Content(members)),
+                Err(e) => {
+                    warn!("Warning: {} failed list members with err: {e}", header_meta.requestor);
+                    RawObjectHelper::encode_with_error(e)
+                }
+            };
+
+        if let Ok(r) = r {
+            EventResult::Response(r.into())
+        } else {
+            EventResult::Ignore
+        }
+    }
+
+}
+
+/// OnRevokeRoutine
+pub struct OnRevokeRoutine {
+    cb: Box<dyn CoreMessageCallbackTrait>,
+}
+
+impl OnRevokeRoutine {
+    pub fn new(cb: Box<dyn CoreMessageCallbackTrait>) -> Box<Self> {
+        Box::new(Self{cb})
+    }
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for OnRevokeRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+
+        let member = match RawObjectHelper::decode::<ObjectId>(req) {
+            Ok(message) => {
+                if let DataContent::Content(m) = message {
+                    m
+                } else {
+                    error!("The revoke message is error.");
+                    return EventResult::Ignore;
+                }
+            }
+            Err(e) => {
+                error!("failed decode revoke message with err = {e}");
+                return EventResult::Ignore;
+            }
+        };
+
+        let r =
+            match self.cb.on_revoke(&header_meta.requestor, &member) {
+                Ok(_) => RawObjectHelper::encode_none(),
+                Err(e) => {
+                    warn!("Warning: {} failed revoke {member} with err: {e}", header_meta.requestor);
+                    RawObjectHelper::encode_with_error(e)
+                }
+            };
+
+        if let Ok(r) = r {
+            EventResult::Response(r.into())
+        } else {
+            EventResult::Ignore
+        }
+    }
+
+}
+
+/// OnRevocationRoutine
+pub struct OnRevocationRoutine {
+    cb: Box<dyn CoreMessageCallbackTrait>,
+}
+
+impl OnRevocationRoutine {
+    pub fn new(cb: Box<dyn CoreMessageCallbackTrait>) -> Box<Self> {
+        Box::new(Self{cb})
+    }
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for OnRevocationRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+
+        let revocation = match RawObjectHelper::decode::<RevocationListObject>(req) {
+            Ok(message) => {
+                if let DataContent::Content(m) = message {
+                    m
+                } else {
+                    error!("The revocation message is error.");
+                    return EventResult::Ignore;
+                }
+            }
+            Err(e) => {
+                error!("failed decode revocation message with err = {e}");
+                return EventResult::Ignore;
+            }
+        };
+
+        let r =
+            match self.cb.on_revocation(&header_meta.requestor, revocation) {
+                Ok(_) => RawObjectHelper::encode_none(),
+                Err(e) => {
+                    warn!("Warning: {} failed update revocation list with err: {e}", header_meta.requestor);
+                    RawObjectHelper::encode_with_error(e)
+                }
+            };
+
+        if let Ok(r) = r {
+            EventResult::Response(r.into())
+        } else {
+            EventResult::Ignore
+        }
+    }
+
+}
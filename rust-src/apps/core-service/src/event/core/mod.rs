@@ -3,12 +3,18 @@ mod core_message;
 
 pub use core_message::CoreMessage;
 
-use near_base::{ObjectId, NearResult};
+use near_base::{ObjectId, NearResult, RevocationListObject};
 use near_util::Topic;
 use base::MessageType;
+use right::{GrantObject, RedeemObject};
 
 pub trait CoreMessageCallbackTrait: Sync + Send {
     fn clone_as_coremessage(&self) -> Box<dyn CoreMessageCallbackTrait>;
     fn on_subscribe(&self, from: &ObjectId, topic: Topic, mt: MessageType) -> NearResult<()>;
     fn on_dissubscribe(&self, from: &ObjectId, topic: Topic) -> NearResult<()>;
+    fn on_redeem(&self, from: &ObjectId, redeem: RedeemObject) -> NearResult<GrantObject>;
+    fn on_grant(&self, from: &ObjectId, grant: GrantObject) -> NearResult<()>;
+    fn on_members(&self, from: &ObjectId) -> NearResult<Vec<GrantObject>>;
+    fn on_revoke(&self, from: &ObjectId, member: &ObjectId) -> NearResult<()>;
+    fn on_revocation(&self, from: &ObjectId, revocation: RevocationListObject) -> NearResult<()>;
 }
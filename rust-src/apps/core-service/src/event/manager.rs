@@ -2,9 +2,10 @@
 use std::sync::Arc;
 
 use base::MessageType;
-use near_base::{NearResult, ObjectId};
+use near_base::{NearResult, ObjectId, RevocationListObject};
 use near_transport::{ProcessTrait, RoutineEventTrait, };
 use near_util::{Topic, TopicRef};
+use right::{GrantObject, RedeemObject};
 
 use crate::process::Process;
 
@@ -96,6 +97,26 @@ impl CoreMessageCallbackTrait for Manager {
     fn on_dissubscribe(&self, from: &ObjectId, topic: Topic) -> NearResult<()> {
         self.queue_message().dissubscribe(from, topic)
     }
+
+    fn on_redeem(&self, from: &ObjectId, redeem: RedeemObject) -> NearResult<GrantObject> {
+        self.0.stack.redeem(from, redeem)
+    }
+
+    fn on_grant(&self, from: &ObjectId, grant: GrantObject) -> NearResult<()> {
+        self.0.stack.grant(from, grant)
+    }
+
+    fn on_members(&self, from: &ObjectId) -> NearResult<Vec<GrantObject>> {
+        self.0.stack.members(from)
+    }
+
+    fn on_revoke(&self, from: &ObjectId, member: &ObjectId) -> NearResult<()> {
+        self.0.stack.revoke(from, member)
+    }
+
+    fn on_revocation(&self, _from: &ObjectId, revocation: RevocationListObject) -> NearResult<()> {
+        self.0.stack.update_revocation_list(revocation)
+    }
 }
 
 impl ProcessTrait for Manager {
@@ -118,4 +139,8 @@ impl DispatchCallbackTrait for Manager {
         unimplemented!()
         // CoreStack::get_instance().stack().post_text_message(Some(target), topic, body, Some(callback))
     }
+
+    fn check_right(&self, requestor: &ObjectId, topic: &Topic, mt: MessageType) -> NearResult<()> {
+        self.0.stack.check_right(requestor, topic, mt)
+    }
 }
@@ -2,6 +2,7 @@
 pub mod queue_message;
 pub mod sub_message;
 
+use base::MessageType;
 use near_base::{ObjectId, NearResult};
 use near_transport::RoutineEventTrait;
 use near_util::Topic;
@@ -9,4 +10,6 @@ use near_util::Topic;
 pub trait DispatchCallbackTrait: Send + Sync {
     fn clone_as_dispatch(&self) -> Box<dyn DispatchCallbackTrait>;
     fn on_dispatch(&self, from: &ObjectId, target:ObjectId, topic: Topic, body: Vec<u8>, callback: Box<dyn RoutineEventTrait>) -> NearResult<()>;
+    /// check the right of the requestor before the message is dispatched.
+    fn check_right(&self, requestor: &ObjectId, topic: &Topic, mt: MessageType) -> NearResult<()>;
 }
@@ -5,7 +5,7 @@ use std::{sync::{RwLock, Arc, },
 
 use base::MessageType;
 use log::{error, trace};
-use near_base::{ObjectId, NearResult, ErrorCode, NearError, ObjectTypeCode};
+use near_base::{ObjectId, NearResult, ErrorCode, NearError};
 use near_transport::{ProcessTrait, RoutineEventTrait, EventResult, HeaderMeta, process::{provider::EventTextResult, TransferEvent}};
 use near_util::{Topic, TopicRef};
 
@@ -99,16 +99,7 @@ impl SubMessage {
                 .cloned()
         } {
             Some(state) => {
-                match state.mt {
-                    MessageType::Public => Ok(()),
-                    MessageType::Private => {
-                        if let ObjectTypeCode::People = requestor.object_type_code()? {
-                            Err(NearError::new(ErrorCode::NEAR_ERROR_FORBIDDEN, format!("{topic} is forbbiden topic.")))
-                        } else {
-                            Ok(())
-                        }
-                    }
-                }?;
+                self.0.cb.check_right(requestor, topic, state.mt)?;
 
                 let r = 
                     state.targets
@@ -1,16 +1,21 @@
 
 use std::{sync::Arc, path::PathBuf};
 
-use log::debug;
-use near_base::{DeviceObject, ErrorCode, FileDecoder, NearError, NearResult, ObjectId, ObjectTypeCode, ServiceObjectSubCode, };
+use log::{debug, error, info};
+use near_base::{DeviceObject, ErrorCode, FileDecoder, FileEncoder, RevocationListObject, NearError, NearResult, ObjectId, ObjectTypeCode, ServiceObjectSubCode, };
 use near_core::{get_service_path, get_data_path};
-use near_util::TopicRef;
+use near_util::{Topic, TopicRef};
 use near_transport::{ProcessTrait, RoutineEventTrait, };
 
+use base::MessageType;
 use common::{RuntimeProcessTrait, CoreStack};
+use once_cell::sync::OnceCell;
+use right::{RightStack, RightStackConfig, GrantObject, RedeemObject};
 
 use crate::event::Manager as EventManager;
 
+const REVOCATIONS_FILE: &'static str = "revocations";
+
 #[derive(Clone)]
 pub(super) struct Config {
     #[allow(unused)]
@@ -26,6 +31,7 @@ struct ProcessImpl {
     config: Config,
 
     components: Option<ProcessComponents>,
+    right_stack: OnceCell<RightStack>,
 }
 
 #[derive(Clone)]
@@ -44,6 +50,7 @@ impl Process {
             service_name: service_name.to_owned(),
             config: config.clone(),
             components: None,
+            right_stack: OnceCell::new(),
         }));
 
         let mut_ret = unsafe { &mut *(Arc::as_ptr(&ret.0) as *mut ProcessImpl) };
@@ -71,11 +78,110 @@ impl Process {
         &self.0.components.as_ref().unwrap().event_manager
     }
 
+    /// The requests are checked by the message type only before the right stack is opened.
+    pub(crate) fn check_right(&self, requestor: &ObjectId, topic: &Topic, mt: MessageType) -> NearResult<()> {
+        match self.0.right_stack.get() {
+            Some(right_stack) => right_stack.check(requestor, topic, mt, None),
+            None => right::check_message_type(requestor, topic, mt),
+        }
+    }
+
+    #[inline]
+    fn right_stack(&self) -> NearResult<&RightStack> {
+        self.0.right_stack
+            .get()
+            .ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_UNINITIALIZED, "The right stack isn't opened."))
+    }
+
+    /// Only the owner could manage the members of the home.
+    fn check_owner(&self, requestor: &ObjectId) -> NearResult<&RightStack> {
+        let right_stack = self.right_stack()?;
+
+        if right_stack.owner() == Some(requestor) {
+            Ok(right_stack)
+        } else {
+            Err(NearError::new(ErrorCode::NEAR_ERROR_FORBIDDEN, format!("{requestor} isn't the owner.")))
+        }
+    }
+
+    /// The redeem is sent by the people who redeems the invite, nobody redeems it for the others.
+    pub(crate) fn redeem(&self, requestor: &ObjectId, redeem: RedeemObject) -> NearResult<GrantObject> {
+        let people = redeem.people().object_id();
+
+        if people != requestor {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_FORBIDDEN, format!("{requestor} can't redeem the invite for {people}.")));
+        }
+
+        self.right_stack()?.redeem(redeem)
+    }
+
+    /// The grant is signed by the owner, and it's sent by the owner too.
+    pub(crate) fn grant(&self, requestor: &ObjectId, grant: GrantObject) -> NearResult<()> {
+        self.check_owner(requestor)?.grant(grant)
+    }
+
+    pub(crate) fn members(&self, requestor: &ObjectId) -> NearResult<Vec<GrantObject>> {
+        Ok(self.check_owner(requestor)?.members())
+    }
+
+    /// The tunnels of the member are closed at once, so it has to connect again and it's refused.
+    pub(crate) fn revoke(&self, requestor: &ObjectId, member: &ObjectId) -> NearResult<()> {
+        self.check_owner(requestor)?.revoke(member)?;
+
+        if CoreStack::get_instance().stack().close_tunnels(member) {
+            debug!("closed the tunnels of {member}");
+        }
+
+        Ok(())
+    }
+
+    /// The revocation lists are kept in the data path, so they're enforced again after restart.
+    pub(crate) fn update_revocation_list(&self, revocation: RevocationListObject) -> NearResult<()> {
+        let stack = CoreStack::get_instance().stack();
+
+        stack.update_revocation_list(revocation)?;
+
+        stack.revocation_lists()
+            .encode_to_file(get_data_path().join(REVOCATIONS_FILE).as_path(), false)
+            .map(| _ | ())
+            .map_err(| e | {
+                error!("failed save revocation lists with err: {e}");
+                e
+            })
+    }
+
+    fn load_revocation_lists(&self) {
+        let path = get_data_path().join(REVOCATIONS_FILE);
+
+        if !path.exists() {
+            return;
+        }
+
+        match Vec::<RevocationListObject>::decode_from_file(path.as_path()) {
+            Ok(revocations) => {
+                for revocation in revocations {
+                    let _ = CoreStack::get_instance()
+                                .stack()
+                                .update_revocation_list(revocation)
+                                .map_err(| e | error!("ignore the revocation list with err: {e}"));
+                }
+                info!("loaded {}", path.display());
+            }
+            Err(e) => { error!("failed load {} with err: {e}", path.display()); }
+        }
+    }
+
 }
 
 #[async_trait::async_trait]
 impl RuntimeProcessTrait for Process {
     async fn run(&self) -> NearResult<()> {
+        let _ = self.0.right_stack.set(RightStack::open(&self.0.service_name,
+                                                          CoreStack::get_instance().stack(),
+                                                          // the services check the privileges of their topics.
+                                                          RightStackConfig { default_privilege: None, ..Default::default() }));
+        self.load_revocation_lists();
+
         let need_find_sn = 
             match CoreStack::get_instance().stack().local_device_id().object_type_code()? {
                 ObjectTypeCode::Device(_) => true,
@@ -60,7 +60,7 @@ impl From<(Hash256, u32)> for ChunkId {
         // 4bytes chunk len
         unsafe {
             let len_ptr = buff[1..5].as_mut_ptr() as * mut u32;
-            *len_ptr = len;
+            len_ptr.write_unaligned(len);
         }
        
         // remaining, reserve
@@ -77,7 +77,7 @@ impl ChunkId {
     pub fn len(&self) -> usize {
         let chunkid = self.as_slice();
 
-        return unsafe { * ( chunkid[1..5].as_ptr() as *const u32 ) } as usize;
+        return unsafe { ( chunkid[1..5].as_ptr() as *const u32 ).read_unaligned() } as usize;
     }
 
     pub fn to_objectid(&self) -> ObjectId {
@@ -0,0 +1,334 @@
+
+use crate::{errors::*, now, ObjectId, PrivateKey, PublicKey, Signature, Serialize, Deserialize,
+            NamedObject, ObjectDescTrait, ObjectBodyTrait};
+
+/// The object replaces its public key without changing its object id,
+/// the new key is trusted because the record is signed by the old key.
+#[derive(Clone)]
+pub struct KeyRotation {
+    object_id: ObjectId,
+    sequence: u64,
+    old_key: PublicKey,
+    new_key: PublicKey,
+    create_time: u64,
+}
+
+impl KeyRotation {
+    pub fn new(object_id: ObjectId, sequence: u64, old_key: PublicKey, new_key: PublicKey) -> Self {
+        Self {
+            object_id,
+            sequence,
+            old_key,
+            new_key,
+            create_time: now(),
+        }
+    }
+
+    pub fn object_id(&self) -> &ObjectId {
+        &self.object_id
+    }
+
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    pub fn old_key(&self) -> &PublicKey {
+        &self.old_key
+    }
+
+    pub fn new_key(&self) -> &PublicKey {
+        &self.new_key
+    }
+
+    pub fn create_time(&self) -> u64 {
+        self.create_time
+    }
+
+    fn signer_buffer(&self) -> NearResult<Vec<u8>> {
+        let mut buf = vec![0u8; self.raw_capacity()];
+        let _ = self.serialize(&mut buf)?;
+        Ok(buf)
+    }
+
+    /// Sign it with the old private key.
+    pub fn sign(self, old_private_key: &PrivateKey) -> NearResult<KeyRotationObject> {
+        if old_private_key.public() != self.old_key {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, "the private key isn't the old key"));
+        }
+
+        let signature = old_private_key.sign(&self.signer_buffer()?)?;
+
+        Ok(KeyRotationObject {
+            rotation: self,
+            signature,
+        })
+    }
+}
+
+impl std::fmt::Display for KeyRotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "object_id: {}, sequence: {}, create_time: {}", self.object_id, self.sequence, self.create_time)
+    }
+}
+
+impl Serialize for KeyRotation {
+    fn raw_capacity(&self) -> usize {
+        self.object_id.raw_capacity() +
+        self.sequence.raw_capacity() +
+        self.old_key.raw_capacity() +
+        self.new_key.raw_capacity() +
+        self.create_time.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = self.object_id.serialize(buf)?;
+        let buf = self.sequence.serialize(buf)?;
+        let buf = self.old_key.serialize(buf)?;
+        let buf = self.new_key.serialize(buf)?;
+        let buf = self.create_time.serialize(buf)?;
+
+        Ok(buf)
+    }
+}
+
+impl Deserialize for KeyRotation {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (object_id, buf) = ObjectId::deserialize(buf)?;
+        let (sequence, buf) = u64::deserialize(buf)?;
+        let (old_key, buf) = PublicKey::deserialize(buf)?;
+        let (new_key, buf) = PublicKey::deserialize(buf)?;
+        let (create_time, buf) = u64::deserialize(buf)?;
+
+        Ok((Self {
+            object_id, sequence, old_key, new_key, create_time,
+        }, buf))
+    }
+}
+
+/// The key rotation and the signature of the old key.
+#[derive(Clone)]
+pub struct KeyRotationObject {
+    rotation: KeyRotation,
+    signature: Signature,
+}
+
+impl KeyRotationObject {
+    pub fn rotation(&self) -> &KeyRotation {
+        &self.rotation
+    }
+
+    /// The old key must be the current key of the object.
+    pub fn verify(&self, current_key: &PublicKey) -> NearResult<()> {
+        if &self.rotation.old_key != current_key {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("the old key of {} isn't the current key", self.rotation.object_id)));
+        }
+
+        self.rotation.old_key.verify(&self.rotation.signer_buffer()?, &self.signature)
+    }
+
+    /// Replace the public key of the object with the new key, the object id is kept.
+    /// The sequence must be greater than the last applied one, so the older rotation can't be replayed.
+    pub fn apply<DESC, BODY>(&self, last_sequence: u64, object: &mut NamedObject<DESC, BODY>) -> NearResult<()>
+    where DESC: ObjectDescTrait<PublicKeyObj = PublicKey> + Serialize + Deserialize + std::default::Default,
+          BODY: ObjectBodyTrait + Serialize + Deserialize + std::default::Default {
+        if object.object_id() != &self.rotation.object_id {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("the rotation isn't for {}", object.object_id())));
+        }
+
+        if self.rotation.sequence <= last_sequence {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_EXPIRED, format!("the rotation {} isn't newer than {last_sequence}", self.rotation.sequence)));
+        }
+
+        let current_key =
+            object.desc()
+                .public_key()
+                .ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_CRYPTO_INVALID_PUBKEY, format!("{} hasn't public key", object.object_id())))?;
+
+        self.verify(current_key)?;
+
+        object.mut_desc().set_public_key(Some(self.rotation.new_key.clone()));
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for KeyRotationObject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "rotation: {}, sign: {{***}}", self.rotation)
+    }
+}
+
+impl std::fmt::Debug for KeyRotationObject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn std::fmt::Display).fmt(f)
+    }
+}
+
+impl Serialize for KeyRotationObject {
+    fn raw_capacity(&self) -> usize {
+        self.rotation.raw_capacity() + self.signature.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = self.rotation.serialize(buf)?;
+        let buf = self.signature.serialize(buf)?;
+
+        Ok(buf)
+    }
+}
+
+impl Deserialize for KeyRotationObject {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (rotation, buf) = KeyRotation::deserialize(buf)?;
+        let (signature, buf) = Signature::deserialize(buf)?;
+
+        Ok((Self { rotation, signature }, buf))
+    }
+}
+
+/// All of the rotations of the object in order,
+/// the current key is trusted from the key which the object id is built from.
+#[derive(Clone, Default)]
+pub struct KeyRotationChain(Vec<KeyRotationObject>);
+
+impl KeyRotationChain {
+    pub fn rotations(&self) -> &[KeyRotationObject] {
+        &self.0
+    }
+
+    /// It's 0 before the first rotation.
+    pub fn last_sequence(&self) -> u64 {
+        self.0.last().map(| r | r.rotation.sequence).unwrap_or(0)
+    }
+
+    /// Apply the rotation to the object and append it.
+    pub fn apply<DESC, BODY>(&mut self, rotation: KeyRotationObject, object: &mut NamedObject<DESC, BODY>) -> NearResult<()>
+    where DESC: ObjectDescTrait<PublicKeyObj = PublicKey> + Serialize + Deserialize + std::default::Default,
+          BODY: ObjectBodyTrait + Serialize + Deserialize + std::default::Default {
+        rotation.apply(self.last_sequence(), object)?;
+        self.0.push(rotation);
+        Ok(())
+    }
+
+    /// Every rotation is signed by the key before it with a greater sequence.
+    pub fn verify(&self) -> NearResult<()> {
+        let mut last: Option<&KeyRotation> = None;
+
+        for r in self.0.iter() {
+            let (sequence, current_key) =
+                match last {
+                    Some(last) => {
+                        if last.object_id != r.rotation.object_id {
+                            return Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("the rotation {} isn't for {}", r.rotation, last.object_id)));
+                        }
+                        (last.sequence, &last.new_key)
+                    }
+                    None => (0, &r.rotation.old_key),
+                };
+
+            if r.rotation.sequence <= sequence {
+                return Err(NearError::new(ErrorCode::NEAR_ERROR_EXPIRED, format!("the rotation {} isn't newer than {sequence}", r.rotation.sequence)));
+            }
+
+            r.verify(current_key)?;
+
+            last = Some(&r.rotation);
+        }
+
+        Ok(())
+    }
+
+    /// The object id must be built from the key before the first rotation,
+    /// and the last new key is the current key of the object.
+    pub fn verify_object<DESC, BODY>(&self, object: &NamedObject<DESC, BODY>) -> NearResult<()>
+    where DESC: ObjectDescTrait<PublicKeyObj = PublicKey> + Serialize + Deserialize + std::default::Default,
+          BODY: ObjectBodyTrait + Serialize + Deserialize + std::default::Default {
+        let (first, last) =
+            match (self.0.first(), self.0.last()) {
+                (Some(first), Some(last)) => (&first.rotation, &last.rotation),
+                _ => return object.verify_object_id(),
+            };
+
+        if &first.object_id != object.object_id() {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("the rotations aren't for {}", object.object_id())));
+        }
+
+        let mut origin = object.desc().clone();
+        origin.set_public_key(Some(first.old_key.clone()));
+        if &origin.calculate_id()? != object.object_id() {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("{} isn't built from the key before the first rotation", object.object_id())));
+        }
+
+        if object.desc().public_key() != Some(&last.new_key) {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("the last key of the rotations isn't the current key of {}", object.object_id())));
+        }
+
+        self.verify()
+    }
+}
+
+impl Serialize for KeyRotationChain {
+    fn raw_capacity(&self) -> usize {
+        self.0.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        self.0.serialize(buf)
+    }
+}
+
+impl Deserialize for KeyRotationChain {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (rotations, buf) = Vec::<KeyRotationObject>::deserialize(buf)?;
+
+        Ok((Self(rotations), buf))
+    }
+}
+
+#[test]
+fn test_key_rotation() {
+    use crate::{ObjectBuilder, RawConvertTo, device::{DeviceDescContent, DeviceBodyContent}};
+
+    let old_key = PrivateKey::generate_rsa1024().unwrap();
+    let new_key = PrivateKey::generate_rsa1024().unwrap();
+
+    let mut device =
+        ObjectBuilder::new(DeviceDescContent::default(), DeviceBodyContent::default())
+            .update_desc(| desc | {
+                desc.set_public_key(old_key.public());
+            })
+            .build()
+            .unwrap();
+    let object_id = device.object_id().clone();
+
+    let rotation =
+        KeyRotation::new(object_id.clone(), 1, old_key.public(), new_key.public())
+            .sign(&old_key)
+            .unwrap();
+
+    let mut chain = KeyRotationChain::default();
+    chain.apply(rotation.clone(), &mut device).unwrap();
+    assert_eq!(device.object_id(), &object_id);
+    assert!(device.desc().public_key() == Some(&new_key.public()));
+    assert!(device.verify_object_id().is_err());
+    chain.verify_object(&device).unwrap();
+
+    // it has been rotated, so the old key isn't the current key.
+    assert!(rotation.apply(chain.last_sequence(), &mut device).is_err());
+
+    // the key is rotated back, but the first rotation can't be replayed.
+    let back = KeyRotation::new(object_id.clone(), 2, new_key.public(), old_key.public()).sign(&new_key).unwrap();
+    chain.apply(back, &mut device).unwrap();
+    assert!(rotation.apply(chain.last_sequence(), &mut device).unwrap_err().errno() == ErrorCode::NEAR_ERROR_EXPIRED);
+    assert!(chain.clone().apply(rotation.clone(), &mut device).is_err());
+    chain.verify_object(&device).unwrap();
+
+    // the chain which misses a rotation doesn't reach the current key.
+    let (chain, _) = KeyRotationChain::deserialize(&chain.to_vec().unwrap()).unwrap();
+    assert_eq!(chain.last_sequence(), 2);
+    assert!(KeyRotationChain(chain.rotations()[1..].to_vec()).verify_object(&device).is_err());
+    assert!(KeyRotationChain(chain.rotations()[..1].to_vec()).verify_object(&device).is_err());
+}
@@ -9,6 +9,8 @@ pub mod hash_util;
 pub mod objects;
 pub mod chunk;
 pub mod check_sum;
+pub mod revocation;
+pub mod key_rotation;
 
 pub use area::Area;
 pub use device_types::DeviceType;
@@ -19,5 +21,7 @@ pub use hash_value::Hash256;
 pub use hash_util::{hash_data, hash_file};
 pub use chunk::{ChunkId, CHUNK_MAX_LEN};
 pub use check_sum::*;
+pub use revocation::{RevocationList, RevocationListObject, check_device};
+pub use key_rotation::{KeyRotation, KeyRotationObject, KeyRotationChain};
 
 pub use objects::*;
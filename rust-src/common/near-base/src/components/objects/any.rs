@@ -3,6 +3,7 @@ use crate::{DeviceObject,
             people::PeopleObject, 
             ExtentionObject, 
             file::FileObject, 
+            dir::DirObject, 
             Serialize, Deserialize, 
             ObjectId, ObjectTypeCode, 
             NearResult, NearError, ErrorCode, 
@@ -17,6 +18,7 @@ pub enum AnyNamedObject {
     People(PeopleObject),
     Extention(ExtentionObject),
     File(FileObject),
+    Dir(DirObject),
     Thing(ThingObject),
 }
 
@@ -29,6 +31,7 @@ macro_rules! match_anynamed_obj {
             Self::People($o) => $body,
             Self::Extention($o) => $body,
             Self::File($o) => $body,
+            Self::Dir($o) => $body,
             Self::Thing($o) => $body,
         }
     };
@@ -58,6 +61,22 @@ impl AnyNamedObject {
     pub fn area(&self) -> Option<&Area> {
         match_anynamed_obj!(self, o, { o.desc().area() }, )
     }
+
+    pub fn create_timestamp(&self) -> u64 {
+        match_anynamed_obj!(self, o, { o.desc().create_timestamp() }, )
+    }
+
+    pub fn expired_time(&self) -> Option<u64> {
+        match_anynamed_obj!(self, o, { o.desc().expired_time() }, )
+    }
+
+    pub fn update_time(&self) -> u64 {
+        match_anynamed_obj!(self, o, { o.body().update_time() }, )
+    }
+
+    pub fn verify_object_id(&self) -> NearResult<()> {
+        match_anynamed_obj!(self, o, { o.verify_object_id() }, )
+    }
 }
 
 impl Serialize for AnyNamedObject {
@@ -69,6 +88,7 @@ impl Serialize for AnyNamedObject {
             Self::People(o) => o.raw_capacity(),
             Self::Extention(o) => o.raw_capacity(),
             Self::File(o) => o.raw_capacity(),
+            Self::Dir(o) => o.raw_capacity(),
             Self::Thing(o) => o.raw_capacity(),
         }
     }
@@ -82,6 +102,7 @@ impl Serialize for AnyNamedObject {
             Self::People(o) => o.serialize(buf),
             Self::Extention(o) => o.serialize(buf),
             Self::File(o) => o.serialize(buf),
+            Self::Dir(o) => o.serialize(buf),
             Self::Thing(o) => o.serialize(buf),
         }
     }
@@ -101,6 +122,7 @@ impl Deserialize for AnyNamedObject {
             ObjectTypeCode::Extention => ExtentionObject::deserialize(buf).map(| (o, buf) | (Self::Extention(o), buf)),
             ObjectTypeCode::People => PeopleObject::deserialize(buf).map(| (o, buf) | (Self::People(o), buf)),
             ObjectTypeCode::File => FileObject::deserialize(buf).map(| (o, buf) | (Self::File(o), buf)),
+            ObjectTypeCode::Dir => DirObject::deserialize(buf).map(| (o, buf) | (Self::Dir(o), buf)),
             ObjectTypeCode::Thing => ThingObject::deserialize(buf).map(| (o, buf) | (Self::Thing(o), buf)),
             _ => {
                 Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("Parsing not supported in AnyNamedObject, object_type_code={}.", object_type_code)))
@@ -118,6 +140,7 @@ impl std::fmt::Display for AnyNamedObject {
             Self::People(o) => o.fmt(f),
             Self::Extention(o) => o.fmt(f),
             Self::File(o) => o.fmt(f),
+            Self::Dir(o) => o.fmt(f),
             Self::Thing(o) => o.fmt(f,)
         }
     }
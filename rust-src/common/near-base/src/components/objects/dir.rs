@@ -0,0 +1,271 @@
+
+use crate::{components::ObjectTypeCode,
+            Serialize, Deserialize,
+            public_key::PublicKey,
+            errors::*, ObjectBodyTrait, ObjectDescTrait,
+            Area,
+            Hash256, hash_data, };
+
+use super::{object_type::ObjectId,
+            object_impl::{NamedObject, NamedObjectDesc, NamedObjectBody},
+            file::FileObject, };
+
+pub type DirDesc = NamedObjectDesc<DirDescContent>;
+pub type DirBody = NamedObjectBody<DirBodyContent>;
+pub type DirObject = NamedObject<DirDescContent, DirBodyContent>;
+
+/// The file of the directory tree.
+#[derive(Clone, Default)]
+pub struct DirEntry {
+    /// relative path to the root of the directory, it's separated by '/'.
+    path: String,
+    /// unix permission bits.
+    mode: u32,
+    /// modified time in microseconds.
+    mtime: u64,
+    file: FileObject,
+}
+
+impl DirEntry {
+    pub fn new(path: String, mode: u32, mtime: u64, file: FileObject) -> Self {
+        Self { path, mode, mtime, file }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn mode(&self) -> u32 {
+        self.mode
+    }
+
+    pub fn mtime(&self) -> u64 {
+        self.mtime
+    }
+
+    pub fn file(&self) -> &FileObject {
+        &self.file
+    }
+}
+
+impl std::fmt::Display for DirEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "path: [{}], mode: [{:o}], mtime: [{}], file: [{}]", self.path, self.mode, self.mtime, self.file.object_id())
+    }
+}
+
+impl Serialize for DirEntry {
+    fn raw_capacity(&self) -> usize {
+        self.path.raw_capacity() +
+        self.mode.raw_capacity() +
+        self.mtime.raw_capacity() +
+        self.file.raw_capacity()
+    }
+
+    fn serialize<'a>(&self, buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = self.path.serialize(buf)?;
+        let buf = self.mode.serialize(buf)?;
+        let buf = self.mtime.serialize(buf)?;
+        let buf = self.file.serialize(buf)?;
+
+        Ok(buf)
+    }
+
+}
+
+impl Deserialize for DirEntry {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (path, buf) = String::deserialize(buf)?;
+        let (mode, buf) = u32::deserialize(buf)?;
+        let (mtime, buf) = u64::deserialize(buf)?;
+        let (file, buf) = FileObject::deserialize(buf)?;
+
+        Ok((Self{
+            path, mode, mtime, file,
+        }, buf))
+    }
+
+}
+
+#[derive(Clone, Default)]
+pub struct DirDescContent {
+    name: String,
+    /// the total length of the files.
+    len: u64,
+    /// the hash of the entries, the object id of the directory changes when any file changes.
+    hash: Hash256,
+}
+
+impl DirDescContent {
+    pub fn new(name: String, body: &DirBodyContent) -> NearResult<Self> {
+        let len = body.entries.iter().map(| entry | entry.file.desc().content().len()).sum();
+
+        let mut data = vec![0u8; body.raw_capacity()];
+        let _ = body.serialize(&mut data)?;
+
+        Ok(Self { name, len, hash: hash_data(&data) })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub fn hash(&self) -> &Hash256 {
+        &self.hash
+    }
+}
+
+impl std::fmt::Display for DirDescContent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "object_type_code: [{}], name: [{}], len: [{}], hash: [{}]", self.object_type_code(), self.name, self.len, self.hash)
+    }
+}
+
+impl ObjectDescTrait for DirDescContent {
+    fn object_type_code(&self) -> ObjectTypeCode {
+        ObjectTypeCode::with_dir()
+    }
+
+    type OwnerObj = ObjectId;
+    type AreaObj = Area;
+    type AuthorObj = ObjectId;
+    type PublicKeyObj = PublicKey;
+
+}
+
+impl Serialize for DirDescContent {
+    fn raw_capacity(&self) -> usize {
+        self.name.raw_capacity() +
+        self.len.raw_capacity() +
+        self.hash.raw_capacity()
+    }
+
+    fn serialize<'a>(&self, buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = self.name.serialize(buf)?;
+        let buf = self.len.serialize(buf)?;
+        let buf = self.hash.serialize(buf)?;
+
+        Ok(buf)
+    }
+
+}
+
+impl Deserialize for DirDescContent {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (name, buf) = String::deserialize(buf)?;
+        let (len, buf) = u64::deserialize(buf)?;
+        let (hash, buf) = Hash256::deserialize(buf)?;
+
+        Ok((Self{
+            name, len, hash,
+        }, buf))
+    }
+
+}
+
+#[derive(Clone, Default)]
+pub struct DirBodyContent {
+    entries: Vec<DirEntry>,
+}
+
+impl DirBodyContent {
+    /// The entries are sorted by the path, so the same tree is always the same object.
+    pub fn new(mut entries: Vec<DirEntry>) -> Self {
+        entries.sort_by(| a, b | a.path.cmp(&b.path));
+
+        Self {
+            entries
+        }
+    }
+}
+
+impl DirBodyContent {
+    pub fn entries(&self) -> &[DirEntry] {
+        self.entries.as_slice()
+    }
+
+    pub fn entry_of(&self, path: &str) -> Option<&DirEntry> {
+        self.entries
+            .binary_search_by(| entry | entry.path.as_str().cmp(path))
+            .ok()
+            .map(| index | &self.entries[index])
+    }
+}
+
+impl std::fmt::Display for DirBodyContent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DirBodyContent: [ entries: {} ]",
+               self.entries.len())
+    }
+}
+
+impl ObjectBodyTrait for DirBodyContent {
+}
+
+impl Serialize for DirBodyContent {
+    fn raw_capacity(&self) -> usize {
+        self.entries.raw_capacity()
+    }
+
+    fn serialize<'a>(&self, buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = self.entries.serialize(buf)?;
+
+        Ok(buf)
+    }
+
+}
+
+impl Deserialize for DirBodyContent {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (entries, buf) = Vec::<DirEntry>::deserialize(buf)?;
+
+        Ok((Self{
+            entries
+        }, buf))
+    }
+
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::{hash_data, ObjectBuilder, file::{FileDescContent, FileBodyContent}, };
+
+    use super::*;
+
+    #[test]
+    fn test_dir() {
+        let file = | name: &str, data: &str | {
+            ObjectBuilder::new(FileDescContent::new(name.into(), data.len() as u64, hash_data(data.as_bytes())),
+                               FileBodyContent::new(vec![]))
+                .build().unwrap()
+        };
+
+        let body = DirBodyContent::new(vec![DirEntry::new("b/c.txt".into(), 0o644, 2, file("c.txt", "456")),
+                                            DirEntry::new("a.txt".into(), 0o755, 1, file("a.txt", "123"))]);
+        let dir =
+            ObjectBuilder::new(DirDescContent::new("test".into(), &body).unwrap(), body)
+                .build().unwrap();
+
+        assert_eq!(dir.desc().content().len(), 6);
+        assert_eq!(dir.body().content().entries()[0].path(), "a.txt");
+        assert_eq!(dir.body().content().entry_of("b/c.txt").unwrap().mode(), 0o644);
+
+        let mut data = vec![0u8; dir.raw_capacity()];
+        let _ = dir.serialize(&mut data).unwrap();
+        let (other, _) = DirObject::deserialize(&data).unwrap();
+
+        assert_eq!(other.object_id(), dir.object_id());
+        assert_eq!(other.body().content().entries().len(), 2);
+
+        let code = dir.object_id().object_type_code().unwrap();
+        assert_eq!(code.name(), "Dir");
+        assert_eq!(code.into_u16(), ObjectTypeCode::with_dir().into_u16());
+        assert_eq!(ObjectTypeCode::from(code.into_u16()).name(), "Dir");
+    }
+
+}
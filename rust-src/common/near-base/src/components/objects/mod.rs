@@ -8,6 +8,7 @@ pub mod thing;
 pub mod extention;
 pub mod people;
 pub mod file;
+pub mod dir;
 pub mod proof_of_data;
 pub mod any;
 
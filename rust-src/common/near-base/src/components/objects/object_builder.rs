@@ -219,6 +219,14 @@ where T: ObjectDescTrait + Serialize + Deserialize + std::default::Default {
     }
 }
 
+impl<T> NamedObjectDesc<T>
+where T: ObjectDescTrait + Serialize + Deserialize + std::default::Default {
+    /// Build the object id from the desc again.
+    pub fn calculate_id(&self) -> NearResult<ObjectId> {
+        ObjectIdBuilder::new(self).build()
+    }
+}
+
 pub struct ObjectBuilder<DESC, BODY>
 where DESC: ObjectDescTrait + Serialize + Deserialize,
       BODY: ObjectBodyTrait + Serialize + Deserialize {
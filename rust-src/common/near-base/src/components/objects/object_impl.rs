@@ -114,6 +114,11 @@ impl<T: ObjectDescTrait + Serialize + Deserialize + std::default::Default> Named
         self.public_key.as_ref()
     }
 
+    /// It doesn't change the object id, so it's only used by the key rotation.
+    pub fn set_public_key(&mut self, public_key: Option<T::PublicKeyObj>) {
+        self.public_key = public_key;
+    }
+
     pub fn content(&self) -> &T {
         &self.content
     }
@@ -323,6 +328,17 @@ where DESC: ObjectDescTrait + Serialize + Deserialize + std::default::Default,
         self.nonce = nonce;
     }
 
+    /// The object id must be built from its desc, the rotated one is checked by its `KeyRotationChain`.
+    pub fn verify_object_id(&self) -> NearResult<()> {
+        let object_id = self.desc.calculate_id()?;
+
+        if object_id == self.object_id {
+            Ok(())
+        } else {
+            Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("the object id is {}, but its desc is {object_id}", self.object_id)))
+        }
+    }
+
 }
 
 impl<DESC, BODY> Serialize for NamedObject<DESC, BODY>
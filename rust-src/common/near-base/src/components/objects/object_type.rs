@@ -22,6 +22,7 @@ pub(super) enum ObjectTypeMajorCode {
     OBJECT_TYPE_THING_CODE      = 4,
     OBJECT_TYPE_PEOPLE_CODE     = 6,
     OBJECT_TYPE_FILE_CODE       = 0xa,
+    OBJECT_TYPE_DIR_CODE        = 0xb,
     OBJECT_TYPE_RAW_CODE        = 0xe,
     OBJECT_TYPE_OTHER_CODE      = 0xf,
 }
@@ -37,6 +38,7 @@ impl std::fmt::Display for ObjectTypeMajorCode {
                 Self::OBJECT_TYPE_THING_CODE => "Thing",
                 Self::OBJECT_TYPE_PEOPLE_CODE => "People",
                 Self::OBJECT_TYPE_FILE_CODE => "File",
+                Self::OBJECT_TYPE_DIR_CODE => "Dir",
                 Self::OBJECT_TYPE_RAW_CODE => "Raw",
                 Self::OBJECT_TYPE_OTHER_CODE => "Other",
             };
@@ -57,6 +59,7 @@ impl TryFrom<u8> for ObjectTypeMajorCode {
             4 => Ok(Self::OBJECT_TYPE_THING_CODE),
             6 => Ok(Self::OBJECT_TYPE_PEOPLE_CODE),
             0xa => Ok(Self::OBJECT_TYPE_FILE_CODE),
+            0xb => Ok(Self::OBJECT_TYPE_DIR_CODE),
             0xe => Ok(Self::OBJECT_TYPE_RAW_CODE),
             0xf => Ok(Self::OBJECT_TYPE_OTHER_CODE),
             _ => Err(NearError::new(ErrorCode::NEAR_ERROR_UNDEFINED, format!("{value} undefined.")))
@@ -200,6 +203,8 @@ pub enum ObjectTypeCode {
     People,
     /// 文件
     File,
+    /// 目录
+    Dir,
     /// Thing属性，如灯等
     Thing,
     /// 内存数据
@@ -217,6 +222,7 @@ impl std::fmt::Display for ObjectTypeCode {
             Self::Extention => ("Extention", 0u8),
             Self::People => ("People", 0u8),
             Self::File => ("File", 0u8),
+            Self::Dir => ("Dir", 0u8),
             Self::Thing => ("Thing", 0u8),
             Self::Raw => ("Raw", 0u8),
             Self::Other(v) => ("Other", *v),
@@ -247,6 +253,10 @@ impl ObjectTypeCode {
         ObjectTypeCode::File
     }
 
+    pub fn with_dir() -> Self {
+        ObjectTypeCode::Dir
+    }
+
     pub fn with_thing() -> Self {
         ObjectTypeCode::Thing
     }
@@ -268,6 +278,7 @@ impl ObjectTypeCode {
                 ObjectTypeCode::Extention => (ObjectTypeMajorCode::OBJECT_TYPE_EXTENTION_CODE as u8, 0u8),
                 ObjectTypeCode::People => (ObjectTypeMajorCode::OBJECT_TYPE_PEOPLE_CODE as u8, 0u8),
                 ObjectTypeCode::File => (ObjectTypeMajorCode::OBJECT_TYPE_FILE_CODE as u8, 0u8),
+                ObjectTypeCode::Dir => (ObjectTypeMajorCode::OBJECT_TYPE_DIR_CODE as u8, 0u8),
                 ObjectTypeCode::Thing => (ObjectTypeMajorCode::OBJECT_TYPE_THING_CODE as u8, 0u8),
                 ObjectTypeCode::Raw => (ObjectTypeMajorCode::OBJECT_TYPE_RAW_CODE as u8, 0u8),
                 ObjectTypeCode::Other(v) => (ObjectTypeMajorCode::OBJECT_TYPE_OTHER_CODE as u8, v),
@@ -278,6 +289,42 @@ impl ObjectTypeCode {
         v
     }
 
+    /// The name of the major code.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ObjectTypeCode::Unknown => "Unknown",
+            ObjectTypeCode::Service(_) => "Service",
+            ObjectTypeCode::Device(_) => "Device",
+            ObjectTypeCode::Extention => "Extention",
+            ObjectTypeCode::People => "People",
+            ObjectTypeCode::File => "File",
+            ObjectTypeCode::Dir => "Dir",
+            ObjectTypeCode::Thing => "Thing",
+            ObjectTypeCode::Raw => "Raw",
+            ObjectTypeCode::Other(_) => "Other",
+        }
+    }
+
+    /// Only the service, device and other objects have the sub code.
+    pub fn sub_code(&self) -> Option<u8> {
+        match self {
+            ObjectTypeCode::Service(v) | 
+            ObjectTypeCode::Device(v) | 
+            ObjectTypeCode::Other(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// The name of the sub code, it's none if the sub code is undefined.
+    pub fn sub_code_name(&self) -> Option<String> {
+        match self {
+            ObjectTypeCode::Service(v) => ServiceObjectSubCode::try_from(*v).ok().map(| v | v.to_string()),
+            ObjectTypeCode::Device(v) => DeviceObjectSubCode::try_from(*v).ok().map(| v | v.to_string()),
+            ObjectTypeCode::Other(v) => OtherObjectSubCode::try_from(*v).ok().map(| v | v.to_string()),
+            _ => None,
+        }
+    }
+
     pub fn split(self) -> (u8 /* master */, u8 /* property */) {
         match self {
             ObjectTypeCode::Unknown => (0u8, 0u8),
@@ -286,6 +333,7 @@ impl ObjectTypeCode {
             ObjectTypeCode::Extention => (ObjectTypeMajorCode::OBJECT_TYPE_EXTENTION_CODE as u8, 0u8),
             ObjectTypeCode::People => (ObjectTypeMajorCode::OBJECT_TYPE_PEOPLE_CODE as u8, 0u8),
             ObjectTypeCode::File => (ObjectTypeMajorCode::OBJECT_TYPE_FILE_CODE as u8, 0u8),
+            ObjectTypeCode::Dir => (ObjectTypeMajorCode::OBJECT_TYPE_DIR_CODE as u8, 0u8),
             ObjectTypeCode::Thing => (ObjectTypeMajorCode::OBJECT_TYPE_THING_CODE as u8, 0u8),
             ObjectTypeCode::Raw => (ObjectTypeMajorCode::OBJECT_TYPE_RAW_CODE as u8, 0u8),
             ObjectTypeCode::Other(v) => (ObjectTypeMajorCode::OBJECT_TYPE_OTHER_CODE as u8, v),
@@ -323,6 +371,11 @@ impl ObjectTypeCode {
                     .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_SYSTERM, format!("{e}")))?;
                 Ok(())
             }
+            ObjectTypeCode::Dir => {
+                text.write_fmt(format_args!("{}", ObjectTypeMajorCode::OBJECT_TYPE_DIR_CODE))
+                    .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_SYSTERM, format!("{e}")))?;
+                Ok(())
+            }
             ObjectTypeCode::Thing => {
                 text.write_fmt(format_args!("{}", ObjectTypeMajorCode::OBJECT_TYPE_THING_CODE))
                     .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_SYSTERM, format!("{e}")))?;
@@ -362,6 +415,8 @@ impl From<u16> for ObjectTypeCode {
             ObjectTypeCode::People
         } else if h == ObjectTypeMajorCode::OBJECT_TYPE_FILE_CODE as u8 { 
             ObjectTypeCode::File
+        } else if h == ObjectTypeMajorCode::OBJECT_TYPE_DIR_CODE as u8 {
+            ObjectTypeCode::Dir
         } else if h == ObjectTypeMajorCode::OBJECT_TYPE_THING_CODE as u8 {
             ObjectTypeCode::Thing
         } else if h == ObjectTypeMajorCode::OBJECT_TYPE_RAW_CODE as u8 {
@@ -394,6 +449,8 @@ impl TryFrom<&ObjectId> for ObjectTypeCode {
             ObjectTypeCode::People
         } else if master == ObjectTypeMajorCode::OBJECT_TYPE_FILE_CODE as u8 {
             ObjectTypeCode::File
+        } else if master == ObjectTypeMajorCode::OBJECT_TYPE_DIR_CODE as u8 {
+            ObjectTypeCode::Dir
         } else if master == ObjectTypeMajorCode::OBJECT_TYPE_THING_CODE as u8 {
             ObjectTypeCode::Thing
         } else if master == ObjectTypeMajorCode::OBJECT_TYPE_RAW_CODE as u8 {
@@ -468,6 +525,16 @@ impl ObjectId {
     pub fn object_type_code(&self) -> NearResult<ObjectTypeCode> {
         ObjectTypeCode::try_from(self)
     }
+
+    /// Parse the base58 object id, the unknown object type is refused.
+    pub fn from_str_typed(s: &str) -> NearResult<(Self, ObjectTypeCode)> {
+        let id = Self::from_str(s)?;
+
+        match id.object_type_code()? {
+            ObjectTypeCode::Unknown => Err(NearError::new(ErrorCode::NEAR_ERROR_UNDEFINED, format!("{s} is unknown object type."))),
+            code => Ok((id, code)),
+        }
+    }
 }
 
 impl AsRef<GenericArray<u8, U32>> for ObjectId {
@@ -510,6 +577,10 @@ impl FromStr for ObjectId {
                     })
                 })?;
 
+        if v.len() != Self::capacity() {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, format!("{s} isn't {} bytes.", Self::capacity())));
+        }
+
         Ok(Self(GenericArray::clone_from_slice(v.as_slice())))
     }
 
@@ -540,3 +611,27 @@ impl PartialEq<ObjectId> for &ObjectId {
         self.0.eq(&other.0)
     }
 }
+
+#[test]
+fn test_object_id_typed() {
+    use crate::{DeviceObject, ObjectBuilder, PrivateKey, device::{DeviceDescContent, DeviceBodyContent}};
+
+    let mut device: DeviceObject =
+        ObjectBuilder::new(DeviceDescContent::with_device(DeviceObjectSubCode::OBJECT_TYPE_DEVICE_CORE as u8), DeviceBodyContent::default())
+            .update_desc(| desc | desc.set_public_key(PrivateKey::generate_rsa1024().unwrap().public()))
+            .build()
+            .unwrap();
+
+    let (id, code) = ObjectId::from_str_typed(&device.object_id().to_string()).unwrap();
+    assert!(&id == device.object_id());
+    assert_eq!(code.name(), "Device");
+    assert_eq!(code.sub_code_name().as_deref(), Some("core-service"));
+    device.verify_object_id().unwrap();
+
+    // the desc is changed
+    device.mut_desc().set_public_key(Some(PrivateKey::generate_rsa1024().unwrap().public()));
+    assert!(device.verify_object_id().is_err());
+
+    assert!(ObjectId::from_str("1234").is_err());
+    assert!(ObjectId::from_str_typed(&ObjectId::default().to_string()).is_err());
+}
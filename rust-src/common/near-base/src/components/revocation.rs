@@ -0,0 +1,264 @@
+
+use crate::{errors::*, now, ObjectId, DeviceObject, PrivateKey, Signature, Serialize, Deserialize, people::PeopleObject};
+
+/// The devices which are revoked by their owner, the newer sequence replaces the older list.
+/// It only revokes the devices whose owner is the issuer.
+#[derive(Clone, Default)]
+pub struct RevocationList {
+    issuer: ObjectId,
+    sequence: u64,
+    revoked: Vec<ObjectId>,
+    create_time: u64,
+}
+
+impl RevocationList {
+    pub fn new(issuer: ObjectId, sequence: u64) -> Self {
+        Self {
+            issuer,
+            sequence,
+            revoked: vec![],
+            create_time: now(),
+        }
+    }
+
+    pub fn revoke(mut self, device_id: ObjectId) -> Self {
+        if !self.revoked.contains(&device_id) {
+            self.revoked.push(device_id);
+        }
+        self
+    }
+
+    pub fn issuer(&self) -> &ObjectId {
+        &self.issuer
+    }
+
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    pub fn revoked(&self) -> &[ObjectId] {
+        self.revoked.as_slice()
+    }
+
+    pub fn create_time(&self) -> u64 {
+        self.create_time
+    }
+
+    /// The newer list of the same issuer replaces this one.
+    pub fn is_replaced_by(&self, other: &RevocationList) -> bool {
+        self.issuer == other.issuer && self.sequence < other.sequence
+    }
+
+    pub fn is_revoked(&self, device: &DeviceObject) -> bool {
+        device.desc().owner() == Some(&self.issuer) && self.revoked.contains(device.object_id())
+    }
+
+    fn signer_buffer(&self) -> NearResult<Vec<u8>> {
+        let mut buf = vec![0u8; self.raw_capacity()];
+        let _ = self.serialize(&mut buf)?;
+        Ok(buf)
+    }
+
+    /// Sign it with the private key of the issuer's people object.
+    pub fn sign(self, issuer: PeopleObject, private_key: &PrivateKey) -> NearResult<RevocationListObject> {
+        if issuer.object_id() != &self.issuer {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("{} isn't the issuer", issuer.object_id())));
+        }
+
+        let signature = private_key.sign(&self.signer_buffer()?)?;
+
+        Ok(RevocationListObject {
+            list: self,
+            issuer,
+            signature,
+        })
+    }
+}
+
+impl std::fmt::Display for RevocationList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "issuer: {}, sequence: {}, revoked: {}", self.issuer, self.sequence, self.revoked.len())
+    }
+}
+
+impl Serialize for RevocationList {
+    fn raw_capacity(&self) -> usize {
+        self.issuer.raw_capacity() +
+        self.sequence.raw_capacity() +
+        self.revoked.raw_capacity() +
+        self.create_time.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = self.issuer.serialize(buf)?;
+        let buf = self.sequence.serialize(buf)?;
+        let buf = self.revoked.serialize(buf)?;
+        let buf = self.create_time.serialize(buf)?;
+
+        Ok(buf)
+    }
+}
+
+impl Deserialize for RevocationList {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (issuer, buf) = ObjectId::deserialize(buf)?;
+        let (sequence, buf) = u64::deserialize(buf)?;
+        let (revoked, buf) = Vec::<ObjectId>::deserialize(buf)?;
+        let (create_time, buf) = u64::deserialize(buf)?;
+
+        Ok((Self {
+            issuer, sequence, revoked, create_time,
+        }, buf))
+    }
+}
+
+/// The revocation list with the people object of its issuer, so that anyone could verify it.
+#[derive(Clone)]
+pub struct RevocationListObject {
+    list: RevocationList,
+    issuer: PeopleObject,
+    signature: Signature,
+}
+
+impl RevocationListObject {
+    pub fn list(&self) -> &RevocationList {
+        &self.list
+    }
+
+    pub fn issuer(&self) -> &PeopleObject {
+        &self.issuer
+    }
+
+    pub fn verify(&self) -> NearResult<()> {
+        if self.issuer.object_id() != self.list.issuer() {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("{} isn't the issuer", self.issuer.object_id())));
+        }
+
+        let public_key =
+            self.issuer
+                .desc()
+                .public_key()
+                .ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_CRYPTO_INVALID_PUBKEY, format!("{} hasn't public key", self.issuer.object_id())))?;
+
+        public_key.verify(&self.list.signer_buffer()?, &self.signature)
+    }
+}
+
+impl std::fmt::Display for RevocationListObject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "list: {}, sign: {{***}}", self.list)
+    }
+}
+
+impl std::fmt::Debug for RevocationListObject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn std::fmt::Display).fmt(f)
+    }
+}
+
+impl Serialize for RevocationListObject {
+    fn raw_capacity(&self) -> usize {
+        self.list.raw_capacity() + self.issuer.raw_capacity() + self.signature.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = self.list.serialize(buf)?;
+        let buf = self.issuer.serialize(buf)?;
+        let buf = self.signature.serialize(buf)?;
+
+        Ok(buf)
+    }
+}
+
+impl Deserialize for RevocationListObject {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (list, buf) = RevocationList::deserialize(buf)?;
+        let (issuer, buf) = PeopleObject::deserialize(buf)?;
+        let (signature, buf) = Signature::deserialize(buf)?;
+
+        Ok((Self { list, issuer, signature }, buf))
+    }
+}
+
+/// The device is refused if it's expired, or it's revoked by its owner.
+pub fn check_device(device: &DeviceObject, revocation: Option<&RevocationList>) -> NearResult<()> {
+    if let Some(expired_time) = device.desc().expired_time() {
+        if expired_time <= now() {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_EXPIRED, format!("{} is expired", device.object_id())));
+        }
+    }
+
+    if revocation.map(| list | list.is_revoked(device)).unwrap_or(false) {
+        return Err(NearError::new(ErrorCode::NEAR_ERROR_REVOKED, format!("{} is revoked", device.object_id())));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_revocation_list() {
+    use crate::{ObjectBuilder, device::{DeviceDescContent, DeviceBodyContent}, people::{PeopleDescContent, PeopleBodyContent}};
+
+    let people = | private_key: &PrivateKey | -> PeopleObject {
+        ObjectBuilder::new(PeopleDescContent::new(), PeopleBodyContent::default())
+            .update_desc(| desc | desc.set_public_key(private_key.public()))
+            .build()
+            .unwrap()
+    };
+    let device = | owner: &PeopleObject, expired_time: Option<u64> | -> DeviceObject {
+        ObjectBuilder::new(DeviceDescContent::with_device(1), DeviceBodyContent::default())
+            .update_desc(| desc | {
+                desc.set_public_key(PrivateKey::generate_rsa1024().unwrap().public());
+                desc.set_owner(Some(owner.object_id().clone()));
+                desc.set_expired_time(expired_time);
+            })
+            .build()
+            .unwrap()
+    };
+
+    let owner_key = PrivateKey::generate_rsa1024().unwrap();
+    let owner = people(&owner_key);
+    let other_key = PrivateKey::generate_rsa1024().unwrap();
+    let other = people(&other_key);
+
+    let revoked = device(&owner, None);
+    let kept = device(&owner, None);
+    let others = device(&other, None);
+
+    let list =
+        RevocationList::new(owner.object_id().clone(), 1)
+            .revoke(revoked.object_id().clone())
+            .revoke(revoked.object_id().clone())
+            .revoke(others.object_id().clone());
+    assert_eq!(list.revoked().len(), 2);
+
+    let signed = list.clone().sign(owner.clone(), &owner_key).unwrap();
+    signed.verify().unwrap();
+
+    let mut buf = vec![0u8; signed.raw_capacity()];
+    let _ = signed.serialize(&mut buf).unwrap();
+    let (decoded, _) = RevocationListObject::deserialize(&buf).unwrap();
+    decoded.verify().unwrap();
+    assert_eq!(decoded.list().sequence(), 1);
+
+    // it's signed by the other people in the name of the owner.
+    assert!(list.clone().sign(other.clone(), &other_key).is_err());
+    assert!(RevocationListObject { list: list.clone(), issuer: owner.clone(), signature: list.clone().sign(owner.clone(), &other_key).unwrap().signature }.verify().is_err());
+
+    // only the devices of the issuer are revoked.
+    assert!(list.is_revoked(&revoked));
+    assert!(!list.is_revoked(&kept));
+    assert!(!list.is_revoked(&others));
+
+    assert!(check_device(&kept, Some(&list)).is_ok());
+    assert!(check_device(&revoked, Some(&list)).unwrap_err().errno() == ErrorCode::NEAR_ERROR_REVOKED);
+    assert!(check_device(&revoked, None).is_ok());
+    assert!(check_device(&device(&owner, Some(now() - 1)), None).unwrap_err().errno() == ErrorCode::NEAR_ERROR_EXPIRED);
+
+    // the newer sequence of the same issuer replaces it.
+    assert!(list.is_replaced_by(&RevocationList::new(owner.object_id().clone(), 2)));
+    assert!(!list.is_replaced_by(&RevocationList::new(owner.object_id().clone(), 1)));
+    assert!(!list.is_replaced_by(&RevocationList::new(other.object_id().clone(), 2)));
+}
@@ -0,0 +1,242 @@
+
+use std::{io::Read, path::Path};
+
+use aes_gcm::{aead::{Aead, Payload}, Aes256Gcm, KeyInit, Nonce};
+use rand::random;
+
+use crate::{Deserialize, ErrorCode, FileEncoder, NearError, NearResult, Serialize};
+
+use super::private_key::PrivateKey;
+
+// "NKEY", the plain private key starts with its key type, so they can't be confused.
+const KEY_FILE_MAGIC: u32 = 0x4e4b4559;
+
+const KDF_SCRYPT: u8 = 1u8;
+
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+const DERIVED_KEY_LENGTH: usize = 32;
+
+fn random_bytes(len: usize) -> Vec<u8> {
+    (0..len).map(| _ | random::<u8>()).collect()
+}
+
+/// The passphrase is stretched by the KDF into the AEAD key.
+#[derive(Clone)]
+pub enum KeyDerivation {
+    Scrypt {
+        log_n: u8,
+        r: u32,
+        p: u32,
+        salt: Vec<u8>,
+    },
+}
+
+impl KeyDerivation {
+    fn scrypt() -> Self {
+        Self::Scrypt {
+            log_n: SCRYPT_LOG_N,
+            r: SCRYPT_R,
+            p: SCRYPT_P,
+            salt: random_bytes(SALT_LENGTH),
+        }
+    }
+
+    fn derive(&self, passphrase: &str) -> NearResult<[u8; DERIVED_KEY_LENGTH]> {
+        match self {
+            Self::Scrypt { log_n, r, p, salt } => {
+                let params =
+                    scrypt::Params::new(*log_n, *r, *p, DERIVED_KEY_LENGTH)
+                        .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("invalid scrypt params with err: {e}")))?;
+
+                let mut key = [0u8; DERIVED_KEY_LENGTH];
+                scrypt::scrypt(passphrase.as_bytes(), salt, &params, &mut key)
+                    .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_3RD, format!("failed scrypt with err: {e}")))?;
+
+                Ok(key)
+            }
+        }
+    }
+}
+
+impl Serialize for KeyDerivation {
+    fn raw_capacity(&self) -> usize {
+        match self {
+            Self::Scrypt { log_n, r, p, salt } => {
+                KDF_SCRYPT.raw_capacity() + log_n.raw_capacity() + r.raw_capacity() + p.raw_capacity() + salt.raw_capacity()
+            }
+        }
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        match self {
+            Self::Scrypt { log_n, r, p, salt } => {
+                let buf = KDF_SCRYPT.serialize(buf)?;
+                let buf = log_n.serialize(buf)?;
+                let buf = r.serialize(buf)?;
+                let buf = p.serialize(buf)?;
+                let buf = salt.serialize(buf)?;
+
+                Ok(buf)
+            }
+        }
+    }
+}
+
+impl Deserialize for KeyDerivation {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (kdf, buf) = u8::deserialize(buf)?;
+
+        match kdf {
+            KDF_SCRYPT => {
+                let (log_n, buf) = u8::deserialize(buf)?;
+                let (r, buf) = u32::deserialize(buf)?;
+                let (p, buf) = u32::deserialize(buf)?;
+                let (salt, buf) = Vec::<u8>::deserialize(buf)?;
+
+                Ok((Self::Scrypt { log_n, r, p, salt }, buf))
+            }
+            _ => Err(NearError::new(ErrorCode::NEAR_ERROR_UNKNOWN_PROTOCOL, format!("unknown kdf: {kdf}")))
+        }
+    }
+}
+
+/// The private key which is sealed by AES-256-GCM with the passphrase,
+/// the KDF params are authenticated as the associated data.
+#[derive(Clone)]
+pub struct EncryptedPrivateKey {
+    kdf: KeyDerivation,
+    nonce: Vec<u8>,
+    cipher: Vec<u8>,
+}
+
+impl EncryptedPrivateKey {
+    pub fn encrypt(private_key: &PrivateKey, passphrase: &str) -> NearResult<Self> {
+        let kdf = KeyDerivation::scrypt();
+        let nonce = random_bytes(NONCE_LENGTH);
+
+        let plain = {
+            let mut buf = vec![0u8; private_key.raw_capacity()];
+            let _ = private_key.serialize(&mut buf)?;
+            buf
+        };
+
+        let aad = Self::associated_data(&kdf)?;
+        let cipher =
+            Aes256Gcm::new_from_slice(&kdf.derive(passphrase)?)
+                .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_CRYPTO_ENCRYPT, format!("invalid key with err: {e}")))?
+                .encrypt(Nonce::from_slice(&nonce), Payload { msg: &plain, aad: &aad })
+                .map_err(| _ | NearError::new(ErrorCode::NEAR_ERROR_CRYPTO_ENCRYPT, "failed seal private key"))?;
+
+        Ok(Self { kdf, nonce, cipher })
+    }
+
+    pub fn decrypt(&self, passphrase: &str) -> NearResult<PrivateKey> {
+        if self.nonce.len() != NONCE_LENGTH {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, "invalid nonce"));
+        }
+
+        let aad = Self::associated_data(&self.kdf)?;
+        let plain =
+            Aes256Gcm::new_from_slice(&self.kdf.derive(passphrase)?)
+                .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_CRYPTO_DECRYPT, format!("invalid key with err: {e}")))?
+                .decrypt(Nonce::from_slice(&self.nonce), Payload { msg: &self.cipher, aad: &aad })
+                .map_err(| _ | NearError::new(ErrorCode::NEAR_ERROR_CRYPTO_DECRYPT, "wrong passphrase or the key file is damaged"))?;
+
+        let (private_key, _) = PrivateKey::deserialize(&plain)?;
+
+        Ok(private_key)
+    }
+
+    fn associated_data(kdf: &KeyDerivation) -> NearResult<Vec<u8>> {
+        let mut buf = vec![0u8; KEY_FILE_MAGIC.raw_capacity() + kdf.raw_capacity()];
+        let remain = KEY_FILE_MAGIC.serialize(&mut buf)?;
+        let _ = kdf.serialize(remain)?;
+        Ok(buf)
+    }
+}
+
+impl Serialize for EncryptedPrivateKey {
+    fn raw_capacity(&self) -> usize {
+        KEY_FILE_MAGIC.raw_capacity() + self.kdf.raw_capacity() + self.nonce.raw_capacity() + self.cipher.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = KEY_FILE_MAGIC.serialize(buf)?;
+        let buf = self.kdf.serialize(buf)?;
+        let buf = self.nonce.serialize(buf)?;
+        let buf = self.cipher.serialize(buf)?;
+
+        Ok(buf)
+    }
+}
+
+impl Deserialize for EncryptedPrivateKey {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (magic, buf) = u32::deserialize(buf)?;
+        if magic != KEY_FILE_MAGIC {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, "it isn't an encrypted key file"));
+        }
+
+        let (kdf, buf) = KeyDerivation::deserialize(buf)?;
+        let (nonce, buf) = Vec::<u8>::deserialize(buf)?;
+        let (cipher, buf) = Vec::<u8>::deserialize(buf)?;
+
+        Ok((Self { kdf, nonce, cipher }, buf))
+    }
+}
+
+/// Load the private key file, both of the plain and the encrypted are supported,
+/// the passphrase is required by the encrypted.
+pub fn load_private_key(file: &Path, passphrase: Option<&str>) -> NearResult<PrivateKey> {
+    let data = {
+        let mut data = vec![];
+        std::fs::File::open(file)
+            .and_then(| mut f | f.read_to_end(&mut data))
+            .map_err(NearError::from)?;
+        data
+    };
+
+    match EncryptedPrivateKey::deserialize(&data) {
+        Ok((encrypted, _)) => {
+            let passphrase =
+                passphrase.ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_CRYPTO_DECRYPT, format!("{} is encrypted, missing passphrase", file.display())))?;
+            encrypted.decrypt(passphrase)
+        }
+        Err(_) => {
+            let (private_key, _) = PrivateKey::deserialize(&data)?;
+            Ok(private_key)
+        }
+    }
+}
+
+/// Save the private key, it's encrypted if the passphrase is set.
+pub fn save_private_key(file: &Path, private_key: &PrivateKey, passphrase: Option<&str>) -> NearResult<()> {
+    let _ = 
+        match passphrase {
+            Some(passphrase) => EncryptedPrivateKey::encrypt(private_key, passphrase)?.encode_to_file(file, false),
+            None => private_key.encode_to_file(file, false),
+        }?;
+
+    Ok(())
+}
+
+#[test]
+fn test_encrypted_private_key() {
+    let private_key = PrivateKey::generate_rsa1024().unwrap();
+
+    let encrypted = EncryptedPrivateKey::encrypt(&private_key, "near").unwrap();
+
+    let mut buf = vec![0u8; encrypted.raw_capacity()];
+    let _ = encrypted.serialize(&mut buf).unwrap();
+    let (encrypted, _) = EncryptedPrivateKey::deserialize(&buf).unwrap();
+
+    assert!(encrypted.decrypt("near").unwrap().public() == private_key.public());
+    assert!(encrypted.decrypt("wrong").is_err());
+}
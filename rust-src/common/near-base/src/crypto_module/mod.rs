@@ -4,11 +4,15 @@ pub mod aes_key;
 pub mod private_key;
 pub mod public_key;
 pub mod signature;
+pub mod key_file;
+pub mod secret_store;
 
 pub use aes_key::AesKey;
 pub use private_key::PrivateKey;
 pub use public_key::PublicKey;
 pub use signature::Signature;
+pub use key_file::{EncryptedPrivateKey, load_private_key, save_private_key};
+pub use secret_store::{SecretStoreTrait, FileKeyring};
 
 use async_trait::async_trait;
 
@@ -13,7 +13,7 @@ const RSA2048_PUBLIC_LENGHT_DEFAULT_MAX: usize = 294;
 const RSA3072_PUBLIC_CODE: u8 = 3;
 const RSA3072_PUBLIC_LENGHT_DEFAULT_MAX: usize = 422;
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 pub enum PublicKey {
     Rsa(RSAPublicKey),
 }
@@ -0,0 +1,42 @@
+
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+
+use crate::NearResult;
+
+use super::{private_key::PrivateKey, key_file::{load_private_key, save_private_key}};
+
+/// Where the private keys are kept, so that a service could fetch its key from an agent
+/// or a keyring instead of the plain key file.
+#[async_trait]
+pub trait SecretStoreTrait: Send + Sync {
+    async fn load_key(&self, name: &str) -> NearResult<PrivateKey>;
+    async fn store_key(&self, name: &str, private_key: &PrivateKey) -> NearResult<()>;
+}
+
+/// The key files in a directory, they are encrypted when the passphrase is set.
+pub struct FileKeyring {
+    root: PathBuf,
+    passphrase: Option<String>,
+}
+
+impl FileKeyring {
+    pub fn new(root: PathBuf, passphrase: Option<String>) -> Self {
+        Self {
+            root,
+            passphrase,
+        }
+    }
+}
+
+#[async_trait]
+impl SecretStoreTrait for FileKeyring {
+    async fn load_key(&self, name: &str) -> NearResult<PrivateKey> {
+        load_private_key(self.root.join(name).as_path(), self.passphrase.as_deref())
+    }
+
+    async fn store_key(&self, name: &str, private_key: &PrivateKey) -> NearResult<()> {
+        save_private_key(self.root.join(name).as_path(), private_key, self.passphrase.as_deref())
+    }
+}
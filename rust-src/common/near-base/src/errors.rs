@@ -59,6 +59,7 @@ pub enum ErrorCode {
     NEAR_ERROR_CRYPTO_VERIFY              = 45,
     NEAR_ERROR_CRYPTO_SIGNDATA_OUTOFLIMIT = 46,
     NEAR_ERROR_CRYPTO_INVALID_PUBKEY      = 47,
+    NEAR_ERROR_REVOKED                    = 48,
 
     NEAR_ERROR_CRYPTO_AEK_ENCRYPT         = 51,
     NEAR_ERROR_CRYPTO_AEK_DECRYPT         = 52,
@@ -150,6 +151,7 @@ impl ErrorCode {
             ErrorCode::NEAR_ERROR_CRYPTO_VERIFY             	=> 45,
             ErrorCode::NEAR_ERROR_CRYPTO_SIGNDATA_OUTOFLIMIT	=> 46,
             ErrorCode::NEAR_ERROR_CRYPTO_INVALID_PUBKEY     	=> 47,
+            ErrorCode::NEAR_ERROR_REVOKED                       => 48,
             ErrorCode::NEAR_ERROR_CRYPTO_AEK_ENCRYPT        	=> 51,
             ErrorCode::NEAR_ERROR_CRYPTO_AEK_DECRYPT        	=> 52,
             ErrorCode::NEAR_ERROR_TOPIC_EXCEPTION           	=> 61,
@@ -224,6 +226,7 @@ impl From<u16> for ErrorCode {
             45      => ErrorCode::NEAR_ERROR_CRYPTO_VERIFY,
             46      => ErrorCode::NEAR_ERROR_CRYPTO_SIGNDATA_OUTOFLIMIT,
             47      => ErrorCode::NEAR_ERROR_CRYPTO_INVALID_PUBKEY,
+            48      => ErrorCode::NEAR_ERROR_REVOKED,
             51      => ErrorCode::NEAR_ERROR_CRYPTO_AEK_ENCRYPT,
             52      => ErrorCode::NEAR_ERROR_CRYPTO_AEK_DECRYPT,
             61      => ErrorCode::NEAR_ERROR_TOPIC_EXCEPTION,
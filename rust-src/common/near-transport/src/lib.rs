@@ -17,6 +17,7 @@ pub use process::{PackageEventTrait, ProcessTrait, RoutineEventTrait, ItfTrait,
                   EventResult, ResponseEvent, TransferEvent,
         };
 pub use network::MTU as PayloadMaxLen;
+pub use tunnel::SendPriority;
 
 use std::time::Duration;
 
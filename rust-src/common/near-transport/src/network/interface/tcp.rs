@@ -220,6 +220,12 @@ impl Interface {
 }
 
 impl Interface {
+    /// Shut the socket down, so the receiving loop ends and the tunnel is closed.
+    pub(crate) fn shutdown(&self) {
+        trace!("{} will shutdown", self);
+        let _ = self.0.socket.shutdown(std::net::Shutdown::Both);
+    }
+
     pub(in super::super) async fn recv_package<R>(&self, decoder: impl PackageDecodeTrait<R=R>) -> NearResult<R> {
         decoder.package_decode(self.socket()).await
     }
@@ -47,6 +47,12 @@ impl PackageDataSet {
         self.0.dataset.len()
     }
 
+    /// The topic of the message, all of the dataset have the same one.
+    pub fn topic(&self) -> Option<&String> {
+        self.0.dataset.first()
+            .and_then(| (data_context, _) | data_context.head_ext.topic())
+    }
+
     pub fn dataset(&self, index: usize) -> Option<&Data> {
         self.0.dataset.get(index)
             .map(| (_, data) | data)
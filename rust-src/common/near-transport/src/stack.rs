@@ -1,5 +1,6 @@
 use log::{debug, error, info, trace, warn};
 use std::{
+    collections::HashMap,
     net::{Ipv4Addr, Ipv6Addr, SocketAddr},
     sync::{Arc, RwLock},
 };
@@ -18,7 +19,7 @@ use crate::process::{
 use crate::coturn::stun::c::ping::PingManager;
 use crate::coturn::stun::s::peer_manager::PeerManager;
 use crate::stack_tunnel_event::{RuntimeTunnelEvent, ServiceTunnelEvent};
-use crate::tunnel::{tunnel::State, TunnelEventTrait};
+use crate::tunnel::{tunnel::State, TunnelEventTrait, SendPriority};
 use crate::{
     h::OnBuildPackage,
     network::{DataContext, NetManager, MTU},
@@ -87,6 +88,8 @@ struct StackImpl {
     config: StackConfig,
     // aes key
     aes_key: RwLock<AesKey>,
+    // the revocation lists of the owners
+    revocations: RwLock<HashMap<ObjectId, RevocationListObject>>,
 
     components: Option<StackComponents>,
 
@@ -173,6 +176,7 @@ impl Stack {
             local_random: Sequence::random(),
             config: params.config.unwrap_or(StackConfig::new()),
             aes_key: RwLock::new(AesKey::generate()),
+            revocations: RwLock::new(HashMap::new()),
             components: None,
             events: StackEvents {
                 process_impl: process_impl,
@@ -294,6 +298,7 @@ impl Stack {
             local_random: Sequence::random(),
             config: params.config.unwrap_or(StackConfig::new()),
             aes_key: RwLock::new(AesKey::generate()),
+            revocations: RwLock::new(HashMap::new()),
             components: None,
             events: StackEvents {
                 process_impl: process_impl,
@@ -392,6 +397,7 @@ impl Stack {
             local_random: Sequence::random(),
             config: params.config.unwrap_or(StackConfig::new()),
             aes_key: RwLock::new(AesKey::generate()),
+            revocations: RwLock::new(HashMap::new()),
             components: None,
             events: StackEvents {
                 process_impl: process_impl,
@@ -477,6 +483,64 @@ impl Stack {
         self.stun_client().remove_sn(remote_id).await
     }
 
+    /// Tear all of the tunnels of the remote down, it must exchange again before it sends any message.
+    pub fn close_tunnels(&self, remote: &ObjectId) -> bool {
+        self.tunnel_manager().remove_container(remote)
+    }
+
+    /// Accept the newer revocation list of the owner, and the tunnels of the revoked devices are closed.
+    pub fn update_revocation_list(&self, revocation: RevocationListObject) -> NearResult<()> {
+        revocation.verify()?;
+
+        let list = revocation.list();
+
+        {
+            let revocations = &mut *self.0.revocations.write().unwrap();
+
+            if let Some(exist) = revocations.get(list.issuer()) {
+                if !exist.list().is_replaced_by(list) {
+                    return Err(NearError::new(ErrorCode::NEAR_ERROR_EXPIRED, format!("{} has newer revocation list", list.issuer())));
+                }
+            }
+
+            info!("update revocation list: {}", revocation);
+            revocations.insert(list.issuer().clone(), revocation.clone());
+        }
+
+        for device_id in list.revoked() {
+            if self.close_tunnels(device_id) {
+                info!("closed the tunnels of revoked {device_id}");
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn revocation_list(&self, owner: &ObjectId) -> Option<RevocationListObject> {
+        self.0.revocations.read().unwrap().get(owner).cloned()
+    }
+
+    pub fn revocation_lists(&self) -> Vec<RevocationListObject> {
+        self.0.revocations.read().unwrap().values().cloned().collect()
+    }
+
+    /// The device is refused with NEAR_ERROR_EXPIRED or NEAR_ERROR_REVOKED.
+    pub fn check_device(&self, device: &DeviceObject) -> NearResult<()> {
+        let revocations = self.0.revocations.read().unwrap();
+
+        check_device(device,
+                     device.desc()
+                        .owner()
+                        .and_then(| owner | revocations.get(owner))
+                        .map(| revocation | revocation.list()))
+    }
+
+    /// The messages of the topic and its sub topics are sent with the priority,
+    /// the others are interactive.
+    pub fn set_topic_priority(&self, topic: &Topic, priority: SendPriority) {
+        self.tunnel_manager().set_topic_priority(topic.topic(), priority)
+    }
+
     pub fn is_core(&self) -> bool {
         match &self.0.local {
             StackDevice::CoreService(_) | StackDevice::CoturnMiner(_) => true,
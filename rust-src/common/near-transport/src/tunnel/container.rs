@@ -20,6 +20,7 @@ use crate::{
 };
 
 use super::{message::Message, PostMessageTrait, TunnelManager};
+use super::priority::PriorityGate;
 use super::p::{OnRecvMessageCallback, OnSendMessageCallback};
 use super::tunnel::{DynamicTunnel, State, TunnelStateTrait};
 use super::{
@@ -293,6 +294,8 @@ struct TunnelContainerImpl {
     state: RwLock<TunnelContainerState>,
     recyle_state: RwLock<RecyleState>,
     tunnel_message: Option<TunnelMessages>,
+    // the bulk messages yield to the interactive ones.
+    gate: PriorityGate,
 }
 
 #[derive(Clone)]
@@ -312,6 +315,7 @@ impl TunnelContainer {
             }),
             recyle_state: RwLock::new(Default::default()),
             tunnel_message: None,
+            gate: PriorityGate::new(),
         }));
 
         let tunnel_message = TunnelMessages::new(tunnel.clone());
@@ -400,6 +404,22 @@ impl TunnelContainer {
         }
     }
 
+    /// Close all of the tunnels, and they won't be reconnected.
+    pub(super) fn shutdown(&self) {
+        let tunnels = std::mem::take(&mut self.0.state.write().unwrap().tunnel_entries);
+
+        self.0.recyle_state.write().unwrap().endpoints.clear();
+
+        for tunnel in tunnels.values() {
+            info!("{tunnel} will shutdown.");
+            if tunnel.local().is_tcp() {
+                AsRef::<TcpTunnel>::as_ref(tunnel).shutdown();
+            } else if tunnel.local().is_udp() {
+                AsRef::<UdpTunnel>::as_ref(tunnel).shutdown();
+            }
+        }
+    }
+
     pub fn close_tunnel(&self, tunnel: DynamicTunnel) {
         let need_recyle = 
             if let Some(remove_tunnel) = 
@@ -493,6 +513,19 @@ impl TunnelContainer {
                     std::mem::replace(&mut body.from_device, any::AnyNamedObject::None)
                 };
 
+                match &from_device {
+                    any::AnyNamedObject::Device(o) |
+                    any::AnyNamedObject::Service(o) => {
+                        self.as_stack()
+                            .check_device(o)
+                            .map_err(| e | {
+                                warn!("refuse {} exchange with err: {e}", tunnel);
+                                e
+                            })?;
+                    }
+                    _ => { /* ignore */ }
+                }
+
                 match {
                     if tunnel.local().is_tcp() {
                         tunnel.clone_as_tunnel::<TcpTunnel>().on_tunnel_event(head, head_ext, body)
@@ -1182,6 +1215,9 @@ impl PostMessageTrait<(SequenceString, PackageDataSet)> for TunnelContainer {
     ) -> NearResult<Self::R> {
         let (sequence, package) = context;
 
+        let priority = self.0.manager.priority_of(&package);
+        let _guard = self.0.gate.enter(priority).await;
+
         let tunnel = 
             self.wait_and_take_tunnel()
                 .await
@@ -6,7 +6,8 @@ use near_base::{sequence::SequenceString, *};
 
 use crate::{h::OnTimeTrait, network::{DataContext, TcpInterface, TcpPackageEventTrait, UdpInterface, UdpPackageEventTrait 
             }, package::{MajorCommand, PackageDataSet }, Stack };
-use super::{container::{TunnelContainer, TunnelGuard}, tunnel::State, DynamicTunnel, PostMessageTrait };
+use super::{container::{TunnelContainer, TunnelGuard}, tunnel::State, DynamicTunnel, PostMessageTrait, 
+            priority::{PriorityTable, SendPriority}, };
 use super::tcp::Tunnel as TcpTunnel;
 use super::udp::Tunnel as UdpTunnel;
 
@@ -54,6 +55,7 @@ struct ManagerImpl {
     entries: RwLock<BTreeMap<ObjectId, TunnelGuard>>,
     resender_queue: RwLock<BTreeMap<ObjectId, TunnelContainer>>,
     recyle_queue: RwLock<BTreeMap<ObjectId, TunnelContainer>>,
+    priorities: PriorityTable,
 }
 
 #[derive(Clone)]
@@ -67,6 +69,7 @@ impl Manager {
                 entries: RwLock::new(BTreeMap::new()),
                 resender_queue: RwLock::new(Default::default()),
                 recyle_queue: RwLock::new(Default::default()),
+                priorities: PriorityTable::default(),
             }));
 
         Ok(manager)
@@ -77,6 +80,15 @@ impl Manager {
         &self.0.stack
     }
 
+    /// The messages whose topic starts with the prefix are sent with the priority.
+    pub fn set_topic_priority(&self, prefix: &str, priority: SendPriority) {
+        self.0.priorities.set(prefix, priority)
+    }
+
+    pub(super) fn priority_of(&self, package: &PackageDataSet) -> SendPriority {
+        self.0.priorities.priority_of(package.topic())
+    }
+
     pub fn container_of(&self, id: &ObjectId) -> Option<TunnelGuard> {
         self.0.entries.read().unwrap()
             .get(&id)
@@ -115,6 +127,22 @@ impl Manager {
         }
     }
 
+    /// Remove the container of the remote and shut all of its tunnels down.
+    pub fn remove_container(&self, remote: &ObjectId) -> bool {
+        let container = self.0.entries.write().unwrap().remove(remote);
+
+        self.remove_resender(remote);
+        self.remove_recyle(remote);
+
+        match container {
+            Some(container) => {
+                container.shutdown();
+                true
+            }
+            None => false,
+        }
+    }
+
     #[allow(unused)]
     pub fn close_tunnel(&self, tunnel: DynamicTunnel) {
         if let Some(guard) = self.container_of(tunnel.peer_id()) {
@@ -8,9 +8,11 @@ pub mod udp;
 
 mod message;
 mod p;
+mod priority;
 
 pub use manager::{Manager as TunnelManager, Config as TunnelManagerConfig, };
 pub use container::Config as TunnelContainerConfig;
+pub use priority::SendPriority;
 use near_base::{ObjectId, Endpoint, NearResult};
 pub use tunnel::{TunnelStateTrait, DynamicTunnel};
 pub use tcp::Config as TcpConfig;
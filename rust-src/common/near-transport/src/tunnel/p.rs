@@ -29,15 +29,18 @@ pub(crate) struct TunnelVerifier {
 
 impl TunnelVerifier {
     pub async fn new(stack: &Stack, remote_id: &ObjectId) -> NearResult<Self> {
+        let remote = 
+            stack.cacher_manager()
+                .get(&remote_id)
+                .await
+                .ok_or_else(|| {
+                    NearError::new(ErrorCode::NEAR_ERROR_NOTFOUND, format!("not found {}", remote_id))
+                })?;
+
+        stack.check_device(&remote)?;
+
         Ok(Self {
-            remote: {
-                stack.cacher_manager()
-                    .get(&remote_id)
-                    .await
-                    .ok_or_else(|| {
-                        NearError::new(ErrorCode::NEAR_ERROR_NOTFOUND, format!("not found {}", remote_id))
-                    })?
-            },
+            remote,
         })
     }
 }
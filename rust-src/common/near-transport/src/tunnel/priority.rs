@@ -0,0 +1,138 @@
+
+use std::sync::{Mutex, RwLock};
+
+use near_base::StateWaiter;
+
+/// The priority class of the message sent by the tunnel container.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SendPriority {
+    /// the control messages, they are sent at once.
+    Interactive,
+    /// the large transfers, they yield to the interactive messages which are being sent.
+    Bulk,
+}
+
+impl std::default::Default for SendPriority {
+    fn default() -> Self {
+        Self::Interactive
+    }
+}
+
+impl std::fmt::Display for SendPriority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Interactive => write!(f, "interactive"),
+            Self::Bulk => write!(f, "bulk"),
+        }
+    }
+}
+
+/// The priority of the topics, the longest prefix of the topic is taken.
+/// The topic which doesn't match any prefix is interactive.
+#[derive(Default)]
+pub(crate) struct PriorityTable(RwLock<Vec<(String, SendPriority)>>);
+
+impl PriorityTable {
+    pub(crate) fn set(&self, prefix: &str, priority: SendPriority) {
+        let table = &mut *self.0.write().unwrap();
+
+        match table.iter_mut().find(| (p, _) | p == prefix) {
+            Some((_, v)) => { *v = priority; }
+            None => { table.push((prefix.to_owned(), priority)); }
+        }
+    }
+
+    pub(crate) fn priority_of(&self, topic: Option<&String>) -> SendPriority {
+        let topic = match topic {
+            Some(topic) => topic,
+            None => { return SendPriority::Interactive; }
+        };
+
+        self.0.read().unwrap()
+            .iter()
+            .filter(| (prefix, _) | topic.starts_with(prefix.as_str()))
+            .max_by_key(| (prefix, _) | prefix.len())
+            .map(| (_, priority) | *priority)
+            .unwrap_or_default()
+    }
+}
+
+struct GateState {
+    interactive: usize,
+    waiter: StateWaiter,
+}
+
+/// The bulk messages of the container wait until none of the interactive ones is being sent.
+pub(crate) struct PriorityGate(Mutex<GateState>);
+
+impl PriorityGate {
+    pub(crate) fn new() -> Self {
+        Self(Mutex::new(GateState {
+            interactive: 0,
+            waiter: StateWaiter::new(),
+        }))
+    }
+
+    pub(crate) async fn enter(&self, priority: SendPriority) -> PriorityGuard<'_> {
+        match priority {
+            SendPriority::Interactive => {
+                self.0.lock().unwrap().interactive += 1;
+            }
+            SendPriority::Bulk => {
+                loop {
+                    let waiter = {
+                        let state = &mut *self.0.lock().unwrap();
+                        if state.interactive == 0 {
+                            break;
+                        }
+                        state.waiter.new_waiter()
+                    };
+
+                    StateWaiter::wait(waiter, || ()).await;
+                }
+            }
+        }
+
+        PriorityGuard { gate: self, priority }
+    }
+
+    fn leave(&self, priority: SendPriority) {
+        if priority != SendPriority::Interactive {
+            return;
+        }
+
+        let waker = {
+            let state = &mut *self.0.lock().unwrap();
+            state.interactive -= 1;
+            if state.interactive > 0 {
+                return;
+            }
+            state.waiter.transfer()
+        };
+
+        waker.wake();
+    }
+}
+
+pub(crate) struct PriorityGuard<'a> {
+    gate: &'a PriorityGate,
+    priority: SendPriority,
+}
+
+impl Drop for PriorityGuard<'_> {
+    fn drop(&mut self) {
+        self.gate.leave(self.priority);
+    }
+}
+
+#[test]
+fn test_priority_of() {
+    let table = PriorityTable::default();
+    table.set("/nds", SendPriority::Bulk);
+    table.set("/nds/control", SendPriority::Interactive);
+
+    assert_eq!(table.priority_of(Some(&"/nds/piece".to_owned())), SendPriority::Bulk);
+    assert_eq!(table.priority_of(Some(&"/nds/control".to_owned())), SendPriority::Interactive);
+    assert_eq!(table.priority_of(Some(&"/thing/query".to_owned())), SendPriority::Interactive);
+    assert_eq!(table.priority_of(None), SendPriority::Interactive);
+}
@@ -104,6 +104,10 @@ impl Tunnel {
         }))
     }
 
+    pub(super) fn shutdown(&self) {
+        self.0.interface.shutdown()
+    }
+
     pub(super) fn active(&self, remote: &DeviceObject) {
         trace!("active: remote: {remote}");
 
@@ -1,4 +1,4 @@
-use std::{sync::Arc, time::Duration};
+use std::{sync::{Arc, atomic::{AtomicBool, Ordering}}, time::Duration};
 
 use crossbeam::epoch::{self as epoch, Atomic, Owned};
 
@@ -70,6 +70,8 @@ struct TunnelImpl {
     // last_active: AtomicU64,
     // retain_connect_timestamp: AtomicU64,
     connect_state: TunnelStateGuard,
+    // the udp socket is shared by the tunnels, so the closed tunnel is only marked.
+    closed: AtomicBool,
 }
 
 #[derive(Clone)]
@@ -103,12 +105,29 @@ impl Tunnel {
             local_remote: ep_pair,
             interface: interface.clone(),
             tunnel_sync_data: Atomic::new(None),
+            closed: AtomicBool::new(false),
         }))
     }
 
+    /// The tunnel won't be actived and send any more, the remote has to exchange again.
+    pub(super) fn shutdown(&self) {
+        trace!("{} will shutdown", self);
+        self.0.closed.store(true, Ordering::SeqCst);
+    }
+
+    #[inline]
+    fn is_closed(&self) -> bool {
+        self.0.closed.load(Ordering::SeqCst)
+    }
+
     pub(super) fn active(&self, remote: &DeviceObject) {
         trace!("active: remote: {}", remote.object_id());
 
+        if self.is_closed() {
+            debug!("{} was closed, it won't be actived.", self);
+            return;
+        }
+
         match self.0.connect_state.active(remote.object_id()) {
             Ok((sequence, builder)) => {
                 let arc_self = self.clone();
@@ -297,6 +316,10 @@ impl Tunnel {
         package: PackageDataSet
     ) -> NearResult<()> {
 
+        if self.is_closed() {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_TUNNEL_CLOSED, format!("{} was closed.", self)));
+        }
+
         for i in 0..package.dataset_count() {
             if let Some(data) = package.dataset(i) {
                 self.0.interface.send_data_to(data.as_ref(), self.remote()).await?;
@@ -0,0 +1,145 @@
+
+use near_base::{CHUNK_MAX_LEN, NearResult, NearError, ErrorCode, };
+
+/// How the file is split into chunks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Chunking {
+    /// every chunk is CHUNK_MAX_LEN except the last one.
+    Fixed,
+    /// the boundaries are picked by the content (FastCDC), so an insertion only changes the chunks around it.
+    ContentDefined(CdcParams),
+}
+
+impl std::default::Default for Chunking {
+    fn default() -> Self {
+        Self::Fixed
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CdcParams {
+    pub min_len: usize,
+    pub avg_len: usize,
+    pub max_len: usize,
+}
+
+impl std::default::Default for CdcParams {
+    fn default() -> Self {
+        Self {
+            min_len: 128 * 1024,
+            avg_len: 512 * 1024,
+            max_len: CHUNK_MAX_LEN as usize,
+        }
+    }
+}
+
+impl CdcParams {
+    pub fn check(&self) -> NearResult<()> {
+        if self.min_len == 0 ||
+           self.min_len > self.avg_len ||
+           self.avg_len > self.max_len ||
+           self.max_len > CHUNK_MAX_LEN as usize ||
+           !self.avg_len.is_power_of_two() {
+            Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("invalid cdc params {:?}", self)))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+const fn gear_table() -> [u64; 256] {
+    // splitmix64, all of the peers must have the same table.
+    let mut table = [0u64; 256];
+    let mut seed = 0x9e3779b97f4a7c15u64;
+    let mut i = 0;
+
+    while i < 256 {
+        seed = seed.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = seed;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        table[i] = z ^ (z >> 31);
+        i += 1;
+    }
+
+    table
+}
+
+const GEAR: [u64; 256] = gear_table();
+
+// the high bits of the gear hash depend on the last 64 bytes.
+#[inline]
+fn mask(bits: u32) -> u64 {
+    !0u64 << (64 - bits)
+}
+
+/// The length of the first chunk in the data.
+/// The data shorter than `max_len` is taken as the end of the file.
+pub fn cut_point(data: &[u8], params: &CdcParams) -> usize {
+    if data.len() <= params.min_len {
+        return data.len();
+    }
+
+    let bits = params.avg_len.trailing_zeros();
+    // the normalized chunking, it's harder to cut before the average length, and easier after it.
+    let (mask_s, mask_l) = (mask(bits + 2), mask(bits.saturating_sub(2).max(1)));
+    let normal = params.avg_len.min(data.len());
+    let max = params.max_len.min(data.len());
+
+    let mut hash = 0u64;
+    let mut i = params.min_len;
+
+    while i < normal {
+        hash = (hash << 1).wrapping_add(GEAR[data[i] as usize]);
+        if hash & mask_s == 0 {
+            return i + 1;
+        }
+        i += 1;
+    }
+
+    while i < max {
+        hash = (hash << 1).wrapping_add(GEAR[data[i] as usize]);
+        if hash & mask_l == 0 {
+            return i + 1;
+        }
+        i += 1;
+    }
+
+    max
+}
+
+#[test]
+fn test_cut_point() {
+    let params = CdcParams { min_len: 1024, avg_len: 4096, max_len: 16384 };
+    params.check().unwrap();
+
+    let mut seed = 1u64;
+    let data: Vec<u8> = (0..256 * 1024).map(| _ | {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (seed >> 56) as u8
+    }).collect();
+
+    let split = | data: &[u8] | {
+        let mut chunks = vec![];
+        let mut pos = 0;
+        while pos < data.len() {
+            let len = cut_point(&data[pos..], &params);
+            assert!(len <= params.max_len);
+            chunks.push(data[pos..pos + len].to_vec());
+            pos += len;
+        }
+        chunks
+    };
+
+    let chunks = split(&data);
+    assert_eq!(chunks.iter().map(| c | c.len()).sum::<usize>(), data.len());
+    assert!(chunks.iter().rev().skip(1).all(| c | c.len() >= params.min_len));
+
+    // insert a byte near the start, only the first chunks are changed.
+    let mut shifted = vec![0xffu8];
+    shifted.extend_from_slice(&data);
+    let shifted_chunks = split(&shifted);
+
+    let same = shifted_chunks.iter().filter(| c | chunks.contains(c)).count();
+    assert!(same + 2 >= chunks.len());
+}
@@ -2,20 +2,24 @@ use std::{io::Write, path::PathBuf};
 
 use near_base::{
     file::{FileBodyContent, FileDescContent, FileObject},
-    hash_util::hash_stream,
+    hash_util::{hash_data, hash_stream},
     Area, ChunkId, ErrorCode, Hash256, NearError, NearResult, ObjectBuilder, ObjectId,
     CHUNK_MAX_LEN,
 };
 use sha2::Digest;
 // use sha2::Digest;
 
-use crate::ReadWithLimit;
+use crate::{
+    cdc::{cut_point, CdcParams, Chunking},
+    ReadWithLimit,
+};
 
 pub struct FileBuilder<'a> {
     name: Option<&'a str>,
     owner: Option<&'a ObjectId>,
     author: Option<&'a ObjectId>,
     area: Option<Area>,
+    chunking: Chunking,
     path: &'a PathBuf,
 }
 
@@ -26,6 +30,7 @@ impl<'a> FileBuilder<'a> {
             owner: None,
             author: None,
             area: None,
+            chunking: Chunking::default(),
             path,
         }
     }
@@ -49,6 +54,11 @@ impl<'a> FileBuilder<'a> {
         self.area = area;
         self
     }
+
+    pub fn chunking(mut self, chunking: Chunking) -> Self {
+        self.chunking = chunking;
+        self
+    }
 }
 
 impl FileBuilder<'_> {
@@ -71,10 +81,32 @@ impl FileBuilder<'_> {
         let author = self.author;
         let area = self.area;
 
+        let (file_len, file_hash, chunks) = match self.chunking {
+            Chunking::Fixed => Self::split_fixed(self.path).await?,
+            Chunking::ContentDefined(params) => {
+                Self::split_content_defined(self.path, &params).await?
+            }
+        };
+
+        let file = ObjectBuilder::new(
+            FileDescContent::new(name.to_owned(), file_len, file_hash),
+            FileBodyContent::new(chunks),
+        )
+        .update_desc(|desc| {
+            desc.set_owner(owner.cloned());
+            desc.set_author(author.cloned());
+            desc.set_area(area);
+        })
+        .build()?;
+
+        Ok(file)
+    }
+
+    async fn split_fixed(path: &PathBuf) -> NearResult<(u64, Hash256, Vec<ChunkId>)> {
         let f = async_std::fs::OpenOptions::new()
             .create(false)
             .read(true)
-            .open(self.path.as_path())
+            .open(path.as_path())
             .await
             .map_err(|err| NearError::from(err))?;
 
@@ -131,18 +163,60 @@ impl FileBuilder<'_> {
             }
         };
 
-        let file = ObjectBuilder::new(
-            FileDescContent::new(name.to_owned(), file_len, file_hash),
-            FileBodyContent::new(chunks),
-        )
-        .update_desc(|desc| {
-            desc.set_owner(owner.cloned());
-            desc.set_author(author.cloned());
-            desc.set_area(area);
-        })
-        .build()?;
+        Ok((file_len, file_hash, chunks))
+    }
 
-        Ok(file)
+    async fn split_content_defined(
+        path: &PathBuf,
+        params: &CdcParams,
+    ) -> NearResult<(u64, Hash256, Vec<ChunkId>)> {
+        use async_std::io::ReadExt;
+
+        params.check()?;
+
+        let mut f = async_std::fs::File::open(path.as_path())
+            .await
+            .map_err(|err| NearError::from(err))?;
+
+        let mut sha256 = sha2::Sha256::new();
+        let mut chunks = vec![];
+        let mut file_len = 0u64;
+        let mut buf = Vec::with_capacity(params.max_len * 2);
+        let mut eof = false;
+
+        loop {
+            // keep at least max_len bytes in the buffer, so the cut point isn't decided by the read size.
+            while !eof && buf.len() < params.max_len {
+                let start = buf.len();
+                buf.resize(params.max_len * 2, 0);
+                let size = f
+                    .read(&mut buf[start..])
+                    .await
+                    .map_err(|err| NearError::from(err))?;
+                buf.truncate(start + size);
+                eof = size == 0;
+            }
+
+            if buf.is_empty() {
+                break;
+            }
+
+            let len = cut_point(&buf, params);
+            let data = &buf[..len];
+
+            let _ = sha256.write(data);
+            chunks.push(ChunkId::from((hash_data(data), len as u32)));
+            file_len += len as u64;
+
+            buf.drain(..len);
+        }
+
+        // the empty file has one empty chunk as the fixed chunking.
+        if chunks.is_empty() {
+            chunks.push(ChunkId::from((hash_data(&[]), 0)));
+        }
+
+        Ok((file_len, sha256.finalize().into(), chunks))
     }
 }
 
@@ -6,8 +6,10 @@ mod file_util;
 mod thing_util;
 mod read_with_limit;
 mod net;
+mod cdc;
 
 pub use file_util::FileBuilder;
+pub use cdc::{Chunking, CdcParams};
 pub use thing_util::ThingBuilder;
 pub use read_with_limit::{ReadWithLimit, BufReadWithLimit};
 
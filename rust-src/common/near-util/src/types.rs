@@ -3,5 +3,10 @@ pub const CORE_STACK_PORT: u16 = 13456;
 
 pub const HTTP_STACK_PORT: u16 = 18080;
 
+pub const HTTP_API_PORT: u16 = 18090;
+
 pub const DESC_SUFFIX_NAME: &'static str = "desc";
-pub const KEY_SUFFIX_NAME: &'static str = "key";
\ No newline at end of file
+pub const KEY_SUFFIX_NAME: &'static str = "key";
+
+/// The passphrase of the encrypted key file is taken from this environment variable.
+pub const KEY_PASSPHRASE_ENV: &'static str = "NEAR_KEY_PASSPHRASE";
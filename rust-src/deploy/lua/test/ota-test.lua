@@ -0,0 +1,220 @@
+-- The test script of the OTA frames, NO vendor firmware implements them.
+-- The frames are made for the test firmware which speaks the advertising format of 5EEB593E0968AF0FC01260808EC79ABC,
+-- never give them to the brand script of the production, the real things of the brand can't answer them.
+-- hci-service loads only the scripts which are right in the lua path, so it's skipped in this directory.
+--
+-- To try the OTA, copy it into the lua path with the brand id of the test things as its name, e.g.
+--   cp deploy/lua/test/ota-test.lua <lua-path>/<test-brand-id>.lua
+--
+-- host -> thing, cmd in the 8th byte, the data is 16 bytes:
+--   0x20 ota_begin   [1-4]size [5-7]version [8-16]the first 9 bytes of the hash
+--   0x21 ota_chunk   [1-3]offset [4]length [5-16]data(12)
+--   0x22 ota_end     [1-3]version
+--   0x23 ota_abort
+-- thing -> host:
+--   0x3A ota_status  [1]state [2-4]received [5-7]running version
+
+function array_concat(d1, d2) 
+
+    local dataes = {d1, d2}
+    local t = {}
+
+    for i = 1, 2 do 
+        local array = dataes[i] 
+        if (type(array) == "table") then 
+         for j = 1, #array do 
+          t[#t+1] = array[j] 
+         end 
+        else 
+         t[#t+1] = array 
+        end 
+       end 
+    return t 
+end 
+
+function pairsByKeys(t)
+    local key_array = {}
+    local idx = 0
+    for k, v in pairs(t) do
+        key_array[#key_array+1] = k
+        -- idx = idx + 1
+        -- key_array[k] = idx
+    end
+
+    table.sort(key_array)
+    local i = 0
+
+    return function()
+        i = i + 1
+        return key_array[i], t[key_array[i]]
+    end
+end
+
+function tableToVec(tbl)
+    local str = {}
+    local idx = 0
+
+    for k, v in pairsByKeys(tbl) do
+        if type(v) == "table" then
+            str = array_concat(str, tableToVec(v))
+        else
+            str[#str+1] = v
+        end
+        idx = idx + 1
+    end
+
+    return str
+end
+function getBleData() 
+    local bleData = {
+        a = 0xf5,
+        b = 0xf5,
+        c = 0xf5,
+        d = 0xf5,
+        e = 0xf5,
+        f = 0xf5,
+        g = 0xf5,
+        h = 0xf5,
+        i = 0xf5,
+        j = 0xf5,
+        k = 0xf5,
+        l = 0xf5,
+        m = 0xf5,
+        n = 0xf5,
+        o = 0xf5,
+        p = 0xf5,
+    }
+    return bleData
+end
+function getBle() 
+    
+    local ble =
+    {
+        aa_len = 0x1e,
+        ab_type = 0xff,
+        ac_pack_mark = 0xb0,
+        ad_pack_order = 0x55,
+        ae_pack_relay = 0xa0,
+        af_serial_num = 0,
+        ag_group = 0,
+        ah_cmd = 0,
+        ai_addr = {},
+        aj_data,
+    }
+   return ble
+    
+end
+
+function stringToMac(str)
+    
+    return {tonumber(string.sub(str,1,2), 16),tonumber(string.sub(str,3,4), 16),tonumber(string.sub(str,5,6), 16),tonumber(string.sub(str,7,8), 16),tonumber(string.sub(str,9,10), 16),tonumber(string.sub(str,11,12), 16),tonumber(string.sub(str,13,14), 16)}
+end
+
+--固件升级(OTA), 每一帧都由设备应答ota_status
+function otaFrame(cmd)
+    local bleData = getBleData()
+    local ble = getBle()
+    local thingMac = stringToMac(thing_data["thing_mac"])
+    local boxMac = stringToMac("f5"..configure_data:core_mac())
+    ble.af_serial_num = configure_data:gen_serial_num()%255
+    ble.ag_group = boxMac[7]
+    ble.ah_cmd = cmd
+    -- [8 - 14]地址(7) 
+    for i = 1, 7 do
+        ble.ai_addr[i] = thingMac[i]
+    end
+    ble.aj_data = bleData
+    return ble, bleData
+end
+--版本号 "主.次.修订" 转为3个字节
+function versionToBytes(version)
+    local bytes = {0, 0, 0}
+    local i = 1
+    for v in string.gmatch(version, "%d+") do
+        if (i <= 3) then
+            bytes[i] = tonumber(v) % 256
+        end
+        i = i + 1
+    end
+    return bytes
+end
+--开始升级: [1-4]固件大小 [5-7]版本 [8-16]摘要前9个字节
+function ota_begin()
+    local ble, bleData = otaFrame(0x20)
+    local size = tonumber(thing_data["ota_size"])
+    local version = versionToBytes(thing_data["ota_version"])
+    local hash = thing_data["ota_hash"]
+    bleData.a = (size >> 24) & 0xff
+    bleData.b = (size >> 16) & 0xff
+    bleData.c = (size >> 8) & 0xff
+    bleData.d = size & 0xff
+    bleData.e = version[1]
+    bleData.f = version[2]
+    bleData.g = version[3]
+    local keys = {"h", "i", "j", "k", "l", "m", "n", "o", "p"}
+    for i = 1, #keys do
+        bleData[keys[i]] = tonumber(string.sub(hash, i*2-1, i*2), 16)
+    end
+    return tableToVec(ble)
+end
+--固件分片: [1-3]偏移 [4]长度 [5-16]数据(12)
+function ota_chunk()
+    local ble, bleData = otaFrame(0x21)
+    local offset = tonumber(thing_data["ota_offset"])
+    local chunk = thing_data["ota_chunk"]
+    local len = #chunk // 2
+    bleData.a = (offset >> 16) & 0xff
+    bleData.b = (offset >> 8) & 0xff
+    bleData.c = offset & 0xff
+    bleData.d = len
+    local keys = {"e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p"}
+    for i = 1, len do
+        bleData[keys[i]] = tonumber(string.sub(chunk, i*2-1, i*2), 16)
+    end
+    return tableToVec(ble)
+end
+--传输完成, 设备校验后重启到新固件
+function ota_end()
+    local ble, bleData = otaFrame(0x22)
+    local version = versionToBytes(thing_data["ota_version"])
+    bleData.a = version[1]
+    bleData.b = version[2]
+    bleData.c = version[3]
+    return tableToVec(ble)
+end
+--放弃升级, 设备继续运行原固件
+function ota_abort()
+    local ble, bleData = otaFrame(0x23)
+    return tableToVec(ble)
+end
+
+--解析函数
+function alalize_data(datas)
+    if (datas[8] == 0x3A) then --升级状态: [1]状态 [2-4]已接收 [5-7]运行版本
+        output_data:set_cmd("ota_status")
+        local bleMac = hex2str({datas[10]})..":"..hex2str({datas[11]})..":"..hex2str({datas[12]})..":"..hex2str({datas[13]})..":"..hex2str({datas[14]})..":"..hex2str({datas[15]})
+        output_data:reset_mac(bleMac)
+        local states = {"receiving", "verified", "running", "failed", "rolled_back"}
+        local state = states[datas[16]]
+        if (state == nil) then
+            return false
+        end
+        output_data:set("ota_state", state)
+        output_data:set("ota_offset", ((datas[17] << 16) | (datas[18] << 8) | datas[19]).."")
+        output_data:set("ota_version", datas[20].."."..datas[21].."."..datas[22])
+        return true
+    end
+    return false
+end
+
+---将16进制串转换为字符串
+function hex2str(hex)
+	--拼接字符串
+	local index=1
+	local ret=""
+	for index=1,#hex do
+		ret=ret..string.format("%02X",hex[index])
+	end
+ 
+	return ret
+end
@@ -2,11 +2,13 @@
 mod message;
 mod chunk_v0;
 mod module;
+mod version;
 
 pub mod raw_object;
 
 pub use message::{MessageExpire, MessageType, SubscribeMessage, DissubcribeMessage, };
 pub use module::ModuleTrait;
+pub use version::Version;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd)]
 pub enum Command {
@@ -0,0 +1,134 @@
+use std::{cmp::Ordering, str::FromStr};
+
+use near_base::{NearError, ErrorCode};
+
+/// The firmware version, such as "1.0.2", "v1.2" or "1.0.2-beta".
+/// The leading 'v' is ignored, the missing parts are 0 and the build metadata after '+' is ignored,
+/// so "v1.2" is the same as "1.2.0". The pre-release is older than its release.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Version {
+    parts: Vec<u64>,
+    pre: Vec<String>,
+}
+
+impl Version {
+    pub fn is_prerelease(&self) -> bool {
+        !self.pre.is_empty()
+    }
+
+    fn part(&self, index: usize) -> u64 {
+        self.parts.get(index).cloned().unwrap_or_default()
+    }
+}
+
+impl FromStr for Version {
+    type Err = NearError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text = s.trim();
+        let text = text.strip_prefix(['v', 'V']).unwrap_or(text);
+        let text = text.split('+').next().unwrap_or_default();
+
+        let (release, pre) = match text.split_once('-') {
+            Some((release, pre)) => (release, Some(pre)),
+            None => (text, None),
+        };
+
+        let mut parts =
+            release.split('.')
+                .map(| part | part.parse::<u64>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("invalid version [{s}] with err: {e}")))?;
+
+        // the trailing zero doesn't change the version.
+        while parts.len() > 1 && parts.last() == Some(&0) {
+            parts.pop();
+        }
+
+        let pre = match pre {
+            Some(pre) => {
+                let pre: Vec<String> = pre.split('.').map(| id | id.to_owned()).collect();
+                if pre.iter().any(| id | id.is_empty()) {
+                    return Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("invalid pre-release of version [{s}]")));
+                }
+                pre
+            }
+            None => vec![],
+        };
+
+        Ok(Self { parts, pre })
+    }
+}
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.part(0), self.part(1), self.part(2))?;
+
+        for part in self.parts.iter().skip(3) {
+            write!(f, ".{part}")?;
+        }
+
+        if self.is_prerelease() {
+            write!(f, "-{}", self.pre.join("."))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let len = std::cmp::max(self.parts.len(), other.parts.len());
+
+        (0..len).map(| index | self.part(index).cmp(&other.part(index)))
+            .find(| ord | ord.is_ne())
+            .unwrap_or_else(|| {
+                match (self.pre.is_empty(), other.pre.is_empty()) {
+                    (true, true) => Ordering::Equal,
+                    (true, false) => Ordering::Greater,
+                    (false, true) => Ordering::Less,
+                    (false, false) => {
+                        // the numeric identifier is compared by the number, and it's older than the alphanumeric one.
+                        self.pre.iter().zip(other.pre.iter())
+                            .map(| (l, r) | {
+                                match (l.parse::<u64>(), r.parse::<u64>()) {
+                                    (Ok(l), Ok(r)) => l.cmp(&r),
+                                    (Ok(_), Err(_)) => Ordering::Less,
+                                    (Err(_), Ok(_)) => Ordering::Greater,
+                                    (Err(_), Err(_)) => l.cmp(r),
+                                }
+                            })
+                            .find(| ord | ord.is_ne())
+                            .unwrap_or_else(|| self.pre.len().cmp(&other.pre.len()))
+                    }
+                }
+            })
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[test]
+fn test_version() {
+    let v = | s: &str | s.parse::<Version>().unwrap();
+
+    assert_eq!(v("v1.2"), v("1.2.0"));
+    assert_eq!(v("V1.2.0+build.7"), v("1.2"));
+    assert_eq!(v("v1.2").to_string(), "1.2.0");
+    assert_eq!(v(" 1.0.2-beta ").to_string(), "1.0.2-beta");
+
+    assert!(v("1.0.2-beta") < v("1.0.2"));
+    assert!(v("1.0.2") < v("1.0.10"));
+    assert!(v("1.0.2-alpha") < v("1.0.2-beta"));
+    assert!(v("1.0.2-beta.2") < v("1.0.2-beta.11"));
+    assert!(v("1.0.2-beta") < v("1.0.2-beta.1"));
+    assert!(v("2") > v("1.9.9"));
+
+    assert!("".parse::<Version>().is_err());
+    assert!("1.x".parse::<Version>().is_err());
+    assert!("1.0-".parse::<Version>().is_err());
+}
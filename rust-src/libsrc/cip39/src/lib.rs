@@ -1,5 +1,6 @@
 
 pub mod profile;
+pub mod mnemonic;
 
 #[allow(unused)]
 mod cip39;
@@ -12,6 +13,9 @@ mod seed;
 #[allow(unused)]
 mod path;
 
+pub use path::{ChainNetwork, ChainBipPath, NEAR_BIP};
+pub use mnemonic::{generate_mnemonic, validate_mnemonic, KeyVersion, MnemonicKeyChain, RestoredPeople};
+
 #[derive(Clone, Copy)]
 pub enum CipPrivateKey {
     Rsa1024,
@@ -0,0 +1,249 @@
+
+use bip39::{Language, Mnemonic};
+use log::error;
+use near_base::{people::{PeopleBodyContent, PeopleDescContent, PeopleObject}, ErrorCode, NearError, NearResult, ObjectBuilder, PrivateKey,
+                KeyRotation, KeyRotationObject};
+
+use crate::{path::{ChainBipPath, ChainNetwork}, seed_key_bip::SeedKeyBip, CipPrivateKey};
+
+// the people key is lighter, it only signs the grants and the revocations.
+const PEOPLE_KEY: CipPrivateKey = CipPrivateKey::Rsa1024;
+const DEVICE_KEY: CipPrivateKey = CipPrivateKey::Rsa2048;
+
+/// The mnemonic restores the same keys along the same version.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum KeyVersion {
+    /// The people key is derived along the device path with RSA-2048, so it's the key of the first device,
+    /// and the people object has the create time. The mnemonics which are created before V1 are on it.
+    #[default]
+    V0,
+    /// The people key is derived along the people path with RSA-1024, and the people object hasn't the create time.
+    V1,
+}
+
+/// Generate a random english mnemonic, the word count is one of 12, 15, 18, 21, 24.
+pub fn generate_mnemonic(word_count: usize) -> NearResult<String> {
+    Mnemonic::generate_in(Language::English, word_count)
+        .map(| mn | mn.to_string())
+        .map_err(| e | {
+            let error_string = format!("failed to generate mnemonic with err: {e}");
+            error!("{error_string}");
+            NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, error_string)
+        })
+}
+
+/// Check the words and the checksum of the mnemonic, return the normalized mnemonic.
+pub fn validate_mnemonic(mnemonic: &str) -> NearResult<String> {
+    let mnemonic = SeedKeyBip::fix_mnemonic(mnemonic)?;
+
+    Mnemonic::parse_in_normalized(Language::English, mnemonic.as_str())
+        .map(| mn | mn.to_string())
+        .map_err(| e | {
+            let error_string = format!("invalid mnemonic: err={e}");
+            error!("{error_string}");
+            NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, error_string)
+        })
+}
+
+/// The people and its devices which are restored from the mnemonic.
+pub struct RestoredPeople {
+    pub people: PeopleObject,
+    pub people_key: PrivateKey,
+    pub device_keys: Vec<PrivateKey>,
+}
+
+/// All of the keys are derived from the mnemonic along the paths:
+///   people: m/1314'/1536'/0'/network/people_index (V1), m/1314'/513'/people_index'/network/0 (V0)
+///   device: m/1314'/513'/people_index'/network/device_index
+/// the network is 0 for main, 1 for test and 2 for beta.
+pub struct MnemonicKeyChain {
+    seed: SeedKeyBip,
+    network: ChainNetwork,
+    version: KeyVersion,
+}
+
+impl MnemonicKeyChain {
+    pub fn new(mnemonic: &str, password: Option<&str>, network: ChainNetwork) -> NearResult<Self> {
+        let mnemonic = validate_mnemonic(mnemonic)?;
+
+        Ok(Self {
+            seed: SeedKeyBip::from_mnemonic(&mnemonic, password)?,
+            network,
+            version: KeyVersion::default(),
+        })
+    }
+
+    pub fn with_version(mut self, version: KeyVersion) -> Self {
+        self.version = version;
+        self
+    }
+
+    pub fn network(&self) -> ChainNetwork {
+        self.network
+    }
+
+    pub fn version(&self) -> KeyVersion {
+        self.version
+    }
+
+    pub fn people_key(&self, people_index: u32) -> NearResult<PrivateKey> {
+        self.people_key_of(self.version, people_index)
+    }
+
+    fn people_key_of(&self, version: KeyVersion, people_index: u32) -> NearResult<PrivateKey> {
+        match version {
+            KeyVersion::V0 => self.seed.sub_key(&ChainBipPath::new_device(self.network, people_index, Some(0)), DEVICE_KEY),
+            KeyVersion::V1 => self.seed.sub_key(&ChainBipPath::new_people(self.network, Some(people_index)), PEOPLE_KEY),
+        }
+    }
+
+    pub fn device_key(&self, people_index: u32, device_index: u32) -> NearResult<PrivateKey> {
+        self.seed.sub_key(&ChainBipPath::new_device(self.network, people_index, Some(device_index)), DEVICE_KEY)
+    }
+
+    /// The same object id is built from the same key and the same create time,
+    /// the people object of V1 hasn't the create time, and the one of V0 needs its original create time.
+    pub fn people(&self, people_index: u32, create_time: Option<u64>, name: Option<&str>, userdata: Option<Vec<u8>>) -> NearResult<(PeopleObject, PrivateKey)> {
+        let people_key = self.people_key(people_index)?;
+
+        let people =
+            ObjectBuilder::new(PeopleDescContent::new(), PeopleBodyContent::default())
+                .update_desc(| desc | {
+                    match create_time {
+                        Some(create_time) => desc.set_create_timestamp(create_time),
+                        None => desc.no_create_time(),
+                    }
+                    desc.set_public_key(people_key.public());
+                })
+                .update_body(| body | {
+                    body.mut_body()
+                        .set_name(name)
+                        .set_userdata(userdata);
+                })
+                .build()?;
+
+        Ok((people, people_key))
+    }
+
+    /// Restore the people and the keys of its first `device_count` devices.
+    pub fn restore(&self, people_index: u32, create_time: Option<u64>, name: Option<&str>, device_count: u32) -> NearResult<RestoredPeople> {
+        let (people, people_key) = self.people(people_index, create_time, name, None)?;
+
+        let device_keys =
+            (0..device_count)
+                .map(| device_index | self.device_key(people_index, device_index))
+                .collect::<NearResult<Vec<PrivateKey>>>()?;
+
+        Ok(RestoredPeople {
+            people,
+            people_key,
+            device_keys,
+        })
+    }
+
+    /// The people of V0 moves to the key of V1 without changing its object id,
+    /// the rotation is signed by the key of V0, and the key of V1 is returned with it.
+    pub fn migrate(&self, people: &PeopleObject, people_index: u32, sequence: u64) -> NearResult<(KeyRotationObject, PrivateKey)> {
+        let old_key = self.people_key_of(KeyVersion::V0, people_index)?;
+        let new_key = self.people_key_of(KeyVersion::V1, people_index)?;
+
+        if people.desc().public_key() != Some(&old_key.public()) {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("{} isn't derived from the mnemonic", people.object_id())));
+        }
+
+        let rotation =
+            KeyRotation::new(people.object_id().clone(), sequence, old_key.public(), new_key.public())
+                .sign(&old_key)?;
+
+        Ok((rotation, new_key))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use near_base::{hash_data, RawConvertTo};
+
+    use super::*;
+
+    // the test vector of BIP39
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_validate_mnemonic() {
+        assert_eq!(validate_mnemonic(&format!("  {}  ", PHRASE.replace(' ', "  "))).unwrap(), PHRASE);
+        // the checksum is wrong
+        assert!(validate_mnemonic("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon").is_err());
+        assert!(validate_mnemonic("abandon abandon abandon").is_err());
+
+        let mnemonic = generate_mnemonic(24).unwrap();
+        assert_eq!(validate_mnemonic(&mnemonic).unwrap(), mnemonic);
+    }
+
+    #[test]
+    fn test_path() {
+        assert_eq!(ChainBipPath::new_people(ChainNetwork::Main, Some(0)).to_string(), "m/1314'/1536'/0'/0/0");
+        assert_eq!(ChainBipPath::new_people(ChainNetwork::Test, Some(2)).to_string(), "m/1314'/1536'/0'/1/2");
+        assert_eq!(ChainBipPath::new_device(ChainNetwork::Beta, 1, Some(3)).to_string(), "m/1314'/513'/1'/2/3");
+    }
+
+    #[test]
+    fn test_restore() {
+        let chain = MnemonicKeyChain::new(PHRASE, Some("near"), ChainNetwork::Test).unwrap().with_version(KeyVersion::V1);
+        let created = chain.people(0, None, Some("BM"), None).unwrap();
+
+        let restored =
+            MnemonicKeyChain::new(PHRASE, Some("near"), ChainNetwork::Test).unwrap()
+                .with_version(KeyVersion::V1)
+                .restore(0, None, Some("BM"), 1)
+                .unwrap();
+
+        assert_eq!(created.0.object_id(), restored.people.object_id());
+        assert_eq!(created.1.to_vec().unwrap(), restored.people_key.to_vec().unwrap());
+        assert_eq!(restored.device_keys.len(), 1);
+        assert_eq!(chain.device_key(0, 0).unwrap().to_vec().unwrap(), restored.device_keys[0].to_vec().unwrap());
+
+        // the other network or password derives the other people.
+        let main = MnemonicKeyChain::new(PHRASE, Some("near"), ChainNetwork::Main).unwrap().with_version(KeyVersion::V1).people(0, None, None, None).unwrap();
+        assert_ne!(main.0.object_id(), restored.people.object_id());
+        let other = MnemonicKeyChain::new(PHRASE, None, ChainNetwork::Test).unwrap().with_version(KeyVersion::V1).people(0, None, None, None).unwrap();
+        assert_ne!(other.0.object_id(), restored.people.object_id());
+    }
+
+    #[test]
+    fn test_vectors() {
+        let v0 = MnemonicKeyChain::new(PHRASE, Some("near"), ChainNetwork::Test).unwrap();
+        let v1 = MnemonicKeyChain::new(PHRASE, Some("near"), ChainNetwork::Test).unwrap().with_version(KeyVersion::V1);
+        let key_hash = | key: PrivateKey | hash_data(&key.public().to_vec().unwrap()).to_hex_string();
+
+        // the people key of V0 is the key of the first device, as the mnemonics before V1 were derived.
+        assert_eq!(v0.version(), KeyVersion::V0);
+        assert_eq!(v0.people_key(0).unwrap().to_vec().unwrap(), v0.device_key(0, 0).unwrap().to_vec().unwrap());
+        assert_eq!(key_hash(v0.people_key(0).unwrap()), "AB3DBDAE7C42A5CAEC62BABE4DD407BDD214876C81A27D981B802428344B9B28");
+        assert_eq!(v0.people(0, Some(1_600_000_000_000_000), None, None).unwrap().0.object_id().to_string(), "7zyhyYMPccCGC4En9wkfiyx32c47naGKMh4CthuQNSzL");
+
+        assert_eq!(key_hash(v1.people_key(0).unwrap()), "91739F43470F681D059E08B927B44E4F1BC36CC6E0E9B823820EE0DBB9655410");
+        assert_eq!(v1.people(0, None, None, None).unwrap().0.object_id().to_string(), "7zytNfUB7aVo7xNKwwCui3tBgM6LyFyVRHGE9d4BV5tA");
+
+        // the device keys don't depend on the version.
+        assert_eq!(key_hash(v0.device_key(0, 1).unwrap()), "5640622D7DEA63181E0CDBA6FC875221B2002B22A2D042537EF24AC93319C8B4");
+        assert_eq!(key_hash(v1.device_key(0, 1).unwrap()), "5640622D7DEA63181E0CDBA6FC875221B2002B22A2D042537EF24AC93319C8B4");
+    }
+
+    #[test]
+    fn test_migrate() {
+        let chain = MnemonicKeyChain::new(PHRASE, Some("near"), ChainNetwork::Test).unwrap();
+        let (mut people, _) = chain.people(0, Some(1_600_000_000_000_000), None, None).unwrap();
+        let object_id = people.object_id().clone();
+
+        let (rotation, new_key) = chain.migrate(&people, 0, 1).unwrap();
+        rotation.apply(&mut people).unwrap();
+
+        assert_eq!(people.object_id(), &object_id);
+        assert!(people.desc().public_key() == Some(&new_key.public()));
+        assert_eq!(new_key.to_vec().unwrap(), chain.with_version(KeyVersion::V1).people_key(0).unwrap().to_vec().unwrap());
+
+        // the people which isn't derived from the mnemonic can't be migrated.
+        let other = MnemonicKeyChain::new(PHRASE, None, ChainNetwork::Test).unwrap();
+        assert!(other.migrate(&people, 0, 2).is_err());
+    }
+}
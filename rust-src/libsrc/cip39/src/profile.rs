@@ -4,7 +4,7 @@ use std::sync::Mutex;
 use log::{info, error};
 use near_base::{DeviceObjectSubCode, ErrorCode, NearError, NearResult, PrivateKey, };
 
-use crate::{seed_key_bip::SeedKeyBip, path::{ChainObjectType, ChainNetwork, ChainBipPath}};
+use crate::{mnemonic::{generate_mnemonic, validate_mnemonic, MnemonicKeyChain}, path::{ChainObjectType, ChainNetwork}};
 
 struct Profile {
     mnemonic: Option<String>,
@@ -25,19 +25,12 @@ impl Profile {
 
     fn set_mnemonic(&mut self, mnemonic: Option<&str>) -> NearResult<Option<String>> {
         if let Some(mn) = mnemonic {
-            info!("set mnemonic: {:?}", mnemonic);
-            self.mnemonic = Some(mn.to_owned());
+            self.mnemonic = Some(validate_mnemonic(mn)?);
+            info!("set mnemonic");
             Ok(self.mnemonic.clone())
         } else {
-            use bip39::*;
-            let mn = 
-                Mnemonic::generate_in(Language::English, 12).map_err(| e | {
-                    let error_string = format!("failed to generate mnemonic with err: {e}");
-                    error!("{error_string}");
-                    NearError::new(ErrorCode::NEAR_ERROR_3RD, error_string)
-                })?;
-            self.mnemonic = Some(mn.to_string());
-            info!("random mnemonic: {}", mn);
+            self.mnemonic = Some(generate_mnemonic(12)?);
+            info!("random mnemonic");
 
             Ok(self.mnemonic.clone())
         }
@@ -64,7 +57,7 @@ impl Profile {
     }
 
     fn set_people_type(&mut self) {
-        self.coin = Some(ChainObjectType::Device(near_base::ObjectTypeCode::People));
+        self.coin = Some(ChainObjectType::People(near_base::ObjectTypeCode::People));
     }
 }
 
@@ -72,22 +65,13 @@ impl Profile {
     fn build(self) -> NearResult<PrivateKey> {
        
         let mn = self.mnemonic.as_ref().ok_or_else(||NearError::new(ErrorCode::NEAR_ERROR_MISSING_DATA, "missing mnemonic string"))?;
-        let mnemonic = SeedKeyBip::from_mnemonic(mn, self.password.as_ref().map(| v | v.as_str()))?;
+        let key_chain = MnemonicKeyChain::new(mn, self.password.as_ref().map(| v | v.as_str()), self.network)?;
         let coin = self.coin.as_ref().ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_MISSING_DATA, "missing coin"))?;
 
-        let bip_path = 
-            match coin {
-                ChainObjectType::Device(_) => ChainBipPath::new_device(self.network, 0, Some(0)),
-                ChainObjectType::People(_) => ChainBipPath::new_people(self.network, Some(0)),
-            };
-
-        let key = 
-            match coin {
-                ChainObjectType::Device(_) => mnemonic.sub_key(&bip_path, crate::CipPrivateKey::Rsa2048)?,
-                ChainObjectType::People(_) => mnemonic.sub_key(&bip_path, crate::CipPrivateKey::Rsa1024)?,
-            };
-
-        Ok(key)
+        match coin {
+            ChainObjectType::Device(_) => key_chain.device_key(0, 0),
+            ChainObjectType::People(_) => key_chain.people_key(0),
+        }
     }
 }
 
@@ -20,8 +20,8 @@ impl fmt::Debug for SeedKeyBip {
 
 impl SeedKeyBip {
     pub fn fix_mnemonic(mnemonic: &str) -> NearResult<String> {
-        let words: Vec<&str> = mnemonic.split(" ").into_iter().map(| v | v.trim()).collect();
-        if words.len() != 12 {
+        let words: Vec<&str> = mnemonic.split_whitespace().collect();
+        if !matches!(words.len(), 12 | 15 | 18 | 21 | 24) {
             let msg = format!("invalid mnemonic words: len={}", words.len());
             error!("{}", msg);
             Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, msg))
@@ -64,6 +64,11 @@ pub fn bm_desc_people_type() -> bool {
     false
 }
 
+pub fn bm_desc_key_passphrase(passphrase: String) -> bool {
+    desc::set_key_passphrase(Some(passphrase));
+    true
+}
+
 pub fn bm_desc_build(user_name: String, user_data: Vec<u8>, output_dir: String) -> Option<String> {
     desc::build(user_name, user_data, output_dir)
         .map_err(| e | {
@@ -195,6 +200,23 @@ pub fn query_all_product(reqeust: ApiRequestCommon, ) -> Vec<u8> {
     inner_bm_request!({crate::query_all_product(reqeust.into(), )})
 }
 
+/// The capability is the bytes of protobuf product_capability.
+pub fn update_product_capability(reqeust: ApiRequestCommon, product_id: String, capability: Vec<u8>) -> Vec<u8> {
+    inner_bm_request!({
+        protos::hci::product::Product_capability::parse_from_bytes(&capability)
+            .map_err(| e | {
+                let error_string = format!("failed decode capability with err = {e}");
+                error!("{error_string}");
+                NearError::new(ErrorCode::NEAR_ERROR_PROTOC_DECODE, error_string)
+            })
+            .and_then(| capability | crate::update_product_capability(reqeust.into(), product_id, capability))
+    })
+}
+
+pub fn query_thing_capability(reqeust: ApiRequestCommon, thing_ids: Vec<String>) -> Vec<u8> {
+    inner_bm_request!({crate::query_thing_capability(reqeust.into(), thing_ids)})
+}
+
 pub fn update_thing(reqeust: ApiRequestCommon, thing_id: String, thing_name: String) -> Vec<u8> {
     inner_bm_request!({crate::update_thing(reqeust.into(), thing_id, thing_name)})
 }
@@ -286,6 +308,63 @@ pub fn execute_schedule(reqeust: ApiRequestCommon, schedule_id: String) -> bool
     }
 }
 
+pub fn query_thing_telemetry(
+    reqeust: ApiRequestCommon, 
+    thing_id: String, 
+    begin: u64, 
+    end: u64, 
+    resolution: i32, 
+    properties: Vec<String>
+) -> Vec<u8> {
+    inner_bm_request!({crate::query_thing_telemetry(reqeust.into(), thing_id, begin, end, resolution, properties)})
+}
+
+// location
+/// The location_type is 0: home, 1: floor, 2: room, 3: zone.
+pub fn add_location(
+    reqeust: ApiRequestCommon, 
+    parent_id: Option<String>, 
+    location_type: i32, 
+    location_name: String, 
+    location_img_idx: Option<u32>
+) -> Vec<u8> {
+    inner_bm_request!({crate::add_location(reqeust.into(), parent_id, location_type, location_name, location_img_idx)})
+}
+
+pub fn update_location(reqeust: ApiRequestCommon, location_id: String, location_name: String, location_img_idx: Option<u32>) -> Vec<u8> {
+    inner_bm_request!({crate::update_location(reqeust.into(), location_id, location_name, location_img_idx)})
+}
+
+pub fn remove_location(reqeust: ApiRequestCommon, location_id: String) -> bool {
+    match crate::remove_location(reqeust.into(), location_id) {
+        Ok(_) => true,
+        Err(e) => {
+            crate::stack::set_last_error(e);
+            false
+        }
+    }
+}
+
+pub fn query_location(reqeust: ApiRequestCommon, location_id: String) -> Vec<u8> {
+    inner_bm_request!({crate::query_location(reqeust.into(), location_id)})
+}
+
+pub fn query_all_location(reqeust: ApiRequestCommon, parent_id: Option<String>) -> Vec<u8> {
+    inner_bm_request!({crate::query_all_location(reqeust.into(), parent_id)})
+}
+
+pub fn insert_location_things(reqeust: ApiRequestCommon, location_id: String, thing_ids: Vec<String>) -> Vec<u8> {
+    inner_bm_request!({crate::update_location_things(reqeust.into(), location_id, true, thing_ids)})
+}
+
+pub fn remove_location_things(reqeust: ApiRequestCommon, location_id: String, thing_ids: Vec<String>) -> Vec<u8> {
+    inner_bm_request!({crate::update_location_things(reqeust.into(), location_id, false, thing_ids)})
+}
+
+pub fn ctrl_location(reqeust: ApiRequestCommon, location_id: String, operator_data: Vec<(String, String)>) -> u32 {
+    crate::ctrl_location(reqeust.into(), location_id, operator_data)
+}
+
 pub fn hci_search_thing(reqeust: ApiRequestCommon, brand_id: String) -> u32 {
     crate::hci_search_thing(reqeust.into(), brand_id)
 }
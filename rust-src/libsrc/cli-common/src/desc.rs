@@ -109,12 +109,23 @@ pub mod build_pk {
 
 }
 
-use std::path::PathBuf;
+use std::{path::PathBuf, sync::RwLock};
 use log::debug;
-use near_base::{NearResult, ObjectBuilder, NearError, ErrorCode, FileEncoder};
+use near_base::{NearResult, ObjectBuilder, NearError, ErrorCode, FileEncoder, save_private_key};
 use near_base::people::{PeopleDescContent, PeopleBodyContent};
 use near_util::{DESC_SUFFIX_NAME, KEY_SUFFIX_NAME};
 
+/// The key file is encrypted with the passphrase when it's set.
+static KEY_PASSPHRASE: RwLock<Option<String>> = RwLock::new(None);
+
+pub fn set_key_passphrase(passphrase: Option<String>) {
+    *KEY_PASSPHRASE.write().unwrap() = passphrase.filter(| v | !v.is_empty());
+}
+
+pub fn key_passphrase() -> Option<String> {
+    KEY_PASSPHRASE.read().unwrap().clone()
+}
+
 pub fn build(user_name: String, user_data: Vec<u8>, output_dir: String) -> NearResult<String> {
     let output_dir = PathBuf::new().join(output_dir);
     if !output_dir.exists() {
@@ -145,7 +156,7 @@ pub fn build(user_name: String, user_data: Vec<u8>, output_dir: String) -> NearR
     let id = o.object_id().to_string();
 
     debug!("encode key-file");
-    let _ = key.encode_to_file(output_dir.join(PathBuf::new().with_file_name(id.as_str()).with_extension(KEY_SUFFIX_NAME)).as_path(), true)?;
+    let _ = save_private_key(output_dir.join(PathBuf::new().with_file_name(id.as_str()).with_extension(KEY_SUFFIX_NAME)).as_path(), &key, key_passphrase().as_deref())?;
     debug!("encode desc-file");
     let _ = o.encode_to_file(output_dir.join(PathBuf::new().with_file_name(id.as_str()).with_extension(DESC_SUFFIX_NAME)).as_path(), true)?;
 
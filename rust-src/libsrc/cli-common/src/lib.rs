@@ -17,7 +17,7 @@ use std::path::PathBuf;
 use near_base::{
     people::PeopleObject, DeviceObject, ErrorCode, FileDecoder, NearError, NearResult,
 };
-use near_base::{FileEncoder, ObjectId, PrivateKey};
+use near_base::{FileEncoder, ObjectId, load_private_key};
 use near_core::path_utils::alter_near_path;
 use near_core::{alter_root_path, get_temp_path, LogLevel};
 use near_core::{get_data_path, get_log_path, panic::PanicBuilder, LoggerBuilder};
@@ -26,6 +26,8 @@ use protos::hci::brand::*;
 use protos::hci::hci_thing::{hci_crud_thing::Hci_crud_m, *};
 use protos::hci::product::*;
 use protos::hci::schedule::*;
+use protos::hci::telemetry::*;
+use protos::hci::location::*;
 use protos::hci::thing::*;
 use stack::CliStackBuild;
 use topic_util::types::hci_types::HciTaskId;
@@ -199,7 +201,7 @@ pub fn open(people: String, core: String) -> NearResult<()> {
             })?;
 
     let people_key =
-        PrivateKey::decode_from_file(get_data_path().join(format!("{people}.{KEY_SUFFIX_NAME}")).as_path())
+        load_private_key(get_data_path().join(format!("{people}.{KEY_SUFFIX_NAME}")).as_path(), desc::key_passphrase().as_deref())
             .map_err(|e| {
                 error!("failed decode people key with err={e}");
                 e
@@ -487,6 +489,46 @@ pub fn query_all_product(reqeust: RequestCommon, ) -> NearResult<Product_info_li
     })
 }
 
+pub fn update_product_capability(reqeust: RequestCommon, product_id: String, capability: Product_capability) -> NearResult<Product_info> {
+    trace!("update_product_capability: product_id: {product_id}, capability: {capability}");
+
+    async_std::task::block_on(async move {
+        match async_std::future::timeout(
+            CliCommonConfig::get_instance().timeout,
+            CliStack::get_instance().unwrap().update_product_capability(reqeust, product_id, capability),
+        )
+        .await
+        {
+            Ok(data) => data,
+            Err(e) => {
+                let error_string = format!("update_product_capability is timeout, err = {e}");
+                error!("{error_string}");
+                Err(NearError::new(ErrorCode::NEAR_ERROR_TIMEOUT, error_string))
+            }
+        }
+    })
+}
+
+pub fn query_thing_capability(reqeust: RequestCommon, thing_ids: Vec<String>) -> NearResult<Thing_capability_list> {
+    trace!("query_thing_capability: thing_ids: {:?}", thing_ids);
+
+    async_std::task::block_on(async move {
+        match async_std::future::timeout(
+            CliCommonConfig::get_instance().timeout,
+            CliStack::get_instance().unwrap().query_thing_capability(reqeust, thing_ids),
+        )
+        .await
+        {
+            Ok(data) => data,
+            Err(e) => {
+                let error_string = format!("query_thing_capability is timeout, err = {e}");
+                error!("{error_string}");
+                Err(NearError::new(ErrorCode::NEAR_ERROR_TIMEOUT, error_string))
+            }
+        }
+    })
+}
+
 // pub fn add_device(product_id: String, device_mac_address: String, device_name: String) -> NearResult<Device_info> {
 //     trace!("add_device: product_id: {product_id}, device_mac_address:{device_mac_address}, device_name: {device_name}");
 
@@ -828,6 +870,195 @@ pub fn execute_schedule(reqeust: RequestCommon, schedule_id: String) -> NearResu
     })
 }
 
+pub fn query_thing_telemetry(
+    reqeust: RequestCommon, 
+    thing_id: String, 
+    begin: u64, 
+    end: u64, 
+    resolution: i32, 
+    properties: Vec<String>
+) -> NearResult<Thing_telemetry_series> {
+    trace!("query_thing_telemetry: thing_id: {thing_id}, begin: {begin}, end: {end}, resolution: {resolution}");
+
+    async_std::task::block_on(async move {
+        match async_std::future::timeout(
+            CliCommonConfig::get_instance().timeout,
+            CliStack::get_instance()
+                .unwrap()
+                .query_thing_telemetry(reqeust, thing_id, begin, end, resolution, properties),
+        )
+        .await
+        {
+            Ok(data) => data,
+            Err(e) => {
+                let error_string = format!("query_thing_telemetry is timeout, err = {e}");
+                error!("{error_string}");
+                Err(NearError::new(ErrorCode::NEAR_ERROR_TIMEOUT, error_string))
+            }
+        }
+    })
+}
+
+pub fn add_location(reqeust: RequestCommon, parent_id: Option<String>, location_type: i32, location_name: String, location_img_idx: Option<u32>) -> NearResult<Location_info> {
+    trace!("add_location: parent_id: {:?}, location_type: {location_type}, location_name: {location_name}", parent_id);
+
+    async_std::task::block_on(async move {
+        match async_std::future::timeout(
+            CliCommonConfig::get_instance().timeout,
+            CliStack::get_instance().unwrap().add_location(reqeust, parent_id, location_type, location_name, location_img_idx),
+        )
+        .await
+        {
+            Ok(data) => data,
+            Err(e) => {
+                let error_string = format!("add_location is timeout, err = {e}");
+                error!("{error_string}");
+                Err(NearError::new(ErrorCode::NEAR_ERROR_TIMEOUT, error_string))
+            }
+        }
+    })
+}
+
+pub fn update_location(reqeust: RequestCommon, location_id: String, location_name: String, location_img_idx: Option<u32>) -> NearResult<Location_info> {
+    trace!("update_location: location_id: {location_id}, location_name: {location_name}");
+
+    async_std::task::block_on(async move {
+        match async_std::future::timeout(
+            CliCommonConfig::get_instance().timeout,
+            CliStack::get_instance().unwrap().update_location(reqeust, location_id, location_name, location_img_idx),
+        )
+        .await
+        {
+            Ok(data) => data,
+            Err(e) => {
+                let error_string = format!("update_location is timeout, err = {e}");
+                error!("{error_string}");
+                Err(NearError::new(ErrorCode::NEAR_ERROR_TIMEOUT, error_string))
+            }
+        }
+    })
+}
+
+pub fn remove_location(reqeust: RequestCommon, location_id: String) -> NearResult<()> {
+    trace!("remove_location: location_id: {location_id}");
+
+    async_std::task::block_on(async move {
+        match async_std::future::timeout(
+            CliCommonConfig::get_instance().timeout,
+            CliStack::get_instance().unwrap().remove_location(reqeust, location_id),
+        )
+        .await
+        {
+            Ok(data) => data,
+            Err(e) => {
+                let error_string = format!("remove_location is timeout, err = {e}");
+                error!("{error_string}");
+                Err(NearError::new(ErrorCode::NEAR_ERROR_TIMEOUT, error_string))
+            }
+        }
+    })?;
+
+    Ok(())
+}
+
+pub fn query_location(reqeust: RequestCommon, location_id: String) -> NearResult<Location_info> {
+    trace!("query_location: location_id: {location_id}");
+
+    async_std::task::block_on(async move {
+        match async_std::future::timeout(
+            CliCommonConfig::get_instance().timeout,
+            CliStack::get_instance().unwrap().query_location(reqeust, location_id),
+        )
+        .await
+        {
+            Ok(data) => data,
+            Err(e) => {
+                let error_string = format!("query_location is timeout, err = {e}");
+                error!("{error_string}");
+                Err(NearError::new(ErrorCode::NEAR_ERROR_TIMEOUT, error_string))
+            }
+        }
+    })
+}
+
+pub fn query_all_location(reqeust: RequestCommon, parent_id: Option<String>) -> NearResult<Location_info_list> {
+    trace!("query_all_location: parent_id: {:?}", parent_id);
+
+    async_std::task::block_on(async move {
+        match async_std::future::timeout(
+            CliCommonConfig::get_instance().timeout,
+            CliStack::get_instance().unwrap().query_all_location(reqeust, parent_id),
+        )
+        .await
+        {
+            Ok(data) => data,
+            Err(e) => {
+                let error_string = format!("query_all_location is timeout, err = {e}");
+                error!("{error_string}");
+                Err(NearError::new(ErrorCode::NEAR_ERROR_TIMEOUT, error_string))
+            }
+        }
+    })
+}
+
+pub fn update_location_things(reqeust: RequestCommon, location_id: String, insert: bool, thing_ids: Vec<String>) -> NearResult<Location_info> {
+    trace!("update_location_things: location_id: {location_id}, insert: {insert}, thing_ids: {:?}", thing_ids);
+
+    async_std::task::block_on(async move {
+        match async_std::future::timeout(
+            CliCommonConfig::get_instance().timeout,
+            CliStack::get_instance().unwrap().update_location_things(reqeust, location_id, insert, thing_ids),
+        )
+        .await
+        {
+            Ok(data) => data,
+            Err(e) => {
+                let error_string = format!("update_location_things is timeout, err = {e}");
+                error!("{error_string}");
+                Err(NearError::new(ErrorCode::NEAR_ERROR_TIMEOUT, error_string))
+            }
+        }
+    })
+}
+
+pub fn ctrl_location(
+    reqeust: RequestCommon, 
+    location_id: String, 
+    thing_data: Vec<(String, String)>
+) -> HciTaskId {
+    trace!(
+        "ctrl_location: location_id: {location_id}, thing_data count: {}",
+        thing_data.len()
+    );
+
+    let map: HashMap<String, String> = thing_data.into_iter().collect();
+
+    async_std::task::block_on(async move {
+        match async_std::future::timeout(
+            CliCommonConfig::get_instance().timeout,
+            CliStack::get_instance()
+                .unwrap()
+                .ctrl_location(reqeust, location_id, map),
+        )
+        .await
+        {
+            Ok(data) => {
+                data.map_err(| e | crate::stack::set_last_error(e))
+                    .unwrap_or(0)
+            }
+            Err(e) => {
+                let error_string = format!("ctrl_location is timeout, err = {e}");
+                error!("{error_string}");
+                crate::stack::set_last_error(NearError::new(
+                    ErrorCode::NEAR_ERROR_TIMEOUT,
+                    error_string,
+                ));
+                0
+            }
+        }
+    })
+}
+
 pub enum HciOperator {
     Remove = 0,
     Pair = 1,
@@ -14,6 +14,8 @@ use protos::hci::schedule::{*, schedule_relation_list_update::Schedule_relation_
 use protos::hci::thing::*;
 use protos::hci::hci_thing::{*, hci_crud_thing::Hci_crud_m};
 use protos::hci::brand::*;
+use protos::hci::telemetry::*;
+use protos::hci::location::{*, location_thing_update::Location_thing_op};
 use protos::DataContent;
 use topic_util::types::hci_types::HciTaskId;
 
@@ -238,6 +240,40 @@ impl CliStack {
         .await
     }
 
+    pub async fn update_product_capability(
+        &self,
+        reqeust: RequestCommon, 
+        product_id: String,
+        capability: Product_capability
+    ) -> NearResult<Product_info> {
+        self.request_and_wait(
+            reqeust,
+            topic_util::topics::hci_storage::NEAR_THING_STORAGE_PRODUCT_CAPABILITY_UPDATE_PUB.topic().clone(),
+            Product_capability_update {
+                product_id,
+                capability: Some(capability).into(),
+                ..Default::default()
+            }
+        )
+        .await
+    }
+
+    pub async fn query_thing_capability(
+        &self,
+        reqeust: RequestCommon, 
+        thing_ids: Vec<String>
+    ) -> NearResult<Thing_capability_list> {
+        self.request_and_wait(
+            reqeust,
+            topic_util::topics::hci_storage::NEAR_THING_STORAGE_THING_CAPABILITY_QUERY_PUB.topic().clone(),
+            Thing_capability_query {
+                thing_ids,
+                ..Default::default()
+            }
+        )
+        .await
+    }
+
     pub async fn update_thing(
         &self, 
         reqeust: RequestCommon, 
@@ -499,6 +535,170 @@ impl CliStack {
         .await
     }
 
+    pub async fn query_thing_telemetry(
+        &self, 
+        reqeust: RequestCommon, 
+        thing_id: String, 
+        begin: u64, 
+        end: u64, 
+        resolution: i32, 
+        properties: Vec<String>
+    ) -> NearResult<Thing_telemetry_series> {
+
+        let resolution = 
+            Thing_telemetry_resolution::from_i32(resolution)
+                .ok_or_else(|| {
+                    let error_string = format!("Undefined [{}] telemetry-resolution.", resolution);
+                    error!("{error_string}");
+                    NearError::new(ErrorCode::NEAR_ERROR_UNDEFINED, error_string)
+                })?;
+
+        self.request_and_wait(
+            reqeust,
+            topic_util::topics::hci_storage::NEAR_THING_STORAGE_TELEMETRY_QUERY_PUB.topic().clone(),
+            Thing_telemetry_query {
+                thing_id,
+                begin,
+                end,
+                resolution: resolution.into(),
+                properties,
+                ..Default::default()
+            }
+        )
+        .await
+    }
+
+    pub async fn add_location(
+        &self, 
+        reqeust: RequestCommon, 
+        parent_id: Option<String>, 
+        location_type: i32, 
+        location_name: String, 
+        location_img_idx: Option<u32>
+    ) -> NearResult<Location_info> {
+
+        let location_type = 
+            Location_type::from_i32(location_type)
+                .ok_or_else(|| {
+                    let error_string = format!("Undefined [{}] location-type.", location_type);
+                    error!("{error_string}");
+                    NearError::new(ErrorCode::NEAR_ERROR_UNDEFINED, error_string)
+                })?;
+
+        self.request_and_wait(
+            reqeust,
+            topic_util::topics::hci_storage::NEAR_THING_STORAGE_LOCATION_ADD_PUB.topic().clone(),
+            Location_add {
+                parent_id: parent_id.unwrap_or_default(),
+                type_: location_type.into(),
+                location_name,
+                location_img_idx: location_img_idx.unwrap_or_default(),
+                ..Default::default()
+            }
+        )
+        .await
+    }
+
+    pub async fn update_location(
+        &self, 
+        reqeust: RequestCommon, 
+        location_id: String, 
+        location_name: String, 
+        location_img_idx: Option<u32>
+    ) -> NearResult<Location_info> {
+        self.request_and_wait(
+            reqeust,
+            topic_util::topics::hci_storage::NEAR_THING_STORAGE_LOCATION_UPDATE_PUB.topic().clone(),
+            Location_info {
+                location_id,
+                location_name,
+                location_img_idx: location_img_idx.unwrap_or_default(),
+                ..Default::default()
+            }
+        )
+        .await
+    }
+
+    pub async fn remove_location(
+        &self, 
+        reqeust: RequestCommon, 
+        location_id: String
+    ) -> NearResult<Empty> {
+        self.request_and_wait(
+            reqeust,
+            topic_util::topics::hci_storage::NEAR_THING_STORAGE_LOCATION_REMOVE_PUB.topic().clone(),
+            location_id
+        )
+        .await
+    }
+
+    pub async fn query_location(
+        &self, 
+        reqeust: RequestCommon, 
+        location_id: String
+    ) -> NearResult<Location_info> {
+        self.request_and_wait(
+            reqeust,
+            topic_util::topics::hci_storage::NEAR_THING_STORAGE_LOCATION_QUERY_PUB.topic().clone(),
+            location_id
+        )
+        .await
+    }
+
+    pub async fn query_all_location(
+        &self, 
+        reqeust: RequestCommon, 
+        parent_id: Option<String>
+    ) -> NearResult<Location_info_list> {
+        self.request_and_wait(
+            reqeust,
+            topic_util::topics::hci_storage::NEAR_THING_STORAGE_LOCATION_QUERY_ALL_PUB.topic().clone(),
+            Location_query_all {
+                parent_id: parent_id.unwrap_or_default(),
+                ..Default::default()
+            }
+        )
+        .await
+    }
+
+    pub async fn update_location_things(
+        &self, 
+        reqeust: RequestCommon, 
+        location_id: String, 
+        insert: bool, 
+        thing_ids: Vec<String>
+    ) -> NearResult<Location_info> {
+        self.request_and_wait(
+            reqeust,
+            topic_util::topics::hci_storage::NEAR_THING_STORAGE_LOCATION_THINGS_UPDATE_PUB.topic().clone(),
+            Location_thing_update {
+                location_id,
+                op: if insert { Location_thing_op::insert } else { Location_thing_op::remove }.into(),
+                thing_ids,
+                ..Default::default()
+            }
+        )
+        .await
+    }
+
+    pub async fn ctrl_location(
+        &self, 
+        reqeust: RequestCommon, 
+        location_id: String, 
+        thing_data: HashMap<String, String>
+    ) -> NearResult<HciTaskId> {
+        self.request_and_wait(
+            reqeust,
+            topic_util::topics::hci_gateway::NEAR_THING_GATEWAY_LOCATION_CTRL_PUB.topic().clone(), 
+            Location_ctrl {
+                location_id,
+                data: thing_data,
+                ..Default::default()
+            }
+        )
+        .await
+    }
+
     pub async fn hci_search_thing(
         &self, 
         reqeust: RequestCommon, 
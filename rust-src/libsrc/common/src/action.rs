@@ -1,10 +1,11 @@
 
-use std::path::PathBuf;
+use std::{path::PathBuf, sync::Arc};
 
 use log::{error, info};
-use near_base::{NearResult, ErrorCode, NearError, DeviceObject, ExtentionObject, FileDecoder, PrivateKey, };
+use near_base::{NearResult, ErrorCode, NearError, DeviceObject, ExtentionObject, FileDecoder, PrivateKey, SecretStoreTrait, load_private_key, };
 use near_core::{LoggerBuilder, get_log_path, panic::PanicBuilder};
 use near_transport::ProcessTrait;
+use near_util::KEY_PASSPHRASE_ENV;
 
 use crate::{config::config, 
             RunMode, 
@@ -160,7 +161,7 @@ impl ProcessActionInner {
         let action = 
             if flag & CORE_FLAG > 0 {
                 if let RunMode::Core(core, core_private_key) = config.mode {
-                    ProcessActionInner::start_core(core, core_private_key, runtime_process_impl, process_impl).await
+                    ProcessActionInner::start_core(core, core_private_key, config.secret_store.clone(), runtime_process_impl, process_impl).await
                 } else {
                     let error_string = format!("Failed to startup, it isn't core-mode.");
                     error!("{error_string}");
@@ -212,6 +213,7 @@ impl ProcessActionInner {
     async fn start_core(
         core: PathBuf, 
         core_private_key: PathBuf, 
+        secret_store: Option<Arc<dyn SecretStoreTrait>>,
         runtime_process_impl: Box<dyn RuntimeProcessTrait>,
         process_impl: Option<Box<dyn ProcessTrait>>
     ) -> NearResult<ProcessAction> {
@@ -225,7 +227,19 @@ impl ProcessActionInner {
         info!("loading core from:{}", core.display());
 
         let core_service = DeviceObject::decode_from_file(&core)?;
-        let core_service_private_key = PrivateKey::decode_from_file(&core_private_key)?;
+        let core_service_private_key: PrivateKey = 
+            match secret_store {
+                Some(secret_store) => {
+                    let name = 
+                        core_private_key.file_name()
+                            .and_then(| name | name.to_str())
+                            .ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("invalid key file {}", core_private_key.display())))?;
+                    secret_store.load_key(name).await?
+                }
+                None => {
+                    load_private_key(&core_private_key, std::env::var(KEY_PASSPHRASE_ENV).ok().as_deref())?
+                }
+            };
 
         info!("core-id: {} startup...", core_service.object_id());
 
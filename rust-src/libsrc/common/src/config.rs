@@ -1,5 +1,7 @@
 
-use std::{sync::RwLock, path::PathBuf};
+use std::{sync::{Arc, RwLock}, path::PathBuf};
+
+use near_base::SecretStoreTrait;
 
 use near_core::LogLevel;
 
@@ -15,6 +17,7 @@ pub struct NearConfig {
     pub(crate) mode: RunMode,
     pub(crate) log_level: LogLevel,
     pub(crate) action: ProcessActionInner,
+    pub(crate) secret_store: Option<Arc<dyn SecretStoreTrait>>,
 }
 
 impl std::default::Default for NearConfig {
@@ -27,6 +30,7 @@ impl std::default::Default for NearConfig {
             #[cfg(not(debug_assertions))]
             log_level: LogLevel::Info,
             action: ProcessActionInner::Start(0),
+            secret_store: None,
         }
     }
 }
@@ -87,6 +91,11 @@ pub(crate) fn action() -> ProcessActionInner {
     CONFIG.read().unwrap().action
 }
 
+#[allow(unused)]
+pub(crate) fn set_secret_store(secret_store: Arc<dyn SecretStoreTrait>) {
+    CONFIG.write().unwrap().secret_store = Some(secret_store);
+}
+
 #[allow(unused)]
 pub(crate) fn config() -> NearConfig {
     CONFIG.read().unwrap().clone()
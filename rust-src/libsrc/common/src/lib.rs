@@ -8,6 +8,8 @@ mod routine_template;
 mod topic_runtime;
 mod process;
 
+use std::sync::Arc;
+
 use near_transport::ProcessTrait;
 pub use runtime_stack::RuntimeStack;
 pub use core_stack::CoreStack;
@@ -17,8 +19,8 @@ pub use topic_runtime::manager::{Manager as TopicRouineManager, TopicRoutineCbEv
 use action::{CORE_FLAG, RUNTIME_FLAG, ProcessActionInner, AUX_FLAG};
 use clap::command;
 use cmd::*;
-use config::{action, set_mode, set_log_level, set_service_name, set_action, RunMode, };
-use near_base::NearResult;
+use config::{action, set_mode, set_log_level, set_service_name, set_action, set_secret_store, RunMode, };
+use near_base::{NearResult, SecretStoreTrait};
 
 use process::process_mutex::SERVICE_NAME;
 
@@ -36,6 +38,7 @@ pub trait RuntimeProcessTrait: Send + Sync {
 pub struct ProcessCommandBuild {
     mode: RunMode,
     process_name: String,
+    secret_store: Option<Arc<dyn SecretStoreTrait>>,
 }
 
 impl ProcessCommandBuild {
@@ -43,6 +46,7 @@ impl ProcessCommandBuild {
         Self {
             mode: RunMode::Core(Default::default(), Default::default()),
             process_name: Default::default(),
+            secret_store: None,
         }
     }
 
@@ -50,6 +54,7 @@ impl ProcessCommandBuild {
         Self {
             mode: RunMode::Runtime(Default::default(), Default::default()),
             process_name: Default::default(),
+            secret_store: None,
         }
     }
 
@@ -57,6 +62,7 @@ impl ProcessCommandBuild {
         Self {
             mode: RunMode::Aux,
             process_name: Default::default(),
+            secret_store: None,
         }
     }
 
@@ -65,14 +71,24 @@ impl ProcessCommandBuild {
         self
     }
 
+    /// The core loads its private key from the secret store instead of the key file.
+    pub fn secret_store(mut self, secret_store: Arc<dyn SecretStoreTrait>) -> Self {
+        self.secret_store = Some(secret_store);
+        self
+    }
+
 }
 
 impl ProcessCommandBuild {
-    pub async fn launch(self, runtime_process_impl: Box<dyn RuntimeProcessTrait>, process_impl: Option<Box<dyn ProcessTrait>>) -> NearResult<ProcessAction> {
+    pub async fn launch(mut self, runtime_process_impl: Box<dyn RuntimeProcessTrait>, process_impl: Option<Box<dyn ProcessTrait>>) -> NearResult<ProcessAction> {
         SERVICE_NAME.lock().unwrap().init(&self.process_name);
 
         set_service_name(&self.process_name);
 
+        if let Some(secret_store) = self.secret_store.take() {
+            set_secret_store(secret_store);
+        }
+
         let matches = 
             command!(self.process_name)
                 .arg(LOGLEVEL_ARG.into_arg())
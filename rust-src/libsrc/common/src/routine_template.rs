@@ -3,7 +3,7 @@ use std::{task::Waker, sync::{Arc, Mutex}, future::Future};
 
 use log::{error, trace};
 
-use near_base::{NearResult, Serialize, Deserialize, NearError};
+use near_base::{NearResult, Serialize, Deserialize, NearError, ObjectId};
 use near_transport::{HeaderMeta, EventResult, Routine, RoutineWrap, RequestorMeta};
 use near_util::Topic;
 
@@ -76,6 +76,37 @@ where T: Serialize + Deserialize + Send + Sync + std::default::Default + Clone +
         })
     }
 
+    pub async fn call_with_target<R: Serialize>(
+        target: ObjectId, 
+        topic: Topic, 
+        raw_data: R
+    ) -> NearResult<impl Future<Output = NearResult<T>>> {
+        trace!("RoutineTemplate::call_with_target: target: {}, topic: {}", target, topic);
+
+        let raw_data_req = protos::RawObjectHelper::encode_with_raw(raw_data)?;
+
+        let result = Arc::new(Mutex::new(RoutineResult::<T>{ value: None, waker: None }));
+
+        RuntimeStack::get_instance()
+            .stack()
+            .post_message(
+                RequestorMeta {
+                    to: Some(target),
+                    topic: Some(topic),
+                    ..Default::default()
+                },
+                raw_data_req, 
+                Some(RoutineWrap::new(Box::new(RoutineTemplate{
+                    result: result.clone(),
+                })))
+            )
+            .await?;
+
+        Ok(RoutineResultFuture {
+            result
+        })
+    }
+
     pub async fn call_with_headermeta<R: Serialize>(
         header_meta: &HeaderMeta, 
         topic: Topic, 
@@ -133,6 +133,13 @@ impl Manager {
         }
     }
 
+    /// The message type of the registered topic.
+    pub fn message_type(&self, topic: &Topic) -> Option<MessageType> {
+        self.0.topics.read().unwrap()
+            .get(topic)
+            .and_then(| value | value.mt)
+    }
+
     pub fn call(&self, topic: &TopicRef<'_>) -> NearResult<Box<dyn RoutineEventTrait>> {
         let routine =
             match self.0.topics.read().unwrap()
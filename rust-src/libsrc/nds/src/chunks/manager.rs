@@ -1,26 +1,54 @@
 
-use std::{sync::{Arc, RwLock}, collections::{BTreeMap, btree_map::Entry}, path::PathBuf};
+use std::{sync::{Arc, RwLock, atomic::{AtomicU64, Ordering}}, collections::{BTreeMap, btree_map::Entry}, path::PathBuf};
 
-use log::info;
+use log::{info, error};
 use near_base::{ChunkId, NearResult, file::FileObject, ErrorCode};
 use near_core::get_service_path;
 
 use crate::inc::{ChunkReaderTrait, ChunkWriterTrait};
 
-use super::{ChunkView, super::NdsStack, source::{ChunkFromTrack, ChunkFromCache}, source::SourceManager, store::MemChunk, ChunkAccess};
+use super::{ChunkView, super::NdsStack, source::{ChunkFromTrack, ChunkFromCache}, source::SourceManager, 
+            store::{MemChunk, LocalChunkStore, MemChunkStore, StoreStat}, ChunkAccess};
 
 struct ManagerImpl {
     stack: NdsStack,
     views: RwLock<BTreeMap<ChunkId, ChunkView>>,
+    local_store: LocalChunkStore,
+    mem_store: MemChunkStore,
+    dedup_chunks: AtomicU64,
+    dedup_bytes: AtomicU64,
 }
 
 pub struct Manager(Arc<ManagerImpl>);
 
 impl Manager {
     pub fn new(stack: NdsStack) -> Self {
+        let config = &stack.nds_config().store;
+        let local_store = LocalChunkStore::open(&stack.nds_config().data_path, config);
+        let mem_store = MemChunkStore::new(config);
+
+        if let Some(interval) = config.scrub_interval {
+            let local_store = local_store.clone();
+            let mem_store = mem_store.clone();
+
+            async_std::task::spawn(async move {
+                loop {
+                    async_std::task::sleep(interval).await;
+
+                    for chunk in local_store.scrub().await {
+                        mem_store.remove(&chunk);
+                    }
+                }
+            });
+        }
+
         Self(Arc::new(ManagerImpl{
             stack,
             views: RwLock::new(BTreeMap::new()),
+            local_store,
+            mem_store,
+            dedup_chunks: AtomicU64::new(0),
+            dedup_bytes: AtomicU64::new(0),
         }))
     }
 
@@ -37,6 +65,30 @@ impl Manager {
         let view = {
             match self.view_of(chunk) {
                 Some(view) => view,
+                None if matches!(access, ChunkAccess::Read) => {
+                    // the stored chunk is cached in the memory, it isn't kept as the view.
+                    let content = match self.0.mem_store.get(chunk) {
+                        Some(content) => content,
+                        None => {
+                            let content = match self.0.local_store.load(chunk).await {
+                                Ok(content) => Ok(content),
+                                Err(err) if err.errno() == ErrorCode::NEAR_ERROR_NOTFOUND => {
+                                    ChunkFromCache::from(chunk)
+                                        .path(&get_service_path(self.0.stack.service_name()))
+                                        .get_chunk()
+                                        .await
+                                }
+                                Err(err) => Err(err),
+                            }?;
+
+                            self.0.mem_store.insert(content.clone());
+                            content
+                        }
+                    };
+
+                    info!("Sucessful create_view for content = {}, access = readonly", content);
+                    ChunkView::with_readonly(chunk.clone(), content.clone_as_reader())
+                }
                 None => {
                     let content = match ChunkFromCache::from(chunk)
                                                     .path(&get_service_path(self.0.stack.service_name()))
@@ -81,9 +133,82 @@ impl Manager {
         Ok(view)
     }
 
+    /// drop the view of the canceled download, the readonly views are kept for uploading.
+    pub fn remove_write_view(&self, chunk: &ChunkId) {
+        let views = &mut *self.0.views.write().unwrap();
+
+        if let Some(view) = views.get(chunk) {
+            if let ChunkAccess::Write = view.access_mode() {
+                views.remove(chunk);
+            }
+        }
+    }
+
+    /// Save the downloaded chunk into the store, and its write view is dropped.
+    pub async fn store_chunk(&self, view: &ChunkView) -> NearResult<()> {
+        let r = self.0.local_store.save(view.chunk(), view).await;
+
+        if let Err(e) = r.as_ref() {
+            error!("failed store {} with err = {e}", view.chunk());
+        }
+
+        self.remove_write_view(view.chunk());
+        r
+    }
+
+    /// True if the chunk is in the store or it's read from the tracked file.
+    pub fn has_chunk(&self, chunk: &ChunkId) -> bool {
+        if self.0.local_store.contains(chunk) {
+            return true;
+        }
+
+        match self.view_of(chunk) {
+            Some(view) => matches!(view.access_mode(), ChunkAccess::Read),
+            None => false,
+        }
+    }
+
+    /// The chunk is shared with the other file, it isn't downloaded again.
+    pub fn on_dedup(&self, chunk: &ChunkId) {
+        self.0.dedup_chunks.fetch_add(1, Ordering::SeqCst);
+        self.0.dedup_bytes.fetch_add(chunk.len() as u64, Ordering::SeqCst);
+    }
+
+    /// The pinned chunks are never evicted from the stores.
+    pub fn pin(&self, chunk: &ChunkId) {
+        self.0.local_store.pin(chunk);
+        self.0.mem_store.pin(chunk);
+    }
+
+    pub fn unpin(&self, chunk: &ChunkId) {
+        self.0.local_store.unpin(chunk);
+        self.0.mem_store.unpin(chunk);
+    }
+
+    pub fn pin_file(&self, file: &FileObject) {
+        file.body().content().chunk_list().iter().for_each(| chunk | self.pin(chunk));
+    }
+
+    pub fn unpin_file(&self, file: &FileObject) {
+        file.body().content().chunk_list().iter().for_each(| chunk | self.unpin(chunk));
+    }
+
+    pub fn store_stat(&self) -> StoreStat {
+        let mut stat = StoreStat::default();
+
+        self.0.local_store.fill_stat(&mut stat);
+        self.0.mem_store.fill_stat(&mut stat);
+        stat.dedup_chunks = self.0.dedup_chunks.load(Ordering::SeqCst);
+        stat.dedup_bytes = self.0.dedup_bytes.load(Ordering::SeqCst);
+
+        stat
+    }
+
     /// track file
     pub async fn track_file(&self, file: &FileObject, path: &PathBuf) -> NearResult<()> {
         let track = ChunkFromTrack::open_with_file(path, file).await?;
+        self.pin_file(file);
+
         let views = &mut self.0.views.write().unwrap();
         let track_chunks = track.chunks();
 
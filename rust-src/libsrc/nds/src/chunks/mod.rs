@@ -7,6 +7,7 @@ mod source;
 
 pub use manager::Manager;
 pub use view::{ChunkView, };
+pub use store::{MemChunk, StoreConfig, StoreStat, EvictPolicy};
 
 #[repr(u16)]
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, )]
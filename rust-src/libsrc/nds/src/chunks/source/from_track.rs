@@ -2,7 +2,7 @@
 use std::{collections::{BTreeMap, btree_map::Entry}, io::SeekFrom, path::PathBuf, sync::{Arc, RwLock}};
 
 use async_std::{fs::File, io::{prelude::SeekExt, ReadExt}};
-use near_base::{ChunkId, NearResult, file::FileObject, CHUNK_MAX_LEN, NearError, ErrorCode};
+use near_base::{ChunkId, NearResult, file::FileObject, NearError, ErrorCode};
 use near_core::near_error;
 use near_util::ReadWithLimit;
 
@@ -19,12 +19,13 @@ struct ChunkListDescImpl {
 impl From<&FileObject> for ChunkListDescImpl {
     fn from(file: &FileObject) -> Self {
         let mut chunks = BTreeMap::new();
+        let mut start = 0u64;
 
-        for (index, chunk) in file.body().content().chunk_list().iter().enumerate() {
-            let chunk_len = chunk.len() as u64;
-            let start = index as u64 * CHUNK_MAX_LEN;
-            let end = start + chunk_len;
-            chunks.insert(chunk.clone(), (start, end));
+        // the chunks may be content defined, so they aren't always CHUNK_MAX_LEN.
+        for chunk in file.body().content().chunk_list().iter() {
+            let end = start + chunk.len() as u64;
+            chunks.entry(chunk.clone()).or_insert((start, end));
+            start = end;
         }
 
         Self {
@@ -1,129 +1,83 @@
 
-use std::{sync::{Arc, }, 
-    };
+use std::{sync::{Arc, RwLock}, collections::BTreeMap, };
 
-use near_base::{ChunkId, NearResult, NearError, ErrorCode};
+use log::debug;
+use near_base::{ChunkId, now, };
 
-use crate::nds::inc::ChunkReaderTrait;
+use super::{MemChunk, StoreConfig, StoreStat, quota::Quota};
 
-struct Content {
-    data: Vec<u8>,
+struct Chunks {
+    quota: Quota,
+    chunks: BTreeMap<ChunkId, MemChunk>,
 }
 
+/// The chunks which are read for uploading, they are kept in the memory until the quota is used up.
 #[derive(Clone)]
-pub struct MemChunkStore(Arc<Content>);
-
-impl std::fmt::Display for MemChunkStore {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "MemChunkStore: len={}", self.0.data.len())
+pub struct MemChunkStore(Arc<RwLock<Chunks>>);
+
+impl MemChunkStore {
+    pub fn new(config: &StoreConfig) -> Self {
+        Self(Arc::new(RwLock::new(Chunks {
+            quota: Quota::new(config.memory_quota, config.policy),
+            chunks: BTreeMap::new(),
+        })))
     }
-}
 
-impl From<Vec<u8>> for MemChunkStore {
-    fn from(data: Vec<u8>) -> Self {
-        Self(Arc::new(Content { data }))
-    }
-}
+    pub fn get(&self, chunk: &ChunkId) -> Option<MemChunk> {
+        let chunks = &mut *self.0.write().unwrap();
 
-#[async_trait::async_trait]
-impl ChunkReaderTrait for MemChunkStore {
-    fn clone_as_reader(&self) -> Box<dyn ChunkReaderTrait> {
-        Box::new(self.clone())
-    }
-
-    async fn exists(&self, _: &ChunkId) -> bool {
-        true
-    }
-
-    async fn get(&self, _: &ChunkId, offset: usize, length: usize) -> NearResult<&[u8]> {
-        let data = self.0.data.as_slice();
-        let len = self.0.data.len();
-        if offset > len {
-            Err(NearError::new(ErrorCode::NEAR_ERROR_OUTOFLIMIT, format!("failed get context with expect offset, offset = {}, len = {}", offset, len)))
-        } else {
-            if offset + length > len {
-                Ok(&data[offset..len])
-            } else {
-                Ok(&data[offset..offset+length])
-            }
+        let content = chunks.chunks.get(chunk).cloned();
+        if content.is_some() {
+            chunks.quota.touch(chunk);
         }
+        content
     }
 
-}
-
-// impl MemChunkStore {
-//     pub fn new(chunk: ChunkId) -> Self {
-//         Self(Arc::new(Content {
-//             // ndc: NamedDataCache::clone(ndc), 
-//             // chunks: RwLock::new(BTreeMap::new())
-//         }))
-//     }
-
-//     pub async fn add(&self, id: ChunkId, chunk: Arc<Vec<u8>>) -> NearResult<()> {
-//         let request = InsertChunkRequest {
-//             chunk_id: id.to_owned(),
-//             state: ChunkState::Ready,
-//             ref_objects: None,
-//             trans_sessions: None,
-//             flags: 0,
-//         };
-
-//         let _ = self.0.ndc.insert_chunk(&request).await.map_err(|e| {
-//             error!("record file chunk to ndc error! chunk={}, {}",id, e);
-//             e
-//         });
-
-//         self.0.chunks.write().unwrap().insert(id, chunk);
-
-//         Ok(())
-//     }
-// }
-
-
-// #[async_trait]
-// impl ChunkReader for MemChunkStore {
-//     fn clone_as_reader(&self) -> Box<dyn ChunkReader> {
-//         Box::new(self.clone())
-//     }
+    /// The chunk isn't cached if the store is full of the pinned chunks.
+    pub fn insert(&self, content: MemChunk) {
+        let chunks = &mut *self.0.write().unwrap();
+        let chunk = content.chunk().clone();
 
-//     async fn exists(&self, chunk: &ChunkId) -> bool {
-//         self.0.chunks.read().unwrap().get(chunk).is_some()
-//     }
-
-//     async fn get(&self, chunk: &ChunkId) -> BuckyResult<Arc<Vec<u8>>> {
-//         self.0.chunks.read().unwrap().get(chunk).cloned()
-//             .ok_or_else(|| BuckyError::new(BuckyErrorCode::NotFound, "chunk not exists"))
-//     }
-// }
-
-
-// #[async_trait]
-// impl ChunkWriter for MemChunkStore {
-//     fn clone_as_writer(&self) -> Box<dyn ChunkWriter> {
-//         Box::new(self.clone())
-//     }
-
-//     async fn err(&self, _e: BuckyErrorCode) -> BuckyResult<()> {
-//         Ok(())
-//     }
-
-
-//     async fn write(&self, chunk: &ChunkId, content: Arc<Vec<u8>>) -> BuckyResult<()> {
-//         if chunk.len() == 0 {
-//             return Ok(());
-//         }
+        if chunks.chunks.contains_key(&chunk) {
+            return;
+        }
 
-//         self.add(chunk.clone(), content).await
-//     }
+        match chunks.quota.evict(chunk.len() as u64) {
+            Some(victims) => {
+                for victim in victims.iter() {
+                    chunks.chunks.remove(victim);
+                }
 
-//     async fn finish(&self) -> BuckyResult<()> {
-//         // do nothing
-//         Ok(())
-//     }
-// }
+                chunks.quota.insert(chunk.clone(), chunk.len() as u64, now());
+                chunks.chunks.insert(chunk, content);
+            }
+            None => {
+                debug!("the memory store is full, {} isn't cached.", chunk);
+            }
+        }
+    }
 
+    pub fn remove(&self, chunk: &ChunkId) {
+        let chunks = &mut *self.0.write().unwrap();
 
+        chunks.quota.remove(chunk);
+        chunks.chunks.remove(chunk);
+    }
 
+    pub fn pin(&self, chunk: &ChunkId) {
+        self.0.write().unwrap().quota.pin(chunk)
+    }
 
+    pub fn unpin(&self, chunk: &ChunkId) {
+        self.0.write().unwrap().quota.unpin(chunk)
+    }
 
+    pub(crate) fn fill_stat(&self, stat: &mut StoreStat) {
+        let chunks = self.0.read().unwrap();
 
+        stat.memory_chunks = chunks.quota.len();
+        stat.memory_bytes = chunks.quota.used();
+        stat.memory_quota = chunks.quota.limit();
+        stat.memory_evictions = chunks.quota.evictions();
+    }
+}
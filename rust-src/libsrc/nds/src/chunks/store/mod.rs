@@ -1,9 +1,11 @@
 
-// mod mem_chunk_store;
-// mod local_chunk_store;
+mod mem_chunk_store;
+mod local_chunk_store;
+mod quota;
 
-// pub use mem_chunk_store::*;
-// pub use local_chunk_store::*;
+pub use mem_chunk_store::MemChunkStore;
+pub use local_chunk_store::LocalChunkStore;
+pub use quota::EvictPolicy;
 mod manager;
 mod mem_chunk;
 
@@ -15,3 +17,44 @@ pub use manager::*;
 pub use mem_chunk::MemChunk;
 // pub use local_chunk::LocalChunk;
 // pub use writer::ChunkWriter;
+
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct StoreConfig {
+    /// the bytes of the chunks saved in the data path.
+    pub disk_quota: u64,
+    /// the bytes of the chunks kept in the memory for uploading.
+    pub memory_quota: u64,
+    pub policy: EvictPolicy,
+    /// how often the saved chunks are re-hashed, none if it's disabled.
+    pub scrub_interval: Option<Duration>,
+}
+
+impl std::default::Default for StoreConfig {
+    fn default() -> Self {
+        Self {
+            disk_quota: 10 * 1024 * 1024 * 1024,
+            memory_quota: 256 * 1024 * 1024,
+            policy: EvictPolicy::Lru,
+            scrub_interval: Some(Duration::from_secs(24 * 60 * 60)),
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct StoreStat {
+    pub disk_chunks: usize,
+    pub disk_bytes: u64,
+    pub disk_quota: u64,
+    pub disk_evictions: u64,
+    pub memory_chunks: usize,
+    pub memory_bytes: u64,
+    pub memory_quota: u64,
+    pub memory_evictions: u64,
+    pub pinned: usize,
+    pub corrupted: u64,
+    /// the chunks which were found in the store instead of being downloaded again.
+    pub dedup_chunks: u64,
+    pub dedup_bytes: u64,
+}
@@ -0,0 +1,184 @@
+
+use std::collections::BTreeMap;
+
+use near_base::{ChunkId, Timestamp, now};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EvictPolicy {
+    /// the chunk which hasn't been read for the longest time is evicted first.
+    Lru,
+    /// the chunk which has been read the least times is evicted first.
+    Lfu,
+}
+
+struct Entry {
+    size: u64,
+    last_access: Timestamp,
+    hits: u64,
+}
+
+/// The usage of the store, it picks the unpinned chunks to evict when the store is full.
+pub struct Quota {
+    limit: u64,
+    used: u64,
+    policy: EvictPolicy,
+    entries: BTreeMap<ChunkId, Entry>,
+    // the chunk can be pinned before it's stored.
+    pins: BTreeMap<ChunkId, u32>,
+    evictions: u64,
+}
+
+impl Quota {
+    pub fn new(limit: u64, policy: EvictPolicy) -> Self {
+        Self {
+            limit,
+            used: 0,
+            policy,
+            entries: BTreeMap::new(),
+            pins: BTreeMap::new(),
+            evictions: 0,
+        }
+    }
+
+    #[inline]
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    #[inline]
+    pub fn used(&self) -> u64 {
+        self.used
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    pub fn evictions(&self) -> u64 {
+        self.evictions
+    }
+
+    pub fn pinned(&self) -> usize {
+        self.entries.keys().filter(| chunk | self.is_pinned(chunk)).count()
+    }
+
+    #[inline]
+    pub fn contains(&self, chunk: &ChunkId) -> bool {
+        self.entries.contains_key(chunk)
+    }
+
+    pub fn chunks(&self) -> Vec<ChunkId> {
+        self.entries.keys().cloned().collect()
+    }
+
+    pub fn insert(&mut self, chunk: ChunkId, size: u64, last_access: Timestamp) {
+        if let Some(prev) = self.entries.insert(chunk, Entry { size, last_access, hits: 0 }) {
+            self.used -= prev.size;
+        }
+        self.used += size;
+    }
+
+    pub fn touch(&mut self, chunk: &ChunkId) {
+        if let Some(entry) = self.entries.get_mut(chunk) {
+            entry.last_access = now();
+            entry.hits += 1;
+        }
+    }
+
+    pub fn remove(&mut self, chunk: &ChunkId) -> bool {
+        match self.entries.remove(chunk) {
+            Some(entry) => {
+                self.used -= entry.size;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn pin(&mut self, chunk: &ChunkId) {
+        *self.pins.entry(chunk.clone()).or_insert(0) += 1;
+    }
+
+    pub fn unpin(&mut self, chunk: &ChunkId) {
+        if let Some(pins) = self.pins.get_mut(chunk) {
+            *pins -= 1;
+            if *pins == 0 {
+                self.pins.remove(chunk);
+            }
+        }
+    }
+
+    #[inline]
+    pub fn is_pinned(&self, chunk: &ChunkId) -> bool {
+        self.pins.contains_key(chunk)
+    }
+
+    /// Evict the unpinned chunks so that the incoming size fits in the limit, and return them.
+    /// None if it doesn't fit even all of the unpinned chunks are evicted, nothing is evicted then.
+    pub fn evict(&mut self, incoming: u64) -> Option<Vec<ChunkId>> {
+        if self.used + incoming <= self.limit {
+            return Some(vec![]);
+        }
+
+        let mut candidates: Vec<(&ChunkId, &Entry)> =
+            self.entries
+                .iter()
+                .filter(| (chunk, _) | !self.is_pinned(chunk))
+                .collect();
+
+        match self.policy {
+            EvictPolicy::Lru => candidates.sort_by_key(| (_, entry) | entry.last_access),
+            EvictPolicy::Lfu => candidates.sort_by_key(| (_, entry) | (entry.hits, entry.last_access)),
+        }
+
+        let mut freed = 0;
+        let mut victims = vec![];
+        for (chunk, entry) in candidates {
+            if self.used - freed + incoming <= self.limit {
+                break;
+            }
+            freed += entry.size;
+            victims.push(chunk.clone());
+        }
+
+        if self.used - freed + incoming > self.limit {
+            return None;
+        }
+
+        for chunk in victims.iter() {
+            self.remove(chunk);
+        }
+        self.evictions += victims.len() as u64;
+
+        Some(victims)
+    }
+}
+
+#[test]
+fn test_quota() {
+    use near_base::hash_data;
+
+    let chunk = | text: &str | ChunkId::from((hash_data(text.as_bytes()), 10));
+    let (a, b, c) = (chunk("a"), chunk("b"), chunk("c"));
+
+    let mut quota = Quota::new(25, EvictPolicy::Lru);
+    quota.insert(a.clone(), 10, 1);
+    quota.insert(b.clone(), 10, 2);
+    quota.touch(&a);
+
+    // b is the least recently used
+    assert_eq!(quota.evict(10).unwrap(), vec![b.clone()]);
+    quota.insert(c.clone(), 10, now());
+
+    // the pinned chunks aren't evicted
+    quota.pin(&a);
+    quota.pin(&c);
+    assert!(quota.evict(10).is_none());
+    assert_eq!(quota.used(), 20);
+
+    quota.unpin(&c);
+    assert_eq!(quota.evict(10).unwrap(), vec![c]);
+    assert_eq!(quota.evictions(), 2);
+}
@@ -2,8 +2,11 @@
 pub const PRIMARY_TOPIC_NDS_LABEL: &'static str = "nds";
 
 pub const SECONDARY_TOPIC_NDS_FILE_LABEL: &'static str = "sync-file";
+pub const SECONDARY_TOPIC_NDS_DIR_LABEL: &'static str = "sync-dir";
 pub const SECONDARY_TOPIC_NDS_INTEREST: &'static str = "interest";
 pub const SECONDARY_TOPIC_NDS_PIECE: &'static str = "piece";
+pub const SECONDARY_TOPIC_NDS_CONTROL: &'static str = "control";
+pub const SECONDARY_TOPIC_NDS_CONFIG: &'static str = "config";
 
 // pub const SECONDARY_TOPIC_NDS_CHUNK_LABEL: &'static str = "chunk";
 // pub const SECONDARY_TOPIC_NDS_CHUNKLIST_LABEL: &'static str = "chunklist";
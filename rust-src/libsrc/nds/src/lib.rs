@@ -6,14 +6,20 @@ mod inc;
 mod tasks;
 mod chunks;
 mod statistic;
+mod transfer;
 // pub mod topic_routine;
 
 use near_base::{NearError, NearResult};
 pub use stack::{Stack as NdsStack, Config as NdsConfig};
-pub use tasks::{DownloadSource, SingleDownloadSource, MultiDownloadSource};
+pub use tasks::{DownloadSource, SingleDownloadSource, MultiDownloadSource, RateLimits as NdsRateLimits};
+pub use transfer::Transfer as NdsTransfer;
+pub use statistic::BytePerfStatPtr as NdsStat;
+pub use chunks::{StoreConfig as NdsStoreConfig, StoreStat as NdsStoreStat, EvictPolicy as NdsEvictPolicy};
+pub use near_util::{Chunking as NdsChunking, CdcParams as NdsCdcParams};
 
 pub enum NdsState {
     Prepair(near_base::file::FileObject),
     Pending(statistic::BytePerfStatPtr),
+    Paused(statistic::BytePerfStatPtr),
     Finished(NearResult<()>),
 }
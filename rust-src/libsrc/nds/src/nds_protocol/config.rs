@@ -0,0 +1,75 @@
+
+use near_base::{Deserialize, Serialize, NearResult, };
+
+use crate::tasks::RateLimits;
+
+// adjust the rate limits of the stack at runtime, the rates are bytes per second, 0 is unlimited.
+#[derive(Clone, Default)]
+pub struct RateLimitMessage {
+    pub upload: u64,
+    pub download: u64,
+    pub session_upload: u64,
+    pub session_download: u64,
+}
+
+impl Serialize for RateLimitMessage {
+    fn raw_capacity(&self) -> usize {
+        self.upload.raw_capacity() +
+        self.download.raw_capacity() +
+        self.session_upload.raw_capacity() +
+        self.session_download.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = self.upload.serialize(buf)?;
+        let buf = self.download.serialize(buf)?;
+        let buf = self.session_upload.serialize(buf)?;
+        let buf = self.session_download.serialize(buf)?;
+
+        Ok(buf)
+    }
+
+}
+
+impl Deserialize for RateLimitMessage {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (upload, buf) = u64::deserialize(buf)?;
+        let (download, buf) = u64::deserialize(buf)?;
+        let (session_upload, buf) = u64::deserialize(buf)?;
+        let (session_download, buf) = u64::deserialize(buf)?;
+
+        Ok((Self{
+            upload, download, session_upload, session_download,
+        }, buf))
+    }
+
+}
+
+impl From<RateLimits> for RateLimitMessage {
+    fn from(v: RateLimits) -> Self {
+        Self {
+            upload: v.upload,
+            download: v.download,
+            session_upload: v.session_upload,
+            session_download: v.session_download,
+        }
+    }
+}
+
+impl From<RateLimitMessage> for RateLimits {
+    fn from(v: RateLimitMessage) -> Self {
+        Self {
+            upload: v.upload,
+            download: v.download,
+            session_upload: v.session_upload,
+            session_download: v.session_download,
+        }
+    }
+}
+
+impl std::fmt::Display for RateLimitMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "upload: {}, download: {}, session_upload: {}, session_download: {}", self.upload, self.download, self.session_upload, self.session_download)
+    }
+}
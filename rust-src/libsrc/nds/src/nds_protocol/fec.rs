@@ -0,0 +1,238 @@
+
+use std::collections::BTreeMap;
+
+use near_base::{NearResult, NearError, ErrorCode, Serialize, Deserialize, RawFixedBytes, };
+
+/// The pieces are coded in blocks, each block has `source` pieces and `repair` pieces,
+/// and any `source` pieces of them rebuild the block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FecParams {
+    pub source: u8,
+    pub repair: u8,
+}
+
+impl std::default::Default for FecParams {
+    fn default() -> Self {
+        Self {
+            source: 32,
+            repair: 4,
+        }
+    }
+}
+
+impl FecParams {
+    /// The symbols of one block are numbered in GF(256), so they can't be more than 256.
+    pub fn check(&self) -> NearResult<()> {
+        if self.source == 0 || self.source as usize + self.repair as usize > 256 {
+            Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("invalid fec params {}", self)))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl std::fmt::Display for FecParams {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}+{}", self.source, self.repair)
+    }
+}
+
+impl RawFixedBytes for FecParams {
+    fn raw_bytes() -> usize {
+        u8::raw_bytes() + u8::raw_bytes()
+    }
+}
+
+impl Serialize for FecParams {
+    fn raw_capacity(&self) -> usize {
+        self.source.raw_capacity() +
+        self.repair.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = self.source.serialize(buf)?;
+        let buf = self.repair.serialize(buf)?;
+
+        Ok(buf)
+    }
+}
+
+impl Deserialize for FecParams {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (source, buf) = u8::deserialize(buf)?;
+        let (repair, buf) = u8::deserialize(buf)?;
+
+        Ok((Self{
+            source, repair,
+        }, buf))
+    }
+}
+
+// GF(2^8) with the polynomial x^8 + x^4 + x^3 + x^2 + 1
+struct Galois {
+    exp: [u8; 512],
+    log: [u8; 256],
+}
+
+lazy_static::lazy_static! {
+    static ref GF: Galois = {
+        let mut exp = [0u8; 512];
+        let mut log = [0u8; 256];
+
+        let mut x = 1u16;
+        for i in 0..255 {
+            exp[i] = x as u8;
+            log[x as usize] = i as u8;
+            x <<= 1;
+            if x & 0x100 != 0 {
+                x ^= 0x11d;
+            }
+        }
+        for i in 255..512 {
+            exp[i] = exp[i - 255];
+        }
+
+        Galois { exp, log }
+    };
+}
+
+impl Galois {
+    #[inline]
+    fn mul(&self, a: u8, b: u8) -> u8 {
+        if a == 0 || b == 0 {
+            0
+        } else {
+            self.exp[self.log[a as usize] as usize + self.log[b as usize] as usize]
+        }
+    }
+
+    #[inline]
+    fn inv(&self, a: u8) -> u8 {
+        debug_assert!(a != 0);
+        self.exp[255 - self.log[a as usize] as usize]
+    }
+
+    /// dst += c * src
+    fn mul_add(&self, dst: &mut [u8], c: u8, src: &[u8]) {
+        if c == 0 {
+            return;
+        }
+
+        for (d, s) in dst.iter_mut().zip(src.iter()) {
+            *d ^= self.mul(c, *s);
+        }
+    }
+
+    /// The Cauchy matrix, every square sub-matrix of it is invertible.
+    #[inline]
+    fn cauchy(&self, source: usize, repair: usize, index: usize) -> u8 {
+        self.inv(((source + repair) ^ index) as u8)
+    }
+}
+
+/// Build the repair pieces of the block, the short source piece is padded with zero to `symbol_len`.
+pub fn encode_repairs(sources: &[Vec<u8>], repair: usize, symbol_len: usize) -> Vec<Vec<u8>> {
+    let gf = &*GF;
+
+    (0..repair)
+        .map(| r | {
+            let mut symbol = vec![0u8; symbol_len];
+            for (index, source) in sources.iter().enumerate() {
+                gf.mul_add(&mut symbol, gf.cauchy(sources.len(), r, index), source);
+            }
+            symbol
+        })
+        .collect()
+}
+
+/// Rebuild the missing source pieces of the block.
+/// `sources` are the received source pieces, and `repairs` are the received repair pieces,
+/// both of them are keyed by the index in the block and padded to `symbol_len`.
+/// None if there aren't enough pieces.
+pub fn decode_sources(count: usize,
+                      sources: &BTreeMap<usize, Vec<u8>>,
+                      repairs: &BTreeMap<usize, Vec<u8>>,
+                      symbol_len: usize) -> Option<BTreeMap<usize, Vec<u8>>> {
+    let gf = &*GF;
+
+    let missing: Vec<usize> = (0..count).filter(| index | !sources.contains_key(index)).collect();
+    if missing.is_empty() {
+        return Some(BTreeMap::new());
+    }
+    if repairs.len() < missing.len() {
+        return None;
+    }
+
+    let rows: Vec<usize> = repairs.keys().take(missing.len()).cloned().collect();
+
+    // remove the received sources from the repairs, the left is the missing sources multiplied by the matrix.
+    let mut matrix: Vec<Vec<u8>> = rows.iter().map(| r | missing.iter().map(| index | gf.cauchy(count, *r, *index)).collect()).collect();
+    let mut values: Vec<Vec<u8>> =
+        rows.iter()
+            .map(| r | {
+                let mut value = repairs.get(r).unwrap().clone();
+                value.resize(symbol_len, 0);
+                for (index, source) in sources.iter() {
+                    gf.mul_add(&mut value, gf.cauchy(count, *r, *index), source);
+                }
+                value
+            })
+            .collect();
+
+    // gauss-jordan elimination
+    let n = missing.len();
+    for col in 0..n {
+        let pivot = (col..n).find(| row | matrix[*row][col] != 0)?;
+        matrix.swap(col, pivot);
+        values.swap(col, pivot);
+
+        let inv = gf.inv(matrix[col][col]);
+        for c in 0..n {
+            matrix[col][c] = gf.mul(matrix[col][c], inv);
+        }
+        for b in values[col].iter_mut() {
+            *b = gf.mul(*b, inv);
+        }
+
+        for row in 0..n {
+            let factor = matrix[row][col];
+            if row == col || factor == 0 {
+                continue;
+            }
+
+            let pivot_row = matrix[col].clone();
+            for c in 0..n {
+                matrix[row][c] ^= gf.mul(factor, pivot_row[c]);
+            }
+            let pivot_value = values[col].clone();
+            gf.mul_add(&mut values[row], factor, &pivot_value);
+        }
+    }
+
+    Some(missing.into_iter().zip(values.into_iter()).collect())
+}
+
+#[test]
+fn test_fec() {
+    let symbol_len = 16;
+    let sources: Vec<Vec<u8>> = (0..5u8).map(| i | (0..symbol_len as u8).map(| b | b.wrapping_mul(31).wrapping_add(i * 7)).collect()).collect();
+    let repairs = encode_repairs(&sources, 3, symbol_len);
+
+    // lose 0, 2 and 4, keep the repair 1 and 2
+    let received: BTreeMap<usize, Vec<u8>> = [1, 3].iter().map(| i | (*i, sources[*i].clone())).collect();
+    let repairs: BTreeMap<usize, Vec<u8>> = [1, 2].iter().map(| r | (*r, repairs[*r].clone())).collect();
+    assert!(decode_sources(5, &received, &repairs, symbol_len).is_none());
+
+    let received: BTreeMap<usize, Vec<u8>> = [3].iter().map(| i | (*i, sources[*i].clone())).collect();
+    let all_repairs = encode_repairs(&sources, 3, symbol_len);
+    let repairs: BTreeMap<usize, Vec<u8>> = (0..3).map(| r | (r, all_repairs[r].clone())).collect();
+    assert!(decode_sources(5, &received, &repairs, symbol_len).is_none());
+
+    let received: BTreeMap<usize, Vec<u8>> = [1, 3].iter().map(| i | (*i, sources[*i].clone())).collect();
+    let recovered = decode_sources(5, &received, &repairs, symbol_len).unwrap();
+    assert_eq!(recovered.len(), 3);
+    for (index, data) in recovered {
+        assert_eq!(data, sources[index]);
+    }
+}
@@ -1,10 +1,17 @@
 
 mod sync_file;
+mod sync_dir;
 mod piece;
+mod fec;
+mod config;
 
 pub use sync_file::{SyncFileMessage};
+pub use sync_dir::{SyncDirMessage};
 pub use piece::{SessionData,
                 InterestMessage, InterestMessageResponse, 
-                ChunkEncodeDesc, PieceEncodeDesc, 
-                PieceControlCommand, 
-                PieceMessageBuilder, PieceMessage, PieceMessageResponse};
+                ChunkEncodeDesc, ChunkRange, PieceEncodeDesc, 
+                PieceControlCommand, PieceControlMessage, 
+                PieceMessageBuilder, EncodedPieceBuilder, PieceMessage, PieceMessageResponse};
+pub use fec::FecParams;
+pub use config::RateLimitMessage;
+pub(crate) use fec::{encode_repairs, decode_sources};
@@ -2,6 +2,8 @@
 use near_base::{ChunkId, Deserialize, Serialize, NearResult, NearError, ErrorCode, RawFixedBytes, };
 use near_transport::{ItfTrait, ItfBuilderTrait, };
 
+use super::fec::FecParams;
+
 #[derive(Clone, Copy, Default)]
 pub struct SessionData {
     pub session_id: u32,
@@ -114,20 +116,41 @@ fn test_interest_message() {
     let len = m.raw_capacity();
     let mut v = vec![0u8; len];
     let _ = m.serialize(v.as_mut_slice()).unwrap();
+
+    let m = InterestMessage {
+        encoder: ChunkEncodeDesc::Fec(ChunkRange { start: 1, end: 9 }, FecParams::default()),
+        ..m
+    };
+    let mut v = vec![0u8; m.raw_capacity()];
+    let _ = m.serialize(v.as_mut_slice()).unwrap();
+    let (m, _) = InterestMessage::deserialize(v.as_slice()).unwrap();
+    match m.encoder {
+        ChunkEncodeDesc::Fec(range, params) => {
+            assert_eq!((range.start, range.end), (1, 9));
+            assert_eq!(params, FecParams::default());
+        }
+        _ => unreachable!(),
+    }
 }
 
+/// The answer of the interest is the handshake of the session,
+/// the uploader tells whether it pushes the repair pieces of the FEC interest.
+#[derive(Clone, Default)]
 pub struct InterestMessageResponse {
     pub chunk: ChunkId,
+    pub fec: bool,
 }
 
 impl Serialize for InterestMessageResponse {
     fn raw_capacity(&self) -> usize {
-        self.chunk.raw_capacity()
+        self.chunk.raw_capacity() +
+        0u8.raw_capacity()
     }
 
     fn serialize<'a>(&self,
                      buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
         let buf = self.chunk.serialize(buf)?;
+        let buf = (self.fec as u8).serialize(buf)?;
 
         Ok(buf)
     }
@@ -138,17 +161,43 @@ impl Deserialize for InterestMessageResponse {
     fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
         let (chunk, buf) = ChunkId::deserialize(buf)?;
 
+        // the old peer doesn't answer it, it only knows the stream.
+        let (fec, buf) = 
+            if buf.is_empty() {
+                (false, buf)
+            } else {
+                let (fec, buf) = u8::deserialize(buf)?;
+                (fec != 0, buf)
+            };
+
         Ok((Self{
             chunk,
+            fec,
         }, buf))
     }
 
 }
 
+#[test]
+fn test_interest_response() {
+    let m = InterestMessageResponse { chunk: ChunkId::default(), fec: true };
+    let mut v = vec![0u8; m.raw_capacity()];
+    let _ = m.serialize(v.as_mut_slice()).unwrap();
+    assert!(InterestMessageResponse::deserialize(v.as_slice()).unwrap().0.fec);
+
+    // the answer of the old peer.
+    let mut v = vec![0u8; ChunkId::default().raw_capacity()];
+    let _ = ChunkId::default().serialize(v.as_mut_slice()).unwrap();
+    assert!(!InterestMessageResponse::deserialize(v.as_slice()).unwrap().0.fec);
+}
+
 #[derive(Clone)]
 pub enum ChunkEncodeDesc {
     // Stream(u32 /* chunk offset size */, u32 /* chunk count size */)
-    Stream(ChunkRange)
+    Stream(ChunkRange),
+    // The pieces of the range are pushed with the repair pieces, it's only interested
+    // when the uploader accepted the FEC in the answer of the former interest.
+    Fec(ChunkRange, FecParams),
 }
 
 impl std::default::Default for ChunkEncodeDesc {
@@ -163,6 +212,9 @@ impl std::fmt::Display for ChunkEncodeDesc {
             Self::Stream(range) => {
                 range.fmt(f)
             }
+            Self::Fec(range, params) => {
+                write!(f, "{}, fec: {}", range, params)
+            }
         }
     }
 }
@@ -209,11 +261,18 @@ impl ChunkEncodeDesc {
         Self::Stream(ChunkRange { start: 0, end: end as u32})
     }
 
+    pub fn range(&self) -> &ChunkRange {
+        match self {
+            Self::Stream(range) => range,
+            Self::Fec(range, _) => range,
+        }
+    }
+
 }
 
 impl RawFixedBytes for ChunkEncodeDesc {
     fn raw_bytes() -> usize {
-        u8::raw_bytes() + u32::raw_bytes() + u32::raw_bytes()
+        u8::raw_bytes() + u32::raw_bytes() + u32::raw_bytes() + FecParams::raw_bytes()
     }
 }
 
@@ -223,6 +282,9 @@ impl Serialize for ChunkEncodeDesc {
             Self::Stream(range) => {
                 1u8.raw_capacity() + range.start.raw_capacity() + range.end.raw_capacity()
             }
+            Self::Fec(range, params) => {
+                2u8.raw_capacity() + range.start.raw_capacity() + range.end.raw_capacity() + params.raw_capacity()
+            }
         }
     }
 
@@ -234,6 +296,14 @@ impl Serialize for ChunkEncodeDesc {
                 let buf = range.start.serialize(buf)?;
                 let buf = range.end.serialize(buf)?;
                 
+                Ok(buf)
+            }
+            Self::Fec(range, params) => {
+                let buf = 2u8.serialize(buf)?;
+                let buf = range.start.serialize(buf)?;
+                let buf = range.end.serialize(buf)?;
+                let buf = params.serialize(buf)?;
+
                 Ok(buf)
             }
         }
@@ -251,6 +321,12 @@ impl Deserialize for ChunkEncodeDesc {
                 let (end, buf) = u32::deserialize(buf)?;
                 Ok((Self::Stream(ChunkRange{start, end}), buf))
             }
+            2u8 => {
+                let (start, buf) = u32::deserialize(buf)?;
+                let (end, buf) = u32::deserialize(buf)?;
+                let (params, buf) = FecParams::deserialize(buf)?;
+                Ok((Self::Fec(ChunkRange{start, end}, params), buf))
+            }
             _ => {
                 Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("Cloud not match [{}] piece desc type", t)))
             }
@@ -261,6 +337,8 @@ impl Deserialize for ChunkEncodeDesc {
 #[derive(Clone, Copy)]
 pub enum PieceEncodeDesc {
     Range(u16 /*piece index*/, u16 /*piece size*/),
+    // the symbol less than the count is the source piece, the others are the repair pieces.
+    Fec(u16 /*first piece index of the block*/, u8 /*source piece count of the block*/, u8 /*symbol index*/, u16 /*piece size*/),
 }
 
 impl std::default::Default for PieceEncodeDesc {
@@ -271,15 +349,23 @@ impl std::default::Default for PieceEncodeDesc {
 
 impl RawFixedBytes for PieceEncodeDesc {
     fn raw_bytes() -> usize {
-        u8::raw_bytes() + u16::raw_bytes() + u16::raw_bytes()
+        u8::raw_bytes() + u16::raw_bytes() + u8::raw_bytes() + u8::raw_bytes() + u16::raw_bytes()
     }
 }
 
 impl PieceEncodeDesc {
+    /// The piece index and size, none if it's a repair piece.
     #[allow(unused)]
     pub fn to_range(&self) -> Option<(u16, u16)> {
         match &self {
             Self::Range(index, count) => Some((*index, *count)),
+            Self::Fec(first, count, symbol, size) => {
+                if symbol < count {
+                    Some((*first + *symbol as u16, *size))
+                } else {
+                    None
+                }
+            }
         }
     }
 }
@@ -290,6 +376,9 @@ impl Serialize for PieceEncodeDesc {
             Self::Range(index, count) => {
                 1u8.raw_capacity() + index.raw_capacity() + count.raw_capacity()
             }
+            Self::Fec(first, count, symbol, size) => {
+                2u8.raw_capacity() + first.raw_capacity() + count.raw_capacity() + symbol.raw_capacity() + size.raw_capacity()
+            }
         }
     }
 
@@ -302,6 +391,14 @@ impl Serialize for PieceEncodeDesc {
                 let buf = count.serialize(buf)?;
                 Ok(buf)
             }
+            Self::Fec(first, count, symbol, size) => {
+                let buf = 2u8.serialize(buf)?;
+                let buf = first.serialize(buf)?;
+                let buf = count.serialize(buf)?;
+                let buf = symbol.serialize(buf)?;
+                let buf = size.serialize(buf)?;
+                Ok(buf)
+            }
         }
     }
 
@@ -317,6 +414,13 @@ impl Deserialize for PieceEncodeDesc {
                 let (count, buf) = u16::deserialize(buf)?;
                 Ok((Self::Range(index, count), buf))
             }
+            2u8 => {
+                let (first, buf) = u16::deserialize(buf)?;
+                let (count, buf) = u8::deserialize(buf)?;
+                let (symbol, buf) = u8::deserialize(buf)?;
+                let (size, buf) = u16::deserialize(buf)?;
+                Ok((Self::Fec(first, count, symbol, size), buf))
+            }
             _ => {
                 Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("Cloud not match [{}] piece desc type", t)))
             }
@@ -330,6 +434,9 @@ impl std::fmt::Display for PieceEncodeDesc {
             Self::Range(index, length) => {
                 write!(f, "Range:{{index={}, length={}}}", index, length)
             }
+            Self::Fec(first, count, symbol, length) => {
+                write!(f, "Fec:{{first={}, count={}, symbol={}, length={}}}", first, count, symbol, length)
+            }
         }
     }
 }
@@ -431,8 +538,9 @@ impl<'a> ItfBuilderTrait for PieceMessageBuilder<'a> {
         for (index, &it) in r.iter().enumerate() {
             let desc = 
             match &self.encoder {
-                ChunkEncodeDesc::Stream(range) => {
-                    let r = PieceEncodeDesc::Range(index as u16, it.len() as u16);
+                ChunkEncodeDesc::Stream(range) | ChunkEncodeDesc::Fec(range, _) => {
+                    // the index is counted from the start of the interested range.
+                    let r = PieceEncodeDesc::Range((range.start as usize + index) as u16, it.len() as u16);
                     length += it.len();
                     r
                 }
@@ -450,6 +558,20 @@ impl<'a> ItfBuilderTrait for PieceMessageBuilder<'a> {
     }
 }
 
+/// The pieces which have been encoded by the uploader, such as the repair pieces.
+pub struct EncodedPieceBuilder {
+    pub pieces: Vec<PieceMessage>,
+}
+
+impl ItfBuilderTrait for EncodedPieceBuilder {
+
+    type R = PieceMessage;
+
+    fn build(&self) -> Vec<Self::R> {
+        self.pieces.clone()
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum PieceControlCommand {
     Continue,
@@ -501,6 +623,56 @@ impl Deserialize for PieceControlCommand {
     }
 }
 
+impl std::fmt::Display for PieceControlCommand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PieceControlCommand::Continue => write!(f, "continue"),
+            PieceControlCommand::Finish => write!(f, "finish"),
+            PieceControlCommand::Pause => write!(f, "pause"),
+            PieceControlCommand::Cancel => write!(f, "cancel"),
+        }
+    }
+}
+
+// The downloader controls the upload session with it.
+#[derive(Clone)]
+pub struct PieceControlMessage {
+    pub session_data: SessionData,
+    pub command: PieceControlCommand,
+}
+
+impl std::fmt::Display for PieceControlMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "session: {}, command: {}", self.session_data, self.command)
+    }
+}
+
+impl Serialize for PieceControlMessage {
+    fn raw_capacity(&self) -> usize {
+        self.session_data.raw_capacity() +
+        self.command.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = self.session_data.serialize(buf)?;
+        let buf = self.command.serialize(buf)?;
+
+        Ok(buf)
+    }
+}
+
+impl Deserialize for PieceControlMessage {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (session_data, buf) = SessionData::deserialize(buf)?;
+        let (command, buf) = PieceControlCommand::deserialize(buf)?;
+
+        Ok((Self{
+            session_data, command,
+        }, buf))
+    }
+}
+
 pub struct PieceMessageResponse {
     pub chunk: ChunkId,
     pub command: PieceControlCommand,
@@ -0,0 +1,47 @@
+
+use near_base::{dir::DirObject, Deserialize, Serialize, NearResult, };
+
+// sync the directory tree
+#[derive(Clone, Default)]
+pub struct SyncDirMessage {
+    pub dir: DirObject,
+}
+
+impl Serialize for SyncDirMessage {
+    fn raw_capacity(&self) -> usize {
+        self.dir.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = self.dir.serialize(buf)?;
+
+        Ok(buf)
+    }
+
+}
+
+impl Deserialize for SyncDirMessage {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (dir, buf) = DirObject::deserialize(buf)?;
+
+        Ok((Self{
+            dir,
+        }, buf))
+    }
+
+}
+
+impl From<DirObject> for SyncDirMessage {
+    fn from(v: DirObject) -> Self {
+        Self {
+            dir: v
+        }
+    }
+}
+
+impl std::fmt::Display for SyncDirMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.dir.fmt(f)
+    }
+}
@@ -1,21 +1,24 @@
 
-use std::{sync::Arc, path::PathBuf, };
+use std::{sync::Arc, path::{Path, PathBuf}, };
 
 use base::{MessageExpire, SubscribeMessage, DissubcribeMessage, };
-use common::{RuntimeStack, TopicRouineManager};
-use log::{trace, error};
-use near_base::{NearResult, ObjectId, NearError, ErrorCode, file::FileObject, ChunkId, };
+use common::{RuntimeStack, TopicRouineManager, TopicRoutineCbEventTrait, RoutineTemplate};
+use log::{trace, error, info};
+use near_base::{NearResult, ObjectId, NearError, ErrorCode, file::FileObject, ChunkId, ObjectBuilder, 
+                dir::{DirObject, DirDescContent, DirBodyContent, DirEntry}, };
 use near_core::get_data_path;
-use near_transport::{Stack as BaseStack, ProcessTrait, RoutineEventTrait, };
-use near_util::{FileBuilder, TOPIC_CORE_SUBSCRIBE, TOPIC_CORE_DISSUBSCRIBE};
+use near_transport::{ProcessTrait, RoutineEventTrait, RequestorMeta, SendPriority, };
+use near_util::{FileBuilder, Chunking, TOPIC_CORE_SUBSCRIBE, TOPIC_CORE_DISSUBSCRIBE};
 use near_util::{Topic, TopicBuilder, TopicStruct, TopicRef, };
 
 use crate::{tasks::DownloadRequestTrait, 
-            inc::{PRIMARY_TOPIC_NDS_LABEL, SECONDARY_TOPIC_NDS_FILE_LABEL, SECONDARY_TOPIC_NDS_INTEREST, SECONDARY_TOPIC_NDS_PIECE},
-            tasks::{Manager as TaskManager, DownloadSourceRef, SessionTrait},
-            chunks::Manager as ChunkManager,
-            nds_protocol::{PieceMessageBuilder, SyncFileMessage, InterestMessage, SessionData, ChunkEncodeDesc, },
-            stack_private::{OnNdsSyncFile, OnNdsInterest, OnNdsPieceData},
+            inc::{PRIMARY_TOPIC_NDS_LABEL, SECONDARY_TOPIC_NDS_FILE_LABEL, SECONDARY_TOPIC_NDS_DIR_LABEL, SECONDARY_TOPIC_NDS_INTEREST, SECONDARY_TOPIC_NDS_PIECE, SECONDARY_TOPIC_NDS_CONTROL, SECONDARY_TOPIC_NDS_CONFIG},
+            tasks::{Manager as TaskManager, DownloadSourceRef, SessionTrait, walk_files, mode_of, mtime_of, Limiter, RateLimits},
+            chunks::{Manager as ChunkManager, StoreConfig, StoreStat, ChunkAccess},
+            statistic::manager::Manager as StatisticManager,
+            nds_protocol::{PieceMessageBuilder, EncodedPieceBuilder, PieceMessage, SyncFileMessage, SyncDirMessage, InterestMessage, InterestMessageResponse, SessionData, ChunkEncodeDesc, PieceControlMessage, FecParams, },
+            stack_private::{OnNdsSyncFile, OnNdsSyncDir, OnNdsInterest, OnNdsPieceData, OnNdsControl, OnNdsConfig},
+            NdsTransfer, MultiDownloadSource,
     };
 
 lazy_static::lazy_static! {
@@ -25,6 +28,12 @@ lazy_static::lazy_static! {
         TopicStruct::try_from(topic).unwrap()
     };
 
+    static ref TOPIC_NDS_SYNC_DIR_PRI: Topic = TopicBuilder::new(PRIMARY_TOPIC_NDS_LABEL).secondary(SECONDARY_TOPIC_NDS_DIR_LABEL).build();
+    static ref TOPIC_NDS_SYNC_DIR: TopicStruct<'static> = {
+        let topic: &'static Topic = &TOPIC_NDS_SYNC_DIR_PRI;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
     static ref TOPIC_NDS_INTEREST_CHUNK_PRI: Topic = TopicBuilder::new(PRIMARY_TOPIC_NDS_LABEL).secondary(SECONDARY_TOPIC_NDS_INTEREST).build();
     static ref TOPIC_NDS_INTEREST_CHUNK: TopicStruct<'static> = {
         let topic: &'static Topic = &TOPIC_NDS_INTEREST_CHUNK_PRI;
@@ -36,19 +45,51 @@ lazy_static::lazy_static! {
         let topic: &'static Topic = &TOPIC_NDS_PIECE_DATA_PRI;
         TopicStruct::try_from(topic).unwrap()
     };
+
+    static ref TOPIC_NDS_CONTROL_PRI: Topic = TopicBuilder::new(PRIMARY_TOPIC_NDS_LABEL).secondary(SECONDARY_TOPIC_NDS_CONTROL).build();
+    static ref TOPIC_NDS_CONTROL: TopicStruct<'static> = {
+        let topic: &'static Topic = &TOPIC_NDS_CONTROL_PRI;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    static ref TOPIC_NDS_CONFIG_PRI: Topic = TopicBuilder::new(PRIMARY_TOPIC_NDS_LABEL).secondary(SECONDARY_TOPIC_NDS_CONFIG).build();
+    static ref TOPIC_NDS_CONFIG: TopicStruct<'static> = {
+        let topic: &'static Topic = &TOPIC_NDS_CONFIG_PRI;
+        TopicStruct::try_from(topic).unwrap()
+    };
 }
 
+/// how long the interest waits for the negotiated encoding of the source.
+const INTEREST_RESPONSE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
 #[derive(Clone)]
 pub struct Config {
-    #[allow(unused)]
-    /// chunk cache save path
+    /// chunk cache save path, the journals of the unfinished downloads are saved in it too.
     pub data_path: PathBuf,
+    /// the quota and the eviction of the chunks saved in the data path.
+    pub store: StoreConfig,
+    /// the directories synced from the others are materialised in it, each one is named by its name.
+    pub dir_path: PathBuf,
+    /// how the tracked files are split into chunks.
+    pub chunking: Chunking,
+    /// the initial rate limits, they could be changed by the config topic.
+    pub limits: RateLimits,
+    /// the repair pieces pushed and interested with, none if it's disabled, it's negotiated in the interest.
+    pub fec: Option<FecParams>,
+    /// the peers whose directories are applied besides the core and its owner.
+    pub trusted: Vec<ObjectId>,
 }
 
 impl std::default::Default for Config {
     fn default() -> Self {
         Self{
             data_path: get_data_path(),
+            store: StoreConfig::default(),
+            dir_path: get_data_path().join("dirs"),
+            chunking: Chunking::default(),
+            limits: RateLimits::default(),
+            fec: None,
+            trusted: vec![],
         }
     }
 }
@@ -57,7 +98,9 @@ struct StackComponents {
     // file_manager: FileManager,
     task_manager: TaskManager,
     chunk_manager: ChunkManager,
+    statistic_manager: StatisticManager,
     topic_manager: TopicRouineManager,
+    limiter: Limiter,
 }
 
 struct StackImpl {
@@ -88,9 +131,13 @@ impl Stack {
         c.components = Some(StackComponents {
             task_manager,
             chunk_manager,
+            statistic_manager: StatisticManager::new(),
             topic_manager: RuntimeStack::get_instance().topic_routine_manager().clone(),
+            limiter: Limiter::new(config.limits),
         });
 
+        ret.task_manager().restore_downloads();
+
         Ok(ret)
     }
 
@@ -109,11 +156,21 @@ impl Stack {
         &self.0.components.as_ref().unwrap().chunk_manager
     }
 
+    #[inline]
+    pub(crate) fn statistic_manager(&self) -> &StatisticManager {
+        &self.0.components.as_ref().unwrap().statistic_manager
+    }
+
     #[inline]
     pub(crate) fn topic_manager(&self) -> &TopicRouineManager {
         &self.0.components.as_ref().unwrap().topic_manager
     }
 
+    #[inline]
+    pub(crate) fn limiter(&self) -> &Limiter {
+        &self.0.components.as_ref().unwrap().limiter
+    }
+
     #[inline]
     pub(crate) fn runtime_stack(&self) -> &RuntimeStack {
         &self.0.runtime_stack
@@ -129,32 +186,33 @@ impl Stack {
 impl Stack {
 
     pub fn register_topic(&self) {
-        let arc_self = self.clone();
-        async_std::task::spawn(async move {
-            {
-                let my = arc_self.clone();
-                // sync file
-                let _ = 
-                arc_self.topic_manager()
-                        .register_topic_event(TOPIC_NDS_SYNC_FILE.topic(), OnNdsSyncFile::new(my.clone()))
-                        .await
-                        .map_err(| e | {
-                            error!("failed register {} topic with err = {e}", TOPIC_NDS_SYNC_FILE.topic());
-                            e
-                        });
-
-                // sync piece
-                let _ = 
-                arc_self.topic_manager()
-                        .register_topic_event(TOPIC_NDS_PIECE_DATA.topic(), OnNdsPieceData::new(my.clone()))
-                        .await
-                        .map_err(| e | {
-                            error!("failed register {} topic with err = {e}", TOPIC_NDS_PIECE_DATA.topic());
-                            e
-                        });
-
-            }
-        });
+        // the pieces yield to the control messages on the same tunnel.
+        self.runtime_stack()
+            .stack()
+            .set_topic_priority(&TOPIC_NDS_PIECE_DATA_PRI, SendPriority::Bulk);
+
+        let topics: [(&Topic, Box<dyn TopicRoutineCbEventTrait>); 5] = [
+            // sync file
+            (TOPIC_NDS_SYNC_FILE.topic(), Box::new(OnNdsSyncFile::new(self.clone()))),
+            // sync dir
+            (TOPIC_NDS_SYNC_DIR.topic(), Box::new(OnNdsSyncDir::new(self.clone()))),
+            // sync piece
+            (TOPIC_NDS_PIECE_DATA.topic(), Box::new(OnNdsPieceData::new(self.clone()))),
+            // control upload session
+            (TOPIC_NDS_CONTROL.topic(), Box::new(OnNdsControl::new(self.clone()))),
+            // rate limits
+            (TOPIC_NDS_CONFIG.topic(), Box::new(OnNdsConfig::new(self.clone()))),
+        ];
+
+        for (topic, event) in topics {
+            let _ = 
+                self.topic_manager()
+                    .register_public_topic(topic, move || event.on_topic_routine())
+                    .map_err(| e | {
+                        error!("failed register {topic} topic with err = {e}");
+                        e
+                    });
+        }
     }
 
     pub async fn track_from_file(&self, path: &PathBuf) -> NearResult<ObjectId> {
@@ -165,6 +223,7 @@ impl Stack {
         let file = 
             FileBuilder::new(path)
                 .author(Some(self.0.runtime_stack.local().object_id()))
+                .chunking(self.nds_config().chunking)
                 .build()
                 .await
                 .map_err(| err | {
@@ -180,11 +239,184 @@ impl Stack {
         Ok(file_id)
     }
 
+    /// Track all of the files under the directory, and sync the tree to the others.
+    pub async fn track_from_dir(&self, path: &PathBuf) -> NearResult<ObjectId> {
+        if !path.is_dir() {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_NOTFOUND, format!("[{}] is not a directory.", path.to_str().unwrap_or("None"))));
+        }
+
+        let name = 
+            path.file_name()
+                .and_then(| name | name.to_str())
+                .ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, "invalid directory, it isn't directory name."))?;
+        let author = self.0.runtime_stack.local().object_id();
+
+        let mut entries = vec![];
+        for (relative, file_path) in walk_files(path)? {
+            let metadata = async_std::fs::metadata(&file_path).await?;
+            let file = 
+                FileBuilder::new(&file_path)
+                    .author(Some(author))
+                    .chunking(self.nds_config().chunking)
+                    .build()
+                    .await
+                    .map_err(| err | {
+                        error!("{err}");
+                        err
+                    })?;
+
+            self.chunk_manager().track_file(&file, &file_path).await?;
+
+            entries.push(DirEntry::new(relative, mode_of(&metadata), mtime_of(&metadata), file));
+        }
+
+        let body = DirBodyContent::new(entries);
+        let dir = 
+            ObjectBuilder::new(DirDescContent::new(name.to_owned(), &body)?, body)
+                .update_desc(| desc | {
+                    desc.set_author(Some(author.clone()));
+                })
+                .build()?;
+        let dir_id = dir.object_id().clone();
+
+        info!("track [{}] as {}, files = {}", path.display(), dir_id, dir.body().content().entries().len());
+
+        self.sync_dir(dir).await?;
+
+        Ok(dir_id)
+    }
+
+    /// Materialise the directory under the root, only the files which are changed are downloaded,
+    /// and the files which aren't in the directory are removed.
+    pub async fn download_dir(&self, dir: DirObject, root: &Path, source: MultiDownloadSource) -> NearResult<()> {
+        self.task_manager().download_dir(dir, root, source).await
+    }
+
+    /// Download the file and wait until it's finished, it returns at once when all of its chunks are stored.
+    pub async fn fetch_file(&self, file: FileObject, source: MultiDownloadSource) -> NearResult<()> {
+        let chunk_manager = self.chunk_manager();
+        if file.body().content().chunk_list().iter().all(| chunk | chunk.len() == 0 || chunk_manager.has_chunk(chunk)) {
+            return Ok(());
+        }
+
+        let file_id = file.object_id().clone();
+        self.task_manager().download_file(file, source).await?;
+
+        match self.task_manager().file_task(&file_id) {
+            Some(task) => task.wait().await,
+            // it's finished in the meantime.
+            None => Ok(()),
+        }
+    }
+
+    /// Read the content of the file from the stored chunks.
+    pub async fn read_file(&self, file: &FileObject) -> NearResult<Vec<u8>> {
+        let mut content = Vec::with_capacity(file.desc().content().len() as usize);
+
+        for chunk in file.body().content().chunk_list().iter().filter(| chunk | chunk.len() > 0) {
+            let view = self.chunk_manager().create_view(chunk, ChunkAccess::Read).await?;
+            content.extend_from_slice(&view.read(0, chunk.len()).await?);
+        }
+
+        Ok(content)
+    }
+
+    /// The root of the synced directory in the dir path.
+    /// Only the directories from the core, its owner and the configured peers are applied,
+    /// the stale files of the root are removed by them.
+    pub(crate) fn is_trusted(&self, id: &ObjectId) -> bool {
+        let local = self.runtime_stack().local();
+        let core = self.runtime_stack().remote();
+
+        id == local.object_id() || 
+        id == core.object_id() || 
+        core.desc().owner() == Some(id) || 
+        local.desc().owner() == Some(id) || 
+        self.nds_config().trusted.contains(id)
+    }
+
+    pub(crate) fn dir_root(&self, dir: &DirObject) -> PathBuf {
+        let name = dir.desc().content().name();
+
+        if name.is_empty() || name == "." || name == ".." || name.contains(['/', '\\']) {
+            self.nds_config().dir_path.join(dir.object_id().to_string())
+        } else {
+            self.nds_config().dir_path.join(name)
+        }
+    }
+
+    /// The handle of the running download.
+    pub fn transfer(&self, file_id: &ObjectId) -> NearResult<NdsTransfer> {
+        self.task_manager()
+            .file_task(file_id)
+            .map(NdsTransfer::from)
+            .ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_NOTFOUND, format!("Not found the download of {file_id}")))
+    }
+
+    pub fn transfers(&self) -> Vec<NdsTransfer> {
+        self.task_manager()
+            .file_tasks()
+            .into_iter()
+            .map(NdsTransfer::from)
+            .collect()
+    }
+
+    /// The chunks of the file are kept in the store until it's unpinned.
+    pub fn pin_file(&self, file: &FileObject) {
+        self.chunk_manager().pin_file(file)
+    }
+
+    pub fn unpin_file(&self, file: &FileObject) {
+        self.chunk_manager().unpin_file(file)
+    }
+
+    pub fn store_stat(&self) -> StoreStat {
+        self.chunk_manager().store_stat()
+    }
+
+    pub fn limits(&self) -> RateLimits {
+        self.limiter().limits()
+    }
+
+    /// The limits take effect on the following pieces of the running transfers too.
+    pub fn set_limits(&self, limits: RateLimits) {
+        info!("set rate limits {limits}");
+        self.limiter().set_limits(limits)
+    }
+
+    pub(self) async fn sync_dir(&self, dir: DirObject) -> NearResult<()> {
+        for entry in dir.body().content().entries() {
+            let topic = TopicBuilder::from(TOPIC_NDS_INTEREST_CHUNK.topic_ref())
+                                    .add_thirdary(entry.file().object_id().to_string().as_str())
+                                    .build();
+            self.topic_manager().register_public_topic(&topic, OnNdsInterest::new(self.clone()))?;
+        }
+
+        let message = 
+            protos::RawObjectHelper::encode_with_raw(SyncDirMessage{
+                dir
+            })
+            .map_err(| e | {
+                error!("failed build message with err = {e}");
+                e
+            })?;
+
+        self.runtime_stack()
+            .stack()
+            .post_message(RequestorMeta {
+                              topic: Some(TOPIC_NDS_SYNC_DIR.topic().clone()),
+                              ..Default::default()
+                          },
+                          message,
+                          None)
+            .await
+    }
+
     pub(self) async fn sync_file(&self, file: FileObject) -> NearResult<()> {
         let topic = TopicBuilder::from(TOPIC_NDS_INTEREST_CHUNK.topic_ref())
                                 .add_thirdary(file.object_id().to_string().as_str())
                                 .build();
-        self.topic_manager().register_topic_event(&topic, OnNdsInterest::new(self.clone())).await?;
+        self.topic_manager().register_public_topic(&topic, OnNdsInterest::new(self.clone()))?;
 
         let message = 
             protos::RawObjectHelper::encode_with_raw(SyncFileMessage{
@@ -197,10 +429,13 @@ impl Stack {
 
         self.runtime_stack()
             .stack()
-            .post_message(None, 
-                          TOPIC_NDS_SYNC_FILE.topic().clone(), 
+            .post_message(RequestorMeta {
+                              topic: Some(TOPIC_NDS_SYNC_FILE.topic().clone()),
+                              ..Default::default()
+                          },
                           message,
                           None)
+            .await
     }
 }
 
@@ -249,7 +484,7 @@ impl DownloadRequestTrait for Stack {
         //     }
     }
 
-    async fn interest_chunk_v2(&self, target: DownloadSourceRef, object_id: Option<ObjectId>, message: InterestMessage) -> NearResult<()> {
+    async fn interest_chunk_v2(&self, target: DownloadSourceRef, object_id: Option<ObjectId>, message: InterestMessage) -> NearResult<InterestMessageResponse> {
 
         let new_topic = if let Some(object_id) = object_id {
             TopicBuilder::from(TOPIC_NDS_INTEREST_CHUNK.topic_ref())
@@ -259,25 +494,24 @@ impl DownloadRequestTrait for Stack {
             TOPIC_NDS_INTEREST_CHUNK_PRI.clone()
         };
 
-        let message = 
-            protos::RawObjectHelper::encode_with_raw(message).map_err(| e | {
-                error!("faield encode message with err = {e}");
-                e
-            })?;
+        // the interest is posted to the source, the default one is left to the stack to route.
+        // the answer carries the negotiated encoding, the stripe stall is left to the swarm.
+        let r = if target.target() == &ObjectId::default() {
+            let fut = RoutineTemplate::<InterestMessageResponse>::call(new_topic, message).await?;
+            async_std::future::timeout(INTEREST_RESPONSE_TIMEOUT, fut).await
+        } else {
+            let fut = RoutineTemplate::<InterestMessageResponse>::call_with_target(target.target().clone(), new_topic, message).await?;
+            async_std::future::timeout(INTEREST_RESPONSE_TIMEOUT, fut).await
+        };
 
-        self.runtime_stack()
-            .stack()
-            .post_message(None, 
-                          new_topic, 
-                          message,
-                          None)
+        r.map_err(| _ | NearError::new(ErrorCode::NEAR_ERROR_TIMEOUT, "interest response timeout"))?
     }
 
 }
 
 // UploadEventTrait
 impl Stack {
-    pub fn push_piece_data(&self, 
+    pub async fn push_piece_data(&self, 
                            target: &ObjectId,
                            session_data: SessionData,
                            chunk: &ChunkId, 
@@ -293,10 +527,49 @@ impl Stack {
         self.0
             .runtime_stack
             .stack()
-            .post_message_with_builder(Some(target.clone()), 
-                                       TOPIC_NDS_PIECE_DATA.topic(),
+            .post_message_with_builder(RequestorMeta {
+                                           to: Some(target.clone()),
+                                           topic: Some(TOPIC_NDS_PIECE_DATA.topic().clone()),
+                                           ..Default::default()
+                                       },
                                        b, 
                                        None)
+            .await
+    }
+    /// Push the pieces which have been encoded.
+    pub async fn push_pieces(&self, target: &ObjectId, pieces: Vec<PieceMessage>) -> NearResult<()> {
+        self.0
+            .runtime_stack
+            .stack()
+            .post_message_with_builder(RequestorMeta {
+                                           to: Some(target.clone()),
+                                           topic: Some(TOPIC_NDS_PIECE_DATA.topic().clone()),
+                                           ..Default::default()
+                                       },
+                                       EncodedPieceBuilder { pieces }, 
+                                       None)
+            .await
+    }
+}
+
+// DownloadControlTrait
+impl Stack {
+    pub(crate) async fn control_session(&self, target: &ObjectId, message: PieceControlMessage) -> NearResult<()> {
+        let message = 
+            protos::RawObjectHelper::encode_with_raw(message).map_err(| e | {
+                error!("faield encode message with err = {e}");
+                e
+            })?;
+
+        self.runtime_stack()
+            .stack()
+            .post_message(RequestorMeta {
+                              to: Some(target.clone()),
+                              topic: Some(TOPIC_NDS_CONTROL.topic().clone()),
+                              ..Default::default()
+                          },
+                          message,
+                          None)
+            .await
     }
 }
- 
\ No newline at end of file
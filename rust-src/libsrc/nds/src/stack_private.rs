@@ -1,22 +1,30 @@
 
 use log::{error, trace, info};
 
-use near_base::{NearResult, ObjectId, NearError, file::FileObject, builder_codec_macro::Empty, };
+use near_base::{NearResult, ObjectId, NearError, ErrorCode, file::FileObject, builder_codec_macro::Empty, };
 use near_transport::{RoutineEventTrait, Routine, EventResult, ResponseEvent, RoutineWrap, HeaderMeta, };
 
 use base::{raw_object::RawObjectGuard};
 use common::TopicRoutineCbEventTrait;
 use protos::DataContent;
 
-use crate::nds_protocol::PieceMessage;
+use crate::nds_protocol::{PieceMessage, PieceControlMessage, RateLimitMessage};
 
 use super::{NdsStack, 
             DownloadSource, SingleDownloadSource, MultiDownloadSource,
-            nds_protocol::{SyncFileMessage, 
-                           InterestMessage, InterestMessageResponse,
+            nds_protocol::{SyncFileMessage, SyncDirMessage, 
+                           InterestMessage, InterestMessageResponse, ChunkEncodeDesc,
             },
     };
 
+/// The creator of the message, or its requestor if it's created by the requestor itself.
+fn from_of(header_meta: &HeaderMeta) -> &ObjectId {
+    header_meta.creator
+        .as_ref()
+        .and_then(| creator | creator.creator.as_ref())
+        .unwrap_or(&header_meta.requestor)
+}
+
 pub struct OnNdsSyncFile {
     stack: NdsStack,
 }
@@ -53,7 +61,7 @@ impl TopicRoutineCbEventTrait for OnNdsSyncFile {
 
                 let r: DataContent<Empty> = match r {
                     DataContent::Content(file) => {
-                        let from = header_meta.creator.as_ref().unwrap_or(&header_meta.requestor);
+                        let from = from_of(header_meta);
 
                         self.nds_stack
                             .task_manager()
@@ -88,6 +96,104 @@ impl TopicRoutineCbEventTrait for OnNdsSyncFile {
     }
 }
 
+/// The directory removes the local files, so it's applied only when it's sent from the trusted one,
+/// and the author of it must be the sender or the trusted one too.
+fn check_sync_dir(nds_stack: &NdsStack, from: &ObjectId, message: &SyncDirMessage) -> NearResult<()> {
+    if !nds_stack.is_trusted(from) {
+        return Err(NearError::new(ErrorCode::NEAR_ERROR_FORBIDDEN, format!("{from} isn't trusted to sync dir {}", message.dir.object_id())));
+    }
+
+    message.dir.verify_object_id()?;
+
+    match message.dir.desc().author() {
+        Some(author) if author != from && !nds_stack.is_trusted(author) => {
+            Err(NearError::new(ErrorCode::NEAR_ERROR_FORBIDDEN, format!("the author {author} of dir {} isn't trusted", message.dir.object_id())))
+        }
+        _ => Ok(()),
+    }
+}
+
+pub struct OnNdsSyncDir {
+    stack: NdsStack,
+}
+
+impl OnNdsSyncDir {
+    pub fn new(stack: NdsStack) -> OnNdsSyncDir {
+        Self{
+            stack
+        }
+    }
+}
+
+impl TopicRoutineCbEventTrait for OnNdsSyncDir {
+    fn on_topic_routine(&self) -> NearResult<Box<dyn RoutineEventTrait>> {
+        trace!("enter.");
+
+        struct SyncDirRoutine {
+            nds_stack: NdsStack,
+        }
+
+        #[async_trait::async_trait]
+        impl Routine<RawObjectGuard, RawObjectGuard> for SyncDirRoutine {
+            async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+                trace!("SyncDirRoutine::on_routine: header_meta={header_meta}, req={req}.");
+
+                let r: DataContent<SyncDirMessage> = match protos::RawObjectHelper::decode::<SyncDirMessage>(req) {
+                    Ok(r) => r.into(),
+                    Err(e) => {
+                        let error_string = format!("failed decode message with err = {e}");
+                        error!("{error_string}, sequence = {}", header_meta.sequence());
+                        Err(e)
+                    }
+                }.into();
+
+                let r: DataContent<Empty> = match r {
+                    DataContent::Content(message) => {
+                        let from = from_of(header_meta).clone();
+                        let nds_stack = self.nds_stack.clone();
+                        let sequence = header_meta.sequence().clone();
+
+                        match check_sync_dir(&nds_stack, &from, &message) {
+                            Ok(_) => {
+                                // the tree may be large, it's synced in the background.
+                                async_std::task::spawn(async move {
+                                    let root = nds_stack.dir_root(&message.dir);
+                                    let source = 
+                                        MultiDownloadSource::new()
+                                            .add_source(SingleDownloadSource::from(DownloadSource::default().set_target(from)));
+
+                                    match nds_stack.task_manager().download_dir(message.dir, &root, source).await {
+                                        Ok(_) => info!("successful sync dir into [{}]", root.display()),
+                                        Err(e) => error!("{e}, sequence = {}", sequence),
+                                    }
+                                });
+
+                                Ok(Empty)
+                            }
+                            Err(e) => {
+                                error!("{e}, sequence = {}", sequence);
+                                Err(e)
+                            }
+                        }
+                    }
+                    DataContent::Error(e) => Err(e),
+                }.into();
+
+                match protos::RawObjectHelper::encode(r) {
+                    Ok(o) => EventResult::Response(o.into()),
+                    Err(e) => {
+                        error!("{e}, sequence = {}", header_meta.sequence());
+                        EventResult::Ignore
+                    }
+                }
+            }
+
+        }
+
+        Ok(RoutineWrap::new(Box::new(SyncDirRoutine{ nds_stack: self.stack.clone() })))
+    }
+}
+
 pub struct OnNdsInterest {
     stack: NdsStack,
 }
@@ -123,10 +229,18 @@ impl TopicRoutineCbEventTrait for OnNdsInterest {
                 };
 
                 let r: DataContent<InterestMessageResponse> = match r {
-                    DataContent::Content(interest) => {
+                    DataContent::Content(mut interest) => {
+                        // the repair pieces are pushed only if they're enabled here, the answer tells the downloader.
+                        let fec = self.nds_stack.nds_config().fec.is_some();
+                        if !fec {
+                            if let ChunkEncodeDesc::Fec(range, _) = &interest.encoder {
+                                interest.encoder = ChunkEncodeDesc::Stream(range.clone());
+                            }
+                        }
+
                         self.nds_stack
                             .task_manager()
-                            .upload(header_meta.creator.as_ref().unwrap_or(&header_meta.requestor).clone(), &interest)
+                            .upload(from_of(header_meta).clone(), &interest)
                             .await
                             .map_err(
                                 | e | {
@@ -140,6 +254,7 @@ impl TopicRoutineCbEventTrait for OnNdsInterest {
                             .map(| _ | {
                                 InterestMessageResponse{
                                     chunk: interest.chunk,
+                                    fec,
                                 }
                             })
                     }
@@ -197,9 +312,11 @@ impl TopicRoutineCbEventTrait for OnNdsPieceData {
 
                 let r: DataContent<Empty> = match r {
                     DataContent::Content(data) => {
+                        let from = from_of(header_meta);
+
                         self.nds_stack
                             .task_manager()
-                            .on_piece_data(&data)
+                            .on_piece_data(from, &data)
                             .await
                             .map(| _ | {
                                 Empty
@@ -233,3 +350,152 @@ impl TopicRoutineCbEventTrait for OnNdsPieceData {
 }
 
 
+
+pub struct OnNdsControl {
+    stack: NdsStack,
+}
+
+impl OnNdsControl {
+    pub fn new(stack: NdsStack) -> Self {
+        Self{
+            stack
+        }
+    }
+}
+
+impl TopicRoutineCbEventTrait for OnNdsControl {
+    fn on_topic_routine(&self) -> NearResult<Box<dyn RoutineEventTrait>> {
+        trace!("enter.");
+
+        struct ControlRoutine {
+            nds_stack: NdsStack,
+        }
+
+        #[async_trait::async_trait]
+        impl Routine<RawObjectGuard, RawObjectGuard> for ControlRoutine {
+            async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+                trace!("ControlRoutine::on_routine: header_meta={header_meta}, req={req}.");
+
+                let r = match protos::RawObjectHelper::decode::<PieceControlMessage>(req) {
+                    Ok(data) => data,
+                    Err(e) => {
+                        let error_string = format!("failed decode message with err = {e}");
+                        error!("{error_string}, sequence = {}", header_meta.sequence());
+                        DataContent::Error(e)
+                    }
+                };
+
+                let r: DataContent<Empty> = match r {
+                    DataContent::Content(message) => {
+                        let from = from_of(header_meta);
+
+                        self.nds_stack
+                            .task_manager()
+                            .on_control(from, &message)
+                            .map(| _ | {
+                                Empty
+                            })
+                            .map_err(| e | {
+                                error!("Failed to control(from={}, message={}) with err = {} on sequence = {}", 
+                                        from,
+                                        message,
+                                        e,
+                                        header_meta.sequence());
+                                e
+                            })
+                    }
+                    DataContent::Error(e) => Err(e),
+                }.into();
+
+                match protos::RawObjectHelper::encode(r) {
+                    Ok(o) => { EventResult::Response(o.into()) },
+                    Err(e) => {
+                        error!("{e}, sequence = {}", header_meta.sequence());
+                        EventResult::Ignore
+                    }
+                }
+            }
+
+        }
+
+        Ok(RoutineWrap::new(Box::new(ControlRoutine{ nds_stack: self.stack.clone() })))
+    }
+}
+
+pub struct OnNdsConfig {
+    stack: NdsStack,
+}
+
+impl OnNdsConfig {
+    pub fn new(stack: NdsStack) -> Self {
+        Self{
+            stack
+        }
+    }
+}
+
+impl TopicRoutineCbEventTrait for OnNdsConfig {
+    fn on_topic_routine(&self) -> NearResult<Box<dyn RoutineEventTrait>> {
+        trace!("enter.");
+
+        struct ConfigRoutine {
+            nds_stack: NdsStack,
+        }
+
+        impl ConfigRoutine {
+            // only the local runtime and its core could change the limits.
+            fn is_trusted(&self, from: &ObjectId) -> bool {
+                let runtime_stack = self.nds_stack.runtime_stack();
+                let stack = runtime_stack.stack();
+
+                from == runtime_stack.local().object_id() ||
+                from == stack.local_device_id() ||
+                from == stack.core_device().object_id()
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl Routine<RawObjectGuard, RawObjectGuard> for ConfigRoutine {
+            async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+                trace!("ConfigRoutine::on_routine: header_meta={header_meta}, req={req}.");
+
+                let r = match protos::RawObjectHelper::decode::<RateLimitMessage>(req) {
+                    Ok(data) => data,
+                    Err(e) => {
+                        let error_string = format!("failed decode message with err = {e}");
+                        error!("{error_string}, sequence = {}", header_meta.sequence());
+                        DataContent::Error(e)
+                    }
+                };
+
+                let r: DataContent<Empty> = match r {
+                    DataContent::Content(message) => {
+                        let from = from_of(header_meta);
+
+                        if self.is_trusted(from) {
+                            info!("{} changed the rate limits to {}, sequence = {}", from, message, header_meta.sequence());
+                            self.nds_stack.set_limits(message.into());
+                            Ok(Empty)
+                        } else {
+                            let error_string = format!("{} isn't permitted to change the rate limits", from);
+                            error!("{error_string}, sequence = {}", header_meta.sequence());
+                            Err(NearError::new(ErrorCode::NEAR_ERROR_FORBIDDEN, error_string))
+                        }
+                    }
+                    DataContent::Error(e) => Err(e),
+                }.into();
+
+                match protos::RawObjectHelper::encode(r) {
+                    Ok(o) => { EventResult::Response(o.into()) },
+                    Err(e) => {
+                        error!("{e}, sequence = {}", header_meta.sequence());
+                        EventResult::Ignore
+                    }
+                }
+            }
+
+        }
+
+        Ok(RoutineWrap::new(Box::new(ConfigRoutine{ nds_stack: self.stack.clone() })))
+    }
+}
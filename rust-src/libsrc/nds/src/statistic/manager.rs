@@ -38,4 +38,17 @@ impl Manager {
             }
         }
     }
+
+    pub fn stat_of(&self, file_id: &ObjectId) -> Option<BytePerfStatPtr> {
+        self.statistics
+            .read().unwrap()
+            .get(file_id)
+            .cloned()
+    }
+
+    pub fn remove(&self, file_id: &ObjectId) -> Option<BytePerfStatPtr> {
+        self.statistics
+            .write().unwrap()
+            .remove(file_id)
+    }
 }
@@ -21,6 +21,7 @@ struct BytePerfStat {
     rate_mbps: AtomicU64,           // sending rate in Mb/s
     max_mbps: AtomicU64,
     min_mbps: AtomicU64,
+    dedup_bytes: AtomicU64,         // the bytes which were found in the store instead of downloading
 }
 
 #[derive(Clone)]
@@ -35,6 +36,27 @@ impl BytePerfStatPtr {
         }))
     }
 
+    #[inline]
+    pub fn total_bytes(&self) -> u64 {
+        self.0.total_bytes
+    }
+
+    #[inline]
+    pub fn finished_bytes(&self) -> u64 {
+        self.0.curr_bytes.load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    #[inline]
+    pub fn dedup_bytes(&self) -> u64 {
+        self.0.dedup_bytes.load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    /// The deduplicated bytes are finished without downloading, they aren't taken into the rate.
+    pub fn dedup(&self, bytes: u64) {
+        self.0.dedup_bytes.fetch_add(bytes, std::sync::atomic::Ordering::SeqCst);
+        self.0.curr_bytes.fetch_add(bytes, std::sync::atomic::Ordering::SeqCst);
+    }
+
     pub fn update(&self, when: Timestamp, bytes: u64) -> Stat {
         debug_assert!(self.0.begin_time_stamp_ms <= when);
 
@@ -1,17 +1,19 @@
 
-use std::sync::{Arc, };
+use std::{sync::{Arc, Mutex, atomic::{AtomicBool, Ordering}}, collections::{BTreeMap, BTreeSet}, };
 
-use log::{info, error, };
-use near_base::{ChunkId, ObjectId, NearResult, };
+use log::{info, error, warn, };
+use near_base::{ChunkId, ObjectId, NearResult, NearError, ErrorCode, Timestamp, now, };
 
-use crate::{nds_protocol::{PieceMessage, InterestMessage, SessionData, ChunkEncodeDesc}, 
-            tasks::{ToSourceTrait, SessionTrait, manager::TaskTrait, }, 
+use crate::{nds_protocol::{PieceMessage, InterestMessage, SessionData, ChunkEncodeDesc, ChunkRange}, 
+            tasks::{ToSourceTrait, SessionTrait, DownloadSourceRef, manager::TaskTrait, }, 
             chunks::ChunkView, 
-            inc::{ChunkWriterFeedbackTrait, ChunkWriterTrait, SaveToPathTrait}
+            inc::{ChunkWriterFeedbackTrait, ChunkWriterTrait}
         };
 
 use super::{manager::Manager as DownloadManager, 
-            DownloadRequestTrait, h::{DownloadTaskTrait, OnEventTrait}, encoder::{ChunkPieceRangeEncoder, FeedbackState}
+            DownloadRequestTrait, h::{DownloadTaskTrait, OnEventTrait}, encoder::{ChunkPieceRangeEncoder, FeedbackState},
+            journal::ChunkJournal,
+            swarm::Swarm,
         };
 
 enum ChunkStateImpl {
@@ -21,6 +23,26 @@ enum ChunkStateImpl {
 struct PendingState {
     view: ChunkView,
     encoder: ChunkPieceRangeEncoder,
+    journal: ChunkJournal,
+    stripes: Mutex<StripeState>,
+}
+
+/// The range requested from the source.
+struct Stripe {
+    range: ChunkRange,
+    source: ObjectId,
+    requested: Timestamp,
+}
+
+#[derive(Default)]
+struct StripeState {
+    stripes: Vec<Stripe>,
+    // the sources which pushed the pieces of the chunk
+    contributors: BTreeSet<ObjectId>,
+    last_piece: BTreeMap<ObjectId, Timestamp>,
+    // the broken chunk came from several sources, it's requested from one source
+    // so that the next failure points out the broken source.
+    isolated: bool,
 }
 
 struct ChunkEvents {
@@ -33,6 +55,8 @@ struct ChunkTaskImpl {
     manager: DownloadManager,
     task_id: u32,
     chunk: ChunkId,
+    swarm: Swarm,
+    paused: AtomicBool,
     state: Option<ChunkStateImpl>,
     events: Option<ChunkEvents>,
 }
@@ -74,7 +98,9 @@ impl ChunkTask {
                chunk: ChunkId, 
                request: Box<dyn DownloadRequestTrait>,
                feedback: Box<dyn ChunkWriterFeedbackTrait>,
-               session: Option<Box<dyn SessionTrait>>) -> NearResult<ChunkTask> {
+               session: Option<Box<dyn SessionTrait>>,
+               journal: ChunkJournal,
+               swarm: Swarm) -> NearResult<ChunkTask> {
 
         let task_id = manager.task_gen_id().generate().into_value();
         let ret = 
@@ -82,17 +108,25 @@ impl ChunkTask {
                 manager,
                 task_id,
                 chunk,
+                swarm,
+                paused: AtomicBool::new(false),
                 state: None,
                 events: None,
             }));
 
         let ret_clone = ret.clone();
-        let view = async_std::task::block_on(async move {
+        let journal_clone = journal.clone();
+        let (view, bitmap) = async_std::task::block_on(async move {
+            // resume from the journal
+            if let Some((content, bitmap)) = journal_clone.load().await {
+                return Ok((ChunkView::with_write(ret_clone.chunk().clone(), content), Some(bitmap)));
+            }
+
             match ret_clone.0.manager.nds_stack()
                     .chunk_manager()
                     .create_view(ret_clone.chunk(), crate::chunks::ChunkAccess::Write)
                     .await {
-                Ok(view) => Ok(view),
+                Ok(view) => Ok((view, None)),
                 Err(err) => {
                     error!("failed [{}] create-view with err = {}", ret_clone.chunk(), err);
                     Err(err)
@@ -109,7 +143,12 @@ impl ChunkTask {
 
         unsafe {
             let mut_self = &mut *(Arc::as_ptr(&ret.0) as *mut ChunkTaskImpl);
-            mut_self.state = Some(ChunkStateImpl::Pending(Arc::new(PendingState { view: view.clone(), encoder: ChunkPieceRangeEncoder::new(ret.chunk(), view) })));
+            mut_self.state = Some(ChunkStateImpl::Pending(Arc::new(PendingState { 
+                view: view.clone(), 
+                encoder: ChunkPieceRangeEncoder::new(ret.chunk(), view, journal.clone(), bitmap),
+                journal,
+                stripes: Mutex::new(StripeState::default()),
+            })));
             mut_self.events = Some(events);
         };
 
@@ -139,6 +178,195 @@ impl ChunkTask {
         self.0.events.as_ref().unwrap().feedback.as_ref()
     }
 
+    pub async fn cancel(&self, e: NearError) {
+        match self.0.state.as_ref().unwrap() {
+            ChunkStateImpl::Pending(state) => state.encoder.err(e).await,
+        }
+    }
+
+    /// The paused chunk isn't checked for the stalled stripes.
+    pub fn set_paused(&self, paused: bool) {
+        self.0.paused.store(paused, Ordering::SeqCst);
+
+        if !paused {
+            let now = now();
+            let pending_state = self.pending_state();
+            let stripes = &mut *pending_state.stripes.lock().unwrap();
+            stripes.stripes.iter_mut().for_each(| stripe | stripe.requested = now);
+            stripes.last_piece.clear();
+        }
+    }
+
+    #[inline]
+    fn pending_state(&self) -> &PendingState {
+        match self.0.state.as_ref().unwrap() {
+            ChunkStateImpl::Pending(state) => state.as_ref(),
+        }
+    }
+
+    /// Assign the ranges to the sources, all of them go to the best one when the chunk is isolated.
+    fn assign(&self, ranges: Vec<ChunkRange>) -> Vec<(ChunkRange, DownloadSourceRef)> {
+        let isolated = self.pending_state().stripes.lock().unwrap().isolated;
+
+        if isolated {
+            match self.0.swarm.sources().into_iter().next() {
+                Some(source) => ranges.into_iter().map(| range | (range, source.clone())).collect(),
+                None => vec![],
+            }
+        } else {
+            self.0.swarm.split(ranges)
+        }
+    }
+
+    async fn request(&self, stripes: Vec<(ChunkRange, DownloadSourceRef)>) {
+        for (range, source) in stripes {
+            let fec = self.0.swarm.fec_of(source.target());
+            let message = InterestMessage {
+                session_data: SessionData {
+                    session_id: self.event_session().session_id(),
+                    session_sub_id: self.session_id(),    
+                },
+                chunk: self.chunk().clone(),
+                encoder: match fec {
+                    Some(params) => ChunkEncodeDesc::Fec(range.clone(), params),
+                    None => ChunkEncodeDesc::Stream(range.clone()),
+                },
+            };
+
+            self.pending_state()
+                .stripes
+                .lock().unwrap()
+                .stripes
+                .push(Stripe {
+                    range,
+                    source: source.target().clone(),
+                    requested: now(),
+                });
+
+            // the answer negotiates the encoding of the following stripes of the source.
+            let arc_self = self.clone();
+            async_std::task::spawn(async move {
+                let target = source.target().clone();
+                let object_id = arc_self.event_session().object_id();
+
+                match arc_self.event_request()
+                              .interest_chunk_v2(source, 
+                                                 Some(object_id.clone()), 
+                                                 message)
+                              .await {
+                    Ok(response) => arc_self.0.swarm.on_negotiated(&target, response.fec),
+                    Err(err) => error!("failed interest chunk object={} chunk={}, err={}", object_id, arc_self.chunk(), err),
+                }
+            });
+        }
+    }
+
+    /// The chunk failed the hash verification, the pieces have been dropped and are requested again.
+    async fn on_broken(&self) {
+        let contributors = {
+            let stripes = &mut *self.pending_state().stripes.lock().unwrap();
+            stripes.stripes.clear();
+            stripes.last_piece.clear();
+            std::mem::take(&mut stripes.contributors)
+        };
+
+        if contributors.len() == 1 {
+            contributors.iter().for_each(| source | self.0.swarm.blacklist(source));
+            self.pending_state().stripes.lock().unwrap().isolated = false;
+        } else {
+            warn!("{} is broken by one of {} sources, it's requested from one source.", self, contributors.len());
+            self.pending_state().stripes.lock().unwrap().isolated = true;
+        }
+
+        let ranges = self.pending_state().encoder.missing_ranges();
+        self.request(self.assign(ranges)).await;
+    }
+
+    /// Request the stalled stripes from the other sources until the chunk is finished.
+    fn watch_stall(&self) {
+        let arc_self = self.clone();
+
+        async_std::task::spawn(async move {
+            let stall_timeout = arc_self.0.swarm.config().stall_timeout;
+
+            loop {
+                async_std::task::sleep(stall_timeout / 2).await;
+
+                let state = arc_self.pending_state();
+                if !state.encoder.is_pending() {
+                    break;
+                }
+
+                if arc_self.0.paused.load(Ordering::SeqCst) {
+                    continue;
+                }
+
+                let now = now();
+                let stalled = {
+                    let stripes = &mut *state.stripes.lock().unwrap();
+                    let last_piece = &stripes.last_piece;
+
+                    let (stalled, alive): (Vec<Stripe>, Vec<Stripe>) =
+                        std::mem::take(&mut stripes.stripes)
+                            .into_iter()
+                            .filter(| stripe | !state.encoder.missing_ranges_in(&stripe.range).is_empty())
+                            .partition(| stripe | {
+                                let last = std::cmp::max(stripe.requested, last_piece.get(&stripe.source).cloned().unwrap_or(0));
+                                now.saturating_sub(last) > stall_timeout.as_micros() as u64
+                            });
+
+                    stripes.stripes = alive;
+                    stalled
+                };
+
+                for stripe in stalled {
+                    arc_self.0.swarm.on_stall(&stripe.source);
+
+                    let sources = arc_self.0.swarm.sources();
+                    let source = match sources.iter()
+                                              .find(| source | source.target() != &stripe.source)
+                                              .or(sources.first()) {
+                        Some(source) => source.clone(),
+                        None => {
+                            error!("{} has no source available.", arc_self);
+                            continue;
+                        }
+                    };
+
+                    warn!("{} stripe [{}, {}) stalled on {}, it's requested from {}.", 
+                          arc_self, stripe.range.start, stripe.range.end, stripe.source, source.target());
+
+                    let missing = state.encoder.missing_ranges_in(&stripe.range);
+                    arc_self.request(missing.into_iter().map(| range | (range, source.clone())).collect()).await;
+                }
+            }
+        });
+    }
+
+    /// The chunk which has been stored for the other file is finished without downloading.
+    async fn dedup(&self) -> bool {
+        let stack = self.0.manager.nds_stack();
+        let chunk_manager = stack.chunk_manager();
+
+        if !chunk_manager.has_chunk(self.chunk()) {
+            return false;
+        }
+
+        info!("{} has been stored, it isn't downloaded again.", self);
+
+        chunk_manager.on_dedup(self.chunk());
+        if let Some(stat) = stack.statistic_manager().stat_of(&self.event_session().object_id()) {
+            stat.dedup(self.chunk().len() as u64);
+        }
+
+        let state = self.pending_state();
+        state.journal.remove().await;
+        chunk_manager.remove_write_view(self.chunk());
+        self.event_feedback().finished(state.view.clone_as_writer()).await;
+
+        true
+    }
+
     fn sync_state(&self) {
         let arc_self = self.clone();
 
@@ -158,7 +386,8 @@ impl ChunkTask {
                 FeedbackState::Finished => {
                     match arc_self.0.state.as_ref().unwrap() {
                         ChunkStateImpl::Pending(state) => {
-                            let _ = state.view.save_to_path(&arc_self.0.manager.nds_stack().nds_config().data_path).await;
+                            let _ = arc_self.0.manager.nds_stack().chunk_manager().store_chunk(&state.view).await;
+                            state.journal.remove().await;
                             arc_self.event_feedback().finished(state.view.clone_as_writer()).await;
                         }
                     }
@@ -204,48 +433,55 @@ impl TaskTrait for ChunkTask {
     async fn start(&self, source: Option<Box<dyn ToSourceTrait>>) {
         info!("{} with begin...", self );
 
-        let source = match source {
-            Some(source) => { source }
-            None => {
-                unreachable!("The chunk must srouce. fatal error.");
-            }
-        };
+        if source.is_none() {
+            unreachable!("The chunk must srouce. fatal error.");
+        }
 
-        let message = InterestMessage {
-            session_data: SessionData {
-                session_id: self.event_session().session_id(),
-                session_sub_id: self.session_id(),    
-            },
-            chunk: self.chunk().clone(),
-            encoder: ChunkEncodeDesc::create_stream(self.chunk()),
-        };
+        if self.dedup().await {
+            return;
+        }
 
         // sync state
         self.sync_state();
 
-        // self.event_request().interest_chunk(source.source_of(0), self.chunk(), Some(self.event_session())).await;
-        if let Err(err) = 
-            self.event_request()
-                .interest_chunk_v2(source.source_of(0), 
-                                   Some(self.event_session().object_id()), 
-                                   message)
-                .await {
-            error!("failed interest chunk object={} chunk={}, err={}", self.event_session().object_id(), self.chunk(), err);
+        let encoder = &self.pending_state().encoder;
+
+        if encoder.check_finished().await {
+            info!("{} has been completed in the journal.", self);
+            return;
         }
 
+        // only the missing pieces are interested, and they are shared among the sources.
+        let ranges = encoder.missing_ranges();
+        self.request(self.assign(ranges)).await;
+
+        self.watch_stall();
     }
 
 }
 
 #[async_trait::async_trait]
 impl OnEventTrait for ChunkTask {
-    async fn on_piece_data(&self, data: &PieceMessage) -> NearResult<()> {
-        let pending_state = self.0.state.as_ref().unwrap();
+    async fn on_piece_data(&self, from: &ObjectId, data: &PieceMessage) -> NearResult<()> {
+        let state = self.pending_state();
+
+        {
+            let stripes = &mut *state.stripes.lock().unwrap();
+            stripes.contributors.insert(from.clone());
+            stripes.last_piece.insert(from.clone(), now());
+        }
 
-        match pending_state {
-            ChunkStateImpl::Pending(state) => {
-                state.encoder.on_piece_data(data).await
+        match state.encoder.on_piece_data(from, data).await {
+            Ok(_) => {
+                self.0.swarm.on_piece(from, data.data.len());
+                Ok(())
+            }
+            Err(e) if e.errno() == ErrorCode::NEAR_ERROR_UNMATCH => {
+                error!("{} failed verify with err = {e}", self);
+                self.on_broken().await;
+                Err(e)
             }
+            Err(e) => Err(e),
         }
     }
 }
@@ -0,0 +1,288 @@
+
+use std::{path::{Path, PathBuf, Component}, collections::BTreeSet, time::{Duration, UNIX_EPOCH}, fs::Metadata, };
+
+use async_std::io::WriteExt;
+use log::{info, warn, error};
+use near_base::{dir::{DirObject, DirEntry}, NearResult, NearError, ErrorCode, hash_file, };
+
+use crate::{MultiDownloadSource, chunks::ChunkAccess, };
+
+use super::DownloadManager;
+
+// the file is written to the temporary one first, it's renamed when all of the chunks are written.
+const TEMP_SUFFIX: &'static str = ".nds-tmp";
+
+/// The files under the root, keyed by the relative path which is separated by '/'.
+/// The symbolic links and the temporary files of the unfinished sync are skipped.
+pub(crate) fn walk_files(root: &Path) -> NearResult<Vec<(String, PathBuf)>> {
+    fn walk(dir: &Path, prefix: &str, files: &mut Vec<(String, PathBuf)>) -> NearResult<()> {
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            let file_type = entry.file_type()?;
+            let name = match entry.file_name().to_str() {
+                Some(name) => name.to_owned(),
+                None => {
+                    warn!("[{}] isn't utf-8 name, ignored.", entry.path().display());
+                    continue;
+                }
+            };
+            let relative = if prefix.is_empty() { name } else { format!("{prefix}/{name}") };
+
+            if file_type.is_dir() {
+                walk(&entry.path(), &relative, files)?;
+            } else if file_type.is_file() && !relative.ends_with(TEMP_SUFFIX) {
+                files.push((relative, entry.path()));
+            }
+        }
+
+        Ok(())
+    }
+
+    let mut files = vec![];
+    walk(root, "", &mut files)?;
+    Ok(files)
+}
+
+#[cfg(unix)]
+pub(crate) fn mode_of(metadata: &Metadata) -> u32 {
+    use std::os::unix::fs::PermissionsExt;
+    metadata.permissions().mode() & 0o7777
+}
+
+#[cfg(not(unix))]
+pub(crate) fn mode_of(metadata: &Metadata) -> u32 {
+    if metadata.permissions().readonly() { 0o444 } else { 0o644 }
+}
+
+pub(crate) fn mtime_of(metadata: &Metadata) -> u64 {
+    metadata.modified()
+        .ok()
+        .and_then(| t | t.duration_since(UNIX_EPOCH).ok())
+        .map(| d | d.as_micros() as u64)
+        .unwrap_or(0)
+}
+
+/// The symbolic link under the root may point to the outside, nothing out of the root is written or removed.
+fn is_confined(root: &Path, path: &Path) -> bool {
+    match (root.canonicalize(), path.canonicalize()) {
+        (Ok(root), Ok(path)) => path.starts_with(root),
+        _ => false,
+    }
+}
+
+fn set_attributes(path: &Path, entry: &DirEntry) -> NearResult<()> {
+    let file = std::fs::File::open(path)?;
+    file.set_modified(UNIX_EPOCH + Duration::from_micros(entry.mtime()))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(entry.mode()))?;
+    }
+
+    Ok(())
+}
+
+/// Materialise the directory tree under the root.
+/// The files which are the same as the entries are kept, only the changed ones are downloaded,
+/// and the files which aren't listed in the directory are removed.
+pub struct DirTask {
+    manager: DownloadManager,
+    dir: DirObject,
+    root: PathBuf,
+    source: MultiDownloadSource,
+}
+
+impl std::fmt::Display for DirTask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DirTask::{{dir:{}, root:{}}}", self.dir.object_id(), self.root.display())
+    }
+}
+
+impl DirTask {
+    pub fn new(manager: DownloadManager, dir: DirObject, root: &Path, source: MultiDownloadSource) -> Self {
+        Self {
+            manager,
+            dir,
+            root: root.to_path_buf(),
+            source,
+        }
+    }
+
+    pub async fn run(&self) -> NearResult<()> {
+        async_std::fs::create_dir_all(&self.root).await?;
+
+        if async_std::fs::symlink_metadata(&self.root).await?.file_type().is_symlink() {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("the root [{}] of {} is a symbolic link", self.root.display(), self.dir.object_id())));
+        }
+
+        let entries = self.dir.body().content().entries();
+        let mut changed = vec![];
+
+        for entry in entries {
+            let path = self.entry_path(entry)?;
+            if !self.is_unchanged(&path, entry).await {
+                changed.push((path, entry));
+            }
+        }
+
+        self.remove_stale();
+
+        info!("{} begin, changed = {}, unchanged = {}", self, changed.len(), entries.len() - changed.len());
+
+        // all of the changed files are downloading together.
+        let mut tasks = vec![];
+        for (path, entry) in changed {
+            let file = entry.file();
+
+            let task = if file.desc().content().len() == 0 {
+                None
+            } else {
+                self.manager.download_file(file.clone(), self.source.clone())?;
+                self.manager.file_task(file.object_id())
+            };
+
+            tasks.push((path, entry, task));
+        }
+
+        for (path, entry, task) in tasks {
+            if let Some(task) = task {
+                task.wait().await.map_err(| e | {
+                    error!("failed download {} of {} with err = {e}", entry.path(), self);
+                    e
+                })?;
+            }
+
+            self.materialise(&path, entry).await?;
+        }
+
+        info!("{} finished.", self);
+
+        Ok(())
+    }
+
+    fn entry_path(&self, entry: &DirEntry) -> NearResult<PathBuf> {
+        let relative = Path::new(entry.path());
+
+        if entry.path().is_empty() || !relative.components().all(| c | matches!(c, Component::Normal(_))) {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("invalid path [{}] in {}", entry.path(), self.dir.object_id())));
+        }
+
+        Ok(self.root.join(relative))
+    }
+
+    async fn is_unchanged(&self, path: &Path, entry: &DirEntry) -> bool {
+        let metadata = match async_std::fs::metadata(path).await {
+            Ok(metadata) if metadata.is_file() => metadata,
+            _ => { return false; }
+        };
+
+        let desc = entry.file().desc().content();
+        if metadata.len() != desc.len() {
+            return false;
+        }
+
+        if mtime_of(&metadata) == entry.mtime() {
+            return true;
+        }
+
+        // the file may be touched only, it's kept if the content is the same.
+        match hash_file(path).await {
+            Ok((hash, _)) if &hash == desc.hash() => {
+                if let Err(e) = set_attributes(path, entry) {
+                    warn!("failed set attributes of [{}] with err = {e}", path.display());
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn remove_stale(&self) {
+        let files = match walk_files(&self.root) {
+            Ok(files) => files,
+            Err(e) => {
+                error!("failed walk [{}] with err = {e}", self.root.display());
+                return;
+            }
+        };
+
+        let content = self.dir.body().content();
+        let mut dirs = BTreeSet::new();
+
+        for (relative, path) in files {
+            if content.entry_of(&relative).is_some() {
+                continue;
+            }
+
+            if !is_confined(&self.root, &path) {
+                warn!("[{}] is out of [{}], it isn't removed.", path.display(), self.root.display());
+                continue;
+            }
+
+            info!("remove [{}] which isn't in {}", path.display(), self.dir.object_id());
+            if let Err(e) = std::fs::remove_file(&path) {
+                warn!("failed remove [{}] with err = {e}", path.display());
+            }
+
+            let mut parent = path.parent();
+            while let Some(dir) = parent.filter(| dir | *dir != self.root.as_path()) {
+                dirs.insert(dir.to_path_buf());
+                parent = dir.parent();
+            }
+        }
+
+        // the deepest directory is removed first, the one which isn't empty is kept.
+        for dir in dirs.iter().rev().filter(| dir | is_confined(&self.root, dir)) {
+            let _ = std::fs::remove_dir(dir);
+        }
+    }
+
+    async fn materialise(&self, path: &Path, entry: &DirEntry) -> NearResult<()> {
+        if let Some(parent) = path.parent() {
+            async_std::fs::create_dir_all(parent).await?;
+
+            if !is_confined(&self.root, parent) {
+                return Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("[{}] is out of [{}]", path.display(), self.root.display())));
+            }
+        }
+
+        let temp = PathBuf::from(format!("{}{TEMP_SUFFIX}", path.display()));
+
+        {
+            let mut file = async_std::fs::File::create(&temp).await?;
+            let chunk_manager = self.manager.nds_stack().chunk_manager();
+
+            for chunk in entry.file().body().content().chunk_list().iter().filter(| chunk | chunk.len() > 0) {
+                let view = chunk_manager.create_view(chunk, ChunkAccess::Read).await?;
+                let data = view.read(0, chunk.len()).await?;
+                file.write_all(&data).await?;
+            }
+
+            file.sync_all().await?;
+        }
+
+        async_std::fs::rename(&temp, path).await?;
+        set_attributes(path, entry)
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn test_confined() {
+    let base = std::env::temp_dir().join(format!("nds-confined-{}", std::process::id()));
+    let root = base.join("root");
+    let outside = base.join("outside");
+    std::fs::create_dir_all(&root).unwrap();
+    std::fs::create_dir_all(&outside).unwrap();
+    std::fs::write(root.join("a.txt"), "123").unwrap();
+    std::fs::write(outside.join("b.txt"), "456").unwrap();
+    std::os::unix::fs::symlink(&outside, root.join("link")).unwrap();
+
+    assert!(is_confined(&root, &root.join("a.txt")));
+    assert!(!is_confined(&root, &root.join("link")));
+    assert!(!is_confined(&root, &root.join("link/b.txt")));
+    assert!(!is_confined(&root, &root.join("missing.txt")));
+
+    let _ = std::fs::remove_dir_all(&base);
+}
@@ -1,10 +1,12 @@
 
-use std::{sync::{atomic::{AtomicU16, AtomicBool, Ordering}, RwLock, Mutex}, };
+use std::{sync::{atomic::{AtomicU16, AtomicBool, Ordering}, RwLock, Mutex}, collections::BTreeMap, };
 
-use log::error;
-use near_base::{StateWaiter, ChunkId, NearResult, NearError, ErrorCode, };
+use log::{error, info};
+use near_base::{StateWaiter, ChunkId, ObjectId, NearResult, NearError, ErrorCode, };
 
-use crate::{nds_protocol::{PieceMessage, PieceEncodeDesc}, inc::{ChunkWriterTrait, ChunkWriterFeedbackTrait}, tasks::download::h::OnEventTrait};
+use crate::{nds_protocol::{PieceMessage, PieceEncodeDesc, ChunkRange, decode_sources}, 
+            inc::{ChunkWriterTrait, ChunkWriterFeedbackTrait}, 
+            tasks::download::{h::OnEventTrait, journal::{ChunkJournal, piece_count}}};
 
 use super::FeedbackState;
 
@@ -14,23 +16,21 @@ struct IndexQueue {
 }
 
 impl IndexQueue {
-    pub fn new(chunk: &ChunkId) -> Self {
-        let chunk_len = chunk.len();
-        let piece_cnt = if chunk_len % PieceMessage::payload_max_len() == 0 {
-            chunk_len / PieceMessage::payload_max_len()
-        } else {
-            chunk_len / PieceMessage::payload_max_len() + 1
+    /// The bitmap is the journal of the received pieces, one bit per piece.
+    pub fn new(chunk: &ChunkId, bitmap: Option<&[u8]>) -> Self {
+        let piece_cnt = piece_count(chunk);
+        let received = | index: usize | {
+            bitmap.and_then(| bitmap | bitmap.get(index / 8))
+                .map(| bits | bits & (1 << (index % 8)) != 0)
+                .unwrap_or(false)
         };
 
+        let queue: Vec<AtomicBool> = (0..piece_cnt).map(| index | AtomicBool::new(received(index))).collect();
+        let count = queue.iter().filter(| q | q.load(Ordering::SeqCst)).count();
+
         Self {
-            count: AtomicU16::new(0),
-            queue: {
-                let mut queue = vec![];
-                for _ in 0..piece_cnt {
-                    queue.push(AtomicBool::new(false));
-                }
-                queue
-            }
+            count: AtomicU16::new(count as u16),
+            queue,
         }
     }
 
@@ -38,10 +38,61 @@ impl IndexQueue {
         self.count.load(Ordering::SeqCst) as usize == self.queue.len()
     }
 
+    pub fn bitmap(&self) -> Vec<u8> {
+        let mut bitmap = vec![0u8; (self.queue.len() + 7) / 8];
+
+        for (index, q) in self.queue.iter().enumerate() {
+            if q.load(Ordering::SeqCst) {
+                bitmap[index / 8] |= 1 << (index % 8);
+            }
+        }
+
+        bitmap
+    }
+
+    /// The ranges of the pieces which haven't been received.
+    pub fn missing_ranges(&self) -> Vec<ChunkRange> {
+        self.missing_ranges_in(&ChunkRange { start: 0, end: self.queue.len() as u32 })
+    }
+
+    pub fn missing_ranges_in(&self, range: &ChunkRange) -> Vec<ChunkRange> {
+        let mut ranges: Vec<ChunkRange> = vec![];
+        let end = std::cmp::min(range.end as usize, self.queue.len());
+
+        for index in range.start as usize..end {
+            if self.queue[index].load(Ordering::SeqCst) {
+                continue;
+            }
+
+            let index = index as u32;
+            match ranges.last_mut() {
+                Some(range) if range.end == index => range.end = index + 1,
+                _ => ranges.push(ChunkRange { start: index, end: index + 1 }),
+            }
+        }
+
+        ranges
+    }
+
+    pub fn reset(&self) {
+        for q in self.queue.iter() {
+            q.store(false, Ordering::SeqCst);
+        }
+        self.count.store(0, Ordering::SeqCst);
+    }
+
+    pub fn is_received(&self, index: u16) -> bool {
+        self.queue
+            .get(index as usize)
+            .map(| q | q.load(Ordering::SeqCst))
+            .unwrap_or(false)
+    }
+
+    /// The repair piece isn't pointed, it's only used to rebuild the source pieces.
     pub fn check_and_point(&self, desc: &PieceEncodeDesc) -> bool {
-        match desc {
-            PieceEncodeDesc::Range(index, _) => {
-                if let Some(q) = self.queue.get(*index as usize) {
+        match desc.to_range() {
+            Some((index, _)) => {
+                if let Some(q) = self.queue.get(index as usize) {
                     if let Ok(_) = q.compare_exchange(false, 
                                                       true, 
                                                       Ordering::SeqCst, 
@@ -55,23 +106,38 @@ impl IndexQueue {
                     unreachable!("fatal error, invalid index.")
                 }    
             }
+            None => false,
         }
     }
 }
 
+// The repair pieces of the block which hasn't been completed.
+struct FecBlock {
+    repairs: BTreeMap<usize, Vec<u8>>,
+}
+
 pub struct ChunkPieceRangeEncoder {
+    chunk: ChunkId,
     indices: IndexQueue,
+    blocks: Mutex<BTreeMap<u16 /* first piece index */, FecBlock>>,
+    // the pieces are written one by one, so the rebuilding never reads the piece which is being written.
+    writing: async_std::sync::Mutex<()>,
     writer: Box<dyn ChunkWriterTrait>,
+    journal: ChunkJournal,
     waiters: RwLock<StateWaiter>,
     state: Mutex<FeedbackState>,
     pending: AtomicBool,
 }
 
 impl ChunkPieceRangeEncoder {
-    pub fn new<W: ChunkWriterTrait>(chunk: &ChunkId, writer: W) -> Self {
+    pub fn new<W: ChunkWriterTrait>(chunk: &ChunkId, writer: W, journal: ChunkJournal, bitmap: Option<Vec<u8>>) -> Self {
         Self {
-            indices: IndexQueue::new(chunk),
+            chunk: chunk.clone(),
+            indices: IndexQueue::new(chunk, bitmap.as_deref()),
+            blocks: Mutex::new(BTreeMap::new()),
+            writing: async_std::sync::Mutex::new(()),
             writer: writer.clone_as_writer(),
+            journal,
             waiters: RwLock::new(StateWaiter::new()),
             state: Mutex::new(FeedbackState::Pending),
             pending: AtomicBool::new(true),
@@ -81,13 +147,151 @@ impl ChunkPieceRangeEncoder {
 
 impl ChunkPieceRangeEncoder {
     pub async fn wait_finished(&self) -> FeedbackState {
-        let waiter = { self.waiters.write().unwrap().new_waiter() };
+        let waiter = {
+            let state = &*self.state.lock().unwrap();
+
+            match state {
+                FeedbackState::Pending => self.waiters.write().unwrap().new_waiter(),
+                _ => { return state.clone(); }
+            }
+        };
 
         StateWaiter::wait(waiter, || {
             self.state.lock().unwrap().clone()
         }).await
     }
 
+    pub fn missing_ranges(&self) -> Vec<ChunkRange> {
+        self.indices.missing_ranges()
+    }
+
+    pub fn missing_ranges_in(&self, range: &ChunkRange) -> Vec<ChunkRange> {
+        self.indices.missing_ranges_in(range)
+    }
+
+    pub fn is_pending(&self) -> bool {
+        match &*self.state.lock().unwrap() {
+            FeedbackState::Pending => true,
+            _ => false,
+        }
+    }
+
+    /// The chunk has been completed in the journal, it needn't be interested again.
+    pub async fn check_finished(&self) -> bool {
+        if !self.indices.is_finished() {
+            return false;
+        }
+
+        self.on_completed().await.is_ok()
+    }
+
+    /// The chunk is verified when all of the pieces are received, 
+    /// the pieces are dropped and the NEAR_ERROR_UNMATCH is returned if it doesn't match the hash.
+    async fn on_completed(&self) -> NearResult<()> {
+        match self.journal.verify().await {
+            Ok(_) => {
+                self.finished(self.writer.clone_as_writer()).await;
+                Ok(())
+            }
+            Err(e) => {
+                error!("{e}");
+                self.journal.remove().await;
+                self.indices.reset();
+                self.blocks.lock().unwrap().clear();
+                Err(e)
+            }
+        }
+    }
+
+    fn piece_len(&self, index: u16) -> usize {
+        let offset = index as usize * PieceMessage::payload_max_len();
+        std::cmp::min(PieceMessage::payload_max_len(), self.chunk.len().saturating_sub(offset))
+    }
+
+    async fn write_piece(&self, desc: &PieceEncodeDesc, data: &[u8]) -> NearResult<()> {
+        if !self.indices.check_and_point(desc) {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_OPERATOR_COMPLETED, "completed"));
+        }
+
+        let (index, _) = match desc.to_range() {
+            Some(range) => { Ok(range) }
+            None => {
+                let error_string = format!("Encoding format error, it isn't Range.");
+                error!("{}", error_string);
+                let err = NearError::new(ErrorCode::NEAR_ERROR_ENCODING_FORMAT, error_string);
+                self.err(err.clone()).await;
+                Err(err)
+            }
+        }?;
+
+        let offset = index as usize * PieceMessage::payload_max_len();
+
+        let r = 
+            match self.writer.write(&self.chunk, offset, data).await {
+                Ok(_) => self.journal.write_piece(offset, data, || self.indices.bitmap()).await,
+                Err(e) => Err(e),
+            };
+
+        match r {
+            Ok(_) => {
+                if self.indices.is_finished() {
+                    self.on_completed().await
+                } else {
+                    Ok(())
+                }
+            }
+            Err(e) => {
+                error!("failed to write with err = {}", e);
+                self.err(e.clone()).await;
+                Err(e)
+            }
+        }
+    }
+
+    /// Rebuild the lost source pieces of the block when there are enough repair pieces.
+    async fn recover(&self, first: u16, count: u8) -> NearResult<()> {
+        let symbol_len = PieceMessage::payload_max_len();
+        let indices: Vec<u16> = (first..first + count as u16).collect();
+
+        let missing = indices.iter().filter(| index | !self.indices.is_received(**index)).count();
+        if missing == 0 {
+            self.blocks.lock().unwrap().remove(&first);
+            return Ok(());
+        }
+
+        let repairs = match self.blocks.lock().unwrap().get(&first) {
+            Some(block) if block.repairs.len() >= missing => block.repairs.clone(),
+            _ => { return Ok(()); }
+        };
+
+        let mut sources = BTreeMap::new();
+        for index in indices.iter().filter(| index | self.indices.is_received(**index)) {
+            let mut data = self.journal.read_piece(*index as usize * symbol_len, self.piece_len(*index)).await?;
+            data.resize(symbol_len, 0);
+            sources.insert((*index - first) as usize, data);
+        }
+
+        let recovered = match decode_sources(count as usize, &sources, &repairs, symbol_len) {
+            Some(recovered) => recovered,
+            None => { return Ok(()); }
+        };
+        self.blocks.lock().unwrap().remove(&first);
+
+        info!("{} pieces of {} are rebuilt from the block {}", recovered.len(), self.chunk, first);
+
+        for (symbol, mut data) in recovered {
+            let index = first + symbol as u16;
+            data.truncate(self.piece_len(index));
+
+            match self.write_piece(&PieceEncodeDesc::Range(index, data.len() as u16), &data).await {
+                Err(e) if e.errno() != ErrorCode::NEAR_ERROR_OPERATOR_COMPLETED => { return Err(e); }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
     pub(self) fn wake(&self) {
         let waker = { self.waiters.write().unwrap().transfer() };
 
@@ -103,7 +307,8 @@ impl ChunkWriterFeedbackTrait for ChunkPieceRangeEncoder {
             let state = &mut *self.state.lock().unwrap();
 
             match state {
-                FeedbackState::Error(_) => { unreachable!("Impossible process") }
+                // canceled before finished
+                FeedbackState::Error(_) => { return; }
                 FeedbackState::Finished => { /* Ignore */ }
                 FeedbackState::Pending => {
                     *state = FeedbackState::Finished;
@@ -120,7 +325,8 @@ impl ChunkWriterFeedbackTrait for ChunkPieceRangeEncoder {
 
             match state {
                 FeedbackState::Error(_) => { /* ignore */ }
-                FeedbackState::Finished => { unreachable!("Impossible process") }
+                // canceled after finished
+                FeedbackState::Finished => { /* ignore */ }
                 FeedbackState::Pending => {
                     *state = FeedbackState::Error(e);
                 }
@@ -134,7 +340,7 @@ impl ChunkWriterFeedbackTrait for ChunkPieceRangeEncoder {
 #[async_trait::async_trait]
 impl OnEventTrait for ChunkPieceRangeEncoder {
 
-    async fn on_piece_data(&self, data: &PieceMessage) -> NearResult<()> {
+    async fn on_piece_data(&self, _from: &ObjectId, data: &PieceMessage) -> NearResult<()> {
         if !self.pending.load(Ordering::SeqCst) {
             match &*self.state.lock().unwrap() {
                 FeedbackState::Pending => { unreachable!("Impossible error") }
@@ -143,34 +349,53 @@ impl OnEventTrait for ChunkPieceRangeEncoder {
             }
         }
 
-        if !self.indices.check_and_point(&data.desc) {
-            Err(NearError::new(ErrorCode::NEAR_ERROR_OPERATOR_COMPLETED, "completed"))
-        } else {
-            let (index, _) = match data.desc.to_range() {
-                Some(range) => { Ok(range) }
-                None => {
-                    let error_string = format!("Encoding format error, it isn't Range.");
-                    error!("{}", error_string);
-                    let err = NearError::new(ErrorCode::NEAR_ERROR_ENCODING_FORMAT, error_string);
-                    self.err(err.clone()).await;
-                    Err(err)
-                }
-            }?;
+        let _guard = self.writing.lock().await;
 
-            match self.writer.write(&data.chunk, index as usize * PieceMessage::payload_max_len(), &data.data).await {
-                Ok(_) => {
-                    if self.indices.is_finished() {
-                        self.finished(self.writer.clone_as_writer()).await;
+        match data.desc {
+            PieceEncodeDesc::Range(..) => {
+                self.write_piece(&data.desc, &data.data).await
+            }
+            PieceEncodeDesc::Fec(first, count, symbol, _) => {
+                if symbol < count {
+                    self.write_piece(&data.desc, &data.data).await?;
+                } else {
+                    if (first..first + count as u16).all(| index | self.indices.is_received(index)) {
+                        return Err(NearError::new(ErrorCode::NEAR_ERROR_OPERATOR_COMPLETED, "completed"));
                     }
-                    Ok(())
-                }
-                Err(e) => {
-                    error!("failed to write with err = {}", e);
-                    self.err(e.clone()).await;
-                    Err(e)
+
+                    self.blocks
+                        .lock().unwrap()
+                        .entry(first)
+                        .or_insert_with(|| FecBlock { repairs: BTreeMap::new() })
+                        .repairs
+                        .insert((symbol - count) as usize, data.data.clone());
                 }
+
+                self.recover(first, count).await
             }
         }
     }
 
 }
+
+#[test]
+fn test_index_queue() {
+    use near_base::hash_data;
+
+    // 5 pieces
+    let chunk = ChunkId::from((hash_data("test".as_bytes()), (PieceMessage::payload_max_len() * 4 + 1) as u32));
+
+    let queue = IndexQueue::new(&chunk, None);
+    assert_eq!(queue.missing_ranges().len(), 1);
+
+    assert!(queue.check_and_point(&PieceEncodeDesc::Range(1, 0)));
+    assert!(queue.check_and_point(&PieceEncodeDesc::Range(2, 0)));
+    assert!(!queue.check_and_point(&PieceEncodeDesc::Range(2, 0)));
+
+    let queue = IndexQueue::new(&chunk, Some(&queue.bitmap()));
+    let ranges = queue.missing_ranges();
+    assert_eq!(ranges.len(), 2);
+    assert_eq!((ranges[0].start, ranges[0].end), (0, 1));
+    assert_eq!((ranges[1].start, ranges[1].end), (3, 5));
+    assert!(!queue.is_finished());
+}
@@ -1,21 +1,27 @@
 
-use std::{sync::{Arc, RwLock, atomic::{AtomicUsize, }}, collections::BTreeMap, };
+use std::{sync::{Arc, RwLock, Mutex, atomic::{AtomicUsize, AtomicBool, }}, collections::{BTreeMap, BTreeSet}, time::Duration, };
 
 use log::{info, error, debug};
-use near_base::{file::FileObject, ChunkId, NearResult, ErrorCode, ObjectId, NearError, };
+use near_base::{file::FileObject, ChunkId, NearResult, ErrorCode, ObjectId, NearError, StateWaiter, now, };
 
-use crate::{nds_protocol::{PieceMessage, InterestMessage}, 
+use crate::{nds_protocol::{PieceMessage, InterestMessage, InterestMessageResponse, PieceControlMessage, PieceControlCommand, SessionData}, 
             tasks::{
                 ToSourceTrait, manager::{TaskTrait, }, 
-                DownloadSourceRef, SessionTrait
-            }, MultiDownloadSource, inc::{ChunkWriterTrait, ChunkWriterFeedbackTrait}
+                DownloadSourceRef, SessionTrait, TokenBucket,
+            }, MultiDownloadSource, inc::{ChunkWriterTrait, ChunkWriterFeedbackTrait},
+            statistic::BytePerfStatPtr,
+            NdsState,
         };
 
 use super::{chunk::{ChunkTask, }, 
             h::{OnEventTrait, DownloadTaskTrait},
             DownloadManager, DownloadRequestTrait,
+            swarm::Swarm,
     };
 
+// the sources aren't paused for the shorter delay, it's paid by the next pieces.
+const THROTTLE_MIN_DELAY: Duration = Duration::from_millis(100);
+
 // use super::{super::{{MultiDownloadSource}, },
 //             DownloadManager, 
 //             chunk::{ChunkTask, ChunkTaskWriterTrait}, OnEventTrait, DownloadTaskTrait, h::DownloadRequestTrait,
@@ -34,6 +40,7 @@ type PendingTaskStateRef = Arc<PendingTaskState>;
 
 struct PendingTaskState {
     index: AtomicUsize,
+    finished: AtomicUsize,
     tasks: BTreeMap<u32, ChunkTask>,
     tasks_ids: Vec<u32>,
     tasks_failure_array: RwLock<Vec<ChunkId>>,
@@ -55,6 +62,7 @@ impl PendingTaskState {
 
         Self {
             index: AtomicUsize::new(0),
+            finished: AtomicUsize::new(0),
             tasks,
             tasks_ids,
             tasks_failure_array: RwLock::new(vec![]),
@@ -72,6 +80,12 @@ impl PendingTaskState {
         }
     }
 
+    /// return true when all of the chunks are finished.
+    pub(self) fn finish_task(&self) -> bool {
+        self.finished.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1 == self.tasks_ids.len() &&
+        self.tasks_failure_array.read().unwrap().is_empty()
+    }
+
     pub(self) fn get_next_task(&self) -> Option<(usize, ChunkTask)> {
         let curr = self.index.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
 
@@ -94,7 +108,7 @@ impl PendingTaskState {
 
 #[async_trait::async_trait]
 impl OnEventTrait for PendingTaskState {
-    async fn on_piece_data(&self, data: &PieceMessage) -> NearResult<()> {
+    async fn on_piece_data(&self, from: &ObjectId, data: &PieceMessage) -> NearResult<()> {
         debug!("session_id=: {}, chunk: {}, desc: {}, data-len: {}", data.session_data, data.chunk, data.desc, data.data.len());
 
         let task = 
@@ -105,10 +119,18 @@ impl OnEventTrait for PendingTaskState {
                     NearError::new(ErrorCode::NEAR_ERROR_NOTFOUND, error_message)
                 })?;
 
-        task.on_piece_data(data).await
+        task.on_piece_data(from, data).await
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TransferState {
+    Pending,
+    Paused,
+    Canceled,
+    Finished,
+}
+
 struct FileTaskImpl {
     manager: DownloadManager,
     task_id: u32,
@@ -116,6 +138,16 @@ struct FileTaskImpl {
     state: Option<PendingTaskStateRef>,
     // state: RwLock<TaskStateImp>,
     source: MultiDownloadSource,
+    transfer: RwLock<TransferState>,
+    // the chunk tasks which will be started when it's resumed.
+    deferred: AtomicUsize,
+    stat: BytePerfStatPtr,
+    // woken when the transfer is finished or canceled.
+    waiter: Mutex<StateWaiter>,
+    // the download rate of the file is limited by it.
+    bucket: TokenBucket,
+    // the sources are paused by the rate limits.
+    throttled: AtomicBool,
 }
 
 #[derive(Clone)]
@@ -154,33 +186,16 @@ impl TaskTrait for FileTask {
     async fn start(&self, _: Option<Box<dyn ToSourceTrait>>) {
         info!("{} begin...", self);
 
-        let pending_state = self.0.state.clone().unwrap();
+        if self.transfer_state() == TransferState::Canceled {
+            self.manager().remove_file(self);
+            return;
+        }
 
-        {
-            // for elf.0.source.source_count();
-            let source_count = self.0.source.source_count();
-
-            for _ in 0..source_count {
-                match pending_state.get_next_task() {
-                    Some((index, task)) => {
-                        let source = match self.get_source(index) {
-                            Ok(source) => { source },
-                            Err(err) => {
-                                error!("Failed to get_source with err: {}", err);
-                                return;
-                                // todo!: feedback
-                            }
-                        };
-
-                        async_std::task::spawn(async move {
-                                task.start(Some(source.to_source())).await;
-                            });
-                    }
-                    None => {
-                        info!("All task have been startup, the {} file's task waiting finished.", self.object_id());
-                    }
-                }
-            }
+        // for elf.0.source.source_count();
+        let source_count = self.0.source.source_count();
+
+        for _ in 0..source_count {
+            self.start_next_task();
         }
 
     }
@@ -189,14 +204,31 @@ impl TaskTrait for FileTask {
 
 #[async_trait::async_trait]
 impl OnEventTrait for FileTask {
-    async fn on_piece_data(&self, data: &PieceMessage) -> NearResult<()> {
-        self.0.state.clone().unwrap().on_piece_data(data).await
+    async fn on_piece_data(&self, from: &ObjectId, data: &PieceMessage) -> NearResult<()> {
+        if self.transfer_state() == TransferState::Canceled {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_IGNORE, format!("{} has been canceled", self)));
+        }
+
+        self.0.state.clone().unwrap().on_piece_data(from, data).await?;
+
+        self.0.stat.update(now(), data.data.len() as u64);
+
+        let delay = self.manager().nds_stack().limiter().charge_download(&self.0.bucket, data.data.len());
+        if delay >= THROTTLE_MIN_DELAY {
+            self.throttle(delay).await;
+        }
+
+        Ok(())
     }
 }
 
 impl FileTask {
     pub fn new(manager: DownloadManager, file: FileObject, source: MultiDownloadSource) -> NearResult<Self> {
-        let chunks = file.body().content().chunk_list().to_vec();
+        // the repeated chunks in the file are downloaded once.
+        let chunks: Vec<ChunkId> = {
+            let mut seen = BTreeSet::new();
+            file.body().content().chunk_list().iter().filter(| chunk | seen.insert((*chunk).clone())).cloned().collect()
+        };
 
         if source.source_count() == 0 {
             error!("Failed to create file-task, because the source is none.");
@@ -205,6 +237,10 @@ impl FileTask {
 
         let task_id = manager.task_gen_id().generate().into_value();
 
+        // the downloaded chunks aren't evicted before the file is finished.
+        manager.nds_stack().chunk_manager().pin_file(&file);
+        let stat = manager.nds_stack().statistic_manager().get(&file);
+        let swarm = Swarm::new(manager.config().swarm.clone(), source.clone());
         let ret = Self(Arc::new(FileTaskImpl{
             manager: manager.clone(),
             task_id,
@@ -213,6 +249,12 @@ impl FileTask {
             // chunks,
             // state: RwLock::new(TaskStateImp::None),
             source,
+            transfer: RwLock::new(TransferState::Pending),
+            deferred: AtomicUsize::new(0),
+            stat,
+            waiter: Mutex::new(StateWaiter::new()),
+            bucket: TokenBucket::new(),
+            throttled: AtomicBool::new(false),
         }));
 
 
@@ -225,7 +267,9 @@ impl FileTask {
                                      chunk.clone(),
                                      Box::new(ret.clone()) as Box<dyn DownloadRequestTrait>,
                                      Box::new(ret.clone()) as Box<dyn ChunkWriterFeedbackTrait>,
-                                     Some(Box::new(ret.clone()) as Box<dyn SessionTrait>)) {
+                                     Some(Box::new(ret.clone()) as Box<dyn SessionTrait>),
+                                     manager.journal().chunk(ret.file().object_id(), chunk),
+                                     swarm.clone()) {
                     Ok(task) => { arrays.push(task); }
                     Err(e) => {
                         error!("Failed to create chunk {} task with err = {}", chunk, e);
@@ -282,11 +326,21 @@ impl FileTask {
     pub fn manager(&self) -> &DownloadManager {
         &self.0.manager
     }
-}
 
-#[async_trait::async_trait]
-impl ChunkWriterFeedbackTrait for FileTask {
-    async fn finished(&self, _: Box<dyn ChunkWriterTrait>) {
+    fn transfer_state(&self) -> TransferState {
+        *self.0.transfer.read().unwrap()
+    }
+
+    fn start_next_task(&self) {
+        match self.transfer_state() {
+            TransferState::Pending => {}
+            TransferState::Paused => {
+                self.0.deferred.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                return;
+            }
+            TransferState::Canceled | TransferState::Finished => { return; }
+        }
+
         let pending_state = self.0.state.clone().unwrap();
 
         match pending_state.get_next_task() {
@@ -304,8 +358,184 @@ impl ChunkWriterFeedbackTrait for FileTask {
                 });
             }
             None => {
-                info!("All tasks are over.");
+                info!("All task have been startup, the {} file's task waiting finished.", self.object_id());
+            }
+        }
+    }
+
+    async fn control_sources(&self, command: PieceControlCommand) {
+        let message = PieceControlMessage {
+            session_data: SessionData {
+                session_id: self.session_id(),
+                session_sub_id: 0,
+            },
+            command,
+        };
+
+        for source in self.0.source.source() {
+            if let Err(e) = self.manager().nds_stack().control_session(source.as_ref().target(), message.clone()).await {
+                error!("failed {} {} with err = {e}", command, source.as_ref().target());
+            }
+        }
+    }
+
+    /// Pause the sources until the received bytes are paid off by the rate limits.
+    async fn throttle(&self, delay: Duration) {
+        if self.0.throttled.swap(true, std::sync::atomic::Ordering::SeqCst) {
+            return;
+        }
+
+        debug!("{} is throttled for {:?}", self, delay);
+        self.control_sources(PieceControlCommand::Pause).await;
+
+        // the chunks aren't stalled while the sources are held.
+        let pending_state = self.0.state.clone().unwrap();
+        pending_state.tasks.values().for_each(| task | task.set_paused(true));
+
+        let arc_self = self.clone();
+        async_std::task::spawn(async move {
+            async_std::task::sleep(delay).await;
+            arc_self.0.throttled.store(false, std::sync::atomic::Ordering::SeqCst);
+
+            // the transfer may be paused or canceled by the user in the meantime.
+            if arc_self.transfer_state() == TransferState::Pending {
+                arc_self.control_sources(PieceControlCommand::Continue).await;
+
+                let pending_state = arc_self.0.state.clone().unwrap();
+                pending_state.tasks.values().for_each(| task | task.set_paused(false));
+            }
+        });
+    }
+
+    pub fn state(&self) -> NdsState {
+        match self.transfer_state() {
+            TransferState::Pending => NdsState::Pending(self.0.stat.clone()),
+            TransferState::Paused => NdsState::Paused(self.0.stat.clone()),
+            TransferState::Canceled => NdsState::Finished(Err(NearError::new(ErrorCode::NEAR_ERROR_IGNORE, "canceled"))),
+            TransferState::Finished => NdsState::Finished(Ok(())),
+        }
+    }
+
+    /// Wait until the transfer is finished or canceled.
+    pub async fn wait(&self) -> NearResult<()> {
+        let waiter = {
+            let waiter = &mut *self.0.waiter.lock().unwrap();
+            match self.transfer_state() {
+                TransferState::Pending | TransferState::Paused => waiter.new_waiter(),
+                _ => { return self.result(); }
             }
+        };
+
+        StateWaiter::wait(waiter, || self.result()).await
+    }
+
+    fn result(&self) -> NearResult<()> {
+        match self.state() {
+            NdsState::Finished(r) => r,
+            _ => Err(NearError::new(ErrorCode::NEAR_ERROR_STATE, format!("{} isn't finished", self))),
+        }
+    }
+
+    fn wake(&self) {
+        self.0.waiter.lock().unwrap().transfer().wake();
+    }
+
+    pub async fn pause(&self) -> NearResult<()> {
+        {
+            let state = &mut *self.0.transfer.write().unwrap();
+            if *state != TransferState::Pending {
+                return Err(NearError::new(ErrorCode::NEAR_ERROR_STATE, format!("{} isn't downloading", self)));
+            }
+            *state = TransferState::Paused;
+        }
+
+        info!("{} paused", self);
+        self.control_sources(PieceControlCommand::Pause).await;
+
+        let pending_state = self.0.state.clone().unwrap();
+        pending_state.tasks.values().for_each(| task | task.set_paused(true));
+
+        Ok(())
+    }
+
+    pub async fn resume(&self) -> NearResult<()> {
+        {
+            let state = &mut *self.0.transfer.write().unwrap();
+            if *state != TransferState::Paused {
+                return Err(NearError::new(ErrorCode::NEAR_ERROR_STATE, format!("{} isn't paused", self)));
+            }
+            *state = TransferState::Pending;
+        }
+
+        info!("{} resumed", self);
+        self.control_sources(PieceControlCommand::Continue).await;
+
+        let pending_state = self.0.state.clone().unwrap();
+        pending_state.tasks.values().for_each(| task | task.set_paused(false));
+
+        for _ in 0..self.0.deferred.swap(0, std::sync::atomic::Ordering::SeqCst) {
+            self.start_next_task();
+        }
+
+        Ok(())
+    }
+
+    /// Stop the transfer, and drop its partial chunks and statistics.
+    pub async fn cancel(&self) -> NearResult<()> {
+        {
+            let state = &mut *self.0.transfer.write().unwrap();
+            match *state {
+                TransferState::Pending | TransferState::Paused => { *state = TransferState::Canceled; }
+                _ => {
+                    return Err(NearError::new(ErrorCode::NEAR_ERROR_STATE, format!("{} has been finished", self)));
+                }
+            }
+        }
+
+        info!("{} canceled", self);
+        self.control_sources(PieceControlCommand::Cancel).await;
+
+        let pending_state = self.0.state.clone().unwrap();
+        let e = NearError::new(ErrorCode::NEAR_ERROR_IGNORE, "canceled");
+        for task in pending_state.tasks.values() {
+            task.cancel(e.clone()).await;
+            self.manager().nds_stack().chunk_manager().remove_write_view(task.chunk());
+        }
+
+        self.manager().remove_file(self);
+        self.manager().journal().remove_task(self.file().object_id());
+        self.manager().nds_stack().statistic_manager().remove(self.file().object_id());
+        self.manager().nds_stack().chunk_manager().unpin_file(self.file());
+        self.wake();
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ChunkWriterFeedbackTrait for FileTask {
+    async fn finished(&self, _: Box<dyn ChunkWriterTrait>) {
+        let pending_state = self.0.state.clone().unwrap();
+
+        if pending_state.finish_task() {
+            info!("{} has been downloaded.", self);
+
+            {
+                let state = &mut *self.0.transfer.write().unwrap();
+                if *state == TransferState::Canceled {
+                    return;
+                }
+                *state = TransferState::Finished;
+            }
+
+            self.control_sources(PieceControlCommand::Finish).await;
+            self.manager().remove_file(self);
+            self.manager().journal().remove_task(self.file().object_id());
+            self.manager().nds_stack().statistic_manager().remove(self.file().object_id());
+            self.manager().nds_stack().chunk_manager().unpin_file(self.file());
+            self.wake();
+        } else {
+            self.start_next_task();
         }
     }
 
@@ -337,7 +567,7 @@ impl DownloadRequestTrait for FileTask {
         self.manager().nds_stack().interest_chunk(target, chunk, session).await
     }
 
-    async fn interest_chunk_v2(&self, target: DownloadSourceRef, object_id: Option<ObjectId>, message: InterestMessage) -> NearResult<()> {
+    async fn interest_chunk_v2(&self, target: DownloadSourceRef, object_id: Option<ObjectId>, message: InterestMessage) -> NearResult<InterestMessageResponse> {
         self.manager().nds_stack().interest_chunk_v2(target, object_id, message).await
     }
 }
@@ -1,11 +1,12 @@
 
-use near_base::{NearResult, };
+use near_base::{NearResult, ObjectId, };
 
 use crate::{tasks::{manager::TaskTrait, }, nds_protocol::PieceMessage};
 
 #[async_trait::async_trait]
 pub trait OnEventTrait: Send + Sync {
-    async fn on_piece_data(&self, data: &PieceMessage) -> NearResult<()>;
+    /// from is the source which pushed the piece.
+    async fn on_piece_data(&self, from: &ObjectId, data: &PieceMessage) -> NearResult<()>;
 }
 
 pub trait DownloadTaskTrait : TaskTrait + OnEventTrait {
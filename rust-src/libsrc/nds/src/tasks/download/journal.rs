@@ -0,0 +1,291 @@
+
+use std::{path::{Path, PathBuf}, io::{ErrorKind, SeekFrom}, sync::Arc};
+
+use async_std::{io::{ReadExt, WriteExt, prelude::SeekExt}, sync::Mutex};
+use log::{warn, info};
+use near_base::{file::FileObject, ChunkId, ObjectId, NearResult, NearError, ErrorCode, hash_data,
+                Serialize, Deserialize, FileEncoder, FileDecoder, };
+
+use crate::chunks::MemChunk;
+
+const JOURNAL_DIR: &'static str = "downloads";
+const TASK_FILE: &'static str = "task";
+const BITMAP_EXTENSION: &'static str = "bitmap";
+
+/// The unfinished download task, saved so that it can be reloaded when the stack restarts.
+pub struct TaskRecord {
+    pub file: FileObject,
+    pub sources: Vec<ObjectId>,
+}
+
+impl Serialize for TaskRecord {
+    fn raw_capacity(&self) -> usize {
+        self.file.raw_capacity() +
+        self.sources.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = self.file.serialize(buf)?;
+        let buf = self.sources.serialize(buf)?;
+
+        Ok(buf)
+    }
+}
+
+impl Deserialize for TaskRecord {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (file, buf) = FileObject::deserialize(buf)?;
+        let (sources, buf) = Vec::<ObjectId>::deserialize(buf)?;
+
+        Ok((Self{
+            file, sources,
+        }, buf))
+    }
+}
+
+/// {data_path}/downloads/{file-id}/
+///     task                    TaskRecord
+///     {chunk}                 partially written chunk data
+///     {chunk}.bitmap          received pieces, one bit per piece
+#[derive(Clone)]
+pub struct Journal {
+    root: PathBuf,
+}
+
+impl Journal {
+    pub fn new(data_path: &Path) -> Self {
+        Self {
+            root: data_path.join(JOURNAL_DIR),
+        }
+    }
+
+    fn task_path(&self, file_id: &ObjectId) -> PathBuf {
+        self.root.join(file_id.to_string())
+    }
+
+    pub fn save_task(&self, record: &TaskRecord) -> NearResult<()> {
+        let task_path = self.task_path(record.file.object_id());
+
+        std::fs::create_dir_all(&task_path)
+            .map_err(| e | {
+                NearError::new(ErrorCode::NEAR_ERROR_SYSTERM, format!("failed create [{}] with err = {e}", task_path.display()))
+            })?;
+
+        record.encode_to_file(task_path.join(TASK_FILE).as_path(), false)
+            .map(| _ | ())
+    }
+
+    /// All unfinished tasks, the broken records are removed.
+    pub fn load_tasks(&self) -> Vec<TaskRecord> {
+        let dir = match std::fs::read_dir(&self.root) {
+            Ok(dir) => dir,
+            Err(_) => return vec![],
+        };
+
+        dir.filter_map(| entry | entry.ok())
+            .filter(| entry | entry.path().is_dir())
+            .filter_map(| entry | {
+                match TaskRecord::decode_from_file(entry.path().join(TASK_FILE).as_path()) {
+                    Ok(record) => Some(record),
+                    Err(e) => {
+                        warn!("failed load download journal [{}] with err = {e}, it will be removed.", entry.path().display());
+                        let _ = std::fs::remove_dir_all(entry.path());
+                        None
+                    }
+                }
+            })
+            .collect()
+    }
+
+    pub fn remove_task(&self, file_id: &ObjectId) {
+        let task_path = self.task_path(file_id);
+
+        if let Err(e) = std::fs::remove_dir_all(&task_path) {
+            if e.kind() != ErrorKind::NotFound {
+                warn!("failed remove download journal [{}] with err = {e}", task_path.display());
+            }
+        }
+    }
+
+    pub fn chunk(&self, file_id: &ObjectId, chunk: &ChunkId) -> ChunkJournal {
+        let task_path = self.task_path(file_id);
+
+        ChunkJournal(Arc::new(ChunkJournalImpl {
+            chunk: chunk.clone(),
+            data_path: task_path.join(chunk.to_string58()),
+            bitmap_path: task_path.join(chunk.to_string58()).with_extension(BITMAP_EXTENSION),
+            lock: Mutex::new(()),
+        }))
+    }
+}
+
+struct ChunkJournalImpl {
+    chunk: ChunkId,
+    data_path: PathBuf,
+    bitmap_path: PathBuf,
+    lock: Mutex<()>,
+}
+
+#[derive(Clone)]
+pub struct ChunkJournal(Arc<ChunkJournalImpl>);
+
+impl ChunkJournal {
+    pub fn chunk(&self) -> &ChunkId {
+        &self.0.chunk
+    }
+
+    /// Load the partial chunk and its piece bitmap, the journal is dropped when it doesn't match the chunk.
+    pub async fn load(&self) -> Option<(MemChunk, Vec<u8>)> {
+        let _guard = self.0.lock.lock().await;
+
+        let data = async_std::fs::read(&self.0.data_path).await.ok()?;
+        let bitmap = async_std::fs::read(&self.0.bitmap_path).await.ok()?;
+
+        if data.len() != self.chunk().len() {
+            warn!("the journal of {} is broken, data-len = {}, it will be dropped.", self.chunk(), data.len());
+            self.remove_files().await;
+            return None;
+        }
+
+        let chunk = MemChunk::with_data(self.chunk().clone(), data);
+
+        if self.is_completed(&bitmap) {
+            if !self.verify_data(chunk.as_ref()) {
+                warn!("the journal of {} doesn't match the chunk hash, it will be dropped.", self.chunk());
+                self.remove_files().await;
+                return None;
+            }
+        }
+
+        info!("reload the journal of {}", self.chunk());
+
+        Some((chunk, bitmap))
+    }
+
+    pub fn is_completed(&self, bitmap: &[u8]) -> bool {
+        let piece_count = piece_count(self.chunk());
+
+        (0..piece_count).all(| index | {
+            bitmap.get(index / 8)
+                .map(| bits | bits & (1 << (index % 8)) != 0)
+                .unwrap_or(false)
+        })
+    }
+
+    fn verify_data(&self, data: &[u8]) -> bool {
+        ChunkId::from((hash_data(data), data.len() as u32)) == *self.chunk()
+    }
+
+    /// Write the piece and the bitmap, the bitmap is written after the data so it never marks a piece which isn't on the disk.
+    pub async fn write_piece(&self, offset: usize, data: &[u8], bitmap: impl FnOnce() -> Vec<u8>) -> NearResult<()> {
+        let _guard = self.0.lock.lock().await;
+
+        let mut file =
+            async_std::fs::OpenOptions::new()
+                .create(true)
+                .write(true)
+                .open(&self.0.data_path)
+                .await
+                .map_err(| e | {
+                    NearError::new(ErrorCode::NEAR_ERROR_SYSTERM, format!("failed open [{}] with err = {e}", self.0.data_path.display()))
+                })?;
+
+        if file.metadata().await.map(| m | m.len() as usize).unwrap_or(0) != self.chunk().len() {
+            file.set_len(self.chunk().len() as u64)
+                .await
+                .map_err(| e | {
+                    NearError::new(ErrorCode::NEAR_ERROR_SYSTERM, format!("failed resize [{}] with err = {e}", self.0.data_path.display()))
+                })?;
+        }
+
+        file.seek(SeekFrom::Start(offset as u64))
+            .await
+            .map_err(| e | {
+                NearError::new(ErrorCode::NEAR_ERROR_SYSTERM, format!("failed seek [{}] with err = {e}", self.0.data_path.display()))
+            })?;
+
+        file.write_all(data)
+            .await
+            .map_err(| e | {
+                NearError::new(ErrorCode::NEAR_ERROR_SYSTERM, format!("failed write [{}] with err = {e}", self.0.data_path.display()))
+            })?;
+        let _ = file.flush().await;
+
+        async_std::fs::write(&self.0.bitmap_path, bitmap())
+            .await
+            .map_err(| e | {
+                NearError::new(ErrorCode::NEAR_ERROR_SYSTERM, format!("failed write [{}] with err = {e}", self.0.bitmap_path.display()))
+            })
+    }
+
+    pub async fn read_piece(&self, offset: usize, length: usize) -> NearResult<Vec<u8>> {
+        let _guard = self.0.lock.lock().await;
+
+        let mut file =
+            async_std::fs::File::open(&self.0.data_path)
+                .await
+                .map_err(| e | {
+                    NearError::new(ErrorCode::NEAR_ERROR_SYSTERM, format!("failed open [{}] with err = {e}", self.0.data_path.display()))
+                })?;
+
+        file.seek(SeekFrom::Start(offset as u64))
+            .await
+            .map_err(| e | {
+                NearError::new(ErrorCode::NEAR_ERROR_SYSTERM, format!("failed seek [{}] with err = {e}", self.0.data_path.display()))
+            })?;
+
+        let mut data = vec![0u8; length];
+        file.read_exact(&mut data)
+            .await
+            .map_err(| e | {
+                NearError::new(ErrorCode::NEAR_ERROR_SYSTERM, format!("failed read [{}] with err = {e}", self.0.data_path.display()))
+            })?;
+
+        Ok(data)
+    }
+
+    /// Check the whole chunk data on the disk against the chunk hash.
+    pub async fn verify(&self) -> NearResult<()> {
+        let _guard = self.0.lock.lock().await;
+
+        let mut data = Vec::with_capacity(self.chunk().len());
+        async_std::fs::File::open(&self.0.data_path)
+            .await
+            .map_err(| e | {
+                NearError::new(ErrorCode::NEAR_ERROR_SYSTERM, format!("failed open [{}] with err = {e}", self.0.data_path.display()))
+            })?
+            .read_to_end(&mut data)
+            .await
+            .map_err(| e | {
+                NearError::new(ErrorCode::NEAR_ERROR_SYSTERM, format!("failed read [{}] with err = {e}", self.0.data_path.display()))
+            })?;
+
+        if self.verify_data(&data) {
+            Ok(())
+        } else {
+            Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("the data of {} doesn't match the hash", self.chunk())))
+        }
+    }
+
+    pub async fn remove(&self) {
+        let _guard = self.0.lock.lock().await;
+        self.remove_files().await;
+    }
+
+    async fn remove_files(&self) {
+        let _ = async_std::fs::remove_file(&self.0.data_path).await;
+        let _ = async_std::fs::remove_file(&self.0.bitmap_path).await;
+    }
+}
+
+pub(super) fn piece_count(chunk: &ChunkId) -> usize {
+    let chunk_len = chunk.len();
+    let piece_len = crate::nds_protocol::PieceMessage::payload_max_len();
+
+    if chunk_len % piece_len == 0 {
+        chunk_len / piece_len
+    } else {
+        chunk_len / piece_len + 1
+    }
+}
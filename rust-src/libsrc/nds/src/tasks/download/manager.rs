@@ -2,22 +2,25 @@
 use std::{sync::{Arc, RwLock}, time::Duration, collections::BTreeMap};
 
 use async_std::task::JoinHandle;
-use log::{info, };
-use near_base::{NearResult, queue::Queue, file::FileObject, Sequence, ErrorCode, NearError};
+use log::{info, error, };
+use near_base::{NearResult, queue::Queue, file::FileObject, Sequence, ErrorCode, NearError, ObjectId};
 use near_core::near_error;
 
 use crate::{NdsStack, 
             nds_protocol::PieceMessage, 
-            tasks::manager::Manager as TaskManager,
-            MultiDownloadSource, };
+            tasks::{manager::Manager as TaskManager, SessionTrait},
+            MultiDownloadSource, SingleDownloadSource, DownloadSource, };
 
 use super::{DownloadFileTask,
-            h::DownloadTaskTrait
+            h::DownloadTaskTrait,
+            journal::{Journal, TaskRecord},
+            swarm::Config as SwarmConfig,
         };
 
 #[derive(Clone)]
 pub struct Config {
     pub work_tasks: usize,
+    pub swarm: SwarmConfig,
 }
 
 struct ManagerImpl {
@@ -25,10 +28,12 @@ struct ManagerImpl {
     task_gen_id: Sequence,
     parent: TaskManager,
     config: Config,
+    journal: Journal,
     task_array: Vec<JoinHandle<()>>,
     // queue: Queue<Box<dyn TaskTrait>>,
     queue: Queue<Box<dyn DownloadTaskTrait>>,
     working_array: RwLock<BTreeMap<u32 /* task-id */, Box<dyn DownloadTaskTrait>>>,
+    files: RwLock<BTreeMap<ObjectId /* file-id */, DownloadFileTask>>,
 }
 
 #[derive(Clone)]
@@ -36,14 +41,17 @@ pub struct Manager(Arc<ManagerImpl>);
 
 impl Manager {
     pub fn open(stack: NdsStack, parent: TaskManager, config: Config) -> NearResult<Self> {
+        let journal = Journal::new(&stack.nds_config().data_path);
         let ret = Self(Arc::new(ManagerImpl{
             stack,
             task_gen_id: Sequence::random(),
             parent,
             config: config.clone(),
+            journal,
             task_array: vec![],
             queue: Queue::default(),
             working_array: RwLock::new(BTreeMap::new()),
+            files: RwLock::new(BTreeMap::new()),
         }));
 
         let mut task_array = vec![];
@@ -81,17 +89,82 @@ impl Manager {
     pub(super) fn task_gen_id(&self) -> &Sequence {
         &self.0.task_gen_id
     }
+
+    pub(super) fn config(&self) -> &Config {
+        &self.0.config
+    }
+
+    pub(super) fn journal(&self) -> &Journal {
+        &self.0.journal
+    }
+
+    pub(super) fn remove_file(&self, file_task: &DownloadFileTask) {
+        self.0.files.write().unwrap().remove(file_task.file().object_id());
+        self.0.working_array.write().unwrap().remove(&file_task.session_id());
+    }
+
+    fn add_file(&self, file_task: &DownloadFileTask) {
+        self.0.files.write().unwrap().insert(file_task.file().object_id().clone(), file_task.clone());
+        self.0.queue.push(file_task.clone_as_downloadtask(), None);
+    }
+
+    pub fn file_task(&self, file_id: &ObjectId) -> Option<DownloadFileTask> {
+        self.0.files.read().unwrap().get(file_id).cloned()
+    }
+
+    pub fn file_tasks(&self) -> Vec<DownloadFileTask> {
+        self.0.files.read().unwrap().values().cloned().collect()
+    }
 }
 
 impl Manager {
     pub fn download_file(&self, file: FileObject, source: MultiDownloadSource) -> NearResult<()> {
+        if self.file_task(file.object_id()).is_some() {
+            info!("The {} is downloading.", file.object_id());
+            return Ok(());
+        }
+
+        let record = TaskRecord {
+            file: file.clone(),
+            sources: source.source().iter().map(| source | source.as_ref().target().clone()).collect(),
+        };
+
         let file_task = DownloadFileTask::new(self.clone(), file, source)?;
 
-        self.0.queue.push(file_task.clone_as_downloadtask());
+        if let Err(e) = self.journal().save_task(&record) {
+            error!("failed save the journal of {} with err = {e}, it couldn't be resumed.", record.file.object_id());
+        }
+
+        self.add_file(&file_task);
 
         Ok(())
     }
 
+    /// Reload the unfinished tasks from the journal, the received pieces aren't interested again.
+    pub fn restore(&self) {
+        for record in self.journal().load_tasks() {
+            let file_id = record.file.object_id().clone();
+
+            let source = 
+                record.sources
+                    .into_iter()
+                    .fold(MultiDownloadSource::new(), | source, target | {
+                        source.add_source(SingleDownloadSource::from(DownloadSource::default().set_target(target)))
+                    });
+
+            match DownloadFileTask::new(self.clone(), record.file, source) {
+                Ok(file_task) => {
+                    info!("resume download {}", file_id);
+                    self.add_file(&file_task);
+                }
+                Err(e) => {
+                    error!("failed resume download {} with err = {e}", file_id);
+                    self.journal().remove_task(&file_id);
+                }
+            }
+        }
+    }
+
     async fn run(&self) {
         loop {
             if let Some(task) = self.0.queue.wait_and_take(Duration::from_secs(1)).await {
@@ -117,7 +190,7 @@ impl Manager {
 }
 
 impl Manager {
-    pub async fn on_piece_data(&self, data: &PieceMessage) -> NearResult<()> {
+    pub async fn on_piece_data(&self, from: &ObjectId, data: &PieceMessage) -> NearResult<()> {
         let woring_chunk = {
             self.0.working_array.read().unwrap()
                 .get(&data.session_data.session_id)
@@ -127,6 +200,6 @@ impl Manager {
                 )
         }?;
 
-        woring_chunk.on_piece_data(data).await
+        woring_chunk.on_piece_data(from, data).await
     }
 }
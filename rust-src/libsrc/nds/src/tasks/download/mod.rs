@@ -5,19 +5,25 @@ mod chunk;
 mod unsafe_array;
 mod h;
 mod encoder;
+mod journal;
+mod swarm;
+mod dir;
 
 pub use manager::{Manager as DownloadManager, Config as DownloadConfig};
+pub use swarm::{Config as SwarmConfig};
 pub use file::{FileTask as DownloadFileTask};
 pub use chunk::{ChunkTask as DownloadChunkTask};
+pub use dir::{DirTask as DownloadDirTask};
+pub(crate) use dir::{walk_files, mode_of, mtime_of};
 
 use near_base::{ChunkId, ObjectId, NearResult};
 
-use crate::nds_protocol::InterestMessage;
+use crate::nds_protocol::{InterestMessage, InterestMessageResponse};
 
 use super::{DownloadSourceRef, SessionTrait, };
 
 #[async_trait::async_trait]
 pub trait DownloadRequestTrait: Send + Sync {
     async fn interest_chunk(&self, target: DownloadSourceRef, chunk: &ChunkId, session: Option<Box<dyn SessionTrait>>);
-    async fn interest_chunk_v2(&self, target: DownloadSourceRef, object_id: Option<ObjectId>, message: InterestMessage) -> NearResult<()>;
+    async fn interest_chunk_v2(&self, target: DownloadSourceRef, object_id: Option<ObjectId>, message: InterestMessage) -> NearResult<InterestMessageResponse>;
 }
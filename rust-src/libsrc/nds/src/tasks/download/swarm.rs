@@ -0,0 +1,263 @@
+
+use std::{sync::{Arc, RwLock}, collections::BTreeMap, time::Duration};
+
+use log::{info, warn};
+use near_base::{ObjectId, Timestamp, now};
+
+use crate::{nds_protocol::{ChunkRange, FecParams}, MultiDownloadSource, SingleDownloadSource, DownloadSource};
+
+use super::super::{DownloadSourceRef, ToSourceTrait};
+
+#[derive(Clone)]
+pub struct Config {
+    /// How many pieces are requested from one source at once.
+    pub stripe_pieces: u32,
+    /// The stripe is requested from another source if none of its pieces arrived in it.
+    pub stall_timeout: Duration,
+    /// The source is disabled when it stalled so many times.
+    pub max_source_errors: u32,
+    /// The pieces are interested with the repair pieces when the source accepted it, none if it's disabled.
+    pub fec: Option<FecParams>,
+}
+
+impl std::default::Default for Config {
+    fn default() -> Self {
+        Self {
+            stripe_pieces: 64,
+            stall_timeout: Duration::from_secs(10),
+            max_source_errors: 3,
+            fec: None,
+        }
+    }
+}
+
+#[derive(Default)]
+struct SourceScore {
+    begin: Timestamp,
+    last: Timestamp,
+    bytes: u64,
+    errors: u32,
+    // none until the source answered the interest.
+    fec: Option<bool>,
+}
+
+impl SourceScore {
+    /// bytes per second, none if it hasn't pushed anything.
+    fn throughput(&self) -> Option<f64> {
+        if self.bytes == 0 {
+            return None;
+        }
+
+        let duration = std::cmp::max(self.last.saturating_sub(self.begin), 1_000) as f64 / 1_000_000f64;
+        Some(self.bytes as f64 / duration)
+    }
+}
+
+struct SwarmImpl {
+    config: Config,
+    sources: MultiDownloadSource,
+    scores: RwLock<BTreeMap<ObjectId, SourceScore>>,
+}
+
+/// All of the sources of the file download, the faster and stabler source is requested more.
+#[derive(Clone)]
+pub struct Swarm(Arc<SwarmImpl>);
+
+impl Swarm {
+    pub fn new(config: Config, sources: MultiDownloadSource) -> Self {
+        Self(Arc::new(SwarmImpl {
+            config,
+            sources,
+            scores: RwLock::new(BTreeMap::new()),
+        }))
+    }
+
+    #[inline]
+    pub fn config(&self) -> &Config {
+        &self.0.config
+    }
+
+    pub fn add_source(&self, target: ObjectId) {
+        let existed =
+            self.all_sources()
+                .iter()
+                .any(| source | source.target() == &target);
+
+        if !existed {
+            let _ = self.0.sources.clone().add_source(SingleDownloadSource::from(DownloadSource::default().set_target(target)));
+        }
+    }
+
+    fn all_sources(&self) -> Vec<DownloadSourceRef> {
+        (0..self.0.sources.source_count())
+            .map(| index | self.0.sources.source_of(index))
+            .collect()
+    }
+
+    /// The enabled sources, the best is the first.
+    pub fn sources(&self) -> Vec<DownloadSourceRef> {
+        let mut sources: Vec<(DownloadSourceRef, f64)> =
+            self.all_sources()
+                .into_iter()
+                .filter(| source | source.is_enabled())
+                .map(| source | {
+                    let weight = self.weight(source.target());
+                    (source, weight)
+                })
+                .collect();
+
+        sources.sort_by(| (_, a), (_, b) | b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+        sources.into_iter().map(| (source, _) | source).collect()
+    }
+
+    fn weight(&self, target: &ObjectId) -> f64 {
+        let scores = self.0.scores.read().unwrap();
+
+        // the unmeasured source is expected as fast as the average.
+        let average = {
+            let measured: Vec<f64> = scores.values().filter_map(| score | score.throughput()).collect();
+            if measured.is_empty() {
+                1f64
+            } else {
+                measured.iter().sum::<f64>() / measured.len() as f64
+            }
+        };
+
+        match scores.get(target) {
+            Some(score) => score.throughput().unwrap_or(average) / (1 + score.errors) as f64,
+            None => average,
+        }
+    }
+
+    pub fn on_piece(&self, from: &ObjectId, bytes: usize) {
+        let now = now();
+        let scores = &mut *self.0.scores.write().unwrap();
+        let score = scores.entry(from.clone()).or_insert_with(|| SourceScore { begin: now, ..Default::default() });
+
+        score.last = now;
+        score.bytes += bytes as u64;
+    }
+
+    /// The FEC params which the source is interested with, none until the source accepted the FEC.
+    pub fn fec_of(&self, target: &ObjectId) -> Option<FecParams> {
+        let params = self.config().fec?;
+
+        match self.0.scores.read().unwrap().get(target).and_then(| score | score.fec) {
+            Some(true) => Some(params),
+            _ => None,
+        }
+    }
+
+    /// The source answered the interest, the old peer doesn't accept the FEC.
+    pub fn on_negotiated(&self, from: &ObjectId, fec: bool) {
+        let scores = &mut *self.0.scores.write().unwrap();
+        let score = scores.entry(from.clone()).or_insert_with(|| SourceScore { begin: now(), ..Default::default() });
+
+        if score.fec != Some(fec) {
+            info!("The source {} {} the fec.", from, if fec { "accepts" } else { "doesn't accept" });
+            score.fec = Some(fec);
+        }
+    }
+
+    /// The source hasn't pushed the requested pieces in time.
+    pub fn on_stall(&self, from: &ObjectId) {
+        let errors = {
+            let scores = &mut *self.0.scores.write().unwrap();
+            let score = scores.entry(from.clone()).or_insert_with(|| SourceScore { begin: now(), ..Default::default() });
+            score.errors += 1;
+            score.errors
+        };
+
+        if errors >= self.config().max_source_errors {
+            warn!("The source {} stalled {} times, it's disabled.", from, errors);
+            self.disable(from);
+        }
+    }
+
+    /// The source pushed the data which doesn't match the chunk.
+    pub fn blacklist(&self, from: &ObjectId) {
+        warn!("The source {} pushed the broken data, it's blacklisted.", from);
+        self.disable(from);
+    }
+
+    fn disable(&self, target: &ObjectId) {
+        let enabled = self.sources();
+
+        // keep the last source.
+        if enabled.len() <= 1 {
+            info!("{} is the last source, it's kept.", target);
+            return;
+        }
+
+        if let Some(source) = enabled.iter().find(| source | source.target() == target) {
+            source.disabled();
+        }
+    }
+
+    /// Split the ranges into stripes, and assign them in proportion to the source weights.
+    pub fn split(&self, ranges: Vec<ChunkRange>) -> Vec<(ChunkRange, DownloadSourceRef)> {
+        let sources = self.sources();
+        if sources.is_empty() {
+            return vec![];
+        }
+
+        let weights: Vec<f64> = sources.iter().map(| source | self.weight(source.target()).max(f64::MIN_POSITIVE)).collect();
+        let mut assigned = vec![0u32; sources.len()];
+        let stripe_pieces = std::cmp::max(self.config().stripe_pieces, 1);
+
+        let mut stripes = vec![];
+        for range in ranges {
+            let mut start = range.start;
+            while start < range.end {
+                let end = std::cmp::min(start + stripe_pieces, range.end);
+
+                // the source with the least load of its weight.
+                let (index, _) =
+                    weights.iter()
+                        .enumerate()
+                        .map(| (index, weight) | (index, (assigned[index] + 1) as f64 / weight))
+                        .fold((0, f64::MAX), | best, curr | if curr.1 < best.1 { curr } else { best });
+
+                assigned[index] += 1;
+                stripes.push((ChunkRange { start, end }, sources[index].clone()));
+
+                start = end;
+            }
+        }
+
+        stripes
+    }
+}
+
+#[test]
+fn test_swarm_split() {
+    use near_base::{ChunkId, hash_data};
+
+    let a = ChunkId::from((hash_data("a".as_bytes()), 1)).to_objectid();
+    let b = ChunkId::from((hash_data("b".as_bytes()), 1)).to_objectid();
+
+    let swarm = Swarm::new(Config { stripe_pieces: 10, fec: Some(FecParams::default()), ..Default::default() }, MultiDownloadSource::new());
+    swarm.add_source(a.clone());
+    swarm.add_source(b);
+
+    let stripes = swarm.split(vec![ChunkRange { start: 0, end: 40 }]);
+    assert_eq!(stripes.len(), 4);
+    assert_eq!(stripes.iter().filter(| (_, source) | source.target() == &a).count(), 2);
+
+    // the broken source isn't requested any more.
+    swarm.blacklist(&a);
+    let stripes = swarm.split(vec![ChunkRange { start: 0, end: 40 }]);
+    assert!(stripes.iter().all(| (_, source) | source.target() != &a));
+
+    // the stream is interested until the source accepted the fec.
+    assert!(swarm.fec_of(&a).is_none());
+    swarm.on_negotiated(&a, true);
+    assert!(swarm.fec_of(&a).is_some());
+    swarm.on_negotiated(&a, false);
+    assert!(swarm.fec_of(&a).is_none());
+
+    // the fec is disabled by default.
+    let swarm = Swarm::new(Config::default(), MultiDownloadSource::new());
+    swarm.on_negotiated(&a, true);
+    assert!(swarm.fec_of(&a).is_none());
+}
@@ -0,0 +1,133 @@
+
+use std::{sync::{Mutex, RwLock}, time::Duration, };
+
+use near_base::{now, Timestamp, };
+
+/// The rates are bytes per second, 0 is unlimited.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RateLimits {
+    /// all of the uploads of the stack.
+    pub upload: u64,
+    /// all of the downloads of the stack.
+    pub download: u64,
+    /// each upload session, it's the downloader of a file.
+    pub session_upload: u64,
+    /// each downloading file.
+    pub session_download: u64,
+}
+
+impl std::fmt::Display for RateLimits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "upload: {}, download: {}, session_upload: {}, session_download: {}", self.upload, self.download, self.session_upload, self.session_download)
+    }
+}
+
+struct TokenBucketState {
+    tokens: f64,
+    last: Timestamp,
+}
+
+/// The tokens are filled by the rate, it holds 1 second of them at most.
+/// The bytes are always taken, the tokens may be negative, it's the delay which the caller should wait.
+pub struct TokenBucket(Mutex<TokenBucketState>);
+
+impl std::default::Default for TokenBucket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TokenBucket {
+    pub fn new() -> Self {
+        Self(Mutex::new(TokenBucketState {
+            tokens: 0f64,
+            last: now(),
+        }))
+    }
+
+    pub fn take(&self, bytes: usize, rate: u64) -> Duration {
+        self.take_at(bytes, rate, now())
+    }
+
+    fn take_at(&self, bytes: usize, rate: u64, now: Timestamp) -> Duration {
+        let state = &mut *self.0.lock().unwrap();
+
+        let elapsed = now.saturating_sub(state.last) as f64 / 1_000_000f64;
+        state.last = std::cmp::max(state.last, now);
+
+        if rate == 0 {
+            state.tokens = 0f64;
+            return Duration::ZERO;
+        }
+
+        let rate = rate as f64;
+        state.tokens = (state.tokens + elapsed * rate).min(rate) - bytes as f64;
+
+        if state.tokens >= 0f64 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-state.tokens / rate)
+        }
+    }
+}
+
+/// The global buckets of the stack, the per-session ones are kept by the sessions.
+pub struct Limiter {
+    limits: RwLock<RateLimits>,
+    upload: TokenBucket,
+    download: TokenBucket,
+}
+
+impl Limiter {
+    pub fn new(limits: RateLimits) -> Self {
+        Self {
+            limits: RwLock::new(limits),
+            upload: TokenBucket::new(),
+            download: TokenBucket::new(),
+        }
+    }
+
+    pub fn limits(&self) -> RateLimits {
+        *self.limits.read().unwrap()
+    }
+
+    pub fn set_limits(&self, limits: RateLimits) {
+        *self.limits.write().unwrap() = limits;
+    }
+
+    /// Wait until the bytes can be pushed by both of the stack and the session.
+    pub async fn acquire_upload(&self, session: &TokenBucket, bytes: usize) {
+        let limits = self.limits();
+
+        let delay = std::cmp::max(self.upload.take(bytes, limits.upload),
+                                  session.take(bytes, limits.session_upload));
+
+        if !delay.is_zero() {
+            async_std::task::sleep(delay).await;
+        }
+    }
+
+    /// The bytes have been received, return how long the sources should be held.
+    pub fn charge_download(&self, session: &TokenBucket, bytes: usize) -> Duration {
+        let limits = self.limits();
+
+        std::cmp::max(self.download.take(bytes, limits.download),
+                      session.take(bytes, limits.session_download))
+    }
+}
+
+#[test]
+fn test_token_bucket() {
+    let bucket = TokenBucket(Mutex::new(TokenBucketState { tokens: 0f64, last: 0 }));
+
+    // unlimited
+    assert_eq!(bucket.take_at(1024 * 1024, 0, 0), Duration::ZERO);
+
+    // 1 second of the tokens at most.
+    assert_eq!(bucket.take_at(1000, 1000, 10_000_000), Duration::ZERO);
+    assert_eq!(bucket.take_at(500, 1000, 10_000_000), Duration::from_millis(500));
+
+    // half of a second later, it's paid off.
+    assert_eq!(bucket.take_at(0, 1000, 10_500_000), Duration::ZERO);
+    assert_eq!(bucket.take_at(2000, 1000, 10_500_000), Duration::from_secs(2));
+}
@@ -1,13 +1,13 @@
 
-use std::sync::Arc;
+use std::{sync::Arc, path::Path, };
 
 use log::debug;
-use near_base::{file::FileObject, NearResult, ObjectId};
+use near_base::{file::FileObject, dir::DirObject, NearResult, ObjectId};
 
-use crate::{NdsStack, MultiDownloadSource, nds_protocol::{PieceMessage, InterestMessage}};
+use crate::{NdsStack, MultiDownloadSource, nds_protocol::{PieceMessage, InterestMessage, PieceControlMessage}};
 
 use super::{SessionTrait, ToSourceTrait,
-            download::{DownloadManager, DownloadConfig},
+            download::{DownloadManager, DownloadConfig, SwarmConfig, DownloadFileTask, DownloadDirTask},
             upload::UploadManager,
         };
 
@@ -50,6 +50,10 @@ impl Manager {
 
         let downloads = DownloadManager::open(stack.clone(), ret.clone(), DownloadConfig{
             work_tasks: ret.0.config.work_count,
+            swarm: SwarmConfig {
+                fec: stack.nds_config().fec,
+                ..Default::default()
+            },
         })?;
 
         let uploads = UploadManager::open(stack.clone());
@@ -71,14 +75,36 @@ impl Manager {
 }
 
 impl Manager {
+    /// reload the unfinished downloads.
+    pub fn restore_downloads(&self) {
+        self.download_manager().restore()
+    }
+
     pub async fn download_file(&self, file: FileObject, source: MultiDownloadSource) -> NearResult<()> {
         self.download_manager().download_file(file, source)
     }
 
+    /// Materialise the directory under the root, it returns when all of the changed files are written.
+    pub async fn download_dir(&self, dir: DirObject, root: &Path, source: MultiDownloadSource) -> NearResult<()> {
+        DownloadDirTask::new(self.download_manager().clone(), dir, root, source).run().await
+    }
+
+    pub fn file_task(&self, file_id: &ObjectId) -> Option<DownloadFileTask> {
+        self.download_manager().file_task(file_id)
+    }
+
+    pub fn file_tasks(&self) -> Vec<DownloadFileTask> {
+        self.download_manager().file_tasks()
+    }
+
     pub async fn upload(&self, target: ObjectId, message: &InterestMessage) -> NearResult<()> {
         debug!("prepairing interest to = {} on chunk = {}", target, &message.chunk);
         self.upload_manager().add_task(target, message).await
     }
+
+    pub fn on_control(&self, target: &ObjectId, message: &PieceControlMessage) -> NearResult<()> {
+        self.upload_manager().on_control(target, message)
+    }
     // pub async fn start_upload(&self, task_id: SequenceValue, chunk: ChunkId, encode_codec: ChunkEncodeDesc, target: ObjectId, ) -> NearResult<()> {
     //     let task = 
     //         self.create_view(chunk)
@@ -100,7 +126,7 @@ impl Manager {
 }
 
 impl Manager {
-    pub async fn on_piece_data(&self, data: &PieceMessage) -> NearResult<()> {
-        self.download_manager().on_piece_data(data).await
+    pub async fn on_piece_data(&self, from: &ObjectId, data: &PieceMessage) -> NearResult<()> {
+        self.download_manager().on_piece_data(from, data).await
     }
 }
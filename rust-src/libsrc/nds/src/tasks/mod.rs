@@ -6,9 +6,13 @@ use near_base::ObjectId;
 mod upload;
 mod download;
 mod manager;
+mod limiter;
 
 pub use manager::Manager;
-pub use download::DownloadRequestTrait;
+pub use download::{DownloadRequestTrait, DownloadFileTask, DownloadDirTask};
+pub(crate) use download::{walk_files, mode_of, mtime_of};
+pub use limiter::RateLimits;
+pub(crate) use limiter::{Limiter, TokenBucket};
 
 pub trait SessionTrait: Send + Sync {
     fn clone_as_session(&self) -> Box<dyn SessionTrait>;
@@ -46,11 +50,11 @@ impl DownloadSource {
         self
     }
 
-    pub fn enabled(&mut self) {
+    pub fn enabled(&self) {
         self.enabled.store(true, std::sync::atomic::Ordering::SeqCst);
     }
 
-    pub fn disabled(&mut self) {
+    pub fn disabled(&self) {
         self.enabled.store(false, std::sync::atomic::Ordering::SeqCst);
     }
 
@@ -1,7 +1,7 @@
 
 use std::sync::{Arc, };
 
-use log::error;
+use log::{error, info};
 use near_base::{ObjectId, NearResult, };
 
 use crate::{tasks::{ToSourceTrait, 
@@ -10,10 +10,10 @@ use crate::{tasks::{ToSourceTrait,
                     upload::UploadManager,
                 }, 
             chunks::ChunkView, 
-            nds_protocol::{SessionData, ChunkEncodeDesc, PieceMessage}, 
+            nds_protocol::{SessionData, ChunkEncodeDesc, ChunkRange, PieceMessage, PieceEncodeDesc, PieceControlCommand, FecParams, encode_repairs}, 
     };
 
-use super::UploadTaskTrait;
+use super::{UploadTaskTrait, session::Session};
 
 struct ChunkTaskImpl {
     manager: UploadManager,
@@ -21,19 +21,21 @@ struct ChunkTaskImpl {
     view: ChunkView,
     encoder: ChunkEncodeDesc,
     target: ObjectId,
+    session: Session,
 }
 
 #[derive(Clone)]
 pub struct ChunkTask(Arc<ChunkTaskImpl>);
 
 impl ChunkTask {
-    pub fn new(manager: UploadManager, session_data: SessionData, view: ChunkView, target: ObjectId, encoder: ChunkEncodeDesc) -> NearResult<Self> {
+    pub fn new(manager: UploadManager, session_data: SessionData, view: ChunkView, target: ObjectId, encoder: ChunkEncodeDesc, session: Session) -> NearResult<Self> {
         Ok(Self(Arc::new(ChunkTaskImpl{
             manager,
             session_data,
             view, 
             encoder,
             target,
+            session,
         })))
     }
 
@@ -64,51 +66,146 @@ impl SessionTrait for ChunkTask {
 impl TaskTrait for ChunkTask {
 
     async fn start(&self, _: Option<Box<dyn ToSourceTrait>>) {
-        let text = match &self.0.encoder {
-            ChunkEncodeDesc::Stream(range) => {
-
-                let (offset, length) = 
-                match ChunkEncodeDesc::create_stream(self.0.view.chunk()) {
-                    ChunkEncodeDesc::Stream(chunk_range) => {
-                        // if !chunk_range.contains(&range.start) {
-                        //     error!("Out of range, except={:?}, start={}", chunk_range, range.start);
-                        //     return;
-                        // }
-                        // if !chunk_range.contains(&range.end) {
-                        //     error!("Out of range, except={:?}, end={}", chunk_range, range.end);
-                        //     return;
-                        // }
-                        if !chunk_range.contains(range) {
-                            error!("Out of range, except-range={}, got-range={}", chunk_range, range);
-                            return;
-                        }
-
-                        if chunk_range.end == range.end {
-                            (range.start as usize * PieceMessage::payload_max_len(), self.0.view.chunk().len())
-                        } else {
-                            (range.start as usize * PieceMessage::payload_max_len(), range.end as usize * PieceMessage::payload_max_len())
-                        }
-                    }
-                };
+        let chunk_range = ChunkEncodeDesc::create_stream(self.0.view.chunk());
+        if !chunk_range.range().contains(self.0.encoder.range()) {
+            error!("Out of range, except-range={}, got-range={}", chunk_range.range(), self.0.encoder.range());
+            return;
+        }
+
+        match &self.0.encoder {
+            ChunkEncodeDesc::Stream(range) => self.push_stream(range).await,
+            ChunkEncodeDesc::Fec(range, params) => self.push_fec(range, params).await,
+        }
+    }
+
+}
+
+impl ChunkTask {
+    /// push the pieces one by one, so that the downloader can pause or cancel it between them.
+    async fn wait_continue(&self) -> bool {
+        match self.0.session.wait_continue().await {
+            PieceControlCommand::Continue => {
+                self.0.session.touch();
+                true
+            }
+            command => {
+                info!("The session {} of {} was stopped by {}", self.0.session_data, self.0.view.chunk(), command);
+                false
+            }
+        }
+    }
+
+    /// Wait until the piece can be pushed under the rate limits.
+    async fn acquire(&self, bytes: usize) {
+        self.0.manager
+            .nds_stack()
+            .limiter()
+            .acquire_upload(self.0.session.bucket(), bytes)
+            .await
+    }
+
+    async fn read_piece(&self, index: u32) -> NearResult<Vec<u8>> {
+        let offset = index as usize * PieceMessage::payload_max_len();
+        let length = std::cmp::min(offset + PieceMessage::payload_max_len(), self.0.view.chunk().len());
+
+        self.0.view.read(offset, length).await
+    }
+
+    async fn push_stream(&self, range: &ChunkRange) {
+        for index in range.start..range.end {
+            if !self.wait_continue().await {
+                return;
+            }
+
+            let text = match self.read_piece(index).await {
+                Ok(v) => { v }
+                Err(err) => {
+                    error!("failed read with err = {}", err);
+                    return;
+                }
+            };
+
+            self.acquire(text.len()).await;
+
+            if let Err(err) = 
+                self.0.manager
+                    .nds_stack()
+                    .push_piece_data(&self.0.target, 
+                                     self.0.session_data, 
+                                     self.0.view.chunk(), 
+                                     ChunkEncodeDesc::Stream(ChunkRange { start: index, end: index + 1 }), 
+                                     text)
+                    .await {
+                error!("failed push_piece_data with err = {}", err);
+                return;
+            }
+        }
+    }
+
+    /// Every block of the source pieces is followed by its repair pieces.
+    async fn push_fec(&self, range: &ChunkRange, params: &FecParams) {
+        if let Err(err) = params.check() {
+            error!("{err}");
+            return;
+        }
+
+        let push = | desc: PieceEncodeDesc, data: Vec<u8> | async move {
+            self.0.manager
+                .nds_stack()
+                .push_pieces(&self.0.target, 
+                             vec![PieceMessage {
+                                session_data: self.0.session_data,
+                                chunk: self.0.view.chunk().clone(),
+                                desc,
+                                data,
+                             }])
+                .await
+                .map_err(| err | {
+                    error!("failed push_pieces with err = {}", err);
+                    err
+                })
+        };
+
+        let mut first = range.start;
+        while first < range.end {
+            let count = std::cmp::min(params.source as u32, range.end - first);
+            let mut sources = vec![];
+
+            for index in first..first + count {
+                if !self.wait_continue().await {
+                    return;
+                }
 
-                match self.0.view.read(offset, length).await {
+                let text = match self.read_piece(index).await {
                     Ok(v) => { v }
                     Err(err) => {
                         error!("failed read with err = {}", err);
                         return;
                     }
+                };
+
+                let desc = PieceEncodeDesc::Fec(first as u16, count as u8, (index - first) as u8, text.len() as u16);
+                self.acquire(text.len()).await;
+                if push(desc, text.clone()).await.is_err() {
+                    return;
                 }
-                // let offset = *index as usize * PIECE_MESSAGE_PALOAD_MAX_LEN;
-                // let mut v = vec![0u8; (*length) as usize];
-                // let _ = self.0.view.read_to_end(&mut v, *offset as usize).await;
+                sources.push(text);
             }
-        };
 
-        if let Err(err) = self.0.manager.nds_stack().push_piece_data(&self.0.target, self.0.session_data, self.0.view.chunk(), self.0.encoder.clone(), text) {
-            error!("failed push_piece_data with err = {}", err);
+            let repairs = encode_repairs(&sources, params.repair as usize, PieceMessage::payload_max_len());
+            for (r, text) in repairs.into_iter().enumerate() {
+                if !self.wait_continue().await {
+                    return;
+                }
+
+                let desc = PieceEncodeDesc::Fec(first as u16, count as u8, (count as usize + r) as u8, text.len() as u16);
+                self.acquire(text.len()).await;
+                if push(desc, text).await.is_err() {
+                    return;
+                }
+            }
+
+            first += count;
         }
     }
-
 }
-
-
@@ -1,19 +1,23 @@
 
-use std::{sync::{Arc, RwLock}, collections::{LinkedList}, };
+use std::{sync::{Arc, RwLock}, collections::{BTreeMap}, time::Duration, };
 
-use near_base::{NearResult, NearError, ObjectId, Sequence};
+use log::info;
+use near_base::{NearResult, NearError, ErrorCode, ObjectId, Sequence, now};
 use near_core::near_error;
 
 use crate::{NdsStack,
             tasks::{manager::{TaskTrait, }, }, 
-            nds_protocol::{InterestMessage}
+            nds_protocol::{InterestMessage, PieceControlMessage, PieceControlCommand}
     };
 
-use super::{chunk::ChunkTask, UploadTaskTrait};
+use super::{chunk::ChunkTask, session::Session};
+
+// the session is released when the downloader hasn't touched it in this window.
+const SESSION_IDLE_TIMEOUT: Duration = Duration::from_secs(120);
+const SESSION_CHECK_INTERVAL: Duration = Duration::from_secs(30);
 
 struct DataImpl {
-    // task_list: BTreeMap<u32, Box<dyn UploadTaskTrait>>,
-    task_list: LinkedList<Box<dyn UploadTaskTrait>>,
+    sessions: BTreeMap<(ObjectId /* target */, u32 /* session-id */), Session>,
 }
 
 struct ManagerImpl {
@@ -37,13 +41,48 @@ impl Manager {
 
 impl Manager {
     pub fn open(stack: NdsStack) -> Self {
-        Self(Arc::new(ManagerImpl{
+        let ret = Self(Arc::new(ManagerImpl{
             stack,
             task_gen_id: Sequence::random(),
             data: RwLock::new(DataImpl{
-                task_list: LinkedList::new(),
+                sessions: BTreeMap::new(),
             })
-        }))
+        }));
+
+        let arc_self = ret.clone();
+        async_std::task::spawn(async move {
+            loop {
+                async_std::task::sleep(SESSION_CHECK_INTERVAL).await;
+                arc_self.release_idle_sessions(SESSION_IDLE_TIMEOUT);
+            }
+        });
+
+        ret
+    }
+
+    /// Cancel the sessions whose downloader failed or abandoned the transfer,
+    /// their paused chunk tasks are woken and exit.
+    fn release_idle_sessions(&self, timeout: Duration) {
+        let now = now();
+
+        let idles: Vec<((ObjectId, u32), Session)> = {
+            let data = &mut *self.0.data.write().unwrap();
+            let keys: Vec<(ObjectId, u32)> = 
+                data.sessions
+                    .iter()
+                    .filter(| (_, session) | session.is_idle(now, timeout))
+                    .map(| (key, _) | key.clone())
+                    .collect();
+
+            keys.into_iter()
+                .filter_map(| key | data.sessions.remove(&key).map(| session | (key, session)))
+                .collect()
+        };
+
+        for ((target, session_id), session) in idles {
+            info!("release the idle upload session {} of {}", session_id, target);
+            session.control(PieceControlCommand::Cancel);
+        }
     }
 
     pub async fn add_task(&self, target: ObjectId, message: &InterestMessage) -> NearResult<()> {
@@ -56,33 +95,58 @@ impl Manager {
                     near_error!(e.errno(), format!("failed to create_view chunk = {} with err = {}", &message.chunk, e))
                 })?;
 
+        let session = 
+            self.0.data
+                .write().unwrap()
+                .sessions
+                .entry((target.clone(), message.session_data.session_id))
+                .or_insert_with(Session::new)
+                .clone();
+        session.touch();
+
         let task = 
             ChunkTask::new(self.clone(), 
                            message.session_data, 
                            view, 
                            target, 
-                           message.encoder.clone())
+                           message.encoder.clone(),
+                           session)
                 .map_err(| e | {
                     near_error!(e.errno(), format!("Failed to ChunkTask::new({}) with err = {}", message.chunk, e))
                 })?;
 
-        self.0.data
-            .write().unwrap()
-            .task_list
-            .push_back(task.clone_as_uploadtask());
-        // match self.0.data.write().unwrap().task_list.entry(task.session_id()) {
-        //     Entry::Occupied(_existed) => {
-        //         unreachable!("Failed to add_task chunk = {} target = {}, because it was existed. But it isnot impositbale.", chunk, target);
-        //     }
-        //     Entry::Vacant(empty) => {
-        //         empty.insert(task.clone_as_uploadtask());
-        //     }
-        // }
-
         async_std::task::spawn(async move {
             task.start(None).await
         });
 
         Ok(())
     }
+
+    /// The downloader pauses, resumes, cancels or finishes its session.
+    pub fn on_control(&self, target: &ObjectId, message: &PieceControlMessage) -> NearResult<()> {
+        let key = (target.clone(), message.session_data.session_id);
+        let data = &mut *self.0.data.write().unwrap();
+
+        let session = 
+            data.sessions
+                .get(&key)
+                .cloned()
+                .ok_or_else(|| {
+                    NearError::new(ErrorCode::NEAR_ERROR_NOTFOUND, format!("Not found the upload session {} of {}", message.session_data, target))
+                })?;
+
+        info!("{} the upload session {} of {}", message.command, message.session_data, target);
+
+        session.touch();
+        session.control(message.command);
+
+        match message.command {
+            PieceControlCommand::Cancel | PieceControlCommand::Finish => {
+                data.sessions.remove(&key);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
 }
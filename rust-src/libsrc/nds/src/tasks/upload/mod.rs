@@ -1,6 +1,7 @@
 
 mod manager;
 mod chunk;
+mod session;
 
 pub use manager::Manager as UploadManager;
 
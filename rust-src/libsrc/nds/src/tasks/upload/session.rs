@@ -0,0 +1,92 @@
+
+use std::{sync::{Arc, Mutex, atomic::{AtomicU64, Ordering}}, time::Duration};
+
+use near_base::{StateWaiter, Timestamp, now};
+
+use crate::{nds_protocol::PieceControlCommand, tasks::TokenBucket, };
+
+struct SessionImpl {
+    command: Mutex<PieceControlCommand>,
+    waiters: Mutex<StateWaiter>,
+    bucket: TokenBucket,
+    // the last time the downloader interested, controlled or received a piece of the session.
+    last_active: AtomicU64,
+}
+
+/// The upload session of the downloader, all of its chunk tasks are controlled together.
+#[derive(Clone)]
+pub struct Session(Arc<SessionImpl>);
+
+impl Session {
+    pub fn new() -> Self {
+        Self(Arc::new(SessionImpl {
+            command: Mutex::new(PieceControlCommand::Continue),
+            waiters: Mutex::new(StateWaiter::new()),
+            bucket: TokenBucket::new(),
+            last_active: AtomicU64::new(now()),
+        }))
+    }
+
+    pub fn touch(&self) {
+        self.0.last_active.store(now(), Ordering::SeqCst);
+    }
+
+    /// The downloader may fail or abandon the transfer without finishing the session.
+    pub fn is_idle(&self, now: Timestamp, timeout: Duration) -> bool {
+        now.saturating_sub(self.0.last_active.load(Ordering::SeqCst)) > timeout.as_micros() as Timestamp
+    }
+
+    pub fn command(&self) -> PieceControlCommand {
+        *self.0.command.lock().unwrap()
+    }
+
+    /// The upload rate of the session is limited by it.
+    pub fn bucket(&self) -> &TokenBucket {
+        &self.0.bucket
+    }
+
+    pub fn control(&self, command: PieceControlCommand) {
+        {
+            let current = &mut *self.0.command.lock().unwrap();
+
+            match current {
+                // the session has been stopped.
+                PieceControlCommand::Cancel | PieceControlCommand::Finish => { return; }
+                _ => { *current = command; }
+            }
+        }
+
+        if command != PieceControlCommand::Pause {
+            let waker = { self.0.waiters.lock().unwrap().transfer() };
+            waker.wake();
+        }
+    }
+
+    /// Wait until the session isn't paused, return Continue or the command which stopped the session.
+    pub async fn wait_continue(&self) -> PieceControlCommand {
+        loop {
+            let waiter = {
+                let command = self.0.command.lock().unwrap();
+
+                match *command {
+                    PieceControlCommand::Pause => self.0.waiters.lock().unwrap().new_waiter(),
+                    command => { return command; }
+                }
+            };
+
+            StateWaiter::wait(waiter, || ()).await;
+        }
+    }
+}
+
+#[test]
+fn test_session_idle() {
+    let session = Session::new();
+    let timeout = Duration::from_secs(120);
+
+    assert!(!session.is_idle(now(), timeout));
+    assert!(session.is_idle(now() + 121_000_000, timeout));
+
+    session.touch();
+    assert!(!session.is_idle(now() + 60_000_000, timeout));
+}
@@ -0,0 +1,49 @@
+
+use near_base::{file::FileObject, NearResult};
+
+use crate::{NdsState, tasks::DownloadFileTask};
+
+/// The handle of the running download, it's got from NdsStack::transfer().
+#[derive(Clone)]
+pub struct Transfer(DownloadFileTask);
+
+impl From<DownloadFileTask> for Transfer {
+    fn from(task: DownloadFileTask) -> Self {
+        Self(task)
+    }
+}
+
+impl std::fmt::Display for Transfer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Transfer {
+    pub fn file(&self) -> &FileObject {
+        self.0.file()
+    }
+
+    pub fn state(&self) -> NdsState {
+        self.0.state()
+    }
+
+    /// Wait until all of the chunks are downloaded, or it's canceled.
+    pub async fn wait(&self) -> NearResult<()> {
+        self.0.wait().await
+    }
+
+    /// The source stops pushing pieces until it's resumed.
+    pub async fn pause(&self) -> NearResult<()> {
+        self.0.pause().await
+    }
+
+    pub async fn resume(&self) -> NearResult<()> {
+        self.0.resume().await
+    }
+
+    /// Stop the transfer, the partial chunks and the statistics are dropped.
+    pub async fn cancel(&self) -> NearResult<()> {
+        self.0.cancel().await
+    }
+}
@@ -3,3 +3,4 @@ pub mod proof_data;
 
 mod proof_of_device;
 mod proof_of_ping;
+mod proof_of_pairing;
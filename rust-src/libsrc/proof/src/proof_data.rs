@@ -1,8 +1,8 @@
 
 use near_base::{PrivateKey, NearResult, PublicKey, Signature, 
                 proof_of_data::{ProofOfDataObject, ProofOfDataDescContent, ProofOfDataBodyContent}, 
-                DeviceObject, Serialize, Deserialize, ObjectBuilder, NearError, ErrorCode};
-use protos::proof::ping::{Proof_of_ping, Proof_of_device};
+                DeviceObject, thing::ThingObject, Serialize, Deserialize, RawConvertTo, ObjectBuilder, NearError, ErrorCode};
+use protos::proof::{ping::{Proof_of_ping, Proof_of_device}, pairing::Proof_of_pairing};
 
 #[async_trait::async_trait]
 pub trait ProofOfProcessTrait {
@@ -21,6 +21,14 @@ impl<T, C> ProofOfDataReq<T, C>
 where T: Clone + std::fmt::Display + Serialize + Deserialize + std::default::Default,
       C: Clone + std::fmt::Display + Serialize + Deserialize + std::default::Default {
 
+    pub fn proof_of_data(&self) -> &ProofOfDataObject<T, C> {
+        &self.proof_of_data
+    }
+
+    pub fn proof(&self) -> &Signature {
+        &self.proof
+    }
+
     pub fn split(self) -> (ProofOfDataObject<T, C>, Signature) {
         (self.proof_of_data, self.proof)
     }
@@ -68,12 +76,65 @@ where T: Clone + std::fmt::Display + Serialize + Deserialize + std::default::Def
     }
 }
 
+/// The proof data of the pairing, it's prefixed by its length in the proof-of-data,
+/// because the protobuf one consumes the whole buffer. The publish and the ping ones are kept as they're.
+#[derive(Clone, Default)]
+pub struct PairingData(Proof_of_pairing);
+
+impl PairingData {
+    pub fn into_inner(self) -> Proof_of_pairing {
+        self.0
+    }
+}
+
+impl From<Proof_of_pairing> for PairingData {
+    fn from(proof_of_pairing: Proof_of_pairing) -> Self {
+        Self(proof_of_pairing)
+    }
+}
+
+impl std::ops::Deref for PairingData {
+    type Target = Proof_of_pairing;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for PairingData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Serialize for PairingData {
+    fn raw_capacity(&self) -> usize {
+        vec![0u8; self.0.raw_capacity()].raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        self.0.to_vec()?.serialize(buf)
+    }
+}
+
+impl Deserialize for PairingData {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (data, buf) = Vec::<u8>::deserialize(buf)?;
+        let (proof_of_pairing, _) = Proof_of_pairing::deserialize(&data)?;
+
+        Ok((Self(proof_of_pairing), buf))
+    }
+}
+
 const PROOFDATASET_OF_PUBLISH: u8       = 1u8;
 const PROOFDATASET_OF_PING: u8          = 2u8;
+const PROOFDATASET_OF_PAIRING: u8       = 3u8;
 
 pub enum ProofDataSet {
     Publish(ProofOfDataReq<Proof_of_device, DeviceObject>),
     Ping(ProofOfDataReq<Proof_of_ping, DeviceObject>),
+    Pairing(ProofOfDataReq<PairingData, ThingObject>),
 }
 
 impl ProofDataSet {
@@ -121,6 +182,31 @@ impl ProofDataSet {
             proof,
         }))
     }
+
+    /// The thing object only carries its desc, its object id is the same as the stored one.
+    pub async fn with_pairing(
+        proof_of_pairing: Proof_of_pairing,
+        thing_object: ThingObject,
+        private_key: PrivateKey
+    ) -> NearResult<Self> {
+        let proof = proof_of_pairing.sign(private_key).await?;
+
+        let proof_of_data = 
+            ObjectBuilder::new(ProofOfDataDescContent::default(), ProofOfDataBodyContent::default())
+                .update_desc(| mut_desc | {
+                    mut_desc.no_create_time();
+                    mut_desc.mut_desc().set_proof_data(PairingData::from(proof_of_pairing));
+                })
+                .update_body(| mut_body | {
+                    mut_body.mut_body().set_data(thing_object);
+                })
+                .build()?;
+
+        Ok(Self::Pairing(ProofOfDataReq{
+            proof_of_data,
+            proof,
+        }))
+    }
 }
 
 impl Serialize for ProofDataSet {
@@ -132,6 +218,9 @@ impl Serialize for ProofDataSet {
             Self::Ping(data) => {
                 PROOFDATASET_OF_PING.raw_capacity() + data.raw_capacity()
             }
+            Self::Pairing(data) => {
+                PROOFDATASET_OF_PAIRING.raw_capacity() + data.raw_capacity()
+            }
         }
     }
 
@@ -148,6 +237,12 @@ impl Serialize for ProofDataSet {
                 let buf = PROOFDATASET_OF_PING.serialize(buf)?;
                 let buf = data.serialize(buf)?;
 
+                Ok(buf)
+            }
+            Self::Pairing(data) => {
+                let buf = PROOFDATASET_OF_PAIRING.serialize(buf)?;
+                let buf = data.serialize(buf)?;
+
                 Ok(buf)
             }
         }
@@ -169,6 +264,11 @@ impl Deserialize for ProofDataSet {
 
                 Ok((Self::Ping(data), buf))
             }
+            PROOFDATASET_OF_PAIRING => {
+                let (data, buf) = ProofOfDataReq::<PairingData, ThingObject>::deserialize(buf)?;
+
+                Ok((Self::Pairing(data), buf))
+            }
             _ => {
                 Err(NearError::new(ErrorCode::NEAR_ERROR_UNDEFINED, format!("undefined id [{f}].")))
             }
@@ -185,6 +285,9 @@ impl std::fmt::Display for ProofDataSet {
             Self::Ping(proof) => {
                 write!(f, "proof-ping: {}", proof)
             }
+            Self::Pairing(proof) => {
+                write!(f, "proof-pairing: {}", proof)
+            }
         }
     }
 }
@@ -0,0 +1,70 @@
+
+use near_base::*;
+
+use protos::proof::pairing::Proof_of_pairing;
+
+use crate::proof_data::ProofOfProcessTrait;
+
+// every field is prefixed by its length, so the fields can't be shifted into each other.
+fn signer_buffer(proof: &Proof_of_pairing) -> Vec<u8> {
+    let mut signer_buffer = vec![];
+
+    for field in [
+        proof.thing_id(),
+        proof.core_id(),
+        proof.owner_id(),
+        proof.nonce(),
+        proof.answer(),
+    ] {
+        signer_buffer.extend_from_slice(&(field.len() as u32).to_be_bytes());
+        signer_buffer.extend_from_slice(field.as_bytes());
+    }
+
+    signer_buffer.extend_from_slice(&proof.pair_time.to_be_bytes());
+    signer_buffer
+}
+
+#[async_trait::async_trait]
+impl ProofOfProcessTrait for Proof_of_pairing {
+    async fn sign(&self, private_key: PrivateKey) -> NearResult<Signature> {
+        private_key.sign(&signer_buffer(self))
+    }
+
+    async fn verify(&self, public_key: &PublicKey, signature: &Signature) -> NearResult<()> {
+        public_key.verify(&signer_buffer(self), signature)
+    }
+
+}
+
+#[test]
+fn test_proof_of_pairing() {
+    let private_key = PrivateKey::generate_rsa1024().unwrap();
+
+    let proof = Proof_of_pairing {
+        thing_id: "thing".to_owned(),
+        core_id: "core".to_owned(),
+        owner_id: "owner".to_owned(),
+        nonce: "0011223344556677".to_owned(),
+        answer: "answer".to_owned(),
+        pair_time: 1,
+        ..Default::default()
+    };
+
+    let signature = async_std::task::block_on(proof.sign(private_key.clone())).unwrap();
+    async_std::task::block_on(proof.verify(&private_key.public(), &signature)).unwrap();
+
+    // another owner
+    let mut changed = proof.clone();
+    changed.owner_id = "other".to_owned();
+    assert!(async_std::task::block_on(changed.verify(&private_key.public(), &signature)).is_err());
+
+    // the fields can't be shifted into each other
+    let mut shifted = proof.clone();
+    shifted.thing_id = "thingc".to_owned();
+    shifted.core_id = "ore".to_owned();
+    assert!(async_std::task::block_on(shifted.verify(&private_key.public(), &signature)).is_err());
+
+    // another key
+    let other_key = PrivateKey::generate_rsa1024().unwrap();
+    assert!(async_std::task::block_on(proof.verify(&other_key.public(), &signature)).is_err());
+}
@@ -0,0 +1,1078 @@
+// This file is generated by rust-protobuf 3.2.0. Do not edit
+// .proto file is parsed by pure
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `audit.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_2_0;
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:audit_record)
+pub struct Audit_record {
+    // message fields
+    // @@protoc_insertion_point(field:audit_record.index)
+    pub index: u64,
+    // @@protoc_insertion_point(field:audit_record.requestor)
+    pub requestor: ::std::string::String,
+    // @@protoc_insertion_point(field:audit_record.sequence)
+    pub sequence: ::std::string::String,
+    // @@protoc_insertion_point(field:audit_record.timestamp)
+    pub timestamp: u64,
+    // @@protoc_insertion_point(field:audit_record.action)
+    pub action: ::std::string::String,
+    // @@protoc_insertion_point(field:audit_record.thing_ids)
+    pub thing_ids: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:audit_record.digest)
+    pub digest: ::std::string::String,
+    // @@protoc_insertion_point(field:audit_record.outcome)
+    pub outcome: u32,
+    // @@protoc_insertion_point(field:audit_record.outcome_message)
+    pub outcome_message: ::std::string::String,
+    // @@protoc_insertion_point(field:audit_record.prev_hash)
+    pub prev_hash: ::std::string::String,
+    // @@protoc_insertion_point(field:audit_record.hash)
+    pub hash: ::std::string::String,
+    // @@protoc_insertion_point(field:audit_record.signature)
+    pub signature: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:audit_record.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Audit_record {
+    fn default() -> &'a Audit_record {
+        <Audit_record as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Audit_record {
+    pub fn new() -> Audit_record {
+        ::std::default::Default::default()
+    }
+
+    // uint64 index = 1;
+
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    pub fn clear_index(&mut self) {
+        self.index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: u64) {
+        self.index = v;
+    }
+
+    // string requestor = 2;
+
+    pub fn requestor(&self) -> &str {
+        &self.requestor
+    }
+
+    pub fn clear_requestor(&mut self) {
+        self.requestor.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requestor(&mut self, v: ::std::string::String) {
+        self.requestor = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_requestor(&mut self) -> &mut ::std::string::String {
+        &mut self.requestor
+    }
+
+    // Take field
+    pub fn take_requestor(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.requestor, ::std::string::String::new())
+    }
+
+    // string sequence = 3;
+
+    pub fn sequence(&self) -> &str {
+        &self.sequence
+    }
+
+    pub fn clear_sequence(&mut self) {
+        self.sequence.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sequence(&mut self, v: ::std::string::String) {
+        self.sequence = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sequence(&mut self) -> &mut ::std::string::String {
+        &mut self.sequence
+    }
+
+    // Take field
+    pub fn take_sequence(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.sequence, ::std::string::String::new())
+    }
+
+    // uint64 timestamp = 4;
+
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    pub fn clear_timestamp(&mut self) {
+        self.timestamp = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_timestamp(&mut self, v: u64) {
+        self.timestamp = v;
+    }
+
+    // string action = 5;
+
+    pub fn action(&self) -> &str {
+        &self.action
+    }
+
+    pub fn clear_action(&mut self) {
+        self.action.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_action(&mut self, v: ::std::string::String) {
+        self.action = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_action(&mut self) -> &mut ::std::string::String {
+        &mut self.action
+    }
+
+    // Take field
+    pub fn take_action(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.action, ::std::string::String::new())
+    }
+
+    // repeated string thing_ids = 6;
+
+    pub fn thing_ids(&self) -> &[::std::string::String] {
+        &self.thing_ids
+    }
+
+    pub fn clear_thing_ids(&mut self) {
+        self.thing_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_thing_ids(&mut self, v: ::std::vec::Vec<::std::string::String>) {
+        self.thing_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_thing_ids(&mut self) -> &mut ::std::vec::Vec<::std::string::String> {
+        &mut self.thing_ids
+    }
+
+    // Take field
+    pub fn take_thing_ids(&mut self) -> ::std::vec::Vec<::std::string::String> {
+        ::std::mem::replace(&mut self.thing_ids, ::std::vec::Vec::new())
+    }
+
+    // string digest = 7;
+
+    pub fn digest(&self) -> &str {
+        &self.digest
+    }
+
+    pub fn clear_digest(&mut self) {
+        self.digest.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_digest(&mut self, v: ::std::string::String) {
+        self.digest = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_digest(&mut self) -> &mut ::std::string::String {
+        &mut self.digest
+    }
+
+    // Take field
+    pub fn take_digest(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.digest, ::std::string::String::new())
+    }
+
+    // uint32 outcome = 8;
+
+    pub fn outcome(&self) -> u32 {
+        self.outcome
+    }
+
+    pub fn clear_outcome(&mut self) {
+        self.outcome = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_outcome(&mut self, v: u32) {
+        self.outcome = v;
+    }
+
+    // string outcome_message = 9;
+
+    pub fn outcome_message(&self) -> &str {
+        &self.outcome_message
+    }
+
+    pub fn clear_outcome_message(&mut self) {
+        self.outcome_message.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_outcome_message(&mut self, v: ::std::string::String) {
+        self.outcome_message = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_outcome_message(&mut self) -> &mut ::std::string::String {
+        &mut self.outcome_message
+    }
+
+    // Take field
+    pub fn take_outcome_message(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.outcome_message, ::std::string::String::new())
+    }
+
+    // string prev_hash = 10;
+
+    pub fn prev_hash(&self) -> &str {
+        &self.prev_hash
+    }
+
+    pub fn clear_prev_hash(&mut self) {
+        self.prev_hash.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_prev_hash(&mut self, v: ::std::string::String) {
+        self.prev_hash = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_prev_hash(&mut self) -> &mut ::std::string::String {
+        &mut self.prev_hash
+    }
+
+    // Take field
+    pub fn take_prev_hash(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.prev_hash, ::std::string::String::new())
+    }
+
+    // string hash = 11;
+
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+
+    pub fn clear_hash(&mut self) {
+        self.hash.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hash(&mut self, v: ::std::string::String) {
+        self.hash = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_hash(&mut self) -> &mut ::std::string::String {
+        &mut self.hash
+    }
+
+    // Take field
+    pub fn take_hash(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.hash, ::std::string::String::new())
+    }
+
+    // bytes signature = 12;
+
+    pub fn signature(&self) -> &[u8] {
+        &self.signature
+    }
+
+    pub fn clear_signature(&mut self) {
+        self.signature.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature(&mut self, v: ::std::vec::Vec<u8>) {
+        self.signature = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_signature(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.signature
+    }
+
+    // Take field
+    pub fn take_signature(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.signature, ::std::vec::Vec::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(12);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "index",
+            |m: &Audit_record| { &m.index },
+            |m: &mut Audit_record| { &mut m.index },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "requestor",
+            |m: &Audit_record| { &m.requestor },
+            |m: &mut Audit_record| { &mut m.requestor },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "sequence",
+            |m: &Audit_record| { &m.sequence },
+            |m: &mut Audit_record| { &mut m.sequence },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "timestamp",
+            |m: &Audit_record| { &m.timestamp },
+            |m: &mut Audit_record| { &mut m.timestamp },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "action",
+            |m: &Audit_record| { &m.action },
+            |m: &mut Audit_record| { &mut m.action },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "thing_ids",
+            |m: &Audit_record| { &m.thing_ids },
+            |m: &mut Audit_record| { &mut m.thing_ids },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "digest",
+            |m: &Audit_record| { &m.digest },
+            |m: &mut Audit_record| { &mut m.digest },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "outcome",
+            |m: &Audit_record| { &m.outcome },
+            |m: &mut Audit_record| { &mut m.outcome },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "outcome_message",
+            |m: &Audit_record| { &m.outcome_message },
+            |m: &mut Audit_record| { &mut m.outcome_message },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "prev_hash",
+            |m: &Audit_record| { &m.prev_hash },
+            |m: &mut Audit_record| { &mut m.prev_hash },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "hash",
+            |m: &Audit_record| { &m.hash },
+            |m: &mut Audit_record| { &mut m.hash },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "signature",
+            |m: &Audit_record| { &m.signature },
+            |m: &mut Audit_record| { &mut m.signature },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Audit_record>(
+            "audit_record",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Audit_record {
+    const NAME: &'static str = "audit_record";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.index = is.read_uint64()?;
+                },
+                18 => {
+                    self.requestor = is.read_string()?;
+                },
+                26 => {
+                    self.sequence = is.read_string()?;
+                },
+                32 => {
+                    self.timestamp = is.read_uint64()?;
+                },
+                42 => {
+                    self.action = is.read_string()?;
+                },
+                50 => {
+                    self.thing_ids.push(is.read_string()?);
+                },
+                58 => {
+                    self.digest = is.read_string()?;
+                },
+                64 => {
+                    self.outcome = is.read_uint32()?;
+                },
+                74 => {
+                    self.outcome_message = is.read_string()?;
+                },
+                82 => {
+                    self.prev_hash = is.read_string()?;
+                },
+                90 => {
+                    self.hash = is.read_string()?;
+                },
+                98 => {
+                    self.signature = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.index != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.index);
+        }
+        if !self.requestor.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.requestor);
+        }
+        if !self.sequence.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.sequence);
+        }
+        if self.timestamp != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.timestamp);
+        }
+        if !self.action.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.action);
+        }
+        for value in &self.thing_ids {
+            my_size += ::protobuf::rt::string_size(6, &value);
+        };
+        if !self.digest.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.digest);
+        }
+        if self.outcome != 0 {
+            my_size += ::protobuf::rt::uint32_size(8, self.outcome);
+        }
+        if !self.outcome_message.is_empty() {
+            my_size += ::protobuf::rt::string_size(9, &self.outcome_message);
+        }
+        if !self.prev_hash.is_empty() {
+            my_size += ::protobuf::rt::string_size(10, &self.prev_hash);
+        }
+        if !self.hash.is_empty() {
+            my_size += ::protobuf::rt::string_size(11, &self.hash);
+        }
+        if !self.signature.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(12, &self.signature);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.index != 0 {
+            os.write_uint64(1, self.index)?;
+        }
+        if !self.requestor.is_empty() {
+            os.write_string(2, &self.requestor)?;
+        }
+        if !self.sequence.is_empty() {
+            os.write_string(3, &self.sequence)?;
+        }
+        if self.timestamp != 0 {
+            os.write_uint64(4, self.timestamp)?;
+        }
+        if !self.action.is_empty() {
+            os.write_string(5, &self.action)?;
+        }
+        for v in &self.thing_ids {
+            os.write_string(6, &v)?;
+        };
+        if !self.digest.is_empty() {
+            os.write_string(7, &self.digest)?;
+        }
+        if self.outcome != 0 {
+            os.write_uint32(8, self.outcome)?;
+        }
+        if !self.outcome_message.is_empty() {
+            os.write_string(9, &self.outcome_message)?;
+        }
+        if !self.prev_hash.is_empty() {
+            os.write_string(10, &self.prev_hash)?;
+        }
+        if !self.hash.is_empty() {
+            os.write_string(11, &self.hash)?;
+        }
+        if !self.signature.is_empty() {
+            os.write_bytes(12, &self.signature)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Audit_record {
+        Audit_record::new()
+    }
+
+    fn clear(&mut self) {
+        self.index = 0;
+        self.requestor.clear();
+        self.sequence.clear();
+        self.timestamp = 0;
+        self.action.clear();
+        self.thing_ids.clear();
+        self.digest.clear();
+        self.outcome = 0;
+        self.outcome_message.clear();
+        self.prev_hash.clear();
+        self.hash.clear();
+        self.signature.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Audit_record {
+        static instance: Audit_record = Audit_record {
+            index: 0,
+            requestor: ::std::string::String::new(),
+            sequence: ::std::string::String::new(),
+            timestamp: 0,
+            action: ::std::string::String::new(),
+            thing_ids: ::std::vec::Vec::new(),
+            digest: ::std::string::String::new(),
+            outcome: 0,
+            outcome_message: ::std::string::String::new(),
+            prev_hash: ::std::string::String::new(),
+            hash: ::std::string::String::new(),
+            signature: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Audit_record {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("audit_record").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Audit_record {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Audit_record {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:audit_query)
+pub struct Audit_query {
+    // message fields
+    // @@protoc_insertion_point(field:audit_query.begin)
+    pub begin: u64,
+    // @@protoc_insertion_point(field:audit_query.end)
+    pub end: u64,
+    // @@protoc_insertion_point(field:audit_query.thing_id)
+    pub thing_id: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:audit_query.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Audit_query {
+    fn default() -> &'a Audit_query {
+        <Audit_query as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Audit_query {
+    pub fn new() -> Audit_query {
+        ::std::default::Default::default()
+    }
+
+    // uint64 begin = 1;
+
+    pub fn begin(&self) -> u64 {
+        self.begin
+    }
+
+    pub fn clear_begin(&mut self) {
+        self.begin = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_begin(&mut self, v: u64) {
+        self.begin = v;
+    }
+
+    // uint64 end = 2;
+
+    pub fn end(&self) -> u64 {
+        self.end
+    }
+
+    pub fn clear_end(&mut self) {
+        self.end = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end(&mut self, v: u64) {
+        self.end = v;
+    }
+
+    // string thing_id = 3;
+
+    pub fn thing_id(&self) -> &str {
+        &self.thing_id
+    }
+
+    pub fn clear_thing_id(&mut self) {
+        self.thing_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_thing_id(&mut self, v: ::std::string::String) {
+        self.thing_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_thing_id(&mut self) -> &mut ::std::string::String {
+        &mut self.thing_id
+    }
+
+    // Take field
+    pub fn take_thing_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.thing_id, ::std::string::String::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "begin",
+            |m: &Audit_query| { &m.begin },
+            |m: &mut Audit_query| { &mut m.begin },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "end",
+            |m: &Audit_query| { &m.end },
+            |m: &mut Audit_query| { &mut m.end },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "thing_id",
+            |m: &Audit_query| { &m.thing_id },
+            |m: &mut Audit_query| { &mut m.thing_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Audit_query>(
+            "audit_query",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Audit_query {
+    const NAME: &'static str = "audit_query";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.begin = is.read_uint64()?;
+                },
+                16 => {
+                    self.end = is.read_uint64()?;
+                },
+                26 => {
+                    self.thing_id = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.begin != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.begin);
+        }
+        if self.end != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.end);
+        }
+        if !self.thing_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.thing_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.begin != 0 {
+            os.write_uint64(1, self.begin)?;
+        }
+        if self.end != 0 {
+            os.write_uint64(2, self.end)?;
+        }
+        if !self.thing_id.is_empty() {
+            os.write_string(3, &self.thing_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Audit_query {
+        Audit_query::new()
+    }
+
+    fn clear(&mut self) {
+        self.begin = 0;
+        self.end = 0;
+        self.thing_id.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Audit_query {
+        static instance: Audit_query = Audit_query {
+            begin: 0,
+            end: 0,
+            thing_id: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Audit_query {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("audit_query").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Audit_query {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Audit_query {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:audit_record_list)
+pub struct Audit_record_list {
+    // message fields
+    // @@protoc_insertion_point(field:audit_record_list.records)
+    pub records: ::std::vec::Vec<Audit_record>,
+    // @@protoc_insertion_point(field:audit_record_list.public_key)
+    pub public_key: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:audit_record_list.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Audit_record_list {
+    fn default() -> &'a Audit_record_list {
+        <Audit_record_list as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Audit_record_list {
+    pub fn new() -> Audit_record_list {
+        ::std::default::Default::default()
+    }
+
+    // repeated .audit_record records = 1;
+
+    pub fn records(&self) -> &[Audit_record] {
+        &self.records
+    }
+
+    pub fn clear_records(&mut self) {
+        self.records.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_records(&mut self, v: ::std::vec::Vec<Audit_record>) {
+        self.records = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_records(&mut self) -> &mut ::std::vec::Vec<Audit_record> {
+        &mut self.records
+    }
+
+    // Take field
+    pub fn take_records(&mut self) -> ::std::vec::Vec<Audit_record> {
+        ::std::mem::replace(&mut self.records, ::std::vec::Vec::new())
+    }
+
+    // bytes public_key = 2;
+
+    pub fn public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+
+    pub fn clear_public_key(&mut self) {
+        self.public_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_public_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.public_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_public_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.public_key
+    }
+
+    // Take field
+    pub fn take_public_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.public_key, ::std::vec::Vec::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "records",
+            |m: &Audit_record_list| { &m.records },
+            |m: &mut Audit_record_list| { &mut m.records },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "public_key",
+            |m: &Audit_record_list| { &m.public_key },
+            |m: &mut Audit_record_list| { &mut m.public_key },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Audit_record_list>(
+            "audit_record_list",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Audit_record_list {
+    const NAME: &'static str = "audit_record_list";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.records.push(is.read_message()?);
+                },
+                18 => {
+                    self.public_key = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.records {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if !self.public_key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.public_key);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.records {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if !self.public_key.is_empty() {
+            os.write_bytes(2, &self.public_key)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Audit_record_list {
+        Audit_record_list::new()
+    }
+
+    fn clear(&mut self) {
+        self.records.clear();
+        self.public_key.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Audit_record_list {
+        static instance: Audit_record_list = Audit_record_list {
+            records: ::std::vec::Vec::new(),
+            public_key: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Audit_record_list {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("audit_record_list").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Audit_record_list {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Audit_record_list {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0baudit.proto\"\xdb\x02\n\x0caudit_record\x12\x14\n\x05index\x18\x01\
+    \x20\x01(\x04R\x05index\x12\x1c\n\trequestor\x18\x02\x20\x01(\tR\treques\
+    tor\x12\x1a\n\x08sequence\x18\x03\x20\x01(\tR\x08sequence\x12\x1c\n\ttim\
+    estamp\x18\x04\x20\x01(\x04R\ttimestamp\x12\x16\n\x06action\x18\x05\x20\
+    \x01(\tR\x06action\x12\x1b\n\tthing_ids\x18\x06\x20\x03(\tR\x08thingIds\
+    \x12\x16\n\x06digest\x18\x07\x20\x01(\tR\x06digest\x12\x18\n\x07outcome\
+    \x18\x08\x20\x01(\rR\x07outcome\x12'\n\x0foutcome_message\x18\t\x20\x01(\
+    \tR\x0eoutcomeMessage\x12\x1b\n\tprev_hash\x18\n\x20\x01(\tR\x08prevHash\
+    \x12\x12\n\x04hash\x18\x0b\x20\x01(\tR\x04hash\x12\x1c\n\tsignature\x18\
+    \x0c\x20\x01(\x0cR\tsignature\"P\n\x0baudit_query\x12\x14\n\x05begin\x18\
+    \x01\x20\x01(\x04R\x05begin\x12\x10\n\x03end\x18\x02\x20\x01(\x04R\x03en\
+    d\x12\x19\n\x08thing_id\x18\x03\x20\x01(\tR\x07thingId\"[\n\x11audit_rec\
+    ord_list\x12'\n\x07records\x18\x01\x20\x03(\x0b2\r.audit_recordR\x07reco\
+    rds\x12\x1d\n\npublic_key\x18\x02\x20\x01(\x0cR\tpublicKeyb\x06proto3\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(0);
+            let mut messages = ::std::vec::Vec::with_capacity(3);
+            messages.push(Audit_record::generated_message_descriptor_data());
+            messages.push(Audit_query::generated_message_descriptor_data());
+            messages.push(Audit_record_list::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(0);
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
@@ -0,0 +1,2508 @@
+// This file is generated by rust-protobuf 3.2.0. Do not edit
+// .proto file is parsed by pure
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `firmware.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_2_0;
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:firmware_info)
+pub struct Firmware_info {
+    // message fields
+    // @@protoc_insertion_point(field:firmware_info.firmware_id)
+    pub firmware_id: ::std::string::String,
+    // @@protoc_insertion_point(field:firmware_info.product_id)
+    pub product_id: ::std::string::String,
+    // @@protoc_insertion_point(field:firmware_info.version)
+    pub version: ::std::string::String,
+    // @@protoc_insertion_point(field:firmware_info.file_id)
+    pub file_id: ::std::string::String,
+    // @@protoc_insertion_point(field:firmware_info.size)
+    pub size: u64,
+    // @@protoc_insertion_point(field:firmware_info.hash)
+    pub hash: ::std::string::String,
+    // @@protoc_insertion_point(field:firmware_info.file)
+    pub file: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:firmware_info.release_note)
+    pub release_note: ::std::string::String,
+    // @@protoc_insertion_point(field:firmware_info.create_timestamp)
+    pub create_timestamp: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:firmware_info.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Firmware_info {
+    fn default() -> &'a Firmware_info {
+        <Firmware_info as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Firmware_info {
+    pub fn new() -> Firmware_info {
+        ::std::default::Default::default()
+    }
+
+    // string firmware_id = 1;
+
+    pub fn firmware_id(&self) -> &str {
+        &self.firmware_id
+    }
+
+    pub fn clear_firmware_id(&mut self) {
+        self.firmware_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_firmware_id(&mut self, v: ::std::string::String) {
+        self.firmware_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_firmware_id(&mut self) -> &mut ::std::string::String {
+        &mut self.firmware_id
+    }
+
+    // Take field
+    pub fn take_firmware_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.firmware_id, ::std::string::String::new())
+    }
+
+    // string product_id = 2;
+
+    pub fn product_id(&self) -> &str {
+        &self.product_id
+    }
+
+    pub fn clear_product_id(&mut self) {
+        self.product_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_product_id(&mut self, v: ::std::string::String) {
+        self.product_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_product_id(&mut self) -> &mut ::std::string::String {
+        &mut self.product_id
+    }
+
+    // Take field
+    pub fn take_product_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.product_id, ::std::string::String::new())
+    }
+
+    // string version = 3;
+
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    pub fn clear_version(&mut self) {
+        self.version.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: ::std::string::String) {
+        self.version = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_version(&mut self) -> &mut ::std::string::String {
+        &mut self.version
+    }
+
+    // Take field
+    pub fn take_version(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.version, ::std::string::String::new())
+    }
+
+    // string file_id = 4;
+
+    pub fn file_id(&self) -> &str {
+        &self.file_id
+    }
+
+    pub fn clear_file_id(&mut self) {
+        self.file_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_id(&mut self, v: ::std::string::String) {
+        self.file_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_file_id(&mut self) -> &mut ::std::string::String {
+        &mut self.file_id
+    }
+
+    // Take field
+    pub fn take_file_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.file_id, ::std::string::String::new())
+    }
+
+    // uint64 size = 5;
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn clear_size(&mut self) {
+        self.size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_size(&mut self, v: u64) {
+        self.size = v;
+    }
+
+    // string hash = 6;
+
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+
+    pub fn clear_hash(&mut self) {
+        self.hash.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hash(&mut self, v: ::std::string::String) {
+        self.hash = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_hash(&mut self) -> &mut ::std::string::String {
+        &mut self.hash
+    }
+
+    // Take field
+    pub fn take_hash(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.hash, ::std::string::String::new())
+    }
+
+    // bytes file = 7;
+
+    pub fn file(&self) -> &[u8] {
+        &self.file
+    }
+
+    pub fn clear_file(&mut self) {
+        self.file.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file(&mut self, v: ::std::vec::Vec<u8>) {
+        self.file = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_file(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.file
+    }
+
+    // Take field
+    pub fn take_file(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.file, ::std::vec::Vec::new())
+    }
+
+    // string release_note = 8;
+
+    pub fn release_note(&self) -> &str {
+        &self.release_note
+    }
+
+    pub fn clear_release_note(&mut self) {
+        self.release_note.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_release_note(&mut self, v: ::std::string::String) {
+        self.release_note = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_release_note(&mut self) -> &mut ::std::string::String {
+        &mut self.release_note
+    }
+
+    // Take field
+    pub fn take_release_note(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.release_note, ::std::string::String::new())
+    }
+
+    // uint64 create_timestamp = 9;
+
+    pub fn create_timestamp(&self) -> u64 {
+        self.create_timestamp
+    }
+
+    pub fn clear_create_timestamp(&mut self) {
+        self.create_timestamp = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_create_timestamp(&mut self, v: u64) {
+        self.create_timestamp = v;
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(9);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "firmware_id",
+            |m: &Firmware_info| { &m.firmware_id },
+            |m: &mut Firmware_info| { &mut m.firmware_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "product_id",
+            |m: &Firmware_info| { &m.product_id },
+            |m: &mut Firmware_info| { &mut m.product_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "version",
+            |m: &Firmware_info| { &m.version },
+            |m: &mut Firmware_info| { &mut m.version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "file_id",
+            |m: &Firmware_info| { &m.file_id },
+            |m: &mut Firmware_info| { &mut m.file_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "size",
+            |m: &Firmware_info| { &m.size },
+            |m: &mut Firmware_info| { &mut m.size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "hash",
+            |m: &Firmware_info| { &m.hash },
+            |m: &mut Firmware_info| { &mut m.hash },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "file",
+            |m: &Firmware_info| { &m.file },
+            |m: &mut Firmware_info| { &mut m.file },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "release_note",
+            |m: &Firmware_info| { &m.release_note },
+            |m: &mut Firmware_info| { &mut m.release_note },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "create_timestamp",
+            |m: &Firmware_info| { &m.create_timestamp },
+            |m: &mut Firmware_info| { &mut m.create_timestamp },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Firmware_info>(
+            "firmware_info",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Firmware_info {
+    const NAME: &'static str = "firmware_info";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.firmware_id = is.read_string()?;
+                },
+                18 => {
+                    self.product_id = is.read_string()?;
+                },
+                26 => {
+                    self.version = is.read_string()?;
+                },
+                34 => {
+                    self.file_id = is.read_string()?;
+                },
+                40 => {
+                    self.size = is.read_uint64()?;
+                },
+                50 => {
+                    self.hash = is.read_string()?;
+                },
+                58 => {
+                    self.file = is.read_bytes()?;
+                },
+                66 => {
+                    self.release_note = is.read_string()?;
+                },
+                72 => {
+                    self.create_timestamp = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.firmware_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.firmware_id);
+        }
+        if !self.product_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.product_id);
+        }
+        if !self.version.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.version);
+        }
+        if !self.file_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.file_id);
+        }
+        if self.size != 0 {
+            my_size += ::protobuf::rt::uint64_size(5, self.size);
+        }
+        if !self.hash.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.hash);
+        }
+        if !self.file.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(7, &self.file);
+        }
+        if !self.release_note.is_empty() {
+            my_size += ::protobuf::rt::string_size(8, &self.release_note);
+        }
+        if self.create_timestamp != 0 {
+            my_size += ::protobuf::rt::uint64_size(9, self.create_timestamp);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.firmware_id.is_empty() {
+            os.write_string(1, &self.firmware_id)?;
+        }
+        if !self.product_id.is_empty() {
+            os.write_string(2, &self.product_id)?;
+        }
+        if !self.version.is_empty() {
+            os.write_string(3, &self.version)?;
+        }
+        if !self.file_id.is_empty() {
+            os.write_string(4, &self.file_id)?;
+        }
+        if self.size != 0 {
+            os.write_uint64(5, self.size)?;
+        }
+        if !self.hash.is_empty() {
+            os.write_string(6, &self.hash)?;
+        }
+        if !self.file.is_empty() {
+            os.write_bytes(7, &self.file)?;
+        }
+        if !self.release_note.is_empty() {
+            os.write_string(8, &self.release_note)?;
+        }
+        if self.create_timestamp != 0 {
+            os.write_uint64(9, self.create_timestamp)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Firmware_info {
+        Firmware_info::new()
+    }
+
+    fn clear(&mut self) {
+        self.firmware_id.clear();
+        self.product_id.clear();
+        self.version.clear();
+        self.file_id.clear();
+        self.size = 0;
+        self.hash.clear();
+        self.file.clear();
+        self.release_note.clear();
+        self.create_timestamp = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Firmware_info {
+        static instance: Firmware_info = Firmware_info {
+            firmware_id: ::std::string::String::new(),
+            product_id: ::std::string::String::new(),
+            version: ::std::string::String::new(),
+            file_id: ::std::string::String::new(),
+            size: 0,
+            hash: ::std::string::String::new(),
+            file: ::std::vec::Vec::new(),
+            release_note: ::std::string::String::new(),
+            create_timestamp: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Firmware_info {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("firmware_info").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Firmware_info {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Firmware_info {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:firmware_info_list)
+pub struct Firmware_info_list {
+    // message fields
+    // @@protoc_insertion_point(field:firmware_info_list.firmwares)
+    pub firmwares: ::std::vec::Vec<Firmware_info>,
+    // special fields
+    // @@protoc_insertion_point(special_field:firmware_info_list.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Firmware_info_list {
+    fn default() -> &'a Firmware_info_list {
+        <Firmware_info_list as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Firmware_info_list {
+    pub fn new() -> Firmware_info_list {
+        ::std::default::Default::default()
+    }
+
+    // repeated .firmware_info firmwares = 1;
+
+    pub fn firmwares(&self) -> &[Firmware_info] {
+        &self.firmwares
+    }
+
+    pub fn clear_firmwares(&mut self) {
+        self.firmwares.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_firmwares(&mut self, v: ::std::vec::Vec<Firmware_info>) {
+        self.firmwares = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_firmwares(&mut self) -> &mut ::std::vec::Vec<Firmware_info> {
+        &mut self.firmwares
+    }
+
+    // Take field
+    pub fn take_firmwares(&mut self) -> ::std::vec::Vec<Firmware_info> {
+        ::std::mem::replace(&mut self.firmwares, ::std::vec::Vec::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "firmwares",
+            |m: &Firmware_info_list| { &m.firmwares },
+            |m: &mut Firmware_info_list| { &mut m.firmwares },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Firmware_info_list>(
+            "firmware_info_list",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Firmware_info_list {
+    const NAME: &'static str = "firmware_info_list";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.firmwares.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.firmwares {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.firmwares {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Firmware_info_list {
+        Firmware_info_list::new()
+    }
+
+    fn clear(&mut self) {
+        self.firmwares.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Firmware_info_list {
+        static instance: Firmware_info_list = Firmware_info_list {
+            firmwares: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Firmware_info_list {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("firmware_info_list").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Firmware_info_list {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Firmware_info_list {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:firmware_add)
+pub struct Firmware_add {
+    // message fields
+    // @@protoc_insertion_point(field:firmware_add.product_id)
+    pub product_id: ::std::string::String,
+    // @@protoc_insertion_point(field:firmware_add.version)
+    pub version: ::std::string::String,
+    // @@protoc_insertion_point(field:firmware_add.file)
+    pub file: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:firmware_add.release_note)
+    pub release_note: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:firmware_add.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Firmware_add {
+    fn default() -> &'a Firmware_add {
+        <Firmware_add as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Firmware_add {
+    pub fn new() -> Firmware_add {
+        ::std::default::Default::default()
+    }
+
+    // string product_id = 1;
+
+    pub fn product_id(&self) -> &str {
+        &self.product_id
+    }
+
+    pub fn clear_product_id(&mut self) {
+        self.product_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_product_id(&mut self, v: ::std::string::String) {
+        self.product_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_product_id(&mut self) -> &mut ::std::string::String {
+        &mut self.product_id
+    }
+
+    // Take field
+    pub fn take_product_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.product_id, ::std::string::String::new())
+    }
+
+    // string version = 2;
+
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    pub fn clear_version(&mut self) {
+        self.version.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: ::std::string::String) {
+        self.version = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_version(&mut self) -> &mut ::std::string::String {
+        &mut self.version
+    }
+
+    // Take field
+    pub fn take_version(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.version, ::std::string::String::new())
+    }
+
+    // bytes file = 3;
+
+    pub fn file(&self) -> &[u8] {
+        &self.file
+    }
+
+    pub fn clear_file(&mut self) {
+        self.file.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file(&mut self, v: ::std::vec::Vec<u8>) {
+        self.file = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_file(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.file
+    }
+
+    // Take field
+    pub fn take_file(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.file, ::std::vec::Vec::new())
+    }
+
+    // string release_note = 4;
+
+    pub fn release_note(&self) -> &str {
+        &self.release_note
+    }
+
+    pub fn clear_release_note(&mut self) {
+        self.release_note.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_release_note(&mut self, v: ::std::string::String) {
+        self.release_note = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_release_note(&mut self) -> &mut ::std::string::String {
+        &mut self.release_note
+    }
+
+    // Take field
+    pub fn take_release_note(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.release_note, ::std::string::String::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "product_id",
+            |m: &Firmware_add| { &m.product_id },
+            |m: &mut Firmware_add| { &mut m.product_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "version",
+            |m: &Firmware_add| { &m.version },
+            |m: &mut Firmware_add| { &mut m.version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "file",
+            |m: &Firmware_add| { &m.file },
+            |m: &mut Firmware_add| { &mut m.file },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "release_note",
+            |m: &Firmware_add| { &m.release_note },
+            |m: &mut Firmware_add| { &mut m.release_note },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Firmware_add>(
+            "firmware_add",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Firmware_add {
+    const NAME: &'static str = "firmware_add";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.product_id = is.read_string()?;
+                },
+                18 => {
+                    self.version = is.read_string()?;
+                },
+                26 => {
+                    self.file = is.read_bytes()?;
+                },
+                34 => {
+                    self.release_note = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.product_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.product_id);
+        }
+        if !self.version.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.version);
+        }
+        if !self.file.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.file);
+        }
+        if !self.release_note.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.release_note);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.product_id.is_empty() {
+            os.write_string(1, &self.product_id)?;
+        }
+        if !self.version.is_empty() {
+            os.write_string(2, &self.version)?;
+        }
+        if !self.file.is_empty() {
+            os.write_bytes(3, &self.file)?;
+        }
+        if !self.release_note.is_empty() {
+            os.write_string(4, &self.release_note)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Firmware_add {
+        Firmware_add::new()
+    }
+
+    fn clear(&mut self) {
+        self.product_id.clear();
+        self.version.clear();
+        self.file.clear();
+        self.release_note.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Firmware_add {
+        static instance: Firmware_add = Firmware_add {
+            product_id: ::std::string::String::new(),
+            version: ::std::string::String::new(),
+            file: ::std::vec::Vec::new(),
+            release_note: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Firmware_add {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("firmware_add").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Firmware_add {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Firmware_add {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:firmware_query)
+pub struct Firmware_query {
+    // message fields
+    // @@protoc_insertion_point(field:firmware_query.product_id)
+    pub product_id: ::std::string::String,
+    // @@protoc_insertion_point(field:firmware_query.version)
+    pub version: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:firmware_query.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Firmware_query {
+    fn default() -> &'a Firmware_query {
+        <Firmware_query as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Firmware_query {
+    pub fn new() -> Firmware_query {
+        ::std::default::Default::default()
+    }
+
+    // string product_id = 1;
+
+    pub fn product_id(&self) -> &str {
+        &self.product_id
+    }
+
+    pub fn clear_product_id(&mut self) {
+        self.product_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_product_id(&mut self, v: ::std::string::String) {
+        self.product_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_product_id(&mut self) -> &mut ::std::string::String {
+        &mut self.product_id
+    }
+
+    // Take field
+    pub fn take_product_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.product_id, ::std::string::String::new())
+    }
+
+    // string version = 2;
+
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    pub fn clear_version(&mut self) {
+        self.version.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: ::std::string::String) {
+        self.version = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_version(&mut self) -> &mut ::std::string::String {
+        &mut self.version
+    }
+
+    // Take field
+    pub fn take_version(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.version, ::std::string::String::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "product_id",
+            |m: &Firmware_query| { &m.product_id },
+            |m: &mut Firmware_query| { &mut m.product_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "version",
+            |m: &Firmware_query| { &m.version },
+            |m: &mut Firmware_query| { &mut m.version },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Firmware_query>(
+            "firmware_query",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Firmware_query {
+    const NAME: &'static str = "firmware_query";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.product_id = is.read_string()?;
+                },
+                18 => {
+                    self.version = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.product_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.product_id);
+        }
+        if !self.version.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.version);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.product_id.is_empty() {
+            os.write_string(1, &self.product_id)?;
+        }
+        if !self.version.is_empty() {
+            os.write_string(2, &self.version)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Firmware_query {
+        Firmware_query::new()
+    }
+
+    fn clear(&mut self) {
+        self.product_id.clear();
+        self.version.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Firmware_query {
+        static instance: Firmware_query = Firmware_query {
+            product_id: ::std::string::String::new(),
+            version: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Firmware_query {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("firmware_query").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Firmware_query {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Firmware_query {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:firmware_query_all)
+pub struct Firmware_query_all {
+    // message fields
+    // @@protoc_insertion_point(field:firmware_query_all.product_id)
+    pub product_id: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:firmware_query_all.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Firmware_query_all {
+    fn default() -> &'a Firmware_query_all {
+        <Firmware_query_all as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Firmware_query_all {
+    pub fn new() -> Firmware_query_all {
+        ::std::default::Default::default()
+    }
+
+    // string product_id = 1;
+
+    pub fn product_id(&self) -> &str {
+        &self.product_id
+    }
+
+    pub fn clear_product_id(&mut self) {
+        self.product_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_product_id(&mut self, v: ::std::string::String) {
+        self.product_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_product_id(&mut self) -> &mut ::std::string::String {
+        &mut self.product_id
+    }
+
+    // Take field
+    pub fn take_product_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.product_id, ::std::string::String::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "product_id",
+            |m: &Firmware_query_all| { &m.product_id },
+            |m: &mut Firmware_query_all| { &mut m.product_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Firmware_query_all>(
+            "firmware_query_all",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Firmware_query_all {
+    const NAME: &'static str = "firmware_query_all";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.product_id = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.product_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.product_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.product_id.is_empty() {
+            os.write_string(1, &self.product_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Firmware_query_all {
+        Firmware_query_all::new()
+    }
+
+    fn clear(&mut self) {
+        self.product_id.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Firmware_query_all {
+        static instance: Firmware_query_all = Firmware_query_all {
+            product_id: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Firmware_query_all {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("firmware_query_all").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Firmware_query_all {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Firmware_query_all {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:ota_status)
+pub struct Ota_status {
+    // message fields
+    // @@protoc_insertion_point(field:ota_status.thing_id)
+    pub thing_id: ::std::string::String,
+    // @@protoc_insertion_point(field:ota_status.firmware_id)
+    pub firmware_id: ::std::string::String,
+    // @@protoc_insertion_point(field:ota_status.version)
+    pub version: ::std::string::String,
+    // @@protoc_insertion_point(field:ota_status.state)
+    pub state: ::protobuf::EnumOrUnknown<Ota_state>,
+    // @@protoc_insertion_point(field:ota_status.acked)
+    pub acked: u64,
+    // @@protoc_insertion_point(field:ota_status.total)
+    pub total: u64,
+    // @@protoc_insertion_point(field:ota_status.error)
+    pub error: ::std::string::String,
+    // @@protoc_insertion_point(field:ota_status.running_version)
+    pub running_version: ::std::string::String,
+    // @@protoc_insertion_point(field:ota_status.update_timestamp)
+    pub update_timestamp: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:ota_status.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Ota_status {
+    fn default() -> &'a Ota_status {
+        <Ota_status as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Ota_status {
+    pub fn new() -> Ota_status {
+        ::std::default::Default::default()
+    }
+
+    // string thing_id = 1;
+
+    pub fn thing_id(&self) -> &str {
+        &self.thing_id
+    }
+
+    pub fn clear_thing_id(&mut self) {
+        self.thing_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_thing_id(&mut self, v: ::std::string::String) {
+        self.thing_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_thing_id(&mut self) -> &mut ::std::string::String {
+        &mut self.thing_id
+    }
+
+    // Take field
+    pub fn take_thing_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.thing_id, ::std::string::String::new())
+    }
+
+    // string firmware_id = 2;
+
+    pub fn firmware_id(&self) -> &str {
+        &self.firmware_id
+    }
+
+    pub fn clear_firmware_id(&mut self) {
+        self.firmware_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_firmware_id(&mut self, v: ::std::string::String) {
+        self.firmware_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_firmware_id(&mut self) -> &mut ::std::string::String {
+        &mut self.firmware_id
+    }
+
+    // Take field
+    pub fn take_firmware_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.firmware_id, ::std::string::String::new())
+    }
+
+    // string version = 3;
+
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    pub fn clear_version(&mut self) {
+        self.version.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: ::std::string::String) {
+        self.version = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_version(&mut self) -> &mut ::std::string::String {
+        &mut self.version
+    }
+
+    // Take field
+    pub fn take_version(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.version, ::std::string::String::new())
+    }
+
+    // .ota_state state = 4;
+
+    pub fn state(&self) -> Ota_state {
+        self.state.enum_value_or_default()
+    }
+
+    pub fn clear_state(&mut self) {
+        self.state = ::protobuf::EnumOrUnknown::new(Ota_state::Pending);
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: Ota_state) {
+        self.state = ::protobuf::EnumOrUnknown::new(v);
+    }
+
+    // uint64 acked = 5;
+
+    pub fn acked(&self) -> u64 {
+        self.acked
+    }
+
+    pub fn clear_acked(&mut self) {
+        self.acked = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_acked(&mut self, v: u64) {
+        self.acked = v;
+    }
+
+    // uint64 total = 6;
+
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    pub fn clear_total(&mut self) {
+        self.total = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total(&mut self, v: u64) {
+        self.total = v;
+    }
+
+    // string error = 7;
+
+    pub fn error(&self) -> &str {
+        &self.error
+    }
+
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        &mut self.error
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error, ::std::string::String::new())
+    }
+
+    // string running_version = 8;
+
+    pub fn running_version(&self) -> &str {
+        &self.running_version
+    }
+
+    pub fn clear_running_version(&mut self) {
+        self.running_version.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_running_version(&mut self, v: ::std::string::String) {
+        self.running_version = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_running_version(&mut self) -> &mut ::std::string::String {
+        &mut self.running_version
+    }
+
+    // Take field
+    pub fn take_running_version(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.running_version, ::std::string::String::new())
+    }
+
+    // uint64 update_timestamp = 9;
+
+    pub fn update_timestamp(&self) -> u64 {
+        self.update_timestamp
+    }
+
+    pub fn clear_update_timestamp(&mut self) {
+        self.update_timestamp = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_update_timestamp(&mut self, v: u64) {
+        self.update_timestamp = v;
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(9);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "thing_id",
+            |m: &Ota_status| { &m.thing_id },
+            |m: &mut Ota_status| { &mut m.thing_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "firmware_id",
+            |m: &Ota_status| { &m.firmware_id },
+            |m: &mut Ota_status| { &mut m.firmware_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "version",
+            |m: &Ota_status| { &m.version },
+            |m: &mut Ota_status| { &mut m.version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "state",
+            |m: &Ota_status| { &m.state },
+            |m: &mut Ota_status| { &mut m.state },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "acked",
+            |m: &Ota_status| { &m.acked },
+            |m: &mut Ota_status| { &mut m.acked },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "total",
+            |m: &Ota_status| { &m.total },
+            |m: &mut Ota_status| { &mut m.total },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error",
+            |m: &Ota_status| { &m.error },
+            |m: &mut Ota_status| { &mut m.error },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "running_version",
+            |m: &Ota_status| { &m.running_version },
+            |m: &mut Ota_status| { &mut m.running_version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "update_timestamp",
+            |m: &Ota_status| { &m.update_timestamp },
+            |m: &mut Ota_status| { &mut m.update_timestamp },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Ota_status>(
+            "ota_status",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Ota_status {
+    const NAME: &'static str = "ota_status";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.thing_id = is.read_string()?;
+                },
+                18 => {
+                    self.firmware_id = is.read_string()?;
+                },
+                26 => {
+                    self.version = is.read_string()?;
+                },
+                32 => {
+                    self.state = is.read_enum_or_unknown()?;
+                },
+                40 => {
+                    self.acked = is.read_uint64()?;
+                },
+                48 => {
+                    self.total = is.read_uint64()?;
+                },
+                58 => {
+                    self.error = is.read_string()?;
+                },
+                66 => {
+                    self.running_version = is.read_string()?;
+                },
+                72 => {
+                    self.update_timestamp = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.thing_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.thing_id);
+        }
+        if !self.firmware_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.firmware_id);
+        }
+        if !self.version.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.version);
+        }
+        if self.state != ::protobuf::EnumOrUnknown::new(Ota_state::Pending) {
+            my_size += ::protobuf::rt::int32_size(4, self.state.value());
+        }
+        if self.acked != 0 {
+            my_size += ::protobuf::rt::uint64_size(5, self.acked);
+        }
+        if self.total != 0 {
+            my_size += ::protobuf::rt::uint64_size(6, self.total);
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.error);
+        }
+        if !self.running_version.is_empty() {
+            my_size += ::protobuf::rt::string_size(8, &self.running_version);
+        }
+        if self.update_timestamp != 0 {
+            my_size += ::protobuf::rt::uint64_size(9, self.update_timestamp);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.thing_id.is_empty() {
+            os.write_string(1, &self.thing_id)?;
+        }
+        if !self.firmware_id.is_empty() {
+            os.write_string(2, &self.firmware_id)?;
+        }
+        if !self.version.is_empty() {
+            os.write_string(3, &self.version)?;
+        }
+        if self.state != ::protobuf::EnumOrUnknown::new(Ota_state::Pending) {
+            os.write_enum(4, ::protobuf::EnumOrUnknown::value(&self.state))?;
+        }
+        if self.acked != 0 {
+            os.write_uint64(5, self.acked)?;
+        }
+        if self.total != 0 {
+            os.write_uint64(6, self.total)?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(7, &self.error)?;
+        }
+        if !self.running_version.is_empty() {
+            os.write_string(8, &self.running_version)?;
+        }
+        if self.update_timestamp != 0 {
+            os.write_uint64(9, self.update_timestamp)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Ota_status {
+        Ota_status::new()
+    }
+
+    fn clear(&mut self) {
+        self.thing_id.clear();
+        self.firmware_id.clear();
+        self.version.clear();
+        self.state = ::protobuf::EnumOrUnknown::new(Ota_state::Pending);
+        self.acked = 0;
+        self.total = 0;
+        self.error.clear();
+        self.running_version.clear();
+        self.update_timestamp = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Ota_status {
+        static instance: Ota_status = Ota_status {
+            thing_id: ::std::string::String::new(),
+            firmware_id: ::std::string::String::new(),
+            version: ::std::string::String::new(),
+            state: ::protobuf::EnumOrUnknown::from_i32(0),
+            acked: 0,
+            total: 0,
+            error: ::std::string::String::new(),
+            running_version: ::std::string::String::new(),
+            update_timestamp: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Ota_status {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ota_status").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Ota_status {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Ota_status {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:ota_status_list)
+pub struct Ota_status_list {
+    // message fields
+    // @@protoc_insertion_point(field:ota_status_list.things)
+    pub things: ::std::vec::Vec<Ota_status>,
+    // special fields
+    // @@protoc_insertion_point(special_field:ota_status_list.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Ota_status_list {
+    fn default() -> &'a Ota_status_list {
+        <Ota_status_list as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Ota_status_list {
+    pub fn new() -> Ota_status_list {
+        ::std::default::Default::default()
+    }
+
+    // repeated .ota_status things = 1;
+
+    pub fn things(&self) -> &[Ota_status] {
+        &self.things
+    }
+
+    pub fn clear_things(&mut self) {
+        self.things.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_things(&mut self, v: ::std::vec::Vec<Ota_status>) {
+        self.things = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_things(&mut self) -> &mut ::std::vec::Vec<Ota_status> {
+        &mut self.things
+    }
+
+    // Take field
+    pub fn take_things(&mut self) -> ::std::vec::Vec<Ota_status> {
+        ::std::mem::replace(&mut self.things, ::std::vec::Vec::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "things",
+            |m: &Ota_status_list| { &m.things },
+            |m: &mut Ota_status_list| { &mut m.things },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Ota_status_list>(
+            "ota_status_list",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Ota_status_list {
+    const NAME: &'static str = "ota_status_list";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.things.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.things {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.things {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Ota_status_list {
+        Ota_status_list::new()
+    }
+
+    fn clear(&mut self) {
+        self.things.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Ota_status_list {
+        static instance: Ota_status_list = Ota_status_list {
+            things: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Ota_status_list {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ota_status_list").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Ota_status_list {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Ota_status_list {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:ota_start)
+pub struct Ota_start {
+    // message fields
+    // @@protoc_insertion_point(field:ota_start.thing_ids)
+    pub thing_ids: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:ota_start.version)
+    pub version: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:ota_start.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Ota_start {
+    fn default() -> &'a Ota_start {
+        <Ota_start as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Ota_start {
+    pub fn new() -> Ota_start {
+        ::std::default::Default::default()
+    }
+
+    // repeated string thing_ids = 1;
+
+    pub fn thing_ids(&self) -> &[::std::string::String] {
+        &self.thing_ids
+    }
+
+    pub fn clear_thing_ids(&mut self) {
+        self.thing_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_thing_ids(&mut self, v: ::std::vec::Vec<::std::string::String>) {
+        self.thing_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_thing_ids(&mut self) -> &mut ::std::vec::Vec<::std::string::String> {
+        &mut self.thing_ids
+    }
+
+    // Take field
+    pub fn take_thing_ids(&mut self) -> ::std::vec::Vec<::std::string::String> {
+        ::std::mem::replace(&mut self.thing_ids, ::std::vec::Vec::new())
+    }
+
+    // string version = 2;
+
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    pub fn clear_version(&mut self) {
+        self.version.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: ::std::string::String) {
+        self.version = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_version(&mut self) -> &mut ::std::string::String {
+        &mut self.version
+    }
+
+    // Take field
+    pub fn take_version(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.version, ::std::string::String::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "thing_ids",
+            |m: &Ota_start| { &m.thing_ids },
+            |m: &mut Ota_start| { &mut m.thing_ids },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "version",
+            |m: &Ota_start| { &m.version },
+            |m: &mut Ota_start| { &mut m.version },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Ota_start>(
+            "ota_start",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Ota_start {
+    const NAME: &'static str = "ota_start";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.thing_ids.push(is.read_string()?);
+                },
+                18 => {
+                    self.version = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.thing_ids {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        if !self.version.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.version);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.thing_ids {
+            os.write_string(1, &v)?;
+        };
+        if !self.version.is_empty() {
+            os.write_string(2, &self.version)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Ota_start {
+        Ota_start::new()
+    }
+
+    fn clear(&mut self) {
+        self.thing_ids.clear();
+        self.version.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Ota_start {
+        static instance: Ota_start = Ota_start {
+            thing_ids: ::std::vec::Vec::new(),
+            version: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Ota_start {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ota_start").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Ota_start {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Ota_start {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:ota_query)
+pub struct Ota_query {
+    // message fields
+    // @@protoc_insertion_point(field:ota_query.thing_ids)
+    pub thing_ids: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:ota_query.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Ota_query {
+    fn default() -> &'a Ota_query {
+        <Ota_query as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Ota_query {
+    pub fn new() -> Ota_query {
+        ::std::default::Default::default()
+    }
+
+    // repeated string thing_ids = 1;
+
+    pub fn thing_ids(&self) -> &[::std::string::String] {
+        &self.thing_ids
+    }
+
+    pub fn clear_thing_ids(&mut self) {
+        self.thing_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_thing_ids(&mut self, v: ::std::vec::Vec<::std::string::String>) {
+        self.thing_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_thing_ids(&mut self) -> &mut ::std::vec::Vec<::std::string::String> {
+        &mut self.thing_ids
+    }
+
+    // Take field
+    pub fn take_thing_ids(&mut self) -> ::std::vec::Vec<::std::string::String> {
+        ::std::mem::replace(&mut self.thing_ids, ::std::vec::Vec::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "thing_ids",
+            |m: &Ota_query| { &m.thing_ids },
+            |m: &mut Ota_query| { &mut m.thing_ids },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Ota_query>(
+            "ota_query",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Ota_query {
+    const NAME: &'static str = "ota_query";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.thing_ids.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.thing_ids {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.thing_ids {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Ota_query {
+        Ota_query::new()
+    }
+
+    fn clear(&mut self) {
+        self.thing_ids.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Ota_query {
+        static instance: Ota_query = Ota_query {
+            thing_ids: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Ota_query {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ota_query").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Ota_query {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Ota_query {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:ota_cancel)
+pub struct Ota_cancel {
+    // message fields
+    // @@protoc_insertion_point(field:ota_cancel.thing_ids)
+    pub thing_ids: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:ota_cancel.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Ota_cancel {
+    fn default() -> &'a Ota_cancel {
+        <Ota_cancel as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Ota_cancel {
+    pub fn new() -> Ota_cancel {
+        ::std::default::Default::default()
+    }
+
+    // repeated string thing_ids = 1;
+
+    pub fn thing_ids(&self) -> &[::std::string::String] {
+        &self.thing_ids
+    }
+
+    pub fn clear_thing_ids(&mut self) {
+        self.thing_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_thing_ids(&mut self, v: ::std::vec::Vec<::std::string::String>) {
+        self.thing_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_thing_ids(&mut self) -> &mut ::std::vec::Vec<::std::string::String> {
+        &mut self.thing_ids
+    }
+
+    // Take field
+    pub fn take_thing_ids(&mut self) -> ::std::vec::Vec<::std::string::String> {
+        ::std::mem::replace(&mut self.thing_ids, ::std::vec::Vec::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "thing_ids",
+            |m: &Ota_cancel| { &m.thing_ids },
+            |m: &mut Ota_cancel| { &mut m.thing_ids },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Ota_cancel>(
+            "ota_cancel",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Ota_cancel {
+    const NAME: &'static str = "ota_cancel";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.thing_ids.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.thing_ids {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.thing_ids {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Ota_cancel {
+        Ota_cancel::new()
+    }
+
+    fn clear(&mut self) {
+        self.thing_ids.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Ota_cancel {
+        static instance: Ota_cancel = Ota_cancel {
+            thing_ids: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Ota_cancel {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ota_cancel").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Ota_cancel {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Ota_cancel {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:ota_state)
+pub enum Ota_state {
+    // @@protoc_insertion_point(enum_value:ota_state.Pending)
+    Pending = 0,
+    // @@protoc_insertion_point(enum_value:ota_state.Transferring)
+    Transferring = 1,
+    // @@protoc_insertion_point(enum_value:ota_state.Verifying)
+    Verifying = 2,
+    // @@protoc_insertion_point(enum_value:ota_state.Succeeded)
+    Succeeded = 3,
+    // @@protoc_insertion_point(enum_value:ota_state.Failed)
+    Failed = 4,
+    // @@protoc_insertion_point(enum_value:ota_state.RolledBack)
+    RolledBack = 5,
+    // @@protoc_insertion_point(enum_value:ota_state.Canceled)
+    Canceled = 6,
+}
+
+impl ::protobuf::Enum for Ota_state {
+    const NAME: &'static str = "ota_state";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Ota_state> {
+        match value {
+            0 => ::std::option::Option::Some(Ota_state::Pending),
+            1 => ::std::option::Option::Some(Ota_state::Transferring),
+            2 => ::std::option::Option::Some(Ota_state::Verifying),
+            3 => ::std::option::Option::Some(Ota_state::Succeeded),
+            4 => ::std::option::Option::Some(Ota_state::Failed),
+            5 => ::std::option::Option::Some(Ota_state::RolledBack),
+            6 => ::std::option::Option::Some(Ota_state::Canceled),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [Ota_state] = &[
+        Ota_state::Pending,
+        Ota_state::Transferring,
+        Ota_state::Verifying,
+        Ota_state::Succeeded,
+        Ota_state::Failed,
+        Ota_state::RolledBack,
+        Ota_state::Canceled,
+    ];
+}
+
+impl ::protobuf::EnumFull for Ota_state {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("ota_state").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for Ota_state {
+    fn default() -> Self {
+        Ota_state::Pending
+    }
+}
+
+impl Ota_state {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Ota_state>("ota_state")
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0efirmware.proto\"\x8c\x02\n\rfirmware_info\x12\x1f\n\x0bfirmware_id\
+    \x18\x01\x20\x01(\tR\nfirmwareId\x12\x1d\n\nproduct_id\x18\x02\x20\x01(\
+    \tR\tproductId\x12\x18\n\x07version\x18\x03\x20\x01(\tR\x07version\x12\
+    \x17\n\x07file_id\x18\x04\x20\x01(\tR\x06fileId\x12\x12\n\x04size\x18\
+    \x05\x20\x01(\x04R\x04size\x12\x12\n\x04hash\x18\x06\x20\x01(\tR\x04hash\
+    \x12\x12\n\x04file\x18\x07\x20\x01(\x0cR\x04file\x12!\n\x0crelease_note\
+    \x18\x08\x20\x01(\tR\x0breleaseNote\x12)\n\x10create_timestamp\x18\t\x20\
+    \x01(\x04R\x0fcreateTimestamp\"B\n\x12firmware_info_list\x12,\n\tfirmwar\
+    es\x18\x01\x20\x03(\x0b2\x0e.firmware_infoR\tfirmwares\"~\n\x0cfirmware_\
+    add\x12\x1d\n\nproduct_id\x18\x01\x20\x01(\tR\tproductId\x12\x18\n\x07ve\
+    rsion\x18\x02\x20\x01(\tR\x07version\x12\x12\n\x04file\x18\x03\x20\x01(\
+    \x0cR\x04file\x12!\n\x0crelease_note\x18\x04\x20\x01(\tR\x0breleaseNote\
+    \"I\n\x0efirmware_query\x12\x1d\n\nproduct_id\x18\x01\x20\x01(\tR\tprodu\
+    ctId\x12\x18\n\x07version\x18\x02\x20\x01(\tR\x07version\"3\n\x12firmwar\
+    e_query_all\x12\x1d\n\nproduct_id\x18\x01\x20\x01(\tR\tproductId\"\x9a\
+    \x02\n\nota_status\x12\x19\n\x08thing_id\x18\x01\x20\x01(\tR\x07thingId\
+    \x12\x1f\n\x0bfirmware_id\x18\x02\x20\x01(\tR\nfirmwareId\x12\x18\n\x07v\
+    ersion\x18\x03\x20\x01(\tR\x07version\x12\x20\n\x05state\x18\x04\x20\x01\
+    (\x0e2\n.ota_stateR\x05state\x12\x14\n\x05acked\x18\x05\x20\x01(\x04R\
+    \x05acked\x12\x14\n\x05total\x18\x06\x20\x01(\x04R\x05total\x12\x14\n\
+    \x05error\x18\x07\x20\x01(\tR\x05error\x12'\n\x0frunning_version\x18\x08\
+    \x20\x01(\tR\x0erunningVersion\x12)\n\x10update_timestamp\x18\t\x20\x01(\
+    \x04R\x0fupdateTimestamp\"6\n\x0fota_status_list\x12#\n\x06things\x18\
+    \x01\x20\x03(\x0b2\x0b.ota_statusR\x06things\"B\n\tota_start\x12\x1b\n\t\
+    thing_ids\x18\x01\x20\x03(\tR\x08thingIds\x12\x18\n\x07version\x18\x02\
+    \x20\x01(\tR\x07version\"(\n\tota_query\x12\x1b\n\tthing_ids\x18\x01\x20\
+    \x03(\tR\x08thingIds\")\n\nota_cancel\x12\x1b\n\tthing_ids\x18\x01\x20\
+    \x03(\tR\x08thingIds*r\n\tota_state\x12\x0b\n\x07Pending\x10\0\x12\x10\n\
+    \x0cTransferring\x10\x01\x12\r\n\tVerifying\x10\x02\x12\r\n\tSucceeded\
+    \x10\x03\x12\n\n\x06Failed\x10\x04\x12\x0e\n\nRolledBack\x10\x05\x12\x0c\
+    \n\x08Canceled\x10\x06b\x06proto3\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(0);
+            let mut messages = ::std::vec::Vec::with_capacity(10);
+            messages.push(Firmware_info::generated_message_descriptor_data());
+            messages.push(Firmware_info_list::generated_message_descriptor_data());
+            messages.push(Firmware_add::generated_message_descriptor_data());
+            messages.push(Firmware_query::generated_message_descriptor_data());
+            messages.push(Firmware_query_all::generated_message_descriptor_data());
+            messages.push(Ota_status::generated_message_descriptor_data());
+            messages.push(Ota_status_list::generated_message_descriptor_data());
+            messages.push(Ota_start::generated_message_descriptor_data());
+            messages.push(Ota_query::generated_message_descriptor_data());
+            messages.push(Ota_cancel::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(1);
+            enums.push(Ota_state::generated_enum_descriptor_data());
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
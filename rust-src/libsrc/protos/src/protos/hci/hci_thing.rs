@@ -37,6 +37,10 @@ pub struct Hci_thing {
     pub status: ::protobuf::EnumOrUnknown<hci_thing::Hci_thing_status>,
     // @@protoc_insertion_point(field:hci_thing.data)
     pub data: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    // @@protoc_insertion_point(field:hci_thing.last_seen)
+    pub last_seen: u64,
+    // @@protoc_insertion_point(field:hci_thing.availabilities)
+    pub availabilities: ::std::vec::Vec<Hci_thing_availability>,
     // special fields
     // @@protoc_insertion_point(special_field:hci_thing.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -145,8 +149,48 @@ impl Hci_thing {
         ::std::mem::replace(&mut self.data, ::std::collections::HashMap::new())
     }
 
+    // uint64 last_seen = 5;
+
+    pub fn last_seen(&self) -> u64 {
+        self.last_seen
+    }
+
+    pub fn clear_last_seen(&mut self) {
+        self.last_seen = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_seen(&mut self, v: u64) {
+        self.last_seen = v;
+    }
+
+    // repeated .hci_thing_availability availabilities = 6;
+
+    pub fn availabilities(&self) -> &[Hci_thing_availability] {
+        &self.availabilities
+    }
+
+    pub fn clear_availabilities(&mut self) {
+        self.availabilities.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_availabilities(&mut self, v: ::std::vec::Vec<Hci_thing_availability>) {
+        self.availabilities = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_availabilities(&mut self) -> &mut ::std::vec::Vec<Hci_thing_availability> {
+        &mut self.availabilities
+    }
+
+    // Take field
+    pub fn take_availabilities(&mut self) -> ::std::vec::Vec<Hci_thing_availability> {
+        ::std::mem::replace(&mut self.availabilities, ::std::vec::Vec::new())
+    }
+
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut fields = ::std::vec::Vec::with_capacity(6);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "thing_id",
@@ -168,6 +212,16 @@ impl Hci_thing {
             |m: &Hci_thing| { &m.data },
             |m: &mut Hci_thing| { &mut m.data },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "last_seen",
+            |m: &Hci_thing| { &m.last_seen },
+            |m: &mut Hci_thing| { &mut m.last_seen },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "availabilities",
+            |m: &Hci_thing| { &m.availabilities },
+            |m: &mut Hci_thing| { &mut m.availabilities },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Hci_thing>(
             "hci_thing",
             fields,
@@ -210,6 +264,12 @@ impl ::protobuf::Message for Hci_thing {
                     is.pop_limit(old_limit);
                     self.data.insert(key, value);
                 },
+                40 => {
+                    self.last_seen = is.read_uint64()?;
+                },
+                50 => {
+                    self.availabilities.push(is.read_message()?);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -237,6 +297,13 @@ impl ::protobuf::Message for Hci_thing {
             entry_size += ::protobuf::rt::string_size(2, &v);
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
         };
+        if self.last_seen != 0 {
+            my_size += ::protobuf::rt::uint64_size(5, self.last_seen);
+        }
+        for value in &self.availabilities {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -261,6 +328,12 @@ impl ::protobuf::Message for Hci_thing {
             os.write_string(1, &k)?;
             os.write_string(2, &v)?;
         };
+        if self.last_seen != 0 {
+            os.write_uint64(5, self.last_seen)?;
+        }
+        for v in &self.availabilities {
+            ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -282,6 +355,8 @@ impl ::protobuf::Message for Hci_thing {
         self.mac_address.clear();
         self.status = ::protobuf::EnumOrUnknown::new(hci_thing::Hci_thing_status::NotFound);
         self.data.clear();
+        self.last_seen = 0;
+        self.availabilities.clear();
         self.special_fields.clear();
     }
 
@@ -373,6 +448,176 @@ pub mod hci_thing {
     }
 }
 
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:hci_thing_availability)
+pub struct Hci_thing_availability {
+    // message fields
+    // @@protoc_insertion_point(field:hci_thing_availability.timestamp)
+    pub timestamp: u64,
+    // @@protoc_insertion_point(field:hci_thing_availability.status)
+    pub status: ::protobuf::EnumOrUnknown<hci_thing::Hci_thing_status>,
+    // special fields
+    // @@protoc_insertion_point(special_field:hci_thing_availability.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Hci_thing_availability {
+    fn default() -> &'a Hci_thing_availability {
+        <Hci_thing_availability as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Hci_thing_availability {
+    pub fn new() -> Hci_thing_availability {
+        ::std::default::Default::default()
+    }
+
+    // uint64 timestamp = 1;
+
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    pub fn clear_timestamp(&mut self) {
+        self.timestamp = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_timestamp(&mut self, v: u64) {
+        self.timestamp = v;
+    }
+
+    // .hci_thing.hci_thing_status status = 2;
+
+    pub fn status(&self) -> hci_thing::Hci_thing_status {
+        self.status.enum_value_or_default()
+    }
+
+    pub fn clear_status(&mut self) {
+        self.status = ::protobuf::EnumOrUnknown::new(hci_thing::Hci_thing_status::NotFound);
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: hci_thing::Hci_thing_status) {
+        self.status = ::protobuf::EnumOrUnknown::new(v);
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "timestamp",
+            |m: &Hci_thing_availability| { &m.timestamp },
+            |m: &mut Hci_thing_availability| { &mut m.timestamp },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "status",
+            |m: &Hci_thing_availability| { &m.status },
+            |m: &mut Hci_thing_availability| { &mut m.status },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Hci_thing_availability>(
+            "hci_thing_availability",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Hci_thing_availability {
+    const NAME: &'static str = "hci_thing_availability";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.timestamp = is.read_uint64()?;
+                },
+                16 => {
+                    self.status = is.read_enum_or_unknown()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.timestamp != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.timestamp);
+        }
+        if self.status != ::protobuf::EnumOrUnknown::new(hci_thing::Hci_thing_status::NotFound) {
+            my_size += ::protobuf::rt::int32_size(2, self.status.value());
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.timestamp != 0 {
+            os.write_uint64(1, self.timestamp)?;
+        }
+        if self.status != ::protobuf::EnumOrUnknown::new(hci_thing::Hci_thing_status::NotFound) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.status))?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Hci_thing_availability {
+        Hci_thing_availability::new()
+    }
+
+    fn clear(&mut self) {
+        self.timestamp = 0;
+        self.status = ::protobuf::EnumOrUnknown::new(hci_thing::Hci_thing_status::NotFound);
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Hci_thing_availability {
+        static instance: Hci_thing_availability = Hci_thing_availability {
+            timestamp: 0,
+            status: ::protobuf::EnumOrUnknown::from_i32(0),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Hci_thing_availability {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("hci_thing_availability").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Hci_thing_availability {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Hci_thing_availability {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 #[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:hci_thing_list)
 pub struct Hci_thing_list {
@@ -535,6 +780,8 @@ pub struct Hci_add_thing {
     pub thing_name: ::std::string::String,
     // @@protoc_insertion_point(field:hci_add_thing.thing)
     pub thing: ::protobuf::MessageField<Hci_thing>,
+    // @@protoc_insertion_point(field:hci_add_thing.owner_override)
+    pub owner_override: bool,
     // special fields
     // @@protoc_insertion_point(special_field:hci_add_thing.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -685,8 +932,23 @@ impl Hci_add_thing {
         self.thing.take().unwrap_or_else(|| Hci_thing::new())
     }
 
+    // bool owner_override = 6;
+
+    pub fn owner_override(&self) -> bool {
+        self.owner_override
+    }
+
+    pub fn clear_owner_override(&mut self) {
+        self.owner_override = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_override(&mut self, v: bool) {
+        self.owner_override = v;
+    }
+
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut fields = ::std::vec::Vec::with_capacity(6);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "brand_id",
@@ -713,6 +975,11 @@ impl Hci_add_thing {
             |m: &Hci_add_thing| { &m.thing },
             |m: &mut Hci_add_thing| { &mut m.thing },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "owner_override",
+            |m: &Hci_add_thing| { &m.owner_override },
+            |m: &mut Hci_add_thing| { &mut m.owner_override },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Hci_add_thing>(
             "hci_add_thing",
             fields,
@@ -746,6 +1013,9 @@ impl ::protobuf::Message for Hci_add_thing {
                 42 => {
                     ::protobuf::rt::read_singular_message_into_field(is, &mut self.thing)?;
                 },
+                48 => {
+                    self.owner_override = is.read_bool()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -774,6 +1044,9 @@ impl ::protobuf::Message for Hci_add_thing {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
+        if self.owner_override != false {
+            my_size += 1 + 1;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -795,6 +1068,9 @@ impl ::protobuf::Message for Hci_add_thing {
         if let Some(v) = self.thing.as_ref() {
             ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
         }
+        if self.owner_override != false {
+            os.write_bool(6, self.owner_override)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -817,6 +1093,7 @@ impl ::protobuf::Message for Hci_add_thing {
         self.minor_product_id.clear();
         self.thing_name.clear();
         self.thing.clear();
+        self.owner_override = false;
         self.special_fields.clear();
     }
 
@@ -827,6 +1104,7 @@ impl ::protobuf::Message for Hci_add_thing {
             minor_product_id: ::std::string::String::new(),
             thing_name: ::std::string::String::new(),
             thing: ::protobuf::MessageField::none(),
+            owner_override: false,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -1544,33 +1822,38 @@ impl ::protobuf::reflect::ProtobufValue for Hci_task_result {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0fhci_thing.proto\x1a\x0bbrand.proto\"\xa8\x02\n\thci_thing\x12\x19\
+    \n\x0fhci_thing.proto\x1a\x0bbrand.proto\"\x86\x03\n\thci_thing\x12\x19\
     \n\x08thing_id\x18\x01\x20\x01(\tR\x07thingId\x12\x1f\n\x0bmac_address\
     \x18\x02\x20\x01(\tR\nmacAddress\x123\n\x06status\x18\x03\x20\x01(\x0e2\
     \x1b.hci_thing.hci_thing_statusR\x06status\x12(\n\x04data\x18\x04\x20\
-    \x03(\x0b2\x14.hci_thing.DataEntryR\x04data\x1a7\n\tDataEntry\x12\x10\n\
-    \x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\t\
-    R\x05value:\x028\x01\"G\n\x10hci_thing_status\x12\x0c\n\x08NotFound\x10\
-    \0\x12\x0c\n\x08Disabled\x10\x01\x12\n\n\x06Online\x10\x02\x12\x0b\n\x07\
-    Offline\x10\x03\"0\n\x0ehci_thing_list\x12\x1e\n\x04list\x18\x01\x20\x03\
-    (\x0b2\n.hci_thingR\x04list\"\xbf\x01\n\rhci_add_thing\x12\x19\n\x08bran\
-    d_id\x18\x01\x20\x01(\tR\x07brandId\x12(\n\x10major_product_id\x18\x02\
-    \x20\x01(\tR\x0emajorProductId\x12(\n\x10minor_product_id\x18\x03\x20\
-    \x01(\tR\x0eminorProductId\x12\x1d\n\nthing_name\x18\x04\x20\x01(\tR\tth\
-    ingName\x12\x20\n\x05thing\x18\x05\x20\x01(\x0b2\n.hci_thingR\x05thing\"\
-    \x93\x01\n\x0ehci_ctrl_thing\x12\x19\n\x08thing_id\x18\x02\x20\x01(\tR\
-    \x07thingId\x12-\n\x04data\x18\x03\x20\x03(\x0b2\x19.hci_ctrl_thing.Data\
-    EntryR\x04data\x1a7\n\tDataEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\
-    \x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\x01\"\x87\
-    \x02\n\x0ehci_crud_thing\x12\x19\n\x08thing_id\x18\x01\x20\x01(\tR\x07th\
-    ingId\x122\n\x06method\x18\x02\x20\x01(\x0e2\x1a.hci_crud_thing.hci_crud\
-    _mR\x06method\x12-\n\x04data\x18\x03\x20\x03(\x0b2\x19.hci_crud_thing.Da\
-    taEntryR\x04data\x1a7\n\tDataEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\
-    \x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\x01\">\n\n\
-    hci_crud_m\x12\n\n\x06remove\x10\0\x12\x08\n\x04pair\x10\x01\x12\x0f\n\
-    \x0bremove_pair\x10\x02\x12\t\n\x05query\x10\x03\"G\n\x0fhci_task_result\
-    \x12\x17\n\x07task_id\x18\x01\x20\x01(\rR\x06taskId\x12\x1b\n\tthing_ids\
-    \x18\x02\x20\x03(\tR\x08thingIdsb\x06proto3\
+    \x03(\x0b2\x14.hci_thing.DataEntryR\x04data\x12\x1b\n\tlast_seen\x18\x05\
+    \x20\x01(\x04R\x08lastSeen\x12?\n\x0eavailabilities\x18\x06\x20\x03(\x0b\
+    2\x17.hci_thing_availabilityR\x0eavailabilities\x1a7\n\tDataEntry\x12\
+    \x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\
+    \x01(\tR\x05value:\x028\x01\"G\n\x10hci_thing_status\x12\x0c\n\x08NotFou\
+    nd\x10\0\x12\x0c\n\x08Disabled\x10\x01\x12\n\n\x06Online\x10\x02\x12\x0b\
+    \n\x07Offline\x10\x03\"k\n\x16hci_thing_availability\x12\x1c\n\ttimestam\
+    p\x18\x01\x20\x01(\x04R\ttimestamp\x123\n\x06status\x18\x02\x20\x01(\x0e\
+    2\x1b.hci_thing.hci_thing_statusR\x06status\"0\n\x0ehci_thing_list\x12\
+    \x1e\n\x04list\x18\x01\x20\x03(\x0b2\n.hci_thingR\x04list\"\xe6\x01\n\rh\
+    ci_add_thing\x12\x19\n\x08brand_id\x18\x01\x20\x01(\tR\x07brandId\x12(\n\
+    \x10major_product_id\x18\x02\x20\x01(\tR\x0emajorProductId\x12(\n\x10min\
+    or_product_id\x18\x03\x20\x01(\tR\x0eminorProductId\x12\x1d\n\nthing_nam\
+    e\x18\x04\x20\x01(\tR\tthingName\x12\x20\n\x05thing\x18\x05\x20\x01(\x0b\
+    2\n.hci_thingR\x05thing\x12%\n\x0eowner_override\x18\x06\x20\x01(\x08R\r\
+    ownerOverride\"\x93\x01\n\x0ehci_ctrl_thing\x12\x19\n\x08thing_id\x18\
+    \x02\x20\x01(\tR\x07thingId\x12-\n\x04data\x18\x03\x20\x03(\x0b2\x19.hci\
+    _ctrl_thing.DataEntryR\x04data\x1a7\n\tDataEntry\x12\x10\n\x03key\x18\
+    \x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\
+    \x028\x01\"\x87\x02\n\x0ehci_crud_thing\x12\x19\n\x08thing_id\x18\x01\
+    \x20\x01(\tR\x07thingId\x122\n\x06method\x18\x02\x20\x01(\x0e2\x1a.hci_c\
+    rud_thing.hci_crud_mR\x06method\x12-\n\x04data\x18\x03\x20\x03(\x0b2\x19\
+    .hci_crud_thing.DataEntryR\x04data\x1a7\n\tDataEntry\x12\x10\n\x03key\
+    \x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05va\
+    lue:\x028\x01\">\n\nhci_crud_m\x12\n\n\x06remove\x10\0\x12\x08\n\x04pair\
+    \x10\x01\x12\x0f\n\x0bremove_pair\x10\x02\x12\t\n\x05query\x10\x03\"G\n\
+    \x0fhci_task_result\x12\x17\n\x07task_id\x18\x01\x20\x01(\rR\x06taskId\
+    \x12\x1b\n\tthing_ids\x18\x02\x20\x03(\tR\x08thingIdsb\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -1589,8 +1872,9 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(1);
             deps.push(super::brand::file_descriptor().clone());
-            let mut messages = ::std::vec::Vec::with_capacity(6);
+            let mut messages = ::std::vec::Vec::with_capacity(7);
             messages.push(Hci_thing::generated_message_descriptor_data());
+            messages.push(Hci_thing_availability::generated_message_descriptor_data());
             messages.push(Hci_thing_list::generated_message_descriptor_data());
             messages.push(Hci_add_thing::generated_message_descriptor_data());
             messages.push(Hci_ctrl_thing::generated_message_descriptor_data());
@@ -0,0 +1,1542 @@
+// This file is generated by rust-protobuf 3.2.0. Do not edit
+// .proto file is parsed by pure
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `location.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_2_0;
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:location_info)
+pub struct Location_info {
+    // message fields
+    // @@protoc_insertion_point(field:location_info.location_id)
+    pub location_id: ::std::string::String,
+    // @@protoc_insertion_point(field:location_info.parent_id)
+    pub parent_id: ::std::string::String,
+    // @@protoc_insertion_point(field:location_info.type)
+    pub type_: ::protobuf::EnumOrUnknown<Location_type>,
+    // @@protoc_insertion_point(field:location_info.location_name)
+    pub location_name: ::std::string::String,
+    // @@protoc_insertion_point(field:location_info.location_img_idx)
+    pub location_img_idx: u32,
+    // @@protoc_insertion_point(field:location_info.thing_ids)
+    pub thing_ids: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:location_info.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Location_info {
+    fn default() -> &'a Location_info {
+        <Location_info as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Location_info {
+    pub fn new() -> Location_info {
+        ::std::default::Default::default()
+    }
+
+    // string location_id = 1;
+
+    pub fn location_id(&self) -> &str {
+        &self.location_id
+    }
+
+    pub fn clear_location_id(&mut self) {
+        self.location_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_location_id(&mut self, v: ::std::string::String) {
+        self.location_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_location_id(&mut self) -> &mut ::std::string::String {
+        &mut self.location_id
+    }
+
+    // Take field
+    pub fn take_location_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.location_id, ::std::string::String::new())
+    }
+
+    // string parent_id = 2;
+
+    pub fn parent_id(&self) -> &str {
+        &self.parent_id
+    }
+
+    pub fn clear_parent_id(&mut self) {
+        self.parent_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_parent_id(&mut self, v: ::std::string::String) {
+        self.parent_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_parent_id(&mut self) -> &mut ::std::string::String {
+        &mut self.parent_id
+    }
+
+    // Take field
+    pub fn take_parent_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.parent_id, ::std::string::String::new())
+    }
+
+    // .location_type type = 3;
+
+    pub fn type_(&self) -> Location_type {
+        self.type_.enum_value_or_default()
+    }
+
+    pub fn clear_type_(&mut self) {
+        self.type_ = ::protobuf::EnumOrUnknown::new(Location_type::Home);
+    }
+
+    // Param is passed by value, moved
+    pub fn set_type(&mut self, v: Location_type) {
+        self.type_ = ::protobuf::EnumOrUnknown::new(v);
+    }
+
+    // string location_name = 4;
+
+    pub fn location_name(&self) -> &str {
+        &self.location_name
+    }
+
+    pub fn clear_location_name(&mut self) {
+        self.location_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_location_name(&mut self, v: ::std::string::String) {
+        self.location_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_location_name(&mut self) -> &mut ::std::string::String {
+        &mut self.location_name
+    }
+
+    // Take field
+    pub fn take_location_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.location_name, ::std::string::String::new())
+    }
+
+    // uint32 location_img_idx = 5;
+
+    pub fn location_img_idx(&self) -> u32 {
+        self.location_img_idx
+    }
+
+    pub fn clear_location_img_idx(&mut self) {
+        self.location_img_idx = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_location_img_idx(&mut self, v: u32) {
+        self.location_img_idx = v;
+    }
+
+    // repeated string thing_ids = 6;
+
+    pub fn thing_ids(&self) -> &[::std::string::String] {
+        &self.thing_ids
+    }
+
+    pub fn clear_thing_ids(&mut self) {
+        self.thing_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_thing_ids(&mut self, v: ::std::vec::Vec<::std::string::String>) {
+        self.thing_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_thing_ids(&mut self) -> &mut ::std::vec::Vec<::std::string::String> {
+        &mut self.thing_ids
+    }
+
+    // Take field
+    pub fn take_thing_ids(&mut self) -> ::std::vec::Vec<::std::string::String> {
+        ::std::mem::replace(&mut self.thing_ids, ::std::vec::Vec::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "location_id",
+            |m: &Location_info| { &m.location_id },
+            |m: &mut Location_info| { &mut m.location_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "parent_id",
+            |m: &Location_info| { &m.parent_id },
+            |m: &mut Location_info| { &mut m.parent_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "type",
+            |m: &Location_info| { &m.type_ },
+            |m: &mut Location_info| { &mut m.type_ },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "location_name",
+            |m: &Location_info| { &m.location_name },
+            |m: &mut Location_info| { &mut m.location_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "location_img_idx",
+            |m: &Location_info| { &m.location_img_idx },
+            |m: &mut Location_info| { &mut m.location_img_idx },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "thing_ids",
+            |m: &Location_info| { &m.thing_ids },
+            |m: &mut Location_info| { &mut m.thing_ids },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Location_info>(
+            "location_info",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Location_info {
+    const NAME: &'static str = "location_info";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.location_id = is.read_string()?;
+                },
+                18 => {
+                    self.parent_id = is.read_string()?;
+                },
+                24 => {
+                    self.type_ = is.read_enum_or_unknown()?;
+                },
+                34 => {
+                    self.location_name = is.read_string()?;
+                },
+                40 => {
+                    self.location_img_idx = is.read_uint32()?;
+                },
+                50 => {
+                    self.thing_ids.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.location_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.location_id);
+        }
+        if !self.parent_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.parent_id);
+        }
+        if self.type_ != ::protobuf::EnumOrUnknown::new(Location_type::Home) {
+            my_size += ::protobuf::rt::int32_size(3, self.type_.value());
+        }
+        if !self.location_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.location_name);
+        }
+        if self.location_img_idx != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.location_img_idx);
+        }
+        for value in &self.thing_ids {
+            my_size += ::protobuf::rt::string_size(6, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.location_id.is_empty() {
+            os.write_string(1, &self.location_id)?;
+        }
+        if !self.parent_id.is_empty() {
+            os.write_string(2, &self.parent_id)?;
+        }
+        if self.type_ != ::protobuf::EnumOrUnknown::new(Location_type::Home) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.type_))?;
+        }
+        if !self.location_name.is_empty() {
+            os.write_string(4, &self.location_name)?;
+        }
+        if self.location_img_idx != 0 {
+            os.write_uint32(5, self.location_img_idx)?;
+        }
+        for v in &self.thing_ids {
+            os.write_string(6, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Location_info {
+        Location_info::new()
+    }
+
+    fn clear(&mut self) {
+        self.location_id.clear();
+        self.parent_id.clear();
+        self.type_ = ::protobuf::EnumOrUnknown::new(Location_type::Home);
+        self.location_name.clear();
+        self.location_img_idx = 0;
+        self.thing_ids.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Location_info {
+        static instance: Location_info = Location_info {
+            location_id: ::std::string::String::new(),
+            parent_id: ::std::string::String::new(),
+            type_: ::protobuf::EnumOrUnknown::from_i32(0),
+            location_name: ::std::string::String::new(),
+            location_img_idx: 0,
+            thing_ids: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Location_info {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("location_info").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Location_info {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Location_info {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:location_info_list)
+pub struct Location_info_list {
+    // message fields
+    // @@protoc_insertion_point(field:location_info_list.locations)
+    pub locations: ::std::vec::Vec<Location_info>,
+    // special fields
+    // @@protoc_insertion_point(special_field:location_info_list.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Location_info_list {
+    fn default() -> &'a Location_info_list {
+        <Location_info_list as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Location_info_list {
+    pub fn new() -> Location_info_list {
+        ::std::default::Default::default()
+    }
+
+    // repeated .location_info locations = 1;
+
+    pub fn locations(&self) -> &[Location_info] {
+        &self.locations
+    }
+
+    pub fn clear_locations(&mut self) {
+        self.locations.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_locations(&mut self, v: ::std::vec::Vec<Location_info>) {
+        self.locations = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_locations(&mut self) -> &mut ::std::vec::Vec<Location_info> {
+        &mut self.locations
+    }
+
+    // Take field
+    pub fn take_locations(&mut self) -> ::std::vec::Vec<Location_info> {
+        ::std::mem::replace(&mut self.locations, ::std::vec::Vec::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "locations",
+            |m: &Location_info_list| { &m.locations },
+            |m: &mut Location_info_list| { &mut m.locations },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Location_info_list>(
+            "location_info_list",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Location_info_list {
+    const NAME: &'static str = "location_info_list";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.locations.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.locations {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.locations {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Location_info_list {
+        Location_info_list::new()
+    }
+
+    fn clear(&mut self) {
+        self.locations.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Location_info_list {
+        static instance: Location_info_list = Location_info_list {
+            locations: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Location_info_list {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("location_info_list").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Location_info_list {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Location_info_list {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:location_add)
+pub struct Location_add {
+    // message fields
+    // @@protoc_insertion_point(field:location_add.parent_id)
+    pub parent_id: ::std::string::String,
+    // @@protoc_insertion_point(field:location_add.type)
+    pub type_: ::protobuf::EnumOrUnknown<Location_type>,
+    // @@protoc_insertion_point(field:location_add.location_name)
+    pub location_name: ::std::string::String,
+    // @@protoc_insertion_point(field:location_add.location_img_idx)
+    pub location_img_idx: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:location_add.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Location_add {
+    fn default() -> &'a Location_add {
+        <Location_add as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Location_add {
+    pub fn new() -> Location_add {
+        ::std::default::Default::default()
+    }
+
+    // string parent_id = 1;
+
+    pub fn parent_id(&self) -> &str {
+        &self.parent_id
+    }
+
+    pub fn clear_parent_id(&mut self) {
+        self.parent_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_parent_id(&mut self, v: ::std::string::String) {
+        self.parent_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_parent_id(&mut self) -> &mut ::std::string::String {
+        &mut self.parent_id
+    }
+
+    // Take field
+    pub fn take_parent_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.parent_id, ::std::string::String::new())
+    }
+
+    // .location_type type = 2;
+
+    pub fn type_(&self) -> Location_type {
+        self.type_.enum_value_or_default()
+    }
+
+    pub fn clear_type_(&mut self) {
+        self.type_ = ::protobuf::EnumOrUnknown::new(Location_type::Home);
+    }
+
+    // Param is passed by value, moved
+    pub fn set_type(&mut self, v: Location_type) {
+        self.type_ = ::protobuf::EnumOrUnknown::new(v);
+    }
+
+    // string location_name = 3;
+
+    pub fn location_name(&self) -> &str {
+        &self.location_name
+    }
+
+    pub fn clear_location_name(&mut self) {
+        self.location_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_location_name(&mut self, v: ::std::string::String) {
+        self.location_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_location_name(&mut self) -> &mut ::std::string::String {
+        &mut self.location_name
+    }
+
+    // Take field
+    pub fn take_location_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.location_name, ::std::string::String::new())
+    }
+
+    // uint32 location_img_idx = 4;
+
+    pub fn location_img_idx(&self) -> u32 {
+        self.location_img_idx
+    }
+
+    pub fn clear_location_img_idx(&mut self) {
+        self.location_img_idx = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_location_img_idx(&mut self, v: u32) {
+        self.location_img_idx = v;
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "parent_id",
+            |m: &Location_add| { &m.parent_id },
+            |m: &mut Location_add| { &mut m.parent_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "type",
+            |m: &Location_add| { &m.type_ },
+            |m: &mut Location_add| { &mut m.type_ },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "location_name",
+            |m: &Location_add| { &m.location_name },
+            |m: &mut Location_add| { &mut m.location_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "location_img_idx",
+            |m: &Location_add| { &m.location_img_idx },
+            |m: &mut Location_add| { &mut m.location_img_idx },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Location_add>(
+            "location_add",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Location_add {
+    const NAME: &'static str = "location_add";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.parent_id = is.read_string()?;
+                },
+                16 => {
+                    self.type_ = is.read_enum_or_unknown()?;
+                },
+                26 => {
+                    self.location_name = is.read_string()?;
+                },
+                32 => {
+                    self.location_img_idx = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.parent_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.parent_id);
+        }
+        if self.type_ != ::protobuf::EnumOrUnknown::new(Location_type::Home) {
+            my_size += ::protobuf::rt::int32_size(2, self.type_.value());
+        }
+        if !self.location_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.location_name);
+        }
+        if self.location_img_idx != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.location_img_idx);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.parent_id.is_empty() {
+            os.write_string(1, &self.parent_id)?;
+        }
+        if self.type_ != ::protobuf::EnumOrUnknown::new(Location_type::Home) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.type_))?;
+        }
+        if !self.location_name.is_empty() {
+            os.write_string(3, &self.location_name)?;
+        }
+        if self.location_img_idx != 0 {
+            os.write_uint32(4, self.location_img_idx)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Location_add {
+        Location_add::new()
+    }
+
+    fn clear(&mut self) {
+        self.parent_id.clear();
+        self.type_ = ::protobuf::EnumOrUnknown::new(Location_type::Home);
+        self.location_name.clear();
+        self.location_img_idx = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Location_add {
+        static instance: Location_add = Location_add {
+            parent_id: ::std::string::String::new(),
+            type_: ::protobuf::EnumOrUnknown::from_i32(0),
+            location_name: ::std::string::String::new(),
+            location_img_idx: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Location_add {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("location_add").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Location_add {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Location_add {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:location_query_all)
+pub struct Location_query_all {
+    // message fields
+    // @@protoc_insertion_point(field:location_query_all.parent_id)
+    pub parent_id: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:location_query_all.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Location_query_all {
+    fn default() -> &'a Location_query_all {
+        <Location_query_all as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Location_query_all {
+    pub fn new() -> Location_query_all {
+        ::std::default::Default::default()
+    }
+
+    // string parent_id = 1;
+
+    pub fn parent_id(&self) -> &str {
+        &self.parent_id
+    }
+
+    pub fn clear_parent_id(&mut self) {
+        self.parent_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_parent_id(&mut self, v: ::std::string::String) {
+        self.parent_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_parent_id(&mut self) -> &mut ::std::string::String {
+        &mut self.parent_id
+    }
+
+    // Take field
+    pub fn take_parent_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.parent_id, ::std::string::String::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "parent_id",
+            |m: &Location_query_all| { &m.parent_id },
+            |m: &mut Location_query_all| { &mut m.parent_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Location_query_all>(
+            "location_query_all",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Location_query_all {
+    const NAME: &'static str = "location_query_all";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.parent_id = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.parent_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.parent_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.parent_id.is_empty() {
+            os.write_string(1, &self.parent_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Location_query_all {
+        Location_query_all::new()
+    }
+
+    fn clear(&mut self) {
+        self.parent_id.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Location_query_all {
+        static instance: Location_query_all = Location_query_all {
+            parent_id: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Location_query_all {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("location_query_all").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Location_query_all {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Location_query_all {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:location_thing_update)
+pub struct Location_thing_update {
+    // message fields
+    // @@protoc_insertion_point(field:location_thing_update.location_id)
+    pub location_id: ::std::string::String,
+    // @@protoc_insertion_point(field:location_thing_update.op)
+    pub op: ::protobuf::EnumOrUnknown<location_thing_update::Location_thing_op>,
+    // @@protoc_insertion_point(field:location_thing_update.thing_ids)
+    pub thing_ids: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:location_thing_update.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Location_thing_update {
+    fn default() -> &'a Location_thing_update {
+        <Location_thing_update as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Location_thing_update {
+    pub fn new() -> Location_thing_update {
+        ::std::default::Default::default()
+    }
+
+    // string location_id = 1;
+
+    pub fn location_id(&self) -> &str {
+        &self.location_id
+    }
+
+    pub fn clear_location_id(&mut self) {
+        self.location_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_location_id(&mut self, v: ::std::string::String) {
+        self.location_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_location_id(&mut self) -> &mut ::std::string::String {
+        &mut self.location_id
+    }
+
+    // Take field
+    pub fn take_location_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.location_id, ::std::string::String::new())
+    }
+
+    // .location_thing_update.location_thing_op op = 2;
+
+    pub fn op(&self) -> location_thing_update::Location_thing_op {
+        self.op.enum_value_or_default()
+    }
+
+    pub fn clear_op(&mut self) {
+        self.op = ::protobuf::EnumOrUnknown::new(location_thing_update::Location_thing_op::none);
+    }
+
+    // Param is passed by value, moved
+    pub fn set_op(&mut self, v: location_thing_update::Location_thing_op) {
+        self.op = ::protobuf::EnumOrUnknown::new(v);
+    }
+
+    // repeated string thing_ids = 3;
+
+    pub fn thing_ids(&self) -> &[::std::string::String] {
+        &self.thing_ids
+    }
+
+    pub fn clear_thing_ids(&mut self) {
+        self.thing_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_thing_ids(&mut self, v: ::std::vec::Vec<::std::string::String>) {
+        self.thing_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_thing_ids(&mut self) -> &mut ::std::vec::Vec<::std::string::String> {
+        &mut self.thing_ids
+    }
+
+    // Take field
+    pub fn take_thing_ids(&mut self) -> ::std::vec::Vec<::std::string::String> {
+        ::std::mem::replace(&mut self.thing_ids, ::std::vec::Vec::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "location_id",
+            |m: &Location_thing_update| { &m.location_id },
+            |m: &mut Location_thing_update| { &mut m.location_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "op",
+            |m: &Location_thing_update| { &m.op },
+            |m: &mut Location_thing_update| { &mut m.op },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "thing_ids",
+            |m: &Location_thing_update| { &m.thing_ids },
+            |m: &mut Location_thing_update| { &mut m.thing_ids },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Location_thing_update>(
+            "location_thing_update",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Location_thing_update {
+    const NAME: &'static str = "location_thing_update";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.location_id = is.read_string()?;
+                },
+                16 => {
+                    self.op = is.read_enum_or_unknown()?;
+                },
+                26 => {
+                    self.thing_ids.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.location_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.location_id);
+        }
+        if self.op != ::protobuf::EnumOrUnknown::new(location_thing_update::Location_thing_op::none) {
+            my_size += ::protobuf::rt::int32_size(2, self.op.value());
+        }
+        for value in &self.thing_ids {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.location_id.is_empty() {
+            os.write_string(1, &self.location_id)?;
+        }
+        if self.op != ::protobuf::EnumOrUnknown::new(location_thing_update::Location_thing_op::none) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.op))?;
+        }
+        for v in &self.thing_ids {
+            os.write_string(3, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Location_thing_update {
+        Location_thing_update::new()
+    }
+
+    fn clear(&mut self) {
+        self.location_id.clear();
+        self.op = ::protobuf::EnumOrUnknown::new(location_thing_update::Location_thing_op::none);
+        self.thing_ids.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Location_thing_update {
+        static instance: Location_thing_update = Location_thing_update {
+            location_id: ::std::string::String::new(),
+            op: ::protobuf::EnumOrUnknown::from_i32(0),
+            thing_ids: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Location_thing_update {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("location_thing_update").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Location_thing_update {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Location_thing_update {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `location_thing_update`
+pub mod location_thing_update {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:location_thing_update.location_thing_op)
+    pub enum Location_thing_op {
+        // @@protoc_insertion_point(enum_value:location_thing_update.location_thing_op.none)
+        none = 0,
+        // @@protoc_insertion_point(enum_value:location_thing_update.location_thing_op.insert)
+        insert = 1,
+        // @@protoc_insertion_point(enum_value:location_thing_update.location_thing_op.remove)
+        remove = 2,
+    }
+
+    impl ::protobuf::Enum for Location_thing_op {
+        const NAME: &'static str = "location_thing_op";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Location_thing_op> {
+            match value {
+                0 => ::std::option::Option::Some(Location_thing_op::none),
+                1 => ::std::option::Option::Some(Location_thing_op::insert),
+                2 => ::std::option::Option::Some(Location_thing_op::remove),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Location_thing_op] = &[
+            Location_thing_op::none,
+            Location_thing_op::insert,
+            Location_thing_op::remove,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Location_thing_op {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("location_thing_update.location_thing_op").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Location_thing_op {
+        fn default() -> Self {
+            Location_thing_op::none
+        }
+    }
+
+    impl Location_thing_op {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Location_thing_op>("location_thing_update.location_thing_op")
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:location_ctrl)
+pub struct Location_ctrl {
+    // message fields
+    // @@protoc_insertion_point(field:location_ctrl.location_id)
+    pub location_id: ::std::string::String,
+    // @@protoc_insertion_point(field:location_ctrl.data)
+    pub data: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:location_ctrl.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Location_ctrl {
+    fn default() -> &'a Location_ctrl {
+        <Location_ctrl as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Location_ctrl {
+    pub fn new() -> Location_ctrl {
+        ::std::default::Default::default()
+    }
+
+    // string location_id = 1;
+
+    pub fn location_id(&self) -> &str {
+        &self.location_id
+    }
+
+    pub fn clear_location_id(&mut self) {
+        self.location_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_location_id(&mut self, v: ::std::string::String) {
+        self.location_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_location_id(&mut self) -> &mut ::std::string::String {
+        &mut self.location_id
+    }
+
+    // Take field
+    pub fn take_location_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.location_id, ::std::string::String::new())
+    }
+
+    // repeated .location_ctrl.DataEntry data = 2;
+
+    pub fn data(&self) -> &::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &self.data
+    }
+
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::collections::HashMap<::std::string::String, ::std::string::String>) {
+        self.data = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_data(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &mut self.data
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        ::std::mem::replace(&mut self.data, ::std::collections::HashMap::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "location_id",
+            |m: &Location_ctrl| { &m.location_id },
+            |m: &mut Location_ctrl| { &mut m.location_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+            "data",
+            |m: &Location_ctrl| { &m.data },
+            |m: &mut Location_ctrl| { &mut m.data },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Location_ctrl>(
+            "location_ctrl",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Location_ctrl {
+    const NAME: &'static str = "location_ctrl";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.location_id = is.read_string()?;
+                },
+                18 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_string()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.data.insert(key, value);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.location_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.location_id);
+        }
+        for (k, v) in &self.data {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.location_id.is_empty() {
+            os.write_string(1, &self.location_id)?;
+        }
+        for (k, v) in &self.data {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            os.write_raw_varint32(18)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Location_ctrl {
+        Location_ctrl::new()
+    }
+
+    fn clear(&mut self) {
+        self.location_id.clear();
+        self.data.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Location_ctrl {
+        static instance: ::protobuf::rt::Lazy<Location_ctrl> = ::protobuf::rt::Lazy::new();
+        instance.get(Location_ctrl::new)
+    }
+}
+
+impl ::protobuf::MessageFull for Location_ctrl {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("location_ctrl").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Location_ctrl {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Location_ctrl {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:location_type)
+pub enum Location_type {
+    // @@protoc_insertion_point(enum_value:location_type.Home)
+    Home = 0,
+    // @@protoc_insertion_point(enum_value:location_type.Floor)
+    Floor = 1,
+    // @@protoc_insertion_point(enum_value:location_type.Room)
+    Room = 2,
+    // @@protoc_insertion_point(enum_value:location_type.Zone)
+    Zone = 3,
+}
+
+impl ::protobuf::Enum for Location_type {
+    const NAME: &'static str = "location_type";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Location_type> {
+        match value {
+            0 => ::std::option::Option::Some(Location_type::Home),
+            1 => ::std::option::Option::Some(Location_type::Floor),
+            2 => ::std::option::Option::Some(Location_type::Room),
+            3 => ::std::option::Option::Some(Location_type::Zone),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [Location_type] = &[
+        Location_type::Home,
+        Location_type::Floor,
+        Location_type::Room,
+        Location_type::Zone,
+    ];
+}
+
+impl ::protobuf::EnumFull for Location_type {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("location_type").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for Location_type {
+    fn default() -> Self {
+        Location_type::Home
+    }
+}
+
+impl Location_type {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Location_type>("location_type")
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0elocation.proto\"\xdd\x01\n\rlocation_info\x12\x1f\n\x0blocation_id\
+    \x18\x01\x20\x01(\tR\nlocationId\x12\x1b\n\tparent_id\x18\x02\x20\x01(\t\
+    R\x08parentId\x12\"\n\x04type\x18\x03\x20\x01(\x0e2\x0e.location_typeR\
+    \x04type\x12#\n\rlocation_name\x18\x04\x20\x01(\tR\x0clocationName\x12(\
+    \n\x10location_img_idx\x18\x05\x20\x01(\rR\x0elocationImgIdx\x12\x1b\n\t\
+    thing_ids\x18\x06\x20\x03(\tR\x08thingIds\"B\n\x12location_info_list\x12\
+    ,\n\tlocations\x18\x01\x20\x03(\x0b2\x0e.location_infoR\tlocations\"\x9e\
+    \x01\n\x0clocation_add\x12\x1b\n\tparent_id\x18\x01\x20\x01(\tR\x08paren\
+    tId\x12\"\n\x04type\x18\x02\x20\x01(\x0e2\x0e.location_typeR\x04type\x12\
+    #\n\rlocation_name\x18\x03\x20\x01(\tR\x0clocationName\x12(\n\x10locatio\
+    n_img_idx\x18\x04\x20\x01(\rR\x0elocationImgIdx\"1\n\x12location_query_a\
+    ll\x12\x1b\n\tparent_id\x18\x01\x20\x01(\tR\x08parentId\"\xc6\x01\n\x15l\
+    ocation_thing_update\x12\x1f\n\x0blocation_id\x18\x01\x20\x01(\tR\nlocat\
+    ionId\x128\n\x02op\x18\x02\x20\x01(\x0e2(.location_thing_update.location\
+    _thing_opR\x02op\x12\x1b\n\tthing_ids\x18\x03\x20\x03(\tR\x08thingIds\"5\
+    \n\x11location_thing_op\x12\x08\n\x04none\x10\0\x12\n\n\x06insert\x10\
+    \x01\x12\n\n\x06remove\x10\x02\"\x97\x01\n\rlocation_ctrl\x12\x1f\n\x0bl\
+    ocation_id\x18\x01\x20\x01(\tR\nlocationId\x12,\n\x04data\x18\x02\x20\
+    \x03(\x0b2\x18.location_ctrl.DataEntryR\x04data\x1a7\n\tDataEntry\x12\
+    \x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\
+    \x01(\tR\x05value:\x028\x01*8\n\rlocation_type\x12\x08\n\x04Home\x10\0\
+    \x12\t\n\x05Floor\x10\x01\x12\x08\n\x04Room\x10\x02\x12\x08\n\x04Zone\
+    \x10\x03b\x06proto3\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(0);
+            let mut messages = ::std::vec::Vec::with_capacity(6);
+            messages.push(Location_info::generated_message_descriptor_data());
+            messages.push(Location_info_list::generated_message_descriptor_data());
+            messages.push(Location_add::generated_message_descriptor_data());
+            messages.push(Location_query_all::generated_message_descriptor_data());
+            messages.push(Location_thing_update::generated_message_descriptor_data());
+            messages.push(Location_ctrl::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(2);
+            enums.push(Location_type::generated_enum_descriptor_data());
+            enums.push(location_thing_update::Location_thing_op::generated_enum_descriptor_data());
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
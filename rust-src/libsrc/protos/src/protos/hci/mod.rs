@@ -7,6 +7,7 @@ inner_impl_default_protobuf_raw_codec!(brand::Brand_info_list);
 inner_impl_default_protobuf_raw_codec!(brand::Brand_add);
 pub mod hci_thing;
 inner_impl_default_protobuf_raw_codec!(hci_thing::Hci_thing);
+inner_impl_default_protobuf_raw_codec!(hci_thing::Hci_thing_availability);
 inner_impl_default_protobuf_raw_codec!(hci_thing::Hci_thing_list);
 inner_impl_default_protobuf_raw_codec!(hci_thing::Hci_add_thing);
 inner_impl_default_protobuf_raw_codec!(hci_thing::Hci_ctrl_thing);
@@ -17,6 +18,11 @@ inner_impl_default_protobuf_raw_codec!(product::Product_info);
 inner_impl_default_protobuf_raw_codec!(product::Product_info_list);
 inner_impl_default_protobuf_raw_codec!(product::Product_add);
 inner_impl_default_protobuf_raw_codec!(product::Product_query_all);
+inner_impl_default_protobuf_raw_codec!(product::Product_property);
+inner_impl_default_protobuf_raw_codec!(product::Product_capability);
+inner_impl_default_protobuf_raw_codec!(product::Product_capability_update);
+inner_impl_default_protobuf_raw_codec!(product::Thing_capability_query);
+inner_impl_default_protobuf_raw_codec!(product::Thing_capability_list);
 pub mod schedule;
 inner_impl_default_protobuf_raw_codec!(schedule::Schedule_info);
 inner_impl_default_protobuf_raw_codec!(schedule::Schedule_cycle_time);
@@ -35,3 +41,34 @@ inner_impl_default_protobuf_raw_codec!(thing::Thing_info_list);
 inner_impl_default_protobuf_raw_codec!(thing::Thing_add);
 inner_impl_default_protobuf_raw_codec!(thing::Thing_query);
 inner_impl_default_protobuf_raw_codec!(thing::Thing_query_all);
+inner_impl_default_protobuf_raw_codec!(thing::Thing_pairing);
+inner_impl_default_protobuf_raw_codec!(thing::Thing_pairing_proof);
+inner_impl_default_protobuf_raw_codec!(thing::Thing_pairing_key);
+pub mod telemetry;
+inner_impl_default_protobuf_raw_codec!(telemetry::Thing_telemetry_sample);
+inner_impl_default_protobuf_raw_codec!(telemetry::Thing_telemetry_aggregate);
+inner_impl_default_protobuf_raw_codec!(telemetry::Thing_telemetry_aggregate_list);
+inner_impl_default_protobuf_raw_codec!(telemetry::Thing_telemetry_query);
+inner_impl_default_protobuf_raw_codec!(telemetry::Thing_telemetry_series);
+pub mod location;
+inner_impl_default_protobuf_raw_codec!(location::Location_info);
+inner_impl_default_protobuf_raw_codec!(location::Location_info_list);
+inner_impl_default_protobuf_raw_codec!(location::Location_add);
+inner_impl_default_protobuf_raw_codec!(location::Location_query_all);
+inner_impl_default_protobuf_raw_codec!(location::Location_thing_update);
+inner_impl_default_protobuf_raw_codec!(location::Location_ctrl);
+pub mod firmware;
+inner_impl_default_protobuf_raw_codec!(firmware::Firmware_info);
+inner_impl_default_protobuf_raw_codec!(firmware::Firmware_info_list);
+inner_impl_default_protobuf_raw_codec!(firmware::Firmware_add);
+inner_impl_default_protobuf_raw_codec!(firmware::Firmware_query);
+inner_impl_default_protobuf_raw_codec!(firmware::Firmware_query_all);
+inner_impl_default_protobuf_raw_codec!(firmware::Ota_status);
+inner_impl_default_protobuf_raw_codec!(firmware::Ota_status_list);
+inner_impl_default_protobuf_raw_codec!(firmware::Ota_start);
+inner_impl_default_protobuf_raw_codec!(firmware::Ota_query);
+inner_impl_default_protobuf_raw_codec!(firmware::Ota_cancel);
+pub mod audit;
+inner_impl_default_protobuf_raw_codec!(audit::Audit_record);
+inner_impl_default_protobuf_raw_codec!(audit::Audit_query);
+inner_impl_default_protobuf_raw_codec!(audit::Audit_record_list);
@@ -35,6 +35,8 @@ pub struct Product_info {
     pub product_id: ::std::string::String,
     // @@protoc_insertion_point(field:product_info.product_name)
     pub product_name: ::std::string::String,
+    // @@protoc_insertion_point(field:product_info.capability)
+    pub capability: ::protobuf::MessageField<Product_capability>,
     // @@protoc_insertion_point(field:product_info.children)
     pub children: ::protobuf::MessageField<Product_info_list>,
     // special fields
@@ -131,6 +133,36 @@ impl Product_info {
         ::std::mem::replace(&mut self.product_name, ::std::string::String::new())
     }
 
+    // .product_capability capability = 4;
+
+    pub fn capability(&self) -> &Product_capability {
+        self.capability.as_ref().unwrap_or_else(|| <Product_capability as ::protobuf::Message>::default_instance())
+    }
+
+    pub fn clear_capability(&mut self) {
+        self.capability.clear();
+    }
+
+    pub fn has_capability(&self) -> bool {
+        self.capability.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_capability(&mut self, v: Product_capability) {
+        self.capability = ::protobuf::MessageField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_capability(&mut self) -> &mut Product_capability {
+        self.capability.mut_or_insert_default()
+    }
+
+    // Take field
+    pub fn take_capability(&mut self) -> Product_capability {
+        self.capability.take().unwrap_or_else(|| Product_capability::new())
+    }
+
     // .product_info_list children = 20;
 
     pub fn children(&self) -> &Product_info_list {
@@ -162,7 +194,7 @@ impl Product_info {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut fields = ::std::vec::Vec::with_capacity(5);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "parent_product_id",
@@ -179,6 +211,11 @@ impl Product_info {
             |m: &Product_info| { &m.product_name },
             |m: &mut Product_info| { &mut m.product_name },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Product_capability>(
+            "capability",
+            |m: &Product_info| { &m.capability },
+            |m: &mut Product_info| { &mut m.capability },
+        ));
         fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Product_info_list>(
             "children",
             |m: &Product_info| { &m.children },
@@ -211,6 +248,9 @@ impl ::protobuf::Message for Product_info {
                 26 => {
                     self.product_name = is.read_string()?;
                 },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.capability)?;
+                },
                 162 => {
                     ::protobuf::rt::read_singular_message_into_field(is, &mut self.children)?;
                 },
@@ -235,6 +275,10 @@ impl ::protobuf::Message for Product_info {
         if !self.product_name.is_empty() {
             my_size += ::protobuf::rt::string_size(3, &self.product_name);
         }
+        if let Some(v) = self.capability.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
         if let Some(v) = self.children.as_ref() {
             let len = v.compute_size();
             my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
@@ -254,6 +298,9 @@ impl ::protobuf::Message for Product_info {
         if !self.product_name.is_empty() {
             os.write_string(3, &self.product_name)?;
         }
+        if let Some(v) = self.capability.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
         if let Some(v) = self.children.as_ref() {
             ::protobuf::rt::write_message_field_with_cached_size(20, v, os)?;
         }
@@ -277,6 +324,7 @@ impl ::protobuf::Message for Product_info {
         self.parent_product_id.clear();
         self.product_id.clear();
         self.product_name.clear();
+        self.capability.clear();
         self.children.clear();
         self.special_fields.clear();
     }
@@ -286,6 +334,7 @@ impl ::protobuf::Message for Product_info {
             parent_product_id: ::std::string::String::new(),
             product_id: ::std::string::String::new(),
             product_name: ::std::string::String::new(),
+            capability: ::protobuf::MessageField::none(),
             children: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
@@ -759,15 +808,1202 @@ impl ::protobuf::reflect::ProtobufValue for Product_query_all {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:product_property)
+pub struct Product_property {
+    // message fields
+    // @@protoc_insertion_point(field:product_property.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:product_property.type)
+    pub type_: ::protobuf::EnumOrUnknown<Product_property_type>,
+    // @@protoc_insertion_point(field:product_property.unit)
+    pub unit: ::std::string::String,
+    // @@protoc_insertion_point(field:product_property.min)
+    pub min: ::std::option::Option<f64>,
+    // @@protoc_insertion_point(field:product_property.max)
+    pub max: ::std::option::Option<f64>,
+    // @@protoc_insertion_point(field:product_property.step)
+    pub step: f64,
+    // @@protoc_insertion_point(field:product_property.enums)
+    pub enums: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:product_property.readable)
+    pub readable: bool,
+    // @@protoc_insertion_point(field:product_property.writable)
+    pub writable: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:product_property.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Product_property {
+    fn default() -> &'a Product_property {
+        <Product_property as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Product_property {
+    pub fn new() -> Product_property {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // .product_property_type type = 2;
+
+    pub fn type_(&self) -> Product_property_type {
+        self.type_.enum_value_or_default()
+    }
+
+    pub fn clear_type_(&mut self) {
+        self.type_ = ::protobuf::EnumOrUnknown::new(Product_property_type::Bool);
+    }
+
+    // Param is passed by value, moved
+    pub fn set_type(&mut self, v: Product_property_type) {
+        self.type_ = ::protobuf::EnumOrUnknown::new(v);
+    }
+
+    // string unit = 3;
+
+    pub fn unit(&self) -> &str {
+        &self.unit
+    }
+
+    pub fn clear_unit(&mut self) {
+        self.unit.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_unit(&mut self, v: ::std::string::String) {
+        self.unit = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_unit(&mut self) -> &mut ::std::string::String {
+        &mut self.unit
+    }
+
+    // Take field
+    pub fn take_unit(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.unit, ::std::string::String::new())
+    }
+
+    // double min = 4;
+
+    pub fn min(&self) -> f64 {
+        self.min.unwrap_or(0.)
+    }
+
+    pub fn clear_min(&mut self) {
+        self.min = ::std::option::Option::None;
+    }
+
+    pub fn has_min(&self) -> bool {
+        self.min.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_min(&mut self, v: f64) {
+        self.min = ::std::option::Option::Some(v);
+    }
+
+    // double max = 5;
+
+    pub fn max(&self) -> f64 {
+        self.max.unwrap_or(0.)
+    }
+
+    pub fn clear_max(&mut self) {
+        self.max = ::std::option::Option::None;
+    }
+
+    pub fn has_max(&self) -> bool {
+        self.max.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max(&mut self, v: f64) {
+        self.max = ::std::option::Option::Some(v);
+    }
+
+    // double step = 6;
+
+    pub fn step(&self) -> f64 {
+        self.step
+    }
+
+    pub fn clear_step(&mut self) {
+        self.step = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_step(&mut self, v: f64) {
+        self.step = v;
+    }
+
+    // repeated string enums = 7;
+
+    pub fn enums(&self) -> &[::std::string::String] {
+        &self.enums
+    }
+
+    pub fn clear_enums(&mut self) {
+        self.enums.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enums(&mut self, v: ::std::vec::Vec<::std::string::String>) {
+        self.enums = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_enums(&mut self) -> &mut ::std::vec::Vec<::std::string::String> {
+        &mut self.enums
+    }
+
+    // Take field
+    pub fn take_enums(&mut self) -> ::std::vec::Vec<::std::string::String> {
+        ::std::mem::replace(&mut self.enums, ::std::vec::Vec::new())
+    }
+
+    // bool readable = 8;
+
+    pub fn readable(&self) -> bool {
+        self.readable
+    }
+
+    pub fn clear_readable(&mut self) {
+        self.readable = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_readable(&mut self, v: bool) {
+        self.readable = v;
+    }
+
+    // bool writable = 9;
+
+    pub fn writable(&self) -> bool {
+        self.writable
+    }
+
+    pub fn clear_writable(&mut self) {
+        self.writable = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_writable(&mut self, v: bool) {
+        self.writable = v;
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(9);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &Product_property| { &m.name },
+            |m: &mut Product_property| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "type",
+            |m: &Product_property| { &m.type_ },
+            |m: &mut Product_property| { &mut m.type_ },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "unit",
+            |m: &Product_property| { &m.unit },
+            |m: &mut Product_property| { &mut m.unit },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "min",
+            |m: &Product_property| { &m.min },
+            |m: &mut Product_property| { &mut m.min },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "max",
+            |m: &Product_property| { &m.max },
+            |m: &mut Product_property| { &mut m.max },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "step",
+            |m: &Product_property| { &m.step },
+            |m: &mut Product_property| { &mut m.step },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "enums",
+            |m: &Product_property| { &m.enums },
+            |m: &mut Product_property| { &mut m.enums },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "readable",
+            |m: &Product_property| { &m.readable },
+            |m: &mut Product_property| { &mut m.readable },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "writable",
+            |m: &Product_property| { &m.writable },
+            |m: &mut Product_property| { &mut m.writable },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Product_property>(
+            "product_property",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Product_property {
+    const NAME: &'static str = "product_property";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                16 => {
+                    self.type_ = is.read_enum_or_unknown()?;
+                },
+                26 => {
+                    self.unit = is.read_string()?;
+                },
+                33 => {
+                    self.min = ::std::option::Option::Some(is.read_double()?);
+                },
+                41 => {
+                    self.max = ::std::option::Option::Some(is.read_double()?);
+                },
+                49 => {
+                    self.step = is.read_double()?;
+                },
+                58 => {
+                    self.enums.push(is.read_string()?);
+                },
+                64 => {
+                    self.readable = is.read_bool()?;
+                },
+                72 => {
+                    self.writable = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.type_ != ::protobuf::EnumOrUnknown::new(Product_property_type::Bool) {
+            my_size += ::protobuf::rt::int32_size(2, self.type_.value());
+        }
+        if !self.unit.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.unit);
+        }
+        if let Some(v) = self.min {
+            my_size += 1 + 8;
+        }
+        if let Some(v) = self.max {
+            my_size += 1 + 8;
+        }
+        if self.step != 0. {
+            my_size += 1 + 8;
+        }
+        for value in &self.enums {
+            my_size += ::protobuf::rt::string_size(7, &value);
+        };
+        if self.readable != false {
+            my_size += 1 + 1;
+        }
+        if self.writable != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.type_ != ::protobuf::EnumOrUnknown::new(Product_property_type::Bool) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.type_))?;
+        }
+        if !self.unit.is_empty() {
+            os.write_string(3, &self.unit)?;
+        }
+        if let Some(v) = self.min {
+            os.write_double(4, v)?;
+        }
+        if let Some(v) = self.max {
+            os.write_double(5, v)?;
+        }
+        if self.step != 0. {
+            os.write_double(6, self.step)?;
+        }
+        for v in &self.enums {
+            os.write_string(7, &v)?;
+        };
+        if self.readable != false {
+            os.write_bool(8, self.readable)?;
+        }
+        if self.writable != false {
+            os.write_bool(9, self.writable)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Product_property {
+        Product_property::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.type_ = ::protobuf::EnumOrUnknown::new(Product_property_type::Bool);
+        self.unit.clear();
+        self.min = ::std::option::Option::None;
+        self.max = ::std::option::Option::None;
+        self.step = 0.;
+        self.enums.clear();
+        self.readable = false;
+        self.writable = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Product_property {
+        static instance: Product_property = Product_property {
+            name: ::std::string::String::new(),
+            type_: ::protobuf::EnumOrUnknown::from_i32(0),
+            unit: ::std::string::String::new(),
+            min: ::std::option::Option::None,
+            max: ::std::option::Option::None,
+            step: 0.,
+            enums: ::std::vec::Vec::new(),
+            readable: false,
+            writable: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Product_property {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("product_property").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Product_property {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Product_property {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:product_capability)
+pub struct Product_capability {
+    // message fields
+    // @@protoc_insertion_point(field:product_capability.properties)
+    pub properties: ::std::vec::Vec<Product_property>,
+    // special fields
+    // @@protoc_insertion_point(special_field:product_capability.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Product_capability {
+    fn default() -> &'a Product_capability {
+        <Product_capability as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Product_capability {
+    pub fn new() -> Product_capability {
+        ::std::default::Default::default()
+    }
+
+    // repeated .product_property properties = 1;
+
+    pub fn properties(&self) -> &[Product_property] {
+        &self.properties
+    }
+
+    pub fn clear_properties(&mut self) {
+        self.properties.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_properties(&mut self, v: ::std::vec::Vec<Product_property>) {
+        self.properties = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_properties(&mut self) -> &mut ::std::vec::Vec<Product_property> {
+        &mut self.properties
+    }
+
+    // Take field
+    pub fn take_properties(&mut self) -> ::std::vec::Vec<Product_property> {
+        ::std::mem::replace(&mut self.properties, ::std::vec::Vec::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "properties",
+            |m: &Product_capability| { &m.properties },
+            |m: &mut Product_capability| { &mut m.properties },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Product_capability>(
+            "product_capability",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Product_capability {
+    const NAME: &'static str = "product_capability";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.properties.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.properties {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.properties {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Product_capability {
+        Product_capability::new()
+    }
+
+    fn clear(&mut self) {
+        self.properties.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Product_capability {
+        static instance: Product_capability = Product_capability {
+            properties: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Product_capability {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("product_capability").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Product_capability {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Product_capability {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:product_capability_update)
+pub struct Product_capability_update {
+    // message fields
+    // @@protoc_insertion_point(field:product_capability_update.product_id)
+    pub product_id: ::std::string::String,
+    // @@protoc_insertion_point(field:product_capability_update.capability)
+    pub capability: ::protobuf::MessageField<Product_capability>,
+    // special fields
+    // @@protoc_insertion_point(special_field:product_capability_update.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Product_capability_update {
+    fn default() -> &'a Product_capability_update {
+        <Product_capability_update as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Product_capability_update {
+    pub fn new() -> Product_capability_update {
+        ::std::default::Default::default()
+    }
+
+    // string product_id = 1;
+
+    pub fn product_id(&self) -> &str {
+        &self.product_id
+    }
+
+    pub fn clear_product_id(&mut self) {
+        self.product_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_product_id(&mut self, v: ::std::string::String) {
+        self.product_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_product_id(&mut self) -> &mut ::std::string::String {
+        &mut self.product_id
+    }
+
+    // Take field
+    pub fn take_product_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.product_id, ::std::string::String::new())
+    }
+
+    // .product_capability capability = 2;
+
+    pub fn capability(&self) -> &Product_capability {
+        self.capability.as_ref().unwrap_or_else(|| <Product_capability as ::protobuf::Message>::default_instance())
+    }
+
+    pub fn clear_capability(&mut self) {
+        self.capability.clear();
+    }
+
+    pub fn has_capability(&self) -> bool {
+        self.capability.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_capability(&mut self, v: Product_capability) {
+        self.capability = ::protobuf::MessageField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_capability(&mut self) -> &mut Product_capability {
+        self.capability.mut_or_insert_default()
+    }
+
+    // Take field
+    pub fn take_capability(&mut self) -> Product_capability {
+        self.capability.take().unwrap_or_else(|| Product_capability::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "product_id",
+            |m: &Product_capability_update| { &m.product_id },
+            |m: &mut Product_capability_update| { &mut m.product_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Product_capability>(
+            "capability",
+            |m: &Product_capability_update| { &m.capability },
+            |m: &mut Product_capability_update| { &mut m.capability },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Product_capability_update>(
+            "product_capability_update",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Product_capability_update {
+    const NAME: &'static str = "product_capability_update";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.product_id = is.read_string()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.capability)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.product_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.product_id);
+        }
+        if let Some(v) = self.capability.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.product_id.is_empty() {
+            os.write_string(1, &self.product_id)?;
+        }
+        if let Some(v) = self.capability.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Product_capability_update {
+        Product_capability_update::new()
+    }
+
+    fn clear(&mut self) {
+        self.product_id.clear();
+        self.capability.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Product_capability_update {
+        static instance: Product_capability_update = Product_capability_update {
+            product_id: ::std::string::String::new(),
+            capability: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Product_capability_update {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("product_capability_update").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Product_capability_update {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Product_capability_update {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:thing_capability_query)
+pub struct Thing_capability_query {
+    // message fields
+    // @@protoc_insertion_point(field:thing_capability_query.thing_ids)
+    pub thing_ids: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:thing_capability_query.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Thing_capability_query {
+    fn default() -> &'a Thing_capability_query {
+        <Thing_capability_query as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Thing_capability_query {
+    pub fn new() -> Thing_capability_query {
+        ::std::default::Default::default()
+    }
+
+    // repeated string thing_ids = 1;
+
+    pub fn thing_ids(&self) -> &[::std::string::String] {
+        &self.thing_ids
+    }
+
+    pub fn clear_thing_ids(&mut self) {
+        self.thing_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_thing_ids(&mut self, v: ::std::vec::Vec<::std::string::String>) {
+        self.thing_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_thing_ids(&mut self) -> &mut ::std::vec::Vec<::std::string::String> {
+        &mut self.thing_ids
+    }
+
+    // Take field
+    pub fn take_thing_ids(&mut self) -> ::std::vec::Vec<::std::string::String> {
+        ::std::mem::replace(&mut self.thing_ids, ::std::vec::Vec::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "thing_ids",
+            |m: &Thing_capability_query| { &m.thing_ids },
+            |m: &mut Thing_capability_query| { &mut m.thing_ids },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Thing_capability_query>(
+            "thing_capability_query",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Thing_capability_query {
+    const NAME: &'static str = "thing_capability_query";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.thing_ids.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.thing_ids {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.thing_ids {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Thing_capability_query {
+        Thing_capability_query::new()
+    }
+
+    fn clear(&mut self) {
+        self.thing_ids.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Thing_capability_query {
+        static instance: Thing_capability_query = Thing_capability_query {
+            thing_ids: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Thing_capability_query {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("thing_capability_query").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Thing_capability_query {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Thing_capability_query {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:thing_capability_list)
+pub struct Thing_capability_list {
+    // message fields
+    // @@protoc_insertion_point(field:thing_capability_list.capabilities)
+    pub capabilities: ::std::collections::HashMap<::std::string::String, Product_capability>,
+    // special fields
+    // @@protoc_insertion_point(special_field:thing_capability_list.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Thing_capability_list {
+    fn default() -> &'a Thing_capability_list {
+        <Thing_capability_list as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Thing_capability_list {
+    pub fn new() -> Thing_capability_list {
+        ::std::default::Default::default()
+    }
+
+    // repeated .thing_capability_list.CapabilitiesEntry capabilities = 1;
+
+    pub fn capabilities(&self) -> &::std::collections::HashMap<::std::string::String, Product_capability> {
+        &self.capabilities
+    }
+
+    pub fn clear_capabilities(&mut self) {
+        self.capabilities.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_capabilities(&mut self, v: ::std::collections::HashMap<::std::string::String, Product_capability>) {
+        self.capabilities = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_capabilities(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, Product_capability> {
+        &mut self.capabilities
+    }
+
+    // Take field
+    pub fn take_capabilities(&mut self) -> ::std::collections::HashMap<::std::string::String, Product_capability> {
+        ::std::mem::replace(&mut self.capabilities, ::std::collections::HashMap::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+            "capabilities",
+            |m: &Thing_capability_list| { &m.capabilities },
+            |m: &mut Thing_capability_list| { &mut m.capabilities },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Thing_capability_list>(
+            "thing_capability_list",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Thing_capability_list {
+    const NAME: &'static str = "thing_capability_list";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_message()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.capabilities.insert(key, value);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for (k, v) in &self.capabilities {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            let len = v.compute_size();
+            entry_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for (k, v) in &self.capabilities {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            let len = v.cached_size() as u64;
+            entry_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            os.write_raw_varint32(10)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Thing_capability_list {
+        Thing_capability_list::new()
+    }
+
+    fn clear(&mut self) {
+        self.capabilities.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Thing_capability_list {
+        static instance: ::protobuf::rt::Lazy<Thing_capability_list> = ::protobuf::rt::Lazy::new();
+        instance.get(Thing_capability_list::new)
+    }
+}
+
+impl ::protobuf::MessageFull for Thing_capability_list {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("thing_capability_list").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Thing_capability_list {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Thing_capability_list {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:product_property_type)
+pub enum Product_property_type {
+    // @@protoc_insertion_point(enum_value:product_property_type.Bool)
+    Bool = 0,
+    // @@protoc_insertion_point(enum_value:product_property_type.Int)
+    Int = 1,
+    // @@protoc_insertion_point(enum_value:product_property_type.Float)
+    Float = 2,
+    // @@protoc_insertion_point(enum_value:product_property_type.String)
+    String = 3,
+    // @@protoc_insertion_point(enum_value:product_property_type.Enum)
+    Enum = 4,
+}
+
+impl ::protobuf::Enum for Product_property_type {
+    const NAME: &'static str = "product_property_type";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Product_property_type> {
+        match value {
+            0 => ::std::option::Option::Some(Product_property_type::Bool),
+            1 => ::std::option::Option::Some(Product_property_type::Int),
+            2 => ::std::option::Option::Some(Product_property_type::Float),
+            3 => ::std::option::Option::Some(Product_property_type::String),
+            4 => ::std::option::Option::Some(Product_property_type::Enum),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [Product_property_type] = &[
+        Product_property_type::Bool,
+        Product_property_type::Int,
+        Product_property_type::Float,
+        Product_property_type::String,
+        Product_property_type::Enum,
+    ];
+}
+
+impl ::protobuf::EnumFull for Product_property_type {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("product_property_type").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for Product_property_type {
+    fn default() -> Self {
+        Product_property_type::Bool
+    }
+}
+
+impl Product_property_type {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Product_property_type>("product_property_type")
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\rproduct.proto\"\xac\x01\n\x0cproduct_info\x12*\n\x11parent_product_i\
+    \n\rproduct.proto\"\xe1\x01\n\x0cproduct_info\x12*\n\x11parent_product_i\
     d\x18\x01\x20\x01(\tR\x0fparentProductId\x12\x1d\n\nproduct_id\x18\x02\
     \x20\x01(\tR\tproductId\x12!\n\x0cproduct_name\x18\x03\x20\x01(\tR\x0bpr\
-    oductName\x12.\n\x08children\x18\x14\x20\x01(\x0b2\x12.product_info_list\
-    R\x08children\">\n\x11product_info_list\x12)\n\x08products\x18\x01\x20\
-    \x03(\x0b2\r.product_infoR\x08products\"6\n\x0bproduct_add\x12'\n\x07pro\
-    duct\x18\x01\x20\x01(\x0b2\r.product_infoR\x07product\".\n\x11product_qu\
-    ery_all\x12\x19\n\x08brand_id\x18\x01\x20\x01(\tR\x07brandIdb\x06proto3\
+    oductName\x123\n\ncapability\x18\x04\x20\x01(\x0b2\x13.product_capabilit\
+    yR\ncapability\x12.\n\x08children\x18\x14\x20\x01(\x0b2\x12.product_info\
+    _listR\x08children\">\n\x11product_info_list\x12)\n\x08products\x18\x01\
+    \x20\x03(\x0b2\r.product_infoR\x08products\"6\n\x0bproduct_add\x12'\n\
+    \x07product\x18\x01\x20\x01(\x0b2\r.product_infoR\x07product\".\n\x11pro\
+    duct_query_all\x12\x19\n\x08brand_id\x18\x01\x20\x01(\tR\x07brandId\"\
+    \x86\x02\n\x10product_property\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04\
+    name\x12*\n\x04type\x18\x02\x20\x01(\x0e2\x16.product_property_typeR\x04\
+    type\x12\x12\n\x04unit\x18\x03\x20\x01(\tR\x04unit\x12\x15\n\x03min\x18\
+    \x04\x20\x01(\x01H\0R\x03min\x88\x01\x01\x12\x15\n\x03max\x18\x05\x20\
+    \x01(\x01H\x01R\x03max\x88\x01\x01\x12\x12\n\x04step\x18\x06\x20\x01(\
+    \x01R\x04step\x12\x14\n\x05enums\x18\x07\x20\x03(\tR\x05enums\x12\x1a\n\
+    \x08readable\x18\x08\x20\x01(\x08R\x08readable\x12\x1a\n\x08writable\x18\
+    \t\x20\x01(\x08R\x08writableB\x06\n\x04_minB\x06\n\x04_max\"G\n\x12produ\
+    ct_capability\x121\n\nproperties\x18\x01\x20\x03(\x0b2\x11.product_prope\
+    rtyR\nproperties\"o\n\x19product_capability_update\x12\x1d\n\nproduct_id\
+    \x18\x01\x20\x01(\tR\tproductId\x123\n\ncapability\x18\x02\x20\x01(\x0b2\
+    \x13.product_capabilityR\ncapability\"5\n\x16thing_capability_query\x12\
+    \x1b\n\tthing_ids\x18\x01\x20\x03(\tR\x08thingIds\"\xbb\x01\n\x15thing_c\
+    apability_list\x12L\n\x0ccapabilities\x18\x01\x20\x03(\x0b2(.thing_capab\
+    ility_list.CapabilitiesEntryR\x0ccapabilities\x1aT\n\x11CapabilitiesEntr\
+    y\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12)\n\x05value\x18\x02\
+    \x20\x01(\x0b2\x13.product_capabilityR\x05value:\x028\x01*K\n\x15product\
+    _property_type\x12\x08\n\x04Bool\x10\0\x12\x07\n\x03Int\x10\x01\x12\t\n\
+    \x05Float\x10\x02\x12\n\n\x06String\x10\x03\x12\x08\n\x04Enum\x10\x04b\
+    \x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -785,12 +2021,18 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
     file_descriptor.get(|| {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(0);
-            let mut messages = ::std::vec::Vec::with_capacity(4);
+            let mut messages = ::std::vec::Vec::with_capacity(9);
             messages.push(Product_info::generated_message_descriptor_data());
             messages.push(Product_info_list::generated_message_descriptor_data());
             messages.push(Product_add::generated_message_descriptor_data());
             messages.push(Product_query_all::generated_message_descriptor_data());
-            let mut enums = ::std::vec::Vec::with_capacity(0);
+            messages.push(Product_property::generated_message_descriptor_data());
+            messages.push(Product_capability::generated_message_descriptor_data());
+            messages.push(Product_capability_update::generated_message_descriptor_data());
+            messages.push(Thing_capability_query::generated_message_descriptor_data());
+            messages.push(Thing_capability_list::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(1);
+            enums.push(Product_property_type::generated_enum_descriptor_data());
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
                 file_descriptor_proto(),
                 deps,
@@ -0,0 +1,1633 @@
+// This file is generated by rust-protobuf 3.2.0. Do not edit
+// .proto file is parsed by pure
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `telemetry.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_2_0;
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:thing_telemetry_sample)
+pub struct Thing_telemetry_sample {
+    // message fields
+    // @@protoc_insertion_point(field:thing_telemetry_sample.thing_id)
+    pub thing_id: ::std::string::String,
+    // @@protoc_insertion_point(field:thing_telemetry_sample.timestamp)
+    pub timestamp: u64,
+    // @@protoc_insertion_point(field:thing_telemetry_sample.data)
+    pub data: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:thing_telemetry_sample.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Thing_telemetry_sample {
+    fn default() -> &'a Thing_telemetry_sample {
+        <Thing_telemetry_sample as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Thing_telemetry_sample {
+    pub fn new() -> Thing_telemetry_sample {
+        ::std::default::Default::default()
+    }
+
+    // string thing_id = 1;
+
+    pub fn thing_id(&self) -> &str {
+        &self.thing_id
+    }
+
+    pub fn clear_thing_id(&mut self) {
+        self.thing_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_thing_id(&mut self, v: ::std::string::String) {
+        self.thing_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_thing_id(&mut self) -> &mut ::std::string::String {
+        &mut self.thing_id
+    }
+
+    // Take field
+    pub fn take_thing_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.thing_id, ::std::string::String::new())
+    }
+
+    // uint64 timestamp = 2;
+
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    pub fn clear_timestamp(&mut self) {
+        self.timestamp = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_timestamp(&mut self, v: u64) {
+        self.timestamp = v;
+    }
+
+    // repeated .thing_telemetry_sample.DataEntry data = 3;
+
+    pub fn data(&self) -> &::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &self.data
+    }
+
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::collections::HashMap<::std::string::String, ::std::string::String>) {
+        self.data = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_data(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &mut self.data
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        ::std::mem::replace(&mut self.data, ::std::collections::HashMap::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "thing_id",
+            |m: &Thing_telemetry_sample| { &m.thing_id },
+            |m: &mut Thing_telemetry_sample| { &mut m.thing_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "timestamp",
+            |m: &Thing_telemetry_sample| { &m.timestamp },
+            |m: &mut Thing_telemetry_sample| { &mut m.timestamp },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+            "data",
+            |m: &Thing_telemetry_sample| { &m.data },
+            |m: &mut Thing_telemetry_sample| { &mut m.data },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Thing_telemetry_sample>(
+            "thing_telemetry_sample",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Thing_telemetry_sample {
+    const NAME: &'static str = "thing_telemetry_sample";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.thing_id = is.read_string()?;
+                },
+                16 => {
+                    self.timestamp = is.read_uint64()?;
+                },
+                26 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_string()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.data.insert(key, value);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.thing_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.thing_id);
+        }
+        if self.timestamp != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.timestamp);
+        }
+        for (k, v) in &self.data {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.thing_id.is_empty() {
+            os.write_string(1, &self.thing_id)?;
+        }
+        if self.timestamp != 0 {
+            os.write_uint64(2, self.timestamp)?;
+        }
+        for (k, v) in &self.data {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            os.write_raw_varint32(26)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Thing_telemetry_sample {
+        Thing_telemetry_sample::new()
+    }
+
+    fn clear(&mut self) {
+        self.thing_id.clear();
+        self.timestamp = 0;
+        self.data.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Thing_telemetry_sample {
+        static instance: ::protobuf::rt::Lazy<Thing_telemetry_sample> = ::protobuf::rt::Lazy::new();
+        instance.get(Thing_telemetry_sample::new)
+    }
+}
+
+impl ::protobuf::MessageFull for Thing_telemetry_sample {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("thing_telemetry_sample").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Thing_telemetry_sample {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Thing_telemetry_sample {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:thing_telemetry_aggregate)
+pub struct Thing_telemetry_aggregate {
+    // message fields
+    // @@protoc_insertion_point(field:thing_telemetry_aggregate.property)
+    pub property: ::std::string::String,
+    // @@protoc_insertion_point(field:thing_telemetry_aggregate.begin)
+    pub begin: u64,
+    // @@protoc_insertion_point(field:thing_telemetry_aggregate.end)
+    pub end: u64,
+    // @@protoc_insertion_point(field:thing_telemetry_aggregate.count)
+    pub count: u32,
+    // @@protoc_insertion_point(field:thing_telemetry_aggregate.min)
+    pub min: f64,
+    // @@protoc_insertion_point(field:thing_telemetry_aggregate.max)
+    pub max: f64,
+    // @@protoc_insertion_point(field:thing_telemetry_aggregate.avg)
+    pub avg: f64,
+    // @@protoc_insertion_point(field:thing_telemetry_aggregate.last)
+    pub last: ::std::string::String,
+    // @@protoc_insertion_point(field:thing_telemetry_aggregate.numeric_count)
+    pub numeric_count: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:thing_telemetry_aggregate.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Thing_telemetry_aggregate {
+    fn default() -> &'a Thing_telemetry_aggregate {
+        <Thing_telemetry_aggregate as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Thing_telemetry_aggregate {
+    pub fn new() -> Thing_telemetry_aggregate {
+        ::std::default::Default::default()
+    }
+
+    // string property = 1;
+
+    pub fn property(&self) -> &str {
+        &self.property
+    }
+
+    pub fn clear_property(&mut self) {
+        self.property.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_property(&mut self, v: ::std::string::String) {
+        self.property = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_property(&mut self) -> &mut ::std::string::String {
+        &mut self.property
+    }
+
+    // Take field
+    pub fn take_property(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.property, ::std::string::String::new())
+    }
+
+    // uint64 begin = 2;
+
+    pub fn begin(&self) -> u64 {
+        self.begin
+    }
+
+    pub fn clear_begin(&mut self) {
+        self.begin = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_begin(&mut self, v: u64) {
+        self.begin = v;
+    }
+
+    // uint64 end = 3;
+
+    pub fn end(&self) -> u64 {
+        self.end
+    }
+
+    pub fn clear_end(&mut self) {
+        self.end = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end(&mut self, v: u64) {
+        self.end = v;
+    }
+
+    // uint32 count = 4;
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: u32) {
+        self.count = v;
+    }
+
+    // double min = 5;
+
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    pub fn clear_min(&mut self) {
+        self.min = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_min(&mut self, v: f64) {
+        self.min = v;
+    }
+
+    // double max = 6;
+
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    pub fn clear_max(&mut self) {
+        self.max = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max(&mut self, v: f64) {
+        self.max = v;
+    }
+
+    // double avg = 7;
+
+    pub fn avg(&self) -> f64 {
+        self.avg
+    }
+
+    pub fn clear_avg(&mut self) {
+        self.avg = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_avg(&mut self, v: f64) {
+        self.avg = v;
+    }
+
+    // string last = 8;
+
+    pub fn last(&self) -> &str {
+        &self.last
+    }
+
+    pub fn clear_last(&mut self) {
+        self.last.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last(&mut self, v: ::std::string::String) {
+        self.last = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_last(&mut self) -> &mut ::std::string::String {
+        &mut self.last
+    }
+
+    // Take field
+    pub fn take_last(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.last, ::std::string::String::new())
+    }
+
+    // uint32 numeric_count = 9;
+
+    pub fn numeric_count(&self) -> u32 {
+        self.numeric_count
+    }
+
+    pub fn clear_numeric_count(&mut self) {
+        self.numeric_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_numeric_count(&mut self, v: u32) {
+        self.numeric_count = v;
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(9);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "property",
+            |m: &Thing_telemetry_aggregate| { &m.property },
+            |m: &mut Thing_telemetry_aggregate| { &mut m.property },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "begin",
+            |m: &Thing_telemetry_aggregate| { &m.begin },
+            |m: &mut Thing_telemetry_aggregate| { &mut m.begin },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "end",
+            |m: &Thing_telemetry_aggregate| { &m.end },
+            |m: &mut Thing_telemetry_aggregate| { &mut m.end },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "count",
+            |m: &Thing_telemetry_aggregate| { &m.count },
+            |m: &mut Thing_telemetry_aggregate| { &mut m.count },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "min",
+            |m: &Thing_telemetry_aggregate| { &m.min },
+            |m: &mut Thing_telemetry_aggregate| { &mut m.min },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "max",
+            |m: &Thing_telemetry_aggregate| { &m.max },
+            |m: &mut Thing_telemetry_aggregate| { &mut m.max },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "avg",
+            |m: &Thing_telemetry_aggregate| { &m.avg },
+            |m: &mut Thing_telemetry_aggregate| { &mut m.avg },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "last",
+            |m: &Thing_telemetry_aggregate| { &m.last },
+            |m: &mut Thing_telemetry_aggregate| { &mut m.last },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "numeric_count",
+            |m: &Thing_telemetry_aggregate| { &m.numeric_count },
+            |m: &mut Thing_telemetry_aggregate| { &mut m.numeric_count },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Thing_telemetry_aggregate>(
+            "thing_telemetry_aggregate",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Thing_telemetry_aggregate {
+    const NAME: &'static str = "thing_telemetry_aggregate";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.property = is.read_string()?;
+                },
+                16 => {
+                    self.begin = is.read_uint64()?;
+                },
+                24 => {
+                    self.end = is.read_uint64()?;
+                },
+                32 => {
+                    self.count = is.read_uint32()?;
+                },
+                41 => {
+                    self.min = is.read_double()?;
+                },
+                49 => {
+                    self.max = is.read_double()?;
+                },
+                57 => {
+                    self.avg = is.read_double()?;
+                },
+                66 => {
+                    self.last = is.read_string()?;
+                },
+                72 => {
+                    self.numeric_count = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.property.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.property);
+        }
+        if self.begin != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.begin);
+        }
+        if self.end != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.end);
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.count);
+        }
+        if self.min != 0. {
+            my_size += 1 + 8;
+        }
+        if self.max != 0. {
+            my_size += 1 + 8;
+        }
+        if self.avg != 0. {
+            my_size += 1 + 8;
+        }
+        if !self.last.is_empty() {
+            my_size += ::protobuf::rt::string_size(8, &self.last);
+        }
+        if self.numeric_count != 0 {
+            my_size += ::protobuf::rt::uint32_size(9, self.numeric_count);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.property.is_empty() {
+            os.write_string(1, &self.property)?;
+        }
+        if self.begin != 0 {
+            os.write_uint64(2, self.begin)?;
+        }
+        if self.end != 0 {
+            os.write_uint64(3, self.end)?;
+        }
+        if self.count != 0 {
+            os.write_uint32(4, self.count)?;
+        }
+        if self.min != 0. {
+            os.write_double(5, self.min)?;
+        }
+        if self.max != 0. {
+            os.write_double(6, self.max)?;
+        }
+        if self.avg != 0. {
+            os.write_double(7, self.avg)?;
+        }
+        if !self.last.is_empty() {
+            os.write_string(8, &self.last)?;
+        }
+        if self.numeric_count != 0 {
+            os.write_uint32(9, self.numeric_count)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Thing_telemetry_aggregate {
+        Thing_telemetry_aggregate::new()
+    }
+
+    fn clear(&mut self) {
+        self.property.clear();
+        self.begin = 0;
+        self.end = 0;
+        self.count = 0;
+        self.min = 0.;
+        self.max = 0.;
+        self.avg = 0.;
+        self.last.clear();
+        self.numeric_count = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Thing_telemetry_aggregate {
+        static instance: Thing_telemetry_aggregate = Thing_telemetry_aggregate {
+            property: ::std::string::String::new(),
+            begin: 0,
+            end: 0,
+            count: 0,
+            min: 0.,
+            max: 0.,
+            avg: 0.,
+            last: ::std::string::String::new(),
+            numeric_count: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Thing_telemetry_aggregate {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("thing_telemetry_aggregate").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Thing_telemetry_aggregate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Thing_telemetry_aggregate {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:thing_telemetry_aggregate_list)
+pub struct Thing_telemetry_aggregate_list {
+    // message fields
+    // @@protoc_insertion_point(field:thing_telemetry_aggregate_list.thing_id)
+    pub thing_id: ::std::string::String,
+    // @@protoc_insertion_point(field:thing_telemetry_aggregate_list.begin)
+    pub begin: u64,
+    // @@protoc_insertion_point(field:thing_telemetry_aggregate_list.resolution)
+    pub resolution: ::protobuf::EnumOrUnknown<Thing_telemetry_resolution>,
+    // @@protoc_insertion_point(field:thing_telemetry_aggregate_list.aggregates)
+    pub aggregates: ::std::vec::Vec<Thing_telemetry_aggregate>,
+    // special fields
+    // @@protoc_insertion_point(special_field:thing_telemetry_aggregate_list.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Thing_telemetry_aggregate_list {
+    fn default() -> &'a Thing_telemetry_aggregate_list {
+        <Thing_telemetry_aggregate_list as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Thing_telemetry_aggregate_list {
+    pub fn new() -> Thing_telemetry_aggregate_list {
+        ::std::default::Default::default()
+    }
+
+    // string thing_id = 1;
+
+    pub fn thing_id(&self) -> &str {
+        &self.thing_id
+    }
+
+    pub fn clear_thing_id(&mut self) {
+        self.thing_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_thing_id(&mut self, v: ::std::string::String) {
+        self.thing_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_thing_id(&mut self) -> &mut ::std::string::String {
+        &mut self.thing_id
+    }
+
+    // Take field
+    pub fn take_thing_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.thing_id, ::std::string::String::new())
+    }
+
+    // uint64 begin = 2;
+
+    pub fn begin(&self) -> u64 {
+        self.begin
+    }
+
+    pub fn clear_begin(&mut self) {
+        self.begin = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_begin(&mut self, v: u64) {
+        self.begin = v;
+    }
+
+    // .thing_telemetry_resolution resolution = 3;
+
+    pub fn resolution(&self) -> Thing_telemetry_resolution {
+        self.resolution.enum_value_or_default()
+    }
+
+    pub fn clear_resolution(&mut self) {
+        self.resolution = ::protobuf::EnumOrUnknown::new(Thing_telemetry_resolution::Raw);
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resolution(&mut self, v: Thing_telemetry_resolution) {
+        self.resolution = ::protobuf::EnumOrUnknown::new(v);
+    }
+
+    // repeated .thing_telemetry_aggregate aggregates = 4;
+
+    pub fn aggregates(&self) -> &[Thing_telemetry_aggregate] {
+        &self.aggregates
+    }
+
+    pub fn clear_aggregates(&mut self) {
+        self.aggregates.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_aggregates(&mut self, v: ::std::vec::Vec<Thing_telemetry_aggregate>) {
+        self.aggregates = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_aggregates(&mut self) -> &mut ::std::vec::Vec<Thing_telemetry_aggregate> {
+        &mut self.aggregates
+    }
+
+    // Take field
+    pub fn take_aggregates(&mut self) -> ::std::vec::Vec<Thing_telemetry_aggregate> {
+        ::std::mem::replace(&mut self.aggregates, ::std::vec::Vec::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "thing_id",
+            |m: &Thing_telemetry_aggregate_list| { &m.thing_id },
+            |m: &mut Thing_telemetry_aggregate_list| { &mut m.thing_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "begin",
+            |m: &Thing_telemetry_aggregate_list| { &m.begin },
+            |m: &mut Thing_telemetry_aggregate_list| { &mut m.begin },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "resolution",
+            |m: &Thing_telemetry_aggregate_list| { &m.resolution },
+            |m: &mut Thing_telemetry_aggregate_list| { &mut m.resolution },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "aggregates",
+            |m: &Thing_telemetry_aggregate_list| { &m.aggregates },
+            |m: &mut Thing_telemetry_aggregate_list| { &mut m.aggregates },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Thing_telemetry_aggregate_list>(
+            "thing_telemetry_aggregate_list",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Thing_telemetry_aggregate_list {
+    const NAME: &'static str = "thing_telemetry_aggregate_list";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.thing_id = is.read_string()?;
+                },
+                16 => {
+                    self.begin = is.read_uint64()?;
+                },
+                24 => {
+                    self.resolution = is.read_enum_or_unknown()?;
+                },
+                34 => {
+                    self.aggregates.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.thing_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.thing_id);
+        }
+        if self.begin != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.begin);
+        }
+        if self.resolution != ::protobuf::EnumOrUnknown::new(Thing_telemetry_resolution::Raw) {
+            my_size += ::protobuf::rt::int32_size(3, self.resolution.value());
+        }
+        for value in &self.aggregates {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.thing_id.is_empty() {
+            os.write_string(1, &self.thing_id)?;
+        }
+        if self.begin != 0 {
+            os.write_uint64(2, self.begin)?;
+        }
+        if self.resolution != ::protobuf::EnumOrUnknown::new(Thing_telemetry_resolution::Raw) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.resolution))?;
+        }
+        for v in &self.aggregates {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Thing_telemetry_aggregate_list {
+        Thing_telemetry_aggregate_list::new()
+    }
+
+    fn clear(&mut self) {
+        self.thing_id.clear();
+        self.begin = 0;
+        self.resolution = ::protobuf::EnumOrUnknown::new(Thing_telemetry_resolution::Raw);
+        self.aggregates.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Thing_telemetry_aggregate_list {
+        static instance: Thing_telemetry_aggregate_list = Thing_telemetry_aggregate_list {
+            thing_id: ::std::string::String::new(),
+            begin: 0,
+            resolution: ::protobuf::EnumOrUnknown::from_i32(0),
+            aggregates: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Thing_telemetry_aggregate_list {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("thing_telemetry_aggregate_list").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Thing_telemetry_aggregate_list {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Thing_telemetry_aggregate_list {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:thing_telemetry_query)
+pub struct Thing_telemetry_query {
+    // message fields
+    // @@protoc_insertion_point(field:thing_telemetry_query.thing_id)
+    pub thing_id: ::std::string::String,
+    // @@protoc_insertion_point(field:thing_telemetry_query.begin)
+    pub begin: u64,
+    // @@protoc_insertion_point(field:thing_telemetry_query.end)
+    pub end: u64,
+    // @@protoc_insertion_point(field:thing_telemetry_query.resolution)
+    pub resolution: ::protobuf::EnumOrUnknown<Thing_telemetry_resolution>,
+    // @@protoc_insertion_point(field:thing_telemetry_query.properties)
+    pub properties: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:thing_telemetry_query.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Thing_telemetry_query {
+    fn default() -> &'a Thing_telemetry_query {
+        <Thing_telemetry_query as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Thing_telemetry_query {
+    pub fn new() -> Thing_telemetry_query {
+        ::std::default::Default::default()
+    }
+
+    // string thing_id = 1;
+
+    pub fn thing_id(&self) -> &str {
+        &self.thing_id
+    }
+
+    pub fn clear_thing_id(&mut self) {
+        self.thing_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_thing_id(&mut self, v: ::std::string::String) {
+        self.thing_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_thing_id(&mut self) -> &mut ::std::string::String {
+        &mut self.thing_id
+    }
+
+    // Take field
+    pub fn take_thing_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.thing_id, ::std::string::String::new())
+    }
+
+    // uint64 begin = 2;
+
+    pub fn begin(&self) -> u64 {
+        self.begin
+    }
+
+    pub fn clear_begin(&mut self) {
+        self.begin = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_begin(&mut self, v: u64) {
+        self.begin = v;
+    }
+
+    // uint64 end = 3;
+
+    pub fn end(&self) -> u64 {
+        self.end
+    }
+
+    pub fn clear_end(&mut self) {
+        self.end = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end(&mut self, v: u64) {
+        self.end = v;
+    }
+
+    // .thing_telemetry_resolution resolution = 4;
+
+    pub fn resolution(&self) -> Thing_telemetry_resolution {
+        self.resolution.enum_value_or_default()
+    }
+
+    pub fn clear_resolution(&mut self) {
+        self.resolution = ::protobuf::EnumOrUnknown::new(Thing_telemetry_resolution::Raw);
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resolution(&mut self, v: Thing_telemetry_resolution) {
+        self.resolution = ::protobuf::EnumOrUnknown::new(v);
+    }
+
+    // repeated string properties = 5;
+
+    pub fn properties(&self) -> &[::std::string::String] {
+        &self.properties
+    }
+
+    pub fn clear_properties(&mut self) {
+        self.properties.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_properties(&mut self, v: ::std::vec::Vec<::std::string::String>) {
+        self.properties = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_properties(&mut self) -> &mut ::std::vec::Vec<::std::string::String> {
+        &mut self.properties
+    }
+
+    // Take field
+    pub fn take_properties(&mut self) -> ::std::vec::Vec<::std::string::String> {
+        ::std::mem::replace(&mut self.properties, ::std::vec::Vec::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "thing_id",
+            |m: &Thing_telemetry_query| { &m.thing_id },
+            |m: &mut Thing_telemetry_query| { &mut m.thing_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "begin",
+            |m: &Thing_telemetry_query| { &m.begin },
+            |m: &mut Thing_telemetry_query| { &mut m.begin },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "end",
+            |m: &Thing_telemetry_query| { &m.end },
+            |m: &mut Thing_telemetry_query| { &mut m.end },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "resolution",
+            |m: &Thing_telemetry_query| { &m.resolution },
+            |m: &mut Thing_telemetry_query| { &mut m.resolution },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "properties",
+            |m: &Thing_telemetry_query| { &m.properties },
+            |m: &mut Thing_telemetry_query| { &mut m.properties },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Thing_telemetry_query>(
+            "thing_telemetry_query",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Thing_telemetry_query {
+    const NAME: &'static str = "thing_telemetry_query";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.thing_id = is.read_string()?;
+                },
+                16 => {
+                    self.begin = is.read_uint64()?;
+                },
+                24 => {
+                    self.end = is.read_uint64()?;
+                },
+                32 => {
+                    self.resolution = is.read_enum_or_unknown()?;
+                },
+                42 => {
+                    self.properties.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.thing_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.thing_id);
+        }
+        if self.begin != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.begin);
+        }
+        if self.end != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.end);
+        }
+        if self.resolution != ::protobuf::EnumOrUnknown::new(Thing_telemetry_resolution::Raw) {
+            my_size += ::protobuf::rt::int32_size(4, self.resolution.value());
+        }
+        for value in &self.properties {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.thing_id.is_empty() {
+            os.write_string(1, &self.thing_id)?;
+        }
+        if self.begin != 0 {
+            os.write_uint64(2, self.begin)?;
+        }
+        if self.end != 0 {
+            os.write_uint64(3, self.end)?;
+        }
+        if self.resolution != ::protobuf::EnumOrUnknown::new(Thing_telemetry_resolution::Raw) {
+            os.write_enum(4, ::protobuf::EnumOrUnknown::value(&self.resolution))?;
+        }
+        for v in &self.properties {
+            os.write_string(5, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Thing_telemetry_query {
+        Thing_telemetry_query::new()
+    }
+
+    fn clear(&mut self) {
+        self.thing_id.clear();
+        self.begin = 0;
+        self.end = 0;
+        self.resolution = ::protobuf::EnumOrUnknown::new(Thing_telemetry_resolution::Raw);
+        self.properties.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Thing_telemetry_query {
+        static instance: Thing_telemetry_query = Thing_telemetry_query {
+            thing_id: ::std::string::String::new(),
+            begin: 0,
+            end: 0,
+            resolution: ::protobuf::EnumOrUnknown::from_i32(0),
+            properties: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Thing_telemetry_query {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("thing_telemetry_query").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Thing_telemetry_query {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Thing_telemetry_query {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:thing_telemetry_series)
+pub struct Thing_telemetry_series {
+    // message fields
+    // @@protoc_insertion_point(field:thing_telemetry_series.thing_id)
+    pub thing_id: ::std::string::String,
+    // @@protoc_insertion_point(field:thing_telemetry_series.resolution)
+    pub resolution: ::protobuf::EnumOrUnknown<Thing_telemetry_resolution>,
+    // @@protoc_insertion_point(field:thing_telemetry_series.samples)
+    pub samples: ::std::vec::Vec<Thing_telemetry_sample>,
+    // @@protoc_insertion_point(field:thing_telemetry_series.aggregates)
+    pub aggregates: ::std::vec::Vec<Thing_telemetry_aggregate_list>,
+    // special fields
+    // @@protoc_insertion_point(special_field:thing_telemetry_series.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Thing_telemetry_series {
+    fn default() -> &'a Thing_telemetry_series {
+        <Thing_telemetry_series as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Thing_telemetry_series {
+    pub fn new() -> Thing_telemetry_series {
+        ::std::default::Default::default()
+    }
+
+    // string thing_id = 1;
+
+    pub fn thing_id(&self) -> &str {
+        &self.thing_id
+    }
+
+    pub fn clear_thing_id(&mut self) {
+        self.thing_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_thing_id(&mut self, v: ::std::string::String) {
+        self.thing_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_thing_id(&mut self) -> &mut ::std::string::String {
+        &mut self.thing_id
+    }
+
+    // Take field
+    pub fn take_thing_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.thing_id, ::std::string::String::new())
+    }
+
+    // .thing_telemetry_resolution resolution = 2;
+
+    pub fn resolution(&self) -> Thing_telemetry_resolution {
+        self.resolution.enum_value_or_default()
+    }
+
+    pub fn clear_resolution(&mut self) {
+        self.resolution = ::protobuf::EnumOrUnknown::new(Thing_telemetry_resolution::Raw);
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resolution(&mut self, v: Thing_telemetry_resolution) {
+        self.resolution = ::protobuf::EnumOrUnknown::new(v);
+    }
+
+    // repeated .thing_telemetry_sample samples = 3;
+
+    pub fn samples(&self) -> &[Thing_telemetry_sample] {
+        &self.samples
+    }
+
+    pub fn clear_samples(&mut self) {
+        self.samples.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_samples(&mut self, v: ::std::vec::Vec<Thing_telemetry_sample>) {
+        self.samples = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_samples(&mut self) -> &mut ::std::vec::Vec<Thing_telemetry_sample> {
+        &mut self.samples
+    }
+
+    // Take field
+    pub fn take_samples(&mut self) -> ::std::vec::Vec<Thing_telemetry_sample> {
+        ::std::mem::replace(&mut self.samples, ::std::vec::Vec::new())
+    }
+
+    // repeated .thing_telemetry_aggregate_list aggregates = 4;
+
+    pub fn aggregates(&self) -> &[Thing_telemetry_aggregate_list] {
+        &self.aggregates
+    }
+
+    pub fn clear_aggregates(&mut self) {
+        self.aggregates.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_aggregates(&mut self, v: ::std::vec::Vec<Thing_telemetry_aggregate_list>) {
+        self.aggregates = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_aggregates(&mut self) -> &mut ::std::vec::Vec<Thing_telemetry_aggregate_list> {
+        &mut self.aggregates
+    }
+
+    // Take field
+    pub fn take_aggregates(&mut self) -> ::std::vec::Vec<Thing_telemetry_aggregate_list> {
+        ::std::mem::replace(&mut self.aggregates, ::std::vec::Vec::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "thing_id",
+            |m: &Thing_telemetry_series| { &m.thing_id },
+            |m: &mut Thing_telemetry_series| { &mut m.thing_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "resolution",
+            |m: &Thing_telemetry_series| { &m.resolution },
+            |m: &mut Thing_telemetry_series| { &mut m.resolution },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "samples",
+            |m: &Thing_telemetry_series| { &m.samples },
+            |m: &mut Thing_telemetry_series| { &mut m.samples },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "aggregates",
+            |m: &Thing_telemetry_series| { &m.aggregates },
+            |m: &mut Thing_telemetry_series| { &mut m.aggregates },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Thing_telemetry_series>(
+            "thing_telemetry_series",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Thing_telemetry_series {
+    const NAME: &'static str = "thing_telemetry_series";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.thing_id = is.read_string()?;
+                },
+                16 => {
+                    self.resolution = is.read_enum_or_unknown()?;
+                },
+                26 => {
+                    self.samples.push(is.read_message()?);
+                },
+                34 => {
+                    self.aggregates.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.thing_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.thing_id);
+        }
+        if self.resolution != ::protobuf::EnumOrUnknown::new(Thing_telemetry_resolution::Raw) {
+            my_size += ::protobuf::rt::int32_size(2, self.resolution.value());
+        }
+        for value in &self.samples {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.aggregates {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.thing_id.is_empty() {
+            os.write_string(1, &self.thing_id)?;
+        }
+        if self.resolution != ::protobuf::EnumOrUnknown::new(Thing_telemetry_resolution::Raw) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.resolution))?;
+        }
+        for v in &self.samples {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
+        for v in &self.aggregates {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Thing_telemetry_series {
+        Thing_telemetry_series::new()
+    }
+
+    fn clear(&mut self) {
+        self.thing_id.clear();
+        self.resolution = ::protobuf::EnumOrUnknown::new(Thing_telemetry_resolution::Raw);
+        self.samples.clear();
+        self.aggregates.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Thing_telemetry_series {
+        static instance: Thing_telemetry_series = Thing_telemetry_series {
+            thing_id: ::std::string::String::new(),
+            resolution: ::protobuf::EnumOrUnknown::from_i32(0),
+            samples: ::std::vec::Vec::new(),
+            aggregates: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Thing_telemetry_series {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("thing_telemetry_series").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Thing_telemetry_series {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Thing_telemetry_series {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:thing_telemetry_resolution)
+pub enum Thing_telemetry_resolution {
+    // @@protoc_insertion_point(enum_value:thing_telemetry_resolution.Raw)
+    Raw = 0,
+    // @@protoc_insertion_point(enum_value:thing_telemetry_resolution.Minute)
+    Minute = 1,
+    // @@protoc_insertion_point(enum_value:thing_telemetry_resolution.Hour)
+    Hour = 2,
+    // @@protoc_insertion_point(enum_value:thing_telemetry_resolution.Day)
+    Day = 3,
+}
+
+impl ::protobuf::Enum for Thing_telemetry_resolution {
+    const NAME: &'static str = "thing_telemetry_resolution";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Thing_telemetry_resolution> {
+        match value {
+            0 => ::std::option::Option::Some(Thing_telemetry_resolution::Raw),
+            1 => ::std::option::Option::Some(Thing_telemetry_resolution::Minute),
+            2 => ::std::option::Option::Some(Thing_telemetry_resolution::Hour),
+            3 => ::std::option::Option::Some(Thing_telemetry_resolution::Day),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [Thing_telemetry_resolution] = &[
+        Thing_telemetry_resolution::Raw,
+        Thing_telemetry_resolution::Minute,
+        Thing_telemetry_resolution::Hour,
+        Thing_telemetry_resolution::Day,
+    ];
+}
+
+impl ::protobuf::EnumFull for Thing_telemetry_resolution {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("thing_telemetry_resolution").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for Thing_telemetry_resolution {
+    fn default() -> Self {
+        Thing_telemetry_resolution::Raw
+    }
+}
+
+impl Thing_telemetry_resolution {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Thing_telemetry_resolution>("thing_telemetry_resolution")
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0ftelemetry.proto\"\xc1\x01\n\x16thing_telemetry_sample\x12\x19\n\
+    \x08thing_id\x18\x01\x20\x01(\tR\x07thingId\x12\x1c\n\ttimestamp\x18\x02\
+    \x20\x01(\x04R\ttimestamp\x125\n\x04data\x18\x03\x20\x03(\x0b2!.thing_te\
+    lemetry_sample.DataEntryR\x04data\x1a7\n\tDataEntry\x12\x10\n\x03key\x18\
+    \x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\
+    \x028\x01\"\xe4\x01\n\x19thing_telemetry_aggregate\x12\x1a\n\x08property\
+    \x18\x01\x20\x01(\tR\x08property\x12\x14\n\x05begin\x18\x02\x20\x01(\x04\
+    R\x05begin\x12\x10\n\x03end\x18\x03\x20\x01(\x04R\x03end\x12\x14\n\x05co\
+    unt\x18\x04\x20\x01(\rR\x05count\x12\x10\n\x03min\x18\x05\x20\x01(\x01R\
+    \x03min\x12\x10\n\x03max\x18\x06\x20\x01(\x01R\x03max\x12\x10\n\x03avg\
+    \x18\x07\x20\x01(\x01R\x03avg\x12\x12\n\x04last\x18\x08\x20\x01(\tR\x04l\
+    ast\x12#\n\rnumeric_count\x18\t\x20\x01(\rR\x0cnumericCount\"\xca\x01\n\
+    \x1ething_telemetry_aggregate_list\x12\x19\n\x08thing_id\x18\x01\x20\x01\
+    (\tR\x07thingId\x12\x14\n\x05begin\x18\x02\x20\x01(\x04R\x05begin\x12;\n\
+    \nresolution\x18\x03\x20\x01(\x0e2\x1b.thing_telemetry_resolutionR\nreso\
+    lution\x12:\n\naggregates\x18\x04\x20\x03(\x0b2\x1a.thing_telemetry_aggr\
+    egateR\naggregates\"\xb7\x01\n\x15thing_telemetry_query\x12\x19\n\x08thi\
+    ng_id\x18\x01\x20\x01(\tR\x07thingId\x12\x14\n\x05begin\x18\x02\x20\x01(\
+    \x04R\x05begin\x12\x10\n\x03end\x18\x03\x20\x01(\x04R\x03end\x12;\n\nres\
+    olution\x18\x04\x20\x01(\x0e2\x1b.thing_telemetry_resolutionR\nresolutio\
+    n\x12\x1e\n\nproperties\x18\x05\x20\x03(\tR\nproperties\"\xe4\x01\n\x16t\
+    hing_telemetry_series\x12\x19\n\x08thing_id\x18\x01\x20\x01(\tR\x07thing\
+    Id\x12;\n\nresolution\x18\x02\x20\x01(\x0e2\x1b.thing_telemetry_resoluti\
+    onR\nresolution\x121\n\x07samples\x18\x03\x20\x03(\x0b2\x17.thing_teleme\
+    try_sampleR\x07samples\x12?\n\naggregates\x18\x04\x20\x03(\x0b2\x1f.thin\
+    g_telemetry_aggregate_listR\naggregates*D\n\x1athing_telemetry_resolutio\
+    n\x12\x07\n\x03Raw\x10\0\x12\n\n\x06Minute\x10\x01\x12\x08\n\x04Hour\x10\
+    \x02\x12\x07\n\x03Day\x10\x03b\x06proto3\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(0);
+            let mut messages = ::std::vec::Vec::with_capacity(5);
+            messages.push(Thing_telemetry_sample::generated_message_descriptor_data());
+            messages.push(Thing_telemetry_aggregate::generated_message_descriptor_data());
+            messages.push(Thing_telemetry_aggregate_list::generated_message_descriptor_data());
+            messages.push(Thing_telemetry_query::generated_message_descriptor_data());
+            messages.push(Thing_telemetry_series::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(1);
+            enums.push(Thing_telemetry_resolution::generated_enum_descriptor_data());
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
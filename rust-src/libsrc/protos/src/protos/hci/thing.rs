@@ -41,6 +41,8 @@ pub struct Thing_info {
     pub thing_name: ::std::string::String,
     // @@protoc_insertion_point(field:thing_info.mac_address)
     pub mac_address: ::std::string::String,
+    // @@protoc_insertion_point(field:thing_info.owner_id)
+    pub owner_id: ::std::string::String,
     // special fields
     // @@protoc_insertion_point(special_field:thing_info.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -213,8 +215,34 @@ impl Thing_info {
         ::std::mem::replace(&mut self.mac_address, ::std::string::String::new())
     }
 
+    // string owner_id = 7;
+
+    pub fn owner_id(&self) -> &str {
+        &self.owner_id
+    }
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: ::std::string::String) {
+        self.owner_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_owner_id(&mut self) -> &mut ::std::string::String {
+        &mut self.owner_id
+    }
+
+    // Take field
+    pub fn take_owner_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.owner_id, ::std::string::String::new())
+    }
+
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut fields = ::std::vec::Vec::with_capacity(7);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "brand_id",
@@ -246,6 +274,11 @@ impl Thing_info {
             |m: &Thing_info| { &m.mac_address },
             |m: &mut Thing_info| { &mut m.mac_address },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "owner_id",
+            |m: &Thing_info| { &m.owner_id },
+            |m: &mut Thing_info| { &mut m.owner_id },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Thing_info>(
             "thing_info",
             fields,
@@ -282,6 +315,9 @@ impl ::protobuf::Message for Thing_info {
                 50 => {
                     self.mac_address = is.read_string()?;
                 },
+                58 => {
+                    self.owner_id = is.read_string()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -312,6 +348,9 @@ impl ::protobuf::Message for Thing_info {
         if !self.mac_address.is_empty() {
             my_size += ::protobuf::rt::string_size(6, &self.mac_address);
         }
+        if !self.owner_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.owner_id);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -336,6 +375,9 @@ impl ::protobuf::Message for Thing_info {
         if !self.mac_address.is_empty() {
             os.write_string(6, &self.mac_address)?;
         }
+        if !self.owner_id.is_empty() {
+            os.write_string(7, &self.owner_id)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -359,6 +401,7 @@ impl ::protobuf::Message for Thing_info {
         self.thing_id.clear();
         self.thing_name.clear();
         self.mac_address.clear();
+        self.owner_id.clear();
         self.special_fields.clear();
     }
 
@@ -370,6 +413,7 @@ impl ::protobuf::Message for Thing_info {
             thing_id: ::std::string::String::new(),
             thing_name: ::std::string::String::new(),
             mac_address: ::std::string::String::new(),
+            owner_id: ::std::string::String::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -549,6 +593,10 @@ pub struct Thing_add {
     pub thing: ::protobuf::MessageField<Thing_info>,
     // @@protoc_insertion_point(field:thing_add.thing_data)
     pub thing_data: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    // @@protoc_insertion_point(field:thing_add.pairing_proof)
+    pub pairing_proof: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:thing_add.owner_override)
+    pub owner_override: bool,
     // special fields
     // @@protoc_insertion_point(special_field:thing_add.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -620,8 +668,49 @@ impl Thing_add {
         ::std::mem::replace(&mut self.thing_data, ::std::collections::HashMap::new())
     }
 
+    // bytes pairing_proof = 3;
+
+    pub fn pairing_proof(&self) -> &[u8] {
+        &self.pairing_proof
+    }
+
+    pub fn clear_pairing_proof(&mut self) {
+        self.pairing_proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pairing_proof(&mut self, v: ::std::vec::Vec<u8>) {
+        self.pairing_proof = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_pairing_proof(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.pairing_proof
+    }
+
+    // Take field
+    pub fn take_pairing_proof(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.pairing_proof, ::std::vec::Vec::new())
+    }
+
+    // bool owner_override = 4;
+
+    pub fn owner_override(&self) -> bool {
+        self.owner_override
+    }
+
+    pub fn clear_owner_override(&mut self) {
+        self.owner_override = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_override(&mut self, v: bool) {
+        self.owner_override = v;
+    }
+
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = ::std::vec::Vec::with_capacity(4);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Thing_info>(
             "thing",
@@ -633,6 +722,16 @@ impl Thing_add {
             |m: &Thing_add| { &m.thing_data },
             |m: &mut Thing_add| { &mut m.thing_data },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "pairing_proof",
+            |m: &Thing_add| { &m.pairing_proof },
+            |m: &mut Thing_add| { &mut m.pairing_proof },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "owner_override",
+            |m: &Thing_add| { &m.owner_override },
+            |m: &mut Thing_add| { &mut m.owner_override },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Thing_add>(
             "thing_add",
             fields,
@@ -669,6 +768,12 @@ impl ::protobuf::Message for Thing_add {
                     is.pop_limit(old_limit);
                     self.thing_data.insert(key, value);
                 },
+                26 => {
+                    self.pairing_proof = is.read_bytes()?;
+                },
+                32 => {
+                    self.owner_override = is.read_bool()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -691,6 +796,12 @@ impl ::protobuf::Message for Thing_add {
             entry_size += ::protobuf::rt::string_size(2, &v);
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
         };
+        if !self.pairing_proof.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.pairing_proof);
+        }
+        if self.owner_override != false {
+            my_size += 1 + 1;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -709,6 +820,12 @@ impl ::protobuf::Message for Thing_add {
             os.write_string(1, &k)?;
             os.write_string(2, &v)?;
         };
+        if !self.pairing_proof.is_empty() {
+            os.write_bytes(3, &self.pairing_proof)?;
+        }
+        if self.owner_override != false {
+            os.write_bool(4, self.owner_override)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -728,6 +845,8 @@ impl ::protobuf::Message for Thing_add {
     fn clear(&mut self) {
         self.thing.clear();
         self.thing_data.clear();
+        self.pairing_proof.clear();
+        self.owner_override = false;
         self.special_fields.clear();
     }
 
@@ -1138,22 +1257,579 @@ impl ::protobuf::reflect::ProtobufValue for Thing_query_all {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:thing_pairing)
+pub struct Thing_pairing {
+    // message fields
+    // @@protoc_insertion_point(field:thing_pairing.brand_id)
+    pub brand_id: ::std::string::String,
+    // @@protoc_insertion_point(field:thing_pairing.mac_address)
+    pub mac_address: ::std::string::String,
+    // @@protoc_insertion_point(field:thing_pairing.thing_data)
+    pub thing_data: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:thing_pairing.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Thing_pairing {
+    fn default() -> &'a Thing_pairing {
+        <Thing_pairing as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Thing_pairing {
+    pub fn new() -> Thing_pairing {
+        ::std::default::Default::default()
+    }
+
+    // string brand_id = 1;
+
+    pub fn brand_id(&self) -> &str {
+        &self.brand_id
+    }
+
+    pub fn clear_brand_id(&mut self) {
+        self.brand_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_brand_id(&mut self, v: ::std::string::String) {
+        self.brand_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_brand_id(&mut self) -> &mut ::std::string::String {
+        &mut self.brand_id
+    }
+
+    // Take field
+    pub fn take_brand_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.brand_id, ::std::string::String::new())
+    }
+
+    // string mac_address = 2;
+
+    pub fn mac_address(&self) -> &str {
+        &self.mac_address
+    }
+
+    pub fn clear_mac_address(&mut self) {
+        self.mac_address.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mac_address(&mut self, v: ::std::string::String) {
+        self.mac_address = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_mac_address(&mut self) -> &mut ::std::string::String {
+        &mut self.mac_address
+    }
+
+    // Take field
+    pub fn take_mac_address(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.mac_address, ::std::string::String::new())
+    }
+
+    // repeated .thing_pairing.ThingDataEntry thing_data = 3;
+
+    pub fn thing_data(&self) -> &::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &self.thing_data
+    }
+
+    pub fn clear_thing_data(&mut self) {
+        self.thing_data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_thing_data(&mut self, v: ::std::collections::HashMap<::std::string::String, ::std::string::String>) {
+        self.thing_data = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_thing_data(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &mut self.thing_data
+    }
+
+    // Take field
+    pub fn take_thing_data(&mut self) -> ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        ::std::mem::replace(&mut self.thing_data, ::std::collections::HashMap::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "brand_id",
+            |m: &Thing_pairing| { &m.brand_id },
+            |m: &mut Thing_pairing| { &mut m.brand_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "mac_address",
+            |m: &Thing_pairing| { &m.mac_address },
+            |m: &mut Thing_pairing| { &mut m.mac_address },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+            "thing_data",
+            |m: &Thing_pairing| { &m.thing_data },
+            |m: &mut Thing_pairing| { &mut m.thing_data },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Thing_pairing>(
+            "thing_pairing",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Thing_pairing {
+    const NAME: &'static str = "thing_pairing";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.brand_id = is.read_string()?;
+                },
+                18 => {
+                    self.mac_address = is.read_string()?;
+                },
+                26 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_string()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.thing_data.insert(key, value);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.brand_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.brand_id);
+        }
+        if !self.mac_address.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.mac_address);
+        }
+        for (k, v) in &self.thing_data {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.brand_id.is_empty() {
+            os.write_string(1, &self.brand_id)?;
+        }
+        if !self.mac_address.is_empty() {
+            os.write_string(2, &self.mac_address)?;
+        }
+        for (k, v) in &self.thing_data {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            os.write_raw_varint32(26)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Thing_pairing {
+        Thing_pairing::new()
+    }
+
+    fn clear(&mut self) {
+        self.brand_id.clear();
+        self.mac_address.clear();
+        self.thing_data.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Thing_pairing {
+        static instance: ::protobuf::rt::Lazy<Thing_pairing> = ::protobuf::rt::Lazy::new();
+        instance.get(Thing_pairing::new)
+    }
+}
+
+impl ::protobuf::MessageFull for Thing_pairing {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("thing_pairing").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Thing_pairing {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Thing_pairing {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:thing_pairing_proof)
+pub struct Thing_pairing_proof {
+    // message fields
+    // @@protoc_insertion_point(field:thing_pairing_proof.proof)
+    pub proof: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:thing_pairing_proof.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Thing_pairing_proof {
+    fn default() -> &'a Thing_pairing_proof {
+        <Thing_pairing_proof as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Thing_pairing_proof {
+    pub fn new() -> Thing_pairing_proof {
+        ::std::default::Default::default()
+    }
+
+    // bytes proof = 1;
+
+    pub fn proof(&self) -> &[u8] {
+        &self.proof
+    }
+
+    pub fn clear_proof(&mut self) {
+        self.proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_proof(&mut self, v: ::std::vec::Vec<u8>) {
+        self.proof = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_proof(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.proof
+    }
+
+    // Take field
+    pub fn take_proof(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.proof, ::std::vec::Vec::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "proof",
+            |m: &Thing_pairing_proof| { &m.proof },
+            |m: &mut Thing_pairing_proof| { &mut m.proof },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Thing_pairing_proof>(
+            "thing_pairing_proof",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Thing_pairing_proof {
+    const NAME: &'static str = "thing_pairing_proof";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.proof = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.proof.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.proof);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.proof.is_empty() {
+            os.write_bytes(1, &self.proof)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Thing_pairing_proof {
+        Thing_pairing_proof::new()
+    }
+
+    fn clear(&mut self) {
+        self.proof.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Thing_pairing_proof {
+        static instance: Thing_pairing_proof = Thing_pairing_proof {
+            proof: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Thing_pairing_proof {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("thing_pairing_proof").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Thing_pairing_proof {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Thing_pairing_proof {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:thing_pairing_key)
+pub struct Thing_pairing_key {
+    // message fields
+    // @@protoc_insertion_point(field:thing_pairing_key.public_key)
+    pub public_key: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:thing_pairing_key.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Thing_pairing_key {
+    fn default() -> &'a Thing_pairing_key {
+        <Thing_pairing_key as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Thing_pairing_key {
+    pub fn new() -> Thing_pairing_key {
+        ::std::default::Default::default()
+    }
+
+    // bytes public_key = 1;
+
+    pub fn public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+
+    pub fn clear_public_key(&mut self) {
+        self.public_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_public_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.public_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_public_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.public_key
+    }
+
+    // Take field
+    pub fn take_public_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.public_key, ::std::vec::Vec::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "public_key",
+            |m: &Thing_pairing_key| { &m.public_key },
+            |m: &mut Thing_pairing_key| { &mut m.public_key },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Thing_pairing_key>(
+            "thing_pairing_key",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Thing_pairing_key {
+    const NAME: &'static str = "thing_pairing_key";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.public_key = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.public_key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.public_key);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.public_key.is_empty() {
+            os.write_bytes(1, &self.public_key)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Thing_pairing_key {
+        Thing_pairing_key::new()
+    }
+
+    fn clear(&mut self) {
+        self.public_key.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Thing_pairing_key {
+        static instance: Thing_pairing_key = Thing_pairing_key {
+            public_key: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Thing_pairing_key {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("thing_pairing_key").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Thing_pairing_key {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Thing_pairing_key {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0bthing.proto\"\xd6\x01\n\nthing_info\x12\x19\n\x08brand_id\x18\x01\
+    \n\x0bthing.proto\"\xf1\x01\n\nthing_info\x12\x19\n\x08brand_id\x18\x01\
     \x20\x01(\tR\x07brandId\x12(\n\x10major_product_id\x18\x02\x20\x01(\tR\
     \x0emajorProductId\x12(\n\x10minor_product_id\x18\x03\x20\x01(\tR\x0emin\
     orProductId\x12\x19\n\x08thing_id\x18\x04\x20\x01(\tR\x07thingId\x12\x1d\
     \n\nthing_name\x18\x05\x20\x01(\tR\tthingName\x12\x1f\n\x0bmac_address\
-    \x18\x06\x20\x01(\tR\nmacAddress\"6\n\x0fthing_info_list\x12#\n\x06thing\
-    s\x18\x01\x20\x03(\x0b2\x0b.thing_infoR\x06things\"\xa6\x01\n\tthing_add\
-    \x12!\n\x05thing\x18\x01\x20\x01(\x0b2\x0b.thing_infoR\x05thing\x128\n\n\
-    thing_data\x18\x02\x20\x03(\x0b2\x19.thing_add.ThingDataEntryR\tthingDat\
+    \x18\x06\x20\x01(\tR\nmacAddress\x12\x19\n\x08owner_id\x18\x07\x20\x01(\
+    \tR\x07ownerId\"6\n\x0fthing_info_list\x12#\n\x06things\x18\x01\x20\x03(\
+    \x0b2\x0b.thing_infoR\x06things\"\xf2\x01\n\tthing_add\x12!\n\x05thing\
+    \x18\x01\x20\x01(\x0b2\x0b.thing_infoR\x05thing\x128\n\nthing_data\x18\
+    \x02\x20\x03(\x0b2\x19.thing_add.ThingDataEntryR\tthingData\x12#\n\rpair\
+    ing_proof\x18\x03\x20\x01(\x0cR\x0cpairingProof\x12%\n\x0eowner_override\
+    \x18\x04\x20\x01(\x08R\rownerOverride\x1a<\n\x0eThingDataEntry\x12\x10\n\
+    \x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\t\
+    R\x05value:\x028\x01\"E\n\x0bthing_query\x12\x19\n\x08thing_id\x18\x01\
+    \x20\x01(\tR\x07thingId\x12\x1b\n\tthing_mac\x18\x02\x20\x01(\tR\x08thin\
+    gMac\"K\n\x0fthing_query_all\x12\x19\n\x08brand_id\x18\x01\x20\x01(\tR\
+    \x07brandId\x12\x1d\n\nproduct_id\x18\x02\x20\x01(\tR\tproductId\"\xc7\
+    \x01\n\rthing_pairing\x12\x19\n\x08brand_id\x18\x01\x20\x01(\tR\x07brand\
+    Id\x12\x1f\n\x0bmac_address\x18\x02\x20\x01(\tR\nmacAddress\x12<\n\nthin\
+    g_data\x18\x03\x20\x03(\x0b2\x1d.thing_pairing.ThingDataEntryR\tthingDat\
     a\x1a<\n\x0eThingDataEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\
-    \x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\x01\"E\n\x0bthing\
-    _query\x12\x19\n\x08thing_id\x18\x01\x20\x01(\tR\x07thingId\x12\x1b\n\tt\
-    hing_mac\x18\x02\x20\x01(\tR\x08thingMac\"K\n\x0fthing_query_all\x12\x19\
-    \n\x08brand_id\x18\x01\x20\x01(\tR\x07brandId\x12\x1d\n\nproduct_id\x18\
-    \x02\x20\x01(\tR\tproductIdb\x06proto3\
+    \x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\x01\"+\n\x13thing\
+    _pairing_proof\x12\x14\n\x05proof\x18\x01\x20\x01(\x0cR\x05proof\"8\n\
+    \x11thing_pairing_key\x12\x1d\n\npublic_key\x18\x01\x20\x01(\x0cR\tpubli\
+    cKeyJ\x04\x08\x02\x10\x03b\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -1171,12 +1847,15 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
     file_descriptor.get(|| {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(0);
-            let mut messages = ::std::vec::Vec::with_capacity(5);
+            let mut messages = ::std::vec::Vec::with_capacity(8);
             messages.push(Thing_info::generated_message_descriptor_data());
             messages.push(Thing_info_list::generated_message_descriptor_data());
             messages.push(Thing_add::generated_message_descriptor_data());
             messages.push(Thing_query::generated_message_descriptor_data());
             messages.push(Thing_query_all::generated_message_descriptor_data());
+            messages.push(Thing_pairing::generated_message_descriptor_data());
+            messages.push(Thing_pairing_proof::generated_message_descriptor_data());
+            messages.push(Thing_pairing_key::generated_message_descriptor_data());
             let mut enums = ::std::vec::Vec::with_capacity(0);
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
                 file_descriptor_proto(),
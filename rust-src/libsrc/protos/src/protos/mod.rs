@@ -11,3 +11,5 @@ inner_impl_default_protobuf_raw_codec!(core_message::Dissubscribe_message);
 inner_impl_default_protobuf_raw_codec!(core_message::Dispatch_message);
 pub mod profile;
 inner_impl_default_protobuf_raw_codec!(profile::Data);
+inner_impl_default_protobuf_raw_codec!(profile::Series_data);
+inner_impl_default_protobuf_raw_codec!(profile::Series_range);
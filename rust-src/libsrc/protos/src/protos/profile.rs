@@ -217,9 +217,453 @@ impl ::protobuf::reflect::ProtobufValue for Data {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:series_data)
+pub struct Series_data {
+    // message fields
+    // @@protoc_insertion_point(field:series_data.key)
+    pub key: ::std::string::String,
+    // @@protoc_insertion_point(field:series_data.timestamp)
+    pub timestamp: u64,
+    // @@protoc_insertion_point(field:series_data.value)
+    pub value: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:series_data.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Series_data {
+    fn default() -> &'a Series_data {
+        <Series_data as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Series_data {
+    pub fn new() -> Series_data {
+        ::std::default::Default::default()
+    }
+
+    // string key = 1;
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+
+    // uint64 timestamp = 2;
+
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    pub fn clear_timestamp(&mut self) {
+        self.timestamp = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_timestamp(&mut self, v: u64) {
+        self.timestamp = v;
+    }
+
+    // bytes value = 3;
+
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "key",
+            |m: &Series_data| { &m.key },
+            |m: &mut Series_data| { &mut m.key },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "timestamp",
+            |m: &Series_data| { &m.timestamp },
+            |m: &mut Series_data| { &mut m.timestamp },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "value",
+            |m: &Series_data| { &m.value },
+            |m: &mut Series_data| { &mut m.value },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Series_data>(
+            "series_data",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Series_data {
+    const NAME: &'static str = "series_data";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.key = is.read_string()?;
+                },
+                16 => {
+                    self.timestamp = is.read_uint64()?;
+                },
+                26 => {
+                    self.value = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key);
+        }
+        if self.timestamp != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.timestamp);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.key.is_empty() {
+            os.write_string(1, &self.key)?;
+        }
+        if self.timestamp != 0 {
+            os.write_uint64(2, self.timestamp)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(3, &self.value)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Series_data {
+        Series_data::new()
+    }
+
+    fn clear(&mut self) {
+        self.key.clear();
+        self.timestamp = 0;
+        self.value.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Series_data {
+        static instance: Series_data = Series_data {
+            key: ::std::string::String::new(),
+            timestamp: 0,
+            value: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Series_data {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("series_data").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Series_data {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Series_data {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:series_range)
+pub struct Series_range {
+    // message fields
+    // @@protoc_insertion_point(field:series_range.key)
+    pub key: ::std::string::String,
+    // @@protoc_insertion_point(field:series_range.begin)
+    pub begin: u64,
+    // @@protoc_insertion_point(field:series_range.end)
+    pub end: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:series_range.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Series_range {
+    fn default() -> &'a Series_range {
+        <Series_range as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Series_range {
+    pub fn new() -> Series_range {
+        ::std::default::Default::default()
+    }
+
+    // string key = 1;
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+
+    // uint64 begin = 2;
+
+    pub fn begin(&self) -> u64 {
+        self.begin
+    }
+
+    pub fn clear_begin(&mut self) {
+        self.begin = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_begin(&mut self, v: u64) {
+        self.begin = v;
+    }
+
+    // uint64 end = 3;
+
+    pub fn end(&self) -> u64 {
+        self.end
+    }
+
+    pub fn clear_end(&mut self) {
+        self.end = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end(&mut self, v: u64) {
+        self.end = v;
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "key",
+            |m: &Series_range| { &m.key },
+            |m: &mut Series_range| { &mut m.key },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "begin",
+            |m: &Series_range| { &m.begin },
+            |m: &mut Series_range| { &mut m.begin },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "end",
+            |m: &Series_range| { &m.end },
+            |m: &mut Series_range| { &mut m.end },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Series_range>(
+            "series_range",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Series_range {
+    const NAME: &'static str = "series_range";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.key = is.read_string()?;
+                },
+                16 => {
+                    self.begin = is.read_uint64()?;
+                },
+                24 => {
+                    self.end = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key);
+        }
+        if self.begin != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.begin);
+        }
+        if self.end != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.end);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.key.is_empty() {
+            os.write_string(1, &self.key)?;
+        }
+        if self.begin != 0 {
+            os.write_uint64(2, self.begin)?;
+        }
+        if self.end != 0 {
+            os.write_uint64(3, self.end)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Series_range {
+        Series_range::new()
+    }
+
+    fn clear(&mut self) {
+        self.key.clear();
+        self.begin = 0;
+        self.end = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Series_range {
+        static instance: Series_range = Series_range {
+            key: ::std::string::String::new(),
+            begin: 0,
+            end: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Series_range {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("series_range").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Series_range {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Series_range {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\rprofile.proto\".\n\x04data\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03k\
-    ey\x12\x14\n\x05value\x18\x02\x20\x01(\x0cR\x05valueb\x06proto3\
+    ey\x12\x14\n\x05value\x18\x02\x20\x01(\x0cR\x05value\"S\n\x0bseries_data\
+    \x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x1c\n\ttimestamp\x18\
+    \x02\x20\x01(\x04R\ttimestamp\x12\x14\n\x05value\x18\x03\x20\x01(\x0cR\
+    \x05value\"H\n\x0cseries_range\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03k\
+    ey\x12\x14\n\x05begin\x18\x02\x20\x01(\x04R\x05begin\x12\x10\n\x03end\
+    \x18\x03\x20\x01(\x04R\x03endb\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -237,8 +681,10 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
     file_descriptor.get(|| {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(0);
-            let mut messages = ::std::vec::Vec::with_capacity(1);
+            let mut messages = ::std::vec::Vec::with_capacity(3);
             messages.push(Data::generated_message_descriptor_data());
+            messages.push(Series_data::generated_message_descriptor_data());
+            messages.push(Series_range::generated_message_descriptor_data());
             let mut enums = ::std::vec::Vec::with_capacity(0);
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
                 file_descriptor_proto(),
@@ -6,3 +6,5 @@ inner_impl_default_protobuf_raw_codec!(ping::Proof_of_ping);
 inner_impl_default_protobuf_raw_codec!(ping::Proof_of_ping_resp);
 inner_impl_default_protobuf_raw_codec!(ping::Proof_of_device);
 inner_impl_default_protobuf_raw_codec!(ping::Proof_of_device_resp);
+pub mod pairing;
+inner_impl_default_protobuf_raw_codec!(pairing::Proof_of_pairing);
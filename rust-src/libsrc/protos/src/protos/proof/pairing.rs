@@ -0,0 +1,421 @@
+// This file is generated by rust-protobuf 3.2.0. Do not edit
+// .proto file is parsed by pure
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `pairing.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_2_0;
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:proof_of_pairing)
+pub struct Proof_of_pairing {
+    // message fields
+    // @@protoc_insertion_point(field:proof_of_pairing.thing_id)
+    pub thing_id: ::std::string::String,
+    // @@protoc_insertion_point(field:proof_of_pairing.core_id)
+    pub core_id: ::std::string::String,
+    // @@protoc_insertion_point(field:proof_of_pairing.owner_id)
+    pub owner_id: ::std::string::String,
+    // @@protoc_insertion_point(field:proof_of_pairing.nonce)
+    pub nonce: ::std::string::String,
+    // @@protoc_insertion_point(field:proof_of_pairing.answer)
+    pub answer: ::std::string::String,
+    // @@protoc_insertion_point(field:proof_of_pairing.pair_time)
+    pub pair_time: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:proof_of_pairing.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Proof_of_pairing {
+    fn default() -> &'a Proof_of_pairing {
+        <Proof_of_pairing as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Proof_of_pairing {
+    pub fn new() -> Proof_of_pairing {
+        ::std::default::Default::default()
+    }
+
+    // string thing_id = 1;
+
+    pub fn thing_id(&self) -> &str {
+        &self.thing_id
+    }
+
+    pub fn clear_thing_id(&mut self) {
+        self.thing_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_thing_id(&mut self, v: ::std::string::String) {
+        self.thing_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_thing_id(&mut self) -> &mut ::std::string::String {
+        &mut self.thing_id
+    }
+
+    // Take field
+    pub fn take_thing_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.thing_id, ::std::string::String::new())
+    }
+
+    // string core_id = 2;
+
+    pub fn core_id(&self) -> &str {
+        &self.core_id
+    }
+
+    pub fn clear_core_id(&mut self) {
+        self.core_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_core_id(&mut self, v: ::std::string::String) {
+        self.core_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_core_id(&mut self) -> &mut ::std::string::String {
+        &mut self.core_id
+    }
+
+    // Take field
+    pub fn take_core_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.core_id, ::std::string::String::new())
+    }
+
+    // string owner_id = 3;
+
+    pub fn owner_id(&self) -> &str {
+        &self.owner_id
+    }
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: ::std::string::String) {
+        self.owner_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_owner_id(&mut self) -> &mut ::std::string::String {
+        &mut self.owner_id
+    }
+
+    // Take field
+    pub fn take_owner_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.owner_id, ::std::string::String::new())
+    }
+
+    // string nonce = 4;
+
+    pub fn nonce(&self) -> &str {
+        &self.nonce
+    }
+
+    pub fn clear_nonce(&mut self) {
+        self.nonce.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nonce(&mut self, v: ::std::string::String) {
+        self.nonce = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nonce(&mut self) -> &mut ::std::string::String {
+        &mut self.nonce
+    }
+
+    // Take field
+    pub fn take_nonce(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.nonce, ::std::string::String::new())
+    }
+
+    // string answer = 5;
+
+    pub fn answer(&self) -> &str {
+        &self.answer
+    }
+
+    pub fn clear_answer(&mut self) {
+        self.answer.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_answer(&mut self, v: ::std::string::String) {
+        self.answer = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_answer(&mut self) -> &mut ::std::string::String {
+        &mut self.answer
+    }
+
+    // Take field
+    pub fn take_answer(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.answer, ::std::string::String::new())
+    }
+
+    // uint64 pair_time = 6;
+
+    pub fn pair_time(&self) -> u64 {
+        self.pair_time
+    }
+
+    pub fn clear_pair_time(&mut self) {
+        self.pair_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pair_time(&mut self, v: u64) {
+        self.pair_time = v;
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "thing_id",
+            |m: &Proof_of_pairing| { &m.thing_id },
+            |m: &mut Proof_of_pairing| { &mut m.thing_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "core_id",
+            |m: &Proof_of_pairing| { &m.core_id },
+            |m: &mut Proof_of_pairing| { &mut m.core_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "owner_id",
+            |m: &Proof_of_pairing| { &m.owner_id },
+            |m: &mut Proof_of_pairing| { &mut m.owner_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "nonce",
+            |m: &Proof_of_pairing| { &m.nonce },
+            |m: &mut Proof_of_pairing| { &mut m.nonce },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "answer",
+            |m: &Proof_of_pairing| { &m.answer },
+            |m: &mut Proof_of_pairing| { &mut m.answer },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "pair_time",
+            |m: &Proof_of_pairing| { &m.pair_time },
+            |m: &mut Proof_of_pairing| { &mut m.pair_time },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Proof_of_pairing>(
+            "proof_of_pairing",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Proof_of_pairing {
+    const NAME: &'static str = "proof_of_pairing";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.thing_id = is.read_string()?;
+                },
+                18 => {
+                    self.core_id = is.read_string()?;
+                },
+                26 => {
+                    self.owner_id = is.read_string()?;
+                },
+                34 => {
+                    self.nonce = is.read_string()?;
+                },
+                42 => {
+                    self.answer = is.read_string()?;
+                },
+                48 => {
+                    self.pair_time = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.thing_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.thing_id);
+        }
+        if !self.core_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.core_id);
+        }
+        if !self.owner_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.owner_id);
+        }
+        if !self.nonce.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.nonce);
+        }
+        if !self.answer.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.answer);
+        }
+        if self.pair_time != 0 {
+            my_size += ::protobuf::rt::uint64_size(6, self.pair_time);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.thing_id.is_empty() {
+            os.write_string(1, &self.thing_id)?;
+        }
+        if !self.core_id.is_empty() {
+            os.write_string(2, &self.core_id)?;
+        }
+        if !self.owner_id.is_empty() {
+            os.write_string(3, &self.owner_id)?;
+        }
+        if !self.nonce.is_empty() {
+            os.write_string(4, &self.nonce)?;
+        }
+        if !self.answer.is_empty() {
+            os.write_string(5, &self.answer)?;
+        }
+        if self.pair_time != 0 {
+            os.write_uint64(6, self.pair_time)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Proof_of_pairing {
+        Proof_of_pairing::new()
+    }
+
+    fn clear(&mut self) {
+        self.thing_id.clear();
+        self.core_id.clear();
+        self.owner_id.clear();
+        self.nonce.clear();
+        self.answer.clear();
+        self.pair_time = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Proof_of_pairing {
+        static instance: Proof_of_pairing = Proof_of_pairing {
+            thing_id: ::std::string::String::new(),
+            core_id: ::std::string::String::new(),
+            owner_id: ::std::string::String::new(),
+            nonce: ::std::string::String::new(),
+            answer: ::std::string::String::new(),
+            pair_time: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Proof_of_pairing {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("proof_of_pairing").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Proof_of_pairing {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Proof_of_pairing {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\rpairing.proto\"\xac\x01\n\x10proof_of_pairing\x12\x19\n\x08thing_id\
+    \x18\x01\x20\x01(\tR\x07thingId\x12\x17\n\x07core_id\x18\x02\x20\x01(\tR\
+    \x06coreId\x12\x19\n\x08owner_id\x18\x03\x20\x01(\tR\x07ownerId\x12\x14\
+    \n\x05nonce\x18\x04\x20\x01(\tR\x05nonce\x12\x16\n\x06answer\x18\x05\x20\
+    \x01(\tR\x06answer\x12\x1b\n\tpair_time\x18\x06\x20\x01(\x04R\x08pairTim\
+    eb\x06proto3\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(0);
+            let mut messages = ::std::vec::Vec::with_capacity(1);
+            messages.push(Proof_of_pairing::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(0);
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
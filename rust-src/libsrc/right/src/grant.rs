@@ -0,0 +1,357 @@
+
+use near_base::{ObjectId, PrivateKey, Signature, Serialize, Deserialize, NearResult, NearError, ErrorCode, now, people::PeopleObject};
+
+use crate::{Role, Permission, RedeemObject};
+
+/// The role and the permissions of the grantee, it's issued by the home owner.
+#[derive(Clone)]
+pub struct Grant {
+    issuer: ObjectId,
+    grantee: ObjectId,
+    role: Role,
+    permissions: Vec<Permission>,
+    create_time: u64,
+    expired_time: Option<u64>,
+}
+
+impl Grant {
+    pub fn new(issuer: ObjectId, grantee: ObjectId, role: Role) -> Self {
+        Self {
+            issuer,
+            grantee,
+            role,
+            permissions: vec![],
+            create_time: now(),
+            expired_time: None,
+        }
+    }
+
+    pub fn add_permission(mut self, permission: Permission) -> Self {
+        self.permissions.push(permission);
+        self
+    }
+
+    pub fn set_expired_time(mut self, expired_time: Option<u64>) -> Self {
+        self.expired_time = expired_time;
+        self
+    }
+
+    pub fn issuer(&self) -> &ObjectId {
+        &self.issuer
+    }
+
+    pub fn grantee(&self) -> &ObjectId {
+        &self.grantee
+    }
+
+    pub fn role(&self) -> Role {
+        self.role
+    }
+
+    pub fn permissions(&self) -> &[Permission] {
+        self.permissions.as_slice()
+    }
+
+    pub fn create_time(&self) -> u64 {
+        self.create_time
+    }
+
+    pub fn expired_time(&self) -> Option<u64> {
+        self.expired_time
+    }
+
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expired_time.map(| expired_time | expired_time <= now).unwrap_or(false)
+    }
+
+    fn signer_buffer(&self) -> NearResult<Vec<u8>> {
+        let mut buf = vec![0u8; self.raw_capacity()];
+        let _ = self.serialize(&mut buf)?;
+        Ok(buf)
+    }
+
+    /// Sign it with the private key of the owner's people object.
+    pub fn sign(self, private_key: &PrivateKey) -> NearResult<GrantObject> {
+        let signature = private_key.sign(&self.signer_buffer()?)?;
+
+        Ok(GrantObject {
+            grant: self,
+            proof: GrantProof::Owner(signature),
+        })
+    }
+}
+
+impl std::fmt::Display for Grant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "issuer: {}, grantee: {}, role: {}, permissions: {}, expired_time: {:?}",
+            self.issuer, self.grantee, self.role, self.permissions.len(), self.expired_time)
+    }
+}
+
+impl Serialize for Grant {
+    fn raw_capacity(&self) -> usize {
+        self.issuer.raw_capacity() +
+        self.grantee.raw_capacity() +
+        self.role.raw_capacity() +
+        self.permissions.raw_capacity() +
+        self.create_time.raw_capacity() +
+        self.expired_time.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = self.issuer.serialize(buf)?;
+        let buf = self.grantee.serialize(buf)?;
+        let buf = self.role.serialize(buf)?;
+        let buf = self.permissions.serialize(buf)?;
+        let buf = self.create_time.serialize(buf)?;
+        let buf = self.expired_time.serialize(buf)?;
+
+        Ok(buf)
+    }
+}
+
+impl Deserialize for Grant {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (issuer, buf) = ObjectId::deserialize(buf)?;
+        let (grantee, buf) = ObjectId::deserialize(buf)?;
+        let (role, buf) = Role::deserialize(buf)?;
+        let (permissions, buf) = Vec::<Permission>::deserialize(buf)?;
+        let (create_time, buf) = u64::deserialize(buf)?;
+        let (expired_time, buf) = Option::<u64>::deserialize(buf)?;
+
+        Ok((Self {
+            issuer, grantee, role, permissions, create_time, expired_time,
+        }, buf))
+    }
+}
+
+const PROOF_OF_OWNER: u8     = 1u8;
+const PROOF_OF_INVITE: u8    = 2u8;
+
+#[derive(Clone)]
+enum GrantProof {
+    /// It's signed by the owner.
+    Owner(Signature),
+    /// The grantee redeemed the invite of the owner.
+    Invite(RedeemObject),
+}
+
+impl Serialize for GrantProof {
+    fn raw_capacity(&self) -> usize {
+        match self {
+            Self::Owner(signature) => PROOF_OF_OWNER.raw_capacity() + signature.raw_capacity(),
+            Self::Invite(redeem) => PROOF_OF_INVITE.raw_capacity() + redeem.raw_capacity(),
+        }
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        match self {
+            Self::Owner(signature) => {
+                let buf = PROOF_OF_OWNER.serialize(buf)?;
+                signature.serialize(buf)
+            }
+            Self::Invite(redeem) => {
+                let buf = PROOF_OF_INVITE.serialize(buf)?;
+                redeem.serialize(buf)
+            }
+        }
+    }
+}
+
+impl Deserialize for GrantProof {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (f, buf) = u8::deserialize(buf)?;
+
+        match f {
+            PROOF_OF_OWNER => {
+                let (signature, buf) = Signature::deserialize(buf)?;
+                Ok((Self::Owner(signature), buf))
+            }
+            PROOF_OF_INVITE => {
+                let (redeem, buf) = RedeemObject::deserialize(buf)?;
+                Ok((Self::Invite(redeem), buf))
+            }
+            _ => Err(NearError::new(ErrorCode::NEAR_ERROR_UNDEFINED, format!("undefined grant proof [{f}]."))),
+        }
+    }
+}
+
+/// The grant and the proof of its issuer.
+#[derive(Clone)]
+pub struct GrantObject {
+    grant: Grant,
+    proof: GrantProof,
+}
+
+impl GrantObject {
+    /// The grant of the people who redeemed the invite.
+    pub fn with_redeem(redeem: RedeemObject) -> Self {
+        Self {
+            grant: redeem.invite().to_grant(redeem.people().object_id().clone()),
+            proof: GrantProof::Invite(redeem),
+        }
+    }
+
+    pub fn grant(&self) -> &Grant {
+        &self.grant
+    }
+
+    /// The code of the invite if it's redeemed.
+    pub fn invite_code(&self) -> Option<&str> {
+        match &self.proof {
+            GrantProof::Owner(_) => None,
+            GrantProof::Invite(redeem) => Some(redeem.invite().code()),
+        }
+    }
+
+    /// It must be issued by the owner.
+    pub fn verify(&self, owner: &PeopleObject) -> NearResult<()> {
+        match &self.proof {
+            GrantProof::Owner(signature) => {
+                if self.grant.issuer() != owner.object_id() {
+                    return Err(NearError::new(ErrorCode::NEAR_ERROR_FORBIDDEN, format!("{} isn't the owner", self.grant.issuer())));
+                }
+
+                let public_key =
+                    owner.desc()
+                        .public_key()
+                        .ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_CRYPTO_INVALID_PUBKEY, format!("{} hasn't public key", owner.object_id())))?;
+
+                public_key.verify(&self.grant.signer_buffer()?, signature)
+            }
+            GrantProof::Invite(redeem) => {
+                redeem.verify(owner)?;
+
+                if redeem.people().object_id() != self.grant.grantee() || !redeem.invite().is_granted(&self.grant) {
+                    return Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("the grant of {} isn't the invite", self.grant.grantee())));
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for GrantObject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.proof {
+            GrantProof::Owner(_) => write!(f, "grant: {}, sign: {{***}}", self.grant),
+            GrantProof::Invite(redeem) => write!(f, "grant: {}, invite: {}", self.grant, redeem.invite().code()),
+        }
+    }
+}
+
+impl Serialize for GrantObject {
+    fn raw_capacity(&self) -> usize {
+        self.grant.raw_capacity() + self.proof.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = self.grant.serialize(buf)?;
+        let buf = self.proof.serialize(buf)?;
+
+        Ok(buf)
+    }
+}
+
+impl Deserialize for GrantObject {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (grant, buf) = Grant::deserialize(buf)?;
+        let (proof, buf) = GrantProof::deserialize(buf)?;
+
+        Ok((Self { grant, proof }, buf))
+    }
+}
+
+#[test]
+fn test_grant_verify() {
+    use near_base::{ObjectBuilder, people::{PeopleDescContent, PeopleBodyContent}};
+
+    let people = | private_key: &PrivateKey | -> PeopleObject {
+        ObjectBuilder::new(PeopleDescContent::new(), PeopleBodyContent::default())
+            .update_desc(| desc | desc.set_public_key(private_key.public()))
+            .build()
+            .unwrap()
+    };
+
+    let owner_key = PrivateKey::generate_rsa1024().unwrap();
+    let owner = people(&owner_key);
+    let other_key = PrivateKey::generate_rsa1024().unwrap();
+    let other = people(&other_key);
+
+    let grant =
+        Grant::new(owner.object_id().clone(), other.object_id().clone(), Role::Member)
+            .sign(&owner_key)
+            .unwrap();
+    grant.verify(&owner).unwrap();
+
+    // the grant is kept after it's encoded.
+    let mut buf = vec![0u8; grant.raw_capacity()];
+    let _ = grant.serialize(&mut buf).unwrap();
+    let (decoded, _) = GrantObject::deserialize(&buf).unwrap();
+    decoded.verify(&owner).unwrap();
+    assert_eq!(decoded.grant().role(), Role::Member);
+
+    // it isn't issued by the owner.
+    assert!(grant.verify(&other).is_err());
+
+    // it's signed by the other people in the name of the owner.
+    let forged =
+        Grant::new(owner.object_id().clone(), other.object_id().clone(), Role::Member)
+            .sign(&other_key)
+            .unwrap();
+    assert!(forged.verify(&owner).is_err());
+
+    assert!(Grant::new(owner.object_id().clone(), other.object_id().clone(), Role::Guest)
+                .set_expired_time(Some(10))
+                .is_expired(10));
+}
+
+#[test]
+fn test_grant_of_invite() {
+    use near_base::{ObjectBuilder, people::{PeopleDescContent, PeopleBodyContent}};
+
+    use crate::Invite;
+
+    let people = | private_key: &PrivateKey | -> PeopleObject {
+        ObjectBuilder::new(PeopleDescContent::new(), PeopleBodyContent::default())
+            .update_desc(| desc | desc.set_public_key(private_key.public()))
+            .build()
+            .unwrap()
+    };
+
+    let owner_key = PrivateKey::generate_rsa1024().unwrap();
+    let owner = people(&owner_key);
+    let member_key = PrivateKey::generate_rsa1024().unwrap();
+    let member = people(&member_key);
+
+    let invite = Invite::new(owner.object_id().clone(), Role::Member, now() + 60_000_000).sign(&owner_key).unwrap();
+    let redeem = RedeemObject::new(invite, member.clone(), &member_key).unwrap();
+
+    let grant = GrantObject::with_redeem(redeem.clone());
+    grant.verify(&owner).unwrap();
+    assert_eq!(grant.grant().grantee(), member.object_id());
+    assert_eq!(grant.invite_code(), Some(redeem.invite().code()));
+
+    let mut buf = vec![0u8; grant.raw_capacity()];
+    let _ = grant.serialize(&mut buf).unwrap();
+    let (decoded, _) = GrantObject::deserialize(&buf).unwrap();
+    decoded.verify(&owner).unwrap();
+
+    // the grant is raised above the invite.
+    let raised = GrantObject {
+        grant: Grant::new(owner.object_id().clone(), member.object_id().clone(), Role::Owner),
+        proof: GrantProof::Invite(redeem.clone()),
+    };
+    assert!(raised.verify(&owner).is_err());
+
+    // the grant is given to the other people.
+    let stolen = GrantObject {
+        grant: redeem.invite().to_grant(owner.object_id().clone()),
+        proof: GrantProof::Invite(redeem),
+    };
+    assert!(stolen.verify(&owner).is_err());
+}
@@ -0,0 +1,334 @@
+
+use near_base::{ObjectId, PrivateKey, Signature, Serialize, Deserialize, NearResult, NearError, ErrorCode, now, hash_data, people::PeopleObject};
+
+use crate::{Role, Permission, Grant};
+
+/// The owner invites the people to the home, the invite is redeemed once before it's expired.
+#[derive(Clone)]
+pub struct Invite {
+    issuer: ObjectId,
+    code: String,
+    role: Role,
+    permissions: Vec<Permission>,
+    create_time: u64,
+    expired_time: u64,
+    grant_expired_time: Option<u64>,
+}
+
+impl Invite {
+    pub fn new(issuer: ObjectId, role: Role, expired_time: u64) -> Self {
+        let create_time = now();
+        // the invites which are created at the same time have the different codes.
+        let nonce: [u8; 16] = rand::random();
+
+        Self {
+            code: hash_data([issuer.as_ref().as_slice(), create_time.to_be_bytes().as_slice(), nonce.as_slice()].concat().as_slice()).to_hex_string(),
+            issuer,
+            role,
+            permissions: vec![],
+            create_time,
+            expired_time,
+            grant_expired_time: None,
+        }
+    }
+
+    pub fn add_permission(mut self, permission: Permission) -> Self {
+        self.permissions.push(permission);
+        self
+    }
+
+    /// The grant of the redeemer is expired at the time.
+    pub fn set_grant_expired_time(mut self, grant_expired_time: Option<u64>) -> Self {
+        self.grant_expired_time = grant_expired_time;
+        self
+    }
+
+    pub fn issuer(&self) -> &ObjectId {
+        &self.issuer
+    }
+
+    pub fn code(&self) -> &str {
+        self.code.as_str()
+    }
+
+    pub fn role(&self) -> Role {
+        self.role
+    }
+
+    pub fn permissions(&self) -> &[Permission] {
+        self.permissions.as_slice()
+    }
+
+    pub fn expired_time(&self) -> u64 {
+        self.expired_time
+    }
+
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expired_time <= now
+    }
+
+    /// The grant of the redeemer.
+    pub(crate) fn to_grant(&self, grantee: ObjectId) -> Grant {
+        let grant =
+            self.permissions
+                .iter()
+                .fold(Grant::new(self.issuer.clone(), grantee, self.role), | grant, permission | grant.add_permission(permission.clone()));
+
+        grant.set_expired_time(self.grant_expired_time)
+    }
+
+    /// Is the grant created by the invite.
+    pub(crate) fn is_granted(&self, grant: &Grant) -> bool {
+        grant.issuer() == &self.issuer &&
+        grant.role() == self.role &&
+        grant.permissions() == self.permissions.as_slice() &&
+        grant.expired_time() == self.grant_expired_time
+    }
+
+    fn signer_buffer(&self) -> NearResult<Vec<u8>> {
+        let mut buf = vec![0u8; self.raw_capacity()];
+        let _ = self.serialize(&mut buf)?;
+        Ok(buf)
+    }
+
+    /// Sign it with the private key of the owner's people object.
+    pub fn sign(self, private_key: &PrivateKey) -> NearResult<InviteObject> {
+        let signature = private_key.sign(&self.signer_buffer()?)?;
+
+        Ok(InviteObject {
+            invite: self,
+            signature,
+        })
+    }
+}
+
+impl std::fmt::Display for Invite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "issuer: {}, code: {}, role: {}, permissions: {}, expired_time: {}",
+            self.issuer, self.code, self.role, self.permissions.len(), self.expired_time)
+    }
+}
+
+impl Serialize for Invite {
+    fn raw_capacity(&self) -> usize {
+        self.issuer.raw_capacity() +
+        self.code.raw_capacity() +
+        self.role.raw_capacity() +
+        self.permissions.raw_capacity() +
+        self.create_time.raw_capacity() +
+        self.expired_time.raw_capacity() +
+        self.grant_expired_time.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = self.issuer.serialize(buf)?;
+        let buf = self.code.serialize(buf)?;
+        let buf = self.role.serialize(buf)?;
+        let buf = self.permissions.serialize(buf)?;
+        let buf = self.create_time.serialize(buf)?;
+        let buf = self.expired_time.serialize(buf)?;
+        let buf = self.grant_expired_time.serialize(buf)?;
+
+        Ok(buf)
+    }
+}
+
+impl Deserialize for Invite {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (issuer, buf) = ObjectId::deserialize(buf)?;
+        let (code, buf) = String::deserialize(buf)?;
+        let (role, buf) = Role::deserialize(buf)?;
+        let (permissions, buf) = Vec::<Permission>::deserialize(buf)?;
+        let (create_time, buf) = u64::deserialize(buf)?;
+        let (expired_time, buf) = u64::deserialize(buf)?;
+        let (grant_expired_time, buf) = Option::<u64>::deserialize(buf)?;
+
+        Ok((Self {
+            issuer, code, role, permissions, create_time, expired_time, grant_expired_time,
+        }, buf))
+    }
+}
+
+/// The invite and the signature of the owner.
+#[derive(Clone)]
+pub struct InviteObject {
+    invite: Invite,
+    signature: Signature,
+}
+
+impl InviteObject {
+    pub fn invite(&self) -> &Invite {
+        &self.invite
+    }
+
+    /// It must be issued and signed by the owner.
+    pub fn verify(&self, owner: &PeopleObject) -> NearResult<()> {
+        if self.invite.issuer() != owner.object_id() {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_FORBIDDEN, format!("{} isn't the owner", self.invite.issuer())));
+        }
+
+        let public_key =
+            owner.desc()
+                .public_key()
+                .ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_CRYPTO_INVALID_PUBKEY, format!("{} hasn't public key", owner.object_id())))?;
+
+        public_key.verify(&self.invite.signer_buffer()?, &self.signature)
+    }
+}
+
+impl std::fmt::Display for InviteObject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invite: {}, sign: {{***}}", self.invite)
+    }
+}
+
+impl Serialize for InviteObject {
+    fn raw_capacity(&self) -> usize {
+        self.invite.raw_capacity() + self.signature.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = self.invite.serialize(buf)?;
+        let buf = self.signature.serialize(buf)?;
+
+        Ok(buf)
+    }
+}
+
+impl Deserialize for InviteObject {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (invite, buf) = Invite::deserialize(buf)?;
+        let (signature, buf) = Signature::deserialize(buf)?;
+
+        Ok((Self { invite, signature }, buf))
+    }
+}
+
+/// The people redeems the invite, it's signed by the people.
+#[derive(Clone)]
+pub struct RedeemObject {
+    invite: InviteObject,
+    people: PeopleObject,
+    signature: Signature,
+}
+
+impl RedeemObject {
+    pub fn new(invite: InviteObject, people: PeopleObject, private_key: &PrivateKey) -> NearResult<Self> {
+        let signature = private_key.sign(&invite.invite.signer_buffer()?)?;
+
+        Ok(Self {
+            invite,
+            people,
+            signature,
+        })
+    }
+
+    pub fn invite(&self) -> &Invite {
+        &self.invite.invite
+    }
+
+    pub fn people(&self) -> &PeopleObject {
+        &self.people
+    }
+
+    /// The invite is signed by the owner, and it's redeemed by the people.
+    pub fn verify(&self, owner: &PeopleObject) -> NearResult<()> {
+        self.invite.verify(owner)?;
+
+        let public_key =
+            self.people
+                .desc()
+                .public_key()
+                .ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_CRYPTO_INVALID_PUBKEY, format!("{} hasn't public key", self.people.object_id())))?;
+
+        public_key.verify(&self.invite.invite.signer_buffer()?, &self.signature)
+    }
+}
+
+impl std::fmt::Display for RedeemObject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invite: {}, people: {}, sign: {{***}}", self.invite.invite, self.people.object_id())
+    }
+}
+
+impl Serialize for RedeemObject {
+    fn raw_capacity(&self) -> usize {
+        self.invite.raw_capacity() + self.people.raw_capacity() + self.signature.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = self.invite.serialize(buf)?;
+        let buf = self.people.serialize(buf)?;
+        let buf = self.signature.serialize(buf)?;
+
+        Ok(buf)
+    }
+}
+
+impl Deserialize for RedeemObject {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (invite, buf) = InviteObject::deserialize(buf)?;
+        let (people, buf) = PeopleObject::deserialize(buf)?;
+        let (signature, buf) = Signature::deserialize(buf)?;
+
+        Ok((Self { invite, people, signature }, buf))
+    }
+}
+
+#[test]
+fn test_invite() {
+    use near_base::{ObjectBuilder, people::{PeopleDescContent, PeopleBodyContent}};
+
+    use crate::{Scope, Privilege};
+
+    let people = | private_key: &PrivateKey | -> PeopleObject {
+        ObjectBuilder::new(PeopleDescContent::new(), PeopleBodyContent::default())
+            .update_desc(| desc | desc.set_public_key(private_key.public()))
+            .build()
+            .unwrap()
+    };
+
+    let owner_key = PrivateKey::generate_rsa1024().unwrap();
+    let owner = people(&owner_key);
+    let guest_key = PrivateKey::generate_rsa1024().unwrap();
+    let guest = people(&guest_key);
+
+    let invite = | | {
+        Invite::new(owner.object_id().clone(), Role::Guest, now() + 60_000_000)
+            .add_permission(Permission::new(Scope::Thing("t1".to_owned()), Privilege::Control))
+            .set_grant_expired_time(Some(now() + 3_600_000_000))
+    };
+
+    // the codes are unique even if the invites are created at once.
+    let codes: Vec<String> = (0..8).map(| _ | invite().code().to_owned()).collect();
+    assert!(codes.iter().enumerate().all(| (i, code) | codes.iter().skip(i + 1).all(| other | other != code)));
+
+    let signed = invite().sign(&owner_key).unwrap();
+    signed.verify(&owner).unwrap();
+    // it isn't issued by the guest.
+    assert!(signed.verify(&guest).is_err());
+    // the guest signs it in the name of the owner.
+    assert!(invite().sign(&guest_key).unwrap().verify(&owner).is_err());
+
+    let redeem = RedeemObject::new(signed.clone(), guest.clone(), &guest_key).unwrap();
+    redeem.verify(&owner).unwrap();
+
+    let mut buf = vec![0u8; redeem.raw_capacity()];
+    let _ = redeem.serialize(&mut buf).unwrap();
+    let (decoded, _) = RedeemObject::deserialize(&buf).unwrap();
+    decoded.verify(&owner).unwrap();
+    assert_eq!(decoded.invite().code(), signed.invite().code());
+
+    // it's signed by the other people.
+    assert!(RedeemObject::new(signed.clone(), guest.clone(), &owner_key).unwrap().verify(&owner).is_err());
+
+    let grant = signed.invite().to_grant(guest.object_id().clone());
+    assert!(signed.invite().is_granted(&grant));
+    assert!(!signed.invite().is_granted(&Grant::new(owner.object_id().clone(), guest.object_id().clone(), Role::Member)));
+
+    assert!(signed.invite().is_expired(signed.invite().expired_time()));
+    assert!(!signed.invite().is_expired(now()));
+}
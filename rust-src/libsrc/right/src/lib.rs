@@ -1,13 +1,88 @@
 
 mod stack;
+mod role;
+mod grant;
+mod invite;
 
 pub use stack::Stack as RightStack;
+pub use stack::check_message_type;
+pub use role::{Role, Scope, Permission};
+pub use grant::{Grant, GrantObject};
+pub use invite::{Invite, InviteObject, RedeemObject};
+
+use near_base::{people::PeopleObject, ObjectId, Serialize, Deserialize, RawFixedBytes, NearResult, NearError, ErrorCode};
 
 #[derive(Clone)]
 pub struct RightStackConfig {
+    /// The people object of the home owner, it's found by the owner of the core device if it's none.
+    pub owner: Option<PeopleObject>,
+    /// The privilege required by the topics, the longest prefix is matched.
+    pub topic_privileges: Vec<(String, Privilege)>,
+    /// The privilege required by the topic which isn't matched, it's Control by default.
+    /// None: any granted role passes it, and the service which owns the topic checks the rest.
+    pub default_privilege: Option<Privilege>,
+    /// The runtime services which have the service role, they're loaded from the services file
+    /// of the data path if it's empty. The other runtime services haven't any role.
+    pub services: Vec<ObjectId>,
+}
 
+impl Default for RightStackConfig {
+    fn default() -> Self {
+        Self {
+            owner: None,
+            topic_privileges: vec![],
+            default_privilege: Some(Privilege::Control),
+            services: vec![],
+        }
+    }
 }
 
+/// The higher privilege includes the lower ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Privilege {
+    Read = 1,
+    Control = 2,
+    Manage = 3,
+}
+
+impl TryFrom<u8> for Privilege {
+    type Error = NearError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Read),
+            2 => Ok(Self::Control),
+            3 => Ok(Self::Manage),
+            _ => Err(NearError::new(ErrorCode::NEAR_ERROR_UNDEFINED, format!("undefined {value} privilege")))
+        }
+    }
+}
+
+impl std::fmt::Display for Privilege {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read => write!(f, "read"),
+            Self::Control => write!(f, "control"),
+            Self::Manage => write!(f, "manage"),
+        }
+    }
+}
+
+impl Serialize for Privilege {
+    fn raw_capacity(&self) -> usize {
+        u8::raw_bytes()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        (*self as u8).serialize(buf)
+    }
+}
+
+impl Deserialize for Privilege {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (v, buf) = u8::deserialize(buf)?;
 
+        Ok((Self::try_from(v)?, buf))
+    }
 }
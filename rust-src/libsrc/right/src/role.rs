@@ -0,0 +1,211 @@
+
+use near_base::{Serialize, Deserialize, RawFixedBytes, NearResult, NearError, ErrorCode};
+
+use crate::Privilege;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    /// The people who owns the home, it's granted all.
+    Owner = 1,
+    /// It could read and control all of the things.
+    Member = 2,
+    /// It could only do what its permissions allow.
+    Guest = 3,
+    /// The devices and the services of the home.
+    Service = 4,
+}
+
+impl Role {
+    /// The privilege of the role on all of the topics and the things.
+    pub fn privilege(&self) -> Option<Privilege> {
+        match self {
+            Self::Owner | Self::Service => Some(Privilege::Manage),
+            Self::Member => Some(Privilege::Control),
+            Self::Guest => None,
+        }
+    }
+}
+
+impl TryFrom<u8> for Role {
+    type Error = NearError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Owner),
+            2 => Ok(Self::Member),
+            3 => Ok(Self::Guest),
+            4 => Ok(Self::Service),
+            _ => Err(NearError::new(ErrorCode::NEAR_ERROR_UNDEFINED, format!("undefined {value} role")))
+        }
+    }
+}
+
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Owner => write!(f, "owner"),
+            Self::Member => write!(f, "member"),
+            Self::Guest => write!(f, "guest"),
+            Self::Service => write!(f, "service"),
+        }
+    }
+}
+
+impl Serialize for Role {
+    fn raw_capacity(&self) -> usize {
+        u8::raw_bytes()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        (*self as u8).serialize(buf)
+    }
+}
+
+impl Deserialize for Role {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (v, buf) = u8::deserialize(buf)?;
+
+        Ok((Self::try_from(v)?, buf))
+    }
+}
+
+const SCOPE_OF_ALL: u8      = 1u8;
+const SCOPE_OF_TOPIC: u8    = 2u8;
+const SCOPE_OF_THING: u8    = 3u8;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Scope {
+    All,
+    /// The topic and the topics under it.
+    Topic(String),
+    /// The thing id.
+    Thing(String),
+}
+
+impl Scope {
+    pub fn matches(&self, topic: &str, thing_id: Option<&str>) -> bool {
+        match self {
+            Self::All => true,
+            Self::Topic(prefix) => {
+                topic == prefix ||
+                (topic.starts_with(prefix.as_str()) && (prefix.ends_with('/') || topic[prefix.len()..].starts_with('/')))
+            }
+            Self::Thing(id) => thing_id.map(| thing_id | thing_id == id).unwrap_or(false),
+        }
+    }
+}
+
+impl std::fmt::Display for Scope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::All => write!(f, "all"),
+            Self::Topic(topic) => write!(f, "topic: {topic}"),
+            Self::Thing(thing_id) => write!(f, "thing: {thing_id}"),
+        }
+    }
+}
+
+impl Serialize for Scope {
+    fn raw_capacity(&self) -> usize {
+        match self {
+            Self::All => SCOPE_OF_ALL.raw_capacity(),
+            Self::Topic(topic) => SCOPE_OF_TOPIC.raw_capacity() + topic.raw_capacity(),
+            Self::Thing(thing_id) => SCOPE_OF_THING.raw_capacity() + thing_id.raw_capacity(),
+        }
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        match self {
+            Self::All => SCOPE_OF_ALL.serialize(buf),
+            Self::Topic(topic) => {
+                let buf = SCOPE_OF_TOPIC.serialize(buf)?;
+                topic.serialize(buf)
+            }
+            Self::Thing(thing_id) => {
+                let buf = SCOPE_OF_THING.serialize(buf)?;
+                thing_id.serialize(buf)
+            }
+        }
+    }
+}
+
+impl Deserialize for Scope {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (f, buf) = u8::deserialize(buf)?;
+
+        match f {
+            SCOPE_OF_ALL => Ok((Self::All, buf)),
+            SCOPE_OF_TOPIC => {
+                let (topic, buf) = String::deserialize(buf)?;
+                Ok((Self::Topic(topic), buf))
+            }
+            SCOPE_OF_THING => {
+                let (thing_id, buf) = String::deserialize(buf)?;
+                Ok((Self::Thing(thing_id), buf))
+            }
+            _ => Err(NearError::new(ErrorCode::NEAR_ERROR_UNDEFINED, format!("undefined scope [{f}]."))),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Permission {
+    pub scope: Scope,
+    pub privilege: Privilege,
+}
+
+impl Permission {
+    pub fn new(scope: Scope, privilege: Privilege) -> Self {
+        Self { scope, privilege }
+    }
+
+    pub fn allows(&self, topic: &str, thing_id: Option<&str>, required: Privilege) -> bool {
+        self.privilege >= required && self.scope.matches(topic, thing_id)
+    }
+}
+
+impl std::fmt::Display for Permission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} on {}", self.privilege, self.scope)
+    }
+}
+
+impl Serialize for Permission {
+    fn raw_capacity(&self) -> usize {
+        self.scope.raw_capacity() + self.privilege.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = self.scope.serialize(buf)?;
+        let buf = self.privilege.serialize(buf)?;
+
+        Ok(buf)
+    }
+}
+
+impl Deserialize for Permission {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (scope, buf) = Scope::deserialize(buf)?;
+        let (privilege, buf) = Privilege::deserialize(buf)?;
+
+        Ok((Self { scope, privilege }, buf))
+    }
+}
+
+#[test]
+fn test_permission() {
+    let p = Permission::new(Scope::Topic("/near/thing/gateway".to_owned()), Privilege::Control);
+
+    assert!(p.allows("/near/thing/gateway/ctrl", None, Privilege::Read));
+    assert!(p.allows("/near/thing/gateway", None, Privilege::Control));
+    assert!(!p.allows("/near/thing/gatewayx/ctrl", None, Privilege::Read));
+    assert!(!p.allows("/near/thing/gateway/ctrl", None, Privilege::Manage));
+
+    let p = Permission::new(Scope::Thing("t1".to_owned()), Privilege::Control);
+    assert!(p.allows("/near/thing/gateway/ctrl", Some("t1"), Privilege::Control));
+    assert!(!p.allows("/near/thing/gateway/ctrl", Some("t2"), Privilege::Control));
+    assert!(!p.allows("/near/thing/gateway/ctrl", None, Privilege::Control));
+}
@@ -1,19 +1,55 @@
 
-use std::sync::Arc;
+use std::{sync::{Arc, RwLock}, collections::HashMap, path::PathBuf, str::FromStr, time::SystemTime};
 
-use near_base::NearResult;
+use log::{error, info, warn};
+
+use base::MessageType;
+use near_base::{NearResult, NearError, ErrorCode, ObjectId, ObjectTypeCode, FileDecoder, FileEncoder, now, people::PeopleObject};
+use near_core::{get_data_path, get_service_path};
 use near_transport::{Stack as BaseStack};
+use near_util::Topic;
 
-use crate::RightStackConfig;
+use crate::{RightStackConfig, Privilege, Role, Grant, GrantObject, RedeemObject};
 
-struct StackComponents {
+const GRANTS_FILE: &'static str = "grants";
+const REDEEMED_FILE: &'static str = "redeemed";
+/// The allow-list of the runtime services, one object id in each line.
+const SERVICES_FILE: &'static str = "services";
+
+/// Only the devices and the services could call the private topics.
+pub fn check_message_type(requestor: &ObjectId, topic: &Topic, mt: MessageType) -> NearResult<()> {
+    match mt {
+        MessageType::Public => Ok(()),
+        MessageType::Private => {
+            if let ObjectTypeCode::People = requestor.object_type_code()? {
+                Err(NearError::new(ErrorCode::NEAR_ERROR_FORBIDDEN, format!("{topic} is forbbiden topic.")))
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
 
+#[derive(Default)]
+struct Grants {
+    /// The modified time of the grants file when it's loaded.
+    modified: Option<SystemTime>,
+    entries: HashMap<ObjectId, GrantObject>,
+}
+
+struct StackComponents {
+    owner: Option<PeopleObject>,
+    services: Vec<ObjectId>,
+    grants: RwLock<Grants>,
+    redeemed: RwLock<Vec<String>>,
 }
 
 struct StackImpl {
     service_name: String,
     stack: BaseStack,
     config: RightStackConfig,
+    grants_path: PathBuf,
+    redeemed_path: PathBuf,
 
     components: Option<StackComponents>,
 }
@@ -27,9 +63,26 @@ impl Stack {
             service_name: service_name.to_owned(),
             stack,
             config,
+            // the grants are shared by the services of the home, and they're written by the core.
+            grants_path: get_data_path().join(GRANTS_FILE),
+            redeemed_path: get_service_path(service_name).join(REDEEMED_FILE),
             components: None,
         }));
 
+        ret.migrate_grants();
+
+        let owner = ret.load_owner();
+
+        let components = StackComponents {
+            grants: RwLock::new(owner.as_ref().map(| owner | ret.load_grants(owner)).unwrap_or_default()),
+            redeemed: RwLock::new(ret.load_redeemed()),
+            services: ret.load_services(),
+            owner,
+        };
+
+        let mut_ret = unsafe { &mut *(Arc::as_ptr(&ret.0) as *mut StackImpl) };
+        mut_ret.components = Some(components);
+
         ret
     }
 
@@ -49,12 +102,528 @@ impl Stack {
         &self.0.config
     }
 
+    #[inline]
+    fn components(&self) -> &StackComponents {
+        self.0.components.as_ref().unwrap()
+    }
+
+    pub fn owner(&self) -> Option<&ObjectId> {
+        self.components().owner.as_ref().map(| owner | owner.object_id())
+    }
+
 }
 
 impl Stack {
-    pub fn grant(&self) -> NearResult<()> {
-        unimplemented!()
+    fn load_owner(&self) -> Option<PeopleObject> {
+        if let Some(owner) = self.config().owner.as_ref() {
+            return Some(owner.clone());
+        }
+
+        let owner_id = self.base_stack().core_device().desc().owner().cloned()?;
+
+        let dir =
+            std::fs::read_dir(get_data_path())
+                .map_err(| e | error!("failed read {} with err: {e}", get_data_path().display()))
+                .ok()?;
+
+        let owner =
+            dir.filter_map(| file | file.ok())
+                .filter(| file | file.path().extension().map(| ext | ext.eq_ignore_ascii_case("desc")).unwrap_or(false))
+                .filter_map(| file | PeopleObject::decode_from_file(file.path().as_path()).ok())
+                .find(| people | people.object_id() == &owner_id);
+
+        match owner.as_ref() {
+            Some(_) => info!("{} is the owner", owner_id),
+            None => warn!("Not found the people object of the owner {owner_id}, the grants are disabled."),
+        }
+
+        owner
+    }
+
+    /// The services file is shared by the services of the home, and it's written when the runtime services are installed.
+    fn load_services(&self) -> Vec<ObjectId> {
+        if !self.config().services.is_empty() {
+            return self.config().services.clone();
+        }
+
+        let path = get_data_path().join(SERVICES_FILE);
+
+        let content =
+            match std::fs::read_to_string(path.as_path()) {
+                Ok(content) => content,
+                Err(e) => {
+                    warn!("failed read {} with err: {e}, none of the runtime services is trusted.", path.display());
+                    return vec![];
+                }
+            };
+
+        content.lines()
+            .map(| line | line.trim())
+            .filter(| line | !line.is_empty() && !line.starts_with('#'))
+            .filter_map(| line | {
+                ObjectId::from_str(line)
+                    .map_err(| e | error!("ignore the service {line} in {} with err: {e}", path.display()))
+                    .ok()
+            })
+            .collect()
+    }
+
+    /// The grants file was kept in the service path of the core, it's moved to the data path
+    /// which is shared by the services of the home.
+    fn migrate_grants(&self) {
+        if !self.base_stack().is_core() || self.0.grants_path.exists() {
+            return;
+        }
+
+        let legacy_path = get_service_path(self.service_name()).join(GRANTS_FILE);
+
+        if !legacy_path.exists() {
+            return;
+        }
+
+        match std::fs::rename(legacy_path.as_path(), self.0.grants_path.as_path())
+                .or_else(| _ | {
+                    std::fs::copy(legacy_path.as_path(), self.0.grants_path.as_path())
+                        .and_then(| _ | std::fs::remove_file(legacy_path.as_path()))
+                }) {
+            Ok(_) => info!("moved {} to {}", legacy_path.display(), self.0.grants_path.display()),
+            Err(e) => error!("failed move {} to {} with err: {e}", legacy_path.display(), self.0.grants_path.display()),
+        }
+    }
+
+    fn grants_modified(&self) -> Option<SystemTime> {
+        std::fs::metadata(self.0.grants_path.as_path())
+            .and_then(| meta | meta.modified())
+            .ok()
+    }
+
+    fn load_grants(&self, owner: &PeopleObject) -> Grants {
+        if !self.0.grants_path.exists() {
+            return Grants::default();
+        }
+
+        let modified = self.grants_modified();
+
+        match Vec::<GrantObject>::decode_from_file(self.0.grants_path.as_path()) {
+            Ok(grants) => {
+                Grants {
+                    modified,
+                    entries: grants.into_iter()
+                                .filter(| grant | {
+                                    grant.verify(owner)
+                                        .map_err(| e | error!("ignore the grant of {} with err: {e}", grant.grant().grantee()))
+                                        .is_ok()
+                                })
+                                .map(| grant | (grant.grant().grantee().clone(), grant))
+                                .collect(),
+                }
+            }
+            Err(e) => {
+                error!("failed load {} with err: {e}", self.0.grants_path.display());
+                Grants { modified, entries: HashMap::new() }
+            }
+        }
     }
 
-    
+    fn save_grants(&self, grants: &mut Grants) -> NearResult<()> {
+        grants.entries
+            .values()
+            .cloned()
+            .collect::<Vec<GrantObject>>()
+            .encode_to_file(self.0.grants_path.as_path(), false)
+            .map(| _ | ())
+            .map_err(| e | {
+                error!("failed save {} with err: {e}", self.0.grants_path.display());
+                e
+            })?;
+
+        grants.modified = self.grants_modified();
+
+        Ok(())
+    }
+
+    /// The grants file is written by the core, so the other services reload it when it's changed,
+    /// then the revoked people is refused at once.
+    fn refresh_grants(&self) {
+        let owner = match self.components().owner.as_ref() {
+            Some(owner) => owner,
+            None => { return; }
+        };
+
+        if self.base_stack().is_core() {
+            return;
+        }
+
+        let modified = self.grants_modified();
+
+        if self.components().grants.read().unwrap().modified == modified {
+            return;
+        }
+
+        info!("reload {}", self.0.grants_path.display());
+
+        *self.components().grants.write().unwrap() = self.load_grants(owner);
+    }
+
+    fn load_redeemed(&self) -> Vec<String> {
+        if !self.0.redeemed_path.exists() {
+            return vec![];
+        }
+
+        Vec::<String>::decode_from_file(self.0.redeemed_path.as_path())
+            .map_err(| e | error!("failed load {} with err: {e}", self.0.redeemed_path.display()))
+            .unwrap_or_default()
+    }
+}
+
+impl Stack {
+    /// Accept the grant which is signed by the owner, it replaces the older grant of the grantee.
+    pub fn grant(&self, grant: GrantObject) -> NearResult<()> {
+        let owner =
+            self.components()
+                .owner
+                .as_ref()
+                .ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_UNINITIALIZED, "Not found the owner"))?;
+
+        grant.verify(owner)?;
+
+        if grant.grant().is_expired(now()) {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_EXPIRED, format!("the grant of {} is expired", grant.grant().grantee())));
+        }
+
+        let grants = &mut *self.components().grants.write().unwrap();
+
+        if let Some(exist) = grants.entries.get(grant.grant().grantee()) {
+            if exist.grant().create_time() > grant.grant().create_time() {
+                return Err(NearError::new(ErrorCode::NEAR_ERROR_EXPIRED, format!("{} has newer grant", grant.grant().grantee())));
+            }
+        }
+
+        info!("grant {}", grant);
+
+        grants.entries.insert(grant.grant().grantee().clone(), grant);
+        self.save_grants(grants)
+    }
+
+    /// The people redeems the invite of the owner, and it's granted the role of the invite.
+    /// Every invite is redeemed only once.
+    pub fn redeem(&self, redeem: RedeemObject) -> NearResult<GrantObject> {
+        let owner =
+            self.components()
+                .owner
+                .as_ref()
+                .ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_UNINITIALIZED, "Not found the owner"))?;
+
+        let redeemed = &mut *self.components().redeemed.write().unwrap();
+
+        check_redeem(owner, &redeem, redeemed, now())?;
+
+        let code = redeem.invite().code().to_owned();
+        let grant = GrantObject::with_redeem(redeem);
+
+        {
+            let grants = &mut *self.components().grants.write().unwrap();
+
+            info!("redeem {}", grant);
+
+            grants.entries.insert(grant.grant().grantee().clone(), grant.clone());
+            self.save_grants(grants)?;
+        }
+
+        redeemed.push(code);
+        redeemed.encode_to_file(self.0.redeemed_path.as_path(), false)
+            .map_err(| e | {
+                error!("failed save {} with err: {e}", self.0.redeemed_path.display());
+                e
+            })?;
+
+        Ok(grant)
+    }
+
+    pub fn revoke(&self, grantee: &ObjectId) -> NearResult<()> {
+        let grants = &mut *self.components().grants.write().unwrap();
+
+        grants.entries
+            .remove(grantee)
+            .ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_NOTFOUND, format!("Not found the grant of {grantee}")))?;
+
+        info!("revoke {grantee}");
+
+        self.save_grants(grants)
+    }
+
+    pub fn grants(&self) -> Vec<GrantObject> {
+        self.refresh_grants();
+
+        self.components().grants.read().unwrap().entries.values().cloned().collect()
+    }
+
+    /// The people who are granted and their grants aren't expired.
+    pub fn members(&self) -> Vec<GrantObject> {
+        let now = now();
+
+        self.grants()
+            .into_iter()
+            .filter(| grant | !grant.grant().is_expired(now))
+            .collect()
+    }
+
+    /// The owner and the granted people have their roles, the runtime services and the devices of the home are the services.
+    pub fn role_of(&self, requestor: &ObjectId) -> NearResult<Option<Role>> {
+        match requestor.object_type_code()? {
+            ObjectTypeCode::People => {},
+            _ => {
+                let base_stack = self.base_stack();
+                return service_role_of(requestor, &self.components().services, &[base_stack.core_device().object_id(), base_stack.local_device_id()]);
+            }
+        }
+
+        if self.owner() == Some(requestor) {
+            return Ok(Some(Role::Owner));
+        }
+
+        self.refresh_grants();
+
+        Ok(self.components()
+               .grants
+               .read().unwrap()
+               .entries
+               .get(requestor)
+               .filter(| grant | !grant.grant().is_expired(now()))
+               .map(| grant | grant.grant().role()))
+    }
+
+    /// The privilege which is required by the topic.
+    pub fn privilege_of(&self, topic: &Topic) -> Option<Privilege> {
+        required_privilege(self.config(), topic.topic().as_str())
+    }
+
+    /// It must be called before the request is dispatched, the thing id is given if the request is on the thing.
+    /// The people is refused if the home hasn't owner.
+    pub fn check(&self, requestor: &ObjectId, topic: &Topic, mt: MessageType, thing_id: Option<&str>) -> NearResult<()> {
+        check_message_type(requestor, topic, mt)?;
+
+        let required = self.privilege_of(topic);
+        let role = self.role_of(requestor)?;
+
+        let allowed = {
+            let grants = self.components().grants.read().unwrap();
+            is_allowed(role, grants.entries.get(requestor).map(| grant | grant.grant()), topic.topic(), thing_id, required)
+        };
+
+        if allowed {
+            Ok(())
+        } else {
+            match required {
+                Some(required) => Err(NearError::new(ErrorCode::NEAR_ERROR_FORBIDDEN, format!("{requestor} hasn't {required} privilege of {topic}"))),
+                None => Err(NearError::new(ErrorCode::NEAR_ERROR_FORBIDDEN, format!("{requestor} hasn't any role of {topic}"))),
+            }
+        }
+    }
+}
+
+/// The invite is signed by the owner and it isn't expired, it's redeemed once by the people except the owner.
+fn check_redeem(owner: &PeopleObject, redeem: &RedeemObject, redeemed: &[String], now: u64) -> NearResult<()> {
+    redeem.verify(owner)?;
+
+    let invite = redeem.invite();
+
+    if invite.is_expired(now) {
+        return Err(NearError::new(ErrorCode::NEAR_ERROR_EXPIRED, format!("the invite {} is expired", invite.code())));
+    }
+
+    match invite.role() {
+        Role::Member | Role::Guest => {},
+        _ => { return Err(NearError::new(ErrorCode::NEAR_ERROR_FORBIDDEN, format!("{} couldn't be invited", invite.role()))); }
+    }
+
+    if redeem.people().object_id() == owner.object_id() {
+        return Err(NearError::new(ErrorCode::NEAR_ERROR_FORBIDDEN, "the owner couldn't redeem the invite"));
+    }
+
+    if redeemed.iter().any(| code | code == invite.code()) {
+        return Err(NearError::new(ErrorCode::NEAR_ERROR_ALREADY_EXIST, format!("the invite {} has been redeemed", invite.code())));
+    }
+
+    Ok(())
+}
+
+/// Only the allowed runtime services and the given devices of the home are the services.
+fn service_role_of(requestor: &ObjectId, services: &[ObjectId], devices: &[&ObjectId]) -> NearResult<Option<Role>> {
+    match requestor.object_type_code()? {
+        ObjectTypeCode::Extention => Ok(services.contains(requestor).then_some(Role::Service)),
+        ObjectTypeCode::People => Ok(None),
+        _ => Ok(devices.iter().any(| device | *device == requestor).then_some(Role::Service)),
+    }
+}
+
+/// The longest prefix is matched, and the default privilege is required if none is matched.
+fn required_privilege(config: &RightStackConfig, topic: &str) -> Option<Privilege> {
+    config.topic_privileges
+        .iter()
+        .filter(| (prefix, _) | crate::Scope::Topic(prefix.clone()).matches(topic, None))
+        .max_by_key(| (prefix, _) | prefix.len())
+        .map(| (_, privilege) | *privilege)
+        .or(config.default_privilege)
+}
+
+/// The role gives its privilege on all, otherwise the grant has to allow the topic or the thing.
+/// The requestor which hasn't role is refused.
+fn is_allowed(role: Option<Role>, grant: Option<&Grant>, topic: &str, thing_id: Option<&str>, required: Option<Privilege>) -> bool {
+    if role.is_none() {
+        return false;
+    }
+
+    let required = match required {
+        Some(required) => required,
+        None => { return true; }
+    };
+
+    if role.and_then(| role | role.privilege()).map(| privilege | privilege >= required).unwrap_or(false) {
+        return true;
+    }
+
+    grant.map(| grant | {
+            grant.permissions()
+                .iter()
+                .any(| permission | permission.allows(topic, thing_id, required))
+        })
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod test {
+    use near_base::{ObjectBuilder, ObjectId, PrivateKey, DeviceObject, ExtentionObject,
+                    device::{DeviceDescContent, DeviceBodyContent},
+                    extention::{ExtentionDescContent, ExtentionBodyContent},
+                    people::{PeopleObject, PeopleDescContent, PeopleBodyContent}};
+
+    use crate::{RightStackConfig, Privilege, Role, Grant, Permission, Scope, Invite, RedeemObject};
+
+    fn people_with_key(private_key: &PrivateKey) -> PeopleObject {
+        ObjectBuilder::new(PeopleDescContent::new(), PeopleBodyContent::default())
+            .update_desc(| desc | desc.set_public_key(private_key.public()))
+            .build()
+            .unwrap()
+    }
+
+    fn people() -> PeopleObject {
+        people_with_key(&PrivateKey::generate_rsa1024().unwrap())
+    }
+
+    fn device() -> DeviceObject {
+        ObjectBuilder::new(DeviceDescContent::with_device(1), DeviceBodyContent::default())
+            .update_desc(| desc | desc.set_public_key(PrivateKey::generate_rsa1024().unwrap().public()))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_service_role_of() {
+        let extention: ExtentionObject =
+            ObjectBuilder::new(ExtentionDescContent::default(), ExtentionBodyContent::default())
+                .build()
+                .unwrap();
+        let other_extention: ExtentionObject =
+            ObjectBuilder::new({
+                    let mut desc = ExtentionDescContent::default();
+                    desc.set_extention_name("other");
+                    desc
+                }, ExtentionBodyContent::default())
+                .build()
+                .unwrap();
+        let core = device();
+        let other = device();
+        let people = people();
+
+        let services = [extention.object_id().clone()];
+        let devices = [core.object_id()];
+
+        assert!(extention.object_id() != other_extention.object_id());
+        assert_eq!(super::service_role_of(extention.object_id(), &services, &devices).unwrap(), Some(Role::Service));
+        assert_eq!(super::service_role_of(other_extention.object_id(), &services, &devices).unwrap(), None);
+        assert_eq!(super::service_role_of(extention.object_id(), &[], &devices).unwrap(), None);
+        assert_eq!(super::service_role_of(core.object_id(), &services, &devices).unwrap(), Some(Role::Service));
+        assert_eq!(super::service_role_of(other.object_id(), &services, &devices).unwrap(), None);
+        assert_eq!(super::service_role_of(people.object_id(), &services, &devices).unwrap(), None);
+    }
+
+    #[test]
+    fn test_required_privilege() {
+        let mut config = RightStackConfig {
+            topic_privileges: vec![
+                ("/near/thing/gateway".to_owned(), Privilege::Control),
+                ("/near/thing/gateway/search".to_owned(), Privilege::Manage),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(super::required_privilege(&config, "/near/thing/gateway/ctrl"), Some(Privilege::Control));
+        assert_eq!(super::required_privilege(&config, "/near/thing/gateway/search/result"), Some(Privilege::Manage));
+        assert_eq!(super::required_privilege(&config, "/near/thing/storage"), Some(Privilege::Control));
+
+        config.default_privilege = None;
+        assert_eq!(super::required_privilege(&config, "/near/thing/storage"), None);
+    }
+
+    #[test]
+    fn test_is_allowed() {
+        let topic = "/near/thing/gateway/ctrl";
+        let grant =
+            Grant::new(ObjectId::default(), ObjectId::default(), Role::Guest)
+                .add_permission(Permission::new(Scope::Thing("t1".to_owned()), Privilege::Control));
+
+        // the requestor without role is refused, even if nothing is required.
+        assert!(!super::is_allowed(None, None, topic, None, None));
+        assert!(!super::is_allowed(None, Some(&grant), topic, Some("t1"), Some(Privilege::Read)));
+
+        assert!(super::is_allowed(Some(Role::Owner), None, topic, None, Some(Privilege::Manage)));
+        assert!(super::is_allowed(Some(Role::Service), None, topic, None, Some(Privilege::Manage)));
+        assert!(super::is_allowed(Some(Role::Member), None, topic, None, Some(Privilege::Control)));
+        assert!(!super::is_allowed(Some(Role::Member), None, topic, None, Some(Privilege::Manage)));
+
+        assert!(super::is_allowed(Some(Role::Guest), None, topic, None, None));
+        assert!(!super::is_allowed(Some(Role::Guest), None, topic, None, Some(Privilege::Read)));
+        assert!(super::is_allowed(Some(Role::Guest), Some(&grant), topic, Some("t1"), Some(Privilege::Control)));
+        assert!(!super::is_allowed(Some(Role::Guest), Some(&grant), topic, Some("t2"), Some(Privilege::Control)));
+        assert!(!super::is_allowed(Some(Role::Guest), Some(&grant), topic, Some("t1"), Some(Privilege::Manage)));
+    }
+
+    #[test]
+    fn test_check_redeem() {
+        let owner_key = PrivateKey::generate_rsa1024().unwrap();
+        let owner = people_with_key(&owner_key);
+        let guest_key = PrivateKey::generate_rsa1024().unwrap();
+        let guest = people_with_key(&guest_key);
+        let now = near_base::now();
+
+        let invite =
+            Invite::new(owner.object_id().clone(), Role::Guest, now + 60_000_000)
+                .sign(&owner_key)
+                .unwrap();
+        let redeem = RedeemObject::new(invite.clone(), guest.clone(), &guest_key).unwrap();
+
+        super::check_redeem(&owner, &redeem, &[], now).unwrap();
+
+        // every invite is redeemed once.
+        assert!(super::check_redeem(&owner, &redeem, &[redeem.invite().code().to_owned()], now).is_err());
+
+        // it's expired.
+        assert!(super::check_redeem(&owner, &redeem, &[], now + 60_000_000).is_err());
+
+        // it isn't issued by the owner.
+        assert!(super::check_redeem(&people(), &redeem, &[], now).is_err());
+
+        // the owner redeems its invite.
+        let redeem = RedeemObject::new(invite, owner.clone(), &owner_key).unwrap();
+        assert!(super::check_redeem(&owner, &redeem, &[], now).is_err());
+
+        // the owner couldn't be invited.
+        let invite =
+            Invite::new(owner.object_id().clone(), Role::Owner, now + 60_000_000)
+                .sign(&owner_key)
+                .unwrap();
+        let redeem = RedeemObject::new(invite, guest, &guest_key).unwrap();
+        assert!(super::check_redeem(&owner, &redeem, &[], now).is_err());
+    }
 }
@@ -1,11 +1,15 @@
 pub mod sqlite_storage;
 
-use near_base::{Deserialize, NearResult, Serialize};
+use near_base::{Deserialize, NearResult, Serialize, Timestamp};
 
 pub trait ItemTrait: Serialize + Deserialize {
     fn id(&self) -> &str;
 }
 
+pub trait SeriesItemTrait: ItemTrait {
+    fn timestamp(&self) -> Timestamp;
+}
+
 // trait StorageCloneTrait<T: ItemTrait + Send + Sync> {
 //     fn clone_as_storage(&self) -> Box<dyn StorageTrait<T>>;
 // }
@@ -27,3 +31,15 @@ pub trait StorageTransactionTrait<T: ItemTrait + Send + Sync>: StorageTrait<T> {
     async fn rollback(&mut self) -> NearResult<()>;
     async fn commit(&mut self) -> NearResult<()>;
 }
+
+/// Append-only storage for timestamped items, each row is indexed by (id, timestamp).
+#[async_trait::async_trait]
+pub trait SeriesStorageTrait<T: SeriesItemTrait + Send + Sync>: Send + Sync {
+
+    async fn append(&self, data: &T) -> NearResult<()>;
+    /// Load the items in [begin, end), all of ids will be loaded when the prefix is None.
+    async fn load_range(&self, prefix: Option<&str>, begin: Timestamp, end: Timestamp) -> NearResult<Vec<T>>;
+    async fn delete_with_prefix(&self, prefix: &str) -> NearResult<()>;
+    /// Remove all of items which are older than the timestamp.
+    async fn delete_before(&self, end: Timestamp) -> NearResult<()>;
+}
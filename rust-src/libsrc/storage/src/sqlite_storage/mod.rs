@@ -1,5 +1,6 @@
 
 mod storage;
+mod series;
 mod sqlmap;
 
 pub use storage::SqliteStorage;
@@ -14,3 +15,15 @@ pub enum StorageType {
     Update = 4,
     Delete = 5,
 }
+
+#[repr(u16)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SeriesStorageType {
+    Init = 0,
+    Index = 1,
+    QueryRange = 2,
+    QueryRangeAll = 3,
+    Append = 4,
+    Delete = 5,
+    DeleteBefore = 6,
+}
@@ -0,0 +1,156 @@
+
+use std::sync::Arc;
+
+use log::error;
+use near_base::{NearResult, Timestamp};
+
+use dataagent_util::Helper;
+use protos::profile::{Series_data, Series_range};
+
+use crate::{
+    sqlite_storage::{sqlmap::SeriesSqlmapBuild, SeriesStorageType},
+    SeriesItemTrait, SeriesStorageTrait,
+};
+
+struct SqliteSeriesStorageImpl<T> {
+    helper: Arc<Helper>,
+    name: String,
+    _marker: std::marker::PhantomData<T>,
+}
+
+#[derive(Clone)]
+pub(super) struct SqliteSeriesStorage<T>(Arc<SqliteSeriesStorageImpl<T>>);
+
+impl<T> SqliteSeriesStorage<T> {
+    pub fn new(helper: Arc<Helper>, name: &str) -> Self {
+        Self(Arc::new(SqliteSeriesStorageImpl {
+            helper,
+            name: name.to_owned(),
+            _marker: Default::default(),
+        }))
+    }
+
+    fn sql_key(&self, mode: SeriesStorageType) -> String {
+        SeriesSqlmapBuild {
+            name: &self.0.name,
+            mode,
+        }
+        .build_sql_key()
+    }
+}
+
+#[allow(non_snake_case)]
+mod EncodeAndDecode {
+    use crate::SeriesItemTrait;
+    use log::error;
+    use near_base::NearResult;
+
+    pub fn try_encode(data: &impl SeriesItemTrait) -> NearResult<protos::profile::Series_data> {
+        Ok(protos::profile::Series_data {
+            key: data.id().to_owned(),
+            timestamp: data.timestamp(),
+            value: {
+                let mut buff = vec![0u8; data.raw_capacity()];
+                let _ = data.serialize(&mut buff).map_err(|e| {
+                    error!("failed serialize [{}] with err: {e}", data.id());
+                    e
+                })?;
+                buff
+            },
+            ..Default::default()
+        })
+    }
+
+    pub fn try_decode<T: SeriesItemTrait>(data: &protos::profile::Series_data) -> NearResult<T> {
+        T::deserialize(data.value()).map(|(v, _)| v).map_err(|e| {
+            error!("failed deserialize [{}] at {} with err: {e}", data.key(), data.timestamp());
+            e
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: SeriesItemTrait + Send + Sync + Clone + 'static> SeriesStorageTrait<T> for SqliteSeriesStorage<T> {
+
+    async fn append(&self, data: &T) -> NearResult<()> {
+        let sqlmap = self.sql_key(SeriesStorageType::Append);
+
+        self.0.helper
+            .execute_with_param(&sqlmap, &EncodeAndDecode::try_encode(data)?)
+            .await
+            .map_err(|e| {
+                error!("failed append [{}] with err: {e}", data.id());
+                e
+            })
+    }
+
+    async fn load_range(&self, prefix: Option<&str>, begin: Timestamp, end: Timestamp) -> NearResult<Vec<T>> {
+        let (sqlmap, key) =
+            match prefix {
+                Some(prefix) => (self.sql_key(SeriesStorageType::QueryRange), prefix.to_owned()),
+                None => (self.sql_key(SeriesStorageType::QueryRangeAll), String::default()),
+            };
+
+        let dataes = self
+            .0.helper
+            .query_all_with_param::<Series_data>(
+                &sqlmap,
+                Series_range {
+                    key,
+                    begin,
+                    end,
+                    ..Default::default()
+                },
+            )
+            .await
+            .map_err(|e| {
+                error!("failed load range [{begin}, {end}) data with err: {e}");
+                e
+            })?;
+
+        let mut ret = vec![];
+        for data in dataes {
+            if let Ok(v) = EncodeAndDecode::try_decode(&data) {
+                ret.push(v);
+            }
+        }
+
+        Ok(ret)
+    }
+
+    async fn delete_with_prefix(&self, prefix: &str) -> NearResult<()> {
+        let sqlmap = self.sql_key(SeriesStorageType::Delete);
+
+        self.0.helper
+            .execute_with_param(
+                &sqlmap,
+                &Series_range {
+                    key: prefix.to_owned(),
+                    ..Default::default()
+                },
+            )
+            .await
+            .map_err(|e| {
+                error!("failed delete [{prefix}] with err: {e}");
+                e
+            })
+    }
+
+    async fn delete_before(&self, end: Timestamp) -> NearResult<()> {
+        let sqlmap = self.sql_key(SeriesStorageType::DeleteBefore);
+
+        self.0.helper
+            .execute_with_param(
+                &sqlmap,
+                &Series_range {
+                    end,
+                    ..Default::default()
+                },
+            )
+            .await
+            .map_err(|e| {
+                error!("failed delete before {end} with err: {e}");
+                e
+            })
+    }
+}
@@ -1,5 +1,5 @@
 
-use super::StorageType;
+use super::{StorageType, SeriesStorageType};
 
 pub(super) struct SqlmapBuild<'a> {
     pub(super) name: &'a str,
@@ -60,3 +60,73 @@ impl SqlmapBuild<'_> {
         }
     }
 }
+
+pub(super) struct SeriesSqlmapBuild<'a> {
+    pub(super) name: &'a str,
+    pub(super) mode: SeriesStorageType,
+}
+
+impl SeriesSqlmapBuild<'_> {
+    pub(super) fn build_sql_key(self, ) -> String {
+        match &self.mode {
+            SeriesStorageType::Init => format!("init_series_{}_key", self.name),
+            SeriesStorageType::Index => format!("index_series_{}_key", self.name),
+            SeriesStorageType::QueryRange => format!("queryrange_series_{}_key", self.name),
+            SeriesStorageType::QueryRangeAll => format!("queryrangeall_series_{}_key", self.name),
+            SeriesStorageType::Append => format!("append_series_{}_key", self.name),
+            SeriesStorageType::Delete => format!("delete_series_{}_key", self.name),
+            SeriesStorageType::DeleteBefore => format!("deletebefore_series_{}_key", self.name),
+        }
+    }
+
+    pub(super) fn build(self) -> (String, Option<String>, Option<String>, String) {
+        let mode = self.mode;
+        let name = self.name;
+
+        let key = self.build_sql_key();
+        match mode {
+            SeriesStorageType::Init => {
+                let sql = 
+                    format!(r#"CREATE TABLE IF NOT EXISTS {} (
+                                key TEXT NOT NULL, 
+                                timestamp INTEGER NOT NULL, 
+                                value BLOB NOT NULL
+                            );"#, name);
+                (key, None, None, sql)
+            }
+            SeriesStorageType::Index => {
+                let sql = format!("CREATE INDEX IF NOT EXISTS {name}_key_timestamp ON {name} (key, timestamp);");
+                (key, None, None, sql)
+            }
+            SeriesStorageType::QueryRange => {
+                let sql = format!(r#"select key, timestamp, value from {} 
+                                     where key = #key# and timestamp >= #begin# and timestamp < #end# 
+                                     order by timestamp"#, name);
+
+                (key, Some("range".to_owned()), Some("data".to_owned()), sql)
+            }
+            SeriesStorageType::QueryRangeAll => {
+                let sql = format!(r#"select key, timestamp, value from {} 
+                                     where timestamp >= #begin# and timestamp < #end# 
+                                     order by timestamp"#, name);
+
+                (key, Some("range".to_owned()), Some("data".to_owned()), sql)
+            }
+            SeriesStorageType::Append => {
+                let sql = format!(r#"insert into {} (key, timestamp, value) values (#key#, #timestamp#, #value#)"#, name);
+
+                (key, Some("data".to_owned()), None, sql)
+            }
+            SeriesStorageType::Delete => {
+                let sql = format!("delete from {} where key = #key#", name);
+
+                (key, Some("range".to_owned()), None, sql)
+            }
+            SeriesStorageType::DeleteBefore => {
+                let sql = format!("delete from {} where timestamp < #end#", name);
+
+                (key, Some("range".to_owned()), None, sql)
+            }
+        }
+    }
+}
@@ -12,8 +12,8 @@ use dataagent_util::{Helper, Transaction};
 use protos::profile::Data;
 
 use crate::{
-    sqlite_storage::{sqlmap::SqlmapBuild, StorageType},
-    ItemTrait, StorageTrait, StorageTransactionTrait,
+    sqlite_storage::{sqlmap::{SqlmapBuild, SeriesSqlmapBuild}, StorageType, SeriesStorageType, series::SqliteSeriesStorage},
+    ItemTrait, StorageTrait, StorageTransactionTrait, SeriesItemTrait, SeriesStorageTrait,
 };
 
 type HelperRef = Arc<Helper>;
@@ -75,6 +75,51 @@ impl SqliteStorage {
 
         Ok(Box::new(r) as Box<dyn StorageTrait<T>>)
     }
+
+    pub async fn add_series_storage<T: SeriesItemTrait + Send + Sync + Clone + 'static>(
+        &self,
+        name: &str,
+    ) -> NearResult<Box<dyn SeriesStorageTrait<T>>> {
+        trace!("add series storage: {name}");
+
+        let mut sqlmaps = vec![];
+
+        for mode in vec![
+            SeriesStorageType::Init,
+            SeriesStorageType::Index,
+            SeriesStorageType::QueryRange,
+            SeriesStorageType::QueryRangeAll,
+            SeriesStorageType::Append,
+            SeriesStorageType::Delete,
+            SeriesStorageType::DeleteBefore,
+        ] {
+            sqlmaps.push(SeriesSqlmapBuild { name, mode }.build());
+        }
+
+        {
+            let _locker = self.locker.lock().unwrap();
+
+            for sqlmap in sqlmaps {
+                let (name, input, output, sql) = sqlmap;
+                let _ = self.helper.add_sql(
+                    name,
+                    input.as_ref().map(|v| v.as_str()),
+                    output.as_ref().map(|v| v.as_str()),
+                    sql,
+                );
+            }
+        }
+
+        for mode in [SeriesStorageType::Init, SeriesStorageType::Index] {
+            let sqlmap = SeriesSqlmapBuild { name, mode }.build_sql_key();
+
+            let _ = self.helper.execute(sqlmap.as_str()).await?;
+        }
+
+        let r = SqliteSeriesStorage::<T>::new(self.helper.clone(), name);
+
+        Ok(Box::new(r) as Box<dyn SeriesStorageTrait<T>>)
+    }
 }
 
 struct SqliteSubStorageImpl<T> {
@@ -13,6 +13,16 @@ pub const TOPIC_P_KENERL_LABEL: &'static str  = "kenerl";
 
 pub const TOPIC_S_SUBSCRIBE_LABEL: &'static str = "subscribe";
 pub const TOPIC_S_DISSUBSCRIBE_LABEL: &'static str = "dissubscribe";
+/// The people redeems the invite of the home owner.
+pub const TOPIC_S_REDEEM_LABEL: &'static str = "redeem";
+/// The owner grants the role and the permissions to the people.
+pub const TOPIC_S_GRANT_LABEL: &'static str = "grant";
+/// The owner lists the members of the home.
+pub const TOPIC_S_MEMBERS_LABEL: &'static str = "members";
+/// The owner revokes the member of the home.
+pub const TOPIC_S_REVOKE_LABEL: &'static str = "revoke";
+/// The owner publishes the revocation list of its devices.
+pub const TOPIC_S_REVOCATION_LABEL: &'static str = "revocation";
 
 lazy_static::lazy_static! {
     pub static ref TOPIC_SUBSCRIBE_STATIC: Topic = {
@@ -20,4 +30,29 @@ lazy_static::lazy_static! {
             .secondary(TOPIC_S_SUBSCRIBE_LABEL)
             .build()
     };
+    pub static ref TOPIC_REDEEM_STATIC: Topic = {
+        TopicBuilder::new(TOPIC_P_CORE_LABEL)
+            .secondary(TOPIC_S_REDEEM_LABEL)
+            .build()
+    };
+    pub static ref TOPIC_GRANT_STATIC: Topic = {
+        TopicBuilder::new(TOPIC_P_CORE_LABEL)
+            .secondary(TOPIC_S_GRANT_LABEL)
+            .build()
+    };
+    pub static ref TOPIC_MEMBERS_STATIC: Topic = {
+        TopicBuilder::new(TOPIC_P_CORE_LABEL)
+            .secondary(TOPIC_S_MEMBERS_LABEL)
+            .build()
+    };
+    pub static ref TOPIC_REVOKE_STATIC: Topic = {
+        TopicBuilder::new(TOPIC_P_CORE_LABEL)
+            .secondary(TOPIC_S_REVOKE_LABEL)
+            .build()
+    };
+    pub static ref TOPIC_REVOCATION_STATIC: Topic = {
+        TopicBuilder::new(TOPIC_P_CORE_LABEL)
+            .secondary(TOPIC_S_REVOCATION_LABEL)
+            .build()
+    };
 }
@@ -165,4 +165,68 @@ lazy_static::lazy_static!{
         TopicStruct::try_from(topic).unwrap()
     };
 
+    // start upgrading the firmware of things
+    static ref NEAR_THING_SERVICE_OTA_START: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(SERVICE_LABEL)
+            .add_thirdary("ota")
+            .add_thirdary("start")
+            .build();
+    pub static ref NEAR_THING_SERVICE_OTA_START_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_SERVICE_OTA_START;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    // query the upgrade status of things
+    static ref NEAR_THING_SERVICE_OTA_QUERY: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(SERVICE_LABEL)
+            .add_thirdary("ota")
+            .add_thirdary("query")
+            .build();
+    pub static ref NEAR_THING_SERVICE_OTA_QUERY_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_SERVICE_OTA_QUERY;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    // cancel the upgrade of things
+    static ref NEAR_THING_SERVICE_OTA_CANCEL: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(SERVICE_LABEL)
+            .add_thirdary("ota")
+            .add_thirdary("cancel")
+            .build();
+    pub static ref NEAR_THING_SERVICE_OTA_CANCEL_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_SERVICE_OTA_CANCEL;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    // challenge the thing and prove it's present
+    static ref NEAR_THING_SERVICE_PAIRING: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(SERVICE_LABEL)
+            .add_thirdary("pairing")
+            .build();
+    pub static ref NEAR_THING_SERVICE_PAIRING_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_SERVICE_PAIRING;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    // the public key which signs the pairing proofs
+    static ref NEAR_THING_SERVICE_PAIRING_KEY: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(SERVICE_LABEL)
+            .add_thirdary("pairing")
+            .add_thirdary("key")
+            .build();
+    pub static ref NEAR_THING_SERVICE_PAIRING_KEY_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_SERVICE_PAIRING_KEY;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
 }
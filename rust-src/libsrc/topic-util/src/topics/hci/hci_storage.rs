@@ -10,6 +10,11 @@ const PRODUCT_LABEL: &'static str   = "product";
 const THING_LABEL: &'static str     = "thing";
 const SCHEDULE_LABEL: &'static str  = "schedule";
 const STORAGE_LABEL: &'static str   = "storage";
+const TELEMETRY_LABEL: &'static str = "telemetry";
+const CAPABILITY_LABEL: &'static str = "capability";
+const LOCATION_LABEL: &'static str  = "location";
+const FIRMWARE_LABEL: &'static str  = "firmware";
+const AUDIT_LABEL: &'static str     = "audit";
 
 // transactino
 lazy_static::lazy_static! {
@@ -161,6 +166,34 @@ lazy_static::lazy_static!{
         let topic: &'static Topic = &NEAR_THING_STORAGE_PRODUCT_QUERY_ALL;
         TopicStruct::try_from(topic).unwrap()
     };
+
+    // update capability
+    static ref NEAR_THING_STORAGE_PRODUCT_CAPABILITY_UPDATE: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(STORAGE_LABEL)
+            .add_thirdary(PRODUCT_LABEL)
+            .add_thirdary(CAPABILITY_LABEL)
+            .add_thirdary("update")
+            .build();
+    pub static ref NEAR_THING_STORAGE_PRODUCT_CAPABILITY_UPDATE_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_STORAGE_PRODUCT_CAPABILITY_UPDATE;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    // query capability of things
+    static ref NEAR_THING_STORAGE_THING_CAPABILITY_QUERY: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(STORAGE_LABEL)
+            .add_thirdary(THING_LABEL)
+            .add_thirdary(CAPABILITY_LABEL)
+            .add_thirdary("query")
+            .build();
+    pub static ref NEAR_THING_STORAGE_THING_CAPABILITY_QUERY_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_STORAGE_THING_CAPABILITY_QUERY;
+        TopicStruct::try_from(topic).unwrap()
+    };
 }
 
 // device
@@ -355,3 +388,212 @@ lazy_static::lazy_static! {
     };
 
 }
+
+// telemetry
+lazy_static::lazy_static! {
+    // append telemetry sample
+    static ref NEAR_THING_STORAGE_TELEMETRY_APPEND: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(STORAGE_LABEL)
+            .add_thirdary(TELEMETRY_LABEL)
+            .add_thirdary("append")
+            .build();
+    pub static ref NEAR_THING_STORAGE_TELEMETRY_APPEND_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_STORAGE_TELEMETRY_APPEND;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    // query telemetry with time-range
+    static ref NEAR_THING_STORAGE_TELEMETRY_QUERY: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(STORAGE_LABEL)
+            .add_thirdary(TELEMETRY_LABEL)
+            .add_thirdary("query")
+            .build();
+    pub static ref NEAR_THING_STORAGE_TELEMETRY_QUERY_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_STORAGE_TELEMETRY_QUERY;
+        TopicStruct::try_from(topic).unwrap()
+    };
+}
+
+// location
+lazy_static::lazy_static! {
+    // add location
+    static ref NEAR_THING_STORAGE_LOCATION_ADD: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(STORAGE_LABEL)
+            .add_thirdary(LOCATION_LABEL)
+            .add_thirdary("add")
+            .build();
+    pub static ref NEAR_THING_STORAGE_LOCATION_ADD_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_STORAGE_LOCATION_ADD;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    // update location
+    static ref NEAR_THING_STORAGE_LOCATION_UPDATE: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(STORAGE_LABEL)
+            .add_thirdary(LOCATION_LABEL)
+            .add_thirdary("update")
+            .build();
+    pub static ref NEAR_THING_STORAGE_LOCATION_UPDATE_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_STORAGE_LOCATION_UPDATE;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    // remove location
+    static ref NEAR_THING_STORAGE_LOCATION_REMOVE: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(STORAGE_LABEL)
+            .add_thirdary(LOCATION_LABEL)
+            .add_thirdary("remove")
+            .build();
+    pub static ref NEAR_THING_STORAGE_LOCATION_REMOVE_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_STORAGE_LOCATION_REMOVE;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    // query location
+    static ref NEAR_THING_STORAGE_LOCATION_QUERY: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(STORAGE_LABEL)
+            .add_thirdary(LOCATION_LABEL)
+            .add_thirdary("query")
+            .build();
+    pub static ref NEAR_THING_STORAGE_LOCATION_QUERY_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_STORAGE_LOCATION_QUERY;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    // query all location
+    static ref NEAR_THING_STORAGE_LOCATION_QUERY_ALL: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(STORAGE_LABEL)
+            .add_thirdary(LOCATION_LABEL)
+            .add_thirdary("query-all")
+            .build();
+    pub static ref NEAR_THING_STORAGE_LOCATION_QUERY_ALL_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_STORAGE_LOCATION_QUERY_ALL;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    // insert or remove things of room
+    static ref NEAR_THING_STORAGE_LOCATION_THINGS_UPDATE: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(STORAGE_LABEL)
+            .add_thirdary(LOCATION_LABEL)
+            .add_thirdary("things")
+            .add_thirdary("update")
+            .build();
+    pub static ref NEAR_THING_STORAGE_LOCATION_THINGS_UPDATE_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_STORAGE_LOCATION_THINGS_UPDATE;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    // query all things in the location and its children
+    static ref NEAR_THING_STORAGE_LOCATION_THINGS_QUERY: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(STORAGE_LABEL)
+            .add_thirdary(LOCATION_LABEL)
+            .add_thirdary("things")
+            .add_thirdary("query")
+            .build();
+    pub static ref NEAR_THING_STORAGE_LOCATION_THINGS_QUERY_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_STORAGE_LOCATION_THINGS_QUERY;
+        TopicStruct::try_from(topic).unwrap()
+    };
+}
+
+// firmware
+lazy_static::lazy_static! {
+    // add firmware
+    static ref NEAR_THING_STORAGE_FIRMWARE_ADD: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(STORAGE_LABEL)
+            .add_thirdary(FIRMWARE_LABEL)
+            .add_thirdary("add")
+            .build();
+    pub static ref NEAR_THING_STORAGE_FIRMWARE_ADD_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_STORAGE_FIRMWARE_ADD;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    // remove firmware
+    static ref NEAR_THING_STORAGE_FIRMWARE_REMOVE: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(STORAGE_LABEL)
+            .add_thirdary(FIRMWARE_LABEL)
+            .add_thirdary("remove")
+            .build();
+    pub static ref NEAR_THING_STORAGE_FIRMWARE_REMOVE_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_STORAGE_FIRMWARE_REMOVE;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    // query the firmware of the product
+    static ref NEAR_THING_STORAGE_FIRMWARE_QUERY: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(STORAGE_LABEL)
+            .add_thirdary(FIRMWARE_LABEL)
+            .add_thirdary("query")
+            .build();
+    pub static ref NEAR_THING_STORAGE_FIRMWARE_QUERY_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_STORAGE_FIRMWARE_QUERY;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    // query all firmwares
+    static ref NEAR_THING_STORAGE_FIRMWARE_QUERY_ALL: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(STORAGE_LABEL)
+            .add_thirdary(FIRMWARE_LABEL)
+            .add_thirdary("query-all")
+            .build();
+    pub static ref NEAR_THING_STORAGE_FIRMWARE_QUERY_ALL_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_STORAGE_FIRMWARE_QUERY_ALL;
+        TopicStruct::try_from(topic).unwrap()
+    };
+}
+
+// audit
+lazy_static::lazy_static! {
+    // append audit record
+    static ref NEAR_THING_STORAGE_AUDIT_APPEND: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(STORAGE_LABEL)
+            .add_thirdary(AUDIT_LABEL)
+            .add_thirdary("append")
+            .build();
+    pub static ref NEAR_THING_STORAGE_AUDIT_APPEND_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_STORAGE_AUDIT_APPEND;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    // query audit records with time-range
+    static ref NEAR_THING_STORAGE_AUDIT_QUERY: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(STORAGE_LABEL)
+            .add_thirdary(AUDIT_LABEL)
+            .add_thirdary("query")
+            .build();
+    pub static ref NEAR_THING_STORAGE_AUDIT_QUERY_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_STORAGE_AUDIT_QUERY;
+        TopicStruct::try_from(topic).unwrap()
+    };
+}
@@ -131,4 +131,18 @@ lazy_static::lazy_static! {
         let topic: &'static Topic = &NEAR_THING_GATEWAY_SCHEDULE_UPDATE_RELATIONS;
         TopicStruct::try_from(topic).unwrap()
     };
+
+    // location
+    // ctrl all things in the location
+    static ref NEAR_THING_GATEWAY_LOCATION_CTRL: Topic = 
+        TopicBuilder::new(TOPIC_P_NEAR_LABEL)
+            .secondary(THING_LABEL)
+            .add_thirdary(THING_GATEWAY_LABEL)
+            .add_thirdary("location")
+            .add_thirdary("ctrl")
+            .build();
+    pub static ref NEAR_THING_GATEWAY_LOCATION_CTRL_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &NEAR_THING_GATEWAY_LOCATION_CTRL;
+        TopicStruct::try_from(topic).unwrap()
+    };
 }
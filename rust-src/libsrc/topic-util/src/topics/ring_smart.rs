@@ -31,4 +31,76 @@ lazy_static::lazy_static! {
         TopicStruct::try_from(topic).unwrap()
     };
 
+    // revoke
+    static ref CORE_RING_CHAIN_REVOKE: Topic =
+        TopicBuilder::new(TOPIC_P_CORE_LABEL)
+            .secondary(RING_LABEL)
+            .add_thirdary(MAIN_CHAIN_LABEL)
+            .add_thirdary("revoke")
+            .build();
+    pub static ref CORE_RING_CHAIN_REVOKE_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &CORE_RING_CHAIN_REVOKE;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    // rotate
+    static ref CORE_RING_CHAIN_ROTATE: Topic =
+        TopicBuilder::new(TOPIC_P_CORE_LABEL)
+            .secondary(RING_LABEL)
+            .add_thirdary(MAIN_CHAIN_LABEL)
+            .add_thirdary("rotate")
+            .build();
+    pub static ref CORE_RING_CHAIN_ROTATE_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &CORE_RING_CHAIN_ROTATE;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    // replicate
+    static ref CORE_RING_CHAIN_REPLICATE: Topic =
+        TopicBuilder::new(TOPIC_P_CORE_LABEL)
+            .secondary(RING_LABEL)
+            .add_thirdary(MAIN_CHAIN_LABEL)
+            .add_thirdary("replicate")
+            .build();
+    pub static ref CORE_RING_CHAIN_REPLICATE_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &CORE_RING_CHAIN_REPLICATE;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    // replica sync
+    static ref CORE_RING_CHAIN_REPLICA_SYNC: Topic =
+        TopicBuilder::new(TOPIC_P_CORE_LABEL)
+            .secondary(RING_LABEL)
+            .add_thirdary(MAIN_CHAIN_LABEL)
+            .add_thirdary("replica-sync")
+            .build();
+    pub static ref CORE_RING_CHAIN_REPLICA_SYNC_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &CORE_RING_CHAIN_REPLICA_SYNC;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    // replica refused, the entries of the peers which are refused by the rules of the device
+    static ref CORE_RING_CHAIN_REPLICA_REFUSED: Topic =
+        TopicBuilder::new(TOPIC_P_CORE_LABEL)
+            .secondary(RING_LABEL)
+            .add_thirdary(MAIN_CHAIN_LABEL)
+            .add_thirdary("replica-refused")
+            .build();
+    pub static ref CORE_RING_CHAIN_REPLICA_REFUSED_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &CORE_RING_CHAIN_REPLICA_REFUSED;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    // revocation
+    static ref CORE_RING_CHAIN_REVOCATION: Topic =
+        TopicBuilder::new(TOPIC_P_CORE_LABEL)
+            .secondary(RING_LABEL)
+            .add_thirdary(MAIN_CHAIN_LABEL)
+            .add_thirdary("revocation")
+            .build();
+    pub static ref CORE_RING_CHAIN_REVOCATION_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &CORE_RING_CHAIN_REVOCATION;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
 }
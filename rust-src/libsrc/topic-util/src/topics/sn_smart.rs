@@ -55,4 +55,28 @@ lazy_static::lazy_static! {
         TopicStruct::try_from(topic).unwrap()
     };
 
+    // rotate
+    static ref CORE_SN_SMART_ROTATE: Topic =
+        TopicBuilder::new(TOPIC_P_CORE_LABEL)
+            .secondary(SN_SMART_LABEL)
+            .add_thirdary(SMART_LABEL)
+            .add_thirdary("rotate")
+            .build();
+    pub static ref CORE_SN_SMART_ROTATE_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &CORE_SN_SMART_ROTATE;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
+    // revocation
+    static ref CORE_SN_SMART_REVOCATION: Topic =
+        TopicBuilder::new(TOPIC_P_CORE_LABEL)
+            .secondary(SN_SMART_LABEL)
+            .add_thirdary(SMART_LABEL)
+            .add_thirdary("revocation")
+            .build();
+    pub static ref CORE_SN_SMART_REVOCATION_PUB: TopicStruct<'static> = {
+        let topic: &'static Topic = &CORE_SN_SMART_REVOCATION;
+        TopicStruct::try_from(topic).unwrap()
+    };
+
 }
@@ -1,15 +1,44 @@
 
-use near_base::{NearResult, Serialize, Deserialize, DeviceObject};
+use near_base::{NearResult, Serialize, Deserialize, DeviceObject, RevocationListObject, KeyRotationChain, KeyRotationObject};
 
 use storage::ItemTrait;
 
+use crate::replication::{ReplicaEntry, ReplicaCursor, ReplicaRefused};
+
+/// The device and the send time of its last publish, the older publish is refused.
+/// The rotations of its key are kept, so its object id could be checked from them.
 #[derive(Clone)]
 pub struct DeviceObjectItem {
     device_id: String,
     device: DeviceObject,
+    send_time: u64,
+    rotations: KeyRotationChain,
 }
 
 impl DeviceObjectItem {
+    pub fn with_send_time(mut self, send_time: u64) -> Self {
+        self.send_time = send_time;
+        self
+    }
+
+    pub fn with_rotations(mut self, rotations: KeyRotationChain) -> Self {
+        self.rotations = rotations;
+        self
+    }
+
+    pub fn send_time(&self) -> u64 {
+        self.send_time
+    }
+
+    pub fn rotations(&self) -> &KeyRotationChain {
+        &self.rotations
+    }
+
+    /// The rotation must be signed by the current key with a greater sequence.
+    pub fn rotate(&mut self, rotation: KeyRotationObject) -> NearResult<()> {
+        self.rotations.apply(rotation, &mut self.device)
+    }
+
     pub fn take_device(self) -> DeviceObject {
         self.device
     }
@@ -20,6 +49,8 @@ impl From<DeviceObject> for DeviceObjectItem {
         Self {
             device_id: device.object_id().to_string(),
             device,
+            send_time: 0,
+            rotations: KeyRotationChain::default(),
         }
     }
 }
@@ -32,20 +63,28 @@ impl ItemTrait for DeviceObjectItem {
 
 impl Serialize for DeviceObjectItem {
     fn raw_capacity(&self) -> usize {
-        self.device.raw_capacity()
+        self.device.raw_capacity() + self.send_time.raw_capacity() + self.rotations.raw_capacity()
     }
 
     fn serialize<'a>(&self,
                      buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
-        self.device.serialize(buf)
+        let buf = self.device.serialize(buf)?;
+        let buf = self.send_time.serialize(buf)?;
+        let buf = self.rotations.serialize(buf)?;
+
+        Ok(buf)
     }
 }
 
 impl Deserialize for DeviceObjectItem {
     fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
         let (device, buf) = DeviceObject::deserialize(buf)?;
+        // the item which is saved before the send time has none of it.
+        let (send_time, buf) = if buf.is_empty() { (0, buf) } else { u64::deserialize(buf)? };
+        // and the item which is saved before the rotations.
+        let (rotations, buf) = if buf.is_empty() { (KeyRotationChain::default(), buf) } else { KeyRotationChain::deserialize(buf)? };
 
-        Ok((device.into(), buf))
+        Ok((DeviceObjectItem::from(device).with_send_time(send_time).with_rotations(rotations), buf))
     }
 }
 
@@ -56,3 +95,247 @@ impl std::ops::Deref for DeviceObjectItem {
         &self.device
     }
 }
+
+/// The revocation list of the owner, it's keyed by the owner.
+#[derive(Clone)]
+pub struct RevocationItem {
+    owner_id: String,
+    revocation: RevocationListObject,
+}
+
+impl RevocationItem {
+    pub fn take_revocation(self) -> RevocationListObject {
+        self.revocation
+    }
+}
+
+impl From<RevocationListObject> for RevocationItem {
+    fn from(revocation: RevocationListObject) -> Self {
+        Self {
+            owner_id: revocation.list().issuer().to_string(),
+            revocation,
+        }
+    }
+}
+
+impl ItemTrait for RevocationItem {
+    fn id(&self) -> &str {
+        &self.owner_id
+    }
+}
+
+impl Serialize for RevocationItem {
+    fn raw_capacity(&self) -> usize {
+        self.revocation.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        self.revocation.serialize(buf)
+    }
+}
+
+impl Deserialize for RevocationItem {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (revocation, buf) = RevocationListObject::deserialize(buf)?;
+
+        Ok((revocation.into(), buf))
+    }
+}
+
+impl std::ops::Deref for RevocationItem {
+    type Target = RevocationListObject;
+
+    fn deref(&self) -> &Self::Target {
+        &self.revocation
+    }
+}
+
+/// The entry of the local replica log, it's keyed by its index.
+#[derive(Clone)]
+pub struct ReplicaEntryItem {
+    key: String,
+    entry: ReplicaEntry,
+}
+
+impl ReplicaEntryItem {
+    pub fn key_of(index: u64) -> String {
+        format!("{index:020}")
+    }
+
+    pub fn take_entry(self) -> ReplicaEntry {
+        self.entry
+    }
+}
+
+impl From<ReplicaEntry> for ReplicaEntryItem {
+    fn from(entry: ReplicaEntry) -> Self {
+        Self {
+            key: Self::key_of(entry.index()),
+            entry,
+        }
+    }
+}
+
+impl ItemTrait for ReplicaEntryItem {
+    fn id(&self) -> &str {
+        &self.key
+    }
+}
+
+impl Serialize for ReplicaEntryItem {
+    fn raw_capacity(&self) -> usize {
+        self.entry.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        self.entry.serialize(buf)
+    }
+}
+
+impl Deserialize for ReplicaEntryItem {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (entry, buf) = ReplicaEntry::deserialize(buf)?;
+
+        Ok((entry.into(), buf))
+    }
+}
+
+/// How far the log of the origin has been applied, it's keyed by the origin.
+#[derive(Clone)]
+pub struct ReplicaCursorItem {
+    origin_id: String,
+    cursor: ReplicaCursor,
+}
+
+impl ReplicaCursorItem {
+    pub fn take_cursor(self) -> ReplicaCursor {
+        self.cursor
+    }
+}
+
+impl From<ReplicaCursor> for ReplicaCursorItem {
+    fn from(cursor: ReplicaCursor) -> Self {
+        Self {
+            origin_id: cursor.origin().to_string(),
+            cursor,
+        }
+    }
+}
+
+impl ItemTrait for ReplicaCursorItem {
+    fn id(&self) -> &str {
+        &self.origin_id
+    }
+}
+
+impl Serialize for ReplicaCursorItem {
+    fn raw_capacity(&self) -> usize {
+        self.cursor.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        self.cursor.serialize(buf)
+    }
+}
+
+impl Deserialize for ReplicaCursorItem {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (cursor, buf) = ReplicaCursor::deserialize(buf)?;
+
+        Ok((cursor.into(), buf))
+    }
+}
+
+/// The refused entry of the peer, it's keyed by its origin and index.
+#[derive(Clone)]
+pub struct ReplicaRefusedItem {
+    key: String,
+    refused: ReplicaRefused,
+}
+
+impl ReplicaRefusedItem {
+    pub fn take_refused(self) -> ReplicaRefused {
+        self.refused
+    }
+}
+
+impl From<ReplicaRefused> for ReplicaRefusedItem {
+    fn from(refused: ReplicaRefused) -> Self {
+        Self {
+            key: format!("{}-{}", refused.origin(), ReplicaEntryItem::key_of(refused.index())),
+            refused,
+        }
+    }
+}
+
+impl ItemTrait for ReplicaRefusedItem {
+    fn id(&self) -> &str {
+        &self.key
+    }
+}
+
+impl Serialize for ReplicaRefusedItem {
+    fn raw_capacity(&self) -> usize {
+        self.refused.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        self.refused.serialize(buf)
+    }
+}
+
+impl Deserialize for ReplicaRefusedItem {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (refused, buf) = ReplicaRefused::deserialize(buf)?;
+
+        Ok((refused.into(), buf))
+    }
+}
+
+#[test]
+fn test_device_item() {
+    use near_base::{ObjectBuilder, RawConvertTo, device::{DeviceDescContent, DeviceBodyContent}};
+
+    let device = ObjectBuilder::new(DeviceDescContent::with_device(1), DeviceBodyContent::default()).build().unwrap();
+
+    let item = DeviceObjectItem::from(device.clone()).with_send_time(100);
+    let (decoded, _) = DeviceObjectItem::deserialize(&item.to_vec().unwrap()).unwrap();
+    assert_eq!(decoded.object_id(), device.object_id());
+    assert_eq!(decoded.send_time(), 100);
+
+    // the item which is saved before the send time.
+    let (decoded, _) = DeviceObjectItem::deserialize(&device.to_vec().unwrap()).unwrap();
+    assert_eq!(decoded.object_id(), device.object_id());
+    assert_eq!(decoded.send_time(), 0);
+    assert_eq!(decoded.rotations().last_sequence(), 0);
+}
+
+#[test]
+fn test_device_item_rotate() {
+    use near_base::{ObjectBuilder, RawConvertTo, KeyRotation, PrivateKey, device::{DeviceDescContent, DeviceBodyContent}};
+
+    let old_key = PrivateKey::generate_rsa1024().unwrap();
+    let new_key = PrivateKey::generate_rsa1024().unwrap();
+    let device =
+        ObjectBuilder::new(DeviceDescContent::with_device(1), DeviceBodyContent::default())
+            .update_desc(| desc | desc.set_public_key(old_key.public()))
+            .build()
+            .unwrap();
+
+    let mut item = DeviceObjectItem::from(device.clone()).with_send_time(100);
+    let rotation = KeyRotation::new(device.object_id().clone(), 5, old_key.public(), new_key.public()).sign(&old_key).unwrap();
+    item.rotate(rotation.clone()).unwrap();
+
+    let (decoded, _) = DeviceObjectItem::deserialize(&item.to_vec().unwrap()).unwrap();
+    assert_eq!(decoded.send_time(), 100);
+    assert_eq!(decoded.rotations().last_sequence(), 5);
+    assert!(decoded.desc().public_key() == Some(&new_key.public()));
+    decoded.rotations().verify_object(&*decoded).unwrap();
+
+    // the same rotation can't be applied twice.
+    assert!(item.rotate(rotation).is_err());
+}
@@ -2,3 +2,4 @@
 pub mod process;
 pub mod routines;
 pub mod cahces;
+pub mod replication;
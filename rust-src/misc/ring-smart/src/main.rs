@@ -1,15 +1,80 @@
 
+use std::{path::PathBuf, str::FromStr};
+
+use log::{warn, error};
+
+use near_base::{ErrorCode, NearError, NearResult, ObjectId};
+use near_core::get_data_path;
+
 use common::{ProcessCommandBuild, ProcessAction};
 
-use ring_smart::process::Process;
+use ring_smart::{process::Process, replication::ReplicationConfig};
 
 const SERVICE_NAME: &str = "ring-smart";
 
+async fn load_from_config(service_name: &str) -> NearResult<ReplicationConfig> {
+    let toml_file = PathBuf::new().with_file_name(service_name).with_extension("toml");
+    let content = 
+        async_std::fs::read_to_string(get_data_path().join(toml_file.as_path()))
+            .await
+            .map_err(| _ | {
+                let error_string = format!("Missing [{}] file, will run without replication", toml_file.display());
+                warn!("{error_string}");
+                NearError::new(ErrorCode::NEAR_ERROR_NOTFOUND, error_string)
+            })?;
+
+    let val: toml::Value = 
+        toml::from_str(&content).map_err(| e | {
+            let error_string = format!("parse [{}] with err: {e}", toml_file.display());
+            error!("{error_string}");
+            NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, error_string)
+        })?;
+
+    let mut config = ReplicationConfig::default();
+
+    if let Some(replication) = val.get("replication") {
+        config.sync_interval = 
+            replication.get("sync_interval")
+                .and_then(| v | v.as_integer())
+                .map(| v | std::time::Duration::from_secs(v as u64))
+                .unwrap_or(config.sync_interval);
+
+        if let Some(peers) = replication.get("peers").and_then(| v | v.as_array()) {
+            for peer in peers.iter().filter_map(| v | v.as_str()) {
+                config.peers.push(
+                    ObjectId::from_str(peer)
+                        .map_err(| e | {
+                            let error_string = format!("invalid [replication.peers] {peer} with err: {e}");
+                            error!("{error_string}");
+                            NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, error_string)
+                        })?
+                );
+            }
+        }
+
+        if let Some(sns) = replication.get("sns").and_then(| v | v.as_array()) {
+            for sn in sns.iter().filter_map(| v | v.as_str()) {
+                config.sns.push(
+                    ObjectId::from_str(sn)
+                        .map_err(| e | {
+                            let error_string = format!("invalid [replication.sns] {sn} with err: {e}");
+                            error!("{error_string}");
+                            NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, error_string)
+                        })?
+                );
+            }
+        }
+    }
+
+    Ok(config)
+}
+
 #[async_std::main]
 async fn main() {
     let process = 
-        match ProcessCommandBuild::new(SERVICE_NAME, true)
-                .launch(Process::new(SERVICE_NAME).await.expect("failed create main process"))
+        match ProcessCommandBuild::with_runtime()
+                .name(SERVICE_NAME)
+                .launch(Process::new(SERVICE_NAME, load_from_config(SERVICE_NAME).await.ok()).await.expect("failed create main process"), None)
                 .await {
         Ok(process) => {
             if let ProcessAction::Start(process) = process {
@@ -1,9 +1,9 @@
 
 use std::{path::PathBuf, sync::Arc};
 
-use log::trace;
+use log::{trace, error, warn};
 
-use near_base::{NearResult, ObjectId};
+use near_base::{NearResult, ErrorCode, ObjectId, DeviceObject, check_device};
 use near_core::get_service_path;
 use near_transport::{ProcessTrait, RoutineEventTrait};
 use near_util::TopicRef;
@@ -12,16 +12,22 @@ use common::{RuntimeProcessTrait, RuntimeStack};
 use storage::{StorageTrait, sqlite_storage::SqliteStorage};
 use topic_util::topics::ring_smart::*;
 
-use crate::{routines::{publish::PublishRoutine, check_out::CheckOutRoutine}, cahces::DeviceObjectItem};
+use crate::{routines::{publish::PublishRoutine, check_out::CheckOutRoutine, revoke::RevokeRoutine, revocation::RevocationRoutine, rotate::RotateRoutine,
+                       replicate::ReplicateRoutine, replica_sync::ReplicaSyncRoutine, replica_refused::ReplicaRefusedRoutine},
+            cahces::{DeviceObjectItem, RevocationItem},
+            replication::{Replication, ReplicationConfig}};
 
 #[derive(Clone)]
 #[allow(unused)]
 pub(crate) struct Config {
     pub(crate) work_path: PathBuf,
+    pub(crate) replication: ReplicationConfig,
 }
 
 struct ProcessComponents {
     device_storage: Box<dyn StorageTrait<DeviceObjectItem>>,
+    revocation_storage: Box<dyn StorageTrait<RevocationItem>>,
+    replication: Replication,
 }
 
 struct ProcessImpl {
@@ -38,11 +44,12 @@ struct ProcessImpl {
 pub struct Process(Arc<ProcessImpl>);
 
 impl Process {
-    pub async fn new(service_name: &str) -> NearResult<Box<Self>> {
+    pub async fn new(service_name: &str, replication: Option<ReplicationConfig>) -> NearResult<Box<Self>> {
         let config = {
             let work_path = get_service_path(service_name);
             Config {
                 work_path: work_path,
+                replication: replication.unwrap_or_default(),
             }    
         };
 
@@ -56,6 +63,8 @@ impl Process {
         let mut_ret = unsafe { &mut *(Arc::as_ptr(&ret.0) as *mut ProcessImpl) };
         mut_ret.components = Some(ProcessComponents {
             device_storage: ret.0.storage.add_storage("device").await?,
+            revocation_storage: ret.0.storage.add_storage("revocation").await?,
+            replication: Replication::new(&ret.0.storage, config.replication.clone()).await?,
         });
 
         Ok(Box::new(ret))
@@ -65,6 +74,36 @@ impl Process {
     pub(crate) fn device_storage(&self) -> &dyn StorageTrait<DeviceObjectItem> {
         self.0.components.as_ref().unwrap().device_storage.as_ref()
     }
+
+    #[inline]
+    pub(crate) fn revocation_storage(&self) -> &dyn StorageTrait<RevocationItem> {
+        self.0.components.as_ref().unwrap().revocation_storage.as_ref()
+    }
+
+    #[inline]
+    pub(crate) fn replication(&self) -> &Replication {
+        &self.0.components.as_ref().unwrap().replication
+    }
+
+    /// The device is refused if it's expired, or it's revoked by its owner.
+    pub(crate) async fn check_device(&self, device: &DeviceObject) -> NearResult<()> {
+        let revocation =
+            match device.desc().owner() {
+                Some(owner) => {
+                    match self.revocation_storage().load_with_prefix(owner.to_string().as_str()).await {
+                        Ok(revocation) => Some(revocation),
+                        Err(e) if e.errno() == ErrorCode::NEAR_ERROR_NOTFOUND => None,
+                        Err(e) => {
+                            error!("failed load the revocation list of {owner} with err: {e}");
+                            return Err(e);
+                        }
+                    }
+                }
+                None => None,
+            };
+
+        check_device(device, revocation.as_ref().map(| revocation | revocation.list()))
+    }
 }
 
 impl Process {
@@ -91,8 +130,95 @@ impl Process {
                 )?;
         }
 
+        {
+            let arc_self = self.clone();
+
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    CORE_RING_CHAIN_REVOKE_PUB.topic(), 
+                    move || Ok(RevokeRoutine::new(arc_self.clone()))
+                )?;
+        }
+
+        {
+            let arc_self = self.clone();
+
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    CORE_RING_CHAIN_REVOCATION_PUB.topic(), 
+                    move || Ok(RevocationRoutine::new(arc_self.clone()))
+                )?;
+        }
+
+        {
+            let arc_self = self.clone();
+
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    CORE_RING_CHAIN_ROTATE_PUB.topic(), 
+                    move || Ok(RotateRoutine::new(arc_self.clone()))
+                )?;
+        }
+
+        {
+            let arc_self = self.clone();
+
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    CORE_RING_CHAIN_REPLICATE_PUB.topic(), 
+                    move || Ok(ReplicateRoutine::new(arc_self.clone()))
+                )?;
+        }
+
+        {
+            let arc_self = self.clone();
+
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    CORE_RING_CHAIN_REPLICA_SYNC_PUB.topic(), 
+                    move || Ok(ReplicaSyncRoutine::new(arc_self.clone()))
+                )?;
+        }
+
+        {
+            let arc_self = self.clone();
+
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    CORE_RING_CHAIN_REPLICA_REFUSED_PUB.topic(), 
+                    move || Ok(ReplicaRefusedRoutine::new(arc_self.clone()))
+                )?;
+        }
+
         Ok(())
     }
+
+    // pull the peers at the interval, so the entries which aren't pushed could be caught up.
+    pub(in self) fn start_replication(&self) {
+        if self.replication().peers().is_empty() {
+            return;
+        }
+
+        let arc_self = self.clone();
+
+        async_std::task::spawn(async move {
+            loop {
+                for peer in arc_self.replication().peers() {
+                    if let Err(e) = arc_self.replication().sync(&arc_self, peer).await {
+                        warn!("failed sync from {peer} with err: {e}");
+                    }
+                }
+
+                async_std::task::sleep(arc_self.replication().sync_interval()).await;
+            }
+        });
+    }
 }
 
 #[async_trait::async_trait]
@@ -103,6 +229,8 @@ impl RuntimeProcessTrait for Process {
 
         self.subscribe_topic().await?;
 
+        self.start_replication();
+
         Ok(())
     }
 
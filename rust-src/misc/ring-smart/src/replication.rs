@@ -0,0 +1,722 @@
+
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use async_std::sync::Mutex;
+use log::{trace, error, info, warn};
+
+use near_base::{hash_data, now, ErrorCode, Hash256, KeyRotationObject, NearError, NearResult, ObjectId, RawConvertTo, Serialize, Deserialize};
+use near_transport::RequestorMeta;
+
+use common::{RoutineTemplate, RuntimeStack};
+use proof::proof_data::ProofDataSet;
+use storage::{ItemTrait, StorageTrait, sqlite_storage::SqliteStorage};
+use topic_util::topics::{ring_smart::{CORE_RING_CHAIN_REPLICATE_PUB, CORE_RING_CHAIN_REPLICA_SYNC_PUB}, sn_smart::CORE_SN_SMART_ROTATE_PUB};
+
+use crate::{cahces::{ReplicaEntryItem, ReplicaCursorItem, ReplicaRefusedItem}, process::Process, routines::{publish::publish_device, rotate::rotate_device}};
+
+/// The most entries are answered by one sync.
+pub(crate) const REPLICA_SYNC_BATCH: u64 = 64;
+const REPLICA_SYNC_TIMEOUT: Duration = Duration::from_secs(30);
+const REPLICA_SYNC_INTERVAL: Duration = Duration::from_secs(60);
+
+/// The ring nodes which the publishes are replicated with, they are the core devices of the peers.
+/// The key rotations are pushed to the SN too, so the key of their cached peer is replaced.
+#[derive(Clone)]
+pub struct ReplicationConfig {
+    pub peers: Vec<ObjectId>,
+    pub sns: Vec<ObjectId>,
+    pub sync_interval: Duration,
+}
+
+impl std::default::Default for ReplicationConfig {
+    fn default() -> Self {
+        Self {
+            peers: vec![],
+            sns: vec![],
+            sync_interval: REPLICA_SYNC_INTERVAL,
+        }
+    }
+}
+
+const REPLICA_DATA_OF_PUBLISH: u8 = 1u8;
+const REPLICA_DATA_OF_ROTATION: u8 = 2u8;
+
+/// What is replicated, it's checked again by the replicas.
+#[derive(Clone)]
+pub enum ReplicaData {
+    // the publish request.
+    Publish(Vec<u8>),
+    Rotation(KeyRotationObject),
+}
+
+impl Serialize for ReplicaData {
+    fn raw_capacity(&self) -> usize {
+        match self {
+            Self::Publish(data) => REPLICA_DATA_OF_PUBLISH.raw_capacity() + data.raw_capacity(),
+            Self::Rotation(data) => REPLICA_DATA_OF_ROTATION.raw_capacity() + data.raw_capacity(),
+        }
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        match self {
+            Self::Publish(data) => {
+                let buf = REPLICA_DATA_OF_PUBLISH.serialize(buf)?;
+                let buf = data.serialize(buf)?;
+
+                Ok(buf)
+            }
+            Self::Rotation(data) => {
+                let buf = REPLICA_DATA_OF_ROTATION.serialize(buf)?;
+                let buf = data.serialize(buf)?;
+
+                Ok(buf)
+            }
+        }
+    }
+}
+
+impl Deserialize for ReplicaData {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (f, buf) = u8::deserialize(buf)?;
+
+        match f {
+            REPLICA_DATA_OF_PUBLISH => {
+                let (data, buf) = Vec::<u8>::deserialize(buf)?;
+                Ok((Self::Publish(data), buf))
+            }
+            REPLICA_DATA_OF_ROTATION => {
+                let (data, buf) = KeyRotationObject::deserialize(buf)?;
+                Ok((Self::Rotation(data), buf))
+            }
+            _ => Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, format!("unknown replica data {f}"))),
+        }
+    }
+}
+
+/// One publish or key rotation in the append-only log of its origin,
+/// every entry is chained with the hash of the previous one.
+#[derive(Clone)]
+pub struct ReplicaEntry {
+    origin: ObjectId,
+    index: u64,
+    prev_hash: Hash256,
+    data: ReplicaData,
+}
+
+impl ReplicaEntry {
+    pub fn origin(&self) -> &ObjectId {
+        &self.origin
+    }
+
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    pub fn prev_hash(&self) -> &Hash256 {
+        &self.prev_hash
+    }
+
+    pub fn data(&self) -> &ReplicaData {
+        &self.data
+    }
+
+    pub fn hash(&self) -> NearResult<Hash256> {
+        Ok(hash_data(&self.to_vec()?))
+    }
+}
+
+impl std::fmt::Display for ReplicaEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "origin: {}, index: {}, prev_hash: {}", self.origin, self.index, self.prev_hash)
+    }
+}
+
+impl std::fmt::Debug for ReplicaEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn std::fmt::Display).fmt(f)
+    }
+}
+
+impl Serialize for ReplicaEntry {
+    fn raw_capacity(&self) -> usize {
+        self.origin.raw_capacity() + self.index.raw_capacity() + self.prev_hash.raw_capacity() + self.data.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = self.origin.serialize(buf)?;
+        let buf = self.index.serialize(buf)?;
+        let buf = self.prev_hash.serialize(buf)?;
+        let buf = self.data.serialize(buf)?;
+
+        Ok(buf)
+    }
+}
+
+impl Deserialize for ReplicaEntry {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (origin, buf) = ObjectId::deserialize(buf)?;
+        let (index, buf) = u64::deserialize(buf)?;
+        let (prev_hash, buf) = Hash256::deserialize(buf)?;
+        let (data, buf) = ReplicaData::deserialize(buf)?;
+
+        Ok((Self { origin, index, prev_hash, data }, buf))
+    }
+}
+
+/// The last entry of the origin which has been applied, the index is 0 before the first one.
+#[derive(Clone)]
+pub struct ReplicaCursor {
+    origin: ObjectId,
+    index: u64,
+    hash: Hash256,
+}
+
+impl ReplicaCursor {
+    fn new(origin: ObjectId) -> Self {
+        Self {
+            origin,
+            index: 0,
+            hash: Hash256::default(),
+        }
+    }
+
+    pub fn origin(&self) -> &ObjectId {
+        &self.origin
+    }
+
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    fn next(&self, data: ReplicaData) -> ReplicaEntry {
+        ReplicaEntry {
+            origin: self.origin.clone(),
+            index: self.index + 1,
+            prev_hash: self.hash,
+            data,
+        }
+    }
+
+    /// Move to the entry, it must be the next one of the chain.
+    fn advance(&mut self, entry: &ReplicaEntry) -> NearResult<()> {
+        if entry.origin != self.origin || entry.index != self.index + 1 {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("{entry} isn't next to {}:{}", self.origin, self.index)));
+        }
+
+        if entry.prev_hash != self.hash {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_CONFLICT, format!("{entry} forks the log, expect prev_hash: {}", self.hash)));
+        }
+
+        self.hash = entry.hash()?;
+        self.index = entry.index;
+
+        Ok(())
+    }
+
+    /// Move to the entry of the forked log, the entry itself is still checked by the rules of the device.
+    fn rebase(&mut self, entry: &ReplicaEntry) -> NearResult<()> {
+        if entry.origin != self.origin || entry.index != self.index + 1 {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("{entry} isn't next to {}:{}", self.origin, self.index)));
+        }
+
+        self.hash = entry.hash()?;
+        self.index = entry.index;
+
+        Ok(())
+    }
+}
+
+impl Serialize for ReplicaCursor {
+    fn raw_capacity(&self) -> usize {
+        self.origin.raw_capacity() + self.index.raw_capacity() + self.hash.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = self.origin.serialize(buf)?;
+        let buf = self.index.serialize(buf)?;
+        let buf = self.hash.serialize(buf)?;
+
+        Ok(buf)
+    }
+}
+
+impl Deserialize for ReplicaCursor {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (origin, buf) = ObjectId::deserialize(buf)?;
+        let (index, buf) = u64::deserialize(buf)?;
+        let (hash, buf) = Hash256::deserialize(buf)?;
+
+        Ok((Self { origin, index, hash }, buf))
+    }
+}
+
+/// The entry of the peer which is refused by the rules of the publish or the rotation,
+/// it's kept with the reason, so the diverged record of the device could be found.
+#[derive(Clone)]
+pub struct ReplicaRefused {
+    origin: ObjectId,
+    index: u64,
+    hash: Hash256,
+    reason: String,
+    refuse_time: u64,
+}
+
+impl ReplicaRefused {
+    fn new(entry: &ReplicaEntry, reason: String) -> NearResult<Self> {
+        Ok(Self {
+            origin: entry.origin.clone(),
+            index: entry.index,
+            hash: entry.hash()?,
+            reason,
+            refuse_time: now(),
+        })
+    }
+
+    pub fn origin(&self) -> &ObjectId {
+        &self.origin
+    }
+
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl std::fmt::Display for ReplicaRefused {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "origin: {}, index: {}, hash: {}, reason: {}, refuse_time: {}", self.origin, self.index, self.hash, self.reason, self.refuse_time)
+    }
+}
+
+impl Serialize for ReplicaRefused {
+    fn raw_capacity(&self) -> usize {
+        self.origin.raw_capacity() + self.index.raw_capacity() + self.hash.raw_capacity() + self.reason.raw_capacity() + self.refuse_time.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = self.origin.serialize(buf)?;
+        let buf = self.index.serialize(buf)?;
+        let buf = self.hash.serialize(buf)?;
+        let buf = self.reason.serialize(buf)?;
+        let buf = self.refuse_time.serialize(buf)?;
+
+        Ok(buf)
+    }
+}
+
+impl Deserialize for ReplicaRefused {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (origin, buf) = ObjectId::deserialize(buf)?;
+        let (index, buf) = u64::deserialize(buf)?;
+        let (hash, buf) = Hash256::deserialize(buf)?;
+        let (reason, buf) = String::deserialize(buf)?;
+        let (refuse_time, buf) = u64::deserialize(buf)?;
+
+        Ok((Self { origin, index, hash, reason, refuse_time }, buf))
+    }
+}
+
+struct CursorState {
+    cursor: ReplicaCursor,
+    stored: bool,
+}
+
+struct ReplicationImpl {
+    config: ReplicationConfig,
+    entry_storage: Box<dyn StorageTrait<ReplicaEntryItem>>,
+    cursor_storage: Box<dyn StorageTrait<ReplicaCursorItem>>,
+    refused_storage: Box<dyn StorageTrait<ReplicaRefusedItem>>,
+    // the entries of one origin are applied one by one.
+    cursors: Mutex<HashMap<ObjectId, CursorState>>,
+}
+
+/// Every ring node keeps the log of its own publishes, pushes the new entry to the peers,
+/// and pulls the missing entries of the peers.
+/// The entry of the peer is applied with the same rules of the publish or the rotation, the refused one
+/// moves the cursor too, so the local record is kept, and it's recorded to be queried.
+/// The forked log of the peer is reconciled by the same rules, but it doesn't stop the replication.
+#[derive(Clone)]
+pub(crate) struct Replication(Arc<ReplicationImpl>);
+
+impl Replication {
+    pub(crate) async fn new(storage: &SqliteStorage, config: ReplicationConfig) -> NearResult<Self> {
+        Ok(Self(Arc::new(ReplicationImpl {
+            config,
+            entry_storage: storage.add_storage("replica").await?,
+            cursor_storage: storage.add_storage("replica_cursor").await?,
+            refused_storage: storage.add_storage("replica_refused").await?,
+            cursors: Mutex::new(HashMap::new()),
+        })))
+    }
+
+    #[inline]
+    pub(crate) fn peers(&self) -> &[ObjectId] {
+        &self.0.config.peers
+    }
+
+    #[inline]
+    pub(crate) fn sns(&self) -> &[ObjectId] {
+        &self.0.config.sns
+    }
+
+    #[inline]
+    pub(crate) fn sync_interval(&self) -> Duration {
+        self.0.config.sync_interval
+    }
+
+    async fn load_cursor(&self, origin: &ObjectId) -> NearResult<CursorState> {
+        match self.0.cursor_storage.load_with_prefix(origin.to_string().as_str()).await {
+            Ok(item) => Ok(CursorState { cursor: item.take_cursor(), stored: true }),
+            Err(e) if e.errno() == ErrorCode::NEAR_ERROR_NOTFOUND => Ok(CursorState { cursor: ReplicaCursor::new(origin.clone()), stored: false }),
+            Err(e) => {
+                error!("failed load the cursor of {origin} with err: {e}");
+                Err(e)
+            }
+        }
+    }
+
+    async fn cursor_state<'a>(&self, cursors: &'a mut HashMap<ObjectId, CursorState>, origin: &ObjectId) -> NearResult<&'a mut CursorState> {
+        if !cursors.contains_key(origin) {
+            let state = self.load_cursor(origin).await?;
+            cursors.insert(origin.clone(), state);
+        }
+
+        cursors.get_mut(origin).ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_FATAL, format!("missing the cursor of {origin}")))
+    }
+
+    async fn save_cursor(&self, state: &mut CursorState) -> NearResult<()> {
+        let item = ReplicaCursorItem::from(state.cursor.clone());
+
+        if state.stored {
+            self.0.cursor_storage.update(&item).await
+        } else {
+            self.0.cursor_storage.create_new(&item).await?;
+            state.stored = true;
+            Ok(())
+        }
+    }
+
+    /// Append the publish or the rotation into the local log, and push it to the peers.
+    pub(crate) async fn append_and_replicate(&self, data: ReplicaData) {
+        match self.append(data).await {
+            Ok(entry) => self.replicate(entry).await,
+            Err(e) => error!("failed append the replica log with err: {e}"),
+        }
+    }
+
+    async fn append(&self, data: ReplicaData) -> NearResult<ReplicaEntry> {
+        let local = RuntimeStack::get_instance().remote().object_id().clone();
+
+        let cursors = &mut *self.0.cursors.lock().await;
+
+        let state = self.cursor_state(cursors, &local).await?;
+
+        // the entry could be saved but its cursor isn't, if it's broken at that time.
+        while let Ok(item) = self.0.entry_storage.load_with_prefix(ReplicaEntryItem::key_of(state.cursor.index() + 1).as_str()).await {
+            state.cursor.advance(&item.take_entry())?;
+        }
+
+        let entry = state.cursor.next(data);
+
+        self.0.entry_storage.create_new(&ReplicaEntryItem::from(entry.clone())).await?;
+        state.cursor.advance(&entry)?;
+        self.save_cursor(state).await?;
+
+        trace!("append replica entry: {entry}");
+
+        Ok(entry)
+    }
+
+    async fn replicate(&self, entry: ReplicaEntry) {
+        for peer in self.peers() {
+            let raw = match protos::RawObjectHelper::encode_with_raw(entry.clone()) {
+                Ok(raw) => raw,
+                Err(e) => {
+                    error!("failed encode {entry} with err: {e}");
+                    return;
+                }
+            };
+
+            if let Err(e) =
+                RuntimeStack::get_instance()
+                    .stack()
+                    .post_message(
+                        RequestorMeta {
+                            to: Some(peer.clone()),
+                            topic: Some(CORE_RING_CHAIN_REPLICATE_PUB.topic().clone()),
+                            ..Default::default()
+                        },
+                        raw,
+                        None
+                    )
+                    .await {
+                // the peer will pull it later.
+                warn!("failed replicate {entry} to {peer} with err: {e}");
+            }
+        }
+    }
+
+    /// Push the applied rotation to the SN, the SN which hasn't cached the device ignores it.
+    pub(crate) async fn push_rotation(&self, rotation: &KeyRotationObject) {
+        for sn in self.sns() {
+            let raw = match protos::RawObjectHelper::encode_with_raw(rotation.clone()) {
+                Ok(raw) => raw,
+                Err(e) => {
+                    error!("failed encode {rotation} with err: {e}");
+                    return;
+                }
+            };
+
+            if let Err(e) =
+                RuntimeStack::get_instance()
+                    .stack()
+                    .post_message(
+                        RequestorMeta {
+                            to: Some(sn.clone()),
+                            topic: Some(CORE_SN_SMART_ROTATE_PUB.topic().clone()),
+                            ..Default::default()
+                        },
+                        raw,
+                        None
+                    )
+                    .await {
+                warn!("failed push {rotation} to {sn} with err: {e}");
+            }
+        }
+    }
+
+    /// The entries of the local log which start from the index.
+    pub(crate) async fn entries(&self, from: u64) -> NearResult<Vec<ReplicaEntry>> {
+        let mut entries = vec![];
+
+        for index in from.max(1)..from.max(1) + REPLICA_SYNC_BATCH {
+            match self.0.entry_storage.load_with_prefix(ReplicaEntryItem::key_of(index).as_str()).await {
+                Ok(item) => entries.push(item.take_entry()),
+                Err(e) if e.errno() == ErrorCode::NEAR_ERROR_NOTFOUND => break,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(entries)
+    }
+
+    /// The refused entries of the peers.
+    pub(crate) async fn refused(&self) -> NearResult<Vec<ReplicaRefused>> {
+        Ok(self.0.refused_storage
+            .load()
+            .await?
+            .into_iter()
+            .map(| item | item.take_refused())
+            .collect())
+    }
+
+    async fn save_refused(&self, refused: ReplicaRefused) -> NearResult<()> {
+        let item = ReplicaRefusedItem::from(refused);
+
+        // the entry of the forked log could be refused again.
+        match self.0.refused_storage.load_with_prefix(item.id()).await {
+            Ok(_) => self.0.refused_storage.update(&item).await,
+            Err(e) if e.errno() == ErrorCode::NEAR_ERROR_NOTFOUND => self.0.refused_storage.create_new(&item).await,
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Apply the entry of the peer, return false if it isn't next to the cursor.
+    /// The entry is only accepted from its origin, the peer can't forge the log of the others.
+    pub(crate) async fn receive(&self, process: &Process, sender: &ObjectId, sequence: &(dyn std::fmt::Display + Sync), entry: ReplicaEntry) -> NearResult<bool> {
+        let origin = entry.origin().clone();
+
+        if !self.peers().contains(&origin) {
+            let error_string = format!("{origin} isn't the peer of replication");
+            error!("{error_string}, sequence: {sequence}");
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_REFUSE, error_string));
+        }
+
+        if &origin != sender {
+            let error_string = format!("{sender} can't replicate the entry of {origin}");
+            error!("{error_string}, sequence: {sequence}");
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_REFUSE, error_string));
+        }
+
+        let cursors = &mut *self.0.cursors.lock().await;
+
+        let state = self.cursor_state(cursors, &origin).await?;
+
+        if entry.index() <= state.cursor.index() {
+            trace!("{entry} has been applied, sequence: {sequence}");
+            return Ok(true);
+        }
+
+        if entry.index() > state.cursor.index() + 1 {
+            info!("{entry} is ahead of {}, sequence: {sequence}", state.cursor.index());
+            return Ok(false);
+        }
+
+        // check the chain before apply it.
+        let mut cursor = state.cursor.clone();
+        match cursor.advance(&entry) {
+            Ok(_) => {}
+            Err(e) if e.errno() == ErrorCode::NEAR_ERROR_CONFLICT => {
+                // the origin rebuilt its log, the entry is still checked by the rules of the device.
+                warn!("{e}, it's reconciled by the rules of the device, sequence: {sequence}");
+                cursor.rebase(&entry)?;
+            }
+            Err(e) => {
+                error!("{e}, sequence: {sequence}");
+                return Err(e);
+            }
+        }
+
+        let r =
+            match entry.data() {
+                ReplicaData::Publish(proof) => {
+                    let (proof, _) = ProofDataSet::deserialize(proof)?;
+                    publish_device(process, sequence, proof).await
+                }
+                ReplicaData::Rotation(rotation) => {
+                    rotate_device(process, sequence, rotation).await
+                }
+            };
+
+        match r {
+            Ok(device_id) => info!("apply {entry} for {device_id}, sequence: {sequence}"),
+            Err(e) => {
+                error!("refused {entry} with err: {e}, sequence: {sequence}");
+                self.save_refused(ReplicaRefused::new(&entry, e.to_string())?).await?;
+            }
+        }
+
+        state.cursor = cursor;
+        self.save_cursor(state).await?;
+
+        Ok(true)
+    }
+
+    /// Pull the missing entries of the peer.
+    /// The last applied entry is pulled again, if it's changed the log of the peer is forked,
+    /// then all of its entries are pulled and reconciled by the rules of the device.
+    pub(crate) async fn sync(&self, process: &Process, peer: &ObjectId) -> NearResult<()> {
+        loop {
+            let last = {
+                let cursors = &mut *self.0.cursors.lock().await;
+                self.cursor_state(cursors, peer).await?.cursor.clone()
+            };
+            let from = last.index().max(1);
+
+            let entries =
+                async_std::future::timeout(
+                    REPLICA_SYNC_TIMEOUT,
+                    RoutineTemplate::<Vec<ReplicaEntry>>::call_with_target(peer.clone(), CORE_RING_CHAIN_REPLICA_SYNC_PUB.topic().clone(), from).await?
+                )
+                .await
+                .map_err(| _ | NearError::new(ErrorCode::NEAR_ERROR_TIMEOUT, format!("sync from {peer} timeout")))??;
+
+            let count = entries.len() as u64;
+            let mut entries = entries.into_iter();
+
+            if last.index() > 0 {
+                let matched =
+                    match entries.next() {
+                        Some(entry) => entry.index() == last.index() && entry.hash()? == last.hash,
+                        None => false,
+                    };
+
+                if !matched {
+                    warn!("the log of {peer} is forked from {}, sync it from the first entry", last.index());
+                    self.reset_cursor(peer).await?;
+                    continue;
+                }
+            }
+
+            for entry in entries {
+                if !self.receive(process, peer, &format!("sync-{peer}"), entry).await? {
+                    return Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("{peer} answered the broken entries")));
+                }
+            }
+
+            if count < REPLICA_SYNC_BATCH {
+                break Ok(());
+            }
+        }
+    }
+
+    async fn reset_cursor(&self, origin: &ObjectId) -> NearResult<()> {
+        let cursors = &mut *self.0.cursors.lock().await;
+        let state = self.cursor_state(cursors, origin).await?;
+
+        state.cursor = ReplicaCursor::new(origin.clone());
+        self.save_cursor(state).await
+    }
+}
+
+#[test]
+fn test_replica_chain() {
+    let origin = ObjectId::default();
+
+    let mut cursor = ReplicaCursor::new(origin.clone());
+    let first = cursor.next(ReplicaData::Publish(vec![1, 2, 3]));
+    cursor.advance(&first).unwrap();
+    let second = cursor.next(ReplicaData::Publish(vec![4, 5, 6]));
+    assert_eq!(second.index(), 2);
+    assert!(second.prev_hash() == &first.hash().unwrap());
+
+    // the entry is refused if its previous one is changed.
+    let mut replica = ReplicaCursor::new(origin.clone());
+    let forked = ReplicaCursor::new(origin).next(ReplicaData::Publish(vec![7, 8, 9]));
+    replica.advance(&forked).unwrap();
+    assert!(replica.advance(&second).unwrap_err().errno() == ErrorCode::NEAR_ERROR_CONFLICT);
+    assert!(replica.advance(&first).unwrap_err().errno() == ErrorCode::NEAR_ERROR_UNMATCH);
+
+    // the forked log is followed from the entry, so the next one of the origin is accepted.
+    assert!(replica.rebase(&first).unwrap_err().errno() == ErrorCode::NEAR_ERROR_UNMATCH);
+    replica.rebase(&second).unwrap();
+    cursor.advance(&second).unwrap();
+    replica.advance(&cursor.next(ReplicaData::Publish(vec![7]))).unwrap();
+    assert_eq!(replica.index(), 3);
+}
+
+#[test]
+fn test_replica_refused() {
+    let entry = ReplicaCursor::new(ObjectId::default()).next(ReplicaData::Publish(vec![1, 2, 3]));
+
+    let refused = ReplicaRefused::new(&entry, "the publish at 1 isn't newer than 2".to_owned()).unwrap();
+    let buf = refused.to_vec().unwrap();
+    let (decoded, remain) = ReplicaRefused::deserialize(&buf).unwrap();
+    assert!(remain.is_empty());
+    assert_eq!(decoded.index(), 1);
+    assert_eq!(decoded.reason(), refused.reason());
+    assert!(decoded.hash == entry.hash().unwrap());
+}
+
+#[test]
+fn test_replica_data() {
+    use near_base::{KeyRotation, PrivateKey};
+
+    let old_key = PrivateKey::generate_rsa1024().unwrap();
+    let rotation = KeyRotation::new(ObjectId::default(), 1, old_key.public(), PrivateKey::generate_rsa1024().unwrap().public()).sign(&old_key).unwrap();
+
+    let mut cursor = ReplicaCursor::new(ObjectId::default());
+    let first = cursor.next(ReplicaData::Publish(vec![1, 2, 3]));
+    cursor.advance(&first).unwrap();
+    let second = cursor.next(ReplicaData::Rotation(rotation.clone()));
+
+    for entry in [first, second] {
+        let buf = entry.to_vec().unwrap();
+        let (decoded, remain) = ReplicaEntry::deserialize(&buf).unwrap();
+        assert!(remain.is_empty());
+        assert!(decoded.hash().unwrap() == entry.hash().unwrap());
+    }
+
+    let (decoded, _) = ReplicaData::deserialize(&ReplicaData::Rotation(rotation.clone()).to_vec().unwrap()).unwrap();
+    match decoded {
+        ReplicaData::Rotation(decoded) => decoded.verify(rotation.rotation().old_key()).unwrap(),
+        ReplicaData::Publish(_) => unreachable!(),
+    }
+}
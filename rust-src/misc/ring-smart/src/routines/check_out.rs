@@ -1,7 +1,7 @@
 
 use log::{trace, error};
 
-use near_base::{ErrorCode, NearError, NearResult, ObjectId, DeviceObject, ObjectTypeCode, OBJECT_TYPE_DEVICE_CORE, };
+use near_base::{ErrorCode, NearError, NearResult, ObjectId, DeviceObject, ObjectTypeCode, DeviceObjectSubCode, };
 use near_transport::{EventResult, HeaderMeta, Routine, RoutineEventTrait, RoutineWrap};
 
 use base::raw_object::RawObjectGuard;
@@ -44,7 +44,7 @@ impl CheckOutRoutine {
         match object_id.object_type_code() {
             Ok(code) => {
                 if let ObjectTypeCode::Device(v) = code {
-                    if v == OBJECT_TYPE_DEVICE_CORE {
+                    if v == DeviceObjectSubCode::OBJECT_TYPE_DEVICE_CORE as u8 {
                         Ok(())
                     } else {
                         let error_string = format!("[{object_id}] is not core-device.");
@@ -63,15 +63,26 @@ impl CheckOutRoutine {
             }
         }?;
 
+        let device = 
+            self.process
+                .device_storage()
+                .load_with_prefix(object_id.to_string().as_str())
+                .await
+                .map(| device | device.take_device())
+                .map_err(| e |{
+                    error!("{e}, sequence: {}", header_meta.sequence());
+                    e
+                })?;
+
         self.process
-            .device_storage()
-            .load_with_prefix(object_id.to_string().as_str())
+            .check_device(&device)
             .await
-            .map(| device | device.take_device())
-            .map_err(| e |{
+            .map_err(| e | {
                 error!("{e}, sequence: {}", header_meta.sequence());
                 e
-            })
+            })?;
+
+        Ok(device)
     }
 
 }
@@ -1,3 +1,9 @@
 
 pub mod check_out;
 pub mod publish;
+pub mod revoke;
+pub mod revocation;
+pub mod rotate;
+pub mod replicate;
+pub mod replica_sync;
+pub mod replica_refused;
@@ -1,14 +1,14 @@
 
 use log::{trace, error, info};
 
-use near_base::{ErrorCode, NearError, NearResult, builder_codec_macro::Empty, ObjectBuilder, device::{DeviceDescContent, DeviceBodyContent}, };
+use near_base::{ErrorCode, NearError, NearResult, builder_codec_macro::Empty, ObjectBuilder, ObjectId, DeviceObject, RawConvertTo, device::{DeviceDescContent, DeviceBodyContent}, };
 use near_transport::{EventResult, HeaderMeta, Routine, RoutineEventTrait, RoutineWrap};
 
 use base::raw_object::RawObjectGuard;
 use proof::proof_data::{ProofDataSet, ProofOfProcessTrait};
 use protos::{DataContent, try_decode_raw_object, try_encode_raw_object};
 
-use crate::{process::Process, cahces::DeviceObjectItem};
+use crate::{process::Process, cahces::DeviceObjectItem, replication::ReplicaData};
 
 pub struct PublishRoutine {
     process: Process,
@@ -43,125 +43,160 @@ impl Routine<RawObjectGuard, RawObjectGuard> for PublishRoutine {
 impl PublishRoutine {
 
     async fn on_routine(&self, header_meta: &HeaderMeta, proof: ProofDataSet) -> NearResult<Empty> {
+        // the original request is kept in the replica log, so the peers could check it again.
+        let raw_proof = proof.to_vec()?;
 
-        let (mut proof_of_data, proof) = match proof {
-            ProofDataSet::Publish(proof) => Ok(proof.split()),
-            _ => Err(NearError::new(ErrorCode::NEAR_ERROR_FATAL, "error proof"))
-        }?;
+        let _ = publish_device(&self.process, header_meta.sequence(), proof).await?;
 
-        let proof_of_device = proof_of_data.mut_desc().mut_content().take_proof_data();
-        let device = DeviceObjectItem::from(proof_of_data.mut_body().mut_content().take_data());
+        self.process.replication().append_and_replicate(ReplicaData::Publish(raw_proof)).await;
 
-        let mut device_transaction = 
-            self.process.device_storage()
-                .begin()
-                .await
-                .map_err(| e | {
-                    error!("{e}, sequence: {}", header_meta.sequence());
-                    e
-                })?;
+        Ok(Empty)
+    }
 
-        match device_transaction.load_with_prefix(device.object_id().to_string().as_str()).await {
-            Ok(local_device) => {
+}
 
-                if local_device.desc().create_timestamp() != device.desc().create_timestamp() {
-                    let error_string = "Core element[create timestamp] conflicts";
-                    error!("{error_string}, sequence: {}", header_meta.sequence());
-                    Err(NearError::new(ErrorCode::NEAR_ERROR_CONFLICT, error_string))
-                } else {
-                    Ok(())
-                }?;
+/// Check the proof of the device and save it, it's shared by the publish and the replicas from the peers.
+pub(crate) async fn publish_device(process: &Process, sequence: &(dyn std::fmt::Display + Sync), proof: ProofDataSet) -> NearResult<ObjectId> {
+
+    let (mut proof_of_data, proof) = match proof {
+        ProofDataSet::Publish(proof) => Ok(proof.split()),
+        _ => Err(NearError::new(ErrorCode::NEAR_ERROR_FATAL, "error proof"))
+    }?;
+
+    let proof_of_device = proof_of_data.mut_desc().mut_content().take_proof_data();
+    let send_time = proof_of_device.send_time;
+    let device = DeviceObjectItem::from(proof_of_data.mut_body().mut_content().take_data()).with_send_time(send_time);
+
+    process
+        .check_device(&device)
+        .await
+        .map_err(| e | {
+            error!("{e}, sequence: {}", sequence);
+            e
+        })?;
+
+    let mut device_transaction = 
+        process.device_storage()
+            .begin()
+            .await
+            .map_err(| e | {
+                error!("{e}, sequence: {}", sequence);
+                e
+            })?;
+
+    match device_transaction.load_with_prefix(device.object_id().to_string().as_str()).await {
+        Ok(local_device) => {
+
+            if local_device.desc().create_timestamp() != device.desc().create_timestamp() {
+                let error_string = "Core element[create timestamp] conflicts";
+                error!("{error_string}, sequence: {}", sequence);
+                Err(NearError::new(ErrorCode::NEAR_ERROR_CONFLICT, error_string))
+            } else {
+                Ok(())
+            }?;
 
-                if local_device.desc().owner() != device.desc().owner() {
-                    let error_string = "Core element[owner] conflicts";
-                    error!("{error_string}, sequence: {}", header_meta.sequence());
-                    Err(NearError::new(ErrorCode::NEAR_ERROR_CONFLICT, error_string))
-                } else {
-                    Ok(())
-                }?;
+            if local_device.desc().owner() != device.desc().owner() {
+                let error_string = "Core element[owner] conflicts";
+                error!("{error_string}, sequence: {}", sequence);
+                Err(NearError::new(ErrorCode::NEAR_ERROR_CONFLICT, error_string))
+            } else {
+                Ok(())
+            }?;
+
+            // the replayed or the older publish can't roll the device back.
+            if send_time <= local_device.send_time() {
+                let error_string = format!("the publish at {send_time} isn't newer than {}", local_device.send_time());
+                error!("{error_string}, sequence: {}", sequence);
+                Err(NearError::new(ErrorCode::NEAR_ERROR_EXPIRED, error_string))
+            } else {
+                Ok(())
+            }?;
 
-                let public_key = 
-                    local_device.desc().public_key().ok_or_else(|| {
-                        error!("missing public key, sequence: {}", header_meta.sequence());
-                        NearError::new(ErrorCode::NEAR_ERROR_FATAL, "missing public key")
-                    })?;
+            let public_key = 
+                local_device.desc().public_key().ok_or_else(|| {
+                    error!("missing public key, sequence: {}", sequence);
+                    NearError::new(ErrorCode::NEAR_ERROR_FATAL, "missing public key")
+                })?;
+
+            // update
+            proof_of_device.verify(public_key, &proof)
+                .await
+                .map_err(| e | {
+                    error!("{e}, sequence: {}", sequence);
+                    e
+                })?;
 
-                // update
-                proof_of_device.verify(public_key, &proof)
-                    .await
+            // the public key may have been rotated, so the object id of the local is kept.
+            let device_new = 
+                ObjectBuilder::new(DeviceDescContent::default(), DeviceBodyContent::default())
+                    .update_desc(| mut_desc | {
+                        mut_desc.set_create_timestamp(device.desc().create_timestamp());
+                        mut_desc.set_expired_time(device.desc().expired_time());
+                        mut_desc.set_owner(device.desc().owner().cloned());
+                        mut_desc.set_author(device.desc().author().cloned());
+                        mut_desc.set_area(device.desc().area().cloned());
+                        mut_desc.set_public_key(public_key.clone());
+                    })
+                    .update_body(| mut_body | {
+                        mut_body.mut_body().set_endpoints(device.body().content().endpoints().clone());
+                        mut_body.mut_body().set_turn_node_list(device.body().content().turn_node_list().clone());
+                        mut_body.mut_body().set_name(device.body().content().name());
+                        mut_body.mut_body().set_stun_node_list(device.body().content().stun_node_list().clone());
+                        mut_body.mut_body().set_userdata(device.body().content().userdata().clone());
+                    })
+                    .build()
+                    .map(| device_new | {
+                        DeviceObject::build(local_device.object_id().clone(), device_new.desc().clone(), device_new.body().clone())
+                    })
                     .map_err(| e | {
-                        error!("{e}, sequence: {}", header_meta.sequence());
+                        error!("failed update with err: {e}, sequence: {}", sequence);
                         e
                     })?;
 
-                let device_new = 
-                    ObjectBuilder::new(DeviceDescContent::default(), DeviceBodyContent::default())
-                        .update_desc(| mut_desc | {
-                            mut_desc.set_create_timestamp(device.desc().create_timestamp());
-                            mut_desc.set_expired_time(device.desc().expired_time());
-                            mut_desc.set_owner(device.desc().owner().cloned());
-                            mut_desc.set_author(device.desc().author().cloned());
-                            mut_desc.set_area(device.desc().area().cloned());
-                            mut_desc.set_public_key(public_key.clone());
-                        })
-                        .update_body(| mut_body | {
-                            mut_body.mut_body().set_endpoints(device.body().content().endpoints().clone());
-                            mut_body.mut_body().set_turn_node_list(device.body().content().turn_node_list().clone());
-                            mut_body.mut_body().set_name(device.body().content().name());
-                            mut_body.mut_body().set_stun_node_list(device.body().content().stun_node_list().clone());
-                            mut_body.mut_body().set_userdata(device.body().content().userdata().clone());
-                        })
-                        .build()
+            // and the rotations of its key.
+            device_transaction.update(&DeviceObjectItem::from(device_new).with_send_time(send_time).with_rotations(local_device.rotations().clone()))
+                .await
+                .map_err(| e | {
+                    error!("{e}, sequence: {}", sequence);
+                    e
+                })?;
+
+            Ok(())
+        }
+        Err(e) => {
+            match e.errno() {
+                ErrorCode::NEAR_ERROR_NOTFOUND => {
+                    let public_key = 
+                        device.desc().public_key().ok_or_else(|| {
+                            error!("missing public key, sequence: {}", sequence);
+                            NearError::new(ErrorCode::NEAR_ERROR_FATAL, "missing public key")
+                        })?;
+
+                    proof_of_device.verify(public_key, &proof).await
                         .map_err(| e | {
-                            error!("failed update with err: {e}, sequence: {}", header_meta.sequence());
+                            error!("{e}, sequence: {}", sequence);
                             e
                         })?;
 
-                device_transaction.update(&device_new.into())
-                    .await
-                    .map_err(| e | {
-                        error!("{e}, sequence: {}", header_meta.sequence());
-                        e
-                    })?;
+                    device_transaction.create_new(&device).await
+                        .map_err(| e | {
+                            error!("{e}, sequence: {}", sequence);
+                            e
+                        })?;
 
-                Ok(())
-            }
-            Err(e) => {
-                match e.errno() {
-                    ErrorCode::NEAR_ERROR_NOTFOUND => {
-                        let public_key = 
-                            device.desc().public_key().ok_or_else(|| {
-                                error!("missing public key, sequence: {}", header_meta.sequence());
-                                NearError::new(ErrorCode::NEAR_ERROR_FATAL, "missing public key")
-                            })?;
-
-                        proof_of_device.verify(public_key, &proof).await
-                            .map_err(| e | {
-                                error!("{e}, sequence: {}", header_meta.sequence());
-                                e
-                            })?;
-
-                        device_transaction.create_new(&device).await
-                            .map_err(| e | {
-                                error!("{e}, sequence: {}", header_meta.sequence());
-                                e
-                            })?;
-
-                        Ok(())
-                    },
-                    _ => {
-                        error!("{e}, sequence: {}", header_meta.sequence());
-                        Err(e)
-                    }
+                    Ok(())
+                },
+                _ => {
+                    error!("{e}, sequence: {}", sequence);
+                    Err(e)
                 }
             }
-        }?;
-
-        device_transaction.commit().await.map_err(| e | { error!("{e}, sequence: {}", header_meta.sequence()); e })?;
+        }
+    }?;
 
-        info!("Successfully publish device: {}", device.object_id());
+    device_transaction.commit().await.map_err(| e | { error!("{e}, sequence: {}", sequence); e })?;
 
-        Ok(Empty)
-    }
+    info!("Successfully publish device: {}", device.object_id());
 
+    Ok(device.object_id().clone())
 }
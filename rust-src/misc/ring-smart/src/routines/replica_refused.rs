@@ -0,0 +1,46 @@
+
+use log::trace;
+
+use near_base::{NearResult, builder_codec_macro::Empty, };
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineEventTrait, RoutineWrap};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_decode_raw_object, try_encode_raw_object};
+
+use crate::{process::Process, replication::ReplicaRefused};
+
+pub struct ReplicaRefusedRoutine {
+    process: Process,
+}
+
+impl ReplicaRefusedRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(ReplicaRefusedRoutine{
+            process
+        }))
+    }
+
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for ReplicaRefusedRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("ReplicaRefusedRoutine: header_meta={header_meta}");
+
+        let r = try_decode_raw_object!(Empty, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Vec<ReplicaRefused>> = match r {
+            DataContent::Content(_) => self.on_routine().await.into(),
+            DataContent::Error(e) => DataContent::Error(e),
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl ReplicaRefusedRoutine {
+    /// Answer the entries of the peers which are refused by the rules of the device.
+    async fn on_routine(&self) -> NearResult<Vec<ReplicaRefused>> {
+        self.process.replication().refused().await
+    }
+}
@@ -0,0 +1,46 @@
+
+use log::trace;
+
+use near_base::NearResult;
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineEventTrait, RoutineWrap};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_decode_raw_object, try_encode_raw_object};
+
+use crate::{process::Process, replication::ReplicaEntry};
+
+pub struct ReplicaSyncRoutine {
+    process: Process,
+}
+
+impl ReplicaSyncRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(ReplicaSyncRoutine{
+            process
+        }))
+    }
+
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for ReplicaSyncRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("ReplicaSyncRoutine: header_meta={header_meta}");
+
+        let r = try_decode_raw_object!(u64, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Vec<ReplicaEntry>> = match r {
+            DataContent::Content(from) => self.on_routine(from).await.into(),
+            DataContent::Error(e) => DataContent::Error(e),
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl ReplicaSyncRoutine {
+    /// Answer the entries of the local log from the index.
+    async fn on_routine(&self, from: u64) -> NearResult<Vec<ReplicaEntry>> {
+        self.process.replication().entries(from).await
+    }
+}
@@ -0,0 +1,58 @@
+
+use log::{trace, error};
+
+use near_base::{NearResult, builder_codec_macro::Empty, };
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineEventTrait, RoutineWrap};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_decode_raw_object, try_encode_raw_object};
+
+use crate::{process::Process, replication::ReplicaEntry};
+
+pub struct ReplicateRoutine {
+    process: Process,
+}
+
+impl ReplicateRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(ReplicateRoutine{
+            process
+        }))
+    }
+
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for ReplicateRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("ReplicateRoutine: header_meta={header_meta}");
+
+        let r = try_decode_raw_object!(ReplicaEntry, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Empty> = match r {
+            DataContent::Content(entry) => self.on_routine(header_meta, entry).await.into(),
+            DataContent::Error(e) => DataContent::Error(e),
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl ReplicateRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, entry: ReplicaEntry) -> NearResult<Empty> {
+        let origin = entry.origin().clone();
+        let replication = self.process.replication();
+
+        if !replication.receive(&self.process, &header_meta.requestor, header_meta.sequence(), entry).await? {
+            // some entries are missing, pull them from the origin.
+            let process = self.process.clone();
+            async_std::task::spawn(async move {
+                if let Err(e) = process.replication().sync(&process, &origin).await {
+                    error!("failed sync from {origin} with err: {e}");
+                }
+            });
+        }
+
+        Ok(Empty)
+    }
+}
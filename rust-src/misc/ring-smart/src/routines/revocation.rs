@@ -0,0 +1,76 @@
+
+use log::{trace, error};
+
+use near_base::{ErrorCode, NearError, NearResult, ObjectId, ObjectTypeCode, RevocationListObject, };
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineEventTrait, RoutineWrap};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_decode_raw_object, try_encode_raw_object};
+
+use crate::process::Process;
+
+pub struct RevocationRoutine {
+    process: Process,
+}
+
+impl RevocationRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(RevocationRoutine{
+            process
+        }))
+    }
+
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for RevocationRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("RevocationRoutine: header_meta={header_meta}");
+
+        let r = try_decode_raw_object!(Vec<ObjectId>, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Vec<RevocationListObject>> = match r {
+            DataContent::Content(owners) => self.on_routine(header_meta, owners).await.into(),
+            DataContent::Error(e) => DataContent::Error(e),
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl RevocationRoutine {
+    /// The revocation lists of the owners, the owner which hasn't revoked any device is skipped.
+    async fn on_routine(&self, header_meta: &HeaderMeta, owners: Vec<ObjectId>) -> NearResult<Vec<RevocationListObject>> {
+        let mut revocations = vec![];
+
+        for owner in owners {
+            match owner.object_type_code() {
+                Ok(ObjectTypeCode::People) => Ok(()),
+                Ok(_) => {
+                    let error_string = format!("[{owner}] is not people-id.");
+                    error!("{}, sequence: {}", error_string, header_meta.sequence());
+                    Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, error_string))
+                }
+                Err(e) => {
+                    error!("{e}, sequence: {}", header_meta.sequence());
+                    Err(e)
+                }
+            }?;
+
+            match self.process
+                    .revocation_storage()
+                    .load_with_prefix(owner.to_string().as_str())
+                    .await {
+                Ok(revocation) => revocations.push(revocation.take_revocation()),
+                Err(e) if e.errno() == ErrorCode::NEAR_ERROR_NOTFOUND => {},
+                Err(e) => {
+                    error!("{e}, sequence: {}", header_meta.sequence());
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(revocations)
+    }
+
+}
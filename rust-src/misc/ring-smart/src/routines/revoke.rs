@@ -0,0 +1,90 @@
+
+use log::{trace, error, info};
+
+use near_base::{ErrorCode, NearError, NearResult, builder_codec_macro::Empty, RevocationListObject, };
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineEventTrait, RoutineWrap};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_decode_raw_object, try_encode_raw_object};
+
+use crate::{process::Process, cahces::RevocationItem};
+
+pub struct RevokeRoutine {
+    process: Process,
+}
+
+impl RevokeRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(RevokeRoutine{
+            process
+        }))
+    }
+
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for RevokeRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("RevokeRoutine: header_meta={header_meta}");
+
+        let r = try_decode_raw_object!(RevocationListObject, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Empty> = match r {
+            DataContent::Content(revocation) => self.on_routine(header_meta, revocation).await.into(),
+            DataContent::Error(e) => DataContent::Error(e),
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl RevokeRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, revocation: RevocationListObject) -> NearResult<Empty> {
+
+        revocation.verify()
+            .map_err(| e | {
+                error!("{e}, sequence: {}", header_meta.sequence());
+                e
+            })?;
+
+        let revocation = RevocationItem::from(revocation);
+
+        let mut transaction = 
+            self.process.revocation_storage()
+                .begin()
+                .await
+                .map_err(| e | {
+                    error!("{e}, sequence: {}", header_meta.sequence());
+                    e
+                })?;
+
+        match transaction.load_with_prefix(revocation.list().issuer().to_string().as_str()).await {
+            Ok(local) => {
+                if !local.list().is_replaced_by(revocation.list()) {
+                    let error_string = format!("{} has newer revocation list", revocation.list().issuer());
+                    error!("{error_string}, sequence: {}", header_meta.sequence());
+                    Err(NearError::new(ErrorCode::NEAR_ERROR_EXPIRED, error_string))
+                } else {
+                    transaction.update(&revocation).await
+                }
+            }
+            Err(e) => {
+                match e.errno() {
+                    ErrorCode::NEAR_ERROR_NOTFOUND => transaction.create_new(&revocation).await,
+                    _ => Err(e),
+                }
+            }
+        }
+        .map_err(| e | {
+            error!("{e}, sequence: {}", header_meta.sequence());
+            e
+        })?;
+
+        transaction.commit().await.map_err(| e | { error!("{e}, sequence: {}", header_meta.sequence()); e })?;
+
+        info!("Successfully update revocation list: {}", revocation.list());
+
+        Ok(Empty)
+    }
+
+}
@@ -0,0 +1,102 @@
+
+use log::{trace, error, info};
+
+use near_base::{NearResult, builder_codec_macro::Empty, KeyRotationObject, ObjectId, };
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineEventTrait, RoutineWrap};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_decode_raw_object, try_encode_raw_object};
+
+use crate::{process::Process, replication::ReplicaData};
+
+pub struct RotateRoutine {
+    process: Process,
+}
+
+impl RotateRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(RotateRoutine{
+            process
+        }))
+    }
+
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for RotateRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("RotateRoutine: header_meta={header_meta}");
+
+        let r = try_decode_raw_object!(KeyRotationObject, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Empty> = match r {
+            DataContent::Content(rotation) => self.on_routine(header_meta, rotation).await.into(),
+            DataContent::Error(e) => DataContent::Error(e),
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl RotateRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, rotation: KeyRotationObject) -> NearResult<Empty> {
+
+        let _ = rotate_device(&self.process, header_meta.sequence(), &rotation).await?;
+
+        self.process.replication().append_and_replicate(ReplicaData::Rotation(rotation.clone())).await;
+        self.process.replication().push_rotation(&rotation).await;
+
+        Ok(Empty)
+    }
+
+}
+
+/// Apply the rotation to the saved device, it's shared by the rotate and the replicas from the peers.
+pub(crate) async fn rotate_device(process: &Process, sequence: &(dyn std::fmt::Display + Sync), rotation: &KeyRotationObject) -> NearResult<ObjectId> {
+
+    let mut transaction =
+        process.device_storage()
+            .begin()
+            .await
+            .map_err(| e | {
+                error!("{e}, sequence: {}", sequence);
+                e
+            })?;
+
+    // the send time of the last publish is kept.
+    let mut device =
+        transaction.load_with_prefix(rotation.rotation().object_id().to_string().as_str())
+            .await
+            .map_err(| e | {
+                error!("{e}, sequence: {}", sequence);
+                e
+            })?;
+
+    process
+        .check_device(&device)
+        .await
+        .map_err(| e | {
+            error!("{e}, sequence: {}", sequence);
+            e
+        })?;
+
+    // the new key is accepted only when it's signed by the current key with a greater sequence.
+    device.rotate(rotation.clone())
+        .map_err(| e | {
+            error!("{e}, sequence: {}", sequence);
+            e
+        })?;
+
+    transaction.update(&device)
+        .await
+        .map_err(| e | {
+            error!("{e}, sequence: {}", sequence);
+            e
+        })?;
+
+    transaction.commit().await.map_err(| e | { error!("{e}, sequence: {}", sequence); e })?;
+
+    info!("Successfully rotate key: {}", rotation.rotation());
+
+    Ok(device.object_id().clone())
+}
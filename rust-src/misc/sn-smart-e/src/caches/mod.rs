@@ -1,3 +1,4 @@
 
 // pub mod peer_status;
 pub mod peer_manager;
+pub mod revocation;
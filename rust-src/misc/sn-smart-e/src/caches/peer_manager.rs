@@ -4,7 +4,7 @@ use std::{
     sync::{atomic::{AtomicU64, Ordering}, Mutex}
 };
 
-use near_base::{device::DeviceId, Timestamp, DeviceObject, EndpointPair, NearResult, Signature, NearError, ErrorCode, now, AesKey};
+use near_base::{device::DeviceId, Timestamp, DeviceObject, EndpointPair, NearResult, Signature, NearError, ErrorCode, now, AesKey, KeyRotationObject};
 
 #[derive(Copy, Clone)]
 pub struct Config {
@@ -47,6 +47,8 @@ struct CachedPeerInfo {
     last_call_time: Timestamp,
     last_checkout_time: Timestamp,
     last_ping_sequence: u64,
+    // the sequence of the last key rotation, the older one can't be replayed.
+    last_rotation_sequence: u64,
 }
 
 impl CachedPeerInfo {
@@ -67,6 +69,7 @@ impl CachedPeerInfo {
             last_send_time: send_time,
             last_call_time: 0,
             last_checkout_time: 0,
+            last_rotation_sequence: 0,
         }
     }
 
@@ -260,4 +263,19 @@ impl PeerManager {
             .find_peer(id, reason.unwrap_or(FindPeerReason::Other))
             .map(|c| c.to_found_peer())
     }
+
+    /// Replace the public key of the cached peer, the rotation must be signed by its current key with a greater sequence.
+    pub fn rotate_key(&self, rotation: &KeyRotationObject) -> NearResult<()> {
+        let peer_id = rotation.rotation().object_id();
+
+        self.peers
+            .lock().unwrap()
+            .find_peer(peer_id, FindPeerReason::Other)
+            .ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_NOTFOUND, format!("not found [{}] peer.", peer_id)))
+            .and_then(| c | {
+                rotation.apply(c.last_rotation_sequence, &mut c.desc)?;
+                c.last_rotation_sequence = rotation.rotation().sequence();
+                Ok(())
+            })
+    }
 }
\ No newline at end of file
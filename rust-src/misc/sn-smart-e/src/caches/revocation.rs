@@ -0,0 +1,81 @@
+use std::{collections::BTreeMap, sync::RwLock, time::Duration};
+
+use log::info;
+
+use near_base::{ObjectId, Timestamp, NearResult, NearError, ErrorCode, RevocationListObject};
+
+struct CachedRevocation {
+    revocation: Option<RevocationListObject>,
+    update_time: Timestamp,
+}
+
+/// The revocation lists of the owners, they're pushed by the owners or pulled from the ring-smart.
+pub struct RevocationCache {
+    refresh_interval: Duration,
+    lists: RwLock<BTreeMap<ObjectId, CachedRevocation>>,
+}
+
+impl RevocationCache {
+    pub fn new(refresh_interval: Duration) -> Self {
+        Self {
+            refresh_interval,
+            lists: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    /// The list of the owner if it's fetched in the refresh interval,
+    /// None if it has to be pulled again.
+    pub fn get(&self, owner: &ObjectId, now: Timestamp) -> Option<Option<RevocationListObject>> {
+        self.lists.read().unwrap()
+            .get(owner)
+            .filter(| cached | now < cached.update_time + self.refresh_interval.as_micros() as u64)
+            .map(| cached | cached.revocation.clone())
+    }
+
+    /// The list of the owner even if it's stale.
+    pub fn get_stale(&self, owner: &ObjectId) -> Option<RevocationListObject> {
+        self.lists.read().unwrap()
+            .get(owner)
+            .and_then(| cached | cached.revocation.clone())
+    }
+
+    /// Accept the newer list, the older one only refreshes the update time.
+    pub fn update(&self, revocation: RevocationListObject, now: Timestamp) -> NearResult<()> {
+        revocation.verify()?;
+
+        let lists = &mut *self.lists.write().unwrap();
+        let owner = revocation.list().issuer().clone();
+
+        match lists.get_mut(&owner) {
+            Some(cached) => {
+                cached.update_time = now;
+
+                match cached.revocation.as_ref() {
+                    Some(exist) if !exist.list().is_replaced_by(revocation.list()) => {
+                        return Err(NearError::new(ErrorCode::NEAR_ERROR_EXPIRED, format!("{owner} has newer revocation list")));
+                    }
+                    _ => {}
+                }
+
+                info!("update revocation list: {revocation}");
+                cached.revocation = Some(revocation);
+            }
+            None => {
+                info!("update revocation list: {revocation}");
+                lists.insert(owner, CachedRevocation { revocation: Some(revocation), update_time: now });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The owner hasn't revocation list.
+    pub fn update_none(&self, owner: &ObjectId, now: Timestamp) {
+        let lists = &mut *self.lists.write().unwrap();
+
+        match lists.get_mut(owner) {
+            Some(cached) => cached.update_time = now,
+            None => { lists.insert(owner.clone(), CachedRevocation { revocation: None, update_time: now }); }
+        }
+    }
+}
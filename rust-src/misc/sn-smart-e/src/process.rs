@@ -1,18 +1,23 @@
 
-use std::{path::PathBuf, sync::Arc};
+use std::{path::PathBuf, sync::Arc, time::Duration};
 
-use log::trace;
+use log::{trace, warn};
 
-use near_base::{NearResult, ObjectId};
+use near_base::{NearResult, NearError, ErrorCode, ObjectId, DeviceObject, RevocationListObject, check_device, now};
 use near_core::get_service_path;
 use near_transport::{ProcessTrait, RoutineEventTrait};
 use near_util::TopicRef;
 
-use common::{RuntimeProcessTrait, RuntimeStack};
+use common::{RuntimeProcessTrait, RuntimeStack, RoutineTemplate};
 use storage::sqlite_storage::SqliteStorage;
-use topic_util::topics::sn_smart::*;
+use topic_util::topics::{sn_smart::*, ring_smart::CORE_RING_CHAIN_REVOCATION_PUB};
 
-use crate::{routines::{ping::PingRoutine, call::CallRoutine, checkout::CheckoutRoutine}, caches::peer_manager::PeerManager};
+use crate::{routines::{ping::PingRoutine, call::CallRoutine, checkout::CheckoutRoutine, rotate::RotateRoutine, revocation::RevocationRoutine},
+            caches::{peer_manager::PeerManager, revocation::RevocationCache}};
+
+/// The revocation list of the owner is pulled from the ring-smart again after it.
+const REVOCATION_REFRESH_INTERVAL: Duration = Duration::from_secs(300);
+const REVOCATION_PULL_TIMEOUT: Duration = Duration::from_secs(5);
 
 // use crate::{routines::{publish::PublishRoutine, check_out::CheckOutRoutine}, cahces::DeviceObjectItem};
 
@@ -24,6 +29,7 @@ pub(crate) struct Config {
 
 struct ProcessComponents {
     peer_manager: PeerManager,
+    revocation_cache: RevocationCache,
     // device_storage: Box<dyn StorageTrait<DeviceObjectItem>>,
 }
 
@@ -59,6 +65,7 @@ impl Process {
         let mut_ret = unsafe { &mut *(Arc::as_ptr(&ret.0) as *mut ProcessImpl) };
         mut_ret.components = Some(ProcessComponents {
             peer_manager: PeerManager::new(None),
+            revocation_cache: RevocationCache::new(REVOCATION_REFRESH_INTERVAL),
             // device_storage: ret.0.storage.add_storage("device").await?,
         });
 
@@ -70,6 +77,11 @@ impl Process {
         &self.0.components.as_ref().unwrap().peer_manager
     }
 
+    #[inline]
+    pub(crate) fn revocation_cache(&self) -> &RevocationCache {
+        &self.0.components.as_ref().unwrap().revocation_cache
+    }
+
     // #[inline]
     // pub(crate) fn device_storage(&self) -> &dyn StorageTrait<DeviceObjectItem> {
     //     self.0.components.as_ref().unwrap().device_storage.as_ref()
@@ -77,6 +89,51 @@ impl Process {
 }
 
 impl Process {
+    /// The device is refused if it's expired, or it's revoked by its owner.
+    pub(crate) async fn check_device(&self, device: &DeviceObject) -> NearResult<()> {
+        let revocation =
+            match device.desc().owner() {
+                Some(owner) => self.revocation_of(owner).await,
+                None => None,
+            };
+
+        check_device(device, revocation.as_ref().map(| revocation | revocation.list()))
+    }
+
+    /// The cached list is used if it's fresh, otherwise it's pulled from the ring-smart.
+    /// The stale list is kept if the ring-smart isn't reachable.
+    async fn revocation_of(&self, owner: &ObjectId) -> Option<RevocationListObject> {
+        if let Some(revocation) = self.revocation_cache().get(owner, now()) {
+            return revocation;
+        }
+
+        let r =
+            match RoutineTemplate::<Vec<RevocationListObject>>::call(CORE_RING_CHAIN_REVOCATION_PUB.topic().clone(), vec![owner.clone()]).await {
+                Ok(fut) => {
+                    async_std::future::timeout(REVOCATION_PULL_TIMEOUT, fut)
+                        .await
+                        .unwrap_or_else(| _ | Err(NearError::new(ErrorCode::NEAR_ERROR_TIMEOUT, format!("pull the revocation list of {owner} timeout"))))
+                }
+                Err(e) => Err(e),
+            };
+
+        match r {
+            Ok(revocations) => {
+                match revocations.into_iter().find(| revocation | revocation.list().issuer() == owner) {
+                    Some(revocation) => {
+                        if let Err(e) = self.revocation_cache().update(revocation, now()) {
+                            warn!("ignore the revocation list of {owner} with err: {e}");
+                        }
+                    }
+                    None => self.revocation_cache().update_none(owner, now()),
+                }
+            }
+            Err(e) => warn!("failed pull the revocation list of {owner} with err: {e}"),
+        }
+
+        self.revocation_cache().get_stale(owner)
+    }
+
     pub(in self) async fn subscribe_topic(&self) -> NearResult<()> {
         {
             let this = self.clone();
@@ -111,6 +168,28 @@ impl Process {
                 )?;
         }
 
+        {
+            let this = self.clone();
+
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    CORE_SN_SMART_ROTATE_PUB.topic(), 
+                    move || Ok(RotateRoutine::new(this.clone()))
+                )?;
+        }
+
+        {
+            let this = self.clone();
+
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    CORE_SN_SMART_REVOCATION_PUB.topic(), 
+                    move || Ok(RevocationRoutine::new(this.clone()))
+                )?;
+        }
+
         Ok(())
     }
 }
@@ -61,6 +61,14 @@ impl CheckoutRoutine {
                     NearError::new(ErrorCode::NEAR_ERROR_NOTFOUND, error_string)
                 })?;
 
+        self.process
+            .check_device(&peer.desc)
+            .await
+            .map_err(| e | {
+                error!("{e}, sequence: {}", header_meta.sequence());
+                e
+            })?;
+
         Ok(peer.desc)
     }
 
@@ -2,3 +2,5 @@
 pub mod ping;
 pub mod call;
 pub mod checkout;
+pub mod rotate;
+pub mod revocation;
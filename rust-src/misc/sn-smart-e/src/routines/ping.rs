@@ -93,6 +93,14 @@ impl PingRoutine {
             Err(NearError::new(ErrorCode::NEAR_ERROR_FATAL, "missing public key"))
         }?;
 
+        self.process
+            .check_device(&proof_of_device)
+            .await
+            .map_err(| e | {
+                error!("{e}, sequence: {}", header_meta.sequence());
+                e
+            })?;
+
         if !self.process.peer_manager()
                 .peer_heartbeat(
                     proof_of_device,
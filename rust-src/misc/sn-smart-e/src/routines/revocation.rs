@@ -0,0 +1,61 @@
+
+use log::{trace, error, info};
+
+use near_base::{NearResult, builder_codec_macro::Empty, RevocationListObject, now, };
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineEventTrait, RoutineWrap};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_decode_raw_object, try_encode_raw_object};
+
+use crate::process::Process;
+
+pub struct RevocationRoutine {
+    process: Process,
+}
+
+impl RevocationRoutine {
+
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(RevocationRoutine{
+            process
+        }))
+    }
+
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for RevocationRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("RevocationRoutine: header_meta={header_meta}");
+
+        let r = try_decode_raw_object!(RevocationListObject, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Empty> = match r {
+            DataContent::Content(revocation) => self.on_routine(header_meta, revocation).await.into(),
+            DataContent::Error(e) => DataContent::Error(e),
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl RevocationRoutine {
+
+    async fn on_routine(&self, header_meta: &HeaderMeta, revocation: RevocationListObject) -> NearResult<Empty> {
+
+        let issuer = revocation.list().issuer().clone();
+
+        self.process
+            .revocation_cache()
+            .update(revocation, now())
+            .map_err(| e | {
+                error!("{e}, sequence: {}", header_meta.sequence());
+                e
+            })?;
+
+        info!("Successfully update the revocation list of {issuer}");
+
+        Ok(Empty)
+    }
+
+}
@@ -0,0 +1,59 @@
+
+use log::{trace, error, info};
+
+use near_base::{NearResult, builder_codec_macro::Empty, KeyRotationObject, };
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineEventTrait, RoutineWrap};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_decode_raw_object, try_encode_raw_object};
+
+use crate::process::Process;
+
+pub struct RotateRoutine {
+    process: Process,
+}
+
+impl RotateRoutine {
+
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(RotateRoutine{
+            process
+        }))
+    }
+
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for RotateRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("RotateRoutine: header_meta={header_meta}");
+
+        let r = try_decode_raw_object!(KeyRotationObject, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Empty> = match r {
+            DataContent::Content(rotation) => self.on_routine(header_meta, rotation).await.into(),
+            DataContent::Error(e) => DataContent::Error(e),
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl RotateRoutine {
+
+    async fn on_routine(&self, header_meta: &HeaderMeta, rotation: KeyRotationObject) -> NearResult<Empty> {
+
+        self.process
+            .peer_manager()
+            .rotate_key(&rotation)
+            .map_err(| e | {
+                error!("{e}, sequence: {}", header_meta.sequence());
+                e
+            })?;
+
+        info!("Successfully rotate key: {}", rotation.rotation());
+
+        Ok(Empty)
+    }
+
+}
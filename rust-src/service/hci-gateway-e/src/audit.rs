@@ -0,0 +1,82 @@
+
+use log::{trace, error};
+
+use near_base::{hash_data, builder_codec_macro::Empty, RawConvertTo};
+use near_transport::HeaderMeta;
+
+use base::raw_object::RawObjectGuard;
+use common::RoutineTemplate;
+use protos::{DataContent, hci::audit::Audit_record};
+use topic_util::topics::hci_storage::NEAR_THING_STORAGE_AUDIT_APPEND_PUB;
+
+/// The audit record of a control action, it's taken before the request is decoded,
+/// and committed to hci-storage with the outcome.
+/// The permission changes are handled by core-service without passing the gateway, so they aren't recorded yet.
+pub(crate) struct Audit {
+    record: Audit_record,
+    discarded: bool,
+}
+
+impl Audit {
+    pub(crate) fn new(header_meta: &HeaderMeta, req: &RawObjectGuard) -> Self {
+        let digest = 
+            req.desc().content().data()
+                .to_vec()
+                .map(| data | hash_data(&data).to_string())
+                .unwrap_or_default();
+
+        Self {
+            // the requestor, the sequence and the time are taken by hci-storage.
+            record: Audit_record {
+                action: header_meta.topic.to_string(),
+                digest,
+                ..Default::default()
+            },
+            discarded: false,
+        }
+    }
+
+    /// The action is the request topic by default.
+    pub(crate) fn set_action(&mut self, action: String) {
+        self.record.action = action;
+    }
+
+    pub(crate) fn set_thing_ids(&mut self, thing_ids: impl IntoIterator<Item = String>) {
+        self.record.thing_ids = thing_ids.into_iter().collect();
+    }
+
+    /// It isn't a control action, so nothing is recorded.
+    pub(crate) fn discard(&mut self) {
+        self.discarded = true;
+    }
+
+    /// The action isn't failed even if the record isn't committed.
+    pub(crate) async fn commit<T>(mut self, header_meta: &HeaderMeta, r: &DataContent<T>) {
+        if self.discarded {
+            return;
+        }
+
+        if let DataContent::Error(e) = r {
+            self.record.outcome = e.errno().into_u16() as u32;
+            self.record.outcome_message = e.to_string();
+        }
+
+        let sequence = header_meta.sequence();
+
+        let r = 
+            match RoutineTemplate::<Empty>::call_with_headermeta(
+                    header_meta, 
+                    NEAR_THING_STORAGE_AUDIT_APPEND_PUB.topic().clone(), 
+                    self.record
+                )
+                .await {
+                Ok(fut) => fut.await,
+                Err(e) => Err(e),
+            };
+
+        match r {
+            Ok(_) => trace!("audit {} is recorded, sequence: {sequence}", header_meta.topic),
+            Err(e) => error!("failed record audit with err: {e}, sequence: {sequence}"),
+        }
+    }
+}
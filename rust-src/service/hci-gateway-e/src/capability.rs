@@ -0,0 +1,233 @@
+
+use std::collections::HashMap;
+
+use log::{trace, error};
+
+use near_base::{NearResult, NearError, ErrorCode};
+use near_transport::HeaderMeta;
+
+use common::RoutineTemplate;
+use protos::hci::product::{Product_capability, Product_property, Product_property_type, Thing_capability_query, Thing_capability_list};
+use topic_util::topics::hci_storage::NEAR_THING_STORAGE_THING_CAPABILITY_QUERY_PUB;
+
+/// Check the control data of things against the capability of their products,
+/// it must be called before the data is sent to hci-service.
+/// The product which hasn't declared its capability answers the empty one, so its things accept all of data.
+pub(crate) async fn check_things<'a>(
+    header_meta: &HeaderMeta,
+    things: impl Iterator<Item=(&'a str, &'a HashMap<String, String>)>
+) -> NearResult<()> {
+    let things: Vec<(&str, &HashMap<String, String>)> = things.collect();
+
+    if things.is_empty() {
+        return Ok(());
+    }
+
+    let capabilities = query_capabilities(header_meta, things.iter().map(| (thing_id, _) | thing_id.to_string()).collect()).await?;
+
+    for (thing_id, thing_data) in things {
+        let capability =
+            capabilities.capabilities
+                .get(thing_id)
+                .ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_NOTFOUND, format!("[{thing_id}] isn't found in the storage.")))
+                .map_err(| e | {
+                    error!("{e}, sequence: {}", header_meta.sequence());
+                    e
+                })?;
+
+        check_thing_data(thing_id, capability, thing_data)
+            .map_err(| e | {
+                error!("{e}, sequence: {}", header_meta.sequence());
+                e
+            })?;
+    }
+
+    Ok(())
+}
+
+/// Keep the things which accept the control data, the thing which isn't found in the storage is dropped.
+pub(crate) async fn filter_things(
+    header_meta: &HeaderMeta,
+    thing_ids: Vec<String>,
+    thing_data: &HashMap<String, String>
+) -> NearResult<Vec<String>> {
+    if thing_ids.is_empty() {
+        return Ok(thing_ids);
+    }
+
+    let capabilities = query_capabilities(header_meta, thing_ids.clone()).await?;
+
+    Ok(thing_ids.into_iter()
+        .filter(| thing_id | {
+            match capabilities.capabilities.get(thing_id) {
+                Some(capability) => {
+                    check_thing_data(thing_id, capability, thing_data)
+                        .map_err(| e | {
+                            trace!("{e}, ignore it, sequence: {}", header_meta.sequence());
+                        })
+                        .is_ok()
+                }
+                None => {
+                    trace!("[{thing_id}] isn't found in the storage, ignore it, sequence: {}", header_meta.sequence());
+                    false
+                }
+            }
+        })
+        .collect())
+}
+
+async fn query_capabilities(header_meta: &HeaderMeta, thing_ids: Vec<String>) -> NearResult<Thing_capability_list> {
+    RoutineTemplate::<Thing_capability_list>::call_with_headermeta(
+        header_meta,
+        NEAR_THING_STORAGE_THING_CAPABILITY_QUERY_PUB.topic().clone(),
+        Thing_capability_query {
+            thing_ids,
+            ..Default::default()
+        }
+    )
+    .await
+    .map_err(| e | {
+        error!("{e}, sequence: {}", header_meta.sequence());
+        e
+    })?
+    .await
+    .map_err(| e | {
+        error!("failed query capability with err: {e}, sequence: {}", header_meta.sequence());
+        e
+    })
+}
+
+/// The product without any property accepts all of data.
+pub(crate) fn check_thing_data(thing_id: &str, capability: &Product_capability, thing_data: &HashMap<String, String>) -> NearResult<()> {
+    if capability.properties.is_empty() {
+        return Ok(());
+    }
+
+    for (name, value) in thing_data {
+        let property =
+            capability.properties
+                .iter()
+                .find(| property | property.name() == name)
+                .ok_or_else(|| {
+                    NearError::new(ErrorCode::NEAR_ERROR_UNDEFINED, format!("[{thing_id}] undefined [{name}] property."))
+                })?;
+
+        if !property.writable() {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_FORBIDDEN, format!("[{thing_id}]'s [{name}] property is read-only.")));
+        }
+
+        check_value(property, value)
+            .map_err(| error_string | {
+                NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("[{thing_id}]'s [{name}]={value} is invalid, {error_string}."))
+            })?;
+    }
+
+    Ok(())
+}
+
+fn check_range(property: &Product_property, v: f64) -> Result<(), String> {
+    if property.has_min() && v < property.min() {
+        return Err(format!("less than {}", property.min()));
+    }
+
+    if property.has_max() && v > property.max() {
+        return Err(format!("greater than {}", property.max()));
+    }
+
+    if property.step() > 0f64 {
+        let base = if property.has_min() { property.min() } else { 0f64 };
+        let steps = (v - base) / property.step();
+        if (steps - steps.round()).abs() > 1e-6 {
+            return Err(format!("not a multiple of step {}", property.step()));
+        }
+    }
+
+    Ok(())
+}
+
+fn check_value(property: &Product_property, value: &str) -> Result<(), String> {
+    let value = value.trim();
+
+    match property.type_() {
+        Product_property_type::Bool => {
+            match value {
+                "true" | "false" | "1" | "0" => Ok(()),
+                _ => Err("expect bool".to_owned()),
+            }
+        }
+        Product_property_type::Int => {
+            let v = value.parse::<i64>().map_err(| _ | "expect integer".to_owned())?;
+            check_range(property, v as f64)
+        }
+        Product_property_type::Float => {
+            let v = value.parse::<f64>().map_err(| _ | "expect number".to_owned())?;
+            if v.is_finite() {
+                check_range(property, v)
+            } else {
+                Err("expect finite number".to_owned())
+            }
+        }
+        Product_property_type::String => Ok(()),
+        Product_property_type::Enum => {
+            if property.enums.iter().any(| e | e == value) {
+                Ok(())
+            } else {
+                Err(format!("expect one of {:?}", property.enums))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use protos::hci::product::{Product_capability, Product_property, Product_property_type};
+
+    use super::check_thing_data;
+
+    #[test]
+    fn test_check_thing_data() {
+        let capability = Product_capability {
+            properties: vec![
+                Product_property {
+                    name: "brightness".to_owned(),
+                    type_: Product_property_type::Int.into(),
+                    min: Some(0f64),
+                    max: Some(100f64),
+                    step: 5f64,
+                    readable: true,
+                    writable: true,
+                    ..Default::default()
+                },
+                Product_property {
+                    name: "mode".to_owned(),
+                    type_: Product_property_type::Enum.into(),
+                    enums: vec!["cool".to_owned(), "heat".to_owned()],
+                    readable: true,
+                    writable: true,
+                    ..Default::default()
+                },
+                Product_property {
+                    name: "temperature".to_owned(),
+                    type_: Product_property_type::Float.into(),
+                    readable: true,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        let data = | k: &str, v: &str | HashMap::from([(k.to_owned(), v.to_owned())]);
+
+        assert!(check_thing_data("thing", &capability, &data("brightness", "50")).is_ok());
+        assert!(check_thing_data("thing", &capability, &data("brightness", "500")).is_err());
+        assert!(check_thing_data("thing", &capability, &data("brightness", "52")).is_err());
+        assert!(check_thing_data("thing", &capability, &data("brightness", "on")).is_err());
+        assert!(check_thing_data("thing", &capability, &data("mode", "heat")).is_ok());
+        assert!(check_thing_data("thing", &capability, &data("mode", "dry")).is_err());
+        assert!(check_thing_data("thing", &capability, &data("temperature", "20")).is_err());
+        assert!(check_thing_data("thing", &capability, &data("color", "red")).is_err());
+        assert!(check_thing_data("thing", &Product_capability::default(), &data("color", "red")).is_ok());
+    }
+}
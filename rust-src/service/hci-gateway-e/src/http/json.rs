@@ -0,0 +1,175 @@
+
+use protobuf::{MessageFull, reflect::{MessageDescriptor, RuntimeFieldType, RuntimeType}};
+use protobuf_json_mapping::{PrintOptions, ParseOptions};
+use serde_json::{Value, Map, json};
+
+use near_base::{NearResult, NearError, ErrorCode, builder_codec_macro::Empty};
+
+use protos::hci::{brand::*, product::*, thing::*, hci_thing::*, schedule::*, location::*};
+use topic_util::types::hci_types::HciTaskId;
+
+/// The body of the http request and response, the schema is put into the openapi document.
+pub trait JsonCodec: Sized {
+    fn from_json(value: Value) -> NearResult<Self>;
+    fn to_json(&self) -> NearResult<Value>;
+    /// the schema of the type, the messages are put into the components.
+    fn schema(components: &mut Map<String, Value>) -> Value;
+}
+
+impl JsonCodec for Empty {
+    fn from_json(_: Value) -> NearResult<Self> {
+        Ok(Empty)
+    }
+
+    fn to_json(&self) -> NearResult<Value> {
+        Ok(json!({}))
+    }
+
+    fn schema(_: &mut Map<String, Value>) -> Value {
+        json!({ "type": "object" })
+    }
+}
+
+impl JsonCodec for String {
+    fn from_json(value: Value) -> NearResult<Self> {
+        match value {
+            Value::String(v) => Ok(v),
+            _ => Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, "expect a string")),
+        }
+    }
+
+    fn to_json(&self) -> NearResult<Value> {
+        Ok(Value::String(self.clone()))
+    }
+
+    fn schema(_: &mut Map<String, Value>) -> Value {
+        json!({ "type": "string" })
+    }
+}
+
+impl JsonCodec for HciTaskId {
+    fn from_json(value: Value) -> NearResult<Self> {
+        value.as_u64()
+            .and_then(| v | HciTaskId::try_from(v).ok())
+            .ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, "expect a task id"))
+    }
+
+    fn to_json(&self) -> NearResult<Value> {
+        Ok(json!({ "task_id": self }))
+    }
+
+    fn schema(_: &mut Map<String, Value>) -> Value {
+        json!({
+            "type": "object",
+            "properties": { "task_id": { "type": "integer", "format": "int64" } },
+        })
+    }
+}
+
+fn message_from_json<M: MessageFull>(value: Value) -> NearResult<M> {
+    protobuf_json_mapping::parse_from_str_with_options::<M>(&value.to_string(), &ParseOptions {
+        ignore_unknown_fields: true,
+        ..Default::default()
+    })
+    .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, format!("failed parse {} with err: {e}", M::descriptor().name())))
+}
+
+fn message_to_json<M: MessageFull>(message: &M) -> NearResult<Value> {
+    let text =
+        protobuf_json_mapping::print_to_string_with_options(message, &PrintOptions {
+            proto_field_name: true,
+            always_output_default_values: true,
+            ..Default::default()
+        })
+        .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_ENCODING_FORMAT, format!("failed print {} with err: {e}", M::descriptor().name())))?;
+
+    serde_json::from_str(&text)
+        .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_ENCODING_FORMAT, format!("failed print {} with err: {e}", M::descriptor().name())))
+}
+
+fn type_schema(t: &RuntimeType, components: &mut Map<String, Value>) -> Value {
+    match t {
+        RuntimeType::I32 | RuntimeType::U32 => json!({ "type": "integer", "format": "int32" }),
+        // the 64 bits integers are strings in the proto3 json.
+        RuntimeType::I64 | RuntimeType::U64 => json!({ "type": "string", "format": "int64" }),
+        RuntimeType::F32 => json!({ "type": "number", "format": "float" }),
+        RuntimeType::F64 => json!({ "type": "number", "format": "double" }),
+        RuntimeType::Bool => json!({ "type": "boolean" }),
+        RuntimeType::String => json!({ "type": "string" }),
+        RuntimeType::VecU8 => json!({ "type": "string", "format": "byte" }),
+        RuntimeType::Enum(e) => {
+            json!({
+                "type": "string",
+                "enum": e.values().map(| v | v.name().to_owned()).collect::<Vec<_>>(),
+            })
+        }
+        RuntimeType::Message(m) => message_schema(m, components),
+    }
+}
+
+fn message_schema(descriptor: &MessageDescriptor, components: &mut Map<String, Value>) -> Value {
+    let name = descriptor.full_name().to_owned();
+
+    if !components.contains_key(&name) {
+        // take the place first, the message could refer to itself.
+        components.insert(name.clone(), Value::Null);
+
+        let mut properties = Map::new();
+        for field in descriptor.fields() {
+            let schema = match field.runtime_field_type() {
+                RuntimeFieldType::Singular(t) => type_schema(&t, components),
+                RuntimeFieldType::Repeated(t) => json!({ "type": "array", "items": type_schema(&t, components) }),
+                RuntimeFieldType::Map(_, v) => json!({ "type": "object", "additionalProperties": type_schema(&v, components) }),
+            };
+            properties.insert(field.name().to_owned(), schema);
+        }
+
+        components.insert(name.clone(), json!({ "type": "object", "properties": properties }));
+    }
+
+    json!({ "$ref": format!("#/components/schemas/{name}") })
+}
+
+macro_rules! impl_json_message {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl JsonCodec for $t {
+                fn from_json(value: Value) -> NearResult<Self> {
+                    message_from_json(value)
+                }
+
+                fn to_json(&self) -> NearResult<Value> {
+                    message_to_json(self)
+                }
+
+                fn schema(components: &mut Map<String, Value>) -> Value {
+                    message_schema(&<$t>::descriptor(), components)
+                }
+            }
+        )*
+    };
+}
+
+impl_json_message!(
+    Brand_info, Brand_info_list,
+    Product_info, Product_info_list,
+    Thing_info_list, Thing_query_all,
+    Hci_add_thing, Hci_crud_thing, Hci_ctrl_thing, Hci_task_result, Hci_thing_list,
+    Schedule_info, Schedule_list, Schedule_add, Schedule_relation_list_update,
+    Location_ctrl,
+);
+
+#[test]
+fn test_json_message() {
+    let crud: Hci_crud_thing = JsonCodec::from_json(json!({ "thing_id": "abc", "method": "pair", "unknown": 1 })).unwrap();
+    assert_eq!(crud.thing_id, "abc");
+    assert_eq!(crud.method.enum_value(), Ok(hci_crud_thing::Hci_crud_m::pair));
+
+    let value = crud.to_json().unwrap();
+    assert_eq!(value["thing_id"], "abc");
+    assert_eq!(value["method"], "pair");
+
+    let mut components = Map::new();
+    assert_eq!(Hci_crud_thing::schema(&mut components)["$ref"], "#/components/schemas/hci_crud_thing");
+    assert_eq!(components["hci_crud_thing"]["properties"]["method"]["enum"][1], "pair");
+}
@@ -0,0 +1,286 @@
+
+mod json;
+mod routes;
+mod openapi;
+
+use std::{sync::Arc, net::SocketAddr, path::Path, time::Duration};
+
+use log::{info, error, trace};
+use serde_json::{Value, json};
+use tide::{Request, Response, StatusCode};
+
+use near_base::{NearResult, NearError, ErrorCode, ObjectId};
+use near_util::HTTP_API_PORT;
+
+use crate::process::Process;
+
+use routes::{Route, Method, Caller};
+
+pub const API_PREFIX: &'static str = "/api";
+const TOKEN_FILE: &'static str = "http.token";
+
+#[derive(Clone)]
+pub struct Config {
+    /// It's disabled by default, the api should be enabled by the config.
+    pub enable: bool,
+    /// It's localhost by default, the dashboards on the other hosts should be proxied.
+    pub listen: SocketAddr,
+    /// The bearer token of the requests, it's generated into the work path if it's empty.
+    pub token: String,
+    /// How long the routine is waited for.
+    pub timeout: Duration,
+    /// The people who is authenticated by the token, it's the owner if it's none.
+    pub people: Option<ObjectId>,
+}
+
+impl std::default::Default for Config {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            listen: SocketAddr::from(([127, 0, 0, 1], HTTP_API_PORT)),
+            token: String::default(),
+            timeout: Duration::from_secs(10),
+            people: None,
+        }
+    }
+}
+
+struct ServerState {
+    process: Process,
+    token: String,
+    timeout: Duration,
+    people: Option<ObjectId>,
+}
+
+impl ServerState {
+    fn caller(&self) -> NearResult<Caller> {
+        self.people.as_ref()
+            .or_else(|| self.process.right_stack().owner())
+            .map(| people | Caller {
+                process: self.process.clone(),
+                people: people.clone(),
+            })
+            .ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_FORBIDDEN, "the token isn't bound to any people"))
+    }
+}
+
+/// The JSON api of the gateway, each route calls a topic of the gateway or the storage as the people of the token.
+pub struct HttpServer;
+
+impl HttpServer {
+    pub fn start(process: Process, config: Config, work_path: &Path) -> NearResult<()> {
+        if !config.enable {
+            info!("http api is disabled.");
+            return Ok(());
+        }
+
+        let state = Arc::new(ServerState {
+            process,
+            token: Self::load_token(config.token, work_path)?,
+            timeout: config.timeout,
+            people: config.people,
+        });
+
+        let routes = routes::routes();
+        let document = Arc::new(openapi::document(API_PREFIX, &routes));
+
+        let mut app = tide::new();
+
+        app.at(&format!("{API_PREFIX}/openapi.json"))
+            .get(move | _: Request<()> | {
+                let document = document.clone();
+                async move { Ok(json_response(StatusCode::Ok, &document)) }
+            });
+
+        for route in routes {
+            let method = route.method;
+            let mut at = app.at(&format!("{API_PREFIX}{}", route.path));
+
+            let route = Arc::new(route);
+            let state = state.clone();
+
+            let handler = move | req: Request<()> | {
+                let route = route.clone();
+                let state = state.clone();
+                async move { Ok(handle(&state, &route, req).await) }
+            };
+
+            match method {
+                Method::Get => at.get(handler),
+                Method::Post => at.post(handler),
+                Method::Put => at.put(handler),
+                Method::Delete => at.delete(handler),
+            };
+        }
+
+        let listen = config.listen;
+        async_std::task::spawn(async move {
+            info!("http api is listening on {listen}");
+
+            if let Err(e) = app.listen(listen).await {
+                error!("failed listen http api on {listen} with err: {e}");
+            }
+        });
+
+        Ok(())
+    }
+
+    fn load_token(token: String, work_path: &Path) -> NearResult<String> {
+        if !token.is_empty() {
+            return Ok(token);
+        }
+
+        let token_path = work_path.join(TOKEN_FILE);
+
+        if let Ok(token) = std::fs::read_to_string(&token_path) {
+            let token = token.trim();
+            if !token.is_empty() {
+                return Ok(token.to_owned());
+            }
+        }
+
+        let token = hex::encode(rand::random::<[u8; 16]>());
+
+        Self::write_token(&token_path, &token)
+            .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_SYSTERM, format!("failed write {} with err: {e}", token_path.display())))?;
+
+        info!("http api token is generated in {}", token_path.display());
+
+        Ok(token)
+    }
+
+    // The token is written into a temp file which is created only readable by the owner,
+    // and renamed to the token file, so the token is never readable by the others.
+    fn write_token(token_path: &Path, token: &str) -> std::io::Result<()> {
+        use std::io::Write;
+
+        let temp_path = token_path.with_extension("tmp");
+        let _ = std::fs::remove_file(&temp_path);
+
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        let mut file = options.open(&temp_path)?;
+        file.write_all(token.as_bytes())?;
+        file.sync_all()?;
+
+        std::fs::rename(&temp_path, token_path)
+    }
+}
+
+async fn handle(state: &ServerState, route: &Route, mut req: Request<()>) -> Response {
+    trace!("http {} {}", route.method.as_str(), req.url().path());
+
+    if !authorized(state, &req) {
+        return status_response(StatusCode::Unauthorized, NearError::new(ErrorCode::NEAR_ERROR_FORBIDDEN, "invalid token"));
+    }
+
+    let caller = match state.caller() {
+        Ok(caller) => caller,
+        Err(e) => return error_response(e),
+    };
+
+    let body =
+        match req.body_string().await {
+            Ok(body) if body.trim().is_empty() => Value::Null,
+            Ok(body) => match serde_json::from_str(&body) {
+                Ok(body) => body,
+                Err(e) => return error_response(NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, format!("invalid json with err: {e}"))),
+            },
+            Err(e) => return error_response(NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, format!("failed read body with err: {e}"))),
+        };
+
+    let query =
+        if route.method == Method::Get {
+            req.url().query_pairs().map(| (k, v) | (k.into_owned(), v.into_owned())).collect()
+        } else {
+            vec![]
+        };
+
+    let param = route.param.and_then(| (name, _) | req.param(name).ok().map(| v | v.to_owned()));
+
+    let r =
+        match route.request(body, query, param) {
+            Ok(body) => route.endpoint.call(caller, route.topic.clone(), body, state.timeout).await,
+            Err(e) => Err(e),
+        };
+
+    match r {
+        Ok(value) => json_response(StatusCode::Ok, &value),
+        Err(e) => {
+            error!("failed http {} {} with err: {e}", route.method.as_str(), req.url().path());
+            error_response(e)
+        }
+    }
+}
+
+fn authorized(state: &ServerState, req: &Request<()>) -> bool {
+    let token =
+        req.header("Authorization")
+            .and_then(| v | v.last().as_str().strip_prefix("Bearer "))
+            .map(| v | v.trim().as_bytes())
+            .unwrap_or_default();
+
+    let expect = state.token.as_bytes();
+
+    // don't leak the length of the matched prefix.
+    token.len() == expect.len() &&
+    token.iter().zip(expect.iter()).fold(0u8, | r, (a, b) | r | (a ^ b)) == 0
+}
+
+fn json_response(status: StatusCode, value: &Value) -> Response {
+    let mut resp = Response::new(status);
+    resp.set_content_type("application/json");
+    resp.set_body(value.to_string());
+    resp
+}
+
+fn error_response(e: NearError) -> Response {
+    let status =
+        match e.errno() {
+            ErrorCode::NEAR_ERROR_NOTFOUND => StatusCode::NotFound,
+            ErrorCode::NEAR_ERROR_INVALIDPARAM | ErrorCode::NEAR_ERROR_INVALIDFORMAT => StatusCode::BadRequest,
+            ErrorCode::NEAR_ERROR_FORBIDDEN => StatusCode::Forbidden,
+            ErrorCode::NEAR_ERROR_ALREADY_EXIST => StatusCode::Conflict,
+            ErrorCode::NEAR_ERROR_TIMEOUT => StatusCode::GatewayTimeout,
+            _ => StatusCode::InternalServerError,
+        };
+
+    status_response(status, e)
+}
+
+fn status_response(status: StatusCode, e: NearError) -> Response {
+    json_response(status, &json!({
+        "errno": e.errno().into_u16(),
+        "message": e.to_string(),
+    }))
+}
+
+#[test]
+fn test_load_token() {
+    let work_path = std::env::temp_dir().join(format!("hci-gateway-token-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&work_path);
+    std::fs::create_dir_all(&work_path).unwrap();
+
+    let token = HttpServer::load_token(String::default(), &work_path).unwrap();
+    assert_eq!(token.len(), 32);
+    assert_eq!(HttpServer::load_token(String::default(), &work_path).unwrap(), token);
+    assert_eq!(HttpServer::load_token("configured".to_owned(), &work_path).unwrap(), "configured");
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(work_path.join(TOKEN_FILE)).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    assert!(!work_path.join(TOKEN_FILE).with_extension("tmp").exists());
+
+    let _ = std::fs::remove_dir_all(&work_path);
+}
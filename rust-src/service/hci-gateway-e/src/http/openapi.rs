@@ -0,0 +1,110 @@
+
+use serde_json::{Value, Map, json};
+
+use super::routes::{Route, Method};
+
+/// ":thing_id" of tide is "{thing_id}" in openapi.
+fn openapi_path(path: &str) -> String {
+    path.split('/')
+        .map(| seg | match seg.strip_prefix(':') {
+            Some(name) => format!("{{{name}}}"),
+            None => seg.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// The openapi document of the routes, the schemas are generated from the protobuf descriptors.
+pub fn document(prefix: &str, routes: &[Route]) -> Value {
+    let mut components = Map::new();
+    let mut paths = Map::new();
+
+    for route in routes {
+        let mut operation = Map::new();
+        operation.insert("summary".to_owned(), json!(route.summary));
+        operation.insert("x-topic".to_owned(), json!(route.topic.to_string()));
+
+        let mut parameters = vec![];
+        if let Some((name, _)) = route.param {
+            parameters.push(json!({
+                "name": name,
+                "in": "path",
+                "required": true,
+                "schema": { "type": "string" },
+            }));
+        }
+
+        let request = (route.endpoint.request)(&mut components);
+        match (route.method, route.param) {
+            // the path param is the whole request.
+            (_, Some((_, ""))) => {}
+            // the fields of the request are taken from the query.
+            (Method::Get, _) => {
+                operation.insert("description".to_owned(), json!("The fields of the request are taken from the query."));
+            }
+            _ => {
+                operation.insert("requestBody".to_owned(), json!({
+                    "required": false,
+                    "content": { "application/json": { "schema": request } },
+                }));
+            }
+        }
+
+        if !parameters.is_empty() {
+            operation.insert("parameters".to_owned(), Value::Array(parameters));
+        }
+
+        let response = (route.endpoint.response)(&mut components);
+        operation.insert("responses".to_owned(), json!({
+            "200": {
+                "description": "OK",
+                "content": { "application/json": { "schema": response } },
+            },
+            "default": {
+                "description": "the error of the near",
+                "content": { "application/json": { "schema": { "$ref": "#/components/schemas/error" } } },
+            },
+        }));
+
+        paths.entry(format!("{prefix}{}", openapi_path(route.path)))
+            .or_insert_with(|| Value::Object(Map::new()))
+            .as_object_mut()
+            .unwrap()
+            .insert(route.method.as_str().to_owned(), Value::Object(operation));
+    }
+
+    components.insert("error".to_owned(), json!({
+        "type": "object",
+        "properties": {
+            "errno": { "type": "integer" },
+            "message": { "type": "string" },
+        },
+    }));
+
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "hci-gateway",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "paths": paths,
+        "components": {
+            "schemas": components,
+            "securitySchemes": {
+                "token": { "type": "http", "scheme": "bearer" },
+            },
+        },
+        "security": [ { "token": [] } ],
+    })
+}
+
+#[test]
+fn test_document() {
+    let doc = document("/api", &super::routes::routes());
+
+    assert!(doc["paths"]["/api/things/{thing_id}/control"]["post"]["requestBody"].is_object());
+    assert!(doc["paths"]["/api/things"]["get"].is_object());
+    assert!(doc["paths"]["/api/things"]["post"].is_object());
+    assert!(doc["paths"]["/api/brands/{brand_id}"]["get"]["requestBody"].is_null());
+    assert!(doc["components"]["schemas"]["hci_ctrl_thing"].is_object());
+}
@@ -0,0 +1,295 @@
+
+use std::{future::Future, pin::Pin, time::Duration};
+
+use serde_json::{Value, Map};
+
+use near_base::{NearResult, NearError, ErrorCode, Serialize, Deserialize, ObjectId, now, builder_codec_macro::Empty, sequence::SequenceString};
+use near_transport::{HeaderMeta, CommandParam, CreatorMeta, process::provider::EventTextResult};
+use near_util::Topic;
+
+use base::raw_object::RawObjectGuard;
+use common::{RoutineTemplate, RuntimeStack};
+use protos::{DataContent, hci::{brand::*, product::*, thing::*, hci_thing::*, schedule::*, location::*}};
+use topic_util::{topics::{hci_gateway::*, hci_storage::*}, types::hci_types::HciTaskId};
+
+use crate::process::Process;
+
+use super::json::JsonCodec;
+
+type InvokeFuture = Pin<Box<dyn Future<Output = NearResult<Value>> + Send>>;
+
+/// The people who is authenticated by the token, the requests are made by it.
+#[derive(Clone)]
+pub struct Caller {
+    pub process: Process,
+    pub people: ObjectId,
+}
+
+impl Caller {
+    fn header_meta(&self, topic: &Topic) -> HeaderMeta {
+        HeaderMeta {
+            command: CommandParam::Request(SequenceString::from(&rand::random::<[u8; 32]>())),
+            creator: Some(CreatorMeta {
+                creator: Some(self.people.clone()),
+                ..Default::default()
+            }),
+            requestor: self.people.clone(),
+            to: RuntimeStack::get_instance().local().object_id().clone(),
+            topic: topic.clone(),
+            timestamp: now(),
+            net_meta: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Delete,
+}
+
+impl Method {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Get => "get",
+            Self::Post => "post",
+            Self::Put => "put",
+            Self::Delete => "delete",
+        }
+    }
+}
+
+/// Call the topic with the request, and wait for its response.
+#[derive(Clone, Copy)]
+pub struct Endpoint {
+    pub request: fn(&mut Map<String, Value>) -> Value,
+    pub response: fn(&mut Map<String, Value>) -> Value,
+    invoke: fn(Caller, Topic, Value, Duration) -> InvokeFuture,
+}
+
+impl Endpoint {
+    fn of<Req, Resp>() -> Self
+    where Req: JsonCodec + Serialize + Send + 'static,
+          Resp: JsonCodec + Serialize + Deserialize + Send + Sync + Default + Clone + 'static {
+        Self {
+            request: Req::schema,
+            response: Resp::schema,
+            invoke: invoke::<Req, Resp>,
+        }
+    }
+
+    pub async fn call(&self, caller: Caller, topic: Topic, body: Value, timeout: Duration) -> NearResult<Value> {
+        (self.invoke)(caller, topic, body, timeout).await
+    }
+}
+
+fn invoke<Req, Resp>(caller: Caller, topic: Topic, body: Value, timeout: Duration) -> InvokeFuture
+where Req: JsonCodec + Serialize + Send + 'static,
+      Resp: JsonCodec + Serialize + Deserialize + Send + Sync + Default + Clone + 'static {
+    Box::pin(async move {
+        let req = Req::from_json(body)?;
+
+        async_std::future::timeout(timeout, dispatch::<Req, Resp>(&caller, topic.clone(), req))
+            .await
+            .map_err(| _ | NearError::new(ErrorCode::NEAR_ERROR_TIMEOUT, format!("call {topic} timeout")))??
+            .to_json()
+    })
+}
+
+/// The request is dispatched to the routine of the gateway as the people, so the routine checks the right of the people.
+/// The queries of the storage haven't the routine in the gateway, they're checked here before the gateway calls them.
+async fn dispatch<Req, Resp>(caller: &Caller, topic: Topic, req: Req) -> NearResult<Resp>
+where Req: Serialize + Send + 'static,
+      Resp: Serialize + Deserialize + Send + Sync + Default + Clone + 'static {
+    let header_meta = caller.header_meta(&topic);
+
+    let routine = 
+        match RuntimeStack::get_instance().topic_routine_manager().call(&topic.topic_d()?) {
+            Ok(routine) => routine,
+            Err(_) => {
+                caller.process.check_right(&header_meta, None)?;
+                return RoutineTemplate::<Resp>::call(topic, req).await?.await;
+            }
+        };
+
+    let req = protos::RawObjectHelper::encode_with_raw(req)?;
+    let data = {
+        let mut data = vec![0u8; req.raw_capacity()];
+        let _ = req.serialize(&mut data)?;
+        data
+    };
+
+    match routine.emit(&header_meta, data).await? {
+        EventTextResult::Response(resp) => {
+            let (resp, _) = RawObjectGuard::deserialize(&resp.data)?;
+
+            match protos::RawObjectHelper::decode::<Resp>(resp)? {
+                DataContent::Content(resp) => Ok(resp),
+                DataContent::Error(e) => Err(e),
+            }
+        }
+        _ => Err(NearError::new(ErrorCode::NEAR_ERROR_IGNORE, format!("{topic} hasn't response"))),
+    }
+}
+
+pub struct Route {
+    pub method: Method,
+    /// The tide path, the params begin with ':'.
+    pub path: &'static str,
+    pub summary: &'static str,
+    pub topic: Topic,
+    /// The path param is set into the field of the request, or it's the request if the field is empty.
+    pub param: Option<(&'static str, &'static str)>,
+    /// The fields which are fixed by the route.
+    pub fixed: &'static [(&'static str, &'static str)],
+    pub endpoint: Endpoint,
+}
+
+impl Route {
+    fn new(method: Method, path: &'static str, summary: &'static str, topic: &Topic, endpoint: Endpoint) -> Self {
+        Self {
+            method, path, summary,
+            topic: topic.clone(),
+            param: None,
+            fixed: &[],
+            endpoint,
+        }
+    }
+
+    fn param(mut self, name: &'static str, field: &'static str) -> Self {
+        self.param = Some((name, field));
+        self
+    }
+
+    fn fixed(mut self, fixed: &'static [(&'static str, &'static str)]) -> Self {
+        self.fixed = fixed;
+        self
+    }
+
+    /// Build the request from the body, the query and the path param.
+    pub fn request(&self, body: Value, query: Vec<(String, String)>, param: Option<String>) -> NearResult<Value> {
+        let mut body = match body {
+            Value::Null => Value::Object(Map::new()),
+            body => body,
+        };
+
+        match (self.param, param) {
+            (Some((_, "")), Some(param)) => { return Ok(Value::String(param)); }
+            (Some((_, field)), Some(param)) => { set_field(&mut body, field, Value::String(param))?; }
+            _ => {}
+        }
+
+        for (k, v) in query {
+            set_field(&mut body, &k, Value::String(v))?;
+        }
+
+        for (k, v) in self.fixed {
+            set_field(&mut body, k, Value::String(v.to_string()))?;
+        }
+
+        Ok(body)
+    }
+}
+
+fn set_field(body: &mut Value, field: &str, value: Value) -> NearResult<()> {
+    body.as_object_mut()
+        .map(| o | { o.insert(field.to_owned(), value); })
+        .ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, "the body must be an object"))
+}
+
+pub fn routes() -> Vec<Route> {
+    use Method::*;
+
+    vec![
+        // brands
+        Route::new(Get, "/brands", "query all brands",
+                   NEAR_THING_STORAGE_BRAND_QUERY_ALL_PUB.topic(), Endpoint::of::<Empty, Brand_info_list>()),
+        Route::new(Get, "/brands/:brand_id", "query the brand",
+                   NEAR_THING_STORAGE_BRAND_QUERY_PUB.topic(), Endpoint::of::<String, Brand_info>())
+            .param("brand_id", ""),
+
+        // products
+        Route::new(Get, "/products", "query all products",
+                   NEAR_THING_STORAGE_PRODUCT_QUERY_ALL_PUB.topic(), Endpoint::of::<Empty, Product_info_list>()),
+        Route::new(Get, "/products/:product_id", "query the product",
+                   NEAR_THING_STORAGE_PRODUCT_QUERY_PUB.topic(), Endpoint::of::<String, Product_info>())
+            .param("product_id", ""),
+
+        // things
+        Route::new(Get, "/things", "query the things, filtered by brand_id and product_id",
+                   NEAR_THING_STORAGE_THING_QUERY_ALL_PUB.topic(), Endpoint::of::<Thing_query_all, Thing_info_list>()),
+        Route::new(Post, "/things", "add the thing which is found by the search",
+                   NEAR_THING_GATEWAY_ADD_THING_PUB.topic(), Endpoint::of::<Hci_add_thing, HciTaskId>()),
+        Route::new(Delete, "/things/:thing_id", "remove the thing",
+                   NEAR_THING_GATEWAY_CRUD_THING_PUB.topic(), Endpoint::of::<Hci_crud_thing, HciTaskId>())
+            .param("thing_id", "thing_id")
+            .fixed(&[("method", "remove")]),
+        Route::new(Post, "/things/:thing_id/pair", "pair the thing",
+                   NEAR_THING_GATEWAY_CRUD_THING_PUB.topic(), Endpoint::of::<Hci_crud_thing, HciTaskId>())
+            .param("thing_id", "thing_id")
+            .fixed(&[("method", "pair")]),
+        Route::new(Post, "/things/:thing_id/unpair", "remove the pair of the thing",
+                   NEAR_THING_GATEWAY_CRUD_THING_PUB.topic(), Endpoint::of::<Hci_crud_thing, HciTaskId>())
+            .param("thing_id", "thing_id")
+            .fixed(&[("method", "remove_pair")]),
+        Route::new(Post, "/things/:thing_id/query", "query the status of the thing",
+                   NEAR_THING_GATEWAY_CRUD_THING_PUB.topic(), Endpoint::of::<Hci_crud_thing, HciTaskId>())
+            .param("thing_id", "thing_id")
+            .fixed(&[("method", "query")]),
+        Route::new(Post, "/things/:thing_id/control", "control the thing",
+                   NEAR_THING_GATEWAY_CTRL_THING_PUB.topic(), Endpoint::of::<Hci_ctrl_thing, HciTaskId>())
+            .param("thing_id", "thing_id"),
+
+        // search and the results of the tasks
+        Route::new(Post, "/search/:brand_id", "search the things of the brand",
+                   NEAR_THING_GATEWAY_SEARCH_PUB.topic(), Endpoint::of::<String, HciTaskId>())
+            .param("brand_id", ""),
+        Route::new(Post, "/tasks/result", "get the result of the task",
+                   NEAR_THING_GATEWAY_SEARCH_RESULT_PUB.topic(), Endpoint::of::<Hci_task_result, Hci_thing_list>()),
+
+        // schedules
+        Route::new(Get, "/schedules", "query all schedules",
+                   NEAR_THING_STORAGE_SCHEDULE_QUERYALL_PUB.topic(), Endpoint::of::<Empty, Schedule_list>()),
+        Route::new(Get, "/schedules/:schedule_id", "query the schedule",
+                   NEAR_THING_STORAGE_SCHEDULE_QUERY_PUB.topic(), Endpoint::of::<String, Schedule_info>())
+            .param("schedule_id", ""),
+        Route::new(Post, "/schedules", "add the schedule",
+                   NEAR_THING_GATEWAY_SCHEDULE_ADD_PUB.topic(), Endpoint::of::<Schedule_add, Schedule_info>()),
+        Route::new(Put, "/schedules/:schedule_id", "update the schedule",
+                   NEAR_THING_GATEWAY_SCHEDULE_UPDATE_PUB.topic(), Endpoint::of::<Schedule_info, Schedule_info>())
+            .param("schedule_id", "schedule_id"),
+        Route::new(Delete, "/schedules/:schedule_id", "remove the schedule",
+                   NEAR_THING_GATEWAY_SCHEDULE_REMOVE_PUB.topic(), Endpoint::of::<String, Empty>())
+            .param("schedule_id", ""),
+        Route::new(Put, "/schedules/:schedule_id/relations", "insert or remove the things of the schedule",
+                   NEAR_THING_GATEWAY_SCHEDULE_UPDATE_RELATIONS_PUB.topic(), Endpoint::of::<Schedule_relation_list_update, Schedule_info>())
+            .param("schedule_id", "schedule_id"),
+
+        // locations
+        Route::new(Post, "/locations/:location_id/control", "control all things of the location",
+                   NEAR_THING_GATEWAY_LOCATION_CTRL_PUB.topic(), Endpoint::of::<Location_ctrl, HciTaskId>())
+            .param("location_id", "location_id"),
+    ]
+}
+
+#[test]
+fn test_route_request() {
+    use serde_json::json;
+
+    let routes = routes();
+
+    let unpair = routes.iter().find(| r | r.path == "/things/:thing_id/unpair").unwrap();
+    let body = unpair.request(Value::Null, vec![], Some("abc".to_owned())).unwrap();
+    assert_eq!(body, json!({ "thing_id": "abc", "method": "remove_pair" }));
+
+    let brand = routes.iter().find(| r | r.path == "/brands/:brand_id").unwrap();
+    assert_eq!(brand.request(Value::Null, vec![], Some("b1".to_owned())).unwrap(), json!("b1"));
+
+    let things = routes.iter().find(| r | r.path == "/things" && r.method == Method::Get).unwrap();
+    let body = things.request(Value::Null, vec![("brand_id".to_owned(), "b1".to_owned())], None).unwrap();
+    assert_eq!(body, json!({ "brand_id": "b1" }));
+
+    assert!(unpair.request(json!([1]), vec![], Some("abc".to_owned())).is_err());
+}
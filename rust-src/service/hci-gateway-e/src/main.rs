@@ -1,21 +1,82 @@
 
 mod process;
 mod p;
+mod capability;
 // mod manager;
 mod routines;
+mod http;
+mod audit;
+
+use std::{path::PathBuf, str::FromStr};
+
+use near_base::{NearResult, NearError, ErrorCode, ObjectId};
 
 use common::{ProcessCommandBuild, ProcessAction, };
 use log::info;
+use near_core::get_data_path;
 use process::Process;
 
 use crate::p::SERVICE_NAME;
 
+async fn load_from_config(service_name: &str) -> NearResult<http::Config> {
+    let toml_file = PathBuf::new().with_file_name(service_name).with_extension("toml");
+    let content = 
+        async_std::fs::read_to_string(get_data_path().join(toml_file.as_path()))
+            .await
+            .map_err(| _ | {
+                let error_string = format!("Missing [{}] file, will run with default configuration", toml_file.display());
+                println!("{error_string}");
+                NearError::new(ErrorCode::NEAR_ERROR_NOTFOUND, error_string)
+            })?;
+
+    let val: toml::Value = 
+        toml::from_str(&content).map_err(| e | {
+            let error_string = format!("parse [{}] with err: {e}", toml_file.display());
+            println!("{error_string}");
+            NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, error_string)
+        })?;
+
+    let mut config = http::Config::default();
+
+    if let Some(http) = val.get("http") {
+        config.enable = http.get("enable").and_then(| v | v.as_bool()).unwrap_or(config.enable);
+        config.token = http.get("token").and_then(| v | v.as_str()).map(| v | v.to_owned()).unwrap_or(config.token);
+        config.timeout = 
+            http.get("timeout")
+                .and_then(| v | v.as_integer())
+                .map(| v | std::time::Duration::from_secs(v as u64))
+                .unwrap_or(config.timeout);
+
+        if let Some(people) = http.get("people").and_then(| v | v.as_str()) {
+            config.people = 
+                Some(ObjectId::from_str(people)
+                        .map_err(| e | {
+                            let error_string = format!("invalid [http.people] {people} with err: {e}");
+                            println!("{error_string}");
+                            e
+                        })?);
+        }
+
+        if let Some(listen) = http.get("listen").and_then(| v | v.as_str()) {
+            config.listen = 
+                listen.parse()
+                    .map_err(| e | {
+                        let error_string = format!("invalid [http.listen] {listen} with err: {e}");
+                        println!("{error_string}");
+                        NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, error_string)
+                    })?;
+        }
+    }
+
+    Ok(config)
+}
+
 #[async_std::main]
 async fn main() {
     let process = 
         match ProcessCommandBuild::with_runtime()
                     .name(SERVICE_NAME)
-                    .launch(Box::new(Process::new(SERVICE_NAME.to_owned())), None).await {
+                    .launch(Box::new(Process::new(SERVICE_NAME.to_owned(), load_from_config(SERVICE_NAME).await.ok())), None).await {
         Ok(process) => {
             if let ProcessAction::Start(process) = process {
                 process
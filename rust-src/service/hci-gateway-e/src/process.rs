@@ -1,12 +1,15 @@
 
 use std::{sync::Arc, path::PathBuf};
 
-use log::trace;
+use log::{trace, error};
 
-use near_base::NearResult;
+use near_base::{NearResult, NearError, ErrorCode};
+use near_transport::HeaderMeta;
 
+use base::MessageType;
 use common::{RuntimeProcessTrait, RuntimeStack};
-use topic_util::topics::hci_gateway::*;
+use right::{RightStack, RightStackConfig, Privilege};
+use topic_util::topics::{hci_gateway::*, hci_storage::*};
 
 use crate::routines::schedule::add::AddScheduleRoutine;
 use crate::routines::schedule::remove::RemoveScheduleRoutine;
@@ -17,6 +20,8 @@ use crate::routines::things::crud_thing::CrudThingRoutine;
 use crate::routines::things::ctrl_thing::CtrlThingRoutine;
 use crate::routines::things::search::SearchRoutine;
 use crate::routines::things::search_result::SearchResultRoutine;
+use crate::routines::location::ctrl::CtrlLocationRoutine;
+use crate::http::{self, HttpServer};
 
 #[derive(Clone)]
 struct Config {
@@ -24,9 +29,11 @@ struct Config {
     work_path: PathBuf,
     #[allow(unused)]
     thing_cache_path: PathBuf,
+    http_config: http::Config,
 }
 
 struct ProcessComponents {
+    right_stack: once_cell::sync::OnceCell<RightStack>,
 }
 
 struct ProcessImpl {
@@ -42,7 +49,7 @@ struct ProcessImpl {
 pub struct Process(Arc<ProcessImpl>);
 
 impl Process {
-    pub fn new(service_name: String,) -> Self {
+    pub fn new(service_name: String, http_config: Option<http::Config>) -> Self {
         let work_path = near_core::get_service_path(service_name.as_str());
 
         let ret = Self(Arc::new(ProcessImpl {
@@ -54,11 +61,13 @@ impl Process {
                     thing_cache_path
                 },
                 work_path: work_path,
+                http_config: http_config.unwrap_or_default(),
             },
             components: None
         }));
 
         let components = ProcessComponents {
+            right_stack: once_cell::sync::OnceCell::new(),
         };
 
         {
@@ -69,6 +78,68 @@ impl Process {
         ret
     }
 
+    #[inline]
+    pub(crate) fn right_stack(&self) -> &RightStack {
+        self.0.components.as_ref().unwrap().right_stack.get().expect("right stack must open.")
+    }
+
+    /// Check the right of the requestor before the request is dispatched, the thing id is given if the request is on the thing.
+    /// The message type is the one which the topic is registered with, the topics of the other services
+    /// are checked by the core with their subscriptions when they're dispatched.
+    pub(crate) fn check_right(&self, header_meta: &HeaderMeta, thing_id: Option<&str>) -> NearResult<()> {
+        let mt =
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .message_type(&header_meta.topic)
+                .unwrap_or(MessageType::Public);
+
+        self.right_stack()
+            .check(&header_meta.requestor, &header_meta.topic, mt, thing_id)
+            .map_err(| e | {
+                error!("{e}, sequence: {}", header_meta.sequence());
+                e
+            })
+    }
+
+    fn open_right(&self) -> NearResult<()> {
+        let topic_privileges =
+            [
+                (NEAR_THING_GATEWAY_SEARCH_RESULT_PUB.topic(), Privilege::Read),
+                (NEAR_THING_GATEWAY_SEARCH_PUB.topic(), Privilege::Manage),
+                (NEAR_THING_GATEWAY_ADD_THING_PUB.topic(), Privilege::Manage),
+                (NEAR_THING_GATEWAY_CRUD_THING_PUB.topic(), Privilege::Manage),
+                (NEAR_THING_GATEWAY_SCHEDULE_ADD_PUB.topic(), Privilege::Manage),
+                (NEAR_THING_GATEWAY_SCHEDULE_UPDATE_PUB.topic(), Privilege::Manage),
+                (NEAR_THING_GATEWAY_SCHEDULE_REMOVE_PUB.topic(), Privilege::Manage),
+                (NEAR_THING_GATEWAY_SCHEDULE_UPDATE_RELATIONS_PUB.topic(), Privilege::Manage),
+                // the queries of the storage which are called by the http api.
+                (NEAR_THING_STORAGE_BRAND_QUERY_ALL_PUB.topic(), Privilege::Read),
+                (NEAR_THING_STORAGE_BRAND_QUERY_PUB.topic(), Privilege::Read),
+                (NEAR_THING_STORAGE_PRODUCT_QUERY_ALL_PUB.topic(), Privilege::Read),
+                (NEAR_THING_STORAGE_PRODUCT_QUERY_PUB.topic(), Privilege::Read),
+                (NEAR_THING_STORAGE_THING_QUERY_ALL_PUB.topic(), Privilege::Read),
+                (NEAR_THING_STORAGE_SCHEDULE_QUERYALL_PUB.topic(), Privilege::Read),
+                (NEAR_THING_STORAGE_SCHEDULE_QUERY_PUB.topic(), Privilege::Read),
+            ]
+            .into_iter()
+            .map(| (topic, privilege) | (topic.topic().clone(), privilege))
+            .collect();
+
+        let right_stack = 
+            RightStack::open(
+                &self.0.service_name, 
+                RuntimeStack::get_instance().stack(),
+                RightStackConfig {
+                    topic_privileges,
+                    ..Default::default()
+                }
+            );
+
+        self.0.components.as_ref().unwrap()
+            .right_stack
+            .set(right_stack)
+            .map_err(| _ | NearError::new(ErrorCode::NEAR_ERROR_ACTIVED, "right stack has been opened."))
+    }
 }
 
 impl Process {
@@ -179,6 +250,21 @@ impl Process {
         Ok(())
     }
 
+    pub async fn subscribe_location_topic(&self) -> NearResult<()> {
+        {
+            let arc_self = self.clone();
+            // ctrl all of things in the location
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    NEAR_THING_GATEWAY_LOCATION_CTRL_PUB.topic(),
+                    move || Ok(CtrlLocationRoutine::new(arc_self.clone()))
+                )?;
+        }
+
+        Ok(())
+    }
+
 }
 
 #[async_trait::async_trait]
@@ -187,8 +273,13 @@ impl RuntimeProcessTrait for Process {
     async fn run(&self) -> NearResult<()> {
         trace!("run enter");
 
+        self.open_right()?;
+
         self.subscribe_things_topic().await?;
         self.subscribe_schedule_topic().await?;
+        self.subscribe_location_topic().await?;
+
+        HttpServer::start(self.clone(), self.0.config.http_config.clone(), self.0.config.work_path.as_path())?;
 
         Ok(())
     }
@@ -0,0 +1,106 @@
+
+use std::collections::HashMap;
+
+use common::RoutineTemplate;
+use log::{trace, error, info};
+
+use near_base::{NearResult, NearError, ErrorCode};
+use near_transport::{RoutineEventTrait, RoutineWrap, Routine, HeaderMeta, EventResult};
+
+use base::raw_object::RawObjectGuard;
+use protos::{try_decode_raw_object, hci::location::Location_ctrl, DataContent, try_encode_raw_object};
+use topic_util::types::hci_types::HciTaskId;
+use topic_util::topics::hci_service::NEAR_THING_SERVICE_CONTROL_THING_PUB;
+use topic_util::topics::hci_storage::NEAR_THING_STORAGE_LOCATION_THINGS_QUERY_PUB;
+
+use crate::{audit::Audit, process::Process};
+
+pub struct CtrlLocationRoutine {
+    process: Process,
+}
+
+impl CtrlLocationRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(CtrlLocationRoutine{
+            process
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for CtrlLocationRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("CtrlLocationRoutine::on_routine header_meta={header_meta}");
+
+        let mut audit = Audit::new(header_meta, &req);
+
+        let r = try_decode_raw_object!(Location_ctrl, req, o, { (o.take_location_id(), o.take_data()) }, { header_meta.sequence() });
+
+        let r: DataContent<HciTaskId> = match r {
+            DataContent::Content((location_id, thing_data)) => {
+                audit.set_thing_ids([location_id.clone()]);
+                self.on_routine(header_meta, &mut audit, location_id, thing_data).await.into()
+            }
+            DataContent::Error(e) => DataContent::Error(e)
+        };
+
+        audit.commit(header_meta, &r).await;
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl CtrlLocationRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, audit: &mut Audit, location_id: String, thing_data: HashMap<String, String>) -> NearResult<HciTaskId> {
+        self.process.check_right(header_meta, None)?;
+
+        let thing_ids = 
+            RoutineTemplate::<Vec<String>>::call_with_headermeta(
+                header_meta, 
+                NEAR_THING_STORAGE_LOCATION_THINGS_QUERY_PUB.topic().clone(),
+                location_id.clone(),
+            )
+            .await
+            .map_err(| e | {
+                error!("{e}, sequence: {}", header_meta.sequence());
+                e
+            })?
+            .await
+            .map_err(| e | {
+                error!("failed query [{location_id}] things with err: {e}, sequence: {}", header_meta.sequence());
+                e
+            })?;
+
+        // only the things which support the data are controlled.
+        let thing_ids = crate::capability::filter_things(header_meta, thing_ids, &thing_data).await?;
+
+        if thing_ids.is_empty() {
+            let error_string = format!("[{location_id}] hasn't any thing supports the data.");
+            error!("{error_string}, sequence: {}", header_meta.sequence());
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_NOTFOUND, error_string));
+        }
+
+        // the things are recorded as they are resolved now, so the record is found by each of them.
+        audit.set_thing_ids(std::iter::once(location_id.clone()).chain(thing_ids.iter().cloned()));
+
+        RoutineTemplate::<HciTaskId>::call_with_headermeta(
+            header_meta, 
+            NEAR_THING_SERVICE_CONTROL_THING_PUB.topic().clone(),
+            thing_ids.into_iter().map(| thing_id | (thing_id, thing_data.clone())).collect::<Vec<(String, HashMap<String, String>)>>(),
+        )
+        .await
+        .map_err(| e | {
+            error!("{e}, sequence: {}", header_meta.sequence());
+            e
+        })?
+        .await
+        .map(| task_id | {
+            info!("Successfully ctrl [{location_id}] location");
+            task_id
+        })
+        .map_err(| e | {
+            error!("failed ctrl [{location_id}] location with err: {e}, sequence: {}", header_meta.sequence());
+            e
+        })
+    }
+}
@@ -1,5 +1,6 @@
 
 pub mod schedule;
+pub mod location;
 pub mod things;
 
 use bytes::*;
@@ -9,16 +9,16 @@ use base::raw_object::RawObjectGuard;
 use protos::{DataContent, try_decode_raw_object, try_encode_raw_object, hci::schedule::{Schedule_add, Schedule_info, Schedule_relation_list, Schedule_mode}};
 use topic_util::topics::{hci_storage::NEAR_THING_STORAGE_SCHEDULE_ADD_PUB, hci_schedule::NEAR_THING_SCHEDULE_ADD_PUB, hci_service::NEAR_THING_SERVICE_SCHEDULE_ADD_PUB};
 
-use crate::process::Process;
+use crate::{audit::Audit, process::Process};
 
 pub struct AddScheduleRoutine {
-    _process: Process,
+    process: Process,
 }
 
 impl AddScheduleRoutine {
     pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
         RoutineWrap::new(Box::new(AddScheduleRoutine{
-            _process: process
+            process
         }))
     }
 }
@@ -28,15 +28,21 @@ impl Routine<RawObjectGuard, RawObjectGuard> for AddScheduleRoutine {
     async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
         trace!("AddScheduleRoutine: header_meta={header_meta}");
 
+        let mut audit = Audit::new(header_meta, &req);
+
         let r = 
             try_decode_raw_object!(Schedule_add, req, o, o, { header_meta.sequence() });
 
         let r: DataContent<Schedule_info> = match r {
-            DataContent::Content(schedule_data) => 
-                self.on_routine(header_meta, schedule_data).await.into(),
+            DataContent::Content(schedule_data) => {
+                audit.set_thing_ids(schedule_data.thing_relation.iter().map(| relation | relation.thing_id().to_owned()));
+                self.on_routine(header_meta, schedule_data).await.into()
+            }
             DataContent::Error(e) => DataContent::Error(e)
         };
 
+        audit.commit(header_meta, &r).await;
+
         try_encode_raw_object!(r, { header_meta.sequence() })
     }
 }
@@ -44,8 +50,15 @@ impl Routine<RawObjectGuard, RawObjectGuard> for AddScheduleRoutine {
 impl AddScheduleRoutine {
     async fn on_routine(&self, header_meta: &HeaderMeta, schedule_data: Schedule_add) -> NearResult<Schedule_info> {
 
-        // check right
-        // todo
+        self.process.check_right(header_meta, None)?;
+
+        crate::capability::check_things(
+            header_meta, 
+            schedule_data.thing_relation
+                .iter()
+                .map(| relation | (relation.thing_id(), &relation.thing_data_property))
+        )
+        .await?;
 
         let schedule_info = 
             RoutineTemplate::<Schedule_info>::call_with_headermeta(
@@ -9,16 +9,16 @@ use base::raw_object::RawObjectGuard;
 use protos::{DataContent, try_decode_raw_object, try_encode_raw_object, hci::schedule::{Schedule_info, Schedule_mode}};
 use topic_util::topics::{hci_storage::NEAR_THING_STORAGE_SCHEDULE_REMOVE_PUB, hci_schedule::NEAR_THING_SCHEDULE_REMOVE_PUB, hci_service::NEAR_THING_SERVICE_SCHEDULE_REMOVE_PUB};
 
-use crate::process::Process;
+use crate::{audit::Audit, process::Process};
 
 pub struct RemoveScheduleRoutine {
-    _process: Process,
+    process: Process,
 }
 
 impl RemoveScheduleRoutine {
     pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
         RoutineWrap::new(Box::new(RemoveScheduleRoutine{
-            _process: process
+            process
         }))
     }
 }
@@ -28,6 +28,8 @@ impl Routine<RawObjectGuard, RawObjectGuard> for RemoveScheduleRoutine {
     async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
         trace!("RemoveScheduleRoutine: header_meta={header_meta}");
 
+        let audit = Audit::new(header_meta, &req);
+
         let r = 
             try_decode_raw_object!(String, req, o, o, { header_meta.sequence() });
 
@@ -37,12 +39,15 @@ impl Routine<RawObjectGuard, RawObjectGuard> for RemoveScheduleRoutine {
             DataContent::Error(e) => DataContent::Error(e)
         };
 
+        audit.commit(header_meta, &r).await;
+
         try_encode_raw_object!(r, { header_meta.sequence() })
     }
 }
 
 impl RemoveScheduleRoutine {
     async fn on_routine(&self, header_meta: &HeaderMeta, schedule_id: String) -> NearResult<Empty> {
+        self.process.check_right(header_meta, None)?;
 
         let mut schedule_info = 
             RoutineTemplate::<Schedule_info>::call_with_headermeta(
@@ -13,16 +13,16 @@ use topic_util::topics::hci_schedule::{NEAR_THING_SCHEDULE_ADD_PUB, NEAR_THING_S
 use topic_util::topics::hci_storage::NEAR_THING_STORAGE_SCHEDULE_UPDATE_PUB;
 use topic_util::types::Status;
 
-use crate::process::Process;
+use crate::{audit::Audit, process::Process};
 
 pub struct UpdateScheduleRoutine {
-    _process: Process,
+    process: Process,
 }
 
 impl UpdateScheduleRoutine {
     pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
         RoutineWrap::new(Box::new(UpdateScheduleRoutine{
-            _process: process
+            process
         }))
     }
 
@@ -33,19 +33,35 @@ impl Routine<RawObjectGuard, RawObjectGuard> for UpdateScheduleRoutine {
     async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
         trace!("UpdateScheduleRoutine: header_meta={header_meta}");
 
+        let mut audit = Audit::new(header_meta, &req);
+
         let r = try_decode_raw_object!(Schedule_info, req, o, o, { header_meta.sequence() });
 
         let r: DataContent<Schedule_info> = match r {
-            DataContent::Content(schedule) => self.on_routine(header_meta, schedule).await.into(),
+            DataContent::Content(schedule) => {
+                audit.set_thing_ids(schedule.thing_relation.iter().map(| relation | relation.thing_id().to_owned()));
+                self.on_routine(header_meta, schedule).await.into()
+            }
             DataContent::Error(e) => DataContent::Error(e),
         };
 
+        audit.commit(header_meta, &r).await;
+
         try_encode_raw_object!(r, { header_meta.sequence() })
     }
 }
 
 impl UpdateScheduleRoutine {
     async fn on_routine(&self, header_meta: &HeaderMeta, new_schedule: Schedule_info) -> NearResult<Schedule_info> {
+        self.process.check_right(header_meta, None)?;
+
+        crate::capability::check_things(
+            header_meta, 
+            new_schedule.thing_relation
+                .iter()
+                .map(| relation | (relation.thing_id(), &relation.thing_data_property))
+        )
+        .await?;
 
         let schedule_info = 
             RoutineTemplate::<Schedule_info>::call_with_headermeta(
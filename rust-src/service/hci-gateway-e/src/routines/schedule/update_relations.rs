@@ -14,16 +14,16 @@ use topic_util::topics::hci_schedule::NEAR_THING_SCHEDULE_ADD_PUB;
 use topic_util::topics::hci_service::{NEAR_THING_SERVICE_SCHEDULE_ADD_PUB, NEAR_THING_SERVICE_SCHEDULE_REMOVE_PUB};
 use topic_util::topics::hci_storage::NEAR_THING_STORAGE_SCHEDULE_RELATIONS_UPDATE_PUB;
 
-use crate::process::Process;
+use crate::{audit::Audit, process::Process};
 
 pub struct UpdateScheduleRelationsRoutine {
-    _process: Process,
+    process: Process,
 }
 
 impl UpdateScheduleRelationsRoutine {
     pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
         RoutineWrap::new(Box::new(UpdateScheduleRelationsRoutine{
-            _process: process
+            process
         }))
     }
 
@@ -34,19 +34,27 @@ impl Routine<RawObjectGuard, RawObjectGuard> for UpdateScheduleRelationsRoutine
     async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
         trace!("UpdateScheduleRelationsRoutine: header_meta={header_meta}");
 
+        let mut audit = Audit::new(header_meta, &req);
+
         let r = try_decode_raw_object!(Schedule_relation_list_update, req, o, o, { header_meta.sequence() });
 
         let r: DataContent<Schedule_info> = match r {
-            DataContent::Content(schedule_relations) => self.on_routine(header_meta, schedule_relations).await.into(),
+            DataContent::Content(schedule_relations) => {
+                audit.set_thing_ids(schedule_relations.relations.thing_relation.iter().map(| relation | relation.thing_id().to_owned()));
+                self.on_routine(header_meta, schedule_relations).await.into()
+            }
             DataContent::Error(e) => DataContent::Error(e),
         };
 
+        audit.commit(header_meta, &r).await;
+
         try_encode_raw_object!(r, { header_meta.sequence() })
     }
 }
 
 impl UpdateScheduleRelationsRoutine {
     async fn on_routine(&self, header_meta: &HeaderMeta, mut schedule_relations: Schedule_relation_list_update) -> NearResult<Schedule_info> {
+        self.process.check_right(header_meta, None)?;
 
         let op = schedule_relations.op();
         if let Schedule_relation_list_op::none = op {
@@ -55,6 +63,17 @@ impl UpdateScheduleRelationsRoutine {
             Ok(())
         }?;
 
+        if let Schedule_relation_list_op::update = op {
+            crate::capability::check_things(
+                header_meta, 
+                schedule_relations.relations()
+                    .thing_relation
+                    .iter()
+                    .map(| relation | (relation.thing_id(), &relation.thing_data_property))
+            )
+            .await?;
+        }
+
         let schedule = 
             RoutineTemplate::<Schedule_info>::call_with_headermeta(
                 header_meta, 
@@ -2,15 +2,15 @@
 use common::RoutineTemplate;
 use log::{trace, error};
 
-use near_base::{NearResult, thing::ThingObject};
+use near_base::{NearResult, NearError, ErrorCode, thing::ThingObject};
 use near_transport::{Routine, HeaderMeta, RoutineWrap, RoutineEventTrait, EventResult};
 
 use base::raw_object::RawObjectGuard;
-use protos::{DataContent, try_decode_raw_object, hci::hci_thing::*, try_encode_raw_object, hci::thing::{Thing_add, Thing_info}};
-use topic_util::{topics::{hci_service::NEAR_THING_SERVICE_ADD_THING_PUB, hci_storage::NEAR_THING_STORAGE_THING_ADD_PUB}, 
+use protos::{DataContent, try_decode_raw_object, hci::hci_thing::*, try_encode_raw_object, hci::thing::{Thing_add, Thing_info, Thing_pairing, Thing_pairing_proof}};
+use topic_util::{topics::{hci_service::{NEAR_THING_SERVICE_ADD_THING_PUB, NEAR_THING_SERVICE_PAIRING_PUB}, hci_storage::NEAR_THING_STORAGE_THING_ADD_PUB}, 
                  types::hci_types::HciTaskId};
 
-use crate::process::Process;
+use crate::{audit::Audit, process::Process};
 
 pub struct AddThingRoutine {
     #[allow(unused)]
@@ -36,26 +36,29 @@ impl Routine<RawObjectGuard, RawObjectGuard> for AddThingRoutine {
     async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
         trace!("AddThingRoutine::on_routine header_meta={header_meta}");
 
+        let mut audit = Audit::new(header_meta, &req);
+
         let r = try_decode_raw_object!(Hci_add_thing, req, o, o, { header_meta.sequence() });
 
         let r: DataContent<HciTaskId> = match r {
             DataContent::Content(c) => {
-                self.on_routine(header_meta, c)
+                self.on_routine(header_meta, &mut audit, c)
                     .await
                     .into()
             },
             DataContent::Error(e) => DataContent::Error(e),
         };
 
+        audit.commit(header_meta, &r).await;
+
         try_encode_raw_object!(r, { header_meta.sequence() })
     }
 }
 
 impl AddThingRoutine {
-    pub(in self) async fn on_routine(&self, header_meta: &HeaderMeta, mut hci_add_thing: Hci_add_thing) -> NearResult<HciTaskId> {
+    pub(in self) async fn on_routine(&self, header_meta: &HeaderMeta, audit: &mut Audit, mut hci_add_thing: Hci_add_thing) -> NearResult<HciTaskId> {
 
-        // todo!
-        // check creator right
+        self.process.check_right(header_meta, None)?;
 
         let thing = {
             let brand_id = hci_add_thing.take_brand_id();
@@ -63,6 +66,38 @@ impl AddThingRoutine {
             let minor_product_id = hci_add_thing.take_minor_product_id();
             let thing_name = hci_add_thing.take_thing_name();
             let mut thing = hci_add_thing.take_thing();
+            let mac_address = thing.take_mac_address();
+            let thing_data = thing.take_data();
+            let owner_override = hci_add_thing.owner_override;
+
+            // the thing signs the challenge if its brand is configured, hci-storage refuses it without the proof.
+            // only the owner adds the thing without the proof, and it's recorded by hci-storage.
+            let pairing_proof = 
+                if owner_override {
+                    self.check_owner(header_meta)?;
+                    Thing_pairing_proof::default()
+                } else {
+                    RoutineTemplate::<Thing_pairing_proof>::call_with_headermeta(
+                        header_meta,
+                        NEAR_THING_SERVICE_PAIRING_PUB.topic().clone(),
+                        Thing_pairing {
+                            brand_id: brand_id.clone(),
+                            mac_address: mac_address.clone(),
+                            thing_data: thing_data.clone(),
+                            ..Default::default()
+                        }
+                    )
+                    .await
+                    .map_err(| e | {
+                        error!("{e}, sequence: {}", header_meta.sequence());
+                        e
+                    })?
+                    .await
+                    .map_err(| e | {
+                        error!("{e}, sequence: {}", header_meta.sequence());
+                        e
+                    })?
+                };
 
             RoutineTemplate::<ThingObject>::call_with_headermeta(
                 header_meta,
@@ -73,10 +108,12 @@ impl AddThingRoutine {
                         major_product_id,
                         minor_product_id,
                         thing_name,
-                        mac_address: thing.take_mac_address(),
+                        mac_address,
                         ..Default::default()
                     }).into(),
-                    thing_data: thing.take_data(),
+                    thing_data,
+                    pairing_proof: pairing_proof.proof,
+                    owner_override,
                     ..Default::default()
                 }
             )
@@ -92,6 +129,9 @@ impl AddThingRoutine {
             })?
         };
 
+        // the thing id is created by the storage.
+        audit.set_thing_ids([thing.object_id().to_string()]);
+
         RoutineTemplate::<HciTaskId>::call_with_headermeta(
             header_meta, 
             NEAR_THING_SERVICE_ADD_THING_PUB.topic().clone(),
@@ -110,4 +150,14 @@ impl AddThingRoutine {
 
     }
 
+    fn check_owner(&self, header_meta: &HeaderMeta) -> NearResult<()> {
+        if self.process.right_stack().owner() == Some(&header_meta.requestor) {
+            Ok(())
+        } else {
+            let error_string = format!("{} isn't the owner, it can't add the thing without the pairing proof", header_meta.requestor);
+            error!("{error_string}, sequence: {}", header_meta.sequence());
+            Err(NearError::new(ErrorCode::NEAR_ERROR_FORBIDDEN, error_string))
+        }
+    }
+
 }
\ No newline at end of file
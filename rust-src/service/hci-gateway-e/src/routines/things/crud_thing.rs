@@ -14,7 +14,7 @@ use topic_util::{topics::{hci_service::{NEAR_THING_SERVICE_REMOVE_THING_PUB,
                                           hci_storage::NEAR_THING_STORAGE_THING_REMOVE_PUB}, 
                                           types::hci_types::HciTaskId};
 
-use crate::process::Process;
+use crate::{audit::Audit, process::Process};
 
 pub struct CrudThingRoutine {
     #[allow(unused)]
@@ -40,10 +40,19 @@ impl Routine<RawObjectGuard, RawObjectGuard> for CrudThingRoutine {
     async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
         trace!("CrudThingRoutine::on_routine header_meta={header_meta}");
 
+        let mut audit = Audit::new(header_meta, &req);
+
         let r = try_decode_raw_object!(Hci_crud_thing, req, o, o, { header_meta.sequence() });
 
         let r: DataContent<HciTaskId> = match r {
             DataContent::Content(c) => {
+                // the query isn't a control action.
+                if c.method.enum_value() == Ok(Hci_crud_m::query) {
+                    audit.discard();
+                } else {
+                    audit.set_action(format!("{}/{:?}", header_meta.topic, c.method.enum_value_or_default()));
+                    audit.set_thing_ids([c.thing_id().to_owned()]);
+                }
                 self.on_routine(header_meta, c)
                     .await
                     .into()
@@ -51,6 +60,8 @@ impl Routine<RawObjectGuard, RawObjectGuard> for CrudThingRoutine {
             DataContent::Error(e) => DataContent::Error(e),
         };
 
+        audit.commit(header_meta, &r).await;
+
         try_encode_raw_object!(r, { header_meta.sequence() })
     }
 }
@@ -67,6 +78,8 @@ impl CrudThingRoutine {
         let thing_data = hci_crud_thing.take_data();
         let thing_id = hci_crud_thing.thing_id();
 
+        self.process.check_right(header_meta, Some(thing_id))?;
+
         let r = 
             RoutineTemplate::<HciTaskId>::call_with_headermeta(
                 header_meta, 
@@ -12,10 +12,9 @@ use protos::{try_decode_raw_object, hci::hci_thing::*, DataContent, try_encode_r
 use topic_util::types::hci_types::HciTaskId;
 use topic_util::topics::hci_service::NEAR_THING_SERVICE_CONTROL_THING_PUB;
 
-use crate::process::Process;
+use crate::{audit::Audit, process::Process};
 
 pub struct CtrlThingRoutine {
-    #[allow(unused)]
     process: Process,
 }
 
@@ -32,19 +31,30 @@ impl Routine<RawObjectGuard, RawObjectGuard> for CtrlThingRoutine {
     async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
         trace!("CtrlThingRoutine::on_routine header_meta={header_meta}");
 
+        let mut audit = Audit::new(header_meta, &req);
+
         let r = try_decode_raw_object!(Hci_ctrl_thing, req, o, { (o.take_thing_id(), o.take_data()) }, { header_meta.sequence() });
 
         let r: DataContent<HciTaskId> = match r {
-            DataContent::Content((thing_id, thing_data)) => self.on_routine(header_meta, thing_id, thing_data).await.into(),
+            DataContent::Content((thing_id, thing_data)) => {
+                audit.set_thing_ids([thing_id.clone()]);
+                self.on_routine(header_meta, thing_id, thing_data).await.into()
+            }
             DataContent::Error(e) => DataContent::Error(e)
         };
 
+        audit.commit(header_meta, &r).await;
+
         try_encode_raw_object!(r, { header_meta.sequence() })
     }
 }
 
 impl CtrlThingRoutine {
     async fn on_routine(&self, header_meta: &HeaderMeta, thing_id: String, thing_data: HashMap<String, String>) -> NearResult<HciTaskId> {
+        self.process.check_right(header_meta, Some(&thing_id))?;
+
+        crate::capability::check_things(header_meta, std::iter::once((thing_id.as_str(), &thing_data))).await?;
+
         let thing_id_clone = thing_id.clone();
         RoutineTemplate::<HciTaskId>::call_with_headermeta(
             header_meta, 
@@ -53,6 +53,8 @@ impl Routine<RawObjectGuard, RawObjectGuard> for SearchRoutine {
 impl SearchRoutine {
 
     pub(in self) async fn on_routine(&self, header_meta: &HeaderMeta, brand_id: String) -> NearResult<HciTaskId> {
+        self.process.check_right(header_meta, None)?;
+
         // query brand
         let brand = 
             RoutineTemplate::<Brand_info>::call_with_headermeta(
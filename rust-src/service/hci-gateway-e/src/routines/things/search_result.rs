@@ -46,6 +46,7 @@ impl Routine<RawObjectGuard, RawObjectGuard> for SearchResultRoutine {
 
 impl SearchResultRoutine {
     async fn get_search_result(&self, header_meta: &HeaderMeta, task_id: HciTaskId, thing_ids: Vec<String>) -> NearResult<Hci_thing_list> {
+        self.process.check_right(header_meta, None)?;
 
         RoutineTemplate::<Hci_thing_list>
             ::call_with_headermeta(header_meta, 
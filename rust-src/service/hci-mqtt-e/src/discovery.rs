@@ -0,0 +1,289 @@
+
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use protos::hci::{thing::Thing_info, schedule::Schedule_info, product::{Product_capability, Product_property, Product_property_type}};
+
+pub const ONLINE: &'static str  = "online";
+pub const OFFLINE: &'static str = "offline";
+
+const SCENE_PAYLOAD: &'static str = "ON";
+
+pub enum Command {
+    CtrlThing { thing_id: String, data: HashMap<String, String> },
+    ExecuteScene { schedule_id: String },
+}
+
+pub struct ThingDevice<'a> {
+    pub thing: &'a Thing_info,
+    pub brand_name: &'a str,
+    pub product_name: &'a str,
+}
+
+/// Build the topics and the Home Assistant MQTT-discovery payloads.
+pub struct Discovery<'a> {
+    pub base_topic: &'a str,
+    pub discovery_prefix: &'a str,
+}
+
+fn object_id(v: &str) -> String {
+    v.chars()
+        .map(| c | if c.is_ascii_alphanumeric() || c == '_' || c == '-' { c } else { '_' })
+        .collect()
+}
+
+impl Discovery<'_> {
+    pub fn status_topic(&self) -> String {
+        format!("{}/status", self.base_topic)
+    }
+
+    pub fn thing_state_topic(&self, thing_id: &str) -> String {
+        format!("{}/thing/{thing_id}/state", self.base_topic)
+    }
+
+    pub fn thing_availability_topic(&self, thing_id: &str) -> String {
+        format!("{}/thing/{thing_id}/availability", self.base_topic)
+    }
+
+    pub fn thing_command_topic(&self, thing_id: &str, property: &str) -> String {
+        format!("{}/thing/{thing_id}/set/{property}", self.base_topic)
+    }
+
+    pub fn scene_command_topic(&self, schedule_id: &str) -> String {
+        format!("{}/scene/{schedule_id}/set", self.base_topic)
+    }
+
+    pub fn command_filters(&self) -> Vec<String> {
+        vec![
+            format!("{}/thing/+/set", self.base_topic),
+            format!("{}/thing/+/set/+", self.base_topic),
+            format!("{}/scene/+/set", self.base_topic),
+        ]
+    }
+
+    fn config_topic(&self, component: &str, node_id: &str, object: &str) -> String {
+        format!("{}/{component}/{}/{}/config", self.discovery_prefix, object_id(node_id), object_id(object))
+    }
+
+    /// One entity per property of the capability, return (config topic, payload).
+    pub fn thing_configs(&self, device: &ThingDevice<'_>, capability: &Product_capability) -> Vec<(String, String)> {
+        let thing_id = device.thing.thing_id();
+
+        let device_info = json!({
+            "identifiers": [thing_id],
+            "name": device.thing.thing_name(),
+            "manufacturer": device.brand_name,
+            "model": device.product_name,
+            "connections": [["mac", device.thing.mac_address()]],
+        });
+
+        capability.properties
+            .iter()
+            .filter(| property | property.readable() || property.writable())
+            .map(| property | {
+                let (component, mut payload) = self.property_config(thing_id, property);
+
+                payload["name"] = json!(property.name());
+                payload["unique_id"] = json!(format!("{}_{}", thing_id, object_id(property.name())));
+                payload["device"] = device_info.clone();
+                payload["availability_mode"] = json!("all");
+                payload["availability"] = json!([
+                    { "topic": self.status_topic() },
+                    { "topic": self.thing_availability_topic(thing_id) },
+                ]);
+
+                (self.config_topic(component, thing_id, property.name()), payload.to_string())
+            })
+            .collect()
+    }
+
+    fn property_config(&self, thing_id: &str, property: &Product_property) -> (&'static str, Value) {
+        let name = property.name();
+        let key = serde_json::to_string(name).unwrap_or_default();
+        let value_template = format!("{{{{ value_json[{key}] }}}}");
+        let command_topic = self.thing_command_topic(thing_id, name);
+        let state_topic = self.thing_state_topic(thing_id);
+
+        let with_unit = | mut v: Value | {
+            if !property.unit().is_empty() {
+                v["unit_of_measurement"] = json!(property.unit());
+            }
+            v
+        };
+
+        match (property.type_(), property.writable()) {
+            (Product_property_type::Bool, writable) => {
+                let value_template = format!("{{{{ 'true' if value_json[{key}] in ['true', '1'] else 'false' }}}}");
+                let mut v = json!({
+                    "state_topic": state_topic,
+                    "value_template": value_template,
+                    "payload_on": "true",
+                    "payload_off": "false",
+                });
+
+                if writable {
+                    v["command_topic"] = json!(command_topic);
+                    v["state_on"] = json!("true");
+                    v["state_off"] = json!("false");
+                    ("switch", v)
+                } else {
+                    ("binary_sensor", v)
+                }
+            }
+            (Product_property_type::Int, true) |
+            (Product_property_type::Float, true) => {
+                let mut v = with_unit(json!({
+                    "state_topic": state_topic,
+                    "value_template": value_template,
+                    "command_topic": command_topic,
+                }));
+                if property.has_min() { v["min"] = json!(property.min()); }
+                if property.has_max() { v["max"] = json!(property.max()); }
+                if property.step() > 0f64 {
+                    v["step"] = json!(property.step());
+                } else if property.type_() == Product_property_type::Int {
+                    v["step"] = json!(1);
+                }
+                ("number", v)
+            }
+            (Product_property_type::Enum, true) => {
+                ("select", json!({
+                    "state_topic": state_topic,
+                    "value_template": value_template,
+                    "command_topic": command_topic,
+                    "options": property.enums,
+                }))
+            }
+            (Product_property_type::String, true) => {
+                ("text", json!({
+                    "state_topic": state_topic,
+                    "value_template": value_template,
+                    "command_topic": command_topic,
+                }))
+            }
+            (_, false) => {
+                ("sensor", with_unit(json!({
+                    "state_topic": state_topic,
+                    "value_template": value_template,
+                })))
+            }
+        }
+    }
+
+    /// The schedule is exposed as a scene.
+    pub fn scene_config(&self, schedule: &Schedule_info) -> (String, String) {
+        let payload = json!({
+            "name": schedule.schedule_name(),
+            "unique_id": format!("scene_{}", schedule.schedule_id()),
+            "command_topic": self.scene_command_topic(schedule.schedule_id()),
+            "payload_on": SCENE_PAYLOAD,
+            "availability_topic": self.status_topic(),
+        });
+
+        (self.config_topic("scene", "scene", schedule.schedule_id()), payload.to_string())
+    }
+
+    /// The topic is {base}/thing/{thing_id}/set with a json object or {base}/thing/{thing_id}/set/{property} with the value,
+    /// or {base}/scene/{schedule_id}/set.
+    pub fn parse_command(&self, topic: &str, payload: &[u8]) -> Option<Command> {
+        let topic = topic.strip_prefix(self.base_topic)?.strip_prefix('/')?;
+        let payload = std::str::from_utf8(payload).ok()?.trim();
+
+        match topic.split('/').collect::<Vec<&str>>().as_slice() {
+            ["thing", thing_id, "set"] => {
+                let data =
+                    match serde_json::from_str::<Value>(payload).ok()? {
+                        Value::Object(object) => {
+                            object.into_iter()
+                                .map(| (k, v) | {
+                                    let v = match v {
+                                        Value::String(v) => v,
+                                        v => v.to_string(),
+                                    };
+                                    (k, v)
+                                })
+                                .collect::<HashMap<String, String>>()
+                        }
+                        _ => return None,
+                    };
+
+                if data.is_empty() {
+                    None
+                } else {
+                    Some(Command::CtrlThing { thing_id: thing_id.to_string(), data })
+                }
+            }
+            ["thing", thing_id, "set", property] => {
+                Some(Command::CtrlThing {
+                    thing_id: thing_id.to_string(),
+                    data: HashMap::from([(property.to_string(), payload.to_owned())]),
+                })
+            }
+            ["scene", schedule_id, "set"] => {
+                Some(Command::ExecuteScene { schedule_id: schedule_id.to_string() })
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use protos::hci::{thing::Thing_info, product::{Product_capability, Product_property, Product_property_type}};
+
+    use super::{Discovery, ThingDevice, Command};
+
+    #[test]
+    fn test_discovery() {
+        let discovery = Discovery { base_topic: "near", discovery_prefix: "homeassistant" };
+
+        let thing = Thing_info {
+            thing_id: "T1".to_owned(),
+            thing_name: "lamp".to_owned(),
+            ..Default::default()
+        };
+        let capability = Product_capability {
+            properties: vec![
+                Product_property {
+                    name: "on".to_owned(),
+                    type_: Product_property_type::Bool.into(),
+                    readable: true,
+                    writable: true,
+                    ..Default::default()
+                },
+                Product_property {
+                    name: "temperature".to_owned(),
+                    type_: Product_property_type::Float.into(),
+                    unit: "°C".to_owned(),
+                    readable: true,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        let configs = discovery.thing_configs(&ThingDevice { thing: &thing, brand_name: "", product_name: "" }, &capability);
+        assert_eq!(configs.len(), 2);
+        assert_eq!(configs[0].0, "homeassistant/switch/T1/on/config");
+        assert!(configs[0].1.contains("near/thing/T1/set/on"));
+        assert_eq!(configs[1].0, "homeassistant/sensor/T1/temperature/config");
+
+        match discovery.parse_command("near/thing/T1/set", br#"{"on": true, "level": 5}"#) {
+            Some(Command::CtrlThing { thing_id, data }) => {
+                assert_eq!(thing_id, "T1");
+                assert_eq!(data.get("on").unwrap(), "true");
+                assert_eq!(data.get("level").unwrap(), "5");
+            }
+            _ => panic!("expect ctrl thing"),
+        }
+
+        match discovery.parse_command("near/thing/T1/set/on", b"false") {
+            Some(Command::CtrlThing { data, .. }) => assert_eq!(data.get("on").unwrap(), "false"),
+            _ => panic!("expect ctrl thing"),
+        }
+
+        assert!(matches!(discovery.parse_command("near/scene/S1/set", b"ON"), Some(Command::ExecuteScene { .. })));
+        assert!(discovery.parse_command("other/thing/T1/set/on", b"true").is_none());
+    }
+}
@@ -0,0 +1,104 @@
+
+mod p;
+mod mqtt;
+mod discovery;
+mod process;
+
+use std::{path::PathBuf, time::Duration, str::FromStr};
+
+use log::info;
+
+use near_base::{NearResult, NearError, ErrorCode, ObjectId};
+use near_core::get_data_path;
+
+use common::{ProcessCommandBuild, ProcessAction, };
+use process::{Process, Config};
+
+use crate::p::SERVICE_NAME;
+
+pub async fn load_from_config(service_name: &str) -> NearResult<Config> {
+    let toml_file = PathBuf::new().with_file_name(service_name).with_extension("toml");
+    let content =
+        async_std::fs::read_to_string(get_data_path().join(toml_file.as_path()))
+            .await
+            .map_err(| _ | {
+                let error_string = format!("Missing [{}] file, the broker credentials and the people are required", toml_file.display());
+                println!("{error_string}");
+                NearError::new(ErrorCode::NEAR_ERROR_NOTFOUND, error_string)
+            })?;
+
+    let val: toml::Value =
+        toml::from_str(&content).map_err(| e | {
+            let error_string = format!("parse [{}] with err: {e}", toml_file.display());
+            println!("{error_string}");
+            NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, error_string)
+        })?;
+
+    let mqtt = val.get("mqtt").ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_NOTFOUND, "Not found [mqtt]."))?;
+
+    let get_str = | name: &str | mqtt.get(name).and_then(| v | v.as_str()).map(| v | v.to_owned());
+    let get_secs = | name: &str | mqtt.get(name).and_then(| v | v.as_integer()).map(| v | Duration::from_secs(v as u64));
+
+    let get_required = | name: &str | {
+        get_str(name).ok_or_else(|| {
+            let error_string = format!("Not found [mqtt.{name}], it's required.");
+            println!("{error_string}");
+            NearError::new(ErrorCode::NEAR_ERROR_NOTFOUND, error_string)
+        })
+    };
+
+    let people = get_required("people")?;
+    let people =
+        ObjectId::from_str(&people)
+            .map_err(| e | {
+                let error_string = format!("invalid [mqtt.people] {people} with err: {e}");
+                println!("{error_string}");
+                NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, error_string)
+            })?;
+
+    let default = Config::default();
+
+    Ok(Config {
+        host: get_str("host").unwrap_or(default.host),
+        port: mqtt.get("port").and_then(| v | v.as_integer()).map(| v | v as u16).unwrap_or(default.port),
+        client_id: get_str("client_id").unwrap_or(default.client_id),
+        username: get_required("username")?,
+        password: get_required("password")?,
+        people,
+        keep_alive: get_secs("keep_alive").unwrap_or(default.keep_alive),
+        reconnect_interval: get_secs("reconnect_interval").unwrap_or(default.reconnect_interval),
+        base_topic: get_str("base_topic").unwrap_or(default.base_topic),
+        discovery_prefix: get_str("discovery_prefix").unwrap_or(default.discovery_prefix),
+        discovery_interval: get_secs("discovery_interval").unwrap_or(default.discovery_interval),
+        state_interval: get_secs("state_interval").unwrap_or(default.state_interval),
+        availability_timeout: get_secs("availability_timeout").unwrap_or(default.availability_timeout),
+    })
+}
+
+#[async_std::main]
+async fn main() {
+    let config =
+        match load_from_config(SERVICE_NAME).await {
+            Ok(config) => config,
+            Err(err) => { panic!("{err}") }
+        };
+
+    let process =
+        match ProcessCommandBuild::with_runtime()
+                    .name(SERVICE_NAME)
+                    .launch(Box::new(Process::new(SERVICE_NAME.to_owned(), config)), None).await {
+        Ok(process) => {
+            if let ProcessAction::Start(process) = process {
+                process
+            } else {
+                info!("exiting...");
+                return;
+            }
+        }
+        Err(err) => { panic!("{err}") }
+    };
+
+    if let Err(err) = process.run().await {
+        panic!("{err}")
+    }
+}
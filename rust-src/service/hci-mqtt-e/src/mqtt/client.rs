@@ -0,0 +1,374 @@
+
+use std::{sync::{Arc, atomic::{AtomicU16, Ordering}}, time::Duration};
+
+use async_std::{net::TcpStream, channel::{Sender, Receiver}, io::{ReadExt, WriteExt}, sync::Mutex};
+use bytes::{Bytes, BytesMut};
+use log::{trace, error, info};
+
+use near_base::{NearResult, NearError, ErrorCode};
+
+use super::packet::{Packet, Connect, LastWill, Publish, QoS};
+
+#[derive(Clone)]
+pub struct MqttOptions {
+    pub host: String,
+    pub port: u16,
+    pub client_id: String,
+    pub keep_alive: Duration,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub last_will: Option<LastWill>,
+}
+
+struct MqttClientImpl {
+    writer: Mutex<TcpStream>,
+    packet_id: AtomicU16,
+}
+
+/// The minimal MQTT 3.1.1 client, publishes with QoS 0 and receives with QoS 1 at most.
+/// The receiver returned by connect is closed when the connection is broken.
+#[derive(Clone)]
+pub struct MqttClient(Arc<MqttClientImpl>);
+
+impl MqttClient {
+    pub async fn connect(options: &MqttOptions) -> NearResult<(Self, Receiver<Publish>)> {
+        let mut stream =
+            async_std::future::timeout(
+                options.keep_alive,
+                TcpStream::connect((options.host.as_str(), options.port))
+            )
+            .await
+            .map_err(| _ | {
+                NearError::new(ErrorCode::NEAR_ERROR_TIMEOUT, format!("connect {}:{} timeout", options.host, options.port))
+            })?
+            .map_err(| e | {
+                NearError::new(ErrorCode::NEAR_ERROR_SYSTERM, format!("failed connect {}:{} with err: {e}", options.host, options.port))
+            })?;
+
+        let mut buf = BytesMut::new();
+        Packet::Connect(Connect {
+            client_id: options.client_id.clone(),
+            keep_alive: options.keep_alive.as_secs() as u16,
+            clean_session: true,
+            username: options.username.clone(),
+            password: options.password.clone(),
+            last_will: options.last_will.clone(),
+        })
+        .encode(&mut buf);
+        write_all(&mut stream, &buf).await?;
+
+        let mut read_buf = BytesMut::new();
+        let packet =
+            async_std::future::timeout(options.keep_alive, read_packet(&mut stream, &mut read_buf))
+                .await
+                .map_err(| _ | NearError::new(ErrorCode::NEAR_ERROR_TIMEOUT, "wait connack timeout"))??;
+
+        match packet {
+            Packet::ConnAck { code: 0, .. } => Ok(()),
+            Packet::ConnAck { code, .. } => Err(NearError::new(ErrorCode::NEAR_ERROR_REFUSE, format!("broker refused with code {code}"))),
+            _ => Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, "expect connack")),
+        }?;
+
+        info!("connected mqtt broker {}:{}", options.host, options.port);
+
+        let client = Self(Arc::new(MqttClientImpl {
+            writer: Mutex::new(stream.clone()),
+            packet_id: AtomicU16::new(1),
+        }));
+
+        let (sender, receiver) = async_std::channel::unbounded();
+
+        client.start_reader(stream, read_buf, options.keep_alive, sender);
+        client.start_ping(options.keep_alive);
+
+        Ok((client, receiver))
+    }
+
+    pub async fn publish(&self, topic: &str, payload: impl Into<Bytes>, retain: bool) -> NearResult<()> {
+        trace!("publish: topic={topic}, retain={retain}");
+
+        self.send(Packet::Publish(Publish {
+            topic: topic.to_owned(),
+            payload: payload.into(),
+            qos: QoS::AtMostOnce,
+            retain,
+            dup: false,
+            packet_id: 0,
+        }))
+        .await
+    }
+
+    pub async fn subscribe(&self, filters: Vec<(String, QoS)>) -> NearResult<()> {
+        trace!("subscribe: filters={:?}", filters);
+
+        self.send(Packet::Subscribe {
+            packet_id: self.next_packet_id(),
+            filters,
+        })
+        .await
+    }
+
+    pub async fn disconnect(&self) {
+        let _ = self.send(Packet::Disconnect).await;
+        let _ = self.0.writer.lock().await.shutdown(std::net::Shutdown::Both);
+    }
+
+    fn next_packet_id(&self) -> u16 {
+        loop {
+            let packet_id = self.0.packet_id.fetch_add(1, Ordering::SeqCst);
+            // zero isn't a valid packet id.
+            if packet_id != 0 {
+                break packet_id;
+            }
+        }
+    }
+
+    async fn send(&self, packet: Packet) -> NearResult<()> {
+        let mut buf = BytesMut::new();
+        packet.encode(&mut buf);
+
+        let writer = &mut *self.0.writer.lock().await;
+        write_all(writer, &buf).await
+    }
+
+    fn start_reader(&self, mut stream: TcpStream, mut buf: BytesMut, keep_alive: Duration, sender: Sender<Publish>) {
+        let arc_self = self.clone();
+
+        async_std::task::spawn(async move {
+            // the broker must answer the ping in time.
+            let wait = keep_alive + keep_alive / 2;
+
+            loop {
+                let packet =
+                    match async_std::future::timeout(wait, read_packet(&mut stream, &mut buf)).await {
+                        Ok(Ok(packet)) => packet,
+                        Ok(Err(e)) => {
+                            error!("failed read mqtt packet with err: {e}");
+                            break;
+                        }
+                        Err(_) => {
+                            error!("mqtt broker hasn't response in {wait:?}");
+                            break;
+                        }
+                    };
+
+                match packet {
+                    Packet::Publish(publish) => {
+                        if publish.qos == QoS::AtLeastOnce {
+                            if let Err(e) = arc_self.send(Packet::PubAck(publish.packet_id)).await {
+                                error!("failed ack [{}] with err: {e}", publish.packet_id);
+                                break;
+                            }
+                        }
+
+                        if sender.send(publish).await.is_err() {
+                            break;
+                        }
+                    }
+                    Packet::SubAck { packet_id, codes } => {
+                        if codes.iter().any(| code | *code == 0x80) {
+                            error!("broker refused subscribe [{packet_id}]");
+                        }
+                    }
+                    Packet::PingResp | Packet::PubAck(_) => {}
+                    packet => {
+                        trace!("ignore packet: {:?}", packet);
+                    }
+                }
+            }
+
+            let _ = stream.shutdown(std::net::Shutdown::Both);
+            sender.close();
+        });
+    }
+
+    fn start_ping(&self, keep_alive: Duration) {
+        let arc_self = self.clone();
+
+        async_std::task::spawn(async move {
+            loop {
+                let _ = async_std::future::timeout(keep_alive / 2, async_std::future::pending::<()>()).await;
+
+                if let Err(e) = arc_self.send(Packet::PingReq).await {
+                    error!("failed ping with err: {e}");
+                    break;
+                }
+            }
+        });
+    }
+}
+
+async fn write_all(stream: &mut TcpStream, buf: &[u8]) -> NearResult<()> {
+    stream.write_all(buf)
+        .await
+        .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_SYSTERM, format!("failed write with err: {e}")))
+}
+
+async fn read_packet(stream: &mut TcpStream, buf: &mut BytesMut) -> NearResult<Packet> {
+    let mut data = [0u8; 4096];
+
+    loop {
+        if let Some(packet) = Packet::decode(buf)? {
+            return Ok(packet);
+        }
+
+        let len =
+            stream.read(&mut data)
+                .await
+                .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_SYSTERM, format!("failed read with err: {e}")))?;
+
+        if len == 0 {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_SYSTERM, "connection closed by broker"));
+        }
+
+        buf.extend_from_slice(&data[..len]);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use async_std::net::{TcpListener, TcpStream};
+    use bytes::{Bytes, BytesMut};
+
+    use near_base::ErrorCode;
+
+    use super::{MqttClient, MqttOptions, read_packet, write_all};
+    use crate::mqtt::packet::{Packet, Publish, QoS};
+
+    fn options(port: u16, username: &str, password: &str) -> MqttOptions {
+        MqttOptions {
+            host: "127.0.0.1".to_owned(),
+            port,
+            client_id: "hci-mqtt-test".to_owned(),
+            keep_alive: Duration::from_secs(30),
+            username: Some(username.to_owned()),
+            password: Some(password.to_owned()),
+            last_will: None,
+        }
+    }
+
+    async fn send(stream: &mut TcpStream, packet: Packet) {
+        let mut buf = BytesMut::new();
+        packet.encode(&mut buf);
+        write_all(stream, &buf).await.unwrap();
+    }
+
+    /// The local broker accepts one client with user/pwd, and sends it a command once it subscribed.
+    /// It returns the packets of the client.
+    async fn broker(listener: TcpListener) -> Vec<Packet> {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut buf = BytesMut::new();
+        let mut packets = vec![];
+
+        while let Ok(packet) = read_packet(&mut stream, &mut buf).await {
+            match &packet {
+                Packet::Connect(connect) => {
+                    let accepted = connect.username.as_deref() == Some("user") && connect.password.as_deref() == Some("pwd");
+                    // 5: not authorized
+                    send(&mut stream, Packet::ConnAck { session_present: false, code: if accepted { 0 } else { 5 } }).await;
+                }
+                Packet::Subscribe { packet_id, filters } => {
+                    send(&mut stream, Packet::SubAck { packet_id: *packet_id, codes: filters.iter().map(| (_, qos) | *qos as u8).collect() }).await;
+                    send(&mut stream, Packet::Publish(Publish {
+                        topic: "near/thing/1/set".to_owned(),
+                        payload: Bytes::from_static(b"{\"on\":true}"),
+                        qos: QoS::AtLeastOnce,
+                        retain: false,
+                        dup: false,
+                        packet_id: 9,
+                    })).await;
+                }
+                _ => {}
+            }
+
+            let disconnect = packet == Packet::Disconnect;
+            packets.push(packet);
+            if disconnect {
+                break;
+            }
+        }
+
+        packets
+    }
+
+    #[test]
+    fn test_client() {
+        async_std::task::block_on(async move {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let port = listener.local_addr().unwrap().port();
+            let broker = async_std::task::spawn(broker(listener));
+
+            let (client, receiver) = MqttClient::connect(&options(port, "user", "pwd")).await.unwrap();
+            client.subscribe(vec![("near/thing/+/set".to_owned(), QoS::AtLeastOnce)]).await.unwrap();
+
+            let command = receiver.recv().await.unwrap();
+            assert_eq!(command.topic, "near/thing/1/set");
+            assert_eq!(command.payload, Bytes::from_static(b"{\"on\":true}"));
+
+            client.publish("near/status", "online", true).await.unwrap();
+            client.disconnect().await;
+
+            let packets = broker.await;
+            assert!(matches!(&packets[0], Packet::Connect(connect) if connect.client_id == "hci-mqtt-test"));
+            assert!(matches!(&packets[1], Packet::Subscribe { filters, .. } if filters[0].0 == "near/thing/+/set"));
+            // the command is acked before it's dispatched.
+            assert_eq!(packets[2], Packet::PubAck(9));
+            assert!(matches!(&packets[3], Packet::Publish(publish) if publish.topic == "near/status" && publish.retain));
+            assert_eq!(packets[4], Packet::Disconnect);
+
+            // the connection is closed, so is the receiver.
+            assert!(receiver.recv().await.is_err());
+        });
+    }
+
+    #[test]
+    fn test_client_refused() {
+        async_std::task::block_on(async move {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let port = listener.local_addr().unwrap().port();
+            let broker = async_std::task::spawn(broker(listener));
+
+            match MqttClient::connect(&options(port, "user", "wrong")).await {
+                Ok(_) => panic!("the broker must refuse the wrong password"),
+                Err(e) => assert!(e.errno() == ErrorCode::NEAR_ERROR_REFUSE),
+            }
+
+            drop(broker);
+        });
+    }
+
+    /// Run it with a real broker, e.g. `mosquitto -v`, and set MQTT_TEST_BROKER=127.0.0.1:1883,
+    /// MQTT_TEST_USERNAME and MQTT_TEST_PASSWORD if the broker requires them.
+    #[test]
+    #[ignore]
+    fn test_client_with_broker() {
+        async_std::task::block_on(async move {
+            let broker = std::env::var("MQTT_TEST_BROKER").unwrap_or("127.0.0.1:1883".to_owned());
+            let (host, port) = broker.split_once(':').unwrap();
+
+            let (client, receiver) =
+                MqttClient::connect(&MqttOptions {
+                    host: host.to_owned(),
+                    port: port.parse().unwrap(),
+                    username: std::env::var("MQTT_TEST_USERNAME").ok(),
+                    password: std::env::var("MQTT_TEST_PASSWORD").ok(),
+                    ..options(0, "", "")
+                })
+                .await
+                .unwrap();
+
+            client.subscribe(vec![("near/test/#".to_owned(), QoS::AtLeastOnce)]).await.unwrap();
+            // wait the suback, the broker drops the publish which is before the subscription.
+            async_std::task::sleep(Duration::from_millis(500)).await;
+            client.publish("near/test/echo", "hello", false).await.unwrap();
+
+            let echo = async_std::future::timeout(Duration::from_secs(5), receiver.recv()).await.unwrap().unwrap();
+            assert_eq!(echo.topic, "near/test/echo");
+            assert_eq!(echo.payload, Bytes::from_static(b"hello"));
+
+            client.disconnect().await;
+        });
+    }
+}
@@ -0,0 +1,6 @@
+
+pub mod packet;
+pub mod client;
+
+pub use packet::{LastWill, Publish, QoS};
+pub use client::{MqttClient, MqttOptions};
@@ -0,0 +1,380 @@
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+
+use near_base::{NearResult, NearError, ErrorCode};
+
+// MQTT 3.1.1, only the packets the bridge uses are supported.
+const CONNECT: u8       = 1;
+const CONNACK: u8       = 2;
+const PUBLISH: u8       = 3;
+const PUBACK: u8        = 4;
+const SUBSCRIBE: u8     = 8;
+const SUBACK: u8        = 9;
+const PINGREQ: u8       = 12;
+const PINGRESP: u8      = 13;
+const DISCONNECT: u8    = 14;
+
+const MAX_REMAINING_LENGTH: usize = 268_435_455;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QoS {
+    AtMostOnce = 0,
+    AtLeastOnce = 1,
+}
+
+impl TryFrom<u8> for QoS {
+    type Error = NearError;
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        match v {
+            0 => Ok(QoS::AtMostOnce),
+            1 => Ok(QoS::AtLeastOnce),
+            _ => Err(NearError::new(ErrorCode::NEAR_ERROR_UNDEFINED, format!("unsupport qos {v}"))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LastWill {
+    pub topic: String,
+    pub payload: Bytes,
+    pub qos: QoS,
+    pub retain: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Connect {
+    pub client_id: String,
+    pub keep_alive: u16,
+    pub clean_session: bool,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub last_will: Option<LastWill>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Publish {
+    pub topic: String,
+    pub payload: Bytes,
+    pub qos: QoS,
+    pub retain: bool,
+    pub dup: bool,
+    pub packet_id: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Packet {
+    Connect(Connect),
+    ConnAck { session_present: bool, code: u8 },
+    Publish(Publish),
+    PubAck(u16),
+    Subscribe { packet_id: u16, filters: Vec<(String, QoS)> },
+    SubAck { packet_id: u16, codes: Vec<u8> },
+    PingReq,
+    PingResp,
+    Disconnect,
+}
+
+fn put_string(buf: &mut BytesMut, v: &str) {
+    put_bytes(buf, v.as_bytes());
+}
+
+fn put_bytes(buf: &mut BytesMut, v: &[u8]) {
+    buf.put_u16(v.len() as u16);
+    buf.put_slice(v);
+}
+
+fn put_remaining_length(buf: &mut BytesMut, mut len: usize) {
+    loop {
+        let mut byte = (len % 128) as u8;
+        len /= 128;
+        if len > 0 {
+            byte |= 0x80;
+        }
+        buf.put_u8(byte);
+        if len == 0 {
+            break;
+        }
+    }
+}
+
+fn get_u8(buf: &mut Bytes) -> NearResult<u8> {
+    if buf.remaining() < 1 {
+        Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, "packet is too short"))
+    } else {
+        Ok(buf.get_u8())
+    }
+}
+
+fn get_u16(buf: &mut Bytes) -> NearResult<u16> {
+    if buf.remaining() < 2 {
+        Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, "packet is too short"))
+    } else {
+        Ok(buf.get_u16())
+    }
+}
+
+fn get_bytes(buf: &mut Bytes) -> NearResult<Bytes> {
+    let len = get_u16(buf)? as usize;
+    if buf.remaining() < len {
+        Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, "packet is too short"))
+    } else {
+        Ok(buf.split_to(len))
+    }
+}
+
+fn get_string(buf: &mut Bytes) -> NearResult<String> {
+    String::from_utf8(get_bytes(buf)?.to_vec())
+        .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, format!("invalid utf8 string with err: {e}")))
+}
+
+impl Packet {
+    pub fn encode(&self, buf: &mut BytesMut) {
+        let mut body = BytesMut::new();
+
+        let header = match self {
+            Packet::Connect(connect) => {
+                put_string(&mut body, "MQTT");
+                body.put_u8(4);
+
+                let mut flags = 0u8;
+                if connect.clean_session { flags |= 0x02; }
+                if let Some(will) = connect.last_will.as_ref() {
+                    flags |= 0x04 | ((will.qos as u8) << 3);
+                    if will.retain { flags |= 0x20; }
+                }
+                if connect.password.is_some() { flags |= 0x40; }
+                if connect.username.is_some() { flags |= 0x80; }
+                body.put_u8(flags);
+                body.put_u16(connect.keep_alive);
+
+                put_string(&mut body, &connect.client_id);
+                if let Some(will) = connect.last_will.as_ref() {
+                    put_string(&mut body, &will.topic);
+                    put_bytes(&mut body, &will.payload);
+                }
+                if let Some(username) = connect.username.as_ref() {
+                    put_string(&mut body, username);
+                }
+                if let Some(password) = connect.password.as_ref() {
+                    put_string(&mut body, password);
+                }
+
+                CONNECT << 4
+            }
+            Packet::ConnAck { session_present, code } => {
+                body.put_u8(if *session_present { 1 } else { 0 });
+                body.put_u8(*code);
+                CONNACK << 4
+            }
+            Packet::Publish(publish) => {
+                put_string(&mut body, &publish.topic);
+                if publish.qos != QoS::AtMostOnce {
+                    body.put_u16(publish.packet_id);
+                }
+                body.put_slice(&publish.payload);
+
+                let mut flags = (publish.qos as u8) << 1;
+                if publish.dup { flags |= 0x08; }
+                if publish.retain { flags |= 0x01; }
+                (PUBLISH << 4) | flags
+            }
+            Packet::PubAck(packet_id) => {
+                body.put_u16(*packet_id);
+                PUBACK << 4
+            }
+            Packet::Subscribe { packet_id, filters } => {
+                body.put_u16(*packet_id);
+                for (filter, qos) in filters {
+                    put_string(&mut body, filter);
+                    body.put_u8(*qos as u8);
+                }
+                (SUBSCRIBE << 4) | 0x02
+            }
+            Packet::SubAck { packet_id, codes } => {
+                body.put_u16(*packet_id);
+                body.put_slice(codes);
+                SUBACK << 4
+            }
+            Packet::PingReq => PINGREQ << 4,
+            Packet::PingResp => PINGRESP << 4,
+            Packet::Disconnect => DISCONNECT << 4,
+        };
+
+        buf.put_u8(header);
+        put_remaining_length(buf, body.len());
+        buf.put(body);
+    }
+
+    /// Return None if the buf doesn't contain a whole packet yet.
+    pub fn decode(buf: &mut BytesMut) -> NearResult<Option<Packet>> {
+        if buf.len() < 2 {
+            return Ok(None);
+        }
+
+        let (len, header_len) = {
+            let mut len = 0usize;
+            let mut multiplier = 1usize;
+            let mut pos = 1;
+            loop {
+                if pos >= buf.len() {
+                    return Ok(None);
+                }
+                if pos > 4 {
+                    return Err(NearError::new(ErrorCode::NEAR_ERROR_OUTOFLIMIT, "remaining length is too long"));
+                }
+                let byte = buf[pos];
+                len += (byte & 0x7F) as usize * multiplier;
+                multiplier *= 128;
+                pos += 1;
+                if byte & 0x80 == 0 {
+                    break;
+                }
+            }
+            (len, pos)
+        };
+
+        if len > MAX_REMAINING_LENGTH {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_OUTOFLIMIT, "remaining length is too long"));
+        }
+
+        if buf.len() < header_len + len {
+            return Ok(None);
+        }
+
+        let header = buf[0];
+        buf.advance(header_len);
+        let mut body = buf.split_to(len).freeze();
+
+        let packet = match header >> 4 {
+            CONNECT => {
+                let protocol = get_string(&mut body)?;
+                let level = get_u8(&mut body)?;
+                if protocol != "MQTT" || level != 4 {
+                    return Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("unsupport protocol {protocol}:{level}")));
+                }
+                let flags = get_u8(&mut body)?;
+                let keep_alive = get_u16(&mut body)?;
+                let client_id = get_string(&mut body)?;
+                let last_will = if flags & 0x04 != 0 {
+                    Some(LastWill {
+                        topic: get_string(&mut body)?,
+                        payload: get_bytes(&mut body)?,
+                        qos: QoS::try_from((flags >> 3) & 0x03)?,
+                        retain: flags & 0x20 != 0,
+                    })
+                } else {
+                    None
+                };
+                let username = if flags & 0x80 != 0 { Some(get_string(&mut body)?) } else { None };
+                let password = if flags & 0x40 != 0 { Some(get_string(&mut body)?) } else { None };
+
+                Packet::Connect(Connect {
+                    client_id,
+                    keep_alive,
+                    clean_session: flags & 0x02 != 0,
+                    username,
+                    password,
+                    last_will,
+                })
+            }
+            CONNACK => {
+                let session_present = get_u8(&mut body)? & 0x01 != 0;
+                let code = get_u8(&mut body)?;
+                Packet::ConnAck { session_present, code }
+            }
+            PUBLISH => {
+                let qos = QoS::try_from((header >> 1) & 0x03)?;
+                let topic = get_string(&mut body)?;
+                let packet_id = if qos != QoS::AtMostOnce { get_u16(&mut body)? } else { 0 };
+
+                Packet::Publish(Publish {
+                    topic,
+                    payload: body,
+                    qos,
+                    retain: header & 0x01 != 0,
+                    dup: header & 0x08 != 0,
+                    packet_id,
+                })
+            }
+            PUBACK => Packet::PubAck(get_u16(&mut body)?),
+            SUBSCRIBE => {
+                let packet_id = get_u16(&mut body)?;
+                let mut filters = vec![];
+                while body.has_remaining() {
+                    let filter = get_string(&mut body)?;
+                    let qos = QoS::try_from(get_u8(&mut body)?)?;
+                    filters.push((filter, qos));
+                }
+                Packet::Subscribe { packet_id, filters }
+            }
+            SUBACK => {
+                let packet_id = get_u16(&mut body)?;
+                Packet::SubAck { packet_id, codes: body.to_vec() }
+            }
+            PINGREQ => Packet::PingReq,
+            PINGRESP => Packet::PingResp,
+            DISCONNECT => Packet::Disconnect,
+            v => {
+                return Err(NearError::new(ErrorCode::NEAR_ERROR_UNDEFINED, format!("unsupport packet type {v}")));
+            }
+        };
+
+        Ok(Some(packet))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bytes::{Bytes, BytesMut};
+
+    use super::{Packet, Connect, LastWill, Publish, QoS};
+
+    #[test]
+    fn test_packet_codec() {
+        let packets = vec![
+            Packet::Connect(Connect {
+                client_id: "near-bridge".to_owned(),
+                keep_alive: 30,
+                clean_session: true,
+                username: Some("user".to_owned()),
+                password: Some("pwd".to_owned()),
+                last_will: Some(LastWill {
+                    topic: "near/status".to_owned(),
+                    payload: Bytes::from_static(b"offline"),
+                    qos: QoS::AtLeastOnce,
+                    retain: true,
+                }),
+            }),
+            Packet::ConnAck { session_present: false, code: 0 },
+            Packet::Publish(Publish {
+                topic: "near/thing/1/state".to_owned(),
+                payload: Bytes::from(vec![b'x'; 300]),
+                qos: QoS::AtLeastOnce,
+                retain: true,
+                dup: false,
+                packet_id: 7,
+            }),
+            Packet::PubAck(7),
+            Packet::Subscribe { packet_id: 8, filters: vec![("near/thing/+/set/#".to_owned(), QoS::AtLeastOnce)] },
+            Packet::SubAck { packet_id: 8, codes: vec![1] },
+            Packet::PingReq,
+            Packet::PingResp,
+            Packet::Disconnect,
+        ];
+
+        let mut buf = BytesMut::new();
+        for packet in packets.iter() {
+            packet.encode(&mut buf);
+        }
+
+        // a partial packet must wait more data.
+        let mut partial = BytesMut::from(&buf[..3]);
+        assert_eq!(Packet::decode(&mut partial).unwrap(), None);
+
+        for packet in packets {
+            assert_eq!(Packet::decode(&mut buf).unwrap(), Some(packet));
+        }
+        assert!(buf.is_empty());
+    }
+}
@@ -0,0 +1,2 @@
+
+pub const SERVICE_NAME: &'static str    = "hci-mqtt";
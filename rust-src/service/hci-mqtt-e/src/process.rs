@@ -0,0 +1,501 @@
+
+use std::{sync::{Arc, Mutex}, collections::{HashMap, BTreeMap}, time::Duration};
+
+use async_std::channel::Receiver;
+use bytes::Bytes;
+use log::{trace, error, info, warn};
+
+use base::MessageType;
+use near_base::{NearResult, NearError, ErrorCode, ObjectId, now, builder_codec_macro::Empty, sequence::SequenceString};
+use near_transport::{HeaderMeta, CommandParam, CreatorMeta};
+use near_util::Topic;
+
+use common::{RuntimeProcessTrait, RoutineTemplate, RuntimeStack};
+use right::{RightStack, RightStackConfig};
+use protos::hci::{brand::Brand_info_list,
+                  product::{Product_info_list, Thing_capability_query, Thing_capability_list},
+                  thing::{Thing_query_all, Thing_info_list},
+                  schedule::{Schedule_list, Schedule_mode},
+                  hci_thing::Hci_ctrl_thing,
+                  telemetry::{Thing_telemetry_query, Thing_telemetry_resolution, Thing_telemetry_series}};
+use topic_util::types::hci_types::HciTaskId;
+use topic_util::topics::hci_gateway::NEAR_THING_GATEWAY_CTRL_THING_PUB;
+use topic_util::topics::hci_schedule::NEAR_THING_SCHEDULE_EXECUTE_PUB;
+use topic_util::topics::hci_storage::{NEAR_THING_STORAGE_BRAND_QUERY_ALL_PUB, NEAR_THING_STORAGE_PRODUCT_QUERY_ALL_PUB,
+                                      NEAR_THING_STORAGE_THING_QUERY_ALL_PUB, NEAR_THING_STORAGE_THING_CAPABILITY_QUERY_PUB,
+                                      NEAR_THING_STORAGE_SCHEDULE_QUERYALL_PUB, NEAR_THING_STORAGE_TELEMETRY_QUERY_PUB};
+
+use crate::discovery::{Discovery, ThingDevice, Command, ONLINE, OFFLINE};
+use crate::mqtt::{MqttClient, MqttOptions, LastWill, Publish, QoS};
+
+#[derive(Clone)]
+pub struct Config {
+    pub host: String,
+    pub port: u16,
+    pub client_id: String,
+    /// The broker must authenticate the bridge, they're required.
+    pub username: String,
+    pub password: String,
+    /// The people whom the commands from the broker are made as, its right is checked before they're dispatched.
+    pub people: ObjectId,
+    pub keep_alive: Duration,
+    pub reconnect_interval: Duration,
+
+    pub base_topic: String,
+    pub discovery_prefix: String,
+    /// Interval to republish the discovery of things and scenes.
+    pub discovery_interval: Duration,
+    /// Interval to publish the state of things.
+    pub state_interval: Duration,
+    /// The thing is offline if it hasn't reported in this duration.
+    pub availability_timeout: Duration,
+}
+
+impl std::default::Default for Config {
+    fn default() -> Self {
+        Self {
+            host: "127.0.0.1".to_owned(),
+            port: 1883,
+            client_id: crate::p::SERVICE_NAME.to_owned(),
+            username: String::new(),
+            password: String::new(),
+            people: ObjectId::default(),
+            keep_alive: Duration::from_secs(30),
+            reconnect_interval: Duration::from_secs(5),
+            base_topic: "near".to_owned(),
+            discovery_prefix: "homeassistant".to_owned(),
+            discovery_interval: Duration::from_secs(60),
+            state_interval: Duration::from_secs(10),
+            availability_timeout: Duration::from_secs(300),
+        }
+    }
+}
+
+#[derive(Default)]
+struct BridgeState {
+    client: Option<MqttClient>,
+    // topic -> payload, which have been published and retained.
+    retained: HashMap<String, String>,
+    things: Vec<String>,
+}
+
+struct ProcessImpl {
+    #[allow(unused)]
+    service_name: String,
+    config: Config,
+
+    state: Mutex<BridgeState>,
+    right_stack: once_cell::sync::OnceCell<RightStack>,
+}
+
+#[derive(Clone)]
+pub struct Process(Arc<ProcessImpl>);
+
+impl Process {
+    pub fn new(service_name: String, config: Config) -> Self {
+        Self(Arc::new(ProcessImpl {
+            service_name,
+            config,
+            state: Mutex::new(BridgeState::default()),
+            right_stack: once_cell::sync::OnceCell::new(),
+        }))
+    }
+
+    #[inline]
+    pub(crate) fn config(&self) -> &Config {
+        &self.0.config
+    }
+
+    fn discovery(&self) -> Discovery<'_> {
+        Discovery {
+            base_topic: self.config().base_topic.as_str(),
+            discovery_prefix: self.config().discovery_prefix.as_str(),
+        }
+    }
+
+    fn client(&self) -> Option<MqttClient> {
+        self.0.state.lock().unwrap().client.clone()
+    }
+
+    fn right_stack(&self) -> &RightStack {
+        self.0.right_stack.get().expect("right stack must open.")
+    }
+
+    fn open_right(&self) -> NearResult<()> {
+        // the commands are made to the public topics, and they require the control privilege by default.
+        let right_stack = RightStack::open(&self.0.service_name, RuntimeStack::get_instance().stack(), RightStackConfig::default());
+
+        self.0.right_stack
+            .set(right_stack)
+            .map_err(| _ | NearError::new(ErrorCode::NEAR_ERROR_ACTIVED, "right stack has been opened."))
+    }
+
+    /// The command is made as the people of the config, so it's refused unless the people is granted.
+    async fn call_as_people<T, R>(&self, topic: &Topic, thing_id: Option<&str>, req: R) -> NearResult<T>
+    where T: near_base::Serialize + near_base::Deserialize + Send + Sync + Default + Clone + 'static,
+          R: near_base::Serialize {
+        let people = &self.config().people;
+
+        self.right_stack()
+            .check(people, topic, MessageType::Public, thing_id)
+            .map_err(| e | {
+                error!("{e}, topic: {topic}");
+                e
+            })?;
+
+        let header_meta = HeaderMeta {
+            command: CommandParam::Request(SequenceString::from(&rand::random::<[u8; 32]>())),
+            creator: Some(CreatorMeta {
+                creator: Some(people.clone()),
+                ..Default::default()
+            }),
+            requestor: people.clone(),
+            to: RuntimeStack::get_instance().local().object_id().clone(),
+            topic: topic.clone(),
+            timestamp: now(),
+            net_meta: None,
+        };
+
+        RoutineTemplate::<T>::call_with_headermeta(&header_meta, topic.clone(), req)
+            .await
+            .map_err(| e | {
+                error!("{e}, topic: {topic}");
+                e
+            })?
+            .await
+    }
+}
+
+impl Process {
+    async fn connect(&self) -> NearResult<Receiver<Publish>> {
+        let config = self.config();
+
+        let (client, receiver) =
+            MqttClient::connect(&MqttOptions {
+                host: config.host.clone(),
+                port: config.port,
+                client_id: config.client_id.clone(),
+                keep_alive: config.keep_alive,
+                username: Some(config.username.clone()),
+                password: Some(config.password.clone()),
+                last_will: Some(LastWill {
+                    topic: self.discovery().status_topic(),
+                    payload: Bytes::from_static(OFFLINE.as_bytes()),
+                    qos: QoS::AtLeastOnce,
+                    retain: true,
+                }),
+            })
+            .await?;
+
+        client.publish(&self.discovery().status_topic(), ONLINE, true).await?;
+        client.subscribe(
+            self.discovery()
+                .command_filters()
+                .into_iter()
+                .map(| filter | (filter, QoS::AtLeastOnce))
+                .collect()
+        )
+        .await?;
+
+        {
+            let state = &mut *self.0.state.lock().unwrap();
+            state.client = Some(client);
+            // the broker may be restarted, publish all of them again.
+            state.retained.clear();
+        }
+
+        Ok(receiver)
+    }
+
+    fn start_bridge(&self) {
+        let arc_self = self.clone();
+
+        async_std::task::spawn(async move {
+            loop {
+                match arc_self.connect().await {
+                    Ok(receiver) => {
+                        if let Err(e) = arc_self.sync_discovery().await {
+                            error!("failed sync discovery with err: {e}");
+                        }
+
+                        while let Ok(publish) = receiver.recv().await {
+                            let arc_self = arc_self.clone();
+                            async_std::task::spawn(async move {
+                                arc_self.on_command(publish).await
+                            });
+                        }
+
+                        warn!("mqtt broker is disconnected.");
+                        if let Some(client) = arc_self.0.state.lock().unwrap().client.take() {
+                            async_std::task::spawn(async move { client.disconnect().await });
+                        }
+                    }
+                    Err(e) => {
+                        error!("failed connect mqtt broker with err: {e}");
+                    }
+                }
+
+                let _ = async_std::future::timeout(arc_self.config().reconnect_interval, async_std::future::pending::<()>()).await;
+            }
+        });
+    }
+
+    fn start_sync(&self) {
+        let arc_self = self.clone();
+
+        async_std::task::spawn(async move {
+            loop {
+                let _ = async_std::future::timeout(arc_self.config().discovery_interval, async_std::future::pending::<()>()).await;
+
+                if arc_self.client().is_some() {
+                    if let Err(e) = arc_self.sync_discovery().await {
+                        error!("failed sync discovery with err: {e}");
+                    }
+                }
+            }
+        });
+
+        let arc_self = self.clone();
+
+        async_std::task::spawn(async move {
+            loop {
+                let _ = async_std::future::timeout(arc_self.config().state_interval, async_std::future::pending::<()>()).await;
+
+                if arc_self.client().is_some() {
+                    arc_self.sync_state().await;
+                }
+            }
+        });
+    }
+
+    /// Publish the retained payload only if it has been changed, empty payload removes the retained one.
+    async fn publish_retained(&self, topic: String, payload: String) -> NearResult<()> {
+        let client = {
+            let state = &*self.0.state.lock().unwrap();
+            if state.retained.get(&topic) == Some(&payload) {
+                return Ok(());
+            }
+            state.client.clone()
+        };
+
+        if let Some(client) = client {
+            client.publish(&topic, payload.clone().into_bytes(), true).await?;
+
+            let state = &mut *self.0.state.lock().unwrap();
+            if payload.is_empty() {
+                state.retained.remove(&topic);
+            } else {
+                state.retained.insert(topic, payload);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn sync_discovery(&self) -> NearResult<()> {
+        trace!("sync_discovery");
+
+        let things = call::<Thing_info_list, _>(NEAR_THING_STORAGE_THING_QUERY_ALL_PUB.topic(), Thing_query_all::default()).await?;
+        let capabilities =
+            call::<Thing_capability_list, _>(
+                NEAR_THING_STORAGE_THING_CAPABILITY_QUERY_PUB.topic(),
+                Thing_capability_query {
+                    thing_ids: things.things.iter().map(| thing | thing.thing_id().to_owned()).collect(),
+                    ..Default::default()
+                }
+            )
+            .await?;
+        let brands = call::<Brand_info_list, _>(NEAR_THING_STORAGE_BRAND_QUERY_ALL_PUB.topic(), Empty).await?;
+        let products = call::<Product_info_list, _>(NEAR_THING_STORAGE_PRODUCT_QUERY_ALL_PUB.topic(), Empty).await?;
+        let schedules = call::<Schedule_list, _>(NEAR_THING_STORAGE_SCHEDULE_QUERYALL_PUB.topic(), Empty).await?;
+
+        let brand_name = | brand_id: &str | {
+            brands.brands
+                .iter()
+                .find(| brand | brand.brand_id() == brand_id)
+                .map(| brand | brand.brand_name())
+                .unwrap_or_default()
+        };
+
+        let product_name = | major_product_id: &str, minor_product_id: &str | {
+            products.products
+                .iter()
+                .find(| product | product.product_id() == major_product_id)
+                .and_then(| major | {
+                    major.children
+                        .products
+                        .iter()
+                        .find(| product | product.product_id() == minor_product_id)
+                        .or(Some(major))
+                })
+                .map(| product | product.product_name())
+                .unwrap_or_default()
+        };
+
+        let mut configs = vec![];
+
+        for thing in things.things.iter() {
+            if let Some(capability) = capabilities.capabilities.get(thing.thing_id()) {
+                let device = ThingDevice {
+                    thing,
+                    brand_name: brand_name(thing.brand_id()),
+                    product_name: product_name(thing.major_product_id(), thing.minor_product_id()),
+                };
+                configs.extend(self.discovery().thing_configs(&device, capability));
+            } else {
+                trace!("[{}] hasn't capability, ignore its discovery.", thing.thing_id());
+            }
+        }
+
+        for schedule in schedules.schedules.iter() {
+            if schedule.mode() == Schedule_mode::Maual {
+                configs.push(self.discovery().scene_config(schedule));
+            }
+        }
+
+        // remove the entities which have been removed.
+        let removed: Vec<String> = {
+            let state = &mut *self.0.state.lock().unwrap();
+            state.things = things.things.iter().map(| thing | thing.thing_id().to_owned()).collect();
+
+            let prefix = format!("{}/", self.config().discovery_prefix);
+            state.retained
+                .keys()
+                .filter(| topic | topic.starts_with(&prefix) && !configs.iter().any(| (config_topic, _) | &config_topic == topic))
+                .cloned()
+                .collect()
+        };
+
+        for topic in removed {
+            self.publish_retained(topic, String::new()).await?;
+        }
+
+        for (topic, payload) in configs {
+            self.publish_retained(topic, payload).await?;
+        }
+
+        info!("Successfully sync discovery.");
+
+        Ok(())
+    }
+
+    async fn sync_state(&self) {
+        let things = self.0.state.lock().unwrap().things.clone();
+        let begin = near_base::now().saturating_sub(self.config().availability_timeout.as_micros() as u64);
+
+        for thing_id in things {
+            let series =
+                match call::<Thing_telemetry_series, _>(
+                        NEAR_THING_STORAGE_TELEMETRY_QUERY_PUB.topic(),
+                        Thing_telemetry_query {
+                            thing_id: thing_id.clone(),
+                            begin,
+                            resolution: Thing_telemetry_resolution::Raw.into(),
+                            ..Default::default()
+                        }
+                    )
+                    .await {
+                    Ok(series) => series,
+                    Err(e) => {
+                        error!("failed query [{thing_id}] telemetry with err: {e}");
+                        continue;
+                    }
+                };
+
+            // the latest value of each property.
+            let mut samples: Vec<_> = series.samples.iter().collect();
+            samples.sort_by_key(| sample | sample.timestamp());
+
+            let mut state = BTreeMap::new();
+            for sample in samples {
+                state.extend(sample.data.iter().map(| (k, v) | (k.clone(), v.clone())));
+            }
+
+            let availability = if state.is_empty() { OFFLINE } else { ONLINE };
+
+            let r =
+                match self.publish_retained(self.discovery().thing_availability_topic(&thing_id), availability.to_owned()).await {
+                    Ok(_) if !state.is_empty() => {
+                        let payload = serde_json::to_string(&state).unwrap_or_default();
+                        self.publish_retained(self.discovery().thing_state_topic(&thing_id), payload).await
+                    }
+                    r => r,
+                };
+
+            if let Err(e) = r {
+                error!("failed publish [{thing_id}] state with err: {e}");
+                break;
+            }
+        }
+    }
+
+    async fn on_command(&self, publish: Publish) {
+        trace!("on_command: topic={}", publish.topic);
+
+        match self.discovery().parse_command(&publish.topic, &publish.payload) {
+            Some(Command::CtrlThing { thing_id, data }) => {
+                // the gateway checks the data against the capability.
+                match self.call_as_people::<HciTaskId, _>(
+                        NEAR_THING_GATEWAY_CTRL_THING_PUB.topic(),
+                        Some(thing_id.as_str()),
+                        Hci_ctrl_thing {
+                            thing_id: thing_id.clone(),
+                            data,
+                            ..Default::default()
+                        }
+                    )
+                    .await {
+                    Ok(task_id) => info!("Successfully ctrl [{thing_id}] thing, task-id: {task_id}"),
+                    Err(e) => error!("failed ctrl [{thing_id}] thing with err: {e}"),
+                }
+            }
+            Some(Command::ExecuteScene { schedule_id }) => {
+                match self.call_as_people::<Empty, _>(NEAR_THING_SCHEDULE_EXECUTE_PUB.topic(), None, schedule_id.clone()).await {
+                    Ok(_) => info!("Successfully execute [{schedule_id}] scene"),
+                    Err(e) => error!("failed execute [{schedule_id}] scene with err: {e}"),
+                }
+            }
+            None => {
+                warn!("invalid command, topic: {}", publish.topic);
+            }
+        }
+    }
+}
+
+async fn call<T, R>(topic: &Topic, req: R) -> NearResult<T>
+where T: near_base::Serialize + near_base::Deserialize + Send + Sync + Default + Clone + 'static,
+      R: near_base::Serialize {
+    RoutineTemplate::<T>::call(topic.clone(), req)
+        .await
+        .map_err(| e | {
+            error!("{e}, topic: {topic}");
+            e
+        })?
+        .await
+}
+
+#[async_trait::async_trait]
+impl RuntimeProcessTrait for Process {
+
+    async fn run(&self) -> NearResult<()> {
+        trace!("run enter");
+
+        self.open_right()?;
+
+        self.start_bridge();
+        self.start_sync();
+
+        Ok(())
+    }
+
+    fn quit(&self) {
+        trace!("quiting...");
+
+        if let Some(client) = self.client() {
+            async_std::task::block_on(async move {
+                let _ = client.publish(&self.discovery().status_topic(), OFFLINE, true).await;
+                client.disconnect().await;
+            });
+        }
+    }
+}
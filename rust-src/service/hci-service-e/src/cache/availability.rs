@@ -0,0 +1,152 @@
+
+use std::{collections::{HashMap, VecDeque}, time::Duration};
+
+use near_base::Timestamp;
+
+/// How many transitions are kept of each thing.
+const MAX_TRANSITIONS: usize = 32;
+
+#[derive(Clone)]
+pub struct Config {
+    pub check_interval: Duration,
+    /// The thing is offline if it hasn't advertised in this window.
+    pub default_timeout: Duration,
+    /// Silence window of the product, the key is the minor or major product id.
+    pub product_timeouts: HashMap<String, Duration>,
+    /// How many probes are sent before the thing is declared offline, zero disables probing.
+    pub probe_count: u32,
+    pub probe_interval: Duration,
+}
+
+impl std::default::Default for Config {
+    fn default() -> Self {
+        Self {
+            check_interval: Duration::from_secs(10),
+            default_timeout: Duration::from_secs(300),
+            product_timeouts: Default::default(),
+            probe_count: 2,
+            probe_interval: Duration::from_secs(10),
+        }
+    }
+}
+
+impl Config {
+    pub fn timeout(&self, minor_product_id: &str, major_product_id: &str) -> Duration {
+        self.product_timeouts.get(minor_product_id)
+            .or_else(|| self.product_timeouts.get(major_product_id))
+            .cloned()
+            .unwrap_or(self.default_timeout)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Availability {
+    Online,
+    Offline,
+}
+
+impl std::fmt::Display for Availability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Online => write!(f, "online"),
+            Self::Offline => write!(f, "offline"),
+        }
+    }
+}
+
+pub enum CheckResult {
+    Alive,
+    Probe,
+    Offline,
+}
+
+#[derive(Clone)]
+pub struct AvailabilityTracker {
+    last_seen: Timestamp,
+    probes: u32,
+    last_probe: Timestamp,
+    transitions: VecDeque<(Timestamp, Availability)>,
+}
+
+impl AvailabilityTracker {
+    pub fn new(now: Timestamp) -> Self {
+        Self {
+            last_seen: now,
+            probes: 0,
+            last_probe: 0,
+            transitions: VecDeque::new(),
+        }
+    }
+
+    #[inline]
+    pub fn last_seen(&self) -> Timestamp {
+        self.last_seen
+    }
+
+    pub fn transitions(&self) -> impl Iterator<Item=&(Timestamp, Availability)> {
+        self.transitions.iter()
+    }
+
+    pub fn seen(&mut self, now: Timestamp) {
+        self.last_seen = now;
+        self.probes = 0;
+    }
+
+    pub fn record(&mut self, now: Timestamp, availability: Availability) {
+        self.transitions.push_back((now, availability));
+        while self.transitions.len() > MAX_TRANSITIONS {
+            self.transitions.pop_front();
+        }
+    }
+
+    /// Probes are sent one by one after the silence window, the thing is offline when all of them are lost.
+    pub fn check(&mut self, now: Timestamp, timeout: Duration, config: &Config) -> CheckResult {
+        if now.saturating_sub(self.last_seen) <= timeout.as_micros() as Timestamp {
+            return CheckResult::Alive;
+        }
+
+        if now.saturating_sub(self.last_probe) < config.probe_interval.as_micros() as Timestamp {
+            // wait the response of the last probe.
+            return CheckResult::Alive;
+        }
+
+        if self.probes < config.probe_count {
+            self.probes += 1;
+            self.last_probe = now;
+            CheckResult::Probe
+        } else {
+            CheckResult::Offline
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{AvailabilityTracker, CheckResult, Config};
+
+    #[test]
+    fn test_availability_check() {
+        let config = Config {
+            probe_count: 2,
+            probe_interval: Duration::from_secs(10),
+            ..Default::default()
+        };
+        let timeout = Duration::from_secs(60);
+        let secs = | v: u64 | v * 1_000_000;
+
+        let mut tracker = AvailabilityTracker::new(secs(1000));
+
+        assert!(matches!(tracker.check(secs(1050), timeout, &config), CheckResult::Alive));
+        assert!(matches!(tracker.check(secs(1061), timeout, &config), CheckResult::Probe));
+        assert!(matches!(tracker.check(secs(1065), timeout, &config), CheckResult::Alive));
+        assert!(matches!(tracker.check(secs(1071), timeout, &config), CheckResult::Probe));
+        assert!(matches!(tracker.check(secs(1081), timeout, &config), CheckResult::Offline));
+
+        // the thing answers the probe.
+        tracker.seen(secs(1082));
+        assert!(matches!(tracker.check(secs(1090), timeout, &config), CheckResult::Alive));
+        assert!(matches!(tracker.check(secs(1143), timeout, &config), CheckResult::Probe));
+    }
+}
@@ -1,5 +1,6 @@
 
 pub mod thing_components;
+pub mod availability;
 
 #[derive(Clone)]
 pub enum ThingStatus {
@@ -1,6 +1,7 @@
 
-use std::{sync::{Arc, RwLock}, collections::{BTreeMap, btree_map::Entry}, str::FromStr, borrow::BorrowMut, };
+use std::{sync::{Arc, RwLock, Mutex}, collections::{BTreeMap, btree_map::Entry}, str::FromStr, borrow::BorrowMut, };
 
+use log::info;
 use mac_address::MacAddress;
 
 use near_base::{ObjectId, thing::ThingObject, NearResult, NearError, ErrorCode, now};
@@ -8,10 +9,15 @@ use near_base::{ObjectId, thing::ThingObject, NearResult, NearError, ErrorCode,
 use crate::{lua::data::Data, tasks::{TaskCbTrait, TaskModule, TaskCbData}};
 
 use super::ThingStatus;
+use super::availability::{Availability, AvailabilityTracker, CheckResult, Config as AvailabilityConfig};
 
 struct ThingComponent {
     thing: ThingObject,
     status: ThingStatus,
+    // (minor product id, major product id)
+    product: (String, String),
+    // the tracker is read by the routines while it's updated by the advertisements.
+    tracker: Mutex<AvailabilityTracker>,
 }
 
 #[derive(Clone)]
@@ -25,12 +31,21 @@ impl From<ThingComponent> for ThingComponentPtr {
 
 impl ThingComponentPtr {
     pub fn new(thing: ThingObject) -> Self {
+        let now = now();
+
         Self(Arc::new(ThingComponent {
             thing, 
-            status: ThingStatus::Online(now(), Default::default()), 
+            status: ThingStatus::Online(now, Default::default()), 
+            product: Default::default(),
+            tracker: Mutex::new(AvailabilityTracker::new(now)),
         }))
     }
 
+    #[inline]
+    fn mut_component(&mut self) -> &mut ThingComponent {
+        unsafe { &mut *(Arc::as_ptr(&self.0) as *mut ThingComponent) }
+    }
+
     #[inline]
     pub fn thing(&self) -> &ThingObject {
         &self.0.thing
@@ -41,21 +56,71 @@ impl ThingComponentPtr {
         self.0.status.clone()
     }
 
+    /// The snapshot of the tracker.
+    #[inline]
+    pub fn tracker(&self) -> AvailabilityTracker {
+        self.0.tracker.lock().unwrap().clone()
+    }
+
+    /// The firmware is published to the minor product, or the major one if the thing doesn't have it.
+    pub fn product_id(&self) -> &str {
+        let (minor_product_id, major_product_id) = &self.0.product;
+
+        if minor_product_id.is_empty() {
+            major_product_id.as_str()
+        } else {
+            minor_product_id.as_str()
+        }
+    }
+
+    pub fn set_product(&mut self, minor_product_id: String, major_product_id: String) {
+        self.mut_component().product = (minor_product_id, major_product_id);
+    }
+
     pub fn online(&mut self, data: Data) {
-        let mut_self = unsafe { &mut *(Arc::as_ptr(&self.0) as *mut ThingComponent) };
+        let now = now();
+        let mut_self = self.mut_component();
+        let mut tracker = mut_self.tracker.lock().unwrap();
+        tracker.seen(now);
+
         let status = &mut mut_self.status;
         match status {
-            ThingStatus::Offline(_, _) | ThingStatus::Online(_, _) => { *status = ThingStatus::Online(now(), data); }
+            ThingStatus::Offline(_, _) => {
+                *status = ThingStatus::Online(now, data);
+                tracker.record(now, Availability::Online);
+                info!("[{}] is online.", mut_self.thing.object_id());
+            }
+            ThingStatus::Online(_, _) => { *status = ThingStatus::Online(now, data); }
             _ => { /* ignore */ }
         }
     }
 
+    /// Any advertisement of the thing proves it's alive, the data is kept.
+    pub fn seen(&mut self) {
+        let now = now();
+        let mut_self = self.mut_component();
+        let mut tracker = mut_self.tracker.lock().unwrap();
+        tracker.seen(now);
+
+        let status = &mut mut_self.status;
+        if let ThingStatus::Offline(_, data) = status {
+            *status = ThingStatus::Online(now, data.take_map().into());
+            tracker.record(now, Availability::Online);
+            info!("[{}] is online.", mut_self.thing.object_id());
+        }
+    }
+
     pub fn offline(&mut self) {
-        let mut_self = unsafe { &mut *(Arc::as_ptr(&self.0) as *mut ThingComponent) };
+        let now = now();
+        let mut_self = self.mut_component();
         let status = &mut mut_self.status;
         match status {
             ThingStatus::Offline(_, _) => { /* ignore */ }
-            ThingStatus::Online(_, data) => { *status = ThingStatus::Offline(now(), data.take_map().into()); }
+            ThingStatus::Online(_, data) => {
+                *status = ThingStatus::Offline(now, data.take_map().into());
+                mut_self.tracker.lock().unwrap().record(now, Availability::Offline);
+                info!("[{}] is offline.", mut_self.thing.object_id());
+            }
             _ => { /* ignore */ }
         }
     }
@@ -174,6 +239,48 @@ impl ThingCollect {
         }
     }
 
+    pub fn set_products(&self, products: impl Iterator<Item=(ObjectId, String, String)>) {
+        let w = &mut *self.0.write().unwrap();
+
+        for (thing_id, minor_product_id, major_product_id) in products {
+            if let Some(thing) = w.things_id_mapping.get_mut(&thing_id) {
+                thing.set_product(minor_product_id, major_product_id);
+            }
+        }
+    }
+
+    pub fn seen(&self, mac: &MacAddress) {
+        let w = &mut *self.0.write().unwrap();
+
+        if let Some(thing) = w.things_mac_mapping.get_mut(mac) {
+            thing.seen();
+        }
+    }
+
+    /// Mark the silent things offline, return the things which should be probed.
+    pub fn check_availability(&self, config: &AvailabilityConfig) -> Vec<ThingComponentPtr> {
+        let now = now();
+        let w = &mut *self.0.write().unwrap();
+
+        let mut probes = vec![];
+
+        for thing in w.things.iter_mut() {
+            if let ThingStatus::Online(_, _) = thing.status() {
+                let component = thing.mut_component();
+                let timeout = config.timeout(&component.product.0, &component.product.1);
+                let r = component.tracker.lock().unwrap().check(now, timeout, config);
+
+                match r {
+                    CheckResult::Alive => {}
+                    CheckResult::Probe => probes.push(thing.clone()),
+                    CheckResult::Offline => thing.offline(),
+                }
+            }
+        }
+
+        probes
+    }
+
 }
 
 #[async_trait::async_trait]
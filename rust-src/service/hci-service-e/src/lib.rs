@@ -6,6 +6,8 @@ pub mod process;
 pub mod routines;
 // pub mod schedule;
 pub mod cache;
+pub mod ota;
+pub mod pairing;
 
 pub const SERVICE_NAME: &'static str = "hci-service";
 
@@ -1,7 +1,7 @@
 
 use std::path::PathBuf;
 
-use near_base::{NearResult, NearError, ErrorCode};
+use near_base::{NearResult, NearError, ErrorCode, PublicKey, Deserialize};
 
 use common::{ProcessCommandBuild, ProcessAction, };
 
@@ -54,13 +54,87 @@ pub async fn load_from_config(service_name: &str) -> NearResult<Config> {
                 ctrl_config: hci_service_e::routines::ctrl_thing_task::Config {
                     ctrl_interval,
                 },
-                query_task_config: Default::default(),
             }
         )
     };
 
+    let load_availability = | val: &toml::Value | -> hci_service_e::cache::availability::Config {
+        let mut config = hci_service_e::cache::availability::Config::default();
+
+        if let Some(availability) = val.get("availability") {
+            let get_secs = | name: &str | {
+                availability.get(name)
+                    .and_then(| v | v.as_integer())
+                    .map(| v | std::time::Duration::from_secs(v as u64))
+            };
+
+            config.check_interval = get_secs("check_interval").unwrap_or(config.check_interval);
+            config.default_timeout = get_secs("default_timeout").unwrap_or(config.default_timeout);
+            config.probe_interval = get_secs("probe_interval").unwrap_or(config.probe_interval);
+            config.probe_count = 
+                availability.get("probe_count")
+                    .and_then(| v | v.as_integer())
+                    .map(| v | v as u32)
+                    .unwrap_or(config.probe_count);
+
+            // [availability.products], product-id = seconds
+            if let Some(products) = availability.get("products").and_then(| v | v.as_table()) {
+                for (product_id, timeout) in products {
+                    if let Some(timeout) = timeout.as_integer() {
+                        config.product_timeouts.insert(product_id.clone(), std::time::Duration::from_secs(timeout as u64));
+                    }
+                }
+            }
+        }
+
+        config
+    };
+
+    let load_ota = | val: &toml::Value | -> hci_service_e::ota::Config {
+        let mut config = hci_service_e::ota::Config::default();
+
+        if let Some(ota) = val.get("ota") {
+            let get_integer = | name: &str | ota.get(name).and_then(| v | v.as_integer());
+
+            config.chunk_size = get_integer("chunk_size").map(| v | v as usize).unwrap_or(config.chunk_size);
+            config.ack_timeout = get_integer("ack_timeout").map(| v | std::time::Duration::from_millis(v as u64)).unwrap_or(config.ack_timeout);
+            config.retry_times = get_integer("retry_times").map(| v | v as u32).unwrap_or(config.retry_times);
+            config.verify_timeout = get_integer("verify_timeout").map(| v | std::time::Duration::from_secs(v as u64)).unwrap_or(config.verify_timeout);
+        }
+
+        config
+    };
+
+    let load_pairing = | val: &toml::Value | -> hci_service_e::pairing::Config {
+        let mut config = hci_service_e::pairing::Config::default();
+
+        if let Some(pairing) = val.get("pairing") {
+            let get_integer = | name: &str | pairing.get(name).and_then(| v | v.as_integer());
+
+            config.answer_timeout = get_integer("answer_timeout").map(| v | std::time::Duration::from_millis(v as u64)).unwrap_or(config.answer_timeout);
+            config.retry_times = get_integer("retry_times").map(| v | v as u32).unwrap_or(config.retry_times);
+
+            // [pairing.brands], brand-id = the hex of the public key which the things of the brand sign the challenge with
+            if let Some(brands) = pairing.get("brands").and_then(| v | v.as_table()) {
+                for (brand_id, public_key) in brands {
+                    match public_key.as_str()
+                                    .and_then(| v | hex::decode(v).ok())
+                                    .and_then(| v | PublicKey::deserialize(&v).map(| (public_key, _) | public_key).ok()) {
+                        Some(public_key) => { config.brands.insert(brand_id.clone(), public_key); }
+                        None => println!("ignore the pairing brand [{brand_id}], its public key is invalid"),
+                    }
+                }
+            }
+        }
+
+        config
+    };
+
     Ok(
         Config {
+            availability_config: load_availability(&val),
+            ota_config: load_ota(&val),
+            pairing_config: load_pairing(&val),
             routines_config: load_routines(&mut val)?,
             ..Default::default()
         }
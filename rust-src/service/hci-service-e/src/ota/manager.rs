@@ -0,0 +1,199 @@
+
+use std::{sync::{Arc, RwLock}, collections::{HashMap, hash_map::Entry}};
+
+use log::{trace, error, info};
+use mac_address::MacAddress;
+
+use near_base::{NearResult, NearError, ErrorCode, ObjectId, Deserialize, hash_data, file::FileObject};
+
+use common::{RuntimeStack, RoutineTemplate};
+use nds::{MultiDownloadSource, SingleDownloadSource, DownloadSource};
+use protos::hci::firmware::{Firmware_info, Firmware_query, Ota_status_list, Ota_state};
+use topic_util::topics::hci_storage::NEAR_THING_STORAGE_FIRMWARE_QUERY_PUB;
+
+use crate::{process::Process, lua::data::Data};
+
+use super::{Config, OtaReport, session::Session};
+
+struct ManagerImpl {
+    process: Process,
+    config: Config,
+    // the last upgrade of each thing
+    sessions: RwLock<HashMap<ObjectId, Session>>,
+}
+
+#[derive(Clone)]
+pub struct Manager(Arc<ManagerImpl>);
+
+impl Manager {
+    pub fn new(process: Process, config: Config) -> Self {
+        Self(Arc::new(ManagerImpl {
+            process,
+            config,
+            sessions: RwLock::new(HashMap::new()),
+        }))
+    }
+
+    /// Upgrade the things to the version of their product, the latest one if the version is empty.
+    pub async fn start(&self, thing_ids: Vec<String>, version: String) -> NearResult<Ota_status_list> {
+        let mut firmwares: HashMap<String, Firmware_info> = HashMap::new();
+        let mut targets = vec![];
+
+        // check all of the things before any of them is started.
+        for thing_id in thing_ids {
+            let thing = self.0.process.thing_components().get_thing_by_id(&thing_id)?;
+
+            let product_id = thing.product_id().to_owned();
+            if product_id.is_empty() {
+                return Err(NearError::new(ErrorCode::NEAR_ERROR_NOTFOUND, format!("Not found the product of {thing_id}")));
+            }
+
+            if let Entry::Vacant(v) = firmwares.entry(product_id.clone()) {
+                v.insert(self.query_firmware(&product_id, &version).await?);
+            }
+
+            targets.push((thing, product_id));
+        }
+
+        let mut groups: HashMap<String, Vec<Session>> = HashMap::new();
+        let mut things = vec![];
+
+        {
+            let sessions = &mut *self.0.sessions.write().unwrap();
+
+            if let Some((thing, _)) = 
+                targets.iter()
+                    .find(| (thing, _) | sessions.get(thing.thing().object_id()).map(| session | !session.is_finished()).unwrap_or(false)) {
+                return Err(NearError::new(ErrorCode::NEAR_ERROR_ACTIVED, format!("{} is upgrading", thing.thing().object_id())));
+            }
+
+            for (thing, product_id) in targets {
+                let session = Session::new(self.0.process.clone(), self.0.config.clone(), thing.clone(), firmwares[&product_id].clone());
+
+                sessions.insert(thing.thing().object_id().clone(), session.clone());
+                things.push(session.status());
+                groups.entry(product_id).or_default().push(session);
+            }
+        }
+
+        for (product_id, sessions) in groups {
+            let firmware = firmwares.remove(&product_id).unwrap();
+            let arc_self = self.clone();
+
+            async_std::task::spawn(async move {
+                arc_self.upgrade(firmware, sessions).await;
+            });
+        }
+
+        Ok(Ota_status_list {
+            things,
+            ..Default::default()
+        })
+    }
+
+    pub fn query(&self, thing_ids: Vec<String>) -> Ota_status_list {
+        let sessions = self.0.sessions.read().unwrap();
+
+        let things =
+            if thing_ids.is_empty() {
+                sessions.values().map(| session | session.status()).collect()
+            } else {
+                thing_ids.iter()
+                    .filter_map(| thing_id | thing_id.parse::<ObjectId>().ok())
+                    .filter_map(| thing_id | sessions.get(&thing_id).map(| session | session.status()))
+                    .collect()
+            };
+
+        Ota_status_list {
+            things,
+            ..Default::default()
+        }
+    }
+
+    pub fn cancel(&self, thing_ids: Vec<String>) -> Ota_status_list {
+        {
+            let sessions = self.0.sessions.read().unwrap();
+
+            thing_ids.iter()
+                .filter_map(| thing_id | thing_id.parse::<ObjectId>().ok())
+                .filter_map(| thing_id | sessions.get(&thing_id))
+                .filter(| session | !session.is_finished())
+                .for_each(| session | session.cancel());
+        }
+
+        self.query(thing_ids)
+    }
+
+    pub fn on_report(&self, mac: MacAddress, data: Data) {
+        let thing = match self.0.process.thing_components().get_thing_by_mac(mac.bytes()) {
+            Ok(thing) => thing,
+            Err(_) => return,
+        };
+
+        let session = match self.0.sessions.read().unwrap().get(thing.thing().object_id()) {
+            Some(session) if !session.is_finished() => session.clone(),
+            _ => return,
+        };
+
+        match OtaReport::try_from(&data) {
+            Ok(report) => session.on_report(report),
+            Err(e) => error!("invalid ota report of {} with err: {e}", thing.thing().object_id()),
+        }
+    }
+}
+
+impl Manager {
+    async fn query_firmware(&self, product_id: &str, version: &str) -> NearResult<Firmware_info> {
+        RoutineTemplate::<Firmware_info>::call(
+            NEAR_THING_STORAGE_FIRMWARE_QUERY_PUB.topic().clone(),
+            Firmware_query {
+                product_id: product_id.to_owned(),
+                version: version.to_owned(),
+                ..Default::default()
+            }
+        )
+        .await
+        .map_err(| e | {
+            error!("failed call {}", NEAR_THING_STORAGE_FIRMWARE_QUERY_PUB.topic());
+            e
+        })?
+        .await
+    }
+
+    /// Download the firmware from the core by nds, and check it before it's pushed to the things.
+    async fn load_firmware(&self, firmware: &Firmware_info) -> NearResult<Vec<u8>> {
+        let (file, _) = FileObject::deserialize(firmware.file.as_slice())?;
+
+        let source =
+            MultiDownloadSource::new()
+                .add_source(SingleDownloadSource::from(DownloadSource::default().set_target(RuntimeStack::get_instance().remote().object_id().clone())));
+
+        let nds_stack = self.0.process.nds_stack();
+        nds_stack.fetch_file(file.clone(), source).await?;
+
+        let content = nds_stack.read_file(&file).await?;
+
+        if content.len() as u64 != firmware.size || hash_data(content.as_slice()) != *file.desc().content().hash() {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("the firmware [{}] is damaged", firmware.file_id)));
+        }
+
+        Ok(content)
+    }
+
+    async fn upgrade(&self, firmware: Firmware_info, sessions: Vec<Session>) {
+        trace!("upgrade [{}] to {} things", firmware.version, sessions.len());
+
+        let content = match self.load_firmware(&firmware).await {
+            Ok(content) => Arc::new(content),
+            Err(e) => {
+                error!("failed load firmware [{}] with err: {e}", firmware.firmware_id);
+                sessions.iter().for_each(| session | session.finish(Ota_state::Failed, e.to_string()));
+                return;
+            }
+        };
+
+        info!("loaded firmware [{}], {} bytes", firmware.version, content.len());
+
+        let _ = futures::future::join_all(sessions.iter().map(| session | session.run(content.clone()))).await;
+    }
+}
@@ -0,0 +1,132 @@
+
+pub mod manager;
+mod session;
+
+use std::{time::Duration, str::FromStr};
+
+use near_base::{NearError, ErrorCode};
+
+use crate::lua::data::Data;
+
+// the params of the ota functions of the brand lua, no vendor script implements them yet,
+// deploy/lua/test/ota-test.lua does it for the test firmware.
+pub const OTA_VERSION: &'static str = "ota_version";
+pub const OTA_SIZE: &'static str    = "ota_size";
+pub const OTA_HASH: &'static str    = "ota_hash";
+pub const OTA_OFFSET: &'static str  = "ota_offset";
+pub const OTA_CHUNK: &'static str   = "ota_chunk";
+// the report of the thing, it's analyzed by the brand lua.
+pub const OTA_STATE: &'static str   = "ota_state";
+pub const OTA_ERROR: &'static str   = "ota_error";
+
+#[derive(Clone)]
+pub struct Config {
+    /// The bytes carried by each advertising, it's decided by the ota protocol of the brand.
+    pub chunk_size: usize,
+    /// How long the report of the thing is waited for after each advertising.
+    pub ack_timeout: Duration,
+    /// How many times the advertising is repeated before the thing is failed.
+    pub retry_times: u32,
+    /// How long the thing verifies the firmware and boots it.
+    pub verify_timeout: Duration,
+}
+
+impl std::default::Default for Config {
+    fn default() -> Self {
+        Self {
+            chunk_size: 12,
+            ack_timeout: Duration::from_secs(2),
+            retry_times: 5,
+            verify_timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ReportState {
+    /// the thing is receiving, the offset is the next byte it expects.
+    Receiving,
+    /// the image is verified, the thing is going to boot it.
+    Verified,
+    /// the thing has booted the new firmware.
+    Running,
+    Failed,
+    /// the new firmware can't boot, the previous one is running.
+    RolledBack,
+}
+
+impl FromStr for ReportState {
+    type Err = NearError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "receiving" => Ok(Self::Receiving),
+            "verified" => Ok(Self::Verified),
+            "running" => Ok(Self::Running),
+            "failed" => Ok(Self::Failed),
+            "rolled_back" => Ok(Self::RolledBack),
+            _ => Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("unknown ota state [{s}]"))),
+        }
+    }
+}
+
+/// What the thing reports about the upgrade.
+#[derive(Clone)]
+pub(crate) struct OtaReport {
+    pub(crate) state: ReportState,
+    pub(crate) offset: u64,
+    pub(crate) version: String,
+    pub(crate) error: String,
+}
+
+impl TryFrom<&Data> for OtaReport {
+    type Error = NearError;
+
+    fn try_from(data: &Data) -> Result<Self, Self::Error> {
+        let state =
+            data.get(OTA_STATE)
+                .ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, "missing ota state"))?
+                .parse()?;
+
+        let offset =
+            match data.get(OTA_OFFSET) {
+                Some(offset) =>
+                    offset.parse::<u64>()
+                        .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("invalid ota offset [{offset}] with err: {e}")))?,
+                None => 0,
+            };
+
+        Ok(Self {
+            state,
+            offset,
+            version: data.get(OTA_VERSION).unwrap_or_default(),
+            error: data.get(OTA_ERROR).unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ota_report() {
+        let data = Data::default();
+        assert!(OtaReport::try_from(&data).is_err());
+
+        data.set(OTA_STATE.to_owned(), "receiving".to_owned());
+        data.set(OTA_OFFSET.to_owned(), "240".to_owned());
+        let report = OtaReport::try_from(&data).unwrap();
+        assert_eq!(report.state, ReportState::Receiving);
+        assert_eq!(report.offset, 240);
+
+        data.set(OTA_STATE.to_owned(), "rolled_back".to_owned());
+        data.set(OTA_VERSION.to_owned(), "1.0.2".to_owned());
+        let report = OtaReport::try_from(&data).unwrap();
+        assert_eq!(report.state, ReportState::RolledBack);
+        assert_eq!(report.version, "1.0.2");
+
+        data.set(OTA_OFFSET.to_owned(), "x".to_owned());
+        assert!(OtaReport::try_from(&data).is_err());
+    }
+}
@@ -0,0 +1,277 @@
+
+use std::{sync::{Arc, Mutex, atomic::{AtomicBool, Ordering}}, collections::HashMap, time::{Duration, Instant}};
+
+use log::{info, error, debug};
+
+use near_base::{NearResult, NearError, ErrorCode, StateWaiter, now};
+
+use base::Version;
+use protos::hci::firmware::{Firmware_info, Ota_status, Ota_state};
+
+use crate::{process::Process, cache::thing_components::ThingComponentPtr, tasks::{TaskModule, TaskData}};
+
+use super::{Config, OtaReport, ReportState, OTA_VERSION, OTA_SIZE, OTA_HASH, OTA_OFFSET, OTA_CHUNK};
+
+/// "v1.2" is the same as "1.2.0", the one which can't be parsed is compared as it is.
+fn is_same_version(l: &str, r: &str) -> bool {
+    match (l.parse::<Version>(), r.parse::<Version>()) {
+        (Ok(l), Ok(r)) => l == r,
+        _ => l.trim() == r.trim(),
+    }
+}
+
+struct SessionState {
+    status: Ota_status,
+    report: Option<OtaReport>,
+    waiter: StateWaiter,
+}
+
+struct SessionImpl {
+    process: Process,
+    config: Config,
+    thing: ThingComponentPtr,
+    firmware: Firmware_info,
+    canceled: AtomicBool,
+    state: Mutex<SessionState>,
+}
+
+/// The upgrade of a thing, the firmware is pushed chunk by chunk and each one is confirmed by the thing.
+#[derive(Clone)]
+pub(super) struct Session(Arc<SessionImpl>);
+
+impl Session {
+    pub(super) fn new(process: Process, config: Config, thing: ThingComponentPtr, firmware: Firmware_info) -> Self {
+        let status = Ota_status {
+            thing_id: thing.thing().object_id().to_string(),
+            firmware_id: firmware.firmware_id.clone(),
+            version: firmware.version.clone(),
+            state: Ota_state::Pending.into(),
+            total: firmware.size,
+            update_timestamp: now(),
+            ..Default::default()
+        };
+
+        Self(Arc::new(SessionImpl {
+            process,
+            config,
+            thing,
+            firmware,
+            canceled: AtomicBool::new(false),
+            state: Mutex::new(SessionState {
+                status,
+                report: None,
+                waiter: StateWaiter::new(),
+            }),
+        }))
+    }
+
+    pub(super) fn status(&self) -> Ota_status {
+        self.0.state.lock().unwrap().status.clone()
+    }
+
+    pub(super) fn is_finished(&self) -> bool {
+        match self.status().state() {
+            Ota_state::Pending | Ota_state::Transferring | Ota_state::Verifying => false,
+            _ => true,
+        }
+    }
+
+    pub(super) fn cancel(&self) {
+        self.0.canceled.store(true, Ordering::SeqCst);
+        self.wake();
+    }
+
+    pub(super) fn on_report(&self, report: OtaReport) {
+        debug!("ota report of {}: state: {:?}, offset: {}, version: {}", self.0.thing.thing().object_id(), report.state, report.offset, report.version);
+
+        // the running version is updated only when the thing confirmed the upgrade or the rollback.
+        self.0.state.lock().unwrap().report = Some(report);
+
+        self.wake();
+    }
+
+    pub(super) async fn run(&self, content: Arc<Vec<u8>>) {
+        let thing_id = self.0.thing.thing().object_id().clone();
+
+        match self.transfer(content.as_slice()).await {
+            Ok(state) => {
+                info!("ota of {thing_id} to [{}] is {:?}", self.0.firmware.version, state);
+                self.finish(state, String::default());
+            }
+            Err(e) => {
+                // the thing keeps running the previous firmware.
+                let _ = self.send(TaskModule::OtaAbort, HashMap::new()).await;
+
+                if self.is_canceled() {
+                    info!("ota of {thing_id} is canceled");
+                    self.finish(Ota_state::Canceled, String::default());
+                } else {
+                    error!("failed ota of {thing_id} with err: {e}");
+                    self.finish(Ota_state::Failed, e.to_string());
+                }
+            }
+        }
+    }
+}
+
+impl Session {
+    async fn transfer(&self, content: &[u8]) -> NearResult<Ota_state> {
+        let size = content.len() as u64;
+        let chunk_size = std::cmp::max(self.0.config.chunk_size, 1) as u64;
+
+        self.update(| status | status.state = Ota_state::Transferring.into());
+
+        // the thing could resume from what it has received.
+        let mut offset =
+            self.request(TaskModule::OtaBegin, self.params(), self.0.config.ack_timeout, | report | report.state == ReportState::Receiving)
+                .await?
+                .offset;
+
+        while offset < size {
+            let end = std::cmp::min(offset + chunk_size, size);
+
+            let params = {
+                let mut params = self.params();
+                params.insert(OTA_OFFSET.to_owned(), offset.to_string());
+                params.insert(OTA_CHUNK.to_owned(), hex::encode_upper(&content[offset as usize..end as usize]));
+                params
+            };
+
+            let expected = offset;
+            offset =
+                self.request(TaskModule::OtaChunk, params, self.0.config.ack_timeout, | report | report.state == ReportState::Receiving && report.offset > expected)
+                    .await?
+                    .offset
+                    .min(size);
+
+            self.update(| status | status.acked = offset);
+        }
+
+        self.update(| status | status.state = Ota_state::Verifying.into());
+
+        let report =
+            self.request(TaskModule::OtaEnd, self.params(), self.0.config.verify_timeout, | report | {
+                matches!(report.state, ReportState::Running | ReportState::RolledBack)
+            })
+            .await?;
+
+        match report.state {
+            ReportState::Running if report.version.is_empty() || is_same_version(&report.version, &self.0.firmware.version) => {
+                self.update(| status | status.running_version = self.0.firmware.version.clone());
+                Ok(Ota_state::Succeeded)
+            }
+            _ => {
+                if !report.version.is_empty() {
+                    self.update(| status | status.running_version = report.version.clone());
+                }
+                Ok(Ota_state::RolledBack)
+            }
+        }
+    }
+
+    fn params(&self) -> HashMap<String, String> {
+        HashMap::from([
+            (OTA_VERSION.to_owned(), self.0.firmware.version.clone()),
+            (OTA_SIZE.to_owned(), self.0.firmware.size.to_string()),
+            (OTA_HASH.to_owned(), self.0.firmware.hash.clone()),
+        ])
+    }
+
+    async fn send(&self, task_module: TaskModule, params: HashMap<String, String>) -> NearResult<()> {
+        let mut task_data = TaskData::from((task_module, self.0.thing.thing()));
+        task_data.params.extend(params);
+
+        self.0.process
+            .task_manager()
+            .add_task(task_data)
+            .await
+            .map(| _ | ())
+    }
+
+    /// Advertise until the thing reports what is expected.
+    async fn request<F>(&self, task_module: TaskModule, params: HashMap<String, String>, timeout: Duration, expected: F) -> NearResult<OtaReport>
+    where F: Fn(&OtaReport) -> bool {
+        for _ in 0..std::cmp::max(self.0.config.retry_times, 1) {
+            self.check_canceled()?;
+
+            self.0.state.lock().unwrap().report = None;
+            self.send(task_module.clone(), params.clone()).await?;
+
+            if let Some(report) = self.wait_report(timeout, &expected).await? {
+                return Ok(report);
+            }
+        }
+
+        Err(NearError::new(ErrorCode::NEAR_ERROR_TIMEOUT, format!("the thing didn't answer {}", task_module.to_str())))
+    }
+
+    async fn wait_report<F>(&self, timeout: Duration, expected: &F) -> NearResult<Option<OtaReport>>
+    where F: Fn(&OtaReport) -> bool {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let waiter = {
+                let state = &mut *self.0.state.lock().unwrap();
+
+                self.check_canceled()?;
+
+                if let Some(report) = state.report.as_ref() {
+                    if report.state == ReportState::Failed {
+                        return Err(NearError::new(ErrorCode::NEAR_ERROR_REFUSE, format!("the thing failed the ota with err: {}", report.error)));
+                    }
+
+                    if expected(report) {
+                        return Ok(Some(report.clone()));
+                    }
+                }
+
+                state.waiter.new_waiter()
+            };
+
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(None);
+            }
+
+            let _ = async_std::future::timeout(deadline - now, StateWaiter::wait(waiter, || ())).await;
+        }
+    }
+
+    fn is_canceled(&self) -> bool {
+        self.0.canceled.load(Ordering::SeqCst)
+    }
+
+    fn check_canceled(&self) -> NearResult<()> {
+        if self.is_canceled() {
+            Err(NearError::new(ErrorCode::NEAR_ERROR_STATE, "the ota has been canceled"))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn update<F: FnOnce(&mut Ota_status)>(&self, f: F) {
+        let status = &mut self.0.state.lock().unwrap().status;
+        f(status);
+        status.update_timestamp = now();
+    }
+
+    pub(super) fn finish(&self, state: Ota_state, error: String) {
+        self.update(| status | {
+            status.state = state.into();
+            status.error = error;
+        });
+    }
+
+    fn wake(&self) {
+        let waiter = self.0.state.lock().unwrap().waiter.transfer();
+        waiter.wake();
+    }
+}
+
+#[test]
+fn test_same_version() {
+    assert!(is_same_version("v1.2", "1.2.0"));
+    assert!(is_same_version("1.0.2-beta", "V1.0.2-beta"));
+    assert!(!is_same_version("1.0.2-beta", "1.0.2"));
+    assert!(is_same_version(" build-7 ", "build-7"));
+}
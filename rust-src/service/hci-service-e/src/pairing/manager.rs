@@ -0,0 +1,209 @@
+
+use std::{sync::{Arc, Mutex}, collections::{HashMap, hash_map::Entry}, path::Path, time::Instant};
+
+use log::{trace, info, warn};
+use mac_address::MacAddress;
+
+use near_base::{NearResult, NearError, ErrorCode, ObjectId, PrivateKey, PublicKey, StateWaiter, RawConvertTo,
+                load_private_key, save_private_key, now};
+use near_util::{ThingBuilder, KEY_PASSPHRASE_ENV};
+
+use common::RuntimeStack;
+use proof::proof_data::ProofDataSet;
+use protos::proof::pairing::Proof_of_pairing;
+
+use crate::{process::Process, lua::data::Data, tasks::{TaskData, TaskModule}, MAC_ADDRESS};
+
+use super::{Config, PAIRING_NONCE, PAIRING_ANSWER, verify_answer};
+
+struct Challenge {
+    nonce: String,
+    answer: Option<String>,
+    waiter: StateWaiter,
+}
+
+struct ManagerImpl {
+    process: Process,
+    config: Config,
+    private_key: PrivateKey,
+    public_key: PublicKey,
+    // the pending challenge of each thing
+    challenges: Mutex<HashMap<MacAddress, Challenge>>,
+}
+
+/// The thing proves it's present by signing the fresh nonce of the core with the key of its brand,
+/// the signature is checked here, then the proof is signed by the pairing key and checked by hci-storage.
+/// The brand is challenged only if it's configured with its key.
+#[derive(Clone)]
+pub struct Manager(Arc<ManagerImpl>);
+
+// the pairing key is created at the first time, it's encrypted if the passphrase is set.
+fn open_pairing_key(key_file: &Path) -> NearResult<PrivateKey> {
+    let passphrase = std::env::var(KEY_PASSPHRASE_ENV).ok();
+
+    if key_file.exists() {
+        load_private_key(key_file, passphrase.as_deref())
+    } else {
+        let private_key = PrivateKey::generate_rsa1024()?;
+        save_private_key(key_file, &private_key, passphrase.as_deref())?;
+        info!("create the pairing key: {}", key_file.display());
+        Ok(private_key)
+    }
+}
+
+impl Manager {
+    pub fn open(process: Process, config: Config, key_file: &Path) -> NearResult<Self> {
+        let private_key = open_pairing_key(key_file)?;
+
+        Ok(Self(Arc::new(ManagerImpl {
+            process,
+            config,
+            public_key: private_key.public(),
+            private_key,
+            challenges: Mutex::new(HashMap::new()),
+        })))
+    }
+
+    #[inline]
+    pub fn public_key(&self) -> &PublicKey {
+        &self.0.public_key
+    }
+
+    /// Challenge the thing with a fresh nonce, the encoded proof binds the thing, the core and the owner.
+    /// It's empty if the brand isn't configured, so the thing is added only by the owner override.
+    pub async fn attest(&self, brand_id: &str, mac: MacAddress, thing_data: HashMap<String, String>, owner: &ObjectId) -> NearResult<Vec<u8>> {
+        trace!("attest {mac} of [{brand_id}] for {owner}");
+
+        let brand_key =
+            match self.0.config.brands.get(brand_id) {
+                Some(brand_key) => brand_key.clone(),
+                None => {
+                    warn!("[{brand_id}] isn't configured to challenge {mac}, it's added only by the owner override");
+                    return Ok(vec![]);
+                }
+            };
+
+        let core = RuntimeStack::get_instance().stack().core_device().object_id().clone();
+
+        // it's the same desc as the one built by hci-storage, so they have the same object id.
+        let thing =
+            ThingBuilder::new()
+                .owner(Some(&core))
+                .mac_address(mac.bytes())
+                .owner_depend_id(brand_id.to_owned())
+                .build()?;
+
+        let nonce = hex::encode_upper(rand::random::<[u8; 8]>());
+
+        match self.0.challenges.lock().unwrap().entry(mac) {
+            Entry::Occupied(_) => {
+                return Err(NearError::new(ErrorCode::NEAR_ERROR_ACTIVED, format!("{mac} is pairing")));
+            }
+            Entry::Vacant(empty) => {
+                empty.insert(Challenge {
+                    nonce: nonce.clone(),
+                    answer: None,
+                    waiter: StateWaiter::new(),
+                });
+            }
+        }
+
+        let r = self.challenge(brand_id, mac, thing_data, &nonce).await;
+        self.0.challenges.lock().unwrap().remove(&mac);
+        let answer = r?;
+
+        verify_answer(&brand_key, &mac, &nonce, &answer)?;
+
+        info!("{mac} signed the pairing challenge for {owner}");
+
+        let proof_of_pairing = Proof_of_pairing {
+            thing_id: thing.object_id().to_string(),
+            core_id: core.to_string(),
+            owner_id: owner.to_string(),
+            nonce,
+            answer,
+            pair_time: now(),
+            ..Default::default()
+        };
+
+        ProofDataSet::with_pairing(proof_of_pairing, thing, self.0.private_key.clone())
+            .await?
+            .to_vec()
+    }
+
+    pub fn on_report(&self, mac: MacAddress, data: Data) {
+        let waiter = {
+            let challenges = &mut *self.0.challenges.lock().unwrap();
+
+            let challenge = match challenges.get_mut(&mac) {
+                Some(challenge) => challenge,
+                None => return,
+            };
+
+            // the answer of the previous challenge is ignored.
+            if data.get(PAIRING_NONCE).as_deref() != Some(challenge.nonce.as_str()) {
+                warn!("{mac} answered an unknown nonce");
+                return;
+            }
+
+            challenge.answer = Some(data.get(PAIRING_ANSWER).unwrap_or_default());
+            challenge.waiter.transfer()
+        };
+
+        waiter.wake();
+    }
+}
+
+impl Manager {
+    /// Advertise the challenge until the thing answers.
+    async fn challenge(&self, brand_id: &str, mac: MacAddress, thing_data: HashMap<String, String>, nonce: &str) -> NearResult<String> {
+        let task_data = {
+            let mut params = thing_data;
+            params.insert(MAC_ADDRESS.to_owned(), mac.to_string());
+            params.insert(PAIRING_NONCE.to_owned(), nonce.to_owned());
+
+            TaskData {
+                task_module: TaskModule::PairingChallenge,
+                module_id: brand_id.to_owned(),
+                params,
+            }
+        };
+
+        for _ in 0..std::cmp::max(self.0.config.retry_times, 1) {
+            self.0.process
+                .task_manager()
+                .add_task(task_data.clone())
+                .await?;
+
+            if let Some(answer) = self.wait_answer(&mac).await {
+                return Ok(answer);
+            }
+        }
+
+        Err(NearError::new(ErrorCode::NEAR_ERROR_TIMEOUT, format!("{mac} didn't answer the pairing challenge")))
+    }
+
+    async fn wait_answer(&self, mac: &MacAddress) -> Option<String> {
+        let deadline = Instant::now() + self.0.config.answer_timeout;
+
+        loop {
+            let waiter = {
+                let challenges = &mut *self.0.challenges.lock().unwrap();
+                let challenge = challenges.get_mut(mac)?;
+
+                if let Some(answer) = challenge.answer.as_ref() {
+                    return Some(answer.clone());
+                }
+
+                challenge.waiter.new_waiter()
+            };
+
+            let now = Instant::now();
+            if now >= deadline {
+                return None;
+            }
+
+            let _ = async_std::future::timeout(deadline - now, StateWaiter::wait(waiter, || ())).await;
+        }
+    }
+}
@@ -0,0 +1,70 @@
+pub mod manager;
+
+use std::{collections::HashMap, time::Duration};
+
+use mac_address::MacAddress;
+
+use near_base::{NearResult, NearError, ErrorCode, PublicKey, Signature, Deserialize};
+
+// the params of the pairing function of the brand lua.
+pub const PAIRING_NONCE: &'static str  = "pairing_nonce";
+// the hex of the signature which the thing answered, it's analyzed by the brand lua.
+pub const PAIRING_ANSWER: &'static str = "pairing_answer";
+
+pub(crate) const PAIRING_KEY_FILE: &'static str = "pairing.key";
+
+#[derive(Clone)]
+pub struct Config {
+    /// How long the answer of the thing is waited for after each advertising.
+    pub answer_timeout: Duration,
+    /// How many times the challenge is repeated before the pairing is failed.
+    pub retry_times: u32,
+    /// The brands whose things sign the challenge, and the public key which the signature is checked by.
+    /// Only the brand which documents its challenge protocol is configured, the things of the others are added by the owner override.
+    pub brands: HashMap<String, PublicKey>,
+}
+
+impl std::default::Default for Config {
+    fn default() -> Self {
+        Self {
+            answer_timeout: Duration::from_secs(2),
+            retry_times: 5,
+            brands: HashMap::new(),
+        }
+    }
+}
+
+// the mac is signed with the nonce, so the answer of a thing can't be taken by another one.
+fn answer_buffer(mac: &MacAddress, nonce: &str) -> Vec<u8> {
+    [mac.bytes().as_slice(), nonce.as_bytes()].concat()
+}
+
+/// The answer is the signature of the thing, it's checked by the public key of its brand.
+pub(crate) fn verify_answer(brand_key: &PublicKey, mac: &MacAddress, nonce: &str, answer: &str) -> NearResult<()> {
+    let signature =
+        hex::decode(answer)
+            .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, format!("{mac} answered an invalid signature with err: {e}")))?;
+    let (signature, _) = Signature::deserialize(&signature)?;
+
+    brand_key.verify(&answer_buffer(mac, nonce), &signature)
+        .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_CRYPTO_VERIFY, format!("{mac} didn't sign the pairing challenge with err: {e}")))
+}
+
+#[test]
+fn test_verify_answer() {
+    use near_base::{PrivateKey, RawConvertTo};
+
+    let thing_key = PrivateKey::generate_rsa1024().unwrap();
+    let mac = MacAddress::new([1, 2, 3, 4, 5, 6]);
+    let nonce = "0011223344556677";
+
+    let answer = hex::encode(thing_key.sign(&answer_buffer(&mac, nonce)).unwrap().to_vec().unwrap());
+    verify_answer(&thing_key.public(), &mac, nonce, &answer).unwrap();
+
+    // another nonce, another thing or another brand
+    assert!(verify_answer(&thing_key.public(), &mac, "7766554433221100", &answer).unwrap_err().errno() == ErrorCode::NEAR_ERROR_CRYPTO_VERIFY);
+    assert!(verify_answer(&thing_key.public(), &MacAddress::new([6, 5, 4, 3, 2, 1]), nonce, &answer).unwrap_err().errno() == ErrorCode::NEAR_ERROR_CRYPTO_VERIFY);
+    assert!(verify_answer(&PrivateKey::generate_rsa1024().unwrap().public(), &mac, nonce, &answer).unwrap_err().errno() == ErrorCode::NEAR_ERROR_CRYPTO_VERIFY);
+    // it isn't a signature
+    assert!(verify_answer(&thing_key.public(), &mac, nonce, "0a0b0c0d").is_err());
+}
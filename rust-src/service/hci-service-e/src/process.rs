@@ -5,14 +5,19 @@ use log::{trace, error};
 
 use near_base::thing::ThingObject;
 use near_core::get_app_path;
-use near_base::{NearResult, NearError, ErrorCode};
+use near_base::{NearResult, NearError, ErrorCode, ObjectId, builder_codec_macro::Empty};
 
 use common::{RuntimeProcessTrait, RuntimeStack, RoutineTemplate};
-use protos::hci::thing::{Thing_query_all, Thing_info_list};
+use nds::{NdsStack, NdsConfig};
+use protos::hci::thing::{Thing_query_all, Thing_info_list, Thing_info};
+use protos::hci::telemetry::Thing_telemetry_sample;
 use topic_util::topics::hci_service::*;
-use topic_util::topics::hci_storage::{NEAR_THING_STORAGE_MULITPLE_THINGOBJECT_QUERY_PUB, NEAR_THING_STORAGE_THING_QUERY_ALL_PUB};
+use topic_util::topics::hci_storage::{NEAR_THING_STORAGE_MULITPLE_THINGOBJECT_QUERY_PUB, NEAR_THING_STORAGE_THING_QUERY_ALL_PUB, NEAR_THING_STORAGE_TELEMETRY_APPEND_PUB};
 
 use crate::cache::thing_components::ThingCollect;
+use crate::cache::availability::Config as AvailabilityConfig;
+use crate::ota::{manager::Manager as OtaManager, Config as OtaConfig};
+use crate::pairing::{manager::Manager as PairingManager, Config as PairingConfig, PAIRING_KEY_FILE};
 use crate::hci::scanning::ScanProcessorEventTrait;
 use crate::routines::add_thing_task::AddThingTaskRoutine;
 use crate::routines::query_all_thing_task::QueryAllThingTaskRoutine;
@@ -23,12 +28,15 @@ use crate::routines::search_task::SearchRoutine;
 use crate::routines::get_task_result::GetTaskResultRoutine;
 use crate::routines::crud_thing_task::CrudThingTaskRoutine;
 use crate::routines::ctrl_thing_task::ControlThingTaskRoutine;
+use crate::routines::ota::{start::StartOtaRoutine, query::QueryOtaRoutine, cancel::CancelOtaRoutine};
+use crate::routines::pairing::{attest::AttestPairingRoutine, key::PairingKeyRoutine};
 use crate::routines::Config as RoutinesConfig;
 use crate::lua::{manager::Manager as LuaManager, configure::ConfigureData};
 use crate::tasks::{manager::{Manager as TaskManager, Config as TaskConfig},
                    cb::TaskManagerCb, 
                    TaskCbTrait, 
                    TaskModule, 
+                   TaskData,
                    TaskCbData};
 use crate::hci::{scanning::ScanProcessor, advertising::AdvertisingProcessor};
 
@@ -38,6 +46,9 @@ pub struct Config {
     pub task_config: TaskConfig,
     pub ctrl_task_config: TaskConfig,
     pub routines_config: RoutinesConfig,
+    pub availability_config: AvailabilityConfig,
+    pub ota_config: OtaConfig,
+    pub pairing_config: PairingConfig,
 }
 
 struct ProcessComponents {
@@ -45,6 +56,10 @@ struct ProcessComponents {
     lua_manager: LuaManager,
     task_manager_cb: TaskManagerCb,
     things_components: ThingCollect,
+    ota_manager: OtaManager,
+    pairing_manager: PairingManager,
+    // it's opened when the runtime is running.
+    nds_stack: once_cell::sync::OnceCell<NdsStack>,
 }
 
 struct ProcessImpl {
@@ -65,6 +80,9 @@ impl Process {
     }
 
     pub async fn new(service_name: &str, config: Option<Config>) -> Self {
+        let (routines_config, availability_config, ota_config, pairing_config) = 
+            config.map(| cfg | (cfg.routines_config, cfg.availability_config, cfg.ota_config, cfg.pairing_config)).unwrap_or_default();
+
         let ret = Self(Arc::new(ProcessImpl{
             service_name: service_name.to_owned(),
             config: Config {
@@ -78,7 +96,10 @@ impl Process {
                     interval: std::time::Duration::from_micros(100),
                     ..Default::default()
                 },
-                routines_config: config.map(| routine_cfg | routine_cfg.routines_config).unwrap_or_default(),
+                routines_config,
+                availability_config,
+                ota_config,
+                pairing_config,
             },
             components: None,
         }));
@@ -87,6 +108,12 @@ impl Process {
         let lua_manager = LuaManager::open(ret.0.config.work_path.join("lua")).await.expect("init lua manager");
         let task_manager_cb = TaskManagerCb::new(ret.clone(), Box::new(ret.clone()) as Box<dyn TaskCbTrait>);
         let task_manager = TaskManager::start(ret.clone()).expect("init task manager.");
+        let ota_manager = OtaManager::new(ret.clone(), ret.0.config.ota_config.clone());
+        let pairing_manager = 
+            PairingManager::open(ret.clone(), 
+                                 ret.0.config.pairing_config.clone(), 
+                                 ret.0.config.work_path.join(PAIRING_KEY_FILE).as_path())
+                .expect("init pairing manager.");
 
         {
             let mut_ret = unsafe { &mut *(Arc::as_ptr(&ret.0) as *mut ProcessImpl) };
@@ -96,6 +123,9 @@ impl Process {
                     task_manager: task_manager.clone(),
                     task_manager_cb,
                     things_components: Default::default(),
+                    ota_manager,
+                    pairing_manager,
+                    nds_stack: once_cell::sync::OnceCell::new(),
                     // schedule_manager: ScheduleManager::new(ret.clone()),
                 });
         }
@@ -140,6 +170,21 @@ impl Process {
     pub fn thing_components(&self) -> &ThingCollect {
         &self.0.components.as_ref().unwrap().things_components
     }
+
+    #[inline]
+    pub fn ota_manager(&self) -> &OtaManager {
+        &self.0.components.as_ref().unwrap().ota_manager
+    }
+
+    #[inline]
+    pub fn pairing_manager(&self) -> &PairingManager {
+        &self.0.components.as_ref().unwrap().pairing_manager
+    }
+
+    #[inline]
+    pub fn nds_stack(&self) -> &NdsStack {
+        self.0.components.as_ref().unwrap().nds_stack.get().expect("nds stack must open.")
+    }
 }
 
 unsafe impl Sync for Process {}
@@ -289,10 +334,70 @@ impl Process {
                 )?;
         }
 
+        {
+            // start ota
+            let arc_self = self.clone();
+
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_private_topic(
+                    NEAR_THING_SERVICE_OTA_START_PUB.topic(),
+                    move || Ok(StartOtaRoutine::new(arc_self.clone()))
+                )?;
+        }
+
+        {
+            // query ota
+            let arc_self = self.clone();
+
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_private_topic(
+                    NEAR_THING_SERVICE_OTA_QUERY_PUB.topic(),
+                    move || Ok(QueryOtaRoutine::new(arc_self.clone()))
+                )?;
+        }
+
+        {
+            // cancel ota
+            let arc_self = self.clone();
+
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_private_topic(
+                    NEAR_THING_SERVICE_OTA_CANCEL_PUB.topic(),
+                    move || Ok(CancelOtaRoutine::new(arc_self.clone()))
+                )?;
+        }
+
+        {
+            // challenge the thing before it's added
+            let arc_self = self.clone();
+
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_private_topic(
+                    NEAR_THING_SERVICE_PAIRING_PUB.topic(),
+                    move || Ok(AttestPairingRoutine::new(arc_self.clone()))
+                )?;
+        }
+
+        {
+            // the key of the pairing proofs
+            let arc_self = self.clone();
+
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_private_topic(
+                    NEAR_THING_SERVICE_PAIRING_KEY_PUB.topic(),
+                    move || Ok(PairingKeyRoutine::new(arc_self.clone()))
+                )?;
+        }
+
         Ok(())
     }
 
-    async fn sync_thing(&self) -> NearResult<Vec<ThingObject>> {
+    async fn sync_thing(&self) -> NearResult<Vec<(ThingObject, Thing_info)>> {
         let routine = 
             RoutineTemplate::<Thing_info_list>::call(
                 NEAR_THING_STORAGE_THING_QUERY_ALL_PUB.topic().clone(),
@@ -304,17 +409,13 @@ impl Process {
                 e
             })?;
 
-        let thing_ids: Vec<String> = 
+        let things: Vec<Thing_info> = 
         async_std::future::timeout(std::time::Duration::from_secs(5), routine)
             .await
             .map_err(| _e |{
                 NearError::new(ErrorCode::NEAR_ERROR_TIMEOUT, "timeout")
             })?
-            .map(| v | {
-                v.things.into_iter()
-                    .map(| thing | thing.thing_id)
-                    .collect()
-            })
+            .map(| v | v.things)
             .map_err(| e | {
                 error!("{e}");
                 e
@@ -322,7 +423,7 @@ impl Process {
 
         RoutineTemplate::<Vec<Option<ThingObject>>>::call(
             NEAR_THING_STORAGE_MULITPLE_THINGOBJECT_QUERY_PUB.topic().clone(),
-            thing_ids
+            things.iter().map(| thing | thing.thing_id().to_owned()).collect::<Vec<String>>()
         )
         .await
         .map_err(| e | {
@@ -332,17 +433,42 @@ impl Process {
         .await
         .map(| v | {
             v.into_iter()
-                 .filter(| it | {
-                    it.is_some()
-                 })
-                 .map(| it | {
-                    it.unwrap()
+                 .zip(things.into_iter())
+                 .filter_map(| (it, thing) | {
+                    it.map(| it | (it, thing))
                  })
                  .collect()
         })
 
     }
 
+    async fn report_telemetry(&self, mac: [u8; 6], data: std::collections::HashMap<String, String>) {
+        if data.is_empty() {
+            return;
+        }
+
+        let thing_id = 
+            match self.thing_components().get_thing_by_mac(mac) {
+                Ok(thing) => thing.thing().object_id().to_string(),
+                Err(_) => return,
+            };
+
+        // don't wait the response, the history is best effort.
+        if let Err(e) = 
+            RoutineTemplate::<Empty>::call(
+                NEAR_THING_STORAGE_TELEMETRY_APPEND_PUB.topic().clone(),
+                Thing_telemetry_sample {
+                    thing_id,
+                    timestamp: near_base::now(),
+                    data,
+                    ..Default::default()
+                }
+            )
+            .await {
+            error!("failed call {} with err: {e}", NEAR_THING_STORAGE_TELEMETRY_APPEND_PUB.topic());
+        }
+    }
+
     async fn init_thing_components(&self) -> NearResult<()> {
         let things = loop {
             match self.sync_thing().await {
@@ -357,11 +483,60 @@ impl Process {
         };
     
         if let Some(things) = things {
-            self.thing_components().add_things(things.into_iter());
+            let products: Vec<(ObjectId, String, String)> = 
+                things.iter()
+                    .map(| (thing_object, thing) | {
+                        (thing_object.object_id().clone(), thing.minor_product_id().to_owned(), thing.major_product_id().to_owned())
+                    })
+                    .collect();
+
+            self.thing_components().add_things(things.into_iter().map(| (thing_object, _) | thing_object));
+            self.thing_components().set_products(products.into_iter());
         }
 
         Ok(())
     }
+
+    fn open_nds(&self) -> NearResult<()> {
+        // the firmwares are downloaded from the core.
+        let nds_stack = 
+            NdsStack::open(self.service_name().to_owned(), 
+                           RuntimeStack::get_instance().clone(), 
+                           NdsConfig {
+                                data_path: self.config().work_path.join("nds"),
+                                dir_path: self.config().work_path.join("nds").join("dirs"),
+                                ..Default::default()
+                           })?;
+
+        nds_stack.register_topic();
+
+        self.0.components.as_ref().unwrap()
+            .nds_stack
+            .set(nds_stack)
+            .map_err(| _ | NearError::new(ErrorCode::NEAR_ERROR_ACTIVED, "nds stack has been opened."))
+    }
+
+    fn start_availability_check(&self) {
+        let arc_self = self.clone();
+
+        async_std::task::spawn(async move {
+            let config = &arc_self.config().availability_config;
+
+            loop {
+                let _ = async_std::future::timeout(config.check_interval, async_std::future::pending::<()>()).await;
+
+                // probe the silent things before declare them offline.
+                for thing in arc_self.thing_components().check_availability(config) {
+                    if let Err(e) = 
+                        arc_self.task_manager()
+                            .add_task(TaskData::from((TaskModule::QueryThing, thing.thing())))
+                            .await {
+                        error!("failed probe [{}] with err: {e}", thing.thing().object_id());
+                    }
+                }
+            }
+        });
+    }
 }
 
 #[async_trait::async_trait]
@@ -373,10 +548,14 @@ impl RuntimeProcessTrait for Process {
         // start scanning
         ScanProcessor::get_instance().active(std::time::Duration::ZERO, self.task_manager_cb().clone_as_event()).await?;
 
+        self.open_nds()?;
+
         self.subscribe_message().await?;
 
         self.init_thing_components().await?;
 
+        self.start_availability_check();
+
         // self.schedule_manager().start();
 
         Ok(())
@@ -400,7 +579,21 @@ impl TaskCbTrait for Process {
     async fn on_taskcb(&self, task_module: TaskModule, data: TaskCbData) {
         match task_module {
             TaskModule::QueryThing => {
-                self.thing_components().on_taskcb(task_module, data).await
+                let (mac, data) = data.split();
+
+                self.report_telemetry(mac.bytes(), data.clone_map()).await;
+
+                self.thing_components().on_taskcb(task_module, (mac, data).into()).await
+            }
+            TaskModule::OtaStatus => {
+                let (mac, data) = data.split();
+
+                self.ota_manager().on_report(mac, data)
+            }
+            TaskModule::PairingChallenge => {
+                let (mac, data) = data.split();
+
+                self.pairing_manager().on_report(mac, data)
             }
             TaskModule::Search => unreachable!(),
             _ => { todo!() }
@@ -5,12 +5,12 @@ use near_base::{ObjectId, NearResult, NearError, ErrorCode};
 use near_transport::{RoutineEventTrait, RoutineWrap, Routine, HeaderMeta, EventResult};
 
 use base::raw_object::RawObjectGuard;
-use protos::{DataContent, hci::hci_thing::{Hci_thing_list, Hci_thing, hci_thing::Hci_thing_status}, try_encode_raw_object, };
+use protos::{DataContent, hci::hci_thing::{Hci_thing_list, Hci_thing, Hci_thing_availability, hci_thing::Hci_thing_status}, try_encode_raw_object, };
 use topic_util::types::hci_types::HciTaskId;
 
 use crate::{process::Process, 
             tasks::{TaskModule, result::search_result::SeachEventResult}, 
-            cache::ThingStatus};
+            cache::{ThingStatus, availability::Availability}};
 
 pub struct GetTaskResultRoutine {
     process: Process,
@@ -120,16 +120,33 @@ impl GetTaskResultRoutine {
         let mut thing_list = Hci_thing_list::new();
 
         for thing_id in thing_ids {
-            let (status, data, mac) = 
+            let (status, data, mac, last_seen, availabilities) = 
             if let Some(thing) = get_thing(&thing_id) {
                 let mac = mac_address::MacAddress::from(thing.thing().desc().content().mac_address().clone());
+                let tracker = thing.tracker();
+                let last_seen = tracker.last_seen();
+                let availabilities = 
+                    tracker
+                        .transitions()
+                        .map(| (timestamp, availability) | {
+                            Hci_thing_availability {
+                                timestamp: *timestamp,
+                                status: match availability {
+                                    Availability::Online => Hci_thing_status::Online,
+                                    Availability::Offline => Hci_thing_status::Offline,
+                                }.into(),
+                                ..Default::default()
+                            }
+                        })
+                        .collect();
+
                 match thing.status() {
-                    ThingStatus::Disable => (Hci_thing_status::Disabled, Default::default(), mac),
-                    ThingStatus::Offline(_, data) => (Hci_thing_status::Offline, data.clone_map(), mac),
-                    ThingStatus::Online(_, data) => (Hci_thing_status::Online, data.clone_map(), mac),
+                    ThingStatus::Disable => (Hci_thing_status::Disabled, Default::default(), mac, last_seen, availabilities),
+                    ThingStatus::Offline(_, data) => (Hci_thing_status::Offline, data.clone_map(), mac, last_seen, availabilities),
+                    ThingStatus::Online(_, data) => (Hci_thing_status::Online, data.clone_map(), mac, last_seen, availabilities),
                 }
             } else {
-                (Hci_thing_status::NotFound, Default::default(), Default::default())
+                (Hci_thing_status::NotFound, Default::default(), Default::default(), 0, vec![])
             };
 
             thing_list.mut_list().push(
@@ -138,6 +155,8 @@ impl GetTaskResultRoutine {
                     mac_address: mac.to_string(),
                     status: status.into(),
                     data,
+                    last_seen,
+                    availabilities,
                     ..Default::default()
                 });
         }
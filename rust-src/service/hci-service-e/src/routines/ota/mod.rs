@@ -0,0 +1,4 @@
+
+pub mod start;
+pub mod query;
+pub mod cancel;
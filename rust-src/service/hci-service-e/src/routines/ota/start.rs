@@ -0,0 +1,51 @@
+
+use log::{trace, error};
+
+use near_base::NearResult;
+use near_transport::{Routine, RoutineEventTrait, RoutineWrap, HeaderMeta, EventResult};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_encode_raw_object, try_decode_raw_object, hci::firmware::{Ota_start, Ota_status_list}};
+
+use crate::process::Process;
+
+pub struct StartOtaRoutine {
+    process: Process,
+}
+
+impl StartOtaRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(Self {
+            process,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for StartOtaRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("StartOtaRoutine::on_routine header_meta={header_meta}");
+
+        let r = try_decode_raw_object!(Ota_start, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Ota_status_list> = match r {
+            DataContent::Content(req) => self.on_routine(header_meta, req).await.into(),
+            DataContent::Error(e) => DataContent::Error(e)
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl StartOtaRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, mut req: Ota_start) -> NearResult<Ota_status_list> {
+        self.process
+            .ota_manager()
+            .start(req.take_thing_ids(), req.take_version())
+            .await
+            .map_err(| e | {
+                error!("{e}, sequence: {}", header_meta.sequence());
+                e
+            })
+    }
+}
@@ -0,0 +1,77 @@
+
+use log::{trace, error, warn};
+
+use mac_address::MacAddress;
+use near_base::{NearResult, NearError, ErrorCode};
+use near_transport::{Routine, RoutineEventTrait, RoutineWrap, HeaderMeta, EventResult};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_encode_raw_object, try_decode_raw_object, hci::thing::{Thing_pairing, Thing_pairing_proof}};
+
+use crate::process::Process;
+
+pub struct AttestPairingRoutine {
+    process: Process,
+}
+
+impl AttestPairingRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(Self {
+            process,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for AttestPairingRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("AttestPairingRoutine::on_routine header_meta={header_meta}");
+
+        let r = try_decode_raw_object!(Thing_pairing, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Thing_pairing_proof> = match r {
+            DataContent::Content(req) => self.on_routine(header_meta, req).await.into(),
+            DataContent::Error(e) => DataContent::Error(e)
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl AttestPairingRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, mut req: Thing_pairing) -> NearResult<Thing_pairing_proof> {
+        // the thing is claimed by whom requests the gateway.
+        let owner = 
+            header_meta.creator.as_ref()
+                .and_then(| creator | creator.creator.as_ref())
+                .ok_or_else(|| {
+                    warn!("missing creator. sequence: {}", header_meta.sequence());
+                    NearError::new(ErrorCode::NEAR_ERROR_NO_TARGET, "missing creator")
+                })?;
+
+        let mac = 
+            req.mac_address().parse::<MacAddress>()
+                .map_err(| e | {
+                    let error_string = format!("failed parse to mac-address with err: {e}");
+                    error!("{error_string}, sequence: {}", header_meta.sequence());
+                    NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, error_string)
+                })?;
+
+        let thing_data = req.take_thing_data();
+
+        let proof = 
+            self.process
+                .pairing_manager()
+                .attest(req.brand_id(), mac, thing_data, owner)
+                .await
+                .map_err(| e | {
+                    error!("{e}, sequence: {}", header_meta.sequence());
+                    e
+                })?;
+
+        Ok(Thing_pairing_proof {
+            proof,
+            ..Default::default()
+        })
+    }
+}
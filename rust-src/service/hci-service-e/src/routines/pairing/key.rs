@@ -0,0 +1,47 @@
+
+use log::trace;
+
+use near_base::{NearResult, RawConvertTo, builder_codec_macro::Empty};
+use near_transport::{Routine, RoutineEventTrait, RoutineWrap, HeaderMeta, EventResult};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_encode_raw_object, try_decode_raw_object, hci::thing::Thing_pairing_key};
+
+use crate::process::Process;
+
+pub struct PairingKeyRoutine {
+    process: Process,
+}
+
+impl PairingKeyRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(Self {
+            process,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for PairingKeyRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("PairingKeyRoutine::on_routine header_meta={header_meta}");
+
+        let r = try_decode_raw_object!(Empty, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Thing_pairing_key> = match r {
+            DataContent::Content(_) => self.on_routine().await.into(),
+            DataContent::Error(e) => DataContent::Error(e)
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl PairingKeyRoutine {
+    async fn on_routine(&self) -> NearResult<Thing_pairing_key> {
+        Ok(Thing_pairing_key {
+            public_key: self.process.pairing_manager().public_key().to_vec()?,
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,3 @@
+
+pub mod attest;
+pub mod key;
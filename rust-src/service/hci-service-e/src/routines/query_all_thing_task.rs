@@ -3,28 +3,13 @@ use std::sync::Arc;
 
 use log::{trace, error};
 
-use near_base::{NearResult, builder_codec_macro::Empty, ObjectId, now};
+use near_base::{NearResult, builder_codec_macro::Empty};
 use near_transport::{Routine, RoutineEventTrait, RoutineWrap, HeaderMeta, EventResult};
 
 use base::raw_object::RawObjectGuard;
 use protos::{DataContent, try_encode_raw_object, try_decode_raw_object};
 
-use crate::{process::Process, tasks::{TaskModule, TaskData}, cache::{thing_components::ThingComponentPtr, ThingStatus}, };
-
-#[derive(Clone)]
-pub struct QueryTaskConfig {
-    pub(crate) timeout_response: std::time::Duration,
-
-}
-
-impl std::default::Default for QueryTaskConfig {
-    fn default() -> Self {
-        Self {
-            timeout_response: std::time::Duration::from_secs(300),
-        }
-    }
-}
-
+use crate::{process::Process, tasks::{TaskModule, TaskData}, cache::thing_components::ThingComponentPtr, };
 
 struct QueryAllThingTaskRoutineImpl {
     process: Process,
@@ -81,33 +66,9 @@ impl QueryAllThingTaskRoutine {
     }
 
     pub(in self) async fn on_routine(&self, header_meta: &HeaderMeta) -> NearResult<Empty> {
-        let timeout_response = self.0.process.config().routines_config.query_task_config.timeout_response.as_micros() as u64;
-        let now = now();
-
         let things = self.0.process.thing_components().get_all_thing();
         let mut fut = vec![];
 
-        // check status
-        {
-            let timeout_things: Vec<&ObjectId> = 
-            things.iter()
-                .filter(| it | {
-                    match it.status() {
-                        ThingStatus::Online(last_updated, _) => {
-                            (now - last_updated) > timeout_response
-                        }
-                        _ => true
-                    }
-                })
-                .map(| it | {
-                    it.thing().object_id()
-                })
-                .collect();
-
-            self.0.process.thing_components().offline(timeout_things.into_iter());
-        }
-
-
         for thing in things {
 
             fut.push(
@@ -86,7 +86,11 @@ impl TaskManagerCb {
         if let Ok(task_module) = TaskModule::from_str(&cmd) {
             match task_module {
                 TaskModule::Search => SeachEventResult::get_instance().on_taskcb(task_module, TaskCbData::from((mac, output))).await,
-                _ => self.0.cb.on_taskcb(task_module, TaskCbData::from((mac, output))).await,
+                _ => {
+                    // any advertisement of the thing refreshes its last-seen time.
+                    self.process().thing_components().seen(&mac);
+                    self.0.cb.on_taskcb(task_module, TaskCbData::from((mac, output))).await
+                }
             }
         } else {
             error!("Unidentified {{{cmd}}} TaskModule");
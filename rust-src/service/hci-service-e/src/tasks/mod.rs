@@ -22,9 +22,15 @@ pub enum TaskModule {
     QueryThing,
     AnalizeData,
     ControlThing,
+    PairingChallenge,
     AddSchedule,
     RemoveSchedule,
     ExecuteSchedule,
+    OtaBegin,
+    OtaChunk,
+    OtaEnd,
+    OtaAbort,
+    OtaStatus,
     Other(String),
 }
 
@@ -60,9 +66,15 @@ impl FromStr for TaskModule {
             "query_thing" => Ok(Self::QueryThing),
             "analize_data" => Ok(Self::AnalizeData),
             "control_thing" => Ok(Self::ControlThing),
+            "pairing_challenge" => Ok(Self::PairingChallenge),
             "add_schedule" => Ok(Self::AddSchedule),
             "remove_schedule" => Ok(Self::RemoveSchedule),
             "execute_schedule" => Ok(Self::ExecuteSchedule),
+            "ota_begin" => Ok(Self::OtaBegin),
+            "ota_chunk" => Ok(Self::OtaChunk),
+            "ota_end" => Ok(Self::OtaEnd),
+            "ota_abort" => Ok(Self::OtaAbort),
+            "ota_status" => Ok(Self::OtaStatus),
             "" => Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, "task name is null")),
             _ => Ok(Self::Other(s.to_owned())),
         }
@@ -80,9 +92,15 @@ impl TaskModule {
             Self::QueryThing => 15,
             Self::AnalizeData => 16,
             Self::ControlThing => 17,
+            Self::PairingChallenge => 18,
             Self::AddSchedule => 21,
             Self::RemoveSchedule => 22,
             Self::ExecuteSchedule => 23,
+            Self::OtaBegin => 31,
+            Self::OtaChunk => 32,
+            Self::OtaEnd => 33,
+            Self::OtaAbort => 34,
+            Self::OtaStatus => 35,
             Self::Other(v) => {
                 let mut hasher = DefaultHasher::new();
                 v.hash(&mut hasher);
@@ -102,9 +120,15 @@ impl TaskModule {
             Self::QueryThing => "query_thing",
             Self::AnalizeData => "analize_data",
             Self::ControlThing => "control_thing",
+            Self::PairingChallenge => "pairing_challenge",
             Self::AddSchedule => "add_schedule",
             Self::RemoveSchedule => "remove_schedule",
             Self::ExecuteSchedule => "execute_schedule",
+            Self::OtaBegin => "ota_begin",
+            Self::OtaChunk => "ota_chunk",
+            Self::OtaEnd => "ota_end",
+            Self::OtaAbort => "ota_abort",
+            Self::OtaStatus => "ota_status",
             Self::Other(v) => v.as_str(),
         }
     }
@@ -0,0 +1,209 @@
+
+use std::path::Path;
+
+use async_std::sync::Mutex;
+use log::{trace, error, info};
+
+use near_base::{hash_data, Deserialize, ErrorCode, ExtentionObject, FileDecoder, Hash256, NearError, NearResult, ObjectId, PrivateKey, PublicKey,
+                RawConvertTo, Signature, Timestamp, load_private_key, save_private_key, };
+use near_util::KEY_PASSPHRASE_ENV;
+
+use protos::hci::audit::Audit_record;
+use storage::{SeriesStorageTrait, StorageTrait};
+
+use crate::caches::audit::{AuditHeadItem, AuditRecordItem};
+
+pub(crate) const AUDIT_TABLE: &str      = "audit";
+pub(crate) const AUDIT_HEAD_TABLE: &str = "audit_head";
+pub(crate) const AUDIT_KEY_FILE: &str   = "audit.key";
+/// Only the service records the control actions.
+pub(crate) const AUDIT_WRITER: &str     = "hci-gateway";
+
+/// The hash covers all of the fields except the hash and the signature.
+pub(crate) fn record_hash(record: &Audit_record) -> NearResult<Hash256> {
+    let mut record = record.clone();
+    record.hash.clear();
+    record.signature.clear();
+
+    Ok(hash_data(&record.to_vec()?))
+}
+
+/// Check the hash and the signature of every record, and the chain of the continuous ones.
+pub(crate) fn verify_records(public_key: &PublicKey, records: &[Audit_record]) -> NearResult<()> {
+    let mut prev: Option<&Audit_record> = None;
+
+    for record in records {
+        let hash = record_hash(record)?;
+        if hash.to_string() != record.hash() {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("the hash of #{} record is changed", record.index())));
+        }
+
+        let (signature, _) = Signature::deserialize(record.signature())?;
+        public_key.verify(hash.as_slice(), &signature)
+            .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_CRYPTO_VERIFY, format!("the signature of #{} record is invalid with err: {e}", record.index())))?;
+
+        if let Some(prev) = prev.filter(| prev | prev.index() + 1 == record.index()) {
+            if prev.hash() != record.prev_hash() {
+                return Err(NearError::new(ErrorCode::NEAR_ERROR_CONFLICT, format!("#{} record isn't chained with #{}", record.index(), prev.index())));
+            }
+        }
+
+        prev = Some(record);
+    }
+
+    Ok(())
+}
+
+/// Chain the record after the previous one, and sign its hash.
+pub(crate) fn seal_record(private_key: &PrivateKey, record: &mut Audit_record, prev: Option<&Audit_record>) -> NearResult<()> {
+    record.index = prev.map(| prev | prev.index()).unwrap_or_default() + 1;
+    record.prev_hash = prev.map(| prev | prev.hash().to_owned()).unwrap_or_default();
+
+    let hash = record_hash(record)?;
+    record.signature = private_key.sign(hash.as_slice())?.to_vec()?;
+    record.hash = hash.to_string();
+
+    Ok(())
+}
+
+// the audit key is created at the first time, it's encrypted if the passphrase is set.
+fn open_audit_key(key_file: &Path) -> NearResult<PrivateKey> {
+    let passphrase = std::env::var(KEY_PASSPHRASE_ENV).ok();
+
+    if key_file.exists() {
+        load_private_key(key_file, passphrase.as_deref())
+    } else {
+        let private_key = PrivateKey::generate_rsa1024()?;
+        save_private_key(key_file, &private_key, passphrase.as_deref())?;
+        info!("create the audit key: {}", key_file.display());
+        Ok(private_key)
+    }
+}
+
+// the object id of the writer is taken from its desc, none of the records is accepted without it.
+fn open_audit_writer(desc_file: &Path) -> Option<ObjectId> {
+    match ExtentionObject::decode_from_file(desc_file) {
+        Ok(desc) => Some(desc.object_id().clone()),
+        Err(e) => {
+            error!("failed load the audit writer from {} with err: {e}, the audit log is read-only", desc_file.display());
+            None
+        }
+    }
+}
+
+/// The append-only log of the control actions, each record is chained with the previous one
+/// and signed by the audit key.
+pub(crate) struct AuditLog {
+    private_key: PrivateKey,
+    public_key: PublicKey,
+    writer: Option<ObjectId>,
+    records: Box<dyn SeriesStorageTrait<AuditRecordItem>>,
+    head: Box<dyn StorageTrait<AuditHeadItem>>,
+    // the records are appended one by one.
+    lock: Mutex<()>,
+}
+
+impl AuditLog {
+    pub(crate) fn new(key_file: &Path,
+                      writer_desc_file: &Path,
+                      records: Box<dyn SeriesStorageTrait<AuditRecordItem>>,
+                      head: Box<dyn StorageTrait<AuditHeadItem>>) -> NearResult<Self> {
+        let private_key = open_audit_key(key_file)?;
+
+        Ok(Self {
+            public_key: private_key.public(),
+            private_key,
+            writer: open_audit_writer(writer_desc_file),
+            records,
+            head,
+            lock: Mutex::new(()),
+        })
+    }
+
+    #[inline]
+    pub(crate) fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    /// The records are only appended by the writer.
+    pub(crate) fn check_writer(&self, requestor: &ObjectId) -> NearResult<()> {
+        if self.writer.as_ref() == Some(requestor) {
+            Ok(())
+        } else {
+            Err(NearError::new(ErrorCode::NEAR_ERROR_REFUSE, format!("{requestor} isn't the audit writer")))
+        }
+    }
+
+    /// The time of the record is taken from the storage clock.
+    pub(crate) async fn append(&self, mut record: Audit_record) -> NearResult<Audit_record> {
+        let _guard = self.lock.lock().await;
+
+        let prev =
+            match self.head.load_with_prefix(AuditHeadItem::key()).await {
+                Ok(head) => Some(head.take()),
+                Err(e) if e.errno() == ErrorCode::NEAR_ERROR_NOTFOUND => None,
+                Err(e) => {
+                    error!("failed load the audit head with err: {e}");
+                    return Err(e);
+                }
+            };
+
+        record.set_timestamp(near_base::now());
+
+        seal_record(&self.private_key, &mut record, prev.as_ref())?;
+
+        self.records.append(&record.clone().into()).await?;
+
+        let head = AuditHeadItem::from(record.clone());
+        match prev {
+            Some(_) => self.head.update(&head).await,
+            None => self.head.create_new(&head).await,
+        }?;
+
+        trace!("append audit record #{}, action: {}", record.index(), record.action());
+
+        Ok(record)
+    }
+
+    /// Load the records in [begin, end) which are sorted by the index, they are verified before returned.
+    pub(crate) async fn query(&self, thing_id: Option<&str>, begin: Timestamp, end: Timestamp) -> NearResult<Vec<Audit_record>> {
+        let mut records: Vec<Audit_record> =
+            self.records
+                .load_range(Some(AuditRecordItem::key()), begin, end)
+                .await?
+                .into_iter()
+                .map(| item | item.take())
+                .filter(| record | {
+                    thing_id.map(| thing_id | record.thing_ids().iter().any(| id | id == thing_id))
+                            .unwrap_or(true)
+                })
+                .collect();
+
+        records.sort_by_key(| record | record.index());
+
+        verify_records(&self.public_key, &records)
+            .map_err(| e | {
+                error!("the audit log is broken with err: {e}");
+                e
+            })?;
+
+        Ok(records)
+    }
+}
+
+#[test]
+fn test_audit_chain() {
+    let private_key = PrivateKey::generate_rsa1024().unwrap();
+
+    let mut first = Audit_record { action: "ctrl".to_owned(), thing_ids: vec!["light".to_owned()], ..Default::default() };
+    seal_record(&private_key, &mut first, None).unwrap();
+    let mut second = Audit_record { action: "remove".to_owned(), outcome: 17, ..Default::default() };
+    seal_record(&private_key, &mut second, Some(&first)).unwrap();
+
+    let mut records = vec![first, second];
+    verify_records(&private_key.public(), &records).unwrap();
+
+    // the outcome is changed
+    records[1].outcome = 0;
+    assert!(verify_records(&private_key.public(), &records).is_err());
+}
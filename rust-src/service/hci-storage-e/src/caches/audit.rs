@@ -0,0 +1,116 @@
+
+use near_base::{Serialize, Deserialize, NearResult, Timestamp};
+
+use protos::hci::audit::Audit_record;
+use storage::{ItemTrait, SeriesItemTrait};
+
+// all of the records are kept under one key, they are filtered by the things after loaded.
+const AUDIT_RECORD_KEY: &str = "audit";
+const AUDIT_HEAD_KEY: &str = "head";
+
+#[derive(Clone)]
+pub struct AuditRecordItem {
+    record: Audit_record,
+}
+
+impl AuditRecordItem {
+    pub fn key() -> &'static str {
+        AUDIT_RECORD_KEY
+    }
+
+    pub fn take(self) -> Audit_record {
+        self.record
+    }
+}
+
+impl From<Audit_record> for AuditRecordItem {
+    fn from(record: Audit_record) -> Self {
+        Self { record }
+    }
+}
+
+impl ItemTrait for AuditRecordItem {
+    fn id(&self) -> &str {
+        AUDIT_RECORD_KEY
+    }
+}
+
+impl SeriesItemTrait for AuditRecordItem {
+    fn timestamp(&self) -> Timestamp {
+        self.record.timestamp()
+    }
+}
+
+impl Serialize for AuditRecordItem {
+    fn raw_capacity(&self) -> usize {
+        self.record.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        self.record.serialize(buf)
+    }
+}
+
+impl Deserialize for AuditRecordItem {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (record, buf) = Audit_record::deserialize(buf)?;
+
+        Ok((Self { record }, buf))
+    }
+}
+
+impl std::ops::Deref for AuditRecordItem {
+    type Target = Audit_record;
+
+    fn deref(&self) -> &Self::Target {
+        &self.record
+    }
+}
+
+/// The last record of the log, the next one is chained with it.
+#[derive(Clone)]
+pub struct AuditHeadItem {
+    record: Audit_record,
+}
+
+impl AuditHeadItem {
+    pub fn key() -> &'static str {
+        AUDIT_HEAD_KEY
+    }
+
+    pub fn take(self) -> Audit_record {
+        self.record
+    }
+}
+
+impl From<Audit_record> for AuditHeadItem {
+    fn from(record: Audit_record) -> Self {
+        Self { record }
+    }
+}
+
+impl ItemTrait for AuditHeadItem {
+    fn id(&self) -> &str {
+        AUDIT_HEAD_KEY
+    }
+}
+
+impl Serialize for AuditHeadItem {
+    fn raw_capacity(&self) -> usize {
+        self.record.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        self.record.serialize(buf)
+    }
+}
+
+impl Deserialize for AuditHeadItem {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (record, buf) = Audit_record::deserialize(buf)?;
+
+        Ok((Self { record }, buf))
+    }
+}
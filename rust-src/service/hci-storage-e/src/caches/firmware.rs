@@ -0,0 +1,152 @@
+use near_base::{Serialize, Deserialize, hash_data, NearResult, NearError, ErrorCode, now, file::FileObject};
+
+use base::Version;
+
+use protos::hci::firmware::*;
+use storage::ItemTrait;
+
+struct FirmwareIdBuilder<'a> {
+    pub product_id: &'a str,
+    pub version: &'a str,
+}
+
+impl FirmwareIdBuilder<'_> {
+    pub(super) fn build(self) -> String {
+        let buf = {
+            let mut buf = vec![0u8; self.product_id.raw_capacity() + self.version.raw_capacity()];
+
+            let _end = self.product_id.serialize(&mut buf).unwrap();
+            let _end = self.version.serialize(_end).unwrap();
+
+            buf
+        };
+
+        let binding = hash_data(buf.as_slice());
+        let buf = binding.as_slice();
+        hex::encode_upper(&buf[0..16])
+    }
+}
+
+#[derive(Clone)]
+pub struct FirmwareItem {
+    firmware: Firmware_info,
+}
+
+impl FirmwareItem {
+    /// The file is published by nds, only its object is saved.
+    pub fn create_new(product_id: String, version: String, file: Vec<u8>, release_note: String) -> NearResult<Self> {
+        if version.trim().is_empty() {
+            Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, "firmware version can't empty."))
+        } else {
+            Ok(())
+        }?;
+
+        // "v1.2" and "1.2.0" are the same firmware.
+        let version = version.parse::<Version>()?.to_string();
+        let version = version.as_str();
+
+        let (file_object, _) = 
+            FileObject::deserialize(file.as_slice())
+                .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("invalid firmware file with err: {e}")))?;
+
+        let desc = file_object.desc().content();
+        if desc.len() == 0 {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, "firmware file is empty."));
+        }
+
+        Ok(Self {
+            firmware: Firmware_info {
+                firmware_id: FirmwareIdBuilder {
+                    product_id: &product_id,
+                    version,
+                }.build(),
+                version: version.to_owned(),
+                file_id: file_object.object_id().to_string(),
+                size: desc.len(),
+                hash: desc.hash().to_hex_string(),
+                file,
+                release_note,
+                create_timestamp: now(),
+                product_id,
+                ..Default::default()
+            }
+        })
+    }
+
+    pub fn take(self) -> Firmware_info {
+        self.firmware
+    }
+}
+
+impl ItemTrait for FirmwareItem {
+    fn id(&self) -> &str {
+        self.firmware.firmware_id()
+    }
+}
+
+impl Serialize for FirmwareItem {
+    fn raw_capacity(&self) -> usize {
+        self.firmware.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        self.firmware.serialize(buf)
+    }
+}
+
+impl Deserialize for FirmwareItem {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (firmware, buf) = Firmware_info::deserialize(buf)?;
+
+        Ok((Self { firmware }, buf))
+    }
+}
+
+impl std::ops::Deref for FirmwareItem {
+    type Target = Firmware_info;
+
+    fn deref(&self) -> &Self::Target {
+        &self.firmware
+    }
+}
+
+/// The firmware of the version, or the latest one of the product when the version is empty.
+/// The versions are compared after they're parsed, the one which can't be parsed is the oldest,
+/// and the pre-release is the latest only when there isn't any release.
+pub fn select_firmware<'a>(firmwares: &'a [FirmwareItem], product_id: &str, version: &str) -> Option<&'a FirmwareItem> {
+    let firmwares = firmwares.iter().filter(| firmware | firmware.product_id() == product_id);
+
+    if version.trim().is_empty() {
+        firmwares.max_by_key(| firmware | {
+            let version = firmware.version().parse::<Version>().ok();
+            (version.as_ref().map(| version | !version.is_prerelease()), version, firmware.create_timestamp())
+        })
+    } else {
+        let version = version.parse::<Version>().ok()?;
+        firmwares.into_iter().find(| firmware | firmware.version().parse::<Version>().ok().as_ref() == Some(&version))
+    }
+}
+
+#[test]
+fn test_select_firmware() {
+    let firmware = | version: &str, create_timestamp: u64 | {
+        FirmwareItem {
+            firmware: Firmware_info {
+                product_id: "p1".to_owned(),
+                version: version.to_owned(),
+                create_timestamp,
+                ..Default::default()
+            }
+        }
+    };
+
+    let firmwares = vec![firmware("1.0.10", 1), firmware("1.2.0", 2), firmware("1.3.0-beta", 3), firmware("1.0.2", 4)];
+
+    assert_eq!(select_firmware(&firmwares, "p1", "v1.2").unwrap().version(), "1.2.0");
+    assert_eq!(select_firmware(&firmwares, "p1", "1.0.2").unwrap().version(), "1.0.2");
+    assert_eq!(select_firmware(&firmwares, "p1", "").unwrap().version(), "1.2.0");
+    assert_eq!(select_firmware(&firmwares[2..3], "p1", "").unwrap().version(), "1.3.0-beta");
+    assert!(select_firmware(&firmwares, "p1", "1.1").is_none());
+    assert!(select_firmware(&firmwares, "p2", "").is_none());
+}
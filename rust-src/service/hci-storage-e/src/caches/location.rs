@@ -0,0 +1,200 @@
+
+use near_base::{Serialize, Deserialize, hash_data, NearResult, NearError, ErrorCode, now};
+
+use protos::hci::location::*;
+use storage::ItemTrait;
+
+struct LocationIdBuilder<'a> {
+    pub parent_id: &'a str,
+    pub location_name: &'a str,
+    pub now: near_base::Timestamp,
+}
+
+impl LocationIdBuilder<'_> {
+    pub(super) fn build(self) -> String {
+        let buf = {
+            let mut buf = vec![0u8; self.parent_id.raw_capacity() + self.location_name.raw_capacity() + self.now.raw_capacity()];
+
+            let _end = self.parent_id.serialize(&mut buf).unwrap();
+            let _end = self.location_name.serialize(_end).unwrap();
+            let _end = self.now.serialize(_end).unwrap();
+
+            buf
+        };
+
+        let binding = hash_data(buf.as_slice());
+        let buf = binding.as_slice();
+        hex::encode_upper(&buf[0..16])
+    }
+}
+
+#[derive(Clone)]
+pub struct LocationItem {
+    location: Location_info,
+}
+
+impl LocationItem {
+    /// Home is the root, floor and zone belong to home, room belongs to floor or home.
+    pub fn create_new(parent: Option<&LocationItem>, location_type: Location_type, location_name: String, location_img_idx: u32) -> NearResult<Self> {
+        let location_name = location_name.as_str().trim();
+        if location_name.is_empty() {
+            Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, "location name can't empty."))
+        } else {
+            Ok(())
+        }?;
+
+        let parent_type = parent.map(| parent | parent.location_type());
+
+        match (location_type, parent_type) {
+            (Location_type::Home, None) |
+            (Location_type::Floor, Some(Location_type::Home)) |
+            (Location_type::Zone, Some(Location_type::Home)) |
+            (Location_type::Room, Some(Location_type::Home)) |
+            (Location_type::Room, Some(Location_type::Floor)) => Ok(()),
+            _ => Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("{:?} can't belong to {:?}.", location_type, parent_type)))
+        }?;
+
+        let parent_id = parent.map(| parent | parent.location_id()).unwrap_or_default();
+
+        Ok(Self {
+            location: Location_info {
+                location_id: LocationIdBuilder {
+                    parent_id,
+                    location_name,
+                    now: now(),
+                }.build(),
+                parent_id: parent_id.to_owned(),
+                type_: location_type.into(),
+                location_name: location_name.to_owned(),
+                location_img_idx,
+                ..Default::default()
+            }
+        })
+    }
+
+    pub fn location_type(&self) -> Location_type {
+        self.location.type_()
+    }
+
+    /// Only room and zone hold things.
+    pub fn is_container(&self) -> bool {
+        match self.location_type() {
+            Location_type::Room | Location_type::Zone => true,
+            Location_type::Home | Location_type::Floor => false,
+        }
+    }
+
+    /// A thing is in one room at most, but it can be in any number of zones as well.
+    pub fn is_exclusive(&self) -> bool {
+        self.location_type() == Location_type::Room
+    }
+
+    pub fn update_name(&mut self, new_location_name: String) {
+        self.location.set_location_name(new_location_name);
+    }
+
+    pub fn update_img_index(&mut self, location_img_idx: u32) {
+        self.location.set_location_img_idx(location_img_idx);
+    }
+
+    pub fn insert_things<'a>(&mut self, thing_ids: impl Iterator<Item=&'a String>) {
+        for thing_id in thing_ids {
+            if !self.location.thing_ids.contains(thing_id) {
+                self.location.thing_ids.push(thing_id.clone());
+            }
+        }
+    }
+
+    /// Return true if any thing has been removed.
+    pub fn remove_things<'a>(&mut self, thing_ids: impl Iterator<Item=&'a String>) -> bool {
+        let thing_ids: Vec<&String> = thing_ids.collect();
+        let count = self.location.thing_ids.len();
+
+        self.location.thing_ids.retain(| thing_id | !thing_ids.contains(&thing_id));
+
+        count != self.location.thing_ids.len()
+    }
+
+    pub fn take(self) -> Location_info {
+        self.location
+    }
+}
+
+impl ItemTrait for LocationItem {
+    fn id(&self) -> &str {
+        self.location.location_id()
+    }
+}
+
+impl Serialize for LocationItem {
+    fn raw_capacity(&self) -> usize {
+        self.location.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        self.location.serialize(buf)
+    }
+}
+
+impl Deserialize for LocationItem {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (location, buf) = Location_info::deserialize(buf)?;
+
+        Ok((Self { location }, buf))
+    }
+}
+
+impl std::ops::Deref for LocationItem {
+    type Target = Location_info;
+
+    fn deref(&self) -> &Self::Target {
+        &self.location
+    }
+}
+
+/// Collect all of things in the location and its descendants.
+pub fn collect_things(locations: &[LocationItem], location_id: &str) -> Vec<String> {
+    let mut things = vec![];
+    let mut pending = vec![location_id];
+
+    while let Some(id) = pending.pop() {
+        for location in locations {
+            if location.location_id() == id {
+                // the thing in a zone may be in a room too.
+                for thing_id in location.thing_ids.iter() {
+                    if !things.contains(thing_id) {
+                        things.push(thing_id.clone());
+                    }
+                }
+            } else if location.parent_id() == id {
+                pending.push(location.location_id());
+            }
+        }
+    }
+
+    things
+}
+
+#[test]
+fn test_zone_overlays_room() {
+    let home = LocationItem::create_new(None, Location_type::Home, "home".to_owned(), 0).unwrap();
+    let mut room = LocationItem::create_new(Some(&home), Location_type::Room, "room".to_owned(), 0).unwrap();
+    let mut zone = LocationItem::create_new(Some(&home), Location_type::Zone, "zone".to_owned(), 0).unwrap();
+
+    assert!(room.is_exclusive());
+    assert!(!zone.is_exclusive());
+
+    let thing_ids = vec!["t1".to_owned(), "t2".to_owned()];
+    room.insert_things(thing_ids.iter());
+    zone.insert_things(thing_ids[1..].iter());
+
+    let home_id = home.location_id().to_owned();
+    let zone_id = zone.location_id().to_owned();
+    let locations = vec![home, room, zone];
+
+    let mut things = collect_things(&locations, &home_id);
+    things.sort();
+    assert_eq!(things, thing_ids);
+    assert_eq!(collect_things(&locations, &zone_id), vec!["t2".to_owned()]);
+}
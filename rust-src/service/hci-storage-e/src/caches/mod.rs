@@ -3,3 +3,7 @@ pub mod brand;
 pub mod product;
 pub mod thing;
 pub mod schedule;
+pub mod telemetry;
+pub mod location;
+pub mod firmware;
+pub mod audit;
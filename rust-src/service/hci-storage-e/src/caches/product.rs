@@ -87,6 +87,37 @@ impl ProductItem {
         });
     }
 
+    /// Set the capability of itself or one of its children, return false if not found the product.
+    pub fn set_capability(&mut self, product_id: &str, capability: Product_capability) -> bool {
+        if self.product.product_id() == product_id {
+            self.product.capability = Some(capability).into();
+            true
+        } else if let Some(child) = self.product.mut_children().mut_products().iter_mut().find(| child | child.product_id() == product_id) {
+            child.capability = Some(capability).into();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// The capability of the child overrides its parent when it's defined,
+    /// it's empty if neither of them declares the capability, and the empty one accepts all of data.
+    pub fn capability(&self, child_product_id: &str) -> Product_capability {
+        self.children()
+            .products()
+            .iter()
+            .find(| child | child.product_id() == child_product_id)
+            .map(| child | child.capability())
+            .filter(| capability | !capability.properties.is_empty())
+            .unwrap_or(self.product.capability())
+            .clone()
+    }
+
+    pub fn contains(&self, product_id: &str) -> bool {
+        self.product_id() == product_id ||
+        self.children().products().iter().any(| child | child.product_id() == product_id)
+    }
+
     pub fn take(self) -> Product_info {
         self.product
     }
@@ -129,3 +160,37 @@ impl std::ops::Deref for ProductItem {
         &self.product
     }
 }
+
+pub fn check_capability(capability: &Product_capability) -> NearResult<()> {
+    let mut names = std::collections::HashSet::new();
+
+    for property in capability.properties.iter() {
+        let name = property.name().trim();
+        if name.is_empty() {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, "property name can't empty."));
+        }
+
+        if !names.insert(name) {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_ALREADY_EXIST, format!("[{name}] property is duplicated.")));
+        }
+
+        match property.type_() {
+            Product_property_type::Int | Product_property_type::Float => {
+                if property.has_min() && property.has_max() && property.min() > property.max() {
+                    return Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("[{name}] property's min is greater than max.")));
+                }
+                if property.step() < 0f64 {
+                    return Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("[{name}] property's step is negative.")));
+                }
+            }
+            Product_property_type::Enum => {
+                if property.enums.is_empty() {
+                    return Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("[{name}] property hasn't any enum.")));
+                }
+            }
+            Product_property_type::Bool | Product_property_type::String => {}
+        }
+    }
+
+    Ok(())
+}
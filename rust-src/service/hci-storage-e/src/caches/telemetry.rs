@@ -0,0 +1,166 @@
+
+use near_base::{Serialize, Deserialize, NearResult, Timestamp};
+
+use protos::hci::telemetry::*;
+use storage::{ItemTrait, SeriesItemTrait};
+
+#[derive(Clone)]
+pub struct TelemetrySampleItem {
+    sample: Thing_telemetry_sample,
+}
+
+impl TelemetrySampleItem {
+    pub fn take(self) -> Thing_telemetry_sample {
+        self.sample
+    }
+}
+
+impl From<Thing_telemetry_sample> for TelemetrySampleItem {
+    fn from(sample: Thing_telemetry_sample) -> Self {
+        Self { sample }
+    }
+}
+
+impl ItemTrait for TelemetrySampleItem {
+    fn id(&self) -> &str {
+        self.sample.thing_id()
+    }
+}
+
+impl SeriesItemTrait for TelemetrySampleItem {
+    fn timestamp(&self) -> Timestamp {
+        self.sample.timestamp()
+    }
+}
+
+impl Serialize for TelemetrySampleItem {
+    fn raw_capacity(&self) -> usize {
+        self.sample.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        self.sample.serialize(buf)
+    }
+}
+
+impl Deserialize for TelemetrySampleItem {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (sample, buf) = Thing_telemetry_sample::deserialize(buf)?;
+
+        Ok((Self { sample }, buf))
+    }
+}
+
+impl std::ops::Deref for TelemetrySampleItem {
+    type Target = Thing_telemetry_sample;
+
+    fn deref(&self) -> &Self::Target {
+        &self.sample
+    }
+}
+
+#[derive(Clone)]
+pub struct TelemetryAggregateItem {
+    aggregates: Thing_telemetry_aggregate_list,
+}
+
+impl TelemetryAggregateItem {
+    pub fn take(self) -> Thing_telemetry_aggregate_list {
+        self.aggregates
+    }
+}
+
+impl From<Thing_telemetry_aggregate_list> for TelemetryAggregateItem {
+    fn from(aggregates: Thing_telemetry_aggregate_list) -> Self {
+        Self { aggregates }
+    }
+}
+
+impl ItemTrait for TelemetryAggregateItem {
+    fn id(&self) -> &str {
+        self.aggregates.thing_id()
+    }
+}
+
+impl SeriesItemTrait for TelemetryAggregateItem {
+    fn timestamp(&self) -> Timestamp {
+        self.aggregates.begin()
+    }
+}
+
+impl Serialize for TelemetryAggregateItem {
+    fn raw_capacity(&self) -> usize {
+        self.aggregates.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        self.aggregates.serialize(buf)
+    }
+}
+
+impl Deserialize for TelemetryAggregateItem {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (aggregates, buf) = Thing_telemetry_aggregate_list::deserialize(buf)?;
+
+        Ok((Self { aggregates }, buf))
+    }
+}
+
+impl std::ops::Deref for TelemetryAggregateItem {
+    type Target = Thing_telemetry_aggregate_list;
+
+    fn deref(&self) -> &Self::Target {
+        &self.aggregates
+    }
+}
+
+/// The rollup watermark of each resolution, the id is the name of target table.
+#[derive(Clone)]
+pub struct TelemetryWatermarkItem {
+    name: String,
+    timestamp: Timestamp,
+}
+
+impl TelemetryWatermarkItem {
+    pub fn new(name: &str, timestamp: Timestamp) -> Self {
+        Self {
+            name: name.to_owned(),
+            timestamp,
+        }
+    }
+
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+}
+
+impl ItemTrait for TelemetryWatermarkItem {
+    fn id(&self) -> &str {
+        self.name.as_str()
+    }
+}
+
+impl Serialize for TelemetryWatermarkItem {
+    fn raw_capacity(&self) -> usize {
+        self.name.raw_capacity() + self.timestamp.raw_capacity()
+    }
+
+    fn serialize<'a>(&self,
+                     buf: &'a mut [u8]) -> NearResult<&'a mut [u8]> {
+        let buf = self.name.serialize(buf)?;
+        let buf = self.timestamp.serialize(buf)?;
+
+        Ok(buf)
+    }
+}
+
+impl Deserialize for TelemetryWatermarkItem {
+    fn deserialize<'de>(buf: &'de [u8]) -> NearResult<(Self, &'de [u8])> {
+        let (name, buf) = String::deserialize(buf)?;
+        let (timestamp, buf) = Timestamp::deserialize(buf)?;
+
+        Ok((Self { name, timestamp }, buf))
+    }
+}
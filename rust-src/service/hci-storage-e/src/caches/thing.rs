@@ -67,10 +67,19 @@ impl ThingItem {
         &self.thing
     }
 
+    pub fn thing_object(&self) -> &ThingObject {
+        &self.thing_object
+    }
+
     pub fn set_thing_name(&mut self, thing_name: String) {
         self.thing.set_thing_name(thing_name);
     }
 
+    /// The people whom the thing is paired for.
+    pub fn set_owner_id(&mut self, owner_id: String) {
+        self.thing.set_owner_id(owner_id);
+    }
+
     pub fn split(self) -> (Thing_info, ThingObject) {
         (self.thing, self.thing_object)
     }
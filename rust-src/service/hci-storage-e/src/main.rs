@@ -2,6 +2,9 @@
 mod caches;
 mod routines;
 mod process;
+mod telemetry;
+mod audit;
+mod pairing;
 
 use common::{ProcessCommandBuild, ProcessAction, };
 use log::{error, info};
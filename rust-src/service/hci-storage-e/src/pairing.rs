@@ -0,0 +1,195 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use log::{trace, error};
+
+use near_base::{builder_codec_macro::Empty, thing::ThingObject, Deserialize, ErrorCode, NearError, NearResult, ObjectId, PublicKey, Timestamp, now};
+
+use common::{RoutineTemplate, RuntimeStack};
+use proof::proof_data::{ProofDataSet, ProofOfProcessTrait};
+use protos::hci::thing::Thing_pairing_key;
+use topic_util::topics::hci_service::NEAR_THING_SERVICE_PAIRING_KEY_PUB;
+
+/// The proof is accepted only in a while after the thing answered.
+pub(crate) const PAIRING_PROOF_EXPIRED: Timestamp = std::time::Duration::from_secs(300).as_micros() as Timestamp;
+/// The audit action of the thing which is added by the owner without the pairing proof.
+pub(crate) const PAIRING_OVERRIDE_ACTION: &str = "pairing-override";
+
+/// Check the proof-of-pairing which is signed by hci-service after the thing signed the challenge,
+/// the thing can't be added or transferred without it, unless the owner overrides it by hci-gateway.
+pub(crate) struct PairingVerifier {
+    // the pairing key is queried from hci-service at the first time.
+    pairing_key: async_std::sync::Mutex<Option<PublicKey>>,
+    // the nonce of the accepted proofs, so none of them is replayed before it expired.
+    nonces: Mutex<HashMap<String, Timestamp>>,
+}
+
+impl PairingVerifier {
+    pub(crate) fn new() -> Self {
+        Self {
+            pairing_key: async_std::sync::Mutex::new(None),
+            nonces: Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn pairing_key(&self) -> NearResult<PublicKey> {
+        let pairing_key = &mut *self.pairing_key.lock().await;
+
+        if let Some(pairing_key) = pairing_key.as_ref() {
+            return Ok(pairing_key.clone());
+        }
+
+        let key =
+            RoutineTemplate::<Thing_pairing_key>::call(
+                NEAR_THING_SERVICE_PAIRING_KEY_PUB.topic().clone(),
+                Empty
+            )
+            .await
+            .map_err(| e | {
+                error!("failed call {}", NEAR_THING_SERVICE_PAIRING_KEY_PUB.topic());
+                e
+            })?
+            .await?;
+
+        let (public_key, _) = PublicKey::deserialize(key.public_key())?;
+        *pairing_key = Some(public_key.clone());
+
+        Ok(public_key)
+    }
+
+    /// Return the owner whom the thing is paired for.
+    pub(crate) async fn verify(&self, owner: &ObjectId, thing: &ThingObject, proof: &[u8]) -> NearResult<String> {
+        if proof.is_empty() {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_REFUSE, format!("{} hasn't the pairing proof", thing.object_id())));
+        }
+
+        let public_key = self.pairing_key().await?;
+        let core_id = RuntimeStack::get_instance().stack().core_device().object_id().to_string();
+
+        self.check_proof(owner, thing, proof, &core_id, &public_key, now()).await
+    }
+
+    async fn check_proof(&self, owner: &ObjectId, thing: &ThingObject, proof: &[u8], core_id: &str, public_key: &PublicKey, now: Timestamp) -> NearResult<String> {
+
+        let proof =
+            match ProofDataSet::deserialize(proof)? {
+                (ProofDataSet::Pairing(proof), _) => Ok(proof),
+                (proof, _) => Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("{proof} isn't the pairing proof"))),
+            }?;
+
+        let proof_of_data = proof.proof_of_data();
+        let proof_of_pairing = proof_of_data.desc().content().proof_data();
+
+        proof_of_data.verify_object_id()?;
+
+        let thing_id = thing.object_id().to_string();
+        if proof_of_pairing.thing_id() != thing_id || proof_of_data.body().content().data().object_id() != thing.object_id() {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("the pairing proof is for {}", proof_of_pairing.thing_id())));
+        }
+
+        if proof_of_pairing.core_id() != core_id {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("the pairing proof is signed by {}", proof_of_pairing.core_id())));
+        }
+
+        if proof_of_pairing.owner_id() != owner.to_string() {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("the pairing proof is for {}", proof_of_pairing.owner_id())));
+        }
+
+        if proof_of_pairing.pair_time > now || now - proof_of_pairing.pair_time > PAIRING_PROOF_EXPIRED {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_EXPIRED, format!("the pairing proof of {thing_id} is expired")));
+        }
+
+        proof_of_pairing.verify(public_key, proof.proof())
+            .await
+            .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_CRYPTO_VERIFY, format!("the pairing proof of {thing_id} is invalid with err: {e}")))?;
+
+        {
+            let nonces = &mut *self.nonces.lock().unwrap();
+            nonces.retain(| _, pair_time | now.saturating_sub(*pair_time) <= PAIRING_PROOF_EXPIRED);
+
+            if nonces.insert(proof_of_pairing.nonce().to_owned(), proof_of_pairing.pair_time).is_some() {
+                return Err(NearError::new(ErrorCode::NEAR_ERROR_REFUSE, format!("the pairing proof of {thing_id} has been used")));
+            }
+        }
+
+        trace!("{thing_id} is paired for {owner}");
+
+        Ok(proof_of_pairing.owner_id().to_owned())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use near_base::{device::{DeviceBodyContent, DeviceDescContent}, thing::ThingObject, ErrorCode, ObjectBuilder, ObjectId, PrivateKey, RawConvertTo, Timestamp};
+    use near_util::ThingBuilder;
+    use proof::proof_data::ProofDataSet;
+    use protos::proof::pairing::Proof_of_pairing;
+
+    use super::{PairingVerifier, PAIRING_PROOF_EXPIRED};
+
+    const PAIR_TIME: Timestamp = 1_700_000_000_000_000;
+
+    fn object_id(key: &PrivateKey) -> ObjectId {
+        ObjectBuilder::new(DeviceDescContent::with_device(1), DeviceBodyContent::default())
+            .update_desc(| desc | desc.set_public_key(key.public()))
+            .build()
+            .unwrap()
+            .object_id()
+            .clone()
+    }
+
+    fn thing(core: &ObjectId, mac: [u8; 6]) -> ThingObject {
+        ThingBuilder::new().owner(Some(core)).mac_address(mac).owner_depend_id("brand".to_owned()).build().unwrap()
+    }
+
+    fn proof(thing: &ThingObject, core: &ObjectId, owner: &ObjectId, nonce: &str, key: &PrivateKey) -> Vec<u8> {
+        let proof_of_pairing = Proof_of_pairing {
+            thing_id: thing.object_id().to_string(),
+            core_id: core.to_string(),
+            owner_id: owner.to_string(),
+            nonce: nonce.to_owned(),
+            pair_time: PAIR_TIME,
+            ..Default::default()
+        };
+
+        async_std::task::block_on(ProofDataSet::with_pairing(proof_of_pairing, thing.clone(), key.clone())).unwrap().to_vec().unwrap()
+    }
+
+    #[test]
+    fn test_verify() {
+        let pairing_key = PrivateKey::generate_rsa1024().unwrap();
+        let core = object_id(&PrivateKey::generate_rsa1024().unwrap());
+        let owner = object_id(&PrivateKey::generate_rsa1024().unwrap());
+        let other = object_id(&PrivateKey::generate_rsa1024().unwrap());
+        let thing = thing(&core, [1, 2, 3, 4, 5, 6]);
+        let core_id = core.to_string();
+
+        let verifier = PairingVerifier::new();
+        let check = | owner: &ObjectId, thing: &ThingObject, proof: &[u8], core_id: &str, now: Timestamp | {
+            async_std::task::block_on(verifier.check_proof(owner, thing, proof, core_id, &pairing_key.public(), now))
+        };
+
+        // the owner or the core isn't the one of the proof
+        let first = proof(&thing, &core, &owner, "01", &pairing_key);
+        assert!(check(&other, &thing, &first, &core_id, PAIR_TIME).unwrap_err().errno() == ErrorCode::NEAR_ERROR_UNMATCH);
+        assert!(check(&owner, &thing, &first, &other.to_string(), PAIR_TIME).unwrap_err().errno() == ErrorCode::NEAR_ERROR_UNMATCH);
+        // the proof is for another thing
+        assert!(check(&owner, &self::thing(&core, [6, 5, 4, 3, 2, 1]), &first, &core_id, PAIR_TIME).unwrap_err().errno() == ErrorCode::NEAR_ERROR_UNMATCH);
+        // expired, or it's from the future
+        assert!(check(&owner, &thing, &first, &core_id, PAIR_TIME + PAIRING_PROOF_EXPIRED + 1).unwrap_err().errno() == ErrorCode::NEAR_ERROR_EXPIRED);
+        assert!(check(&owner, &thing, &first, &core_id, PAIR_TIME - 1).unwrap_err().errno() == ErrorCode::NEAR_ERROR_EXPIRED);
+        // it isn't signed by the pairing key
+        let forged = proof(&thing, &core, &owner, "02", &PrivateKey::generate_rsa1024().unwrap());
+        assert!(check(&owner, &thing, &forged, &core_id, PAIR_TIME).unwrap_err().errno() == ErrorCode::NEAR_ERROR_CRYPTO_VERIFY);
+
+        assert_eq!(check(&owner, &thing, &first, &core_id, PAIR_TIME + 1).unwrap(), owner.to_string());
+        // the nonce is used
+        assert!(check(&owner, &thing, &first, &core_id, PAIR_TIME + 2).unwrap_err().errno() == ErrorCode::NEAR_ERROR_REFUSE);
+
+        // the other one pairs the thing again, so it's transferred,
+        // but the proof of the first owner can't be replayed by the other one.
+        let transfer = proof(&thing, &core, &other, "03", &pairing_key);
+        assert!(check(&other, &thing, &first, &core_id, PAIR_TIME + 3).is_err());
+        assert_eq!(check(&other, &thing, &transfer, &core_id, PAIR_TIME + 3).unwrap(), other.to_string());
+        assert!(check(&other, &thing, &transfer, &core_id, PAIR_TIME + 4).unwrap_err().errno() == ErrorCode::NEAR_ERROR_REFUSE);
+    }
+}
@@ -1,14 +1,15 @@
 
 use std::{sync::Arc, path::PathBuf};
 
-use log::trace;
+use log::{trace, error};
 
-use near_core::get_service_path;
+use near_core::{get_data_path, get_service_path};
 use near_base::NearResult;
+use near_util::DESC_SUFFIX_NAME;
 
 use common::{RuntimeProcessTrait, RuntimeStack};
 
-use storage::StorageTrait;
+use storage::{StorageTrait, SeriesStorageTrait};
 use storage::sqlite_storage::SqliteStorage;
 use topic_util::topics::hci_storage::*;
 
@@ -16,6 +17,9 @@ use crate::caches::brand::BrandItem;
 use crate::caches::product::ProductItem;
 use crate::caches::schedule::ScheduleItem;
 use crate::caches::thing::ThingItem;
+use crate::caches::telemetry::{TelemetrySampleItem, TelemetryAggregateItem, TelemetryWatermarkItem};
+use crate::caches::location::LocationItem;
+use crate::caches::firmware::FirmwareItem;
 use crate::routines::brand::add_brand::AddBrandRoutine;
 use crate::routines::brand::query_all_brand::QueryAllBrandRoutine;
 use crate::routines::brand::query_brand::QueryBrandRoutine;
@@ -34,16 +38,38 @@ use crate::routines::thing::query_thingobject::QueryThingObjectRoutine;
 use crate::routines::thing::query_multiple_thingobject::QueryMultipleThingObjectRoutine;
 use crate::routines::thing::remove::RemoveThingRoutine;
 use crate::routines::thing::update::UpdateThingRoutine;
+use crate::routines::thing::query_capability::QueryThingCapabilityRoutine;
 use crate::routines::product::remove::RemoveProductRoutine;
 use crate::routines::product::add::AddProductRoutine;
 use crate::routines::product::query::QueryProductRoutine;
 use crate::routines::product::query_all::QueryAllProductRoutine;
+use crate::routines::product::update_capability::UpdateProductCapabilityRoutine;
+use crate::routines::telemetry::append::AppendTelemetryRoutine;
+use crate::routines::telemetry::query::QueryTelemetryRoutine;
+use crate::routines::location::add::AddLocationRoutine;
+use crate::routines::location::update::UpdateLocationRoutine;
+use crate::routines::location::remove::RemoveLocationRoutine;
+use crate::routines::location::query::QueryLocationRoutine;
+use crate::routines::location::query_all::QueryAllLocationRoutine;
+use crate::routines::location::update_things::UpdateLocationThingsRoutine;
+use crate::routines::location::query_things::QueryLocationThingsRoutine;
+use crate::routines::firmware::add::AddFirmwareRoutine;
+use crate::routines::firmware::remove::RemoveFirmwareRoutine;
+use crate::routines::firmware::query::QueryFirmwareRoutine;
+use crate::routines::firmware::query_all::QueryAllFirmwareRoutine;
+use crate::routines::audit::append::AppendAuditRoutine;
+use crate::routines::audit::query::QueryAuditRoutine;
+use crate::audit::{AuditLog, AUDIT_TABLE, AUDIT_HEAD_TABLE, AUDIT_KEY_FILE, AUDIT_WRITER};
+use crate::pairing::PairingVerifier;
+use crate::telemetry::{TelemetryConfig, TelemetryStorage, 
+                       TELEMETRY_RAW_TABLE, TELEMETRY_MINUTE_TABLE, TELEMETRY_HOUR_TABLE, TELEMETRY_DAY_TABLE, TELEMETRY_WATERMARK_TABLE};
 
 #[derive(Clone)]
 #[allow(unused)]
 pub(crate) struct Config {
     pub(crate) work_path: PathBuf,
     pub(crate) thing_data_path: PathBuf,
+    pub(crate) telemetry: TelemetryConfig,
 }
 
 struct ProcessComponents {
@@ -51,6 +77,15 @@ struct ProcessComponents {
     product_storage: Box<dyn StorageTrait<ProductItem>>,
     thing_storage: Box<dyn StorageTrait<ThingItem>>,
     schedule_storage: Box<dyn StorageTrait<ScheduleItem>>,
+    telemetry_raw_storage: Box<dyn SeriesStorageTrait<TelemetrySampleItem>>,
+    telemetry_minute_storage: Box<dyn SeriesStorageTrait<TelemetryAggregateItem>>,
+    telemetry_hour_storage: Box<dyn SeriesStorageTrait<TelemetryAggregateItem>>,
+    telemetry_day_storage: Box<dyn SeriesStorageTrait<TelemetryAggregateItem>>,
+    telemetry_watermark_storage: Box<dyn StorageTrait<TelemetryWatermarkItem>>,
+    location_storage: Box<dyn StorageTrait<LocationItem>>,
+    firmware_storage: Box<dyn StorageTrait<FirmwareItem>>,
+    audit_log: AuditLog,
+    pairing_verifier: PairingVerifier,
 }
 
 struct ProcessImpl {
@@ -77,6 +112,7 @@ impl Process {
                     thing_data_path
                 },
                 work_path: work_path,
+                telemetry: TelemetryConfig::default(),
             }    
         };
 
@@ -93,6 +129,18 @@ impl Process {
             product_storage: ret.0.storage.add_storage("product").await?,
             thing_storage: ret.0.storage.add_storage("thing").await?,
             schedule_storage: ret.0.storage.add_storage("schedule").await?,
+            telemetry_raw_storage: ret.0.storage.add_series_storage(TELEMETRY_RAW_TABLE).await?,
+            telemetry_minute_storage: ret.0.storage.add_series_storage(TELEMETRY_MINUTE_TABLE).await?,
+            telemetry_hour_storage: ret.0.storage.add_series_storage(TELEMETRY_HOUR_TABLE).await?,
+            telemetry_day_storage: ret.0.storage.add_series_storage(TELEMETRY_DAY_TABLE).await?,
+            telemetry_watermark_storage: ret.0.storage.add_storage(TELEMETRY_WATERMARK_TABLE).await?,
+            location_storage: ret.0.storage.add_storage("location").await?,
+            firmware_storage: ret.0.storage.add_storage("firmware").await?,
+            audit_log: AuditLog::new(config.work_path.join(AUDIT_KEY_FILE).as_path(),
+                                     get_data_path().join(format!("{AUDIT_WRITER}.{DESC_SUFFIX_NAME}")).as_path(),
+                                     ret.0.storage.add_series_storage(AUDIT_TABLE).await?,
+                                     ret.0.storage.add_storage(AUDIT_HEAD_TABLE).await?)?,
+            pairing_verifier: PairingVerifier::new(),
         });
 
         Ok(Box::new(ret))
@@ -127,6 +175,41 @@ impl Process {
     pub(crate) fn schedule_storage(&self) -> &dyn StorageTrait<ScheduleItem> {
         self.0.components.as_ref().unwrap().schedule_storage.as_ref()
     }
+
+    #[inline]
+    pub(crate) fn telemetry_storage(&self) -> TelemetryStorage<'_> {
+        let components = self.0.components.as_ref().unwrap();
+
+        TelemetryStorage {
+            config: &self.0.config.telemetry,
+            raw: components.telemetry_raw_storage.as_ref(),
+            minute: components.telemetry_minute_storage.as_ref(),
+            hour: components.telemetry_hour_storage.as_ref(),
+            day: components.telemetry_day_storage.as_ref(),
+            watermark: components.telemetry_watermark_storage.as_ref(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn location_storage(&self) -> &dyn StorageTrait<LocationItem> {
+        self.0.components.as_ref().unwrap().location_storage.as_ref()
+    }
+
+    #[inline]
+    pub(crate) fn firmware_storage(&self) -> &dyn StorageTrait<FirmwareItem> {
+        self.0.components.as_ref().unwrap().firmware_storage.as_ref()
+    }
+
+    #[inline]
+    pub(crate) fn audit_log(&self) -> &AuditLog {
+        &self.0.components.as_ref().unwrap().audit_log
+    }
+
+    #[inline]
+    pub(crate) fn pairing_verifier(&self) -> &PairingVerifier {
+        &self.0.components.as_ref().unwrap().pairing_verifier
+    }
+
     // #[inline]
     // pub(crate) fn product_manager(&self) -> &Manager<ProductItem> {
     //     &self.0.components.as_ref().unwrap().product_manager
@@ -402,6 +485,19 @@ impl Process {
                 )?;
         }
 
+        {
+            // update product capability
+            let arc_self = self.clone();
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    NEAR_THING_STORAGE_PRODUCT_CAPABILITY_UPDATE_PUB.topic(), 
+                        move || {
+                        Ok(UpdateProductCapabilityRoutine::new(arc_self.clone()))
+                    }
+                )?;
+        }
+
         Ok(())
     }
 
@@ -484,6 +580,19 @@ impl Process {
                 )?;
         }
 
+        {
+            // query capability of things
+            let arc_self = self.clone();
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    NEAR_THING_STORAGE_THING_CAPABILITY_QUERY_PUB.topic(), 
+                        move || {
+                        Ok(QueryThingCapabilityRoutine::new(arc_self.clone()))
+                    }
+                )?;
+        }
+
         Ok(())
     }
 
@@ -595,12 +704,227 @@ impl Process {
         Ok(())
     }
 
+    pub(self) async fn subscribe_telemetry_topic(&self) -> NearResult<()> {
+        {
+            // append telemetry
+            let arc_self = self.clone();
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    NEAR_THING_STORAGE_TELEMETRY_APPEND_PUB.topic(), 
+                        move || { 
+                        Ok(AppendTelemetryRoutine::new(arc_self.clone()))
+                    }
+                )?;
+        }
+
+        {
+            // query telemetry
+            let arc_self = self.clone();
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    NEAR_THING_STORAGE_TELEMETRY_QUERY_PUB.topic(), 
+                        move || { 
+                        Ok(QueryTelemetryRoutine::new(arc_self.clone()))
+                    }
+                )?;
+        }
+
+        Ok(())
+    }
+
+    pub(self) async fn subscribe_location_topic(&self) -> NearResult<()> {
+        {
+            // add location
+            let arc_self = self.clone();
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    NEAR_THING_STORAGE_LOCATION_ADD_PUB.topic(), 
+                        move || { 
+                        Ok(AddLocationRoutine::new(arc_self.clone()))
+                    }
+                )?;
+        }
+
+        {
+            // update location
+            let arc_self = self.clone();
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    NEAR_THING_STORAGE_LOCATION_UPDATE_PUB.topic(), 
+                        move || { 
+                        Ok(UpdateLocationRoutine::new(arc_self.clone()))
+                    }
+                )?;
+        }
+
+        {
+            // remove location
+            let arc_self = self.clone();
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    NEAR_THING_STORAGE_LOCATION_REMOVE_PUB.topic(), 
+                        move || { 
+                        Ok(RemoveLocationRoutine::new(arc_self.clone()))
+                    }
+                )?;
+        }
+
+        {
+            // query location
+            let arc_self = self.clone();
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    NEAR_THING_STORAGE_LOCATION_QUERY_PUB.topic(), 
+                        move || { 
+                        Ok(QueryLocationRoutine::new(arc_self.clone()))
+                    }
+                )?;
+        }
+
+        {
+            // query all location
+            let arc_self = self.clone();
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    NEAR_THING_STORAGE_LOCATION_QUERY_ALL_PUB.topic(), 
+                        move || { 
+                        Ok(QueryAllLocationRoutine::new(arc_self.clone()))
+                    }
+                )?;
+        }
+
+        {
+            // insert or remove things of location
+            let arc_self = self.clone();
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    NEAR_THING_STORAGE_LOCATION_THINGS_UPDATE_PUB.topic(), 
+                        move || { 
+                        Ok(UpdateLocationThingsRoutine::new(arc_self.clone()))
+                    }
+                )?;
+        }
+
+        {
+            // query things of location and its descendants
+            let arc_self = self.clone();
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    NEAR_THING_STORAGE_LOCATION_THINGS_QUERY_PUB.topic(), 
+                        move || { 
+                        Ok(QueryLocationThingsRoutine::new(arc_self.clone()))
+                    }
+                )?;
+        }
+
+        Ok(())
+    }
+
+    pub(self) async fn subscribe_firmware_topic(&self) -> NearResult<()> {
+        {
+            // add firmware
+            let arc_self = self.clone();
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    NEAR_THING_STORAGE_FIRMWARE_ADD_PUB.topic(), 
+                        move || { 
+                        Ok(AddFirmwareRoutine::new(arc_self.clone()))
+                    }
+                )?;
+        }
+
+        {
+            // remove firmware
+            let arc_self = self.clone();
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    NEAR_THING_STORAGE_FIRMWARE_REMOVE_PUB.topic(), 
+                        move || { 
+                        Ok(RemoveFirmwareRoutine::new(arc_self.clone()))
+                    }
+                )?;
+        }
+
+        {
+            // query firmware, the latest one if the version is empty
+            let arc_self = self.clone();
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    NEAR_THING_STORAGE_FIRMWARE_QUERY_PUB.topic(), 
+                        move || { 
+                        Ok(QueryFirmwareRoutine::new(arc_self.clone()))
+                    }
+                )?;
+        }
+
+        {
+            // query all firmware of the product
+            let arc_self = self.clone();
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    NEAR_THING_STORAGE_FIRMWARE_QUERY_ALL_PUB.topic(), 
+                        move || { 
+                        Ok(QueryAllFirmwareRoutine::new(arc_self.clone()))
+                    }
+                )?;
+        }
+
+        Ok(())
+    }
+
+    pub(self) async fn subscribe_audit_topic(&self) -> NearResult<()> {
+        {
+            // append audit record
+            let arc_self = self.clone();
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    NEAR_THING_STORAGE_AUDIT_APPEND_PUB.topic(), 
+                        move || { 
+                        Ok(AppendAuditRoutine::new(arc_self.clone()))
+                    }
+                )?;
+        }
+
+        {
+            // query audit records
+            let arc_self = self.clone();
+            RuntimeStack::get_instance()
+                .topic_routine_manager()
+                .register_public_topic(
+                    NEAR_THING_STORAGE_AUDIT_QUERY_PUB.topic(), 
+                        move || { 
+                        Ok(QueryAuditRoutine::new(arc_self.clone()))
+                    }
+                )?;
+        }
+
+        Ok(())
+    }
+
     pub(self) async fn subscribe_topic(&self) -> NearResult<()> {
         // self.subscribe_pub_topic().await?;
         self.subscribe_brand_topic().await?;
         self.subscribe_product_topic().await?;
         self.subscribe_thing_topic().await?;
         self.subscribe_schedule_topic().await?;
+        self.subscribe_telemetry_topic().await?;
+        self.subscribe_location_topic().await?;
+        self.subscribe_firmware_topic().await?;
+        self.subscribe_audit_topic().await?;
 
         Ok(())
     }
@@ -617,6 +941,22 @@ impl Process {
 //     }
 // }
 
+impl Process {
+    pub(self) fn start_telemetry_rollup(&self) {
+        let arc_self = self.clone();
+
+        async_std::task::spawn(async move {
+            loop {
+                let _ = async_std::future::timeout(arc_self.config().telemetry.rollup_interval, async_std::future::pending::<()>()).await;
+
+                if let Err(e) = arc_self.telemetry_storage().rollup(near_base::now()).await {
+                    error!("failed rollup telemetry with err: {e}");
+                }
+            }
+        });
+    }
+}
+
 #[async_trait::async_trait]
 impl RuntimeProcessTrait for Process {
 
@@ -628,6 +968,8 @@ impl RuntimeProcessTrait for Process {
 
         self.subscribe_topic().await?;
 
+        self.start_telemetry_rollup();
+
         Ok(())
     }
 
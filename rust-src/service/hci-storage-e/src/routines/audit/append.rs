@@ -0,0 +1,75 @@
+
+use log::{trace, error};
+
+use near_base::{ErrorCode, NearError, NearResult, builder_codec_macro::Empty, };
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineEventTrait, RoutineWrap};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_decode_raw_object, try_encode_raw_object, hci::audit::Audit_record, };
+
+use crate::process::Process;
+
+pub struct AppendAuditRoutine {
+    process: Process,
+}
+
+impl AppendAuditRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(AppendAuditRoutine{
+            process
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for AppendAuditRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("AppendAuditRoutine::on_routine: header_meta={header_meta}");
+
+        let r = try_decode_raw_object!(Audit_record, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Empty> = match r {
+            DataContent::Content(record) => 
+                self.on_routine(header_meta, record).await.into(),
+            DataContent::Error(e) => DataContent::Error(e),
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl AppendAuditRoutine {
+    /// The record is only appended by hci-gateway, the requestor is the creator of the request
+    /// which is forwarded by it.
+    async fn on_routine(&self, header_meta: &HeaderMeta, mut record: Audit_record) -> NearResult<Empty> {
+        let audit_log = self.process.audit_log();
+
+        audit_log.check_writer(&header_meta.requestor)
+            .map_err(| e | {
+                error!("{e}, sequence: {}", header_meta.sequence());
+                e
+            })?;
+
+        let requestor =
+            header_meta.creator
+                .as_ref()
+                .and_then(| creator | creator.creator.as_ref())
+                .ok_or_else(|| {
+                    let error_string = "missing the creator of the request";
+                    error!("{error_string}, sequence: {}", header_meta.sequence());
+                    NearError::new(ErrorCode::NEAR_ERROR_MISSING_DATA, error_string)
+                })?;
+
+        record.requestor = requestor.to_string();
+        record.sequence = header_meta.sequence().to_string();
+
+        audit_log
+            .append(record)
+            .await
+            .map(| _ | Empty)
+            .map_err(| e | {
+                error!("{e}, sequence: {}", header_meta.sequence());
+                e
+            })
+    }
+}
@@ -0,0 +1,67 @@
+
+use log::{trace, error};
+
+use near_base::{NearResult, RawConvertTo};
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineWrap, RoutineEventTrait};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_decode_raw_object, try_encode_raw_object, 
+             hci::audit::{Audit_query, Audit_record_list}, };
+
+use crate::process::Process;
+
+pub struct QueryAuditRoutine {
+    process: Process,
+}
+
+impl QueryAuditRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(Self{
+            process,
+        }))
+    }
+
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for QueryAuditRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("QueryAuditRoutine: header_meta={header_meta}.");
+
+        let r = try_decode_raw_object!(Audit_query, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Audit_record_list> = match r {
+            DataContent::Content(query) => self.on_routine(header_meta, query).await.into(),
+            DataContent::Error(e) => DataContent::Error(e),
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl QueryAuditRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, query: Audit_query) -> NearResult<Audit_record_list> {
+
+        let end = if query.end() == 0 { near_base::now() } else { query.end() };
+        let thing_id = if query.thing_id().is_empty() { None } else { Some(query.thing_id()) };
+
+        let audit_log = self.process.audit_log();
+
+        let records = 
+            audit_log
+                .query(thing_id, query.begin(), end)
+                .await
+                .map_err(| e | {
+                    error!("{e}, sequence: {}", header_meta.sequence());
+                    e
+                })?;
+
+        trace!("query {} audit records of {:?}", records.len(), thing_id);
+
+        Ok(Audit_record_list {
+            records,
+            public_key: audit_log.public_key().to_vec()?,
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,81 @@
+use log::{trace, error, info};
+
+use near_base::{NearResult, NearError, ErrorCode};
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineEventTrait, RoutineWrap};
+
+use base::raw_object::RawObjectGuard;
+use protos::{hci::firmware::{Firmware_add, Firmware_info}, DataContent, try_decode_raw_object, try_encode_raw_object};
+
+use crate::{process::Process, caches::firmware::FirmwareItem};
+
+pub struct AddFirmwareRoutine {
+    process: Process,
+}
+
+impl AddFirmwareRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(AddFirmwareRoutine{
+            process
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for AddFirmwareRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("AddFirmwareRoutine::on_routine: header_meta={header_meta}");
+
+        let r = try_decode_raw_object!(Firmware_add, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Firmware_info> = match r {
+            DataContent::Content(firmware) => self.on_routine(header_meta, firmware).await.into(),
+            DataContent::Error(e) => DataContent::Error(e)
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl AddFirmwareRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, mut firmware: Firmware_add) -> NearResult<Firmware_info> {
+
+        // the child product is saved in its parent.
+        let found = 
+            self.process
+                .product_storage()
+                .load()
+                .await
+                .map_err(| e | {
+                    error!("{e}, sequence: {}", header_meta.sequence());
+                    e
+                })?
+                .iter()
+                .any(| product | product.contains(firmware.product_id()));
+
+        if !found {
+            let error_string = format!("Not found [{}] product", firmware.product_id());
+            error!("{error_string}, sequence: {}", header_meta.sequence());
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_NOTFOUND, error_string));
+        }
+
+        let firmware = 
+            FirmwareItem::create_new(firmware.take_product_id(), firmware.take_version(), firmware.take_file(), firmware.take_release_note())
+                .map_err(| e | {
+                    error!("{e}, sequence: {}", header_meta.sequence());
+                    e
+                })?;
+
+        self.process
+            .firmware_storage()
+            .create_new(&firmware)
+            .await
+            .map_err(| e | {
+                error!("faild add [{}] firmware of [{}] with err: {e}, sequence: {}.", firmware.version(), firmware.product_id(), header_meta.sequence());
+                e
+            })?;
+
+        info!("Successfully add [{}] firmware of [{}], file: {}, sequence: {}.", firmware.version(), firmware.product_id(), firmware.file_id(), header_meta.sequence());
+
+        Ok(firmware.take())
+    }
+}
@@ -0,0 +1,5 @@
+
+pub mod add;
+pub mod remove;
+pub mod query;
+pub mod query_all;
@@ -0,0 +1,61 @@
+use log::{trace, error};
+
+use near_base::{NearResult, NearError, ErrorCode};
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineWrap, RoutineEventTrait};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_decode_raw_object, try_encode_raw_object, hci::firmware::{Firmware_query, Firmware_info}};
+
+use crate::{process::Process, caches::firmware::select_firmware};
+
+pub struct QueryFirmwareRoutine {
+    process: Process,
+}
+
+impl QueryFirmwareRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(Self{
+            process,
+        }))
+    }
+
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for QueryFirmwareRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("QueryFirmwareRoutine: header_meta={header_meta}.");
+
+        let r = try_decode_raw_object!(Firmware_query, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Firmware_info> = match r {
+            DataContent::Content(query) => self.on_routine(header_meta, query).await.into(),
+            DataContent::Error(e) => DataContent::Error(e),
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl QueryFirmwareRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, query: Firmware_query) -> NearResult<Firmware_info> {
+
+        let firmwares = 
+            self.process
+                .firmware_storage()
+                .load()
+                .await
+                .map_err(| e | {
+                    error!("{e}, sequence: {}", header_meta.sequence());
+                    e
+                })?;
+
+        select_firmware(&firmwares, query.product_id(), query.version())
+            .map(| firmware | firmware.clone().take())
+            .ok_or_else(|| {
+                let error_string = format!("Not found [{}] firmware of [{}] product", query.version(), query.product_id());
+                error!("{error_string}, sequence: {}", header_meta.sequence());
+                NearError::new(ErrorCode::NEAR_ERROR_NOTFOUND, error_string)
+            })
+    }
+}
@@ -0,0 +1,65 @@
+use log::{trace, error};
+
+use near_base::NearResult;
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineWrap, RoutineEventTrait};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_decode_raw_object, try_encode_raw_object, hci::firmware::{Firmware_query_all, Firmware_info_list}};
+
+use crate::process::Process;
+
+pub struct QueryAllFirmwareRoutine {
+    process: Process,
+}
+
+impl QueryAllFirmwareRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(Self{
+            process,
+        }))
+    }
+
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for QueryAllFirmwareRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("QueryAllFirmwareRoutine: header_meta={header_meta}.");
+
+        let r = try_decode_raw_object!(Firmware_query_all, req, o, { o.take_product_id() }, { header_meta.sequence() });
+
+        let r: DataContent<Firmware_info_list> = match r {
+            DataContent::Content(product_id) => self.on_routine(header_meta, product_id).await.into(),
+            DataContent::Error(e) => DataContent::Error(e),
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl QueryAllFirmwareRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, product_id: String) -> NearResult<Firmware_info_list> {
+
+        let mut firmwares: Vec<_> = 
+            self.process
+                .firmware_storage()
+                .load()
+                .await
+                .map_err(| e | {
+                    error!("{e}, sequence: {}", header_meta.sequence());
+                    e
+                })?
+                .into_iter()
+                .filter(| firmware | product_id.is_empty() || firmware.product_id() == product_id)
+                .map(| firmware | firmware.take())
+                .collect();
+
+        // the latest one is the first.
+        firmwares.sort_by(| a, b | b.create_timestamp().cmp(&a.create_timestamp()));
+
+        Ok(Firmware_info_list {
+            firmwares,
+            ..Default::default()
+        })
+    }
+}
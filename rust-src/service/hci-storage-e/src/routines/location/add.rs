@@ -0,0 +1,78 @@
+use log::{trace, error};
+
+use near_base::NearResult;
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineEventTrait, RoutineWrap};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_decode_raw_object, try_encode_raw_object, hci::location::{Location_add, Location_info}};
+
+use crate::{process::Process, caches::location::LocationItem};
+
+pub struct AddLocationRoutine {
+    process: Process,
+}
+
+impl AddLocationRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(AddLocationRoutine{
+            process
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for AddLocationRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("AddLocationRoutine::on_routine: header_meta={header_meta}");
+
+        let r = try_decode_raw_object!(Location_add, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Location_info> = match r {
+            DataContent::Content(location) => self.on_routine(header_meta, location).await.into(),
+            DataContent::Error(e) => DataContent::Error(e)
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl AddLocationRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, mut location: Location_add) -> NearResult<Location_info> {
+
+        let parent = {
+            let parent_id = location.parent_id().trim();
+            if parent_id.is_empty() {
+                None
+            } else {
+                Some(
+                    self.process
+                        .location_storage()
+                        .load_with_prefix(parent_id)
+                        .await
+                        .map_err(| e | {
+                            error!("Not found [{parent_id}] location, sequence: {}.", header_meta.sequence());
+                            e
+                        })?
+                )
+            }
+        };
+
+        let location = 
+            LocationItem::create_new(parent.as_ref(), location.type_(), location.take_location_name(), location.location_img_idx())
+                .map_err(| e | {
+                    error!("failed create location with err: {e}, sequence: {}.", header_meta.sequence());
+                    e
+                })?;
+
+        self.process
+            .location_storage()
+            .create_new(&location)
+            .await
+            .map_err(| e | {
+                error!("failed create [{}] with err: {e}, sequence: {}.", location.location_id(), header_meta.sequence());
+                e
+            })?;
+
+        Ok(location.take())
+    }
+}
@@ -0,0 +1,8 @@
+
+pub mod add;
+pub mod update;
+pub mod remove;
+pub mod query;
+pub mod query_all;
+pub mod update_things;
+pub mod query_things;
@@ -0,0 +1,53 @@
+use log::{trace, info};
+
+use near_base::NearResult;
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineWrap, RoutineEventTrait};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_decode_raw_object, try_encode_raw_object, hci::location::Location_info};
+
+use crate::process::Process;
+
+pub struct QueryLocationRoutine {
+    process: Process,
+}
+
+impl QueryLocationRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(Self{
+            process,
+        }))
+    }
+
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for QueryLocationRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("QueryLocationRoutine: header_meta={header_meta}.");
+
+        let r = try_decode_raw_object!(String, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Location_info> = match r {
+            DataContent::Content(location_id) => self.on_routine(header_meta, location_id).await.into(),
+            DataContent::Error(e) => DataContent::Error(e),
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl QueryLocationRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, location_id: String) -> NearResult<Location_info> {
+
+        self.process
+            .location_storage()
+            .load_with_prefix(&location_id)
+            .await
+            .map(| location | location.take())
+            .map_err(| e | {
+                info!("{e}, sequence = {}", header_meta.sequence());
+                e
+            })
+    }
+}
@@ -0,0 +1,62 @@
+use log::{trace, error};
+
+use near_base::NearResult;
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineWrap, RoutineEventTrait};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_decode_raw_object, try_encode_raw_object, hci::location::{Location_query_all, Location_info_list}};
+
+use crate::process::Process;
+
+pub struct QueryAllLocationRoutine {
+    process: Process,
+}
+
+impl QueryAllLocationRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(Self{
+            process,
+        }))
+    }
+
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for QueryAllLocationRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("QueryAllLocationRoutine: header_meta={header_meta}.");
+
+        let r = try_decode_raw_object!(Location_query_all, req, o, { o.take_parent_id() }, { header_meta.sequence() });
+
+        let r: DataContent<Location_info_list> = match r {
+            DataContent::Content(parent_id) => self.on_routine(header_meta, parent_id).await.into(),
+            DataContent::Error(e) => DataContent::Error(e),
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl QueryAllLocationRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, parent_id: String) -> NearResult<Location_info_list> {
+
+        let locations = 
+            self.process
+                .location_storage()
+                .load()
+                .await
+                .map_err(| e | {
+                    error!("{e}, sequence: {}", header_meta.sequence());
+                    e
+                })?
+                .into_iter()
+                .filter(| location | parent_id.is_empty() || location.parent_id() == parent_id)
+                .map(| location | location.take())
+                .collect();
+
+        Ok(Location_info_list {
+            locations,
+            ..Default::default()
+        })
+    }
+}
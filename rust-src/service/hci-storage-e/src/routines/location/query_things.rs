@@ -0,0 +1,65 @@
+use log::{trace, error};
+
+use near_base::NearResult;
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineWrap, RoutineEventTrait};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_decode_raw_object, try_encode_raw_object, };
+
+use crate::{process::Process, caches::location::collect_things};
+
+pub struct QueryLocationThingsRoutine {
+    process: Process,
+}
+
+impl QueryLocationThingsRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(Self{
+            process,
+        }))
+    }
+
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for QueryLocationThingsRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("QueryLocationThingsRoutine: header_meta={header_meta}.");
+
+        let r = try_decode_raw_object!(String, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Vec<String>> = match r {
+            DataContent::Content(location_id) => self.on_routine(header_meta, location_id).await.into(),
+            DataContent::Error(e) => DataContent::Error(e),
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl QueryLocationThingsRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, location_id: String) -> NearResult<Vec<String>> {
+
+        let locations = 
+            self.process
+                .location_storage()
+                .load()
+                .await
+                .map_err(| e | {
+                    error!("{e}, sequence: {}", header_meta.sequence());
+                    e
+                })?;
+
+        // make sure the location is exist.
+        self.process
+            .location_storage()
+            .load_with_prefix(&location_id)
+            .await
+            .map_err(| e | {
+                error!("{e}, sequence: {}", header_meta.sequence());
+                e
+            })?;
+
+        Ok(collect_things(&locations, &location_id))
+    }
+}
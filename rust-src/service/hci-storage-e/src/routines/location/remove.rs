@@ -0,0 +1,71 @@
+use log::{trace, error};
+
+use near_base::{NearResult, NearError, ErrorCode, builder_codec_macro::Empty, };
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineEventTrait, RoutineWrap};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_encode_raw_object, try_decode_raw_object, };
+
+use crate::process::Process;
+
+pub struct RemoveLocationRoutine {
+    process: Process,
+}
+
+impl RemoveLocationRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(RemoveLocationRoutine{
+            process
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for RemoveLocationRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("RemoveLocationRoutine::on_routine: header_meta={header_meta}");
+
+        let r = try_decode_raw_object!(String, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Empty> = match r {
+            DataContent::Content(location_id) => 
+                self.on_routine(header_meta, location_id).await.into(),
+            DataContent::Error(e) => DataContent::Error(e),
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl RemoveLocationRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, location_id: String) -> NearResult<Empty> {
+
+        let has_children = 
+            self.process
+                .location_storage()
+                .load()
+                .await
+                .map_err(| e | {
+                    error!("{e}, sequence: {}", header_meta.sequence());
+                    e
+                })?
+                .iter()
+                .any(| location | location.parent_id() == location_id);
+
+        if has_children {
+            let error_string = format!("[{location_id}] location has children, remove them at first.");
+            error!("{error_string}, sequence: {}", header_meta.sequence());
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_REFUSE, error_string));
+        }
+
+        self.process
+            .location_storage()
+            .delete_with_prefix(&location_id)
+            .await
+            .map(| _ | Empty)
+            .map_err(| e | {
+                error!("{e}, sequence: {}", header_meta.sequence());
+                e
+            })
+    }
+}
@@ -0,0 +1,69 @@
+use log::{trace, error};
+
+use near_base::NearResult;
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineEventTrait, RoutineWrap};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_decode_raw_object, try_encode_raw_object, hci::location::Location_info};
+
+use crate::process::Process;
+
+pub struct UpdateLocationRoutine {
+    process: Process,
+}
+
+impl UpdateLocationRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(UpdateLocationRoutine{
+            process
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for UpdateLocationRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("UpdateLocationRoutine::on_routine: header_meta={header_meta}");
+
+        let r = try_decode_raw_object!(Location_info, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Location_info> = match r {
+            DataContent::Content(location) => self.on_routine(header_meta, location).await.into(),
+            DataContent::Error(e) => DataContent::Error(e)
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl UpdateLocationRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, mut new_location: Location_info) -> NearResult<Location_info> {
+
+        let mut location = 
+            self.process
+                .location_storage()
+                .load_with_prefix(new_location.location_id())
+                .await
+                .map_err(| e | {
+                    error!("{e}, sequence: {}", header_meta.sequence());
+                    e
+                })?;
+
+        let location_name = new_location.take_location_name();
+        if !location_name.trim().is_empty() {
+            location.update_name(location_name.trim().to_owned());
+        }
+        location.update_img_index(new_location.location_img_idx());
+
+        self.process
+            .location_storage()
+            .update(&location)
+            .await
+            .map_err(| e | {
+                error!("{e}, sequence: {}", header_meta.sequence());
+                e
+            })?;
+
+        Ok(location.take())
+    }
+}
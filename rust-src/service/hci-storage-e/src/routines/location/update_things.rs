@@ -0,0 +1,124 @@
+use log::{trace, error, info};
+
+use near_base::{NearResult, NearError, ErrorCode};
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineEventTrait, RoutineWrap};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_decode_raw_object, try_encode_raw_object, 
+             hci::location::{Location_info, Location_thing_update, location_thing_update::Location_thing_op}};
+use storage::ItemTrait;
+
+use crate::process::Process;
+
+pub struct UpdateLocationThingsRoutine {
+    process: Process,
+}
+
+impl UpdateLocationThingsRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(UpdateLocationThingsRoutine{
+            process
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for UpdateLocationThingsRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("UpdateLocationThingsRoutine::on_routine: header_meta={header_meta}");
+
+        let r = try_decode_raw_object!(Location_thing_update, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Location_info> = match r {
+            DataContent::Content(update) => self.on_routine(header_meta, update).await.into(),
+            DataContent::Error(e) => DataContent::Error(e)
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl UpdateLocationThingsRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, update: Location_thing_update) -> NearResult<Location_info> {
+
+        let mut location = 
+            self.process
+                .location_storage()
+                .load_with_prefix(update.location_id())
+                .await
+                .map_err(| e | {
+                    error!("{e}, sequence: {}", header_meta.sequence());
+                    e
+                })?;
+
+        if !location.is_container() {
+            let error_string = format!("{:?} can't hold things.", location.location_type());
+            error!("{error_string}, sequence: {}", header_meta.sequence());
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_INCORRECT_USE, error_string));
+        }
+
+        match update.op() {
+            Location_thing_op::insert => {
+                for thing_id in update.thing_ids.iter() {
+                    self.process
+                        .thing_storage()
+                        .load_with_prefix(thing_id)
+                        .await
+                        .map_err(| e | {
+                            error!("{e}, sequence: {}", header_meta.sequence());
+                            e
+                        })?;
+                }
+
+                // a thing only belongs to one room, the zones overlay the rooms.
+                let others = 
+                    if location.is_exclusive() {
+                        self.process
+                            .location_storage()
+                            .load()
+                            .await
+                            .map_err(| e | {
+                                error!("{e}, sequence: {}", header_meta.sequence());
+                                e
+                            })?
+                    } else {
+                        vec![]
+                    };
+
+                for mut other in others {
+                    if other.id() != location.id() && other.is_exclusive() && other.remove_things(update.thing_ids.iter()) {
+                        self.process
+                            .location_storage()
+                            .update(&other)
+                            .await
+                            .map_err(| e | {
+                                error!("{e}, sequence: {}", header_meta.sequence());
+                                e
+                            })?;
+                    }
+                }
+
+                location.insert_things(update.thing_ids.iter());
+            }
+            Location_thing_op::remove => {
+                location.remove_things(update.thing_ids.iter());
+            }
+            Location_thing_op::none => {
+                return Err(NearError::new(ErrorCode::NEAR_ERROR_UNDEFINED, "undefined location op"));
+            }
+        }
+
+        self.process
+            .location_storage()
+            .update(&location)
+            .await
+            .map_err(| e | {
+                error!("{e}, sequence: {}", header_meta.sequence());
+                e
+            })?;
+
+        info!("Successfully update [{}] things, sequence: {}", location.location_id(), header_meta.sequence());
+
+        Ok(location.take())
+    }
+}
@@ -3,3 +3,4 @@ pub mod add;
 pub mod query;
 pub mod query_all;
 pub mod remove;
+pub mod update_capability;
@@ -0,0 +1,83 @@
+use log::{trace, error, info};
+
+use near_base::{NearResult, NearError, ErrorCode};
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineEventTrait, RoutineWrap};
+
+use base::raw_object::RawObjectGuard;
+use protos::{hci::product::{Product_capability_update, Product_info}, DataContent, try_decode_raw_object, try_encode_raw_object};
+
+use crate::{process::Process, caches::product::check_capability};
+
+pub struct UpdateProductCapabilityRoutine {
+    process: Process,
+}
+
+impl UpdateProductCapabilityRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(UpdateProductCapabilityRoutine{
+            process
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for UpdateProductCapabilityRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("UpdateProductCapabilityRoutine::on_routine: header_meta={header_meta}");
+
+        let r = try_decode_raw_object!(Product_capability_update, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Product_info> = match r {
+            DataContent::Content(update) => self.on_routine(header_meta, update).await.into(),
+            DataContent::Error(e) => DataContent::Error(e)
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl UpdateProductCapabilityRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, mut update: Product_capability_update) -> NearResult<Product_info> {
+
+        let capability = update.take_capability();
+
+        check_capability(&capability)
+            .map_err(| e | {
+                error!("{e}, sequence: {}", header_meta.sequence());
+                e
+            })?;
+
+        // the child product is saved in its parent.
+        let mut product = 
+            self.process
+                .product_storage()
+                .load()
+                .await
+                .map_err(| e | {
+                    error!("{e}, sequence: {}", header_meta.sequence());
+                    e
+                })?
+                .into_iter()
+                .find(| product | product.contains(update.product_id()))
+                .ok_or_else(|| {
+                    let error_string = format!("Not found [{}] product", update.product_id());
+                    error!("{error_string}, sequence: {}", header_meta.sequence());
+                    NearError::new(ErrorCode::NEAR_ERROR_NOTFOUND, error_string)
+                })?;
+
+        product.set_capability(update.product_id(), capability);
+
+        self.process
+            .product_storage()
+            .update(&product)
+            .await
+            .map_err(| e | {
+                error!("faild update [{}] with err: {e}, sequence: {}.", product.product_id(), header_meta.sequence());
+                e
+            })?;
+
+        info!("Successfully update [{}] capability, sequence: {}.", update.product_id(), header_meta.sequence());
+
+        Ok(product.take())
+    }
+}
@@ -0,0 +1,58 @@
+use log::{trace, error};
+
+use near_base::{NearResult, builder_codec_macro::Empty, };
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineEventTrait, RoutineWrap};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_decode_raw_object, try_encode_raw_object, hci::telemetry::Thing_telemetry_sample, };
+
+use crate::process::Process;
+
+pub struct AppendTelemetryRoutine {
+    process: Process,
+}
+
+impl AppendTelemetryRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(AppendTelemetryRoutine{
+            process
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for AppendTelemetryRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("AppendTelemetryRoutine::on_routine: header_meta={header_meta}");
+
+        let r = try_decode_raw_object!(Thing_telemetry_sample, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Empty> = match r {
+            DataContent::Content(sample) => 
+                self.on_routine(header_meta, sample).await.into(),
+            DataContent::Error(e) => DataContent::Error(e),
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl AppendTelemetryRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, mut sample: Thing_telemetry_sample) -> NearResult<Empty> {
+
+        if sample.timestamp() == 0 {
+            sample.set_timestamp(near_base::now());
+        }
+
+        self.process
+            .telemetry_storage()
+            .raw
+            .append(&sample.into())
+            .await
+            .map(| _ | Empty)
+            .map_err(| e | {
+                error!("{e}, sequence: {}", header_meta.sequence());
+                e
+            })
+    }
+}
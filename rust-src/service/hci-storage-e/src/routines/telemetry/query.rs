@@ -0,0 +1,101 @@
+use log::{trace, error};
+
+use near_base::NearResult;
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineWrap, RoutineEventTrait};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_decode_raw_object, try_encode_raw_object, 
+             hci::telemetry::{Thing_telemetry_query, Thing_telemetry_series}, };
+
+use crate::process::Process;
+
+pub struct QueryTelemetryRoutine {
+    process: Process,
+}
+
+impl QueryTelemetryRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(Self{
+            process,
+        }))
+    }
+
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for QueryTelemetryRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("QueryTelemetryRoutine: header_meta={header_meta}.");
+
+        let r = try_decode_raw_object!(Thing_telemetry_query, req, o, o, { header_meta.sequence() });
+
+        let r: DataContent<Thing_telemetry_series> = match r {
+            DataContent::Content(query) => self.on_routine(header_meta, query).await.into(),
+            DataContent::Error(e) => DataContent::Error(e),
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl QueryTelemetryRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, query: Thing_telemetry_query) -> NearResult<Thing_telemetry_series> {
+
+        let resolution = query.resolution();
+        let end = if query.end() == 0 { near_base::now() } else { query.end() };
+        let properties = query.properties.as_slice();
+        let wanted = | property: &str | properties.is_empty() || properties.iter().any(| p | p == property);
+
+        let telemetry = self.process.telemetry_storage();
+
+        let mut series = Thing_telemetry_series {
+            thing_id: query.thing_id.clone(),
+            resolution: resolution.into(),
+            ..Default::default()
+        };
+
+        match telemetry.aggregate_storage(resolution) {
+            None => {
+                series.samples = 
+                    telemetry.raw
+                        .load_range(Some(query.thing_id()), query.begin(), end)
+                        .await
+                        .map_err(| e | {
+                            error!("{e}, sequence: {}", header_meta.sequence());
+                            e
+                        })?
+                        .into_iter()
+                        .map(| sample | {
+                            let mut sample = sample.take();
+                            sample.data.retain(| property, _ | wanted(property));
+                            sample
+                        })
+                        .filter(| sample | !sample.data.is_empty())
+                        .collect();
+            }
+            Some(storage) => {
+                series.aggregates = 
+                    storage
+                        .load_range(Some(query.thing_id()), query.begin(), end)
+                        .await
+                        .map_err(| e | {
+                            error!("{e}, sequence: {}", header_meta.sequence());
+                            e
+                        })?
+                        .into_iter()
+                        .map(| aggregates | {
+                            let mut aggregates = aggregates.take();
+                            aggregates.aggregates.retain(| aggregate | wanted(aggregate.property()));
+                            aggregates
+                        })
+                        .filter(| aggregates | !aggregates.aggregates.is_empty())
+                        .collect();
+            }
+        }
+
+        trace!("query {} telemetry with {:?}, {} samples, {} aggregates", 
+               query.thing_id(), resolution, series.samples.len(), series.aggregates.len());
+
+        Ok(series)
+    }
+}
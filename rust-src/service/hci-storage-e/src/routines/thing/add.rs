@@ -3,15 +3,15 @@ use std::{collections::HashMap, path::PathBuf};
 
 use log::{trace, error, warn, info};
 
-use near_base::{ErrorCode, NearError, thing::ThingObject, NearResult, FileEncoder, };
+use near_base::{ErrorCode, NearError, ObjectId, thing::ThingObject, NearResult, FileEncoder, };
 use near_transport::{EventResult, HeaderMeta, Routine, RoutineEventTrait, RoutineWrap};
 
 use base::raw_object::RawObjectGuard;
 use near_util::DESC_SUFFIX_NAME;
-use protos::{hci::thing::{Thing_info, Thing_add}, DataContent, try_decode_raw_object, try_encode_raw_object};
+use protos::{hci::{thing::{Thing_info, Thing_add}, audit::Audit_record}, DataContent, try_decode_raw_object, try_encode_raw_object};
 use storage::ItemTrait;
 
-use crate::{process::Process, caches::thing::ThingItemBuild};
+use crate::{process::Process, caches::thing::ThingItemBuild, pairing::PAIRING_OVERRIDE_ACTION};
 
 pub struct AddThingRoutine {
     process: Process,
@@ -31,10 +31,10 @@ impl Routine<RawObjectGuard, RawObjectGuard> for AddThingRoutine {
     async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
         trace!("AddThingRoutine: header_meta={header_meta}");
 
-        let r = try_decode_raw_object!(Thing_add, req, o, { (o.take_thing(), o.take_thing_data()) }, { header_meta.sequence() });
+        let r = try_decode_raw_object!(Thing_add, req, o, { (o.take_thing(), o.take_thing_data(), o.take_pairing_proof(), o.owner_override) }, { header_meta.sequence() });
 
         let r: DataContent<ThingObject> = match r {
-            DataContent::Content((thing, thing_data)) => self.on_routine(header_meta, thing, thing_data).await.into(),
+            DataContent::Content((thing, thing_data, pairing_proof, owner_override)) => self.on_routine(header_meta, thing, thing_data, pairing_proof, owner_override).await.into(),
             DataContent::Error(e) => DataContent::Error(e),
         };
 
@@ -43,7 +43,16 @@ impl Routine<RawObjectGuard, RawObjectGuard> for AddThingRoutine {
 }
 
 impl AddThingRoutine {
-    async fn on_routine(&self, header_meta: &HeaderMeta, thing: Thing_info, thing_data: HashMap<String, String>) -> NearResult<ThingObject> {
+    async fn on_routine(&self, header_meta: &HeaderMeta, thing: Thing_info, thing_data: HashMap<String, String>, pairing_proof: Vec<u8>, owner_override: bool) -> NearResult<ThingObject> {
+
+        let owner = 
+            header_meta.creator.as_ref()
+                .and_then(| creator | creator.creator.as_ref())
+                .ok_or_else(|| {
+                    let error_string = "missing creator";
+                    error!("{error_string}, sequence: {}", header_meta.sequence());
+                    NearError::new(ErrorCode::NEAR_ERROR_NO_TARGET, error_string)
+                })?;
 
         // check brand
         self.process.brand_storage().load_with_prefix(thing.brand_id()).await
@@ -73,7 +82,7 @@ impl AddThingRoutine {
 
         // add thing
         // build thing object
-        let thing = {
+        let mut thing = {
             ThingItemBuild {
                 brand_id: thing.brand_id(),
                 major_product_id: thing.major_product_id(),
@@ -89,6 +98,25 @@ impl AddThingRoutine {
             })?
         };
 
+        // the thing signed the challenge of hci-service, so it's present and claimed by the owner.
+        // the owner overrides the proof of the thing which can't be challenged, it's never transferred then.
+        let paired_owner_id = 
+            if owner_override {
+                self.override_pairing(header_meta, owner, thing.thing_object()).await?;
+                None
+            } else {
+                self.process
+                    .pairing_verifier()
+                    .verify(owner, thing.thing_object(), &pairing_proof)
+                    .await
+                    .map(Some)
+                    .map_err(| e | {
+                        error!("failed verify the pairing proof of [{}] with err: {e}, sequence: {}", thing.thing().mac_address(), header_meta.sequence());
+                        e
+                    })?
+            };
+        thing.set_owner_id(paired_owner_id.clone().unwrap_or_else(|| owner.to_string()));
+
         let ((thing, thing_object), newly) = 
             match   self.process
                         .thing_storage()
@@ -101,16 +129,31 @@ impl AddThingRoutine {
                     let error_string = format!("[{}] has been exist.", thing.thing().mac_address());
                     warn!("{error_string}, sequence: {}", header_meta.sequence());
 
-                    self.process
-                        .thing_storage()
-                        .load_with_prefix(thing.id()).await
-                        .map(| thing | {
-                            ((thing.split()), false)
-                        })
-                        .map_err(| e | {
-                            error!("{e}, sequence: {}", header_meta.sequence());
-                            e
-                        })
+                    let mut exist = 
+                        self.process
+                            .thing_storage()
+                            .load_with_prefix(thing.id()).await
+                            .map_err(| e | {
+                                error!("{e}, sequence: {}", header_meta.sequence());
+                                e
+                            })?;
+
+                    // the new owner has proved it's pairing the thing, so the thing is transferred.
+                    if let Some(owner_id) = paired_owner_id.filter(| owner_id | exist.thing().owner_id() != owner_id) {
+                        info!("[{}] is transferred from [{}] to [{owner_id}], sequence: {}", exist.id(), exist.thing().owner_id(), header_meta.sequence());
+
+                        exist.set_owner_id(owner_id);
+                        self.process
+                            .thing_storage()
+                            .update(&exist)
+                            .await
+                            .map_err(| e | {
+                                error!("{e}, sequence: {}", header_meta.sequence());
+                                e
+                            })?;
+                    }
+
+                    Ok((exist.split(), false))
                 }
                 Err(e) => {
                     let error_string = format!("failed add [{}] thing with err: {e}", thing.thing().mac_address());
@@ -149,4 +192,33 @@ impl AddThingRoutine {
         Ok(thing_object)
     }
 
+    /// Only hci-gateway overrides the proof after it checked the owner,
+    /// and the thing isn't added if the override isn't recorded.
+    async fn override_pairing(&self, header_meta: &HeaderMeta, owner: &ObjectId, thing: &ThingObject) -> NearResult<()> {
+        let audit_log = self.process.audit_log();
+
+        audit_log.check_writer(&header_meta.requestor)
+            .map_err(| e | {
+                error!("{e}, sequence: {}", header_meta.sequence());
+                e
+            })?;
+
+        warn!("{owner} adds [{}] without the pairing proof, sequence: {}", thing.object_id(), header_meta.sequence());
+
+        audit_log
+            .append(Audit_record {
+                requestor: owner.to_string(),
+                sequence: header_meta.sequence().to_string(),
+                action: PAIRING_OVERRIDE_ACTION.to_owned(),
+                thing_ids: vec![thing.object_id().to_string()],
+                ..Default::default()
+            })
+            .await
+            .map(| _ | ())
+            .map_err(| e | {
+                error!("failed record the pairing override with err: {e}, sequence: {}", header_meta.sequence());
+                e
+            })
+    }
+
 }
@@ -5,4 +5,4 @@ pub mod update;
 pub mod remove;
 pub mod query_thingobject;
 pub mod query_multiple_thingobject;
-
+pub mod query_capability;
@@ -0,0 +1,70 @@
+use log::{trace, error};
+
+use near_base::NearResult;
+use near_transport::{EventResult, HeaderMeta, Routine, RoutineWrap, RoutineEventTrait};
+
+use base::raw_object::RawObjectGuard;
+use protos::{DataContent, try_encode_raw_object, try_decode_raw_object, 
+             hci::product::{Thing_capability_query, Thing_capability_list}, };
+
+use crate::process::Process;
+
+pub struct QueryThingCapabilityRoutine {
+    process: Process,
+}
+
+impl QueryThingCapabilityRoutine {
+    pub fn new(process: Process) -> Box<dyn RoutineEventTrait> {
+        RoutineWrap::new(Box::new(Self{
+            process,
+        }))
+    }
+
+}
+
+#[async_trait::async_trait]
+impl Routine<RawObjectGuard, RawObjectGuard> for QueryThingCapabilityRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, req: RawObjectGuard) -> EventResult<RawObjectGuard> {
+        trace!("QueryThingCapabilityRoutine::on_routine: header_meta={header_meta}.");
+
+        let r = try_decode_raw_object!(Thing_capability_query, req, o, { o.take_thing_ids() }, { header_meta.sequence() });
+
+        let r: DataContent<Thing_capability_list> = match r {
+            DataContent::Content(thing_ids) => self.on_routine(header_meta, thing_ids).await.into(),
+            DataContent::Error(e) => DataContent::Error(e)
+        };
+
+        try_encode_raw_object!(r, { header_meta.sequence() })
+    }
+}
+
+impl QueryThingCapabilityRoutine {
+    async fn on_routine(&self, header_meta: &HeaderMeta, thing_ids: Vec<String>) -> NearResult<Thing_capability_list> {
+
+        let mut capabilities = Thing_capability_list::default();
+
+        for thing_id in thing_ids {
+            let thing = 
+                match self.process.thing_storage().load_with_prefix(&thing_id).await {
+                    Ok(thing) => thing,
+                    Err(e) => {
+                        error!("{e}, sequence: {}", header_meta.sequence());
+                        continue;
+                    }
+                };
+
+            let thing = thing.thing();
+
+            match self.process.product_storage().load_with_prefix(thing.major_product_id()).await {
+                Ok(product) => {
+                    capabilities.capabilities.insert(thing_id, product.capability(thing.minor_product_id()));
+                }
+                Err(e) => {
+                    error!("{e}, sequence: {}", header_meta.sequence());
+                }
+            }
+        }
+
+        Ok(capabilities)
+    }
+}
@@ -45,11 +45,22 @@ impl RemoveThingRoutine {
             .thing_storage()
             .delete_with_prefix(&thing_id)
             .await
-            .map(| _ | Empty)
             .map_err(| e | {
                 error!("{e}, sequence: {}", header_meta.sequence());
                 e
-            })
+            })?;
+
+        let _ = self.process.telemetry_storage().remove_thing(&thing_id).await;
+
+        if let Ok(locations) = self.process.location_storage().load().await {
+            for mut location in locations {
+                if location.remove_things([&thing_id].into_iter()) {
+                    let _ = self.process.location_storage().update(&location).await;
+                }
+            }
+        }
+
+        Ok(Empty)
 
     }
 }
@@ -0,0 +1,357 @@
+
+use std::{collections::BTreeMap, time::Duration};
+
+use log::{trace, error, info};
+
+use near_base::{NearResult, Timestamp, ErrorCode};
+
+use protos::hci::telemetry::*;
+use storage::{SeriesStorageTrait, StorageTrait};
+
+use crate::caches::telemetry::{TelemetryAggregateItem, TelemetrySampleItem, TelemetryWatermarkItem};
+
+const MICROS_PER_MINUTE: Timestamp  = 60 * 1000 * 1000;
+const MICROS_PER_HOUR: Timestamp    = 60 * MICROS_PER_MINUTE;
+const MICROS_PER_DAY: Timestamp     = 24 * MICROS_PER_HOUR;
+
+pub(crate) const TELEMETRY_RAW_TABLE: &str      = "telemetry_raw";
+pub(crate) const TELEMETRY_MINUTE_TABLE: &str   = "telemetry_minute";
+pub(crate) const TELEMETRY_HOUR_TABLE: &str     = "telemetry_hour";
+pub(crate) const TELEMETRY_DAY_TABLE: &str      = "telemetry_day";
+pub(crate) const TELEMETRY_WATERMARK_TABLE: &str = "telemetry_watermark";
+
+/// How long each resolution is kept, and how often the rollup task runs.
+#[derive(Clone)]
+pub(crate) struct TelemetryConfig {
+    pub(crate) raw_retention: Duration,
+    pub(crate) minute_retention: Duration,
+    pub(crate) hour_retention: Duration,
+    pub(crate) day_retention: Duration,
+    pub(crate) rollup_interval: Duration,
+}
+
+impl std::default::Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            raw_retention: Duration::from_secs(2 * 24 * 3600),
+            minute_retention: Duration::from_secs(14 * 24 * 3600),
+            hour_retention: Duration::from_secs(180 * 24 * 3600),
+            day_retention: Duration::from_secs(3 * 365 * 24 * 3600),
+            rollup_interval: Duration::from_secs(60),
+        }
+    }
+}
+
+pub(crate) fn bucket_duration(resolution: Thing_telemetry_resolution) -> Timestamp {
+    match resolution {
+        Thing_telemetry_resolution::Raw => 0,
+        Thing_telemetry_resolution::Minute => MICROS_PER_MINUTE,
+        Thing_telemetry_resolution::Hour => MICROS_PER_HOUR,
+        Thing_telemetry_resolution::Day => MICROS_PER_DAY,
+    }
+}
+
+#[inline]
+fn bucket_begin(timestamp: Timestamp, duration: Timestamp) -> Timestamp {
+    timestamp - timestamp % duration
+}
+
+/// Aggregate the raw samples of a thing which fall into one bucket.
+/// Numeric properties get min/max/avg, the others only keep the last value.
+pub(crate) fn aggregate_samples(thing_id: &str,
+                                begin: Timestamp,
+                                resolution: Thing_telemetry_resolution,
+                                samples: &[Thing_telemetry_sample]) -> Thing_telemetry_aggregate_list {
+    let end = begin + bucket_duration(resolution);
+    let mut properties: BTreeMap<&str, Thing_telemetry_aggregate> = BTreeMap::new();
+
+    for sample in samples {
+        for (property, value) in sample.data.iter() {
+            let aggregate =
+                properties.entry(property.as_str())
+                    .or_insert_with(|| Thing_telemetry_aggregate {
+                        property: property.clone(),
+                        begin,
+                        end,
+                        min: f64::NAN,
+                        max: f64::NAN,
+                        avg: f64::NAN,
+                        ..Default::default()
+                    });
+
+            if let Ok(v) = value.trim().parse::<f64>() {
+                if aggregate.numeric_count == 0 {
+                    aggregate.min = v;
+                    aggregate.max = v;
+                    aggregate.avg = v;
+                } else {
+                    aggregate.min = aggregate.min.min(v);
+                    aggregate.max = aggregate.max.max(v);
+                    aggregate.avg += (v - aggregate.avg) / (aggregate.numeric_count + 1) as f64;
+                }
+                aggregate.numeric_count += 1;
+            }
+            aggregate.count += 1;
+            aggregate.last = value.clone();
+        }
+    }
+
+    Thing_telemetry_aggregate_list {
+        thing_id: thing_id.to_owned(),
+        begin,
+        resolution: resolution.into(),
+        aggregates: properties.into_values().collect(),
+        ..Default::default()
+    }
+}
+
+/// Merge the finer aggregates of a thing into one coarser bucket.
+/// The lists must be sorted by time, so the last value wins.
+pub(crate) fn merge_aggregates(thing_id: &str,
+                               begin: Timestamp,
+                               resolution: Thing_telemetry_resolution,
+                               lists: &[Thing_telemetry_aggregate_list]) -> Thing_telemetry_aggregate_list {
+    let end = begin + bucket_duration(resolution);
+    let mut properties: BTreeMap<&str, Thing_telemetry_aggregate> = BTreeMap::new();
+
+    for list in lists {
+        for finer in list.aggregates.iter() {
+            let aggregate =
+                properties.entry(finer.property())
+                    .or_insert_with(|| Thing_telemetry_aggregate {
+                        property: finer.property().to_owned(),
+                        begin,
+                        end,
+                        min: f64::NAN,
+                        max: f64::NAN,
+                        avg: f64::NAN,
+                        ..Default::default()
+                    });
+
+            if !finer.avg.is_nan() {
+                // the aggregate which is saved before the numeric count is weighted by all of its samples.
+                let numeric_count = if finer.numeric_count == 0 { finer.count } else { finer.numeric_count };
+
+                if aggregate.avg.is_nan() {
+                    aggregate.min = finer.min;
+                    aggregate.max = finer.max;
+                    aggregate.avg = finer.avg;
+                } else {
+                    let total = (aggregate.numeric_count + numeric_count) as f64;
+                    aggregate.min = aggregate.min.min(finer.min);
+                    aggregate.max = aggregate.max.max(finer.max);
+                    aggregate.avg = (aggregate.avg * aggregate.numeric_count as f64 + finer.avg * numeric_count as f64) / total;
+                }
+                aggregate.numeric_count += numeric_count;
+            }
+            aggregate.count += finer.count;
+            aggregate.last = finer.last.clone();
+        }
+    }
+
+    Thing_telemetry_aggregate_list {
+        thing_id: thing_id.to_owned(),
+        begin,
+        resolution: resolution.into(),
+        aggregates: properties.into_values().collect(),
+        ..Default::default()
+    }
+}
+
+pub(crate) struct TelemetryStorage<'a> {
+    pub(crate) config: &'a TelemetryConfig,
+    pub(crate) raw: &'a dyn SeriesStorageTrait<TelemetrySampleItem>,
+    pub(crate) minute: &'a dyn SeriesStorageTrait<TelemetryAggregateItem>,
+    pub(crate) hour: &'a dyn SeriesStorageTrait<TelemetryAggregateItem>,
+    pub(crate) day: &'a dyn SeriesStorageTrait<TelemetryAggregateItem>,
+    pub(crate) watermark: &'a dyn StorageTrait<TelemetryWatermarkItem>,
+}
+
+impl TelemetryStorage<'_> {
+    pub(crate) fn aggregate_storage(&self, resolution: Thing_telemetry_resolution) -> Option<&dyn SeriesStorageTrait<TelemetryAggregateItem>> {
+        match resolution {
+            Thing_telemetry_resolution::Raw => None,
+            Thing_telemetry_resolution::Minute => Some(self.minute),
+            Thing_telemetry_resolution::Hour => Some(self.hour),
+            Thing_telemetry_resolution::Day => Some(self.day),
+        }
+    }
+
+    async fn load_watermark(&self, name: &str, default: Timestamp) -> NearResult<Option<Timestamp>> {
+        match self.watermark.load_with_prefix(name).await {
+            Ok(watermark) => Ok(Some(watermark.timestamp())),
+            Err(e) if e.errno() == ErrorCode::NEAR_ERROR_NOTFOUND => {
+                self.watermark.create_new(&TelemetryWatermarkItem::new(name, default)).await?;
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Roll the completed buckets of each resolution into the next one, then drop the expired data.
+    pub(crate) async fn rollup(&self, now: Timestamp) -> NearResult<()> {
+        trace!("telemetry rollup, now: {now}");
+
+        // raw -> minute
+        {
+            let end = bucket_begin(now, MICROS_PER_MINUTE);
+            let begin =
+                self.load_watermark(TELEMETRY_MINUTE_TABLE, end).await?
+                    .unwrap_or(end);
+
+            if begin < end {
+                let samples = self.raw.load_range(None, begin, end).await?;
+
+                let mut buckets: BTreeMap<(String, Timestamp), Vec<Thing_telemetry_sample>> = BTreeMap::new();
+                for sample in samples {
+                    let sample = sample.take();
+                    buckets.entry((sample.thing_id.clone(), bucket_begin(sample.timestamp, MICROS_PER_MINUTE)))
+                        .or_default()
+                        .push(sample);
+                }
+
+                for ((thing_id, bucket), samples) in buckets {
+                    self.minute.append(&aggregate_samples(&thing_id, bucket, Thing_telemetry_resolution::Minute, &samples).into()).await?;
+                }
+
+                self.watermark.update(&TelemetryWatermarkItem::new(TELEMETRY_MINUTE_TABLE, end)).await?;
+            }
+        }
+
+        // minute -> hour, hour -> day
+        for (source, target, name, resolution) in [
+            (self.minute, self.hour, TELEMETRY_HOUR_TABLE, Thing_telemetry_resolution::Hour),
+            (self.hour, self.day, TELEMETRY_DAY_TABLE, Thing_telemetry_resolution::Day),
+        ] {
+            let duration = bucket_duration(resolution);
+            let end = bucket_begin(now, duration);
+            let begin =
+                self.load_watermark(name, end).await?
+                    .unwrap_or(end);
+
+            if begin < end {
+                let lists = source.load_range(None, begin, end).await?;
+
+                let mut buckets: BTreeMap<(String, Timestamp), Vec<Thing_telemetry_aggregate_list>> = BTreeMap::new();
+                for list in lists {
+                    let list = list.take();
+                    buckets.entry((list.thing_id.clone(), bucket_begin(list.begin, duration)))
+                        .or_default()
+                        .push(list);
+                }
+
+                for ((thing_id, bucket), lists) in buckets {
+                    target.append(&merge_aggregates(&thing_id, bucket, resolution, &lists).into()).await?;
+                }
+
+                self.watermark.update(&TelemetryWatermarkItem::new(name, end)).await?;
+            }
+        }
+
+        // retention
+        let expired = | retention: Duration | now.saturating_sub(retention.as_micros() as Timestamp);
+        self.raw.delete_before(expired(self.config.raw_retention)).await?;
+        self.minute.delete_before(expired(self.config.minute_retention)).await?;
+        self.hour.delete_before(expired(self.config.hour_retention)).await?;
+        self.day.delete_before(expired(self.config.day_retention)).await?;
+
+        Ok(())
+    }
+
+    /// Delete all of history of the thing.
+    pub(crate) async fn remove_thing(&self, thing_id: &str) -> NearResult<()> {
+        info!("remove {thing_id} telemetry.");
+
+        self.raw.delete_with_prefix(thing_id).await?;
+        self.minute.delete_with_prefix(thing_id).await?;
+        self.hour.delete_with_prefix(thing_id).await?;
+        self.day.delete_with_prefix(thing_id).await
+            .map_err(| e | {
+                error!("failed remove {thing_id} telemetry with err: {e}");
+                e
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use protos::hci::telemetry::*;
+
+    use super::{aggregate_samples, merge_aggregates, MICROS_PER_MINUTE};
+
+    fn sample(timestamp: u64, data: &[(&str, &str)]) -> Thing_telemetry_sample {
+        Thing_telemetry_sample {
+            thing_id: "thing".to_owned(),
+            timestamp,
+            data: data.iter().map(| (k, v) | (k.to_string(), v.to_string())).collect::<HashMap<_, _>>(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_aggregate() {
+        let samples = vec![
+            sample(1, &[("temp", "20"), ("mode", "cool")]),
+            sample(2, &[("temp", "24")]),
+            sample(3, &[("temp", "22"), ("mode", "heat")]),
+        ];
+
+        let minute = aggregate_samples("thing", 0, Thing_telemetry_resolution::Minute, &samples);
+        assert_eq!(minute.aggregates.len(), 2);
+
+        let mode = &minute.aggregates[0];
+        assert_eq!(mode.property(), "mode");
+        assert_eq!(mode.count, 2);
+        assert_eq!(mode.last(), "heat");
+        assert!(mode.avg.is_nan());
+
+        let temp = &minute.aggregates[1];
+        assert_eq!(temp.end, MICROS_PER_MINUTE);
+        assert_eq!(temp.count, 3);
+        assert_eq!(temp.min, 20.0);
+        assert_eq!(temp.max, 24.0);
+        assert_eq!(temp.avg, 22.0);
+        assert_eq!(temp.last(), "22");
+
+        let next = aggregate_samples("thing", MICROS_PER_MINUTE, Thing_telemetry_resolution::Minute, &[sample(MICROS_PER_MINUTE, &[("temp", "30")])]);
+
+        let hour = merge_aggregates("thing", 0, Thing_telemetry_resolution::Hour, &[minute, next]);
+        let temp = &hour.aggregates[1];
+        assert_eq!(temp.count, 4);
+        assert_eq!(temp.min, 20.0);
+        assert_eq!(temp.max, 30.0);
+        assert_eq!(temp.avg, 24.0);
+        assert_eq!(temp.last(), "30");
+    }
+
+    #[test]
+    fn test_aggregate_mixed() {
+        let samples = vec![
+            sample(1, &[("temp", "n/a")]),
+            sample(2, &[("temp", "10")]),
+            sample(3, &[("temp", "20")]),
+        ];
+
+        let minute = aggregate_samples("thing", 0, Thing_telemetry_resolution::Minute, &samples);
+        let temp = &minute.aggregates[0];
+        assert_eq!(temp.count, 3);
+        assert_eq!(temp.numeric_count, 2);
+        assert_eq!(temp.min, 10.0);
+        assert_eq!(temp.max, 20.0);
+        assert_eq!(temp.avg, 15.0);
+
+        let next = aggregate_samples("thing", MICROS_PER_MINUTE, Thing_telemetry_resolution::Minute, &[
+            sample(MICROS_PER_MINUTE, &[("temp", "30")]),
+            sample(MICROS_PER_MINUTE + 1, &[("temp", "n/a")]),
+        ]);
+
+        let hour = merge_aggregates("thing", 0, Thing_telemetry_resolution::Hour, &[minute, next]);
+        let temp = &hour.aggregates[0];
+        assert_eq!(temp.count, 5);
+        assert_eq!(temp.numeric_count, 3);
+        assert_eq!(temp.avg, 20.0);
+        assert_eq!(temp.last(), "n/a");
+    }
+}
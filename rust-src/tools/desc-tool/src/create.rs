@@ -12,6 +12,7 @@ fn core_service_sub_command() -> App<'static> {
         .arg(owner_arg_command.clone())
         .arg(area_arg_command.clone())
         .arg(pktype_arg_command.clone())
+        .arg(encrypt_arg_command.clone())
         .arg(ipprotocol_arg_command.clone())
         .arg(iptype_arg_command.clone())
         .arg(ipaddr_arg_command.clone())
@@ -24,6 +25,7 @@ fn service_sub_command() -> App<'static> {
     SubCommand::with_name("service").about("Create service desc, it allow your service to run in Cluster services of NearOS.")
         .arg(area_arg_command.clone())
         .arg(pktype_arg_command.clone())
+        .arg(encrypt_arg_command.clone())
         .arg(ipprotocol_arg_command.clone())
         .arg(iptype_arg_command.clone())
         .arg(ipaddr_arg_command.clone())
@@ -47,6 +49,7 @@ fn people_sub_command() -> App<'static> {
         .arg(people_name_arg_command.clone())
         .arg(userdata_arg_command.clone())
         .arg(pktype_arg_command.clone())
+        .arg(encrypt_arg_command.clone())
         .arg(output_path_arg_command.clone())
 }
 
@@ -75,6 +78,14 @@ pub fn create_subcommand() -> App<'static> {
 
 // }
 
+fn match_passphrase(matches: &ArgMatches) -> NearResult<Option<String>> {
+    if matches.is_present(encrypt_arg_command.get_id()) {
+        read_passphrase(true).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
 pub fn match_value<'a>(matches: &'a ArgMatches, id: &str) -> Option<&'a str> {
     if matches.try_get_raw(id).is_ok() {
         matches.value_of(id)
@@ -109,6 +120,7 @@ fn create_service_desc<'a>(matches: &'a ArgMatches) -> NearResult<()> {
         }),
         depended_desc: None,
         subscribe_messages: None,
+        passphrase: match_passphrase(matches)?,
     }
     .build()?
     .build()
@@ -130,6 +142,7 @@ fn create_core_service_desc<'a>(matches: &'a ArgMatches) -> NearResult<()> {
         name: Some("core-service"),
         depended_desc: None,
         subscribe_messages: None,
+        passphrase: match_passphrase(matches)?,
     }
     .build()?
     .build()
@@ -152,6 +165,7 @@ pub fn create_extention_desc<'a>(matches: &'a ArgMatches) -> NearResult<()> {
         name: match_value(matches, extention_name_arg_command.get_id()),
         depended_desc: matches.value_of(depended_desc_arg_command.get_id()),
         subscribe_messages: matches.value_of(subscribe_arg_command.get_id()),
+        passphrase: None,
     }
     .build()?
     .build()
@@ -210,6 +224,7 @@ pub fn create_people_desc(matches: &ArgMatches) -> NearResult<()> {
         name: Some(match_value(matches, people_name_arg_command.get_id()).unwrap_or("BM")),
         depended_desc: None,
         subscribe_messages: None,
+        passphrase: match_passphrase(matches)?,
     }
     .build()?
     .build()
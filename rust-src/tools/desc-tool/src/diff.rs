@@ -0,0 +1,98 @@
+#![allow(non_upper_case_globals)]
+
+use std::{collections::BTreeMap, path::PathBuf};
+
+use clap::{SubCommand, Arg, ArgMatches, App};
+use near_base::NearResult;
+use serde_json::{json, Value};
+
+use crate::{h::json_arg_command, object::{InspectObject, flatten, text_of}};
+
+lazy_static::lazy_static! {
+    static ref left_arg: Arg<'static> =
+        Arg::with_name("left")
+            .required(true)
+            .index(1)
+            .help("the first object file");
+
+    static ref right_arg: Arg<'static> =
+        Arg::with_name("right")
+            .required(true)
+            .index(2)
+            .help("the second object file");
+
+}
+
+pub fn diff_subcommand() -> App<'static> {
+    SubCommand::with_name("diff").about("diff the fields of two object files")
+        .arg(left_arg.clone())
+        .arg(right_arg.clone())
+        .arg(json_arg_command.clone())
+}
+
+fn load_fields(file: &str) -> NearResult<BTreeMap<String, Value>> {
+    let fields = InspectObject::load(PathBuf::from(file).as_path())?.fields()?;
+
+    let mut out = BTreeMap::new();
+    flatten("", &fields, &mut out);
+    Ok(out)
+}
+
+/// The fields which are missing or different, (field, left, right).
+fn diff(left: &BTreeMap<String, Value>, right: &BTreeMap<String, Value>) -> Vec<(String, Value, Value)> {
+    let mut keys: Vec<&String> = left.keys().chain(right.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    keys.into_iter()
+        .filter_map(| k | {
+            let l = left.get(k).cloned().unwrap_or(Value::Null);
+            let r = right.get(k).cloned().unwrap_or(Value::Null);
+            if l != r { Some((k.clone(), l, r)) } else { None }
+        })
+        .collect()
+}
+
+pub fn diff_object(matches: &ArgMatches) {
+    let left = matches.value_of(left_arg.get_id()).unwrap();
+    let right = matches.value_of(right_arg.get_id()).unwrap();
+
+    let diffs =
+        match load_fields(left).and_then(| l | load_fields(right).map(| r | diff(&l, &r))) {
+            Ok(diffs) => diffs,
+            Err(e) => {
+                println!("failed diff with err: {e}");
+                std::process::exit(1);
+            }
+        };
+
+    if matches.is_present(json_arg_command.get_id()) {
+        let value: Vec<Value> =
+            diffs.iter()
+                .map(| (field, l, r) | json!({ "field": field, "left": l, "right": r }))
+                .collect();
+        println!("{}", serde_json::to_string_pretty(&value).expect("format json"));
+    } else if diffs.is_empty() {
+        println!("no difference");
+    } else {
+        for (field, l, r) in diffs.iter() {
+            println!("{field}: [{}] -> [{}]", text_of(l), text_of(r));
+        }
+    }
+}
+
+
+#[test]
+fn test_diff() {
+    let left: BTreeMap<String, Value> = [("name".to_owned(), json!("a")), ("sequence".to_owned(), json!(1)), ("owner".to_owned(), json!("o"))].into();
+    let right: BTreeMap<String, Value> = [("name".to_owned(), json!("a")), ("sequence".to_owned(), json!(2)), ("area".to_owned(), json!("x"))].into();
+
+    let diffs = diff(&left, &right);
+    assert_eq!(diffs, vec![
+        ("area".to_owned(), Value::Null, json!("x")),
+        ("owner".to_owned(), json!("o"), Value::Null),
+        ("sequence".to_owned(), json!(1), json!(2)),
+    ]);
+
+    assert!(diff(&left, &left).is_empty());
+}
@@ -8,7 +8,7 @@ use clap::Arg;
 use extention::{ExtentionBodyContent, ExtentionDescContent};
 use near_base::{device::*, *};
 use near_core::*;
-use near_util::{CORE_STACK_PORT, DESC_SUFFIX_NAME, KEY_SUFFIX_NAME};
+use near_util::{CORE_STACK_PORT, DESC_SUFFIX_NAME, KEY_SUFFIX_NAME, KEY_PASSPHRASE_ENV};
 use people::{PeopleBodyContent, PeopleDescContent, PeopleObject};
 
 lazy_static::lazy_static!{
@@ -99,6 +99,16 @@ lazy_static::lazy_static!{
             .possible_values(&["rsa1024", "rsa2048"])
             .help("Private key type");
 
+    pub static ref encrypt_arg_command: Arg<'static> =
+        Arg::with_name("encrypt")
+            .long("encrypt")
+            .help("Encrypt the private key with a passphrase, it's read from ${{NEAR_KEY_PASSPHRASE}} or the terminal.");
+
+    pub static ref json_arg_command: Arg<'static> =
+        Arg::with_name("json")
+            .long("json")
+            .help("Output as json for scripting");
+
     pub static ref service_type_command: Arg<'static> =
         Arg::with_name("service type")
             .long("ctype")
@@ -113,6 +123,33 @@ lazy_static::lazy_static!{
 
 }
 
+/// Read the passphrase of the private key, the new passphrase is asked twice.
+pub fn read_passphrase(confirm: bool) -> NearResult<String> {
+    if let Ok(passphrase) = std::env::var(KEY_PASSPHRASE_ENV) {
+        return Ok(passphrase);
+    }
+
+    let passphrase = 
+        rpassword::prompt_password("passphrase: ")
+            .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("failed read passphrase with err: {e}")))?;
+
+    if passphrase.is_empty() {
+        return Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, "empty passphrase"));
+    }
+
+    if confirm {
+        let again = 
+            rpassword::prompt_password("passphrase again: ")
+                .map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("failed read passphrase with err: {e}")))?;
+
+        if again != passphrase {
+            return Err(NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, "the passphrases don't match"));
+        }
+    }
+
+    Ok(passphrase)
+}
+
 pub enum ServiceObjectType {
     Service(u8),
     Device(u8),
@@ -121,7 +158,7 @@ pub enum ServiceObjectType {
 }
 
 #[derive(Clone, Copy)]
-enum PkType {
+pub enum PkType {
     RSA1024,
     RSA2048,
 }
@@ -182,6 +219,7 @@ pub struct DescToolBuilder<'a> {
     pub name: Option<&'a str>,
     pub depended_desc: Option<&'a str>,
     pub subscribe_messages: Option<&'a str>,
+    pub passphrase: Option<String>,
 }
 
 impl<'a> DescToolBuilder<'a> {
@@ -285,6 +323,7 @@ impl<'a> DescToolBuilder<'a> {
                     None
                 }, 
             subscribe_messages: self.subscribe_messages,
+            passphrase: self.passphrase,
         })
 
     }
@@ -305,6 +344,9 @@ pub struct ServiceObjectDescParams<'a> {
     /// extention property
     depended_desc: Option<PathBuf>,
     subscribe_messages: Option<&'a str>,
+
+    /// the private key is encrypted with it
+    passphrase: Option<String>,
 }
 
 impl ServiceObjectDescParams<'_> {
@@ -327,6 +369,7 @@ impl ServiceObjectDescParams<'_> {
         let output = self.output.unwrap_or(get_data_path());
         let userdata = self.userdata;
         let pktype = self.pktype;
+        let passphrase = self.passphrase;
         let _endpoints = self.endpoints;
         let name = self.name.unwrap_or("BM");
 
@@ -378,6 +421,7 @@ impl ServiceObjectDescParams<'_> {
             desc,
             desc_name: name.to_owned(),
             private_key: private_key,
+            passphrase,
         }.output()
     }
 
@@ -389,6 +433,7 @@ impl ServiceObjectDescParams<'_> {
         let output = self.output.unwrap_or(get_data_path());
         let userdata = self.userdata;
         let pktype = self.pktype;
+        let passphrase = self.passphrase;
         let endpoints = self.endpoints;
         let name = self.name.expect("missing service name");
         
@@ -460,6 +505,7 @@ impl ServiceObjectDescParams<'_> {
             desc,
             desc_name: name.to_owned(),
             private_key: private_key,
+            passphrase,
         }.output()
     }
 
@@ -471,6 +517,7 @@ impl ServiceObjectDescParams<'_> {
         let output = self.output.unwrap_or(get_data_path());
         let userdata = self.userdata;
         let pktype = self.pktype;
+        let passphrase = self.passphrase;
         let endpoints = self.endpoints;
         let name = self.name.expect("missing device name");
 
@@ -540,6 +587,7 @@ impl ServiceObjectDescParams<'_> {
             desc,
             desc_name: name.to_owned(),
             private_key: private_key,
+            passphrase,
         }.output()
     }
 
@@ -551,6 +599,7 @@ impl ServiceObjectDescParams<'_> {
         let output = self.output.unwrap_or(get_data_path());
         let userdata = self.userdata;
         let _pktype = self.pktype;
+        let _passphrase = self.passphrase;
         let _endpoints = self.endpoints;
         let name = self.name.expect("You must have extention name.");
         let depended_desc = self.depended_desc;
@@ -619,6 +668,7 @@ impl ServiceObjectDescParams<'_> {
             desc,
             desc_name: extention_name.to_owned(),
             private_key: None,
+            passphrase: None,
         }.output()
 
         // let core = match matches.value_of(core_service_arg_command.get_id()).map(| v | PathBuf::from(v)) {
@@ -665,6 +715,7 @@ struct ObjectDescOutput<DESC> {
     desc: DESC,
     desc_name: String,
     private_key: Option<PrivateKey>,
+    passphrase: Option<String>,
 }
 
 impl<DESC: FileEncoder> ObjectDescOutput<DESC> {
@@ -690,15 +741,14 @@ impl<DESC: FileEncoder> ObjectDescOutput<DESC> {
             Ok(())
         };
 
-        let output_prikey = | output: &PathBuf, name: &str, key: PrivateKey | -> NearResult<()> {
-            key.encode_to_file(output.join(format!("{name}.{KEY_SUFFIX_NAME}")).as_path(), false)?;
-            Ok(())
+        let output_prikey = | output: &PathBuf, name: &str, key: PrivateKey, passphrase: Option<&str> | -> NearResult<()> {
+            save_private_key(output.join(format!("{name}.{KEY_SUFFIX_NAME}")).as_path(), &key, passphrase)
         };
 
         output_desc(&output, &self.desc_name, self.desc)?;
 
         if let Some(private_key) = self.private_key {
-            output_prikey(&output, &self.desc_name, private_key)?;
+            output_prikey(&output, &self.desc_name, private_key, self.passphrase.as_deref())?;
         }
 
         Ok(())
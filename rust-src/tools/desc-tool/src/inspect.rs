@@ -0,0 +1,52 @@
+#![allow(non_upper_case_globals)]
+
+use std::path::PathBuf;
+
+use clap::{SubCommand, Arg, ArgMatches, App};
+use near_base::{NearResult, ObjectId};
+
+use crate::{h::json_arg_command, object::{InspectObject, object_id_fields, print_fields}};
+
+lazy_static::lazy_static! {
+    static ref target_arg: Arg<'static> =
+        Arg::with_name("target")
+            .required(true)
+            .index(1)
+            .help("object file or base58 object id to inspect");
+
+}
+
+pub fn inspect_subcommand() -> App<'static> {
+    SubCommand::with_name("inspect").about("inspect the object file or decode the object id")
+        .arg(target_arg.clone())
+        .arg(json_arg_command.clone())
+}
+
+fn inspect(target: &str) -> NearResult<serde_json::Value> {
+    let file = PathBuf::from(target);
+
+    if file.is_file() {
+        InspectObject::load(file.as_path())?.fields()
+    } else {
+        let (id, _) = ObjectId::from_str_typed(target)?;
+        Ok(object_id_fields(&id))
+    }
+}
+
+pub fn inspect_object(matches: &ArgMatches) {
+    let target = matches.value_of(target_arg.get_id()).unwrap();
+
+    match inspect(target) {
+        Ok(fields) => {
+            if matches.is_present(json_arg_command.get_id()) {
+                println!("{}", serde_json::to_string_pretty(&fields).expect("format json"));
+            } else {
+                print_fields(&fields);
+            }
+        }
+        Err(e) => {
+            println!("failed inspect {target} with err: {e}");
+            std::process::exit(1);
+        }
+    }
+}
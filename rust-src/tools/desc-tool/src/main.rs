@@ -5,17 +5,30 @@ mod h;
 mod create;
 mod modify;
 mod show;
+mod rotate;
+mod object;
+mod inspect;
+mod verify;
+mod diff;
 
 use create::{create_desc, create_subcommand};
 use modify::{modify_desc, modify_subcommand};
 use show::{show_desc, show_subcommand};
+use rotate::{rotate_desc, rotate_subcommand};
+use inspect::{inspect_object, inspect_subcommand};
+use verify::{verify_object, verify_subcommand};
+use diff::{diff_object, diff_subcommand};
 
 fn main() {
     let matches = 
-        App::new("desc-tool").version("1.0").about("tool to create or show desc files")
+        App::new("desc-tool").version("1.0").about("tool to create, show or inspect desc files")
             .subcommand(create_subcommand())
             .subcommand(modify_subcommand())
             .subcommand(show_subcommand())
+            .subcommand(rotate_subcommand())
+            .subcommand(inspect_subcommand())
+            .subcommand(verify_subcommand())
+            .subcommand(diff_subcommand())
             .get_matches();
 
     if let Some(command) = matches.subcommand() {
@@ -29,6 +42,18 @@ fn main() {
             ("show", matches) => {
                 show_desc(matches)
             }
+            ("rotate", matches) => {
+                rotate_desc(matches)
+            }
+            ("inspect", matches) => {
+                inspect_object(matches)
+            }
+            ("verify", matches) => {
+                verify_object(matches)
+            }
+            ("diff", matches) => {
+                diff_object(matches)
+            }
             _v @ _ => {
             }
         }
@@ -23,6 +23,7 @@ fn modify_core_service_desc<'a>(matches: &'a ArgMatches) -> NearResult<()> {
         name: Some("core-service"),
         depended_desc: None,
         subscribe_messages: None, 
+        passphrase: None,
     }
     .build()?
     .build()
@@ -54,6 +55,7 @@ fn modify_service_desc<'a>(matches: &'a ArgMatches) -> NearResult<()> {
         }),
         depended_desc: None,
         subscribe_messages: None, 
+        passphrase: None,
     }
     .build()?
     .build()
@@ -75,6 +77,7 @@ fn modify_extention_desc<'a>(matches: &'a ArgMatches) -> NearResult<()> {
         name: match_value(matches, extention_name_arg_command.get_id()),
         depended_desc: match_value(matches, depended_desc_arg_command.get_id()),
         subscribe_messages: match_value(matches, subscribe_arg_command.get_id()),
+        passphrase: None,
     }
     .build()?
     .build()
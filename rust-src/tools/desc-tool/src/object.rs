@@ -0,0 +1,307 @@
+
+use std::{collections::BTreeMap, path::Path};
+
+use near_base::{any::AnyNamedObject, Deserialize, ErrorCode, KeyRotationChain, KeyRotationObject, NearError, NearResult, ObjectId, RawConvertTo, RevocationListObject};
+use proof::proof_data::{ProofDataSet, ProofOfDataReq};
+use serde_json::{json, Map, Value};
+
+/// All of the objects which could be read from the file.
+pub(crate) enum InspectObject {
+    Named(AnyNamedObject),
+    Rotation(KeyRotationObject),
+    Rotations(KeyRotationChain),
+    Revocation(RevocationListObject),
+    Proof(ProofDataSet),
+}
+
+// the object is accepted only if the whole file is decoded.
+fn decode_whole<T: Deserialize>(data: &[u8]) -> Option<T> {
+    match T::deserialize(data) {
+        Ok((o, remain)) if remain.is_empty() => Some(o),
+        _ => None,
+    }
+}
+
+impl InspectObject {
+    pub(crate) fn load(file: &Path) -> NearResult<Self> {
+        let data = std::fs::read(file).map_err(NearError::from)?;
+
+        decode_whole(&data).map(Self::Named)
+            .or_else(|| decode_whole(&data).map(Self::Rotation))
+            .or_else(|| decode_whole(&data).map(Self::Rotations))
+            .or_else(|| decode_whole(&data).map(Self::Revocation))
+            .or_else(|| decode_whole(&data).map(Self::Proof))
+            .ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_INVALIDFORMAT, format!("{} isn't any known object", file.display())))
+    }
+
+    pub(crate) fn kind(&self) -> &'static str {
+        match self {
+            Self::Named(_) => "named-object",
+            Self::Rotation(_) => "key-rotation",
+            Self::Rotations(_) => "key-rotations",
+            Self::Revocation(_) => "revocation-list",
+            Self::Proof(ProofDataSet::Publish(_)) => "proof-publish",
+            Self::Proof(ProofDataSet::Ping(_)) => "proof-ping",
+            Self::Proof(ProofDataSet::Pairing(_)) => "proof-pairing",
+        }
+    }
+
+    pub(crate) fn fields(&self) -> NearResult<Value> {
+        let mut fields = Map::new();
+        fields.insert("kind".to_owned(), self.kind().into());
+
+        match self {
+            Self::Named(o) => {
+                named_fields(o, &mut fields)?;
+            }
+            Self::Rotation(o) => {
+                let rotation = o.rotation();
+                fields.insert("id".to_owned(), object_id_fields(rotation.object_id()));
+                fields.insert("sequence".to_owned(), rotation.sequence().into());
+                fields.insert("old_key".to_owned(), rotation.old_key().to_hex()?.into());
+                fields.insert("new_key".to_owned(), rotation.new_key().to_hex()?.into());
+                fields.insert("create_time".to_owned(), rotation.create_time().into());
+            }
+            Self::Rotations(o) => {
+                if let Some(first) = o.rotations().first() {
+                    fields.insert("id".to_owned(), object_id_fields(first.rotation().object_id()));
+                }
+                fields.insert("last_sequence".to_owned(), o.last_sequence().into());
+                fields.insert("rotations".to_owned(), {
+                    o.rotations()
+                        .iter()
+                        .map(| o | {
+                            let rotation = o.rotation();
+                            Ok(json!({
+                                "sequence": rotation.sequence(),
+                                "old_key": rotation.old_key().to_hex()?,
+                                "new_key": rotation.new_key().to_hex()?,
+                                "create_time": rotation.create_time(),
+                            }))
+                        })
+                        .collect::<NearResult<Vec<Value>>>()?
+                        .into()
+                });
+            }
+            Self::Revocation(o) => {
+                let list = o.list();
+                fields.insert("issuer".to_owned(), object_id_fields(o.issuer().object_id()));
+                fields.insert("sequence".to_owned(), list.sequence().into());
+                fields.insert("revoked".to_owned(), list.revoked().iter().map(| id | id.to_string()).collect());
+                fields.insert("create_time".to_owned(), list.create_time().into());
+            }
+            Self::Proof(ProofDataSet::Publish(o)) => {
+                proof_fields(o, &mut fields)?;
+            }
+            Self::Proof(ProofDataSet::Ping(o)) => {
+                proof_fields(o, &mut fields)?;
+            }
+            Self::Proof(ProofDataSet::Pairing(o)) => {
+                let proof_of_data = o.proof_of_data();
+                let thing = proof_of_data.body().content().data();
+
+                fields.insert("id".to_owned(), object_id_fields(proof_of_data.object_id()));
+                fields.insert("proof_data".to_owned(), proof_of_data.desc().content().proof_data().to_string().into());
+                fields.insert("thing".to_owned(), object_id_fields(thing.object_id()));
+                fields.insert("thing_mac_address".to_owned(), hex::encode_upper(thing.desc().content().mac_address()).into());
+                fields.insert("thing_owner_depend_id".to_owned(), thing.desc().content().owner_depend_id().into());
+                fields.insert("signature".to_owned(), o.proof().to_hex()?.into());
+            }
+        }
+
+        Ok(Value::Object(fields))
+    }
+}
+
+/// The object id is decoded into its type code and sub code.
+pub(crate) fn object_id_fields(id: &ObjectId) -> Value {
+    let code = id.object_type_code().unwrap_or_default();
+
+    json!({
+        "object_id": id.to_string(),
+        "type": code.name(),
+        "type_code": code.into_u16(),
+        "sub_code": code.sub_code(),
+        "sub_code_name": code.sub_code_name(),
+    })
+}
+
+fn named_fields(o: &AnyNamedObject, fields: &mut Map<String, Value>) -> NearResult<()> {
+    fields.insert("id".to_owned(), object_id_fields(o.object_id()));
+    fields.insert("create_time".to_owned(), o.create_timestamp().into());
+    fields.insert("expired_time".to_owned(), o.expired_time().into());
+    fields.insert("update_time".to_owned(), o.update_time().into());
+    fields.insert("owner".to_owned(), o.owner().map(| v | v.to_string()).into());
+    fields.insert("author".to_owned(), o.author().map(| v | v.to_string()).into());
+    fields.insert("area".to_owned(), o.area().map(| v | v.to_string()).into());
+    fields.insert("public_key".to_owned(), o.public_key().map(| v | v.to_hex()).transpose()?.into());
+
+    let body =
+        match o {
+            AnyNamedObject::None => Value::Null,
+            AnyNamedObject::Device(o) |
+            AnyNamedObject::Service(o) => {
+                let content = o.body().content();
+                json!({
+                    "name": content.name(),
+                    "endpoints": content.endpoints().iter().map(| v | v.to_string()).collect::<Vec<String>>(),
+                    "stun_node_list": content.stun_node_list().iter().map(| v | v.to_string()).collect::<Vec<String>>(),
+                    "turn_node_list": content.turn_node_list().iter().map(| v | v.to_string()).collect::<Vec<String>>(),
+                })
+            }
+            AnyNamedObject::People(o) => {
+                let content = o.body().content();
+                json!({
+                    "name": content.name(),
+                    "core_service_list": content.core_service_list().iter().map(| v | v.to_string()).collect::<Vec<String>>(),
+                })
+            }
+            AnyNamedObject::Extention(o) => {
+                json!({
+                    "name": o.desc().content().get_extention_name(),
+                    "subscribe_messages": o.body().content().subscribe_messages(),
+                })
+            }
+            AnyNamedObject::File(o) => {
+                let desc = o.desc().content();
+                json!({
+                    "name": desc.name(),
+                    "len": desc.len(),
+                    "hash": desc.hash().to_string(),
+                    "chunk_list": o.body().content().chunk_list().iter().map(| v | v.to_string()).collect::<Vec<String>>(),
+                })
+            }
+            AnyNamedObject::Dir(o) => {
+                let desc = o.desc().content();
+                json!({
+                    "name": desc.name(),
+                    "len": desc.len(),
+                    "hash": desc.hash().to_string(),
+                    "entries": o.body().content().entries().iter().map(| entry | json!({
+                        "path": entry.path(),
+                        "mode": entry.mode(),
+                        "mtime": entry.mtime(),
+                        "file": entry.file().object_id().to_string(),
+                    })).collect::<Vec<Value>>(),
+                })
+            }
+            AnyNamedObject::Thing(o) => {
+                let desc = o.desc().content();
+                json!({
+                    "mac_address": hex::encode_upper(desc.mac_address()),
+                    "owner_depend_id": desc.owner_depend_id(),
+                    "name": o.body().content().name(),
+                    "data": o.body().content().user_data().iter().collect::<BTreeMap<_, _>>(),
+                })
+            }
+        };
+    fields.insert("body".to_owned(), body);
+
+    Ok(())
+}
+
+fn proof_fields<T>(o: &ProofOfDataReq<T, near_base::DeviceObject>, fields: &mut Map<String, Value>) -> NearResult<()>
+where T: Clone + std::fmt::Display + near_base::Serialize + Deserialize + std::default::Default {
+    let proof_of_data = o.proof_of_data();
+    let device = proof_of_data.body().content().data();
+
+    fields.insert("id".to_owned(), object_id_fields(proof_of_data.object_id()));
+    fields.insert("proof_data".to_owned(), proof_of_data.desc().content().proof_data().to_string().into());
+    fields.insert("device".to_owned(), object_id_fields(device.object_id()));
+    fields.insert("device_public_key".to_owned(), device.desc().public_key().map(| v | v.to_hex()).transpose()?.into());
+    fields.insert("signature".to_owned(), o.proof().to_hex()?.into());
+
+    Ok(())
+}
+
+/// The nested fields are joined by the dot, so they're compared one by one.
+pub(crate) fn flatten(prefix: &str, value: &Value, out: &mut BTreeMap<String, Value>) {
+    match value {
+        Value::Object(map) => {
+            for (k, v) in map {
+                let key = if prefix.is_empty() { k.clone() } else { format!("{prefix}.{k}") };
+                flatten(&key, v, out);
+            }
+        }
+        _ => {
+            out.insert(prefix.to_owned(), value.clone());
+        }
+    }
+}
+
+/// The text output of the fields, one line per field.
+pub(crate) fn print_fields(value: &Value) {
+    let mut fields = BTreeMap::new();
+    flatten("", value, &mut fields);
+
+    for (k, v) in fields {
+        println!("{k}: [{}]", text_of(&v));
+    }
+}
+
+pub(crate) fn text_of(value: &Value) -> String {
+    match value {
+        Value::Null => "None".to_owned(),
+        Value::String(v) => v.clone(),
+        _ => value.to_string(),
+    }
+}
+
+#[test]
+fn test_flatten() {
+    let mut out = BTreeMap::new();
+    flatten("", &json!({ "id": { "object_id": "a", "type": "device" }, "sequence": 1, "revoked": ["b"] }), &mut out);
+
+    assert_eq!(out.len(), 4);
+    assert_eq!(out["id.object_id"], json!("a"));
+    assert_eq!(out["id.type"], json!("device"));
+    assert_eq!(out["sequence"], json!(1));
+    // the array isn't flattened
+    assert_eq!(out["revoked"], json!(["b"]));
+}
+
+#[test]
+fn test_load_kind() {
+    use near_base::{device::{DeviceBodyContent, DeviceDescContent}, people::{PeopleBodyContent, PeopleDescContent}, FileEncoder, KeyRotation,
+                    KeyRotationChain, ObjectBuilder, PrivateKey, RevocationList};
+
+    let dir = std::env::temp_dir().join(format!("desc-tool-load-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let people_key = PrivateKey::generate_rsa1024().unwrap();
+    let people =
+        ObjectBuilder::new(PeopleDescContent::new(), PeopleBodyContent::default())
+            .update_desc(| desc | desc.set_public_key(people_key.public()))
+            .build()
+            .unwrap();
+    let device_key = PrivateKey::generate_rsa1024().unwrap();
+    let mut device =
+        ObjectBuilder::new(DeviceDescContent::with_device(1), DeviceBodyContent::default())
+            .update_desc(| desc | desc.set_public_key(device_key.public()))
+            .build()
+            .unwrap();
+    let rotation =
+        KeyRotation::new(device.object_id().clone(), 1, device_key.public(), PrivateKey::generate_rsa1024().unwrap().public())
+            .sign(&device_key)
+            .unwrap();
+    let mut rotations = KeyRotationChain::default();
+    rotations.apply(rotation.clone(), &mut device).unwrap();
+    let revocation = RevocationList::new(people.object_id().clone(), 1).revoke(device.object_id().clone()).sign(people, &people_key).unwrap();
+
+    device.encode_to_file(dir.join("device.desc").as_path(), false).unwrap();
+    rotation.encode_to_file(dir.join("device.rotation").as_path(), false).unwrap();
+    rotations.encode_to_file(dir.join("device.rotations").as_path(), false).unwrap();
+    revocation.encode_to_file(dir.join("people.revocation").as_path(), false).unwrap();
+    std::fs::write(dir.join("unknown"), [1u8, 2, 3]).unwrap();
+
+    assert_eq!(InspectObject::load(dir.join("device.desc").as_path()).unwrap().kind(), "named-object");
+    assert_eq!(InspectObject::load(dir.join("device.rotation").as_path()).unwrap().kind(), "key-rotation");
+    assert_eq!(InspectObject::load(dir.join("device.rotations").as_path()).unwrap().kind(), "key-rotations");
+    assert_eq!(InspectObject::load(dir.join("people.revocation").as_path()).unwrap().kind(), "revocation-list");
+    assert!(InspectObject::load(dir.join("unknown").as_path()).is_err());
+
+    let fields = InspectObject::load(dir.join("device.desc").as_path()).unwrap().fields().unwrap();
+    assert_eq!(fields["id"]["object_id"], json!(device.object_id().to_string()));
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
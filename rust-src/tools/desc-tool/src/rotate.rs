@@ -0,0 +1,101 @@
+#![allow(non_upper_case_globals)]
+
+use std::path::PathBuf;
+
+use clap::{SubCommand, Arg, ArgMatches, App};
+use near_base::{any::AnyNamedObject, DeviceObject, ErrorCode, FileDecoder, FileEncoder, KeyRotation, KeyRotationChain, NearError, NearResult, load_private_key, save_private_key, now};
+use near_util::KEY_SUFFIX_NAME;
+
+use crate::{h::{pktype_arg_command, encrypt_arg_command, read_passphrase, PkType}, create::match_value};
+
+lazy_static::lazy_static! {
+    static ref descfile_arg: Arg<'static> = 
+        Arg::with_name("desc_file")
+            .required(true)
+            .index(1)
+            .help("desc file to rotate, its private key must be in the same directory");
+
+    static ref sequence_arg: Arg<'static> =
+        Arg::with_name("sequence")
+            .long("sequence")
+            .takes_value(true)
+            .help("Rotation sequence, it must be greater than the last one, if not set, will use the current time");
+
+}
+
+pub fn rotate_subcommand() -> App<'static> {
+    SubCommand::with_name("rotate").about("rotate the key of core-service or service desc, the object id isn't changed")
+        .arg(descfile_arg.clone())
+        .arg(pktype_arg_command.clone())
+        .arg(sequence_arg.clone())
+        .arg(encrypt_arg_command.clone())
+}
+
+fn rotate_device(desc_file: PathBuf, mut device: DeviceObject, matches: &ArgMatches) -> NearResult<()> {
+    let name = 
+        desc_file.file_stem()
+            .and_then(| name | name.to_str())
+            .ok_or_else(|| NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("invalid desc file {}", desc_file.display())))?;
+    let key_file = desc_file.with_file_name(format!("{name}.{KEY_SUFFIX_NAME}"));
+
+    // the encrypted key is rotated with the same passphrase.
+    let passphrase = 
+        if matches.is_present(encrypt_arg_command.get_id()) {
+            Some(read_passphrase(false)?)
+        } else {
+            None
+        };
+
+    let old_private_key = load_private_key(key_file.as_path(), passphrase.as_deref())?;
+    let new_private_key = {
+        let pktype: PkType = match_value(matches, pktype_arg_command.get_id()).unwrap_or("rsa2048").try_into()?;
+        pktype.build()?
+    };
+
+    // all of the rotations of the desc are kept, so its object id could be checked from the first key.
+    let rotation_file = desc_file.with_file_name(format!("{name}.rotation"));
+    let mut rotations =
+        if rotation_file.exists() {
+            KeyRotationChain::decode_from_file(rotation_file.as_path())?
+        } else {
+            KeyRotationChain::default()
+        };
+
+    let sequence = 
+        match match_value(matches, sequence_arg.get_id()) {
+            Some(sequence) => sequence.parse::<u64>().map_err(| e | NearError::new(ErrorCode::NEAR_ERROR_INVALIDPARAM, format!("invalid sequence with err: {e}")))?,
+            None => now().max(rotations.last_sequence() + 1),
+        };
+
+    let rotation = 
+        KeyRotation::new(device.object_id().clone(), sequence, old_private_key.public(), new_private_key.public())
+            .sign(&old_private_key)?;
+
+    rotations.apply(rotation, &mut device)?;
+
+    // the last rotation of the file should be published to ring-smart, it's replicated to the peers and pushed to sn-smart.
+    rotations.encode_to_file(rotation_file.as_path(), false)?;
+    device.encode_to_file(desc_file.as_path(), false)?;
+    save_private_key(key_file.as_path(), &new_private_key, passphrase.as_deref())?;
+
+    println!("object id: [{}]", device.object_id());
+    println!("output: {}", rotation_file.display());
+
+    Ok(())
+}
+
+pub fn rotate_desc(matches: &ArgMatches) {
+    let desc_file = matches.value_of(descfile_arg.get_id())
+                                .map(| v | PathBuf::from(v) )
+                                .unwrap();
+
+    match AnyNamedObject::decode_from_file(desc_file.as_path()).expect("parse desc") {
+        AnyNamedObject::Device(o) |
+        AnyNamedObject::Service(o) => {
+            rotate_device(desc_file, o, matches).expect("failed rotate")
+        }
+        _ => {
+            println!("only the key of core-service or service could be rotated.");
+        }
+    }
+}
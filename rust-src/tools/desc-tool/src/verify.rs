@@ -0,0 +1,228 @@
+#![allow(non_upper_case_globals)]
+
+use std::path::PathBuf;
+
+use clap::{SubCommand, Arg, ArgMatches, App};
+use near_base::{any::AnyNamedObject, thing::ThingObject, DeviceObject, ErrorCode, FileDecoder, KeyRotationChain, NearError, NearResult, Serialize, Deserialize,
+                load_private_key};
+use near_util::KEY_PASSPHRASE_ENV;
+use proof::proof_data::{ProofDataSet, ProofOfDataReq, ProofOfProcessTrait, PairingData};
+use serde_json::json;
+
+use crate::{h::json_arg_command, object::InspectObject};
+
+lazy_static::lazy_static! {
+    static ref file_arg: Arg<'static> =
+        Arg::with_name("file")
+            .required(true)
+            .index(1)
+            .help("object file to verify");
+
+    static ref rotation_arg: Arg<'static> =
+        Arg::with_name("rotation")
+            .long("rotation")
+            .takes_value(true)
+            .help("the key rotations of the desc, its object id is built from the key before the first rotation");
+
+    static ref pairing_key_arg: Arg<'static> =
+        Arg::with_name("pairing-key")
+            .long("pairing-key")
+            .takes_value(true)
+            .help("the pairing key file of hci-service, the signature of the proof-pairing is checked by it");
+
+}
+
+pub fn verify_subcommand() -> App<'static> {
+    SubCommand::with_name("verify").about("verify the object id and the signatures of the object file")
+        .arg(file_arg.clone())
+        .arg(rotation_arg.clone())
+        .arg(pairing_key_arg.clone())
+        .arg(json_arg_command.clone())
+}
+
+struct Checks(Vec<(&'static str, NearResult<()>)>);
+
+impl Checks {
+    fn push(&mut self, name: &'static str, r: NearResult<()>) {
+        self.0.push((name, r));
+    }
+
+    fn is_valid(&self) -> bool {
+        self.0.iter().all(| (_, r) | r.is_ok())
+    }
+}
+
+fn verify_proof<T>(checks: &mut Checks, proof: &ProofOfDataReq<T, DeviceObject>)
+where T: Clone + std::fmt::Display + Serialize + Deserialize + std::default::Default + ProofOfProcessTrait {
+    let proof_of_data = proof.proof_of_data();
+    let device = proof_of_data.body().content().data();
+
+    checks.push("object_id", proof_of_data.verify_object_id());
+    checks.push("device_object_id", device.verify_object_id());
+    checks.push("signature", {
+        match device.desc().public_key() {
+            Some(public_key) => async_std::task::block_on(proof_of_data.desc().content().proof_data().verify(public_key, proof.proof())),
+            None => Err(NearError::new(ErrorCode::NEAR_ERROR_CRYPTO_INVALID_PUBKEY, format!("{} hasn't public key", device.object_id()))),
+        }
+    });
+}
+
+// the pairing proof is signed by hci-service, so its signature is checked only if the key is given.
+fn verify_pairing(checks: &mut Checks, proof: &ProofOfDataReq<PairingData, ThingObject>, pairing_key: Option<PathBuf>) -> NearResult<()> {
+    let proof_of_data = proof.proof_of_data();
+    let proof_of_pairing = proof_of_data.desc().content().proof_data();
+    let thing = proof_of_data.body().content().data();
+
+    checks.push("object_id", proof_of_data.verify_object_id());
+    checks.push("thing_object_id", thing.verify_object_id());
+    checks.push("thing_id", {
+        if thing.object_id().to_string() == proof_of_pairing.thing_id() {
+            Ok(())
+        } else {
+            Err(NearError::new(ErrorCode::NEAR_ERROR_UNMATCH, format!("the proof is for {}", proof_of_pairing.thing_id())))
+        }
+    });
+
+    if let Some(pairing_key) = pairing_key {
+        let passphrase = std::env::var(KEY_PASSPHRASE_ENV).ok();
+        let public_key = load_private_key(pairing_key.as_path(), passphrase.as_deref())?.public();
+
+        checks.push("signature", async_std::task::block_on(proof_of_pairing.verify(&public_key, proof.proof())));
+    }
+
+    Ok(())
+}
+
+fn verify(file: PathBuf, rotation: Option<PathBuf>, pairing_key: Option<PathBuf>) -> NearResult<(InspectObject, Checks)> {
+    let object = InspectObject::load(file.as_path())?;
+    let mut checks = Checks(vec![]);
+
+    match &object {
+        InspectObject::Named(o) => {
+            // the object id of the rotated device is checked by its rotations, but not the current key.
+            match (rotation, o) {
+                (Some(rotation), AnyNamedObject::Device(device)) |
+                (Some(rotation), AnyNamedObject::Service(device)) => {
+                    let rotations = KeyRotationChain::decode_from_file(rotation.as_path())?;
+                    checks.push("rotation", rotations.verify_object(device));
+                }
+                (_, o) => {
+                    checks.push("object_id", o.verify_object_id());
+                }
+            }
+        }
+        InspectObject::Rotation(o) => {
+            checks.push("signature", o.verify(o.rotation().old_key()));
+        }
+        InspectObject::Rotations(o) => {
+            checks.push("signature", o.verify());
+        }
+        InspectObject::Revocation(o) => {
+            checks.push("issuer_object_id", o.issuer().verify_object_id());
+            checks.push("signature", o.verify());
+        }
+        InspectObject::Proof(ProofDataSet::Publish(o)) => {
+            verify_proof(&mut checks, o);
+        }
+        InspectObject::Proof(ProofDataSet::Ping(o)) => {
+            verify_proof(&mut checks, o);
+        }
+        InspectObject::Proof(ProofDataSet::Pairing(o)) => {
+            verify_pairing(&mut checks, o, pairing_key)?;
+        }
+    }
+
+    Ok((object, checks))
+}
+
+pub fn verify_object(matches: &ArgMatches) {
+    let file = matches.value_of(file_arg.get_id()).map(PathBuf::from).unwrap();
+    let rotation = matches.value_of(rotation_arg.get_id()).map(PathBuf::from);
+    let pairing_key = matches.value_of(pairing_key_arg.get_id()).map(PathBuf::from);
+
+    let (object, checks) =
+        match verify(file.clone(), rotation, pairing_key) {
+            Ok(r) => r,
+            Err(e) => {
+                println!("failed verify {} with err: {e}", file.display());
+                std::process::exit(1);
+            }
+        };
+
+    let valid = checks.is_valid();
+
+    if matches.is_present(json_arg_command.get_id()) {
+        let value = json!({
+            "file": file.display().to_string(),
+            "kind": object.kind(),
+            "valid": valid,
+            "checks": checks.0.iter().map(| (name, r) | json!({
+                "name": name,
+                "ok": r.is_ok(),
+                "error": r.as_ref().err().map(| e | e.to_string()),
+            })).collect::<Vec<serde_json::Value>>(),
+        });
+        println!("{}", serde_json::to_string_pretty(&value).expect("format json"));
+    } else {
+        println!("kind: [{}]", object.kind());
+        for (name, r) in checks.0.iter() {
+            match r {
+                Ok(_) => println!("{name}: [ok]"),
+                Err(e) => println!("{name}: [failed, {e}]"),
+            }
+        }
+    }
+
+    if !valid {
+        std::process::exit(1);
+    }
+}
+
+#[test]
+fn test_verify_rotation() {
+    use near_base::{device::{DeviceBodyContent, DeviceDescContent}, FileEncoder, KeyRotation, ObjectBuilder, PrivateKey};
+
+    let dir = std::env::temp_dir().join(format!("desc-tool-verify-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let first_key = PrivateKey::generate_rsa1024().unwrap();
+    let second_key = PrivateKey::generate_rsa1024().unwrap();
+    let third_key = PrivateKey::generate_rsa1024().unwrap();
+    let mut device =
+        ObjectBuilder::new(DeviceDescContent::with_device(1), DeviceBodyContent::default())
+            .update_desc(| desc | desc.set_public_key(first_key.public()))
+            .build()
+            .unwrap();
+    let mut rotations = KeyRotationChain::default();
+    rotations.apply(KeyRotation::new(device.object_id().clone(), 1, first_key.public(), second_key.public()).sign(&first_key).unwrap(), &mut device).unwrap();
+    let last = KeyRotation::new(device.object_id().clone(), 2, second_key.public(), third_key.public()).sign(&second_key).unwrap();
+    rotations.apply(last.clone(), &mut device).unwrap();
+
+    let desc_file = dir.join("device.desc");
+    let rotation_file = dir.join("device.rotation");
+    device.encode_to_file(desc_file.as_path(), false).unwrap();
+    rotations.encode_to_file(rotation_file.as_path(), false).unwrap();
+
+    // the object id isn't built from the current key.
+    let (_, checks) = verify(desc_file.clone(), None, None).unwrap();
+    assert!(!checks.is_valid());
+
+    let (_, checks) = verify(desc_file.clone(), Some(rotation_file.clone()), None).unwrap();
+    assert!(checks.is_valid());
+    assert_eq!(checks.0[0].0, "rotation");
+
+    let (object, checks) = verify(rotation_file.clone(), None, None).unwrap();
+    assert_eq!(object.kind(), "key-rotations");
+    assert!(checks.is_valid());
+
+    // the last rotation only, the object id isn't built from its old key.
+    let mut partial = KeyRotationChain::default();
+    let mut origin = device.clone();
+    origin.mut_desc().set_public_key(Some(second_key.public()));
+    partial.apply(last, &mut origin).unwrap();
+    partial.encode_to_file(rotation_file.as_path(), false).unwrap();
+    let (_, checks) = verify(desc_file, Some(rotation_file), None).unwrap();
+    assert!(!checks.is_valid());
+
+    let _ = std::fs::remove_dir_all(&dir);
+}